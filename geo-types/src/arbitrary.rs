@@ -1,8 +1,9 @@
 use crate::{
-    CoordFloat, Coordinate, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint,
-    MultiPolygon, Point, Polygon, Rect, Triangle,
+    CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use std::mem;
+use alloc::vec::Vec;
+use core::mem;
 
 impl<'a, T: arbitrary::Arbitrary<'a> + CoordFloat> arbitrary::Arbitrary<'a> for Coordinate<T> {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
@@ -65,7 +66,7 @@ impl<'a, T: arbitrary::Arbitrary<'a> + CoordFloat> arbitrary::Arbitrary<'a>
     for GeometryCollection<T>
 {
     fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
-        u.arbitrary()
+        u.arbitrary::<Vec<Geometry<T>>>().map(GeometryCollection)
     }
 }
 
@@ -106,3 +107,485 @@ impl<'a, T: arbitrary::Arbitrary<'a> + CoordFloat> arbitrary::Arbitrary<'a> for
         })
     }
 }
+
+/// Generates *valid* geometries from unstructured input, for property testing and fuzzing
+/// algorithms which assume valid input.
+///
+/// Unlike the `Arbitrary` implementations, which accept any coordinates (including `NaN`s and
+/// self-intersecting rings), every geometry generated by a `GeometryStrategy` lies within its
+/// `extent` and is valid in the [OGC-SFA] sense:
+///
+/// - `LineString`s have at least two coordinates and never intersect themselves
+/// - `Polygon` exteriors are counter-clockwise and never intersect themselves
+/// - `Polygon` interiors are clockwise, and lie strictly inside the exterior without touching it
+///   or each other
+/// - the parts of multi-geometries and `GeometryCollection`s are disjoint
+///
+/// The `extent` should have a non-zero width and height.
+///
+/// [OGC-SFA]: https://www.ogc.org/standards/sfa
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::Unstructured;
+/// use geo_types::{GeometryStrategy, Rect};
+///
+/// let strategy = GeometryStrategy::new(Rect::new((0., 0.), (100., 100.)));
+/// let mut u = Unstructured::new(&[7; 256]);
+///
+/// let polygon = strategy.polygon(&mut u).unwrap();
+/// assert!(polygon.exterior().0.len() >= 4);
+/// assert!(polygon.interiors().len() <= strategy.max_holes);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryStrategy<T: CoordFloat> {
+    /// All generated coordinates lie within this `Rect`
+    pub extent: Rect<T>,
+    /// The maximum number of distinct coordinates in each `LineString` or ring
+    pub max_coords: usize,
+    /// The maximum number of parts in each multi-geometry or `GeometryCollection`
+    pub max_parts: usize,
+    /// The maximum number of interior rings in each `Polygon`
+    pub max_holes: usize,
+}
+
+impl<T: CoordFloat> GeometryStrategy<T> {
+    /// Create a strategy generating geometries within `extent`, with at most 16 coordinates per
+    /// `LineString` or ring, 4 parts per multi-geometry and 2 interior rings per `Polygon`.
+    pub fn new(extent: Rect<T>) -> Self {
+        GeometryStrategy {
+            extent,
+            max_coords: 16,
+            max_parts: 4,
+            max_holes: 2,
+        }
+    }
+
+    /// A `Coordinate` anywhere within the extent.
+    pub fn coordinate(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Coordinate<T>> {
+        coordinate_in(u, self.extent)
+    }
+
+    /// A `Point` anywhere within the extent.
+    pub fn point(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Point<T>> {
+        self.coordinate(u).map(Point)
+    }
+
+    /// A `Line` with distinct endpoints.
+    pub fn line(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Line<T>> {
+        let line_string = self.line_string_in(u, self.extent, 2)?;
+        Ok(Line::new(line_string.0[0], line_string.0[1]))
+    }
+
+    /// A non-self-intersecting `LineString`.
+    pub fn line_string(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<LineString<T>> {
+        self.line_string_in(u, self.extent, self.max_coords)
+    }
+
+    /// A valid `Polygon`, possibly with interior rings.
+    pub fn polygon(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Polygon<T>> {
+        self.polygon_in(u, self.extent)
+    }
+
+    /// A counter-clockwise `Triangle` with a non-zero area.
+    pub fn triangle(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Triangle<T>> {
+        let (center, radius) = inscribed_circle(self.extent);
+        let coords = star_ring(u, center, radius, 3)?;
+        Ok(Triangle(coords[0], coords[1], coords[2]))
+    }
+
+    /// A `Rect` within the extent.
+    pub fn rect(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Rect<T>> {
+        Ok(Rect::new(self.coordinate(u)?, self.coordinate(u)?))
+    }
+
+    /// A `MultiPoint` with at least one `Point`.
+    pub fn multi_point(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<MultiPoint<T>> {
+        let count = count_in(u, 1, self.max_parts)?;
+        (0..count).map(|_| self.point(u)).collect()
+    }
+
+    /// A `MultiLineString` of disjoint, non-self-intersecting `LineString`s.
+    pub fn multi_line_string(
+        &self,
+        u: &mut arbitrary::Unstructured,
+    ) -> arbitrary::Result<MultiLineString<T>> {
+        let count = count_in(u, 1, self.max_parts)?;
+        self.cells(count)
+            .map(|cell| self.line_string_in(u, cell, self.max_coords))
+            .collect::<arbitrary::Result<_>>()
+            .map(MultiLineString)
+    }
+
+    /// A `MultiPolygon` of disjoint, valid `Polygon`s.
+    pub fn multi_polygon(
+        &self,
+        u: &mut arbitrary::Unstructured,
+    ) -> arbitrary::Result<MultiPolygon<T>> {
+        let count = count_in(u, 1, self.max_parts)?;
+        self.cells(count)
+            .map(|cell| self.polygon_in(u, cell))
+            .collect::<arbitrary::Result<_>>()
+            .map(MultiPolygon)
+    }
+
+    /// A collection of disjoint geometries of any type other than `GeometryCollection`.
+    pub fn geometry_collection(
+        &self,
+        u: &mut arbitrary::Unstructured,
+    ) -> arbitrary::Result<GeometryCollection<T>> {
+        let count = count_in(u, 1, self.max_parts)?;
+        self.cells(count)
+            .map(|cell| {
+                GeometryStrategy {
+                    extent: cell,
+                    ..*self
+                }
+                .single_geometry(u)
+            })
+            .collect::<arbitrary::Result<_>>()
+            .map(GeometryCollection)
+    }
+
+    /// A valid geometry of any type.
+    pub fn geometry(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Geometry<T>> {
+        if u.int_in_range(0..=9)? == 9 {
+            self.geometry_collection(u)
+                .map(Geometry::GeometryCollection)
+        } else {
+            self.single_geometry(u)
+        }
+    }
+
+    fn single_geometry(&self, u: &mut arbitrary::Unstructured) -> arbitrary::Result<Geometry<T>> {
+        Ok(match u.int_in_range(0..=8)? {
+            0 => Geometry::Point(self.point(u)?),
+            1 => Geometry::Line(self.line(u)?),
+            2 => Geometry::LineString(self.line_string(u)?),
+            3 => Geometry::Polygon(self.polygon(u)?),
+            4 => Geometry::MultiPoint(self.multi_point(u)?),
+            5 => Geometry::MultiLineString(self.multi_line_string(u)?),
+            6 => Geometry::MultiPolygon(self.multi_polygon(u)?),
+            7 => Geometry::Triangle(self.triangle(u)?),
+            8 => Geometry::Rect(self.rect(u)?),
+            _ => unreachable!(),
+        })
+    }
+
+    /// An x-monotone `LineString`, which therefore can't intersect itself.
+    fn line_string_in(
+        &self,
+        u: &mut arbitrary::Unstructured,
+        extent: Rect<T>,
+        max_coords: usize,
+    ) -> arbitrary::Result<LineString<T>> {
+        let count = count_in(u, 2, max_coords)?;
+        // strictly positive steps between successive x values
+        let steps = (0..count)
+            .map(|_| Ok(T::one() + unit::<T>(u)?))
+            .collect::<arbitrary::Result<Vec<T>>>()?;
+        let total = steps.iter().fold(T::zero(), |sum, &step| sum + step);
+        let mut x = T::zero();
+        steps
+            .into_iter()
+            .map(|step| {
+                x = x + step;
+                let y = extent.min().y + unit::<T>(u)? * extent.height();
+                Ok(Coordinate {
+                    x: extent.min().x + x / total * extent.width(),
+                    y,
+                })
+            })
+            .collect()
+    }
+
+    /// A `Polygon` with star-shaped rings.
+    ///
+    /// The exterior's vertices are no closer to the center than half of the inscribed radius of
+    /// `extent`, and successive vertices are less than 180° apart, so the exterior contains a
+    /// disk around the center. Each interior is placed within a separate sector of that disk.
+    fn polygon_in(
+        &self,
+        u: &mut arbitrary::Unstructured,
+        extent: Rect<T>,
+    ) -> arbitrary::Result<Polygon<T>> {
+        let (center, radius) = inscribed_circle(extent);
+        let vertex_count = count_in(u, 3, self.max_coords)?;
+        let exterior = star_ring(u, center, radius, vertex_count)?;
+
+        let two = T::one() + T::one();
+        let pi = T::from(core::f64::consts::PI).unwrap();
+        let max_gap = T::from(1.25).unwrap() * two * pi / T::from(vertex_count).unwrap();
+        let clear_radius = radius / two * (max_gap / two).cos();
+
+        let hole_count = count_in(u, 0, self.max_holes)?;
+        let hole_radius = if hole_count == 1 {
+            clear_radius / two
+        } else {
+            let sector = pi / T::from(hole_count).unwrap();
+            (clear_radius / two).min(clear_radius * sector.sin() / two)
+        } * T::from(0.9).unwrap();
+        let start_angle = unit::<T>(u)? * two * pi;
+        let interiors = (0..hole_count)
+            .map(|i| {
+                let angle =
+                    start_angle + two * pi * T::from(i).unwrap() / T::from(hole_count).unwrap();
+                let hole_center = Coordinate {
+                    x: center.x + clear_radius / two * angle.cos(),
+                    y: center.y + clear_radius / two * angle.sin(),
+                };
+                let vertex_count = count_in(u, 3, self.max_coords)?;
+                let mut hole = star_ring(u, hole_center, hole_radius, vertex_count)?;
+                hole.reverse();
+                Ok(LineString(hole))
+            })
+            .collect::<arbitrary::Result<_>>()?;
+
+        Ok(Polygon::new(LineString(exterior), interiors))
+    }
+
+    /// Split the extent into a grid of at least `count` cells, returning the first `count`, each
+    /// shrunk slightly so that geometries generated in different cells can't touch.
+    fn cells(&self, count: usize) -> impl Iterator<Item = Rect<T>> {
+        let columns = (1..).find(|c| c * c >= count).unwrap();
+        let rows = (1..).find(|r| r * columns >= count).unwrap();
+        let width = self.extent.width() / T::from(columns).unwrap();
+        let height = self.extent.height() / T::from(rows).unwrap();
+        let margin_x = width * T::from(0.05).unwrap();
+        let margin_y = height * T::from(0.05).unwrap();
+        let min = self.extent.min();
+        (0..count).map(move |i| {
+            let x = min.x + width * T::from(i % columns).unwrap();
+            let y = min.y + height * T::from(i / columns).unwrap();
+            Rect::new(
+                (x + margin_x, y + margin_y),
+                (x + width - margin_x, y + height - margin_y),
+            )
+        })
+    }
+}
+
+/// A value in `[0, 1]`.
+fn unit<T: CoordFloat>(u: &mut arbitrary::Unstructured) -> arbitrary::Result<T> {
+    let value = u.int_in_range(0..=u32::MAX)?;
+    Ok(T::from(value).unwrap() / T::from(u32::MAX).unwrap())
+}
+
+fn count_in(u: &mut arbitrary::Unstructured, min: usize, max: usize) -> arbitrary::Result<usize> {
+    u.int_in_range(min..=max.max(min))
+}
+
+fn coordinate_in<T: CoordFloat>(
+    u: &mut arbitrary::Unstructured,
+    extent: Rect<T>,
+) -> arbitrary::Result<Coordinate<T>> {
+    Ok(Coordinate {
+        x: extent.min().x + unit::<T>(u)? * extent.width(),
+        y: extent.min().y + unit::<T>(u)? * extent.height(),
+    })
+}
+
+fn inscribed_circle<T: CoordFloat>(extent: Rect<T>) -> (Coordinate<T>, T) {
+    let two = T::one() + T::one();
+    (extent.center(), extent.width().min(extent.height()) / two)
+}
+
+/// The counter-clockwise vertices of a star-shaped ring around `center`.
+///
+/// Vertices are evenly spaced by angle, with some jitter, at a distance between `radius / 2` and
+/// `radius` from the center.
+fn star_ring<T: CoordFloat>(
+    u: &mut arbitrary::Unstructured,
+    center: Coordinate<T>,
+    radius: T,
+    vertex_count: usize,
+) -> arbitrary::Result<Vec<Coordinate<T>>> {
+    let two = T::one() + T::one();
+    let four = two + two;
+    let step = two * T::from(core::f64::consts::PI).unwrap() / T::from(vertex_count).unwrap();
+    (0..vertex_count)
+        .map(|i| {
+            let angle = step * T::from(i).unwrap() + unit::<T>(u)? * step / four;
+            let distance = radius / two * (T::one() + unit::<T>(u)?);
+            Ok(Coordinate {
+                x: center.x + distance * angle.cos(),
+                y: center.y + distance * angle.sin(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Deterministic pseudo-random bytes, so failures can be reproduced.
+    fn bytes(seed: u64) -> Vec<u8> {
+        let mut state = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (0..4096)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    fn signed_area(ring: &LineString<f64>) -> f64 {
+        ring.lines().map(|line| line.determinant()).sum::<f64>() / 2.
+    }
+
+    fn orientation(a: Coordinate<f64>, b: Coordinate<f64>, c: Coordinate<f64>) -> f64 {
+        (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+    }
+
+    /// Whether the segments intersect or touch, ignoring collinear cases.
+    fn intersects(l1: Line<f64>, l2: Line<f64>) -> bool {
+        let d1 = orientation(l2.start, l2.end, l1.start);
+        let d2 = orientation(l2.start, l2.end, l1.end);
+        let d3 = orientation(l1.start, l1.end, l2.start);
+        let d4 = orientation(l1.start, l1.end, l2.end);
+        d1 * d2 <= 0. && d3 * d4 <= 0.
+    }
+
+    fn contains(ring: &LineString<f64>, coord: Coordinate<f64>) -> bool {
+        ring.lines()
+            .filter(|line| (line.start.y > coord.y) != (line.end.y > coord.y))
+            .filter(|line| {
+                let x = line.start.x
+                    + (coord.y - line.start.y) / (line.end.y - line.start.y)
+                        * (line.end.x - line.start.x);
+                coord.x < x
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    fn assert_simple(ring: &LineString<f64>) {
+        let lines: Vec<_> = ring.lines().collect();
+        for i in 0..lines.len() {
+            for j in i + 2..lines.len() {
+                if i == 0 && j == lines.len() - 1 && ring.is_closed() {
+                    continue;
+                }
+                assert!(!intersects(lines[i], lines[j]), "{:?}", ring);
+            }
+        }
+    }
+
+    fn assert_valid_polygon(polygon: &Polygon<f64>, extent: Rect<f64>) {
+        let exterior = polygon.exterior();
+        assert!(exterior.is_closed());
+        assert!(exterior.0.len() >= 4);
+        assert!(signed_area(exterior) > 0.);
+        assert_simple(exterior);
+        for coord in &exterior.0 {
+            assert!(coord.x >= extent.min().x && coord.x <= extent.max().x);
+            assert!(coord.y >= extent.min().y && coord.y <= extent.max().y);
+        }
+
+        for (i, interior) in polygon.interiors().iter().enumerate() {
+            assert!(interior.is_closed());
+            assert!(signed_area(interior) < 0.);
+            assert_simple(interior);
+            assert!(contains(exterior, interior.0[0]));
+            let others = core::iter::once(exterior).chain(polygon.interiors()[i + 1..].iter());
+            for other in others {
+                assert!(!contains(interior, other.0[0]));
+                for line in interior.lines() {
+                    assert!(other.lines().all(|other| !intersects(line, other)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygons_are_valid() {
+        let extent = Rect::new((-10., 5.), (30., 15.));
+        let strategy = GeometryStrategy {
+            max_holes: 5,
+            ..GeometryStrategy::new(extent)
+        };
+        let mut hole_count = 0;
+        for seed in 0..200 {
+            let bytes = bytes(seed);
+            let mut u = arbitrary::Unstructured::new(&bytes);
+            let polygon = strategy.polygon(&mut u).unwrap();
+            assert!(polygon.interiors().len() <= 5);
+            hole_count += polygon.interiors().len();
+            assert_valid_polygon(&polygon, extent);
+        }
+        assert!(hole_count > 0);
+    }
+
+    #[test]
+    fn multi_polygons_are_disjoint() {
+        let extent = Rect::new((0., 0.), (100., 100.));
+        let strategy = GeometryStrategy::new(extent);
+        for seed in 0..100 {
+            let bytes = bytes(seed);
+            let mut u = arbitrary::Unstructured::new(&bytes);
+            let multi_polygon = strategy.multi_polygon(&mut u).unwrap();
+            assert!(!multi_polygon.0.is_empty() && multi_polygon.0.len() <= 4);
+            for (i, polygon) in multi_polygon.0.iter().enumerate() {
+                assert_valid_polygon(polygon, extent);
+                for other in &multi_polygon.0[i + 1..] {
+                    assert!(!contains(polygon.exterior(), other.exterior().0[0]));
+                    assert!(!contains(other.exterior(), polygon.exterior().0[0]));
+                    for line in polygon.exterior().lines() {
+                        assert!(other
+                            .exterior()
+                            .lines()
+                            .all(|other| !intersects(line, other)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn line_strings_are_simple() {
+        let extent = Rect::new((0., 0.), (1., 1.));
+        let strategy = GeometryStrategy::new(extent);
+        for seed in 0..100 {
+            let bytes = bytes(seed);
+            let mut u = arbitrary::Unstructured::new(&bytes);
+            let line_string = strategy.line_string(&mut u).unwrap();
+            assert!(line_string.0.len() >= 2 && line_string.0.len() <= 16);
+            assert_simple(&line_string);
+        }
+    }
+
+    #[test]
+    fn polygonal_geometries_are_valid() {
+        let extent = Rect::new((-1., -1.), (1., 1.));
+        let strategy = GeometryStrategy::new(extent);
+        for seed in 0..100 {
+            let bytes = bytes(seed);
+            let mut u = arbitrary::Unstructured::new(&bytes);
+            let geometry = strategy.geometry(&mut u).unwrap();
+            let polygons: Vec<Polygon<f64>> = match geometry {
+                Geometry::Polygon(polygon) => vec![polygon],
+                Geometry::MultiPolygon(multi_polygon) => multi_polygon.0,
+                Geometry::Triangle(triangle) => vec![triangle.to_polygon()],
+                Geometry::GeometryCollection(collection) => collection
+                    .0
+                    .into_iter()
+                    .filter_map(|geometry| match geometry {
+                        Geometry::Polygon(polygon) => Some(polygon),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            for polygon in &polygons {
+                assert_valid_polygon(polygon, extent);
+            }
+        }
+    }
+}
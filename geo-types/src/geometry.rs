@@ -4,9 +4,9 @@ use crate::{
 };
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use core::any::type_name;
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 /// An enum representing any possible geometry type.
 ///
@@ -340,3 +340,53 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Geometry<T> {
         }
     }
 }
+
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for Geometry<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, Geometry};
+    ///
+    /// let a: Geometry<f64> = point![x: 0.1 + 0.2, y: 1.].into();
+    /// let b: Geometry<f64> = point![x: 0.3, y: 1.].into();
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        match (self, other) {
+            (Geometry::Point(g1), Geometry::Point(g2)) => g1.ulps_eq(g2, epsilon, max_ulps),
+            (Geometry::Line(g1), Geometry::Line(g2)) => g1.ulps_eq(g2, epsilon, max_ulps),
+            (Geometry::LineString(g1), Geometry::LineString(g2)) => {
+                g1.ulps_eq(g2, epsilon, max_ulps)
+            }
+            (Geometry::Polygon(g1), Geometry::Polygon(g2)) => g1.ulps_eq(g2, epsilon, max_ulps),
+            (Geometry::MultiPoint(g1), Geometry::MultiPoint(g2)) => {
+                g1.ulps_eq(g2, epsilon, max_ulps)
+            }
+            (Geometry::MultiLineString(g1), Geometry::MultiLineString(g2)) => {
+                g1.ulps_eq(g2, epsilon, max_ulps)
+            }
+            (Geometry::MultiPolygon(g1), Geometry::MultiPolygon(g2)) => {
+                g1.ulps_eq(g2, epsilon, max_ulps)
+            }
+            (Geometry::GeometryCollection(g1), Geometry::GeometryCollection(g2)) => {
+                g1.ulps_eq(g2, epsilon, max_ulps)
+            }
+            (Geometry::Rect(g1), Geometry::Rect(g2)) => g1.ulps_eq(g2, epsilon, max_ulps),
+            (Geometry::Triangle(g1), Geometry::Triangle(g2)) => g1.ulps_eq(g2, epsilon, max_ulps),
+            (_, _) => false,
+        }
+    }
+}
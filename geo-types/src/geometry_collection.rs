@@ -1,9 +1,11 @@
 use crate::{CoordNum, Geometry};
+use alloc::vec;
+use alloc::vec::Vec;
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// A collection of [`Geometry`](enum.Geometry.html) types.
 ///
@@ -129,7 +131,7 @@ impl<T: CoordNum> IndexMut<usize> for GeometryCollection<T> {
 // structure helper for consuming iterator
 #[derive(Debug)]
 pub struct IntoIteratorHelper<T: CoordNum> {
-    iter: ::std::vec::IntoIter<Geometry<T>>,
+    iter: ::alloc::vec::IntoIter<Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a consuming iterator. Iteration will
@@ -159,7 +161,7 @@ impl<T: CoordNum> Iterator for IntoIteratorHelper<T> {
 // structure helper for non-consuming iterator
 #[derive(Debug)]
 pub struct IterHelper<'a, T: CoordNum> {
-    iter: ::std::slice::Iter<'a, Geometry<T>>,
+    iter: ::core::slice::Iter<'a, Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a non-consuming iterator. Iteration will
@@ -189,7 +191,7 @@ impl<'a, T: CoordNum> Iterator for IterHelper<'a, T> {
 // structure helper for mutable non-consuming iterator
 #[derive(Debug)]
 pub struct IterMutHelper<'a, T: CoordNum> {
-    iter: ::std::slice::IterMut<'a, Geometry<T>>,
+    iter: ::core::slice::IterMut<'a, Geometry<T>>,
 }
 
 // implement the IntoIterator trait for a mutable non-consuming iterator. Iteration will
@@ -301,3 +303,36 @@ where
         mp_zipper.all(|(lhs, rhs)| lhs.abs_diff_eq(&rhs, epsilon))
     }
 }
+
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for GeometryCollection<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{point, GeometryCollection};
+    ///
+    /// let a = GeometryCollection(vec![point![x: 0.1 + 0.2, y: 1.].into()]);
+    /// let b = GeometryCollection(vec![point![x: 0.3, y: 1.].into()]);
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+
+        let mut mp_zipper = self.iter().zip(other.iter());
+        mp_zipper.all(|(lhs, rhs)| lhs.ulps_eq(rhs, epsilon, max_ulps))
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(missing_debug_implementations)]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
 //! The `geo-types` library provides geospatial primitive types for the [GeoRust] ecosystem.
@@ -35,9 +36,12 @@
 //! The following optional [Cargo features] are available:
 //!
 //! - `approx`: Allows geometry types to be checked for approximate equality with [approx]
-//! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary]
+//! - `arbitrary`: Allows geometry types to be created from unstructured input with [arbitrary],
+//!   and valid geometries to be generated with a [`GeometryStrategy`] for property testing
 //! - `serde`: Allows geometry types to be serialized and deserialized with [Serde]
-//! - `use-rstar`: Allows geometry types to be inserted into [rstar] R*-trees
+//! - `std`: Enabled by default. Without it, the crate is `no_std` and needs only `alloc`, taking
+//!   its floating point functions from [libm]
+//! - `use-rstar`: Allows geometry types to be inserted into [rstar] R*-trees. Requires `std`
 //!
 //! [approx]: https://github.com/brendanzab/approx
 //! [arbitrary]: https://github.com/rust-fuzz/arbitrary
@@ -45,12 +49,14 @@
 //! [GeoRust]: https://georust.org
 //! [GEOS]: https://trac.osgeo.org/geos
 //! [JTS]: https://github.com/locationtech/jts
+//! [libm]: https://github.com/rust-lang/libm
 //! [OGC-SFA]: https://www.ogc.org/standards/sfa
 //! [rstar]: https://github.com/Stoeoef/rstar
 //! [Serde]: https://serde.rs/
+extern crate alloc;
 extern crate num_traits;
+use core::fmt::Debug;
 use num_traits::{Float, Num, NumCast};
-use std::fmt::Debug;
 
 #[cfg(feature = "serde")]
 #[macro_use]
@@ -127,15 +133,23 @@ mod macros;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
+#[cfg(feature = "arbitrary")]
+pub use crate::arbitrary::GeometryStrategy;
 
-#[cfg(feature = "rstar")]
 #[doc(hidden)]
 pub mod private_utils;
 
+#[doc(hidden)]
+pub mod _alloc {
+    //! Lets the macros reach `alloc` when `std` is disabled, whether or not the calling crate
+    //! declares `extern crate alloc`. Not meant for public use.
+    pub use ::alloc::{boxed, vec};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::convert::TryFrom;
+    use core::convert::TryFrom;
 
     #[test]
     fn type_test() {
@@ -1,6 +1,6 @@
 use crate::{CoordNum, Coordinate, Point};
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 /// A line segment made up of exactly two
 /// [`Coordinate`s](struct.Coordinate.html).
@@ -227,6 +227,35 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Line<T> {
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for Line<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Line};
+    ///
+    /// let a = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 0.1 + 0.2, y: 1. });
+    /// let b = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 0.3, y: 1. });
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.start.ulps_eq(&other.start, epsilon, max_ulps)
+            && self.end.ulps_eq(&other.end, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "rstar")]
 impl<T> ::rstar::RTreeObject for Line<T>
 where
@@ -1,9 +1,11 @@
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 use crate::{CoordNum, Coordinate, Line, Point, Triangle};
-use std::iter::FromIterator;
-use std::ops::{Index, IndexMut};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
 
 /// An ordered collection of two or more [`Coordinate`]s, representing a
 /// path between locations.
@@ -116,7 +118,7 @@ where
 
 /// A `Point` iterator returned by the `points_iter` method
 #[derive(Debug)]
-pub struct PointsIter<'a, T: CoordNum + 'a>(::std::slice::Iter<'a, Coordinate<T>>);
+pub struct PointsIter<'a, T: CoordNum + 'a>(::core::slice::Iter<'a, Coordinate<T>>);
 
 impl<'a, T: CoordNum> Iterator for PointsIter<'a, T> {
     type Item = Point<T>;
@@ -270,7 +272,7 @@ impl<T: CoordNum, IC: Into<Coordinate<T>>> FromIterator<IC> for LineString<T> {
 /// Iterate over all the [Coordinate](struct.Coordinates.html)s in this `LineString`.
 impl<T: CoordNum> IntoIterator for LineString<T> {
     type Item = Coordinate<T>;
-    type IntoIter = ::std::vec::IntoIter<Coordinate<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<Coordinate<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -280,9 +282,9 @@ impl<T: CoordNum> IntoIterator for LineString<T> {
 /// Mutably iterate over all the [Coordinate](struct.Coordinates.html)s in this `LineString`.
 impl<'a, T: CoordNum> IntoIterator for &'a mut LineString<T> {
     type Item = &'a mut Coordinate<T>;
-    type IntoIter = ::std::slice::IterMut<'a, Coordinate<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, Coordinate<T>>;
 
-    fn into_iter(self) -> ::std::slice::IterMut<'a, Coordinate<T>> {
+    fn into_iter(self) -> ::core::slice::IterMut<'a, Coordinate<T>> {
         self.0.iter_mut()
     }
 }
@@ -381,6 +383,38 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for LineString<T> {
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for LineString<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::line_string;
+    ///
+    /// let a = line_string![(x: 0., y: 0.), (x: 0.1 + 0.2, y: 1.)];
+    /// let b = line_string![(x: 0., y: 0.), (x: 0.3, y: 1.)];
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+        let mut points_zipper = self.points_iter().zip(other.points_iter());
+        points_zipper.all(|(lhs, rhs)| lhs.ulps_eq(&rhs, epsilon, max_ulps))
+    }
+}
+
 #[cfg(feature = "rstar")]
 impl<T> ::rstar::RTreeObject for LineString<T>
 where
@@ -116,7 +116,7 @@ macro_rules! coord {
 /// [`LineString`]: ./line_string/struct.LineString.html
 #[macro_export]
 macro_rules! line_string {
-    () => { $crate::LineString(vec![]) };
+    () => { $crate::LineString($crate::_alloc::vec![]) };
     (
         $((x: $x:expr, y: $y:expr)),*
         $(,)?
@@ -133,7 +133,7 @@ macro_rules! line_string {
     ) => {
         $crate::LineString(
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::boxed::Box::new(
                     [$($coord), *]
                 )
             )
@@ -209,7 +209,7 @@ macro_rules! line_string {
 /// [`Polygon`]: ./struct.Polygon.html
 #[macro_export]
 macro_rules! polygon {
-    () => { $crate::Polygon::new(line_string![], vec![]) };
+    () => { $crate::Polygon::new(line_string![], $crate::_alloc::vec![]) };
     (
         exterior: [
             $((x: $exterior_x:expr, y: $exterior_y:expr)),*
@@ -257,7 +257,7 @@ macro_rules! polygon {
                 $($exterior_coord), *
             ],
             <[_]>::into_vec(
-                ::std::boxed::Box::new(
+                $crate::_alloc::boxed::Box::new(
                     [
                         $(
                             $crate::line_string![$($interior_coord),*]
@@ -281,7 +281,7 @@ macro_rules! polygon {
     ) => {
         $crate::Polygon::new(
             $crate::line_string![$($coord,)*],
-            vec![],
+            $crate::_alloc::vec![],
         )
     };
 }
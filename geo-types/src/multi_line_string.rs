@@ -1,8 +1,10 @@
 use crate::{CoordNum, LineString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
-use std::iter::FromIterator;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::iter::FromIterator;
 
 /// A collection of
 /// [`LineString`s](line_string/struct.LineString.html). Can
@@ -78,7 +80,7 @@ impl<T: CoordNum, ILS: Into<LineString<T>>> FromIterator<ILS> for MultiLineStrin
 
 impl<T: CoordNum> IntoIterator for MultiLineString<T> {
     type Item = LineString<T>;
-    type IntoIter = ::std::vec::IntoIter<LineString<T>>;
+    type IntoIter = ::alloc::vec::IntoIter<LineString<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
@@ -87,7 +89,7 @@ impl<T: CoordNum> IntoIterator for MultiLineString<T> {
 
 impl<'a, T: CoordNum> IntoIterator for &'a MultiLineString<T> {
     type Item = &'a LineString<T>;
-    type IntoIter = ::std::slice::Iter<'a, LineString<T>>;
+    type IntoIter = ::core::slice::Iter<'a, LineString<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&self.0).iter()
@@ -96,7 +98,7 @@ impl<'a, T: CoordNum> IntoIterator for &'a MultiLineString<T> {
 
 impl<'a, T: CoordNum> IntoIterator for &'a mut MultiLineString<T> {
     type Item = &'a mut LineString<T>;
-    type IntoIter = ::std::slice::IterMut<'a, LineString<T>>;
+    type IntoIter = ::core::slice::IterMut<'a, LineString<T>>;
 
     fn into_iter(self) -> Self::IntoIter {
         (&mut self.0).iter_mut()
@@ -189,6 +191,39 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for MultiLineString<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{line_string, MultiLineString};
+    ///
+    /// let a = MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 0.1 + 0.2, y: 1.)]]);
+    /// let b = MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 0.3, y: 1.)]]);
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+
+        let mut mp_zipper = self.iter().zip(other.iter());
+        mp_zipper.all(|(lhs, rhs)| lhs.ulps_eq(rhs, epsilon, max_ulps))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
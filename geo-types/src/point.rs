@@ -1,9 +1,9 @@
 use crate::{CoordFloat, CoordNum, Coordinate};
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 /// A single point in 2D space.
 ///
@@ -488,6 +488,35 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for Point<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::Point;
+    ///
+    /// let a = Point::new(0.1 + 0.2, 1.0);
+    /// let b = Point::new(0.3, 1.0);
+    ///
+    /// assert_ne!(a, b);
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
 #[cfg(feature = "rstar")]
 // These are required for rstar RTree
 impl<T> ::rstar::Point for Point<T>
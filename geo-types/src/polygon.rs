@@ -1,8 +1,10 @@
 use crate::{CoordFloat, CoordNum, LineString, Point, Rect, Triangle};
+use alloc::vec;
+use alloc::vec::Vec;
 use num_traits::{Float, Signed};
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 /// A bounded two-dimensional area.
 ///
@@ -549,3 +551,39 @@ impl<T: AbsDiffEq<Epsilon = T> + CoordNum> AbsDiffEq for Polygon<T> {
         zipper.all(|(lhs, rhs)| lhs.abs_diff_eq(&rhs, epsilon))
     }
 }
+
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for Polygon<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::polygon;
+    ///
+    /// let a = polygon![(x: 0., y: 0.), (x: 0.1 + 0.2, y: 0.), (x: 0., y: 1.)];
+    /// let b = polygon![(x: 0., y: 0.), (x: 0.3, y: 0.), (x: 0., y: 1.)];
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        if !self.exterior.ulps_eq(&other.exterior, epsilon, max_ulps) {
+            return false;
+        }
+
+        if self.interiors.len() != other.interiors.len() {
+            return false;
+        }
+        let mut zipper = self.interiors.iter().zip(other.interiors.iter());
+        zipper.all(|(lhs, rhs)| lhs.ulps_eq(rhs, epsilon, max_ulps))
+    }
+}
@@ -3,7 +3,9 @@
 // hidden module is public so the geo crate can reuse these algorithms to
 // prevent duplication. These functions are _not_ meant for public consumption.
 
-use crate::{CoordFloat, CoordNum, Coordinate, Line, LineString, Point, Rect};
+#[cfg(feature = "approx")]
+use crate::Point;
+use crate::{CoordFloat, CoordNum, Coordinate, Line, LineString, Rect};
 
 pub fn line_string_bounding_rect<T>(line_string: &LineString<T>) -> Option<Rect<T>>
 where
@@ -93,6 +95,7 @@ where
     line.dx().hypot(line.dy())
 }
 
+#[cfg(feature = "approx")]
 pub fn point_line_string_euclidean_distance<T>(p: Point<T>, l: &LineString<T>) -> T
 where
     T: CoordFloat,
@@ -114,6 +117,7 @@ where
     line_segment_distance(p.into(), l.start, l.end)
 }
 
+#[cfg(feature = "approx")]
 pub fn point_contains_point<T>(p1: Point<T>, p2: Point<T>) -> bool
 where
     T: CoordFloat,
@@ -122,6 +126,7 @@ where
     approx::relative_eq!(distance, 0.0)
 }
 
+#[cfg(feature = "approx")]
 pub fn line_string_contains_point<T>(line_string: &LineString<T>, point: Point<T>) -> bool
 where
     T: CoordFloat,
@@ -1,7 +1,7 @@
 use crate::{polygon, CoordFloat, CoordNum, Coordinate, Polygon};
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 /// An _axis-aligned_ bounded 2D rectangle whose area is
 /// defined by minimum and maximum `Coordinate`s.
@@ -227,6 +227,170 @@ impl<T: CoordNum> Rect<T> {
         ]
     }
 
+    /// The corners of the `Rect`, counter-clockwise from its minimum coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let rect = Rect::new((0., 0.), (10., 20.));
+    /// assert_eq!(
+    ///     rect.corners(),
+    ///     [
+    ///         Coordinate { x: 0., y: 0. },
+    ///         Coordinate { x: 10., y: 0. },
+    ///         Coordinate { x: 10., y: 20. },
+    ///         Coordinate { x: 0., y: 20. },
+    ///     ]
+    /// );
+    /// ```
+    pub fn corners(self) -> [Coordinate<T>; 4] {
+        [
+            self.min,
+            Coordinate {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Coordinate {
+                x: self.min.x,
+                y: self.max.y,
+            },
+        ]
+    }
+
+    /// Create a `Polygon` from the `Rect`, with its exterior wound counter-clockwise from the
+    /// minimum coordinate, through its [`corners`](Self::corners), rather than clockwise as
+    /// [`to_polygon`](Self::to_polygon) winds it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::{polygon, Rect};
+    ///
+    /// let rect = Rect::new((0., 0.), (10., 20.));
+    /// assert_eq!(
+    ///     rect.to_polygon_ccw(),
+    ///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 20.), (x: 0., y: 20.)],
+    /// );
+    /// ```
+    pub fn to_polygon_ccw(self) -> Polygon<T> {
+        polygon![
+            (x: self.min.x, y: self.min.y),
+            (x: self.max.x, y: self.min.y),
+            (x: self.max.x, y: self.max.y),
+            (x: self.min.x, y: self.max.y),
+            (x: self.min.x, y: self.min.y),
+        ]
+    }
+
+    /// The area of the `Rect`, which is zero if it's a line or a point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::Rect;
+    ///
+    /// assert_eq!(Rect::new((0, 0), (10, 20)).area(), 200);
+    /// assert_eq!(Rect::new((0, 0), (10, 0)).area(), 0);
+    /// ```
+    pub fn area(self) -> T {
+        self.width() * self.height()
+    }
+
+    /// The smallest `Rect` containing both `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::Rect;
+    ///
+    /// let a = Rect::new((0, 0), (10, 10));
+    /// let b = Rect::new((5, -5), (20, 5));
+    /// assert_eq!(a.union(b), Rect::new((0, -5), (20, 10)));
+    /// ```
+    pub fn union(self, other: Rect<T>) -> Rect<T> {
+        Rect {
+            min: Coordinate {
+                x: partial_min(self.min.x, other.min.x),
+                y: partial_min(self.min.y, other.min.y),
+            },
+            max: Coordinate {
+                x: partial_max(self.max.x, other.max.x),
+                y: partial_max(self.max.y, other.max.y),
+            },
+        }
+    }
+
+    /// The `Rect` covered by both `self` and `other`, if they meet. Where they only touch, it
+    /// has no width, or height, or both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::Rect;
+    ///
+    /// let a = Rect::new((0, 0), (10, 10));
+    /// assert_eq!(
+    ///     a.intersection(Rect::new((5, -5), (20, 5))),
+    ///     Some(Rect::new((5, 0), (10, 5)))
+    /// );
+    /// // sharing an edge
+    /// assert_eq!(
+    ///     a.intersection(Rect::new((10, 0), (20, 10))),
+    ///     Some(Rect::new((10, 0), (10, 10)))
+    /// );
+    /// assert_eq!(a.intersection(Rect::new((11, 0), (20, 10))), None);
+    /// ```
+    pub fn intersection(self, other: Rect<T>) -> Option<Rect<T>> {
+        let min = Coordinate {
+            x: partial_max(self.min.x, other.min.x),
+            y: partial_max(self.min.y, other.min.y),
+        };
+        let max = Coordinate {
+            x: partial_min(self.max.x, other.max.x),
+            y: partial_min(self.max.y, other.max.y),
+        };
+        if min.x > max.x || min.y > max.y {
+            return None;
+        }
+        Some(Rect { min, max })
+    }
+
+    /// The `Rect` with each of its sides moved outwards by `distance`. A negative distance
+    /// moves them inwards, until they meet at the center.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::Rect;
+    ///
+    /// let rect = Rect::new((0., 0.), (10., 4.));
+    /// assert_eq!(rect.expand(1.), Rect::new((-1., -1.), (11., 5.)));
+    /// assert_eq!(rect.expand(-1.), Rect::new((1., 1.), (9., 3.)));
+    /// // too narrow to shrink any further from top to bottom
+    /// assert_eq!(rect.expand(-3.), Rect::new((3., 2.), (7., 2.)));
+    /// ```
+    pub fn expand(self, distance: T) -> Rect<T> {
+        let two = T::one() + T::one();
+        let expand = |min: T, max: T| {
+            let (min, max) = (min - distance, max + distance);
+            if min > max {
+                let center = (min + max) / two;
+                (center, center)
+            } else {
+                (min, max)
+            }
+        };
+        let (min_x, max_x) = expand(self.min.x, self.max.x);
+        let (min_y, max_y) = expand(self.min.y, self.max.y);
+        Rect {
+            min: Coordinate { x: min_x, y: min_y },
+            max: Coordinate { x: max_x, y: max_y },
+        }
+    }
+
     fn assert_valid_bounds(&self) {
         if !self.has_valid_bounds() {
             panic!("{}", RECT_INVALID_BOUNDS_ERROR);
@@ -264,6 +428,37 @@ impl<T: CoordFloat> Rect<T> {
         )
             .into()
     }
+
+    /// The ratio of the `Rect`'s width to its height, which is infinite for a horizontal line,
+    /// and `NaN` for a point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geo_types::Rect;
+    ///
+    /// assert_eq!(Rect::new((0., 0.), (10., 4.)).aspect_ratio(), 2.5);
+    /// assert_eq!(Rect::new((0., 0.), (10., 0.)).aspect_ratio(), f64::INFINITY);
+    /// ```
+    pub fn aspect_ratio(self) -> T {
+        self.width() / self.height()
+    }
+}
+
+fn partial_min<T: CoordNum>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn partial_max<T: CoordNum>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
 }
 
 static RECT_INVALID_BOUNDS_ERROR: &str = "Failed to create Rect: 'min' coordinate's x/y value must be smaller or equal to the 'max' x/y value";
@@ -350,6 +545,35 @@ where
     }
 }
 
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for Rect<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Rect};
+    ///
+    /// let a = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 0.1 + 0.2, y: 1. });
+    /// let b = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 0.3, y: 1. });
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.min.ulps_eq(&other.min, epsilon, max_ulps)
+            && self.max.ulps_eq(&other.max, epsilon, max_ulps)
+    }
+}
+
 #[deprecated(
     since = "0.6.2",
     note = "Use `Rect::new` instead, since `Rect::try_new` will never Error"
@@ -357,12 +581,13 @@ where
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct InvalidRectCoordinatesError;
 
+#[cfg(feature = "std")]
 #[allow(deprecated)]
 impl std::error::Error for InvalidRectCoordinatesError {}
 
 #[allow(deprecated)]
-impl std::fmt::Display for InvalidRectCoordinatesError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for InvalidRectCoordinatesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", RECT_INVALID_BOUNDS_ERROR)
     }
 }
@@ -414,4 +639,20 @@ mod test {
             Coordinate::from((0., 0.))
         );
     }
+
+    #[test]
+    fn rect_envelope_algebra() {
+        let outer = Rect::new((0, 0), (10, 10));
+        let inner = Rect::new((2, 3), (4, 5));
+        assert_eq!(outer.intersection(inner), Some(inner));
+        assert_eq!(inner.intersection(outer), Some(inner));
+        assert_eq!(outer.union(inner), outer);
+        // meeting at a corner
+        assert_eq!(
+            outer.intersection(Rect::new((10, 10), (12, 12))),
+            Some(Rect::new((10, 10), (10, 10)))
+        );
+        assert_eq!(inner.expand(2).area(), 36);
+        assert_eq!(inner.expand(-5), Rect::new((3, 4), (3, 4)));
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{polygon, CoordNum, Coordinate, Line, Polygon};
 
 #[cfg(any(feature = "approx", test))]
-use approx::{AbsDiffEq, RelativeEq};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 /// A bounded 2D area whose three vertices are defined by
 /// `Coordinate`s. The semantics and validity are that of
@@ -143,3 +143,33 @@ where
         true
     }
 }
+
+#[cfg(any(feature = "approx", test))]
+impl<T> UlpsEq for Triangle<T>
+where
+    T: AbsDiffEq<Epsilon = T> + CoordNum + UlpsEq,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    /// Equality assertion within a limit of units in the last place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Coordinate, Triangle};
+    ///
+    /// let a = Triangle(Coordinate { x: 0., y: 0. }, Coordinate { x: 0.1 + 0.2, y: 0. }, Coordinate { x: 0., y: 1. });
+    /// let b = Triangle(Coordinate { x: 0., y: 0. }, Coordinate { x: 0.3, y: 0. }, Coordinate { x: 0., y: 1. });
+    ///
+    /// approx::assert_ulps_eq!(a, b);
+    /// ```
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            && self.1.ulps_eq(&other.1, epsilon, max_ulps)
+            && self.2.ulps_eq(&other.2, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,68 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::algorithm::area::Area;
+use geo::algorithm::bounding_rect::BoundingRect;
+use geo::algorithm::contains::Contains;
+use geo::algorithm::euclidean_length::EuclideanLength;
+use geo::algorithm::packed::{PackedLineString, PackedPolygon};
+use geo::{Coordinate, LineString, Point, Polygon};
+
+// a ring of a million vertices around a wobbly circle
+fn ring() -> LineString<f64> {
+    let count = 1_000_000;
+    let mut ring: LineString<f64> = (0..count)
+        .map(|i| {
+            let angle = i as f64 / count as f64 * std::f64::consts::PI * 2.;
+            let radius = 10. + (angle * 50.).sin();
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect();
+    ring.close();
+    ring
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let ring = ring();
+    let packed = PackedLineString::from(&ring);
+    let polygon = Polygon::new(ring.clone(), vec![]);
+    let packed_polygon = PackedPolygon::from(&polygon);
+    let coord = Coordinate { x: 0.5, y: 0.5 };
+
+    c.bench_function("bounding rect (1M vertices)", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&ring).bounding_rect()));
+    });
+    c.bench_function("packed bounding rect (1M vertices)", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&packed).bounding_rect()));
+    });
+
+    c.bench_function("euclidean length (1M vertices)", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&ring).euclidean_length()));
+    });
+    c.bench_function("packed euclidean length (1M vertices)", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&packed).euclidean_length()));
+    });
+
+    c.bench_function("signed area (1M vertices)", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&polygon).signed_area()));
+    });
+    c.bench_function("packed signed area (1M vertices)", |bencher| {
+        bencher.iter(|| criterion::black_box(criterion::black_box(&packed).signed_area()));
+    });
+
+    c.bench_function("point in polygon (1M vertices)", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(criterion::black_box(&polygon).contains(&Point(coord)));
+        });
+    });
+    c.bench_function("packed point in polygon (1M vertices)", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(criterion::black_box(&packed_polygon).contains_coord(coord));
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,85 @@
+#![no_main]
+
+//! Relate pairs of polygons which are nearly degenerate with respect to each other, to catch
+//! robustness failures in the topology graph.
+//!
+//! Each input deterministically produces a valid polygon and a second polygon derived from it by
+//! sharing one of its edges, shifting it by a tiny offset, rotating it by a tiny angle, or nudging
+//! one of its vertices. Then we check invariants which must hold however the intersection matrix
+//! is computed, like `a.relate(b)` being the transpose of `b.relate(a)`.
+//!
+//! geo has no overlay operations yet, so there are no overlay invariants (like area conservation)
+//! to check here.
+
+use geo::algorithm::map_coords::MapCoords;
+use geo::algorithm::relate::Relate;
+use geo::algorithm::rotate::RotatePoint;
+use geo_types::{Coordinate, GeometryStrategy, LineString, Polygon, Rect};
+use libfuzzer_sys::arbitrary::{Result, Unstructured};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut u = Unstructured::new(data);
+    if let Ok((a, b)) = polygon_pair(&mut u) {
+        check_relate(&a, &b);
+    }
+});
+
+fn polygon_pair(u: &mut Unstructured) -> Result<(Polygon<f64>, Polygon<f64>)> {
+    let strategy = GeometryStrategy::new(Rect::new((-1000., -1000.), (1000., 1000.)));
+    let a = strategy.polygon(u)?;
+    // a factor of machine epsilon, from a few ulps up to about a billionth
+    let tiny = f64::EPSILON * f64::from(u.int_in_range(1..=1u32 << 22)?);
+
+    let b = match u.int_in_range(0..=3)? {
+        // a triangle sharing an edge of `a`'s exterior, on its outside
+        0 => {
+            let edges = a.exterior().0.len() - 1;
+            let edge = a
+                .exterior()
+                .lines()
+                .nth(u.int_in_range(0..=edges - 1)?)
+                .unwrap();
+            let (dx, dy) = (edge.dx(), edge.dy());
+            let apex = Coordinate {
+                x: (edge.start.x + edge.end.x) / 2. + dy,
+                y: (edge.start.y + edge.end.y) / 2. - dx,
+            };
+            Polygon::new(LineString(vec![edge.end, edge.start, apex]), vec![])
+        }
+        // a sliver of overlap with `a`
+        1 => a.map_coords(|&(x, y)| (x + tiny * 1000., y + tiny * 1000.)),
+        // edges almost parallel to those of `a`
+        2 => {
+            let pivot = a.exterior().0[0].into();
+            a.rotate_around_point(tiny.to_degrees(), pivot)
+        }
+        // `a`, with one of its exterior vertices moved very slightly
+        _ => {
+            let mut exterior = a.exterior().clone();
+            let last = exterior.0.len() - 2;
+            let index = u.int_in_range(0..=last)?;
+            exterior.0[index].x += tiny * 1000.;
+            exterior.0[index].y -= tiny * 1000.;
+            if index == 0 {
+                exterior.0[last + 1] = exterior.0[0];
+            }
+            Polygon::new(exterior, a.interiors().to_vec())
+        }
+    };
+
+    Ok((a, b))
+}
+
+fn check_relate(a: &Polygon<f64>, b: &Polygon<f64>) {
+    let ab = a.relate(b);
+    let ba = b.relate(a);
+
+    assert_eq!(ab.transpose(), ba);
+    assert_eq!(ab.is_contains(), ba.is_within());
+    assert_ne!(ab.is_intersects(), ab.is_disjoint());
+
+    // every polygon is related to itself
+    let aa = a.relate(a);
+    assert!(aa.is_contains() && aa.is_within());
+}
@@ -0,0 +1,222 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::CoordPos;
+use crate::algorithm::dimensions::Dimensions;
+use crate::algorithm::relate::Relate;
+use crate::{GeoFloat, Point, Rect};
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+/// How two neighbouring polygons of an [`AdjacencyGraph`] meet.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Adjacency {
+    /// The polygons share a boundary of nonzero length, without overlapping
+    Edge,
+    /// The polygons only touch at one or more points
+    Point,
+    /// The interiors of the polygons overlap
+    Overlap,
+}
+
+/// Which of a set of polygons neighbour each other, and how.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::adjacency::{Adjacency, AdjacencyGraph};
+/// use geo::polygon;
+///
+/// // a row of two squares, and a square touching the second at a corner
+/// let regions = vec![
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+///     polygon![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 1., y: 1.)],
+///     polygon![(x: 2., y: 1.), (x: 3., y: 1.), (x: 3., y: 2.), (x: 2., y: 2.)],
+/// ];
+/// let graph = AdjacencyGraph::new(&regions);
+///
+/// assert_eq!(graph.neighbours(0), &[(1, Adjacency::Edge)]);
+/// assert_eq!(graph.neighbours(1), &[(0, Adjacency::Edge), (2, Adjacency::Point)]);
+/// assert_eq!(
+///     graph.edges().collect::<Vec<_>>(),
+///     vec![(0, 1, Adjacency::Edge), (1, 2, Adjacency::Point)]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjacencyGraph {
+    neighbours: Vec<Vec<(usize, Adjacency)>>,
+}
+
+impl AdjacencyGraph {
+    /// Build the graph of `polygons`, which can be `Polygon`s or `MultiPolygon`s.
+    ///
+    /// The bounding rectangles of the polygons are indexed in an R*-tree, and only pairs whose
+    /// rectangles intersect are related, with [`Relate`].
+    pub fn new<T, G>(polygons: &[G]) -> Self
+    where
+        T: GeoFloat + RTreeNum,
+        G: BoundingRect<T, Output = Option<Rect<T>>> + Relate<T, G>,
+    {
+        let envelopes: Vec<IndexedEnvelope<T>> = polygons
+            .iter()
+            .enumerate()
+            .filter_map(|(index, polygon)| {
+                polygon.bounding_rect().map(|rect| IndexedEnvelope {
+                    envelope: AABB::from_corners(rect.min().into(), rect.max().into()),
+                    index,
+                })
+            })
+            .collect();
+        let tree = RTree::bulk_load(envelopes.clone());
+
+        let mut neighbours = vec![vec![]; polygons.len()];
+        for envelope in &envelopes {
+            let a = envelope.index;
+            let mut candidates: Vec<usize> = tree
+                .locate_in_envelope_intersecting(&envelope.envelope)
+                .map(|other| other.index)
+                .filter(|b| *b > a)
+                .collect();
+            candidates.sort_unstable();
+            for b in candidates {
+                let matrix = polygons[a].relate(&polygons[b]);
+                let adjacency =
+                    if matrix.get(CoordPos::Inside, CoordPos::Inside) != Dimensions::Empty {
+                        Adjacency::Overlap
+                    } else {
+                        match matrix.get(CoordPos::OnBoundary, CoordPos::OnBoundary) {
+                            Dimensions::OneDimensional => Adjacency::Edge,
+                            Dimensions::ZeroDimensional => Adjacency::Point,
+                            _ => continue,
+                        }
+                    };
+                neighbours[a].push((b, adjacency));
+                neighbours[b].push((a, adjacency));
+            }
+        }
+        for polygon_neighbours in &mut neighbours {
+            polygon_neighbours.sort_unstable_by_key(|(index, _)| *index);
+        }
+        AdjacencyGraph { neighbours }
+    }
+
+    /// The neighbours of the polygon at `index`, in order of their indices.
+    pub fn neighbours(&self, index: usize) -> &[(usize, Adjacency)] {
+        &self.neighbours[index]
+    }
+
+    /// Each pair of neighbours, once, with the lower index first.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, Adjacency)> + '_ {
+        self.neighbours
+            .iter()
+            .enumerate()
+            .flat_map(|(a, neighbours)| {
+                neighbours
+                    .iter()
+                    .filter(move |(b, _)| *b > a)
+                    .map(move |(b, adjacency)| (a, *b, *adjacency))
+            })
+    }
+}
+
+// The bounding rectangle of a polygon, as stored in the R*-tree.
+#[derive(Clone)]
+struct IndexedEnvelope<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    envelope: AABB<Point<T>>,
+    index: usize,
+}
+
+impl<T> RTreeObject for IndexedEnvelope<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MultiPolygon, Polygon, Rect};
+
+    #[test]
+    fn grid() {
+        // a 3 by 3 grid of unit squares, in rows
+        let squares: Vec<Polygon<f64>> = (0..9)
+            .map(|i| {
+                let (x, y) = ((i % 3) as f64, (i / 3) as f64);
+                Rect::new((x, y), (x + 1., y + 1.)).to_polygon_ccw()
+            })
+            .collect();
+        let graph = AdjacencyGraph::new(&squares);
+        // the centre square shares an edge with four squares, and a corner with four more
+        assert_eq!(
+            graph.neighbours(4),
+            &[
+                (0, Adjacency::Point),
+                (1, Adjacency::Edge),
+                (2, Adjacency::Point),
+                (3, Adjacency::Edge),
+                (5, Adjacency::Edge),
+                (6, Adjacency::Point),
+                (7, Adjacency::Edge),
+                (8, Adjacency::Point),
+            ]
+        );
+        assert_eq!(graph.edges().count(), 12 + 8);
+        assert_eq!(
+            graph
+                .edges()
+                .filter(|(_, _, adjacency)| *adjacency == Adjacency::Edge)
+                .count(),
+            12
+        );
+    }
+
+    #[test]
+    fn partial_edges_and_overlaps() {
+        let polygons = vec![
+            Rect::new((0., 0.), (2., 2.)).to_polygon_ccw(),
+            // sharing half of an edge
+            Rect::new((2., 1.), (4., 3.)).to_polygon_ccw(),
+            // touching the first at a corner
+            Rect::new((2., -1.), (3., 0.)).to_polygon_ccw(),
+            // near, but not touching
+            Rect::new((4.5, 1.), (5.5, 2.)).to_polygon_ccw(),
+        ];
+        let graph = AdjacencyGraph::new(&polygons);
+        assert_eq!(
+            graph.edges().collect::<Vec<_>>(),
+            vec![(0, 1, Adjacency::Edge), (0, 2, Adjacency::Point)]
+        );
+
+        let polygons = vec![
+            Rect::new((0., 0.), (2., 2.)).to_polygon_ccw(),
+            Rect::new((1., 1.), (3., 3.)).to_polygon_ccw(),
+        ];
+        assert_eq!(
+            AdjacencyGraph::new(&polygons).edges().collect::<Vec<_>>(),
+            vec![(0, 1, Adjacency::Overlap)]
+        );
+    }
+
+    #[test]
+    fn multi_polygons() {
+        // islands, each touching the mainland along a different edge
+        let regions = vec![
+            MultiPolygon(vec![
+                Rect::new((0., 0.), (1., 1.)).to_polygon_ccw(),
+                Rect::new((5., 0.), (6., 1.)).to_polygon_ccw(),
+            ]),
+            MultiPolygon(vec![Rect::new((1., 0.), (5., 4.)).to_polygon_ccw()]),
+            MultiPolygon(vec![]),
+        ];
+        let graph = AdjacencyGraph::new(&regions);
+        assert_eq!(graph.neighbours(0), &[(1, Adjacency::Edge)]);
+        assert!(graph.neighbours(2).is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+use crate::algorithm::arrangement::Arrangement;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::delaunay::Triangulation;
+use crate::{Coordinate, GeoFloat, Line, MultiPolygon};
+use std::collections::{HashMap, HashSet};
+
+/// The alpha complex of a set of points, from which their [alpha shapes] at every alpha are
+/// drawn.
+///
+/// The alpha shape of a set of points, at an `alpha` radius, is the union of the triangles of
+/// their Delaunay triangulation whose circumcircles have radii of at most `alpha`. With a
+/// small `alpha`, the shape is empty, or made of scattered fragments. As `alpha` grows, the
+/// fragments join, and the concavities and holes between the points fill in, until the shape is
+/// their convex hull. Unlike the [k-nearest concave hull], the shape depends only on the points
+/// and `alpha`, not on the order of a search.
+///
+/// The shape only changes at the circumradii of the triangles, the critical alphas, so those are
+/// the alphas worth trying. The complex is built once, and shapes drawn from it cheaply.
+///
+/// [alpha shapes]: https://en.wikipedia.org/wiki/Alpha_shape
+/// [k-nearest concave hull]: crate::algorithm::k_nearest_concave_hull::KNearestConcaveHull
+#[derive(Debug, Clone)]
+pub struct AlphaComplex<T: GeoFloat> {
+    triangulation: Triangulation<T>,
+    // the circumradius of each triangle, in the same order
+    radii: Vec<T>,
+    // the indices of the triangles, by increasing circumradius
+    order: Vec<usize>,
+}
+
+impl<T: GeoFloat> AlphaComplex<T> {
+    /// The alpha complex of `coords`. Repeated coordinates count once.
+    pub fn new<I: IntoIterator<Item = Coordinate<T>>>(coords: I) -> Self {
+        let triangulation = Triangulation::delaunay(coords);
+        let radii: Vec<T> = triangulation
+            .triangles
+            .iter()
+            .map(|[a, b, c]| {
+                let points = &triangulation.points;
+                circumradius(points[*a], points[*b], points[*c])
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..radii.len()).collect();
+        order.sort_by(|a, b| radii[*a].partial_cmp(&radii[*b]).unwrap());
+        AlphaComplex {
+            triangulation,
+            radii,
+            order,
+        }
+    }
+
+    /// The alphas at which the shape changes, in increasing order: the distinct circumradii of
+    /// the Delaunay triangles. The shape at each is the same up to the next.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::alpha_shape::AlphaComplex;
+    /// use geo::Coordinate;
+    ///
+    /// // a right triangle, and a point off its hypotenuse
+    /// let coords = vec![(0., 0.), (2., 0.), (0., 2.), (3., 3.)];
+    /// let complex = AlphaComplex::new(coords.into_iter().map(Coordinate::from));
+    /// let alphas = complex.critical_alphas();
+    /// assert_eq!(alphas.len(), 2);
+    /// assert_relative_eq!(alphas[0], 2f64.sqrt());
+    /// ```
+    pub fn critical_alphas(&self) -> Vec<T> {
+        let mut alphas: Vec<T> = self.order.iter().map(|index| self.radii[*index]).collect();
+        alphas.dedup();
+        alphas
+    }
+
+    /// The alpha shape at `alpha`: the union of the triangles with circumradii of at most
+    /// `alpha`, as polygons with holes. Triangles meeting only at a corner are in separate
+    /// polygons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::alpha_shape::AlphaComplex;
+    /// use geo::algorithm::area::Area;
+    /// use geo::Coordinate;
+    ///
+    /// // a 3 by 3 grid of points a unit apart, missing its centre
+    /// let coords = (0..9)
+    ///     .filter(|i| *i != 4)
+    ///     .map(|i| Coordinate { x: (i % 3) as f64, y: (i / 3) as f64 });
+    /// let complex = AlphaComplex::new(coords);
+    ///
+    /// // the triangles across the corners of the grid, meeting only at their corners
+    /// let shape = complex.shape(0.75);
+    /// assert_eq!(shape.0.len(), 4);
+    /// assert_relative_eq!(shape.unsigned_area(), 2.);
+    ///
+    /// // then, at the next critical alpha, the gap in the middle fills in
+    /// let alphas = complex.critical_alphas();
+    /// let shape = complex.shape(alphas[1]);
+    /// assert_eq!(shape.0.len(), 1);
+    /// assert_relative_eq!(shape.unsigned_area(), 4.);
+    /// ```
+    pub fn shape(&self, alpha: T) -> MultiPolygon<T> {
+        let count = self
+            .order
+            .iter()
+            .take_while(|index| self.radii[**index] <= alpha)
+            .count();
+        let edges: HashSet<(usize, usize)> = self.order[..count]
+            .iter()
+            .flat_map(|index| {
+                let [a, b, c] = self.triangulation.triangles[*index];
+                vec![(a, b), (b, c), (c, a)]
+            })
+            .collect();
+
+        // the edges with a triangle on their left, but not on their right
+        let points = &self.triangulation.points;
+        let boundary = edges
+            .iter()
+            .filter(|(a, b)| !edges.contains(&(*b, *a)))
+            .map(|(a, b)| (Line::new(points[*a], points[*b]), 0));
+        let arrangement = Arrangement::new(boundary);
+        let polygons = arrangement
+            .faces()
+            .into_iter()
+            .filter(|face| {
+                arrangement
+                    .sides(face.half_edges[0])
+                    .any(|(_, same_way)| same_way)
+            })
+            .map(|face| face.polygon)
+            .collect();
+        MultiPolygon(polygons)
+    }
+
+    /// The least alpha at which the shape is a single polygon, perhaps with holes, covering all
+    /// of the points, or `None` if the points are all in a line.
+    ///
+    /// This is usually the alpha wanted for a concave hull: the tightest shape that holds the
+    /// points together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::alpha_shape::AlphaComplex;
+    /// use geo::Coordinate;
+    ///
+    /// // two unit squares of points, 5 apart
+    /// let coords = vec![
+    ///     (0., 0.), (1., 0.), (1., 1.), (0., 1.),
+    ///     (6., 0.), (7., 0.), (7., 1.), (6., 1.),
+    /// ];
+    /// let complex = AlphaComplex::new(coords.into_iter().map(Coordinate::from));
+    ///
+    /// let alpha = complex.connected_alpha().unwrap();
+    /// assert!(alpha > 2.5);
+    /// assert_eq!(complex.shape(alpha).0.len(), 1);
+    /// assert_eq!(complex.shape(1.).0.len(), 2);
+    /// ```
+    pub fn connected_alpha(&self) -> Option<T> {
+        let triangles = &self.triangulation.triangles;
+        // the triangles added so far, by the edges on their left, and their components
+        let mut lefts = HashMap::new();
+        let mut components: Vec<usize> = (0..triangles.len()).collect();
+        let mut component_count = 0;
+        let mut is_covered = vec![false; self.triangulation.points.len()];
+        let mut covered_count = 0;
+
+        for index in &self.order {
+            let triangle = triangles[*index];
+            component_count += 1;
+            for corner in 0..3 {
+                let (a, b) = (triangle[corner], triangle[(corner + 1) % 3]);
+                if !is_covered[a] {
+                    is_covered[a] = true;
+                    covered_count += 1;
+                }
+                if let Some(neighbour) = lefts.get(&(b, a)) {
+                    let root = find(&mut components, *index);
+                    let other = find(&mut components, *neighbour);
+                    if root != other {
+                        components[other] = root;
+                        component_count -= 1;
+                    }
+                }
+                lefts.insert((a, b), *index);
+            }
+            if component_count == 1 && covered_count == is_covered.len() {
+                return Some(self.radii[*index]);
+            }
+        }
+        None
+    }
+}
+
+/// Find the [alpha shape](AlphaComplex) of the coordinates of a geometry.
+pub trait AlphaShape<T: GeoFloat> {
+    /// The alpha complex of the coordinates of the geometry, from which shapes at every alpha,
+    /// and the alphas at which the shape changes, can be drawn.
+    fn alpha_complex(&self) -> AlphaComplex<T>;
+
+    /// The alpha shape of the coordinates of the geometry at `alpha`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::alpha_shape::AlphaShape;
+    /// use geo::algorithm::area::Area;
+    /// use geo::MultiPoint;
+    ///
+    /// // a 3 by 3 grid of points a unit apart, missing a corner
+    /// let points: MultiPoint<f64> = vec![
+    ///     (0., 0.), (1., 0.), (2., 0.),
+    ///     (0., 1.), (1., 1.), (2., 1.),
+    ///     (0., 2.), (1., 2.),
+    /// ]
+    /// .into();
+    /// let shape = points.alpha_shape(1.);
+    /// assert_eq!(shape.0.len(), 1);
+    /// // the missing corner is cut off
+    /// assert_relative_eq!(shape.unsigned_area(), 3.5);
+    /// ```
+    fn alpha_shape(&self, alpha: T) -> MultiPolygon<T> {
+        self.alpha_complex().shape(alpha)
+    }
+}
+
+impl<T, G> AlphaShape<T> for G
+where
+    T: GeoFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn alpha_complex(&self) -> AlphaComplex<T> {
+        AlphaComplex::new(self.coords_iter())
+    }
+}
+
+fn find(components: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while components[root] != root {
+        root = components[root];
+    }
+    // compress the path
+    let mut index = index;
+    while components[index] != root {
+        let next = components[index];
+        components[index] = root;
+        index = next;
+    }
+    root
+}
+
+fn circumradius<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> T {
+    let length = |p: Coordinate<T>, q: Coordinate<T>| (q.x - p.x).hypot(q.y - p.y);
+    let (ab, ac) = (b - a, c - a);
+    let twice_area = (ab.x * ac.y - ab.y * ac.x).abs();
+    let two = T::one() + T::one();
+    length(a, b) * length(b, c) * length(c, a) / (two * twice_area)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{LineString, MultiPoint};
+
+    fn grid(size: usize, skip: impl Fn(usize, usize) -> bool) -> MultiPoint<f64> {
+        (0..size)
+            .flat_map(|x| (0..size).map(move |y| (x, y)))
+            .filter(|(x, y)| !skip(*x, *y))
+            .map(|(x, y)| (x as f64, y as f64))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn holes() {
+        // a 7 by 7 grid, missing the 3 by 3 block in its middle
+        let points = grid(7, |x, y| (2..5).contains(&x) && (2..5).contains(&y));
+        let complex = points.alpha_complex();
+        assert_relative_eq!(complex.critical_alphas()[0], 0.5f64.sqrt());
+
+        let shape = complex.shape(0.75);
+        assert_eq!(shape.0.len(), 1);
+        assert_eq!(shape.0[0].interiors().len(), 1);
+        // the 4 by 4 block of cells around the missing points is empty, but for a triangle in
+        // each of its corners
+        assert_relative_eq!(shape.unsigned_area(), 36. - 16. + 4. * 0.5);
+        assert_relative_eq!(complex.connected_alpha().unwrap(), 0.5f64.sqrt());
+
+        // at the largest alpha, the shape is the convex hull
+        let alphas = complex.critical_alphas();
+        let hull = complex.shape(alphas[alphas.len() - 1]);
+        assert_eq!(hull.0.len(), 1);
+        assert!(hull.0[0].interiors().is_empty());
+        assert_relative_eq!(hull.unsigned_area(), 36.);
+    }
+
+    #[test]
+    fn degenerate_points() {
+        let line: LineString<f64> = vec![(0., 0.), (1., 1.), (2., 2.)].into();
+        let complex = line.alpha_complex();
+        assert!(complex.critical_alphas().is_empty());
+        assert!(complex.shape(10.).0.is_empty());
+        assert!(complex.connected_alpha().is_none());
+
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert!(empty.alpha_shape(1.).0.is_empty());
+
+        // nothing is in the shape below the smallest alpha
+        let points = grid(3, |_, _| false);
+        assert!(points.alpha_shape(0.7).0.is_empty());
+        assert_relative_eq!(points.alpha_shape(0.71).unsigned_area(), 4.);
+    }
+}
@@ -0,0 +1,351 @@
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::{Coordinate, GeoFloat, LineString, Polygon, Rect, Triangle};
+use alloc::vec::Vec;
+
+/// The angle turned through at each vertex of a line, in degrees.
+///
+/// The turn at a vertex is the signed angle from the direction of the segment arriving at it to
+/// the direction of the segment leaving it. Positive angles are left, counter-clockwise, turns,
+/// and negative angles are right, clockwise, turns. A line which doubles back on itself turns
+/// through `180`.
+///
+/// Which way a vertex turns is decided with the orientation [`Kernel`], so nearly straight
+/// vertices are classified exactly, and only the size of the angle is subject to rounding.
+///
+/// [`Kernel`]: crate::algorithm::kernels::Kernel
+pub trait TurnAngles<T: GeoFloat> {
+    /// The turn at each vertex of a `LineString` but its first and last, in order.
+    ///
+    /// Repeated coordinates are skipped when finding the segments on either side of a vertex, so
+    /// each repetition of a vertex gets the same angle. A vertex with no distinct coordinate
+    /// before or after it turns through `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::angles::TurnAngles;
+    /// use geo::{line_string, LineString};
+    ///
+    /// // east, then north, then north-east
+    /// let line: LineString<f64> = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 2., y: 2.),
+    /// ];
+    ///
+    /// let turns = line.turn_angles();
+    /// assert_relative_eq!(turns[0], 90.);
+    /// assert_relative_eq!(turns[1], -45.);
+    /// ```
+    fn turn_angles(&self) -> Vec<T>;
+}
+
+impl<T: GeoFloat> TurnAngles<T> for LineString<T> {
+    fn turn_angles(&self) -> Vec<T> {
+        if self.0.len() < 3 {
+            return Vec::new();
+        }
+
+        let runs = runs(&self.0);
+        let mut angles = Vec::with_capacity(self.0.len() - 2);
+        for (index, &(coord, count)) in runs.iter().enumerate() {
+            let angle = if index == 0 || index == runs.len() - 1 {
+                T::zero()
+            } else {
+                turn_angle(runs[index - 1].0, coord, runs[index + 1].0)
+            };
+            angles.resize(angles.len() + count, angle);
+        }
+
+        // The ends of the line aren't vertices of it
+        angles.pop();
+        angles.remove(0);
+        angles
+    }
+}
+
+/// The interior angle at each vertex of a polygon, in degrees.
+///
+/// The interior angle is measured inside the polygon, between the two edges meeting at a vertex.
+/// It is less than `180` at convex vertices, and more than `180` at reflex ones. The sum of the
+/// interior angles of a simple ring of `n` vertices is `(n - 2) * 180`.
+///
+/// Rings may be wound either way. Which side of a vertex is inside is decided with the
+/// orientation [`Kernel`], so the angles at nearly straight vertices are never on the wrong side
+/// of `180`.
+///
+/// [`Kernel`]: crate::algorithm::kernels::Kernel
+pub trait InteriorAngles<T: GeoFloat> {
+    /// The interior angle at each vertex of the exterior, then at each vertex of each of the
+    /// interiors in turn. The closing coordinate of each ring is left out.
+    ///
+    /// At the vertices of an interior ring, the angle is measured inside the polygon, outside of
+    /// the hole. Repeated coordinates are skipped when finding the edges on either side of a
+    /// vertex. A ring with fewer than three distinct vertices has no interior, and its angles
+    /// are all `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::angles::InteriorAngles;
+    /// use geo::{polygon, Polygon};
+    ///
+    /// // an L-shape
+    /// let polygon: Polygon<f64> = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 2., y: 0.),
+    ///     (x: 2., y: 1.),
+    ///     (x: 1., y: 1.),
+    ///     (x: 1., y: 2.),
+    ///     (x: 0., y: 2.),
+    /// ];
+    ///
+    /// let angles = polygon.interior_angles();
+    /// assert_relative_eq!(angles[0], 90.);
+    /// // the reflex corner
+    /// assert_relative_eq!(angles[3], 270.);
+    /// ```
+    fn interior_angles(&self) -> Vec<T>;
+}
+
+impl<T: GeoFloat> InteriorAngles<T> for Polygon<T> {
+    fn interior_angles(&self) -> Vec<T> {
+        let mut angles = Vec::new();
+        let inside_on_left = self.exterior().winding_order() != Some(WindingOrder::Clockwise);
+        ring_angles(self.exterior(), inside_on_left, &mut angles);
+        for interior in self.interiors() {
+            let inside_on_left = interior.winding_order() == Some(WindingOrder::Clockwise);
+            ring_angles(interior, inside_on_left, &mut angles);
+        }
+        angles
+    }
+}
+
+impl<T: GeoFloat> InteriorAngles<T> for Triangle<T> {
+    fn interior_angles(&self) -> Vec<T> {
+        self.to_polygon().interior_angles()
+    }
+}
+
+impl<T: GeoFloat> InteriorAngles<T> for Rect<T> {
+    fn interior_angles(&self) -> Vec<T> {
+        self.to_polygon().interior_angles()
+    }
+}
+
+// Append the interior angle at each vertex of `ring` to `angles`.
+fn ring_angles<T: GeoFloat>(ring: &LineString<T>, inside_on_left: bool, angles: &mut Vec<T>) {
+    let coords = if ring.0.len() > 1 && ring.is_closed() {
+        &ring.0[..ring.0.len() - 1]
+    } else {
+        &ring.0[..]
+    };
+
+    let mut runs = runs(coords);
+    // A ring may start part way through a run of repeated coordinates
+    if runs.len() > 1 && runs[0].0 == runs[runs.len() - 1].0 {
+        let (_, count) = runs.pop().expect("runs has more than one element");
+        runs[0].1 += count;
+    }
+
+    let straight = T::from(180).unwrap();
+    let mut ring_angles: Vec<T> = Vec::with_capacity(coords.len());
+    for (index, &(coord, count)) in runs.iter().enumerate() {
+        let angle = if runs.len() < 3 {
+            T::zero()
+        } else {
+            let prev = runs[(index + runs.len() - 1) % runs.len()].0;
+            let next = runs[(index + 1) % runs.len()].0;
+            let turn = turn_angle(prev, coord, next);
+            if inside_on_left {
+                straight - turn
+            } else {
+                straight + turn
+            }
+        };
+        ring_angles.resize(ring_angles.len() + count, angle);
+    }
+
+    // Put back the leading repetitions which were merged into the first run
+    let wrapped = coords.iter().rev().take_while(|&&c| c == coords[0]).count();
+    if wrapped > 0 && wrapped < coords.len() {
+        ring_angles.rotate_left(wrapped);
+    }
+    angles.extend(ring_angles);
+}
+
+// Each distinct coordinate of `coords`, with the number of times it's repeated in a row.
+pub(crate) fn runs<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<(Coordinate<T>, usize)> {
+    let mut runs: Vec<(Coordinate<T>, usize)> = Vec::with_capacity(coords.len());
+    for &coord in coords {
+        match runs.last_mut() {
+            Some((last, count)) if *last == coord => *count += 1,
+            _ => runs.push((coord, 1)),
+        }
+    }
+    runs
+}
+
+// The signed angle, in degrees, from the direction of `a` to `b` to that of `b` to `c`.
+pub(crate) fn turn_angle<T: GeoFloat>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> T {
+    let (incoming, outgoing) = (b - a, c - b);
+    match T::Ker::orient2d(a, b, c) {
+        Orientation::Collinear => match T::Ker::dot_product_sign(incoming, outgoing) {
+            Orientation::Clockwise => T::from(180).unwrap(),
+            _ => T::zero(),
+        },
+        orientation => {
+            let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+            let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+            let angle = cross.abs().atan2(dot).to_degrees();
+            if orientation == Orientation::CounterClockwise {
+                angle
+            } else {
+                -angle
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Coordinate};
+
+    #[test]
+    fn turns_along_a_line() {
+        let line: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 2., y: 1.),
+            (x: 2., y: 0.),
+            (x: 3., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        let turns = line.turn_angles();
+        assert_eq!(turns.len(), 4);
+        assert_relative_eq!(turns[0], 45.);
+        assert_relative_eq!(turns[1], -135.);
+        assert_relative_eq!(turns[2], 90.);
+        // doubling back
+        assert_relative_eq!(turns[3], 180.);
+    }
+
+    #[test]
+    fn turns_skip_repeated_coordinates() {
+        let line: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ];
+        assert_eq!(line.turn_angles(), vec![0., 90., 90.]);
+
+        let short: LineString<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert!(short.turn_angles().is_empty());
+    }
+
+    #[test]
+    fn nearly_straight_turns_keep_their_sign() {
+        let line = LineString::from(vec![
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 1e8, y: 1e8 },
+            Coordinate {
+                x: 2e8,
+                y: 2e8 + 1e-6,
+            },
+        ]);
+        let turn = line.turn_angles()[0];
+        assert!(turn > 0.);
+        assert!(turn < 1e-10);
+    }
+
+    #[test]
+    fn interior_angles_either_winding() {
+        let ccw: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 2., y: 1.),
+            (x: 0., y: 2.),
+        ];
+        let angles = ccw.interior_angles();
+        assert_eq!(angles.len(), 5);
+        assert_relative_eq!(angles.iter().sum::<f64>(), 540., epsilon = 1e-9);
+        assert_relative_eq!(angles[0], 90.);
+        assert!(angles[3] > 180.);
+
+        let mut cw = ccw.clone();
+        cw.exterior_mut(|ring| ring.0.reverse());
+        let mut reversed = cw.interior_angles();
+        reversed.reverse();
+        // the reversed ring starts at the same coordinate
+        reversed.rotate_right(1);
+        for (a, b) in angles.iter().zip(&reversed) {
+            assert_relative_eq!(a, b, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn interior_angles_of_holes() {
+        let polygon: Polygon<f64> = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 5., y: 6.)]],
+        );
+        let angles = polygon.interior_angles();
+        assert_eq!(angles.len(), 7);
+        for angle in &angles[..4] {
+            assert_relative_eq!(*angle, 90.);
+        }
+        // inside the polygon, so around the outside of the triangular hole
+        assert_relative_eq!(
+            angles[4..].iter().sum::<f64>(),
+            3. * 360. - 180.,
+            epsilon = 1e-9
+        );
+        assert!(angles[4..].iter().all(|&angle| angle > 180.));
+    }
+
+    #[test]
+    fn interior_angles_with_repeated_coordinates() {
+        let polygon: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(polygon.interior_angles(), vec![90.; 5]);
+
+        let wrapped: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(wrapped.interior_angles(), vec![90.; 5]);
+
+        let degenerate: Polygon<f64> = polygon![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(degenerate.interior_angles(), vec![0.; 2]);
+    }
+
+    #[test]
+    fn triangle_and_rect() {
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        let angles = triangle.interior_angles();
+        assert_relative_eq!(angles[0], 90.);
+        assert_relative_eq!(angles[1], 45.);
+        assert_relative_eq!(angles[2], 45.);
+
+        let rect = Rect::new((0., 0.), (2., 1.));
+        assert_eq!(rect.interior_angles(), vec![90.; 4]);
+    }
+}
@@ -33,8 +33,7 @@ where
 
     let mut tmp = T::zero();
     for line in linestring.lines() {
-        use crate::algorithm::map_coords::MapCoords;
-        let line = line.map_coords(|&(x, y)| (x - shift.x, y - shift.y));
+        let line = Line::new(line.start - shift, line.end - shift);
         tmp = tmp + line.determinant();
     }
 
@@ -67,6 +66,36 @@ where
 /// assert_eq!(polygon.signed_area(), -30.);
 /// assert_eq!(polygon.unsigned_area(), 30.);
 /// ```
+///
+/// # Orientation
+///
+/// The signed area of a `Polygon` or `Triangle` is positive when its exterior is oriented
+/// counter-clockwise, and negative when it's clockwise. Interior rings always reduce the
+/// magnitude of the area, whichever way they're oriented. A `Rect` has no orientation, so its area
+/// is never negative.
+///
+/// Collections, including nested `GeometryCollection`s, sum the areas of their members. The
+/// signed areas of members with opposite orientations partly cancel each other out, so for
+/// collections, `unsigned_area` is the sum of the members' unsigned areas, not the absolute
+/// value of `signed_area`. Points and lines have no area, and contribute nothing.
+///
+/// ```
+/// use geo::{polygon, Geometry, GeometryCollection, Line, Rect, Triangle};
+/// use geo::algorithm::area::Area;
+///
+/// let collection = GeometryCollection(vec![
+///     Rect::new((0., 0.), (2., 2.)).into(),
+///     // clockwise
+///     Triangle::from([(0., 0.), (0., 2.), (2., 0.)]).into(),
+///     Geometry::GeometryCollection(GeometryCollection(vec![
+///         Line::from([(0., 0.), (5., 5.)]).into(),
+///         polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)].into(),
+///     ])),
+/// ]);
+///
+/// assert_eq!(collection.signed_area(), 4. - 2. + 1.);
+/// assert_eq!(collection.unsigned_area(), 4. + 2. + 1.);
+/// ```
 pub trait Area<T>
 where
     T: CoordNum,
@@ -264,7 +293,10 @@ where
 #[cfg(test)]
 mod test {
     use crate::algorithm::area::Area;
-    use crate::{line_string, polygon, Coordinate, Line, MultiPolygon, Polygon, Rect, Triangle};
+    use crate::{
+        line_string, polygon, Coordinate, Geometry, GeometryCollection, Line, MultiPolygon,
+        Polygon, Rect, Triangle,
+    };
 
     // Area of the polygon
     #[test]
@@ -292,7 +324,7 @@ mod test {
     #[test]
     fn area_polygon_numerical_stability() {
         let polygon = {
-            use std::f64::consts::PI;
+            use core::f64::consts::PI;
             const NUM_VERTICES: usize = 10;
             const ANGLE_INC: f64 = 2. * PI / NUM_VERTICES as f64;
 
@@ -412,6 +444,37 @@ mod test {
         assert_relative_eq!(triangle.signed_area(), -0.5);
     }
 
+    #[test]
+    fn area_collections_match_members_test() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 3., y: 2. });
+        let triangle_cw = Triangle(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: 0.0, y: 2.0 },
+            Coordinate { x: 2.0, y: 0.0 },
+        );
+        let members: Vec<Geometry<f64>> = vec![
+            rect.into(),
+            triangle_cw.into(),
+            Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. }).into(),
+        ];
+        let collection = GeometryCollection(members.clone());
+        let nested = GeometryCollection(vec![
+            Geometry::GeometryCollection(collection.clone()),
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)].into(),
+        ]);
+
+        assert_relative_eq!(collection.signed_area(), 6. - 2.);
+        assert_relative_eq!(collection.unsigned_area(), 6. + 2.);
+        assert_relative_eq!(nested.signed_area(), 6. - 2. + 1.);
+        assert_relative_eq!(nested.unsigned_area(), 6. + 2. + 1.);
+
+        for member in &members {
+            let collection = GeometryCollection(vec![member.clone()]);
+            assert_relative_eq!(collection.signed_area(), member.signed_area());
+            assert_relative_eq!(collection.unsigned_area(), member.unsigned_area());
+        }
+    }
+
     #[test]
     fn area_multi_polygon_area_reversed() {
         let polygon_cw: Polygon<f32> = polygon![
@@ -0,0 +1,223 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::linework::segments;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::algorithm::segment::{cross, midpoint};
+use crate::algorithm::sweepline::SweepLine;
+use crate::{GeoFloat, Line, Polygon};
+
+/// Redistribute values from one set of polygons onto another, in proportion to the areas where
+/// they overlap.
+///
+/// Each source polygon carries an extensive value, such as a population count, which is assumed
+/// to be spread evenly over its area. The value of each target polygon is the sum, over the
+/// source polygons, of the value of the source times the fraction of its area which the target
+/// overlaps. So where the targets cover the sources exactly, once, the total is preserved. This
+/// is the areal interpolation used, for example, to estimate the population of school districts
+/// from that of census tracts.
+///
+/// Source polygons of zero area contribute nothing.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area_interpolation::area_interpolate;
+/// use geo::polygon;
+///
+/// // two tracts, each with a population of 100
+/// let sources = vec![
+///     (polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)], 100.),
+///     (polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)], 100.),
+/// ];
+/// // a district covering all of the first tract and half of the second
+/// let targets = vec![polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 2.), (x: 0., y: 2.)]];
+///
+/// assert_eq!(area_interpolate(&sources, &targets), vec![150.]);
+/// ```
+pub fn area_interpolate<T: GeoFloat>(
+    sources: &[(Polygon<T>, T)],
+    targets: &[Polygon<T>],
+) -> Vec<T> {
+    let mut values = vec![T::zero(); targets.len()];
+    for (source, value) in sources {
+        let area = source.unsigned_area();
+        if area == T::zero() {
+            continue;
+        }
+        for (target, target_value) in targets.iter().zip(values.iter_mut()) {
+            let overlap = intersection_area(source, target);
+            if overlap > T::zero() {
+                *target_value = *target_value + *value * overlap / area;
+            }
+        }
+    }
+    values
+}
+
+/// The area of the intersection of two polygons.
+///
+/// The intersection isn't constructed. Instead, the rings of both polygons are split where they
+/// meet, and the area is summed, with the shoelace formula, over the pieces of each boundary
+/// which lie inside the other polygon, which together bound the intersection. Where the
+/// boundaries overlap, the pieces count once if the polygons lie on the same side of them, and
+/// not at all otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area_interpolation::intersection_area;
+/// use geo::polygon;
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// let b = polygon![(x: 2., y: 1.), (x: 6., y: 1.), (x: 6., y: 3.), (x: 2., y: 3.)];
+/// assert_eq!(intersection_area(&a, &b), 4.);
+///
+/// // polygons sharing an edge don't overlap
+/// let c = polygon![(x: 4., y: 0.), (x: 8., y: 0.), (x: 8., y: 4.), (x: 4., y: 4.)];
+/// assert_eq!(intersection_area(&a, &c), 0.);
+/// ```
+pub fn intersection_area<T: GeoFloat>(a: &Polygon<T>, b: &Polygon<T>) -> T {
+    let (a_rect, b_rect) = match (a.bounding_rect(), b.bounding_rect()) {
+        (Some(a_rect), Some(b_rect)) => (a_rect, b_rect),
+        _ => return T::zero(),
+    };
+    if !a_rect.intersects(&b_rect) {
+        return T::zero();
+    }
+
+    // with the interior of each polygon on the left of its rings
+    let a_segments = ring_segments(&a.orient(Direction::Default));
+    let b_segments = ring_segments(&b.orient(Direction::Default));
+    let a_count = a_segments.len();
+    let noded = SweepLine::new(a_segments.into_iter().chain(b_segments)).noded_segments();
+    let (a_pieces, b_pieces): (Vec<_>, Vec<_>) =
+        noded.into_iter().partition(|piece| piece.source < a_count);
+
+    // measure from a corner of the bounds, to limit the loss of precision
+    let origin = a_rect.min();
+    let mut twice_area = T::zero();
+    for piece in &a_pieces {
+        let include = match b.coordinate_position(&midpoint(piece.line)) {
+            CoordPos::Inside => true,
+            CoordPos::OnBoundary => b_pieces.iter().any(|other| other.line == piece.line),
+            CoordPos::Outside => false,
+        };
+        if include {
+            twice_area = twice_area + cross(piece.line.start - origin, piece.line.end - origin);
+        }
+    }
+    for piece in &b_pieces {
+        if a.coordinate_position(&midpoint(piece.line)) == CoordPos::Inside {
+            twice_area = twice_area + cross(piece.line.start - origin, piece.line.end - origin);
+        }
+    }
+    (twice_area / (T::one() + T::one())).max(T::zero())
+}
+
+// The segments of the rings of `polygon`, without any of no length.
+fn ring_segments<T: GeoFloat>(polygon: &Polygon<T>) -> Vec<Line<T>> {
+    let mut segments = segments(polygon);
+    segments.retain(|line| line.start != line.end);
+    segments
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Rect};
+
+    #[test]
+    fn overlapping() {
+        let a = Rect::new((0., 0.), (4., 4.)).to_polygon_ccw();
+        assert_eq!(
+            intersection_area(&a, &Rect::new((2., 2.), (6., 6.)).to_polygon_ccw()),
+            4.
+        );
+        assert_eq!(
+            intersection_area(&Rect::new((2., 2.), (6., 6.)).to_polygon_ccw(), &a),
+            4.
+        );
+        // contained
+        assert_eq!(
+            intersection_area(&a, &Rect::new((1., 1.), (3., 3.)).to_polygon_ccw()),
+            4.
+        );
+        assert_eq!(
+            intersection_area(&Rect::new((1., 1.), (3., 3.)).to_polygon_ccw(), &a),
+            4.
+        );
+        // disjoint
+        assert_eq!(
+            intersection_area(&a, &Rect::new((5., 5.), (7., 7.)).to_polygon_ccw()),
+            0.
+        );
+        // a diamond inscribed in the square, touching its edges at their midpoints
+        let diamond = polygon![(x: 2., y: 0.), (x: 4., y: 2.), (x: 2., y: 4.), (x: 0., y: 2.)];
+        assert_eq!(intersection_area(&a, &diamond), 8.);
+    }
+
+    #[test]
+    fn shared_boundaries() {
+        let a = Rect::new((0., 0.), (4., 4.)).to_polygon_ccw();
+        // the same polygon, wound the other way
+        let reversed = polygon![(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)];
+        assert_eq!(intersection_area(&a, &reversed), 16.);
+        // sharing part of an edge, on the inside and on the outside
+        assert_eq!(
+            intersection_area(&a, &Rect::new((0., 1.), (2., 3.)).to_polygon_ccw()),
+            4.
+        );
+        assert_eq!(
+            intersection_area(&a, &Rect::new((-2., 1.), (0., 3.)).to_polygon_ccw()),
+            0.
+        );
+        // touching at a corner
+        assert_eq!(
+            intersection_area(&a, &Rect::new((4., 4.), (6., 6.)).to_polygon_ccw()),
+            0.
+        );
+    }
+
+    #[test]
+    fn holes() {
+        let holey = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        ];
+        // filling the hole
+        assert_eq!(
+            intersection_area(&holey, &Rect::new((1., 1.), (3., 3.)).to_polygon_ccw()),
+            0.
+        );
+        // half in the hole
+        assert_eq!(
+            intersection_area(&holey, &Rect::new((0., 0.), (2., 2.)).to_polygon_ccw()),
+            3.
+        );
+        assert_eq!(intersection_area(&holey, &holey), 12.);
+    }
+
+    #[test]
+    fn interpolation() {
+        let sources = vec![
+            (Rect::new((0., 0.), (2., 2.)).to_polygon_ccw(), 40.),
+            (Rect::new((2., 0.), (4., 2.)).to_polygon_ccw(), 80.),
+            // a degenerate source
+            (
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)],
+                1000.,
+            ),
+        ];
+        let targets = vec![
+            Rect::new((0., 0.), (1., 1.)).to_polygon_ccw(),
+            polygon![(x: 1., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 1., y: 2.)],
+            Rect::new((0., 1.), (1., 2.)).to_polygon_ccw(),
+            Rect::new((10., 10.), (11., 11.)).to_polygon_ccw(),
+        ];
+        let values = area_interpolate(&sources, &targets);
+        assert_eq!(values, vec![10., 20. + 80., 10., 0.]);
+        assert_eq!(values.iter().sum::<f64>(), 120.);
+    }
+}
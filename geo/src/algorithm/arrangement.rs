@@ -0,0 +1,272 @@
+// The planar arrangement of a set of labeled, directed segments, such as the rings of a layer of
+// polygons: the segments are noded with a `SweepLine`, and the faces of the resulting planar
+// graph traced. Each edge remembers which labels ran along it, and in which direction, so a face
+// can tell which side of each labeled boundary it's on.
+
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::algorithm::sweepline::SweepLine;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, Polygon};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Arrangement<T: GeoFloat> {
+    nodes: Vec<Coordinate<T>>,
+    // for each edge, its start and end nodes
+    edges: Vec<(usize, usize)>,
+    // for each edge, the labels of the segments running along it, and whether each runs from
+    // its start to its end
+    sides: Vec<Vec<(usize, bool)>>,
+    // for each node, its outgoing half-edges, counter-clockwise from the positive x axis
+    outgoing: Vec<Vec<usize>>,
+}
+
+// A bounded face of an `Arrangement`.
+pub(crate) struct Face<T: GeoFloat> {
+    // the face, with its exterior wound counter-clockwise
+    pub polygon: Polygon<T>,
+    // the half-edges around its exterior, with the face on their left
+    pub half_edges: Vec<usize>,
+}
+
+impl<T: GeoFloat> Arrangement<T> {
+    pub fn new<I: IntoIterator<Item = (Line<T>, usize)>>(segments: I) -> Self {
+        let (segments, labels): (Vec<Line<T>>, Vec<usize>) = segments
+            .into_iter()
+            .filter(|(line, _)| line.start != line.end)
+            .unzip();
+        let pieces: Vec<_> = SweepLine::new(segments)
+            .noded_segments()
+            .into_iter()
+            .filter(|piece| piece.line.start != piece.line.end)
+            .collect();
+
+        let mut nodes: Vec<Coordinate<T>> = pieces
+            .iter()
+            .flat_map(|piece| vec![piece.line.start, piece.line.end])
+            .collect();
+        nodes.sort_by(lex_cmp);
+        nodes.dedup();
+        let node_index = |coord: Coordinate<T>| {
+            nodes
+                .binary_search_by(|node| lex_cmp(node, &coord))
+                .expect("every end of a piece is a node")
+        };
+
+        let mut edges = vec![];
+        let mut sides: Vec<Vec<(usize, bool)>> = vec![];
+        let mut edge_index: HashMap<(usize, usize), usize> = HashMap::new();
+        for piece in pieces {
+            let start = node_index(piece.line.start);
+            let end = node_index(piece.line.end);
+            let key = (start.min(end), start.max(end));
+            let edge = *edge_index.entry(key).or_insert_with(|| {
+                edges.push((start, end));
+                sides.push(vec![]);
+                edges.len() - 1
+            });
+            sides[edge].push((labels[piece.source], edges[edge].0 == start));
+        }
+
+        let mut outgoing = vec![vec![]; nodes.len()];
+        for (edge, (start, end)) in edges.iter().enumerate() {
+            outgoing[*start].push(2 * edge);
+            outgoing[*end].push(2 * edge + 1);
+        }
+        let mut arrangement = Arrangement {
+            nodes,
+            edges,
+            sides,
+            outgoing: vec![],
+        };
+        for half_edges in &mut outgoing {
+            half_edges.sort_by(|a, b| arrangement.compare_angles(*a, *b));
+        }
+        arrangement.outgoing = outgoing;
+        arrangement
+    }
+
+    // The nodes, in lexicographic order.
+    pub fn nodes(&self) -> &[Coordinate<T>] {
+        &self.nodes
+    }
+
+    // The half-edges leaving a node, counter-clockwise from the positive x axis.
+    pub fn outgoing(&self, node: usize) -> &[usize] {
+        &self.outgoing[node]
+    }
+
+    pub fn half_edge_count(&self) -> usize {
+        2 * self.edges.len()
+    }
+
+    // The labels of the segments along a half-edge, and whether each runs the same way.
+    pub fn sides(&self, half_edge: usize) -> impl Iterator<Item = (usize, bool)> + '_ {
+        let forward = half_edge & 1 == 0;
+        self.sides[half_edge / 2]
+            .iter()
+            .map(move |(label, is_forward)| (*label, *is_forward == forward))
+    }
+
+    pub fn line(&self, half_edge: usize) -> Line<T> {
+        Line::new(
+            self.nodes[self.origin(half_edge)],
+            self.nodes[self.origin(half_edge ^ 1)],
+        )
+    }
+
+    // The bounded faces, each with its holes.
+    pub fn faces(&self) -> Vec<Face<T>> {
+        let mut visited = vec![false; self.half_edge_count()];
+        let mut shells = vec![];
+        let mut holes = vec![];
+        for first in 0..visited.len() {
+            if visited[first] {
+                continue;
+            }
+            let mut half_edges = vec![];
+            let mut half_edge = first;
+            while !visited[half_edge] {
+                visited[half_edge] = true;
+                half_edges.push(half_edge);
+                half_edge = self.next(half_edge);
+            }
+            let mut coords: Vec<Coordinate<T>> = half_edges
+                .iter()
+                .map(|half_edge| self.nodes[self.origin(*half_edge)])
+                .collect();
+            coords.push(coords[0]);
+            let ring = LineString(coords);
+            if twice_signed_ring_area(&ring) > T::zero() {
+                shells.push((ring, half_edges));
+            } else {
+                holes.push(ring);
+            }
+        }
+
+        // each hole is the outside of a connected component, and belongs to the smallest face
+        // of another component enclosing it
+        let areas: Vec<T> = shells
+            .iter()
+            .map(|(ring, _)| twice_signed_ring_area(ring))
+            .collect();
+        let mut shell_holes = vec![vec![]; shells.len()];
+        for hole in holes {
+            let coord = hole.0[0];
+            let enclosing = shells
+                .iter()
+                .enumerate()
+                .filter(|(_, (ring, _))| is_inside(ring, coord))
+                .min_by(|a, b| areas[a.0].partial_cmp(&areas[b.0]).unwrap());
+            if let Some((index, _)) = enclosing {
+                shell_holes[index].push(hole);
+            }
+        }
+        shells
+            .into_iter()
+            .zip(shell_holes)
+            .map(|((exterior, half_edges), interiors)| Face {
+                polygon: Polygon::new(exterior, interiors),
+                half_edges,
+            })
+            .collect()
+    }
+
+    pub fn origin(&self, half_edge: usize) -> usize {
+        let (start, end) = self.edges[half_edge / 2];
+        if half_edge & 1 == 0 {
+            start
+        } else {
+            end
+        }
+    }
+
+    // The half-edge after `half_edge` around the face on its left: the one leaving its end
+    // which is next clockwise from its twin.
+    pub fn next(&self, half_edge: usize) -> usize {
+        let twin = half_edge ^ 1;
+        let outgoing = &self.outgoing[self.origin(twin)];
+        let position = outgoing
+            .iter()
+            .position(|other| *other == twin)
+            .expect("a half-edge leaves its origin");
+        outgoing[(position + outgoing.len() - 1) % outgoing.len()]
+    }
+
+    // Compare the directions of two half-edges leaving the same node, exactly, counter-clockwise
+    // from the positive x axis.
+    fn compare_angles(&self, a: usize, b: usize) -> Ordering {
+        let origin = self.nodes[self.origin(a)];
+        let a_end = self.nodes[self.origin(a ^ 1)];
+        let b_end = self.nodes[self.origin(b ^ 1)];
+        let is_lower =
+            |end: Coordinate<T>| end.y < origin.y || (end.y == origin.y && end.x < origin.x);
+        is_lower(a_end).cmp(&is_lower(b_end)).then_with(|| {
+            match T::Ker::orient2d(origin, a_end, b_end) {
+                Orientation::CounterClockwise => Ordering::Less,
+                Orientation::Clockwise => Ordering::Greater,
+                Orientation::Collinear => Ordering::Equal,
+            }
+        })
+    }
+}
+
+// Whether `coord` is strictly inside `ring`, by the crossing number.
+fn is_inside<T: GeoFloat>(ring: &LineString<T>, coord: Coordinate<T>) -> bool {
+    Polygon::new(ring.clone(), vec![]).coordinate_position(&coord) == CoordPos::Inside
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::polygon;
+
+    fn ring_segments(polygon: &Polygon<f64>, label: usize) -> Vec<(Line<f64>, usize)> {
+        polygon
+            .exterior()
+            .lines()
+            .map(|line| (line, label))
+            .collect()
+    }
+
+    #[test]
+    fn faces() {
+        // two overlapping squares, and a separate square inside one of them
+        let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let b = polygon![(x: 2., y: 2.), (x: 6., y: 2.), (x: 6., y: 6.), (x: 2., y: 6.)];
+        let c = polygon![(x: 0.5, y: 0.5), (x: 1., y: 0.5), (x: 1., y: 1.), (x: 0.5, y: 1.)];
+        let segments = ring_segments(&a, 0)
+            .into_iter()
+            .chain(ring_segments(&b, 1))
+            .chain(ring_segments(&c, 2));
+        let arrangement = Arrangement::new(segments);
+        let mut areas: Vec<f64> = arrangement
+            .faces()
+            .iter()
+            .map(|face| face.polygon.unsigned_area())
+            .collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        // c, the overlap, a without the overlap and c, and b without the overlap
+        assert_eq!(areas, vec![0.25, 4., 11.75, 12.]);
+
+        // the face around c has it as a hole, and c's edges run along it
+        let faces = arrangement.faces();
+        let outer = faces
+            .iter()
+            .find(|face| face.polygon.unsigned_area() == 11.75)
+            .unwrap();
+        assert_eq!(outer.polygon.interiors().len(), 1);
+        let inner = faces
+            .iter()
+            .find(|face| face.polygon.unsigned_area() == 0.25)
+            .unwrap();
+        assert!(inner
+            .half_edges
+            .iter()
+            .all(|half_edge| arrangement.sides(*half_edge).eq(vec![(2, true)])));
+    }
+}
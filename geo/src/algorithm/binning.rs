@@ -0,0 +1,420 @@
+use crate::{Coordinate, GeoFloat, LineString, Polygon, Rect};
+
+/// Generate a grid of square cells of side `cell_size` covering `bounds`.
+///
+/// The grid starts at `bounds.min()`; the last row and column of cells may extend past
+/// `bounds.max()`. Cells are ordered row by row, starting with the row at `bounds.min().y`
+/// and proceeding from `bounds.min().x`. Each cell's exterior is wound counter-clockwise.
+///
+/// Returns an empty `Vec` if `cell_size` isn't positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Rect};
+/// use geo::algorithm::binning::square_grid;
+///
+/// let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 3.0, y: 2.0 });
+/// let cells = square_grid(bounds, 1.5);
+/// assert_eq!(cells.len(), 4);
+/// ```
+pub fn square_grid<T>(bounds: Rect<T>, cell_size: T) -> Vec<Polygon<T>>
+where
+    T: GeoFloat,
+{
+    match GridLayout::square(bounds, cell_size) {
+        Some(layout) => layout.cells(),
+        None => vec![],
+    }
+}
+
+/// Generate a grid of flat-topped regular hexagons with circumradius `radius` covering
+/// `bounds`.
+///
+/// The first hexagon is centered on `bounds.min()`, and every other column is offset upwards
+/// by half a hexagon. Cells are ordered row by row, starting with the row centered on
+/// `bounds.min().y` and proceeding from `bounds.min().x`. Each cell's exterior is wound
+/// counter-clockwise.
+///
+/// Returns an empty `Vec` if `radius` isn't positive and finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Rect};
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::binning::hex_grid;
+///
+/// let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 10.0, y: 10.0 });
+/// let cells = hex_grid(bounds, 1.0);
+///
+/// let hex_area = 1.5 * 3.0_f64.sqrt();
+/// approx::assert_relative_eq!(cells[0].unsigned_area(), hex_area);
+/// ```
+pub fn hex_grid<T>(bounds: Rect<T>, radius: T) -> Vec<Polygon<T>>
+where
+    T: GeoFloat,
+{
+    match GridLayout::hex(bounds, radius) {
+        Some(layout) => layout.cells(),
+        None => vec![],
+    }
+}
+
+/// Count the points falling in each cell of a [`square_grid`] covering `bounds`.
+///
+/// Returns every cell of the grid (in the same order as [`square_grid`]) along with the number
+/// of points it contains, including cells containing no points. Points on the boundary
+/// between two cells are counted in the cell above or to the right of it, and points outside
+/// `bounds` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Coordinate, Rect};
+/// use geo::algorithm::binning::square_bin_counts;
+///
+/// let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 2.0, y: 1.0 });
+/// let points = vec![
+///     point!(x: 0.5, y: 0.5),
+///     point!(x: 0.2, y: 0.9),
+///     point!(x: 1.5, y: 0.5),
+///     point!(x: 5.0, y: 5.0),
+/// ];
+///
+/// let counts: Vec<usize> = square_bin_counts(points, bounds, 1.0)
+///     .into_iter()
+///     .map(|(_cell, count)| count)
+///     .collect();
+/// assert_eq!(counts, vec![2, 1]);
+/// ```
+pub fn square_bin_counts<T, I, P>(
+    points: I,
+    bounds: Rect<T>,
+    cell_size: T,
+) -> Vec<(Polygon<T>, usize)>
+where
+    T: GeoFloat,
+    I: IntoIterator<Item = P>,
+    P: Into<Coordinate<T>>,
+{
+    match GridLayout::square(bounds, cell_size) {
+        Some(layout) => layout.bin_counts(points),
+        None => vec![],
+    }
+}
+
+/// Count the points falling in each cell of a [`hex_grid`] covering `bounds`.
+///
+/// Returns every cell of the grid (in the same order as [`hex_grid`]) along with the number of
+/// points it contains, including cells containing no points. Each point is counted in the
+/// hexagon whose center is nearest, and points outside `bounds` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Coordinate, Rect};
+/// use geo::algorithm::binning::hex_bin_counts;
+///
+/// let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 4.0, y: 4.0 });
+/// let points = vec![point!(x: 0.1, y: 0.1), point!(x: 0.0, y: 0.2), point!(x: 3.0, y: 3.0)];
+///
+/// let bins = hex_bin_counts(points, bounds, 1.0);
+/// assert_eq!(bins[0].1, 2);
+/// assert_eq!(bins.iter().map(|(_cell, count)| count).sum::<usize>(), 3);
+/// ```
+pub fn hex_bin_counts<T, I, P>(points: I, bounds: Rect<T>, radius: T) -> Vec<(Polygon<T>, usize)>
+where
+    T: GeoFloat,
+    I: IntoIterator<Item = P>,
+    P: Into<Coordinate<T>>,
+{
+    match GridLayout::hex(bounds, radius) {
+        Some(layout) => layout.bin_counts(points),
+        None => vec![],
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Shape {
+    Square,
+    Hex,
+}
+
+/// The placement of the cells of a grid covering a `Rect`.
+#[derive(Clone, Copy, Debug)]
+struct GridLayout<T: GeoFloat> {
+    shape: Shape,
+    bounds: Rect<T>,
+    /// Side length of squares, or circumradius of hexagons.
+    size: T,
+    columns: usize,
+    rows: usize,
+}
+
+impl<T: GeoFloat> GridLayout<T> {
+    fn square(bounds: Rect<T>, size: T) -> Option<Self> {
+        if !(size > T::zero() && size.is_finite()) {
+            return None;
+        }
+        Some(GridLayout {
+            shape: Shape::Square,
+            bounds,
+            size,
+            columns: Self::count(bounds.width(), size)?.max(1),
+            rows: Self::count(bounds.height(), size)?.max(1),
+        })
+    }
+
+    fn hex(bounds: Rect<T>, size: T) -> Option<Self> {
+        if !(size > T::zero() && size.is_finite()) {
+            return None;
+        }
+        let mut layout = GridLayout {
+            shape: Shape::Hex,
+            bounds,
+            size,
+            columns: 0,
+            rows: 0,
+        };
+        // a hexagon centered on each corner of `bounds` is needed to cover it
+        layout.columns = Self::count(bounds.width(), layout.column_spacing())? + 1;
+        layout.rows = Self::count(bounds.height(), layout.row_spacing())? + 1;
+        Some(layout)
+    }
+
+    fn count(extent: T, spacing: T) -> Option<usize> {
+        (extent / spacing).ceil().to_usize()
+    }
+
+    fn column_spacing(&self) -> T {
+        match self.shape {
+            Shape::Square => self.size,
+            Shape::Hex => self.size * T::from(1.5).unwrap(),
+        }
+    }
+
+    fn row_spacing(&self) -> T {
+        match self.shape {
+            Shape::Square => self.size,
+            Shape::Hex => self.size * T::from(3).unwrap().sqrt(),
+        }
+    }
+
+    /// The origin of a square cell, or the center of a hexagonal cell.
+    fn anchor(&self, row: usize, column: usize) -> Coordinate<T> {
+        let min = self.bounds.min();
+        let mut y = min.y + self.row_spacing() * T::from(row).unwrap();
+        if let Shape::Hex = self.shape {
+            if column % 2 == 1 {
+                y = y + self.row_spacing() / (T::one() + T::one());
+            }
+        }
+        Coordinate {
+            x: min.x + self.column_spacing() * T::from(column).unwrap(),
+            y,
+        }
+    }
+
+    fn cell(&self, row: usize, column: usize) -> Polygon<T> {
+        let anchor = self.anchor(row, column);
+        let offsets: Vec<(T, T)> = match self.shape {
+            Shape::Square => vec![
+                (T::zero(), T::zero()),
+                (self.size, T::zero()),
+                (self.size, self.size),
+                (T::zero(), self.size),
+            ],
+            Shape::Hex => {
+                let half = self.size / (T::one() + T::one());
+                let apothem = self.row_spacing() / (T::one() + T::one());
+                vec![
+                    (self.size, T::zero()),
+                    (half, apothem),
+                    (-half, apothem),
+                    (-self.size, T::zero()),
+                    (-half, -apothem),
+                    (half, -apothem),
+                ]
+            }
+        };
+        let mut ring: Vec<Coordinate<T>> = offsets
+            .into_iter()
+            .map(|(dx, dy)| Coordinate {
+                x: anchor.x + dx,
+                y: anchor.y + dy,
+            })
+            .collect();
+        ring.push(ring[0]);
+        Polygon::new(LineString(ring), vec![])
+    }
+
+    fn cells(&self) -> Vec<Polygon<T>> {
+        (0..self.rows)
+            .flat_map(|row| (0..self.columns).map(move |column| (row, column)))
+            .map(|(row, column)| self.cell(row, column))
+            .collect()
+    }
+
+    /// The index (in the order of `cells`) of the cell containing `coord`.
+    fn cell_index(&self, coord: Coordinate<T>) -> Option<usize> {
+        let min = self.bounds.min();
+        let max = self.bounds.max();
+        if !(coord.x >= min.x && coord.x <= max.x && coord.y >= min.y && coord.y <= max.y) {
+            return None;
+        }
+        let (row, column) = match self.shape {
+            Shape::Square => {
+                let column = ((coord.x - min.x) / self.size).floor().to_usize()?;
+                let row = ((coord.y - min.y) / self.size).floor().to_usize()?;
+                // points on the far edge of `bounds` belong to the last cell
+                (row.min(self.rows - 1), column.min(self.columns - 1))
+            }
+            Shape::Hex => {
+                // The hexagons are the Voronoi cells of their centers, so the nearest center
+                // is among the nearest few columns and rows.
+                let column_estimate = ((coord.x - min.x) / self.column_spacing()).round();
+                let row_estimate = ((coord.y - min.y) / self.row_spacing()).round();
+                let mut nearest: Option<((usize, usize), T)> = None;
+                for column in candidates(column_estimate, self.columns) {
+                    for row in candidates(row_estimate, self.rows) {
+                        let center = self.anchor(row, column);
+                        let distance_2 =
+                            (center.x - coord.x).powi(2) + (center.y - coord.y).powi(2);
+                        match nearest {
+                            Some((_, best)) if best <= distance_2 => {}
+                            _ => nearest = Some(((row, column), distance_2)),
+                        }
+                    }
+                }
+                nearest?.0
+            }
+        };
+        Some(row * self.columns + column)
+    }
+
+    fn bin_counts<I, P>(&self, points: I) -> Vec<(Polygon<T>, usize)>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Coordinate<T>>,
+    {
+        let mut counts = vec![0; self.rows * self.columns];
+        for point in points {
+            if let Some(idx) = self.cell_index(point.into()) {
+                counts[idx] += 1;
+            }
+        }
+        self.cells().into_iter().zip(counts).collect()
+    }
+}
+
+/// The in-range indices adjacent to a (rounded) index estimate.
+fn candidates<T: GeoFloat>(estimate: T, count: usize) -> impl Iterator<Item = usize> {
+    let estimate = estimate.to_isize().unwrap_or(0);
+    (estimate - 1..=estimate + 1)
+        .filter(move |idx| *idx >= 0 && (*idx as usize) < count)
+        .map(|idx| idx as usize)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::winding_order::Winding;
+    use crate::{point, polygon, Point};
+
+    fn bounds(width: f64, height: f64) -> Rect<f64> {
+        Rect::new(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate {
+                x: width,
+                y: height,
+            },
+        )
+    }
+
+    #[test]
+    fn invalid_size_test() {
+        assert!(square_grid(bounds(1.0, 1.0), 0.0).is_empty());
+        assert!(square_grid(bounds(1.0, 1.0), -1.0).is_empty());
+        assert!(hex_grid(bounds(1.0, 1.0), f64::NAN).is_empty());
+        assert!(hex_bin_counts(vec![point!(x: 0.5, y: 0.5)], bounds(1.0, 1.0), 0.0).is_empty());
+    }
+
+    #[test]
+    fn square_grid_test() {
+        let cells = square_grid(bounds(2.0, 1.0), 1.0);
+        assert_eq!(
+            cells,
+            vec![
+                polygon![(x: 0.0, y: 0.0), (x: 1.0, y: 0.0), (x: 1.0, y: 1.0), (x: 0.0, y: 1.0)],
+                polygon![(x: 1.0, y: 0.0), (x: 2.0, y: 0.0), (x: 2.0, y: 1.0), (x: 1.0, y: 1.0)],
+            ]
+        );
+        assert!(cells.iter().all(|cell| cell.exterior().is_ccw()));
+
+        // partial cells are rounded up
+        assert_eq!(square_grid(bounds(2.5, 1.0), 1.0).len(), 3);
+        // a degenerate rect is covered by a single cell
+        assert_eq!(square_grid(bounds(0.0, 0.0), 1.0).len(), 1);
+    }
+
+    #[test]
+    fn hex_grid_test() {
+        let cells = hex_grid(bounds(3.0, 2.0), 1.0);
+        // columns 1.5 apart, rows sqrt(3) apart
+        assert_eq!(cells.len(), 3 * 3);
+        let hex_area = 1.5 * 3.0_f64.sqrt();
+        for cell in &cells {
+            assert!(cell.exterior().is_ccw());
+            assert_relative_eq!(cell.unsigned_area(), hex_area, epsilon = 1e-12);
+        }
+        // the grid covers the bounds
+        for x in 0..=6 {
+            for y in 0..=4 {
+                let p = point!(x: x as f64 * 0.5, y: y as f64 * 0.5);
+                assert!(cells
+                    .iter()
+                    .any(|cell| cell.contains(&p) || cell.exterior().contains(&p)));
+            }
+        }
+    }
+
+    #[test]
+    fn square_bin_counts_test() {
+        let points = vec![
+            point!(x: 0.5, y: 0.5),
+            point!(x: 1.0, y: 0.5),
+            point!(x: 2.0, y: 1.0),
+            point!(x: 1.5, y: 0.5),
+            point!(x: -1.0, y: 0.5),
+        ];
+        let counts: Vec<_> = square_bin_counts(points, bounds(2.0, 1.0), 1.0)
+            .into_iter()
+            .map(|(_, count)| count)
+            .collect();
+        assert_eq!(counts, vec![1, 3]);
+    }
+
+    #[test]
+    fn hex_bin_counts_test() {
+        let points: Vec<Point<f64>> = (0..20)
+            .flat_map(|x| (0..20).map(move |y| point!(x: x as f64 * 0.25, y: y as f64 * 0.25)))
+            .collect();
+        let bins = hex_bin_counts(points.clone(), bounds(4.75, 4.75), 1.0);
+        assert_eq!(
+            bins.iter().map(|(_, count)| count).sum::<usize>(),
+            points.len()
+        );
+        // every point is counted in a hexagon containing it
+        for point in &points {
+            let idx = GridLayout::hex(bounds(4.75, 4.75), 1.0)
+                .unwrap()
+                .cell_index(point.0)
+                .unwrap();
+            let cell = &bins[idx].0;
+            assert!(cell.contains(point) || cell.exterior().contains(point));
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     CoordNum, Coordinate, Geometry, GeometryCollection, GeometryCow, Line, LineString,
     MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use core::iter::FromIterator;
 use geo_types::private_utils::{get_bounding_rect, line_string_bounding_rect};
 
 /// Calculation of the bounding rectangle of a geometry.
@@ -33,6 +34,19 @@ pub trait BoundingRect<T: CoordNum> {
     fn bounding_rect(&self) -> Self::Output;
 }
 
+impl<T> BoundingRect<T> for Coordinate<T>
+where
+    T: CoordNum,
+{
+    type Output = Rect<T>;
+
+    /// Return the bounding rectangle for a `Coordinate`. It will have zero
+    /// width and zero height.
+    fn bounding_rect(&self) -> Self::Output {
+        Rect::new(*self, *self)
+    }
+}
+
 impl<T> BoundingRect<T> for Point<T>
 where
     T: CoordNum,
@@ -189,6 +203,94 @@ where
     }
 }
 
+/// A bounding rectangle grown incrementally, one geometry or coordinate at a time, so that the
+/// bounds of a stream can be found without first collecting it.
+///
+/// Anything with a [`BoundingRect`] can be added, including `Coordinate`s, by
+/// [`extend_with`](Self::extend_with), or from an iterator, by [`Extend`] or [`FromIterator`].
+/// Empty geometries leave the rectangle as it was.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::bounding_rect::BoundingRectAccumulator;
+/// use geo::{line_string, point, Coordinate, Rect};
+///
+/// let mut accumulator = BoundingRectAccumulator::new();
+/// assert_eq!(accumulator.rect(), None);
+///
+/// accumulator.extend_with(&point!(x: 1., y: 2.));
+/// accumulator.extend_with(&line_string![(x: -1., y: 0.), (x: 0., y: 5.)]);
+/// assert_eq!(accumulator.rect(), Some(Rect::new((-1., 0.), (1., 5.))));
+///
+/// // the bounds of a stream of coordinates
+/// let stream = (0..100).map(|i| Coordinate { x: i as f64, y: (i % 7) as f64 });
+/// let accumulator: BoundingRectAccumulator<f64> = stream.collect();
+/// assert_eq!(accumulator.rect(), Some(Rect::new((0., 0.), (99., 6.))));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingRectAccumulator<T: CoordNum> {
+    rect: Option<Rect<T>>,
+}
+
+impl<T: CoordNum> BoundingRectAccumulator<T> {
+    pub fn new() -> Self {
+        BoundingRectAccumulator { rect: None }
+    }
+
+    /// Grow the rectangle to cover `geometry`.
+    pub fn extend_with<G>(&mut self, geometry: &G)
+    where
+        G: BoundingRect<T>,
+        G::Output: Into<Option<Rect<T>>>,
+    {
+        if let Some(rect) = geometry.bounding_rect().into() {
+            self.rect = Some(match self.rect {
+                Some(accumulated) => bounding_rect_merge(accumulated, rect),
+                None => rect,
+            });
+        }
+    }
+
+    /// The rectangle covering everything added so far, if anything with any coordinates has
+    /// been.
+    pub fn rect(&self) -> Option<Rect<T>> {
+        self.rect
+    }
+}
+
+impl<T: CoordNum> Default for BoundingRectAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, G> Extend<G> for BoundingRectAccumulator<T>
+where
+    T: CoordNum,
+    G: BoundingRect<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    fn extend<I: IntoIterator<Item = G>>(&mut self, geometries: I) {
+        for geometry in geometries {
+            self.extend_with(&geometry);
+        }
+    }
+}
+
+impl<T, G> FromIterator<G> for BoundingRectAccumulator<T>
+where
+    T: CoordNum,
+    G: BoundingRect<T>,
+    G::Output: Into<Option<Rect<T>>>,
+{
+    fn from_iter<I: IntoIterator<Item = G>>(geometries: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(geometries);
+        accumulator
+    }
+}
+
 // Return a new rectangle that encompasses the provided rectangles
 fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
     Rect::new(
@@ -206,7 +308,7 @@ fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
 #[cfg(test)]
 mod test {
     use super::bounding_rect_merge;
-    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::algorithm::bounding_rect::{BoundingRect, BoundingRectAccumulator};
     use crate::line_string;
     use crate::{
         polygon, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
@@ -338,4 +440,23 @@ mod test {
             .bounding_rect(),
         );
     }
+
+    #[test]
+    fn accumulator_matches_collection_test() {
+        let geometries = vec![
+            Geometry::LineString(LineString::<f64>(vec![])),
+            Geometry::Point(Point(Coordinate { x: 3., y: -1. })),
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 4.)]),
+            Geometry::MultiPoint(MultiPoint(vec![])),
+        ];
+        let accumulator: BoundingRectAccumulator<f64> = geometries.iter().cloned().collect();
+        assert_eq!(
+            accumulator.rect(),
+            GeometryCollection(geometries).bounding_rect()
+        );
+        assert_eq!(accumulator.rect(), Some(Rect::new((0., -1.), (3., 4.))));
+
+        let empty: BoundingRectAccumulator<f64> = vec![LineString(vec![])].into_iter().collect();
+        assert_eq!(empty.rect(), None);
+    }
 }
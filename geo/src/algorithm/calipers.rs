@@ -0,0 +1,270 @@
+use crate::algorithm::convex_hull::ConvexHull;
+use crate::algorithm::segment::{cross, dot};
+use crate::utils::least_and_greatest_index;
+use crate::{Coordinate, GeoFloat, Line};
+use alloc::vec::Vec;
+
+/// A distance across a geometry, and the pair of points which span it.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaliperSpan<T: GeoFloat> {
+    /// The length of `line`
+    pub length: T,
+    /// The pair of points defining the span
+    pub line: Line<T>,
+}
+
+/// Measure the diameter and width of a geometry, by rotating a pair of parallel calipers around
+/// its convex hull.
+///
+/// Both are `None` for empty geometries, and `0` for geometries of a single point. The width of a
+/// geometry whose points are all collinear is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::calipers::Calipers;
+/// use geo::{polygon, Polygon};
+///
+/// // a 4 × 3 footprint
+/// let footprint: Polygon<f64> =
+///     polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.), (x: 0., y: 3.)];
+///
+/// let diameter = footprint.diameter().unwrap();
+/// assert_relative_eq!(diameter.length, 5.);
+///
+/// let width = footprint.width().unwrap();
+/// assert_relative_eq!(width.length, 3.);
+/// assert_relative_eq!(width.line.start.y.max(width.line.end.y), 3.);
+/// ```
+pub trait Calipers<T: GeoFloat> {
+    /// The farthest pair of points of the geometry.
+    fn diameter(&self) -> Option<CaliperSpan<T>>;
+
+    /// The narrowest extent of the geometry, across the direction in which it's thinnest.
+    ///
+    /// `line` runs from a point on an edge of the convex hull, perpendicularly across the
+    /// geometry, to the vertex of the hull farthest from that edge.
+    fn width(&self) -> Option<CaliperSpan<T>>;
+}
+
+impl<T, G> Calipers<T> for G
+where
+    T: GeoFloat,
+    G: ConvexHull<Scalar = T>,
+{
+    fn diameter(&self) -> Option<CaliperSpan<T>> {
+        rotate_calipers(&hull_vertices(self)).map(|(diameter, _)| diameter)
+    }
+
+    fn width(&self) -> Option<CaliperSpan<T>> {
+        rotate_calipers(&hull_vertices(self)).map(|(_, width)| width)
+    }
+}
+
+// The distinct vertices of the convex hull of `geometry`, counter-clockwise and without the
+// closing coordinate.
+fn hull_vertices<T, G>(geometry: &G) -> Vec<Coordinate<T>>
+where
+    T: GeoFloat,
+    G: ConvexHull<Scalar = T>,
+{
+    let mut vertices = geometry.convex_hull().exterior().0.clone();
+    vertices.dedup();
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    vertices
+}
+
+fn span<T: GeoFloat>(start: Coordinate<T>, end: Coordinate<T>) -> CaliperSpan<T> {
+    let line = Line::new(start, end);
+    let delta = line.delta();
+    CaliperSpan {
+        length: delta.x.hypot(delta.y),
+        line,
+    }
+}
+
+// The diameter and width of the convex polygon with the counter-clockwise `vertices`.
+//
+// For each edge of the hull, the vertex farthest from it is found by advancing a second caliper
+// around the hull for as long as the distance grows. Each vertex is passed at most twice, so the
+// hull is measured in linear time. The farthest pair of vertices is antipodal, so is among the
+// pairs of an edge's endpoints and its farthest vertex, and the narrowest strip enclosing a
+// convex polygon has one side along an edge.
+fn rotate_calipers<T: GeoFloat>(
+    vertices: &[Coordinate<T>],
+) -> Option<(CaliperSpan<T>, CaliperSpan<T>)> {
+    let n = vertices.len();
+    match n {
+        0 => return None,
+        1 => {
+            let point = span(vertices[0], vertices[0]);
+            return Some((point, point));
+        }
+        _ => {}
+    }
+
+    let mut diameter = span(vertices[0], vertices[1]);
+    let mut width: Option<CaliperSpan<T>> = None;
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+        let edge = b - a;
+        while cross(edge, vertices[(j + 1) % n] - a) > cross(edge, vertices[j] - a) {
+            j = (j + 1) % n;
+        }
+
+        // The caliper may rest on an edge parallel to `ab`, so both of its ends are candidates.
+        for &far in &[vertices[j], vertices[(j + 1) % n]] {
+            for &near in &[a, b] {
+                let candidate = span(near, far);
+                if candidate.length > diameter.length {
+                    diameter = candidate;
+                }
+            }
+        }
+
+        let edge_length_squared = dot(edge, edge);
+        let far = vertices[j];
+        let distance = cross(edge, far - a) / edge_length_squared.sqrt();
+        let narrower = match width {
+            Some(width) => distance < width.length,
+            None => true,
+        };
+        if narrower {
+            let offset = far - a;
+            let t = dot(offset, edge) / edge_length_squared;
+            width = Some(CaliperSpan {
+                length: distance,
+                line: Line::new(a + edge * t, far),
+            });
+        }
+    }
+
+    let width = width.expect("a hull of two or more vertices has edges");
+    if width.length > T::zero() {
+        return Some((diameter, width));
+    }
+
+    // All of the points are collinear, and the diameter runs between the extremes of the line.
+    let (least, greatest) = least_and_greatest_index(vertices);
+    let diameter = span(vertices[least], vertices[greatest]);
+    let foot = vertices[least];
+    Some((diameter, span(foot, foot)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, MultiPoint, Polygon};
+
+    #[test]
+    fn rectangle() {
+        let rect: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.), (x: 0., y: 3.)];
+
+        let diameter = rect.diameter().unwrap();
+        assert_relative_eq!(diameter.length, 5.);
+        let (start, end) = (diameter.line.start, diameter.line.end);
+        assert_relative_eq!((start.x - end.x).abs(), 4.);
+        assert_relative_eq!((start.y - end.y).abs(), 3.);
+
+        let width = rect.width().unwrap();
+        assert_relative_eq!(width.length, 3.);
+        assert_relative_eq!(width.line.start.x, width.line.end.x);
+        assert_relative_eq!((width.line.start.y - width.line.end.y).abs(), 3.);
+    }
+
+    #[test]
+    fn rotated_rectangle() {
+        // a 4 × 1 rectangle, rotated by 30 degrees
+        let (sin, cos) = (0.5, 3f64.sqrt() / 2.);
+        let rect: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 4. * cos, y: 4. * sin),
+            (x: 4. * cos - sin, y: 4. * sin + cos),
+            (x: -sin, y: cos),
+        ];
+
+        assert_relative_eq!(
+            rect.diameter().unwrap().length,
+            17f64.sqrt(),
+            epsilon = 1e-12
+        );
+
+        let width = rect.width().unwrap();
+        assert_relative_eq!(width.length, 1., epsilon = 1e-12);
+        // the width runs across the long sides
+        let delta = width.line.delta();
+        assert_relative_eq!(delta.x.abs(), sin, epsilon = 1e-12);
+        assert_relative_eq!(delta.y.abs(), cos, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle: Polygon<f64> = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 3., y: 2.)];
+
+        let diameter = triangle.diameter().unwrap();
+        assert_relative_eq!(diameter.length, 10.);
+
+        // the height of the triangle over its longest side
+        let width = triangle.width().unwrap();
+        assert_relative_eq!(width.length, 2.);
+        assert_eq!(width.line, Line::new((3., 0.), (3., 2.)));
+    }
+
+    #[test]
+    fn concave_polygon_is_measured_by_its_hull() {
+        let l_shape: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 4.),
+            (x: 0., y: 4.),
+        ];
+
+        assert_relative_eq!(l_shape.diameter().unwrap().length, 32f64.sqrt());
+        // across the hull's diagonal edge, from (0, 0)
+        assert_relative_eq!(l_shape.width().unwrap().length, 5. / 2f64.sqrt());
+    }
+
+    #[test]
+    fn many_points() {
+        let points: MultiPoint<f64> = (0..100)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 50.;
+                point!(x: 3. * angle.cos(), y: angle.sin())
+            })
+            .collect();
+
+        let diameter = points.diameter().unwrap();
+        assert_relative_eq!(diameter.length, 6., epsilon = 1e-12);
+        assert_relative_eq!(diameter.line.start.y, 0., epsilon = 1e-12);
+        assert_relative_eq!(points.width().unwrap().length, 2., epsilon = 1e-3);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty: MultiPoint<f64> = MultiPoint(vec![]);
+        assert!(empty.diameter().is_none());
+        assert!(empty.width().is_none());
+
+        let point = MultiPoint(vec![point!(x: 1., y: 2.)]);
+        assert_eq!(point.diameter().unwrap().length, 0.);
+        assert_eq!(point.width().unwrap().length, 0.);
+
+        let line = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        let diameter = line.diameter().unwrap();
+        assert_relative_eq!(diameter.length, 18f64.sqrt());
+        assert_eq!(diameter.line, Line::new((0., 0.), (3., 3.)));
+        assert_eq!(line.width().unwrap().length, 0.);
+
+        let flat: Polygon<f64> = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 5., y: 0.)];
+        assert_relative_eq!(flat.diameter().unwrap().length, 5.);
+        assert_eq!(flat.width().unwrap().length, 0.);
+    }
+}
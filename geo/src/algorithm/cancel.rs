@@ -0,0 +1,109 @@
+use crate::GeoError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A request to abandon a long-running algorithm, checked at safe points while it runs, so that
+/// an interactive application can give up on pathological input rather than block a thread
+/// indefinitely.
+///
+/// The cancellable variants of algorithms, such as
+/// [`RelateCancellable::relate_cancellable`](crate::algorithm::relate::RelateCancellable::relate_cancellable) and
+/// [`VariableBuffer::variable_buffer_cancellable`](crate::algorithm::variable_buffer::VariableBuffer::variable_buffer_cancellable),
+/// check [`is_cancelled`](Self::is_cancelled) between their phases and inside their quadratic
+/// loops, and fail with [`GeoError::Cancelled`](crate::GeoError::Cancelled) once it's true.
+///
+/// Only relate, the buffers and the sweep line noding them can be cancelled: there's no public
+/// overlay or unary union in this crate yet. The union the buffers build their outlines with
+/// checks `cancel` as it runs.
+///
+/// Any closure returning a `bool` is a `Cancel`, as are a [`CancelToken`], cancelled from
+/// another thread, and a [`Deadline`].
+pub trait Cancel {
+    /// Whether the algorithm should give up.
+    fn is_cancelled(&self) -> bool;
+}
+
+impl<F: Fn() -> bool> Cancel for F {
+    fn is_cancelled(&self) -> bool {
+        self()
+    }
+}
+
+/// A flag shared between clones, which cancels every algorithm checking any of them once one
+/// is [cancelled](Self::cancel).
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::cancel::{Cancel, CancelToken};
+///
+/// let token = CancelToken::new();
+/// let handle = token.clone();
+/// assert!(!token.is_cancelled());
+/// std::thread::spawn(move || handle.cancel()).join().unwrap();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the algorithms checking this token, or any of its clones.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Cancel for CancelToken {
+    fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A time budget, which cancels an algorithm still running at an instant.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::cancel::{Cancel, Deadline};
+/// use std::time::Duration;
+///
+/// assert!(!Deadline::after(Duration::from_secs(60)).is_cancelled());
+/// assert!(Deadline::after(Duration::from_secs(0)).is_cancelled());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deadline(pub Instant);
+
+impl Deadline {
+    /// The deadline `budget` from now.
+    pub fn after(budget: Duration) -> Self {
+        Deadline(Instant::now() + budget)
+    }
+}
+
+impl Cancel for Deadline {
+    fn is_cancelled(&self) -> bool {
+        Instant::now() >= self.0
+    }
+}
+
+// What the infallible algorithms pass to the cancellable code they share.
+pub(crate) struct NeverCancelled;
+
+impl Cancel for NeverCancelled {
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+// Fail with `GeoError::Cancelled` once `cancel` is.
+pub(crate) fn check(cancel: &dyn Cancel) -> Result<(), GeoError> {
+    if cancel.is_cancelled() {
+        return Err(GeoError::Cancelled);
+    }
+    Ok(())
+}
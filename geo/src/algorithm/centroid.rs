@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 use crate::algorithm::area::{get_linestring_area, Area};
 use crate::algorithm::dimensions::{Dimensions, Dimensions::*, HasDimensions};
@@ -185,6 +185,33 @@ where
     }
 }
 
+/// The centroid of a `GeometryCollection` is the centroid of its highest-dimensional members.
+///
+/// Like in JTS, areas trump lines, which trump points: once a collection has any member with
+/// area, its lines and points are ignored, as they are infinitely small in comparison. Members of
+/// the same dimension are weighted by their area, length or count respectively. Polygons, rects
+/// and triangles which have collapsed to a line or point count as one.
+///
+/// ```
+/// use geo::algorithm::centroid::Centroid;
+/// use geo::{line_string, point, Geometry, GeometryCollection, Rect};
+///
+/// let points = vec![point!(x: 10., y: 10.), point!(x: 20., y: 0.)];
+/// let short_line = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+/// let long_line = line_string![(x: 0., y: 4.), (x: 6., y: 4.)];
+///
+/// let mut collection: GeometryCollection<f64> = points.into_iter().map(Geometry::from).collect();
+/// assert_eq!(collection.centroid(), Some(point!(x: 15., y: 5.)));
+///
+/// // the lines trump the points, and the longer line pulls harder
+/// collection.0.push(short_line.into());
+/// collection.0.push(long_line.into());
+/// assert_eq!(collection.centroid(), Some(point!(x: 2.5, y: 3.)));
+///
+/// // and areas trump both
+/// collection.0.push(Rect::new((0., 0.), (2., 2.)).into());
+/// assert_eq!(collection.centroid(), Some(point!(x: 1., y: 1.)));
+/// ```
 impl<T> Centroid for GeometryCollection<T>
 where
     T: GeoFloat,
@@ -394,8 +421,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         let shift = ring.0[0];
 
         let accumulated_coord = ring.lines().fold(Coordinate::zero(), |accum, line| {
-            use crate::algorithm::map_coords::MapCoords;
-            let line = line.map_coords(|&(x, y)| (x - shift.x, y - shift.y));
+            let line = Line::new(line.start - shift, line.end - shift);
             let tmp = line.determinant();
             accum + (line.end + line.start) * tmp
         });
@@ -584,7 +610,7 @@ mod test {
     #[test]
     fn centroid_polygon_numerical_stability() {
         let polygon = {
-            use std::f64::consts::PI;
+            use core::f64::consts::PI;
             const NUM_VERTICES: usize = 10;
             const ANGLE_INC: f64 = 2. * PI / NUM_VERTICES as f64;
 
@@ -810,6 +836,53 @@ mod test {
         assert_eq!(collection.centroid().unwrap(), point!(x: 1.0, y: 1.0));
     }
     #[test]
+    fn collection_dimension_priority() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+        let square = Rect::new(c(10., 0.), c(12., 2.));
+        let line = Line::new(c(0., 10.), c(4., 10.));
+        let line_string = line_string![(x: 10., y: 10.), (x: 10., y: 12.)];
+        let point = p(100., 100.);
+
+        // areas are weighted by area, whatever the order or dimension of the other members
+        let collection = GeometryCollection(vec![
+            point.into(),
+            line.into(),
+            polygon.clone().into(),
+            line_string.clone().into(),
+            square.into(),
+        ]);
+        let expected = point!(x: (2. * 8. + 11. * 4.) / 12., y: 1.);
+        assert_relative_eq!(collection.centroid().unwrap(), expected);
+
+        // lines are weighted by length
+        let collection = GeometryCollection(vec![
+            point.into(),
+            line_string.clone().into(),
+            MultiPoint(vec![point, point]).into(),
+            line.into(),
+        ]);
+        let expected = point!(x: (2. * 4. + 10. * 2.) / 6., y: (10. * 4. + 11. * 2.) / 6.);
+        assert_relative_eq!(collection.centroid().unwrap(), expected);
+
+        // nested collections contribute to the same weighting
+        let nested = GeometryCollection(vec![
+            Geometry::GeometryCollection(GeometryCollection(vec![point.into(), polygon.into()])),
+            Geometry::GeometryCollection(GeometryCollection(vec![line.into(), square.into()])),
+        ]);
+        let expected = point!(x: (2. * 8. + 11. * 4.) / 12., y: 1.);
+        assert_relative_eq!(nested.centroid().unwrap(), expected);
+
+        // a collapsed polygon counts as a line, so it trumps points but not areas
+        let collapsed = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 0.)];
+        let collection = GeometryCollection(vec![point.into(), collapsed.clone().into()]);
+        assert_relative_eq!(collection.centroid().unwrap(), point!(x: 2., y: 0.));
+        let collection = GeometryCollection(vec![collapsed.into(), square.into()]);
+        assert_relative_eq!(collection.centroid().unwrap(), point!(x: 11., y: 1.));
+
+        let empty: GeometryCollection<f64> = GeometryCollection(vec![]);
+        assert_eq!(empty.centroid(), None);
+    }
+    #[test]
     fn triangles() {
         // boring triangle
         assert_eq!(
@@ -58,11 +58,7 @@ where
     T: Float + CoordNum,
 {
     fn chamberlain_duquette_signed_area(&self) -> T {
-        self.interiors()
-            .iter()
-            .fold(ring_area(self.exterior()), |total, next| {
-                total - ring_area(next)
-            })
+        signed_area_on_sphere(self, T::from(EQUATORIAL_EARTH_RADIUS).unwrap())
     }
 
     fn chamberlain_duquette_unsigned_area(&self) -> T {
@@ -70,7 +66,20 @@ where
     }
 }
 
-fn ring_area<T>(coords: &LineString<T>) -> T
+// The signed area of `polygon` on a sphere of `radius`.
+pub(crate) fn signed_area_on_sphere<T>(polygon: &Polygon<T>, radius: T) -> T
+where
+    T: Float + CoordNum,
+{
+    polygon
+        .interiors()
+        .iter()
+        .fold(ring_area(polygon.exterior(), radius), |total, next| {
+            total - ring_area(next, radius)
+        })
+}
+
+fn ring_area<T>(coords: &LineString<T>, radius: T) -> T
 where
     T: Float + CoordNum,
 {
@@ -95,10 +104,7 @@ where
             total = total + (p3.x.to_radians() - p1.x.to_radians()) * p2.y.to_radians().sin();
         }
 
-        total = total
-            * T::from(EQUATORIAL_EARTH_RADIUS).unwrap()
-            * T::from(EQUATORIAL_EARTH_RADIUS).unwrap()
-            / T::from(-2).unwrap();
+        total = total * radius * radius / T::from(-2).unwrap();
     }
     total
 }
@@ -0,0 +1,201 @@
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::sweepline::SweepLine;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPolygon};
+
+/// The parts of a set of lines within, and outside, a set of polygons, from [`clip_lines`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClippedLines<T: GeoFloat> {
+    /// The parts of the lines within the polygons, or along their boundaries
+    pub inside: MultiLineString<T>,
+    /// The parts of the lines outside the polygons
+    pub outside: MultiLineString<T>,
+}
+
+/// Clip lines to the area covered by polygons, as when clipping roads to an administrative
+/// boundary, without a full overlay of the two.
+///
+/// The lines are noded against the rings of the polygons with a [`SweepLine`], and each piece
+/// between consecutive nodes is classified by the position of its midpoint. Consecutive pieces
+/// of a line on the same side are joined again, so each part runs as far as it can, in the
+/// direction of its line. Parts which run along the boundary of a polygon are inside it, as
+/// with the intersection of closed sets; the outside parts are what's left of the lines.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::clip_lines::clip_lines;
+/// use geo::{line_string, polygon, MultiLineString, MultiPolygon};
+///
+/// let district = MultiPolygon(vec![polygon![
+///     (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.),
+/// ]]);
+/// // a road which enters the district, turns, and leaves again
+/// let roads = MultiLineString(vec![line_string![
+///     (x: -2., y: 1.), (x: 2., y: 1.), (x: 2., y: 6.),
+/// ]]);
+///
+/// let clipped = clip_lines(&roads, &district);
+/// assert_eq!(
+///     clipped.inside,
+///     MultiLineString(vec![line_string![(x: 0., y: 1.), (x: 2., y: 1.), (x: 2., y: 4.)]])
+/// );
+/// assert_eq!(
+///     clipped.outside,
+///     MultiLineString(vec![
+///         line_string![(x: -2., y: 1.), (x: 0., y: 1.)],
+///         line_string![(x: 2., y: 4.), (x: 2., y: 6.)],
+///     ])
+/// );
+/// ```
+pub fn clip_lines<T: GeoFloat>(
+    lines: &MultiLineString<T>,
+    polygons: &MultiPolygon<T>,
+) -> ClippedLines<T> {
+    // the line string of each segment of the lines
+    let line_of_segment: Vec<usize> = lines
+        .0
+        .iter()
+        .enumerate()
+        .flat_map(|(index, line)| line.lines().map(move |_| index))
+        .collect();
+
+    let mut clipped = ClippedLines {
+        inside: MultiLineString(vec![]),
+        outside: MultiLineString(vec![]),
+    };
+    // the part being built, with its line string, whether it's inside, and the segment its last
+    // piece came from
+    let mut part: Option<(usize, bool, usize, Vec<Coordinate<T>>)> = None;
+    for (source, inside, piece) in clipped_pieces(lines, polygons) {
+        let line = line_of_segment[source];
+        match &mut part {
+            Some((part_line, part_inside, part_source, coords))
+                if *part_line == line
+                    && *part_inside == inside
+                    && coords.last() == Some(&piece.start) =>
+            {
+                // drop nodes within a segment, such as where it crosses another line
+                if *part_source == source {
+                    *coords.last_mut().unwrap() = piece.end;
+                } else {
+                    coords.push(piece.end);
+                    *part_source = source;
+                }
+            }
+            _ => {
+                flush(part.take(), &mut clipped);
+                part = Some((line, inside, source, vec![piece.start, piece.end]));
+            }
+        }
+    }
+    flush(part, &mut clipped);
+    clipped
+}
+
+/// The pieces of the segments of `lines` between their nodes with the rings of `polygons`, in
+/// order along the lines, with the index of the segment each came from, counting across all the
+/// lines, and whether it's inside the polygons. Zero-length pieces are left out.
+pub(crate) fn clipped_pieces<T: GeoFloat>(
+    lines: &MultiLineString<T>,
+    polygons: &MultiPolygon<T>,
+) -> Vec<(usize, bool, Line<T>)> {
+    let segment_count: usize = lines.0.iter().map(|line| line.lines().count()).sum();
+    let edges = polygons.0.iter().flat_map(|polygon| {
+        std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .flat_map(|ring| ring.lines())
+    });
+    // the segments of the lines come before the polygons' edges
+    let sweep_line: SweepLine<T> = lines
+        .0
+        .iter()
+        .flat_map(|line| line.lines())
+        .chain(edges)
+        .collect();
+
+    let two = T::one() + T::one();
+    let mut pieces = vec![];
+    for noded in sweep_line.noded_segments() {
+        if noded.source >= segment_count {
+            break;
+        }
+        let piece = noded.line;
+        if piece.start == piece.end {
+            continue;
+        }
+        let midpoint = (piece.start + piece.end) / two;
+        let inside = polygons.coordinate_position(&midpoint) != CoordPos::Outside;
+        pieces.push((noded.source, inside, piece));
+    }
+    pieces
+}
+
+fn flush<T: GeoFloat>(
+    part: Option<(usize, bool, usize, Vec<Coordinate<T>>)>,
+    clipped: &mut ClippedLines<T>,
+) {
+    if let Some((_, inside, _, coords)) = part {
+        let lines = if inside {
+            &mut clipped.inside
+        } else {
+            &mut clipped.outside
+        };
+        lines.0.push(LineString(coords));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn holes_and_boundaries() {
+        let polygons = MultiPolygon(vec![polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        ]]);
+        let lines = MultiLineString(vec![
+            // through the hole
+            line_string![(x: 1., y: 3.), (x: 8., y: 3.)],
+            // along the bottom edge, and beyond it
+            line_string![(x: -1., y: 0.), (x: 3., y: 0.), (x: 3., y: -1.)],
+        ]);
+        let clipped = clip_lines(&lines, &polygons);
+        assert_eq!(
+            clipped.inside,
+            MultiLineString(vec![
+                line_string![(x: 1., y: 3.), (x: 2., y: 3.)],
+                line_string![(x: 4., y: 3.), (x: 6., y: 3.)],
+                line_string![(x: 0., y: 0.), (x: 3., y: 0.)],
+            ])
+        );
+        assert_eq!(
+            clipped.outside,
+            MultiLineString(vec![
+                line_string![(x: 2., y: 3.), (x: 4., y: 3.)],
+                line_string![(x: 6., y: 3.), (x: 8., y: 3.)],
+                line_string![(x: -1., y: 0.), (x: 0., y: 0.)],
+                line_string![(x: 3., y: 0.), (x: 3., y: -1.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn crossing_lines_stay_whole() {
+        // the lines cross each other inside the polygon, which doesn't split them
+        let polygons = MultiPolygon(vec![polygon![
+            (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.),
+        ]]);
+        let lines = MultiLineString(vec![
+            line_string![(x: 1., y: 1.), (x: 3., y: 3.)],
+            line_string![(x: 1., y: 3.), (x: 3., y: 1.)],
+        ]);
+        let clipped = clip_lines(&lines, &polygons);
+        assert_eq!(clipped.inside, lines);
+        assert!(clipped.outside.0.is_empty());
+
+        let none = clip_lines(&lines, &MultiPolygon(vec![]));
+        assert_eq!(none.outside, lines);
+    }
+}
@@ -1,7 +1,12 @@
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::algorithm::linework::{rect_lines, segments};
+use crate::algorithm::segment;
 use crate::prelude::*;
 use crate::{
-    Closest, GeoFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
+    Closest, Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
 use std::iter;
 
 /// Find the closest `Point` between a given geometry and an input `Point`.
@@ -48,37 +53,15 @@ impl<F: GeoFloat> ClosestPoint<F> for Point<F> {
     }
 }
 
-#[allow(clippy::many_single_char_names)]
 impl<F: GeoFloat> ClosestPoint<F> for Line<F> {
     fn closest_point(&self, p: &Point<F>) -> Closest<F> {
-        let line_length = self.euclidean_length();
-        if line_length == F::zero() {
+        if self.start == self.end {
             // if we've got a zero length line, technically the entire line
             // is the closest point...
             return Closest::Indeterminate;
         }
 
-        // For some line AB, there is some point, C, which will be closest to
-        // P. The line AB will be perpendicular to CP.
-        //
-        // Line equation: P = start + t * (end - start)
-
-        let direction_vector = Point(self.end - self.start);
-        let to_p = Point(p.0 - self.start);
-
-        let t = to_p.dot(direction_vector) / direction_vector.dot(direction_vector);
-
-        // check the cases where the closest point is "outside" the line
-        if t < F::zero() {
-            return Closest::SinglePoint(self.start.into());
-        } else if t > F::one() {
-            return Closest::SinglePoint(self.end.into());
-        }
-
-        let x = direction_vector.x();
-        let y = direction_vector.y();
-        let c = Point(self.start + (t * x, t * y).into());
-
+        let c = Point(segment::closest_coord(*self, p.0).coord);
         if self.intersects(p) {
             Closest::Intersection(c)
         } else {
@@ -139,9 +122,275 @@ impl<F: GeoFloat> ClosestPoint<F> for MultiLineString<F> {
     }
 }
 
+impl<F: GeoFloat> ClosestPoint<F> for Rect<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        closest_of(rect_lines(self).iter(), *p)
+    }
+}
+
+impl<F: GeoFloat> ClosestPoint<F> for Triangle<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        closest_of(self.to_lines().iter(), *p)
+    }
+}
+
+impl<F: GeoFloat> ClosestPoint<F> for GeometryCollection<F> {
+    fn closest_point(&self, p: &Point<F>) -> Closest<F> {
+        closest_of(self.iter(), *p)
+    }
+}
+
+impl<F: GeoFloat> ClosestPoint<F> for Geometry<F> {
+    crate::geometry_delegate_impl! {
+        fn closest_point(&self, p: &Point<F>) -> Closest<F>;
+    }
+}
+
+/// Find the closest `Point` of a given geometry to another geometry.
+///
+/// Like [`ClosestPoint`] for a `Point`, areas are treated as their rings, so a geometry inside a
+/// polygon, but not touching its boundary, has a closest point on the boundary. The result is:
+///
+/// - `Intersection` with one of the points where the two geometries intersect, if they do
+/// - `SinglePoint` if exactly one point of `self` is closest to `other`
+/// - `Indeterminate` if several points of `self` are equally close, like when the closest parts
+///   of the two geometries are parallel, or either geometry is empty
+///
+/// For large geometries, the segments of the larger one are put in an R-tree, so that only
+/// nearby pairs of segments are compared.
+///
+/// # Examples
+///
+/// ```rust
+/// # use geo::algorithm::closest_point::ClosestPointTo;
+/// # use geo::{point, polygon, Closest, Line};
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+///
+/// let diamond = polygon![(x: 4., y: 3.), (x: 5., y: 4.), (x: 4., y: 5.), (x: 3., y: 4.)];
+/// assert_eq!(square.closest_point_to(&diamond), Closest::SinglePoint(point!(x: 2., y: 2.)));
+///
+/// // every point of the top of the square is as close to the line
+/// let line = Line::new((0., 3.), (2., 3.));
+/// assert_eq!(square.closest_point_to(&line), Closest::Indeterminate);
+///
+/// let crossing = Line::new((1., 1.), (1., 3.));
+/// assert_eq!(square.closest_point_to(&crossing), Closest::Intersection(point!(x: 1., y: 2.)));
+/// ```
+pub trait ClosestPointTo<F: GeoFloat, Rhs> {
+    /// Find the closest point of `self` to `other`.
+    fn closest_point_to(&self, other: &Rhs) -> Closest<F>;
+}
+
+// The closest point of `self` to another geometry is found among the closest points of each of
+// the segments of `self` to each of the segments of the other. Points become zero-length segments.
+//
+// When there are many pairs of segments, the segments of the larger geometry are put in an
+// R-tree. Each segment of the smaller geometry then only needs comparing with the segments within
+// the closest distance found so far.
+
+/// The number of pairs of segments above which an R-tree is used to find the closest.
+const BRUTE_FORCE_PAIRS: usize = 1024;
+
+macro_rules! impl_closest_point_to_geometry {
+    ([$($from:ident),*], $to:tt) => {
+        $(
+            impl_closest_point_to_geometry!(@impl $from, $to);
+        )*
+    };
+    (@impl $from:ident, [$($to:ident),*]) => {
+        $(
+            impl<F: GeoFloat + RTreeNum> ClosestPointTo<F, $to<F>> for $from<F> {
+                fn closest_point_to(&self, other: &$to<F>) -> Closest<F> {
+                    closest_between(&segments(self), &segments(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_closest_point_to_geometry!(
+    [
+        Point,
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        GeometryCollection,
+        Geometry
+    ],
+    [
+        Point,
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        GeometryCollection,
+        Geometry
+    ]
+);
+
+fn closest_between<F: GeoFloat + RTreeNum>(from: &[Line<F>], to: &[Line<F>]) -> Closest<F> {
+    let mut best = Best::new();
+    if from.len().saturating_mul(to.len()) <= BRUTE_FORCE_PAIRS {
+        for a in from {
+            for b in to {
+                if best.update(*a, *b) {
+                    return best.closest();
+                }
+            }
+        }
+        return best.closest();
+    }
+
+    // Index the larger of the two, keeping track of which side each segment is from.
+    let (indexed, queries, indexed_is_from) = if from.len() >= to.len() {
+        (from, to, true)
+    } else {
+        (to, from, false)
+    };
+    let tree = RTree::bulk_load(indexed.to_vec());
+    let pair = |indexed: Line<F>, query: Line<F>| {
+        if indexed_is_from {
+            (indexed, query)
+        } else {
+            (query, indexed)
+        }
+    };
+
+    // Seed the closest distance, so that the first query is bounded.
+    let seed = queries[0];
+    let nearest = tree
+        .nearest_neighbor(&Point(seed.start))
+        .expect("the tree is not empty");
+    let (from, to) = pair(*nearest, seed);
+    if best.update(from, to) {
+        return best.closest();
+    }
+
+    for query in queries {
+        let reach = best.reach();
+        let envelope = query.envelope();
+        let (min, max) = (envelope.lower(), envelope.upper());
+        let envelope = AABB::from_corners(
+            Point::new(min.x() - reach, min.y() - reach),
+            Point::new(max.x() + reach, max.y() + reach),
+        );
+        for indexed in tree.locate_in_envelope_intersecting(&envelope) {
+            let (from, to) = pair(*indexed, *query);
+            if best.update(from, to) {
+                return best.closest();
+            }
+        }
+    }
+    best.closest()
+}
+
+// The closest pair of segments found so far.
+struct Best<F: GeoFloat> {
+    square_distance: F,
+    /// `None` until a pair has been compared
+    closest: Option<Closest<F>>,
+}
+
+impl<F: GeoFloat> Best<F> {
+    fn new() -> Self {
+        Best {
+            square_distance: F::infinity(),
+            closest: None,
+        }
+    }
+
+    fn closest(&self) -> Closest<F> {
+        self.closest.unwrap_or(Closest::Indeterminate)
+    }
+
+    // How far from a segment another must be within to be as close as the best so far.
+    fn reach(&self) -> F {
+        let distance = self.square_distance.sqrt();
+        // allowing for the rounding of the square root, so ties aren't missed
+        distance + distance * F::epsilon() * (F::one() + F::one())
+    }
+
+    // Compare the closest points of `from` and `to` with the best so far, returning whether they
+    // intersect, so that nothing can be closer.
+    fn update(&mut self, from: Line<F>, to: Line<F>) -> bool {
+        let (square_distance, closest) = closest_between_segments(from, to);
+        if let Closest::Intersection(_) = closest {
+            self.square_distance = F::zero();
+            self.closest = Some(closest);
+            return true;
+        }
+        if square_distance < self.square_distance {
+            self.square_distance = square_distance;
+            self.closest = Some(closest);
+        } else if square_distance == self.square_distance && self.closest != Some(closest) {
+            // equally close, at a different point
+            self.closest = Some(Closest::Indeterminate);
+        }
+        false
+    }
+}
+
+// The squared distance between `from` and `to`, and the closest point of `from` to `to`, which is
+// indeterminate when the two are parallel, so that a whole range of `from` is equally close.
+fn closest_between_segments<F: GeoFloat>(from: Line<F>, to: Line<F>) -> (F, Closest<F>) {
+    if from.intersects(&to) {
+        let intersection = if from.start == from.end {
+            Some(from.start)
+        } else if to.start == to.end {
+            Some(to.start)
+        } else {
+            line_intersection(from, to).map(|intersection| match intersection {
+                LineIntersection::SinglePoint { intersection, .. } => intersection,
+                LineIntersection::Collinear { intersection } => intersection.start,
+            })
+        };
+        if let Some(intersection) = intersection {
+            return (F::zero(), Closest::Intersection(Point(intersection)));
+        }
+    }
+
+    let candidates = [
+        (from.start, segment::coord_square_distance(to, from.start)),
+        (from.end, segment::coord_square_distance(to, from.end)),
+        closest_to_coord(from, to.start),
+        closest_to_coord(from, to.end),
+    ];
+    let square_distance = candidates
+        .iter()
+        .map(|(_, square_distance)| *square_distance)
+        .fold(F::infinity(), F::min);
+    let mut closest = candidates
+        .iter()
+        .filter(|(_, candidate_distance)| *candidate_distance == square_distance)
+        .map(|(coord, _)| *coord);
+    let first = closest.next().expect("one candidate is closest");
+    if closest.all(|coord| coord == first) {
+        (square_distance, Closest::SinglePoint(Point(first)))
+    } else {
+        (square_distance, Closest::Indeterminate)
+    }
+}
+
+// The closest coordinate of `line` to `coord`, and its squared distance.
+fn closest_to_coord<F: GeoFloat>(line: Line<F>, coord: Coordinate<F>) -> (Coordinate<F>, F) {
+    let closest = segment::closest_coord(line, coord).coord;
+    let offset = coord - closest;
+    (closest, offset.x * offset.x + offset.y * offset.y)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::polygon;
 
     /// Create a test which checks that we get `$should_be` when trying to find
     /// the closest distance between `$p` and the line `(0, 0) -> (100, 100)`.
@@ -286,6 +535,160 @@ mod tests {
         assert_eq!(got, should_be);
     }
 
+    #[test]
+    fn closest_points_between_polygons() {
+        let a = Rect::new((0., 0.), (2., 2.)).to_polygon();
+
+        // the nearest corner
+        let b = Rect::new((3., 3.), (4., 4.)).to_polygon();
+        assert_eq!(
+            a.closest_point_to(&b),
+            Closest::SinglePoint(Point::new(2., 2.))
+        );
+        assert_eq!(
+            b.closest_point_to(&a),
+            Closest::SinglePoint(Point::new(3., 3.))
+        );
+
+        // a corner nearest to an edge
+        let b = polygon![(x: 3., y: 1.), (x: 5., y: 0.), (x: 5., y: 2.)];
+        assert_eq!(
+            a.closest_point_to(&b),
+            Closest::SinglePoint(Point::new(2., 1.))
+        );
+        assert_eq!(
+            b.closest_point_to(&a),
+            Closest::SinglePoint(Point::new(3., 1.))
+        );
+
+        // parallel edges
+        let b = Rect::new((3., 0.5), (4., 1.5)).to_polygon();
+        assert_eq!(a.closest_point_to(&b), Closest::Indeterminate);
+
+        // overlapping
+        let b = Rect::new((1., 1.), (3., 3.)).to_polygon();
+        match a.closest_point_to(&b) {
+            Closest::Intersection(p) => {
+                assert!(a.exterior().intersects(&p) && b.exterior().intersects(&p))
+            }
+            closest => panic!("expected an intersection, got {:?}", closest),
+        }
+
+        // nested, so the rings don't touch
+        let b = polygon![(x: 0.5, y: 0.5), (x: 1., y: 0.25), (x: 1.5, y: 0.5), (x: 1., y: 1.)];
+        assert_eq!(
+            a.closest_point_to(&b),
+            Closest::SinglePoint(Point::new(1., 0.))
+        );
+    }
+
+    #[test]
+    fn closest_points_between_other_geometries() {
+        let rect = Rect::new((0., 0.), (2., 2.));
+        let points = MultiPoint(vec![Point::new(3., 1.), Point::new(1., 5.)]);
+        assert_eq!(
+            rect.closest_point_to(&points),
+            Closest::SinglePoint(Point::new(2., 1.))
+        );
+        assert_eq!(
+            points.closest_point_to(&rect),
+            Closest::SinglePoint(Point::new(3., 1.))
+        );
+
+        // equally close at two points
+        let points = MultiPoint(vec![Point::new(3., 1.), Point::new(1., 3.)]);
+        assert_eq!(rect.closest_point_to(&points), Closest::Indeterminate);
+
+        let triangle = Triangle::from([(3., 0.), (5., 0.), (4., 1.)]);
+        let collection = GeometryCollection(vec![
+            Point::new(10., 10.).into(),
+            Line::new((2.5, 3.), (5., 3.)).into(),
+        ]);
+        assert_eq!(
+            triangle.closest_point_to(&collection),
+            Closest::SinglePoint(Point::new(4., 1.))
+        );
+        let geometry = Geometry::from(triangle);
+        assert_eq!(
+            geometry.closest_point_to(&rect),
+            Closest::SinglePoint(Point::new(3., 0.))
+        );
+
+        let empty = MultiPolygon::<f64>(vec![]);
+        assert_eq!(rect.closest_point_to(&empty), Closest::Indeterminate);
+        assert_eq!(empty.closest_point_to(&rect), Closest::Indeterminate);
+    }
+
+    #[test]
+    fn closest_points_to_a_point_for_all_areas() {
+        let rect = Rect::new((0., 0.), (2., 2.));
+        let triangle = Triangle::from([(0., 0.), (2., 0.), (0., 2.)]);
+        for p in &[
+            Point::new(3., 1.),
+            Point::new(0.5, 1.2),
+            Point::new(-1., -1.),
+        ] {
+            assert_eq!(rect.closest_point(p), rect.to_polygon().closest_point(p));
+            assert_eq!(
+                triangle.closest_point(p),
+                triangle.to_polygon().closest_point(p)
+            );
+            assert_eq!(Geometry::from(rect).closest_point(p), rect.closest_point(p));
+            assert_eq!(rect.closest_point_to(p), rect.closest_point(p));
+            let collection = GeometryCollection(vec![rect.into(), triangle.into()]);
+            assert_eq!(
+                collection.closest_point(p),
+                MultiPolygon(vec![rect.to_polygon(), triangle.to_polygon()]).closest_point(p)
+            );
+        }
+    }
+
+    #[test]
+    fn indexed_search_matches_brute_force() {
+        fn check(from: &[Line<f64>], to: &[Line<f64>]) {
+            assert!(from.len() * to.len() > BRUTE_FORCE_PAIRS);
+            let mut brute_force = Best::new();
+            'outer: for a in from {
+                for b in to {
+                    if brute_force.update(*a, *b) {
+                        break 'outer;
+                    }
+                }
+            }
+            match (closest_between(from, to), brute_force.closest()) {
+                // either may find a different intersection first, which is rounded onto the
+                // segments
+                (Closest::Intersection(p), Closest::Intersection(_)) => {
+                    let on = |lines: &[Line<f64>]| {
+                        lines
+                            .iter()
+                            .any(|line| segment::coord_square_distance(*line, p.0) < 1e-20)
+                    };
+                    assert!(on(from) && on(to));
+                }
+                (indexed, brute_force) => assert_eq!(indexed, brute_force),
+            }
+        }
+
+        // long, wiggly lines, with enough pairs of segments to be indexed
+        let wiggle = |offset: f64, phase: f64| -> LineString<f64> {
+            (0..200)
+                .map(|i| {
+                    let x = i as f64 * 0.5;
+                    (x, offset + (x + phase).sin())
+                })
+                .collect()
+        };
+        for &(offset, phase) in &[(2.5, 0.3), (3., 1.7), (10., 0.), (1., 0.5), (0.5, 2.)] {
+            let a = segments(&wiggle(0., 0.));
+            let b = segments(&wiggle(offset, phase));
+            check(&a, &b);
+            // indexing the other geometry
+            check(&a[..10], &b);
+            check(&a, &b[..10]);
+        }
+    }
+
     #[test]
     fn polygon_with_point_near_interior_ring() {
         let poly = holy_polygon();
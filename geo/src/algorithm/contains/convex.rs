@@ -0,0 +1,234 @@
+//! Containment by `Rect`s and `Triangle`s.
+//!
+//! Both are convex, so a geometry lies in one (including on its boundary) exactly when every
+//! vertex of the geometry does. This lets them answer `contains` from the geometry's vertices in
+//! linear time, rather than converting to a `Polygon` and computing the full intersection matrix.
+
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::*;
+
+/// A convex area.
+pub(super) trait ConvexArea<T: GeoNum>: CoordinatePosition<Scalar = T> {
+    /// Whether the area has collapsed to a line or a point.
+    fn is_degenerate(&self) -> bool;
+
+    /// Whether the segment from `start` to `end`, both of which lie in the (non-degenerate) area,
+    /// lies on a single edge of its boundary.
+    fn segment_on_boundary(&self, start: Coordinate<T>, end: Coordinate<T>) -> bool;
+}
+
+impl<T: GeoNum> ConvexArea<T> for Rect<T> {
+    fn is_degenerate(&self) -> bool {
+        let (min, max) = (self.min(), self.max());
+        min.x == max.x || min.y == max.y
+    }
+
+    fn segment_on_boundary(&self, start: Coordinate<T>, end: Coordinate<T>) -> bool {
+        let (min, max) = (self.min(), self.max());
+        (start.x == end.x && (start.x == min.x || start.x == max.x))
+            || (start.y == end.y && (start.y == min.y || start.y == max.y))
+    }
+}
+
+impl<T: GeoNum> ConvexArea<T> for Triangle<T> {
+    fn is_degenerate(&self) -> bool {
+        T::Ker::orient2d(self.0, self.1, self.2) == Orientation::Collinear
+    }
+
+    fn segment_on_boundary(&self, start: Coordinate<T>, end: Coordinate<T>) -> bool {
+        self.to_lines().iter().any(|edge| {
+            T::Ker::orient2d(edge.start, edge.end, start) == Orientation::Collinear
+                && T::Ker::orient2d(edge.start, edge.end, end) == Orientation::Collinear
+        })
+    }
+}
+
+/// `area` contains `geometry` if no part of the geometry is outside of the area, and the
+/// interiors of the two intersect. Like a point, a degenerate area has no interior, so it
+/// contains nothing.
+pub(super) fn contains<T, A, G>(area: &A, geometry: &G) -> bool
+where
+    T: GeoNum,
+    A: ConvexArea<T>,
+    G: ConvexPlacement<T, A>,
+{
+    !area.is_degenerate() && geometry.is_covered_by(area) && geometry.meets_interior_of(area)
+}
+
+/// How a geometry lies relative to a [`ConvexArea`].
+pub(super) trait ConvexPlacement<T: GeoNum, A: ConvexArea<T>> {
+    /// Whether no part of `self` lies outside of `area`.
+    fn is_covered_by(&self, area: &A) -> bool;
+
+    /// Whether the interior of `self` intersects the interior of `area`, assuming `self` is
+    /// covered by it.
+    fn meets_interior_of(&self, area: &A) -> bool;
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Coordinate<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        area.coordinate_position(self) != CoordPos::Outside
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        area.coordinate_position(self) == CoordPos::Inside
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Point<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.0.is_covered_by(area)
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        self.0.meets_interior_of(area)
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for MultiPoint<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.iter().all(|point| point.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        self.iter().any(|point| point.meets_interior_of(area))
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Line<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.start.is_covered_by(area) && self.end.is_covered_by(area)
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        !area.segment_on_boundary(self.start, self.end)
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for LineString<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.0.iter().all(|coord| coord.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        self.lines().any(|line| line.meets_interior_of(area))
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for MultiLineString<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.iter()
+            .all(|line_string| line_string.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        self.iter()
+            .any(|line_string| line_string.meets_interior_of(area))
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Polygon<T> {
+    // Interior rings lie within the exterior, so only the exterior needs checking.
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.exterior().is_covered_by(area)
+    }
+
+    // The interior of a covered polygon with any area lies in the interior of `area`, while a
+    // collapsed polygon is only as good as its exterior ring.
+    fn meets_interior_of(&self, area: &A) -> bool {
+        has_area(&self.exterior().0) || self.exterior().meets_interior_of(area)
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for MultiPolygon<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.iter().all(|polygon| polygon.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        self.iter().any(|polygon| polygon.meets_interior_of(area))
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Rect<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        let (min, max) = (self.min(), self.max());
+        let corners = [
+            min,
+            Coordinate { x: max.x, y: min.y },
+            max,
+            Coordinate { x: min.x, y: max.y },
+        ];
+        corners.iter().all(|corner| corner.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        let (min, max) = (self.min(), self.max());
+        (min.x != max.x && min.y != max.y) || Line::new(min, max).meets_interior_of(area)
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Triangle<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.to_array()
+            .iter()
+            .all(|vertex| vertex.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        has_area(&self.to_array())
+            || self
+                .to_lines()
+                .iter()
+                .any(|edge| edge.meets_interior_of(area))
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for Geometry<T> {
+    geometry_delegate_impl! {
+        fn is_covered_by(&self, area: &A) -> bool;
+        fn meets_interior_of(&self, area: &A) -> bool;
+    }
+}
+
+impl<T: GeoNum, A: ConvexArea<T>> ConvexPlacement<T, A> for GeometryCollection<T> {
+    fn is_covered_by(&self, area: &A) -> bool {
+        self.iter().all(|geometry| geometry.is_covered_by(area))
+    }
+
+    fn meets_interior_of(&self, area: &A) -> bool {
+        self.iter().any(|geometry| geometry.meets_interior_of(area))
+    }
+}
+
+// Whether the coordinates don't all lie on a single line.
+fn has_area<T: GeoNum>(coords: &[Coordinate<T>]) -> bool {
+    let first = match coords.first() {
+        Some(first) => *first,
+        None => return false,
+    };
+    let second = match coords.iter().find(|coord| **coord != first) {
+        Some(second) => *second,
+        None => return false,
+    };
+    coords
+        .iter()
+        .any(|coord| T::Ker::orient2d(first, second, *coord) != Orientation::Collinear)
+}
+
+// Implement `Contains` for a convex area, for each of the given geometry types.
+macro_rules! impl_contains_for_convex_area {
+    ($area:ident, [$($geometry:ident),*]) => {
+        $(
+            impl<T> Contains<$geometry<T>> for $area<T>
+            where
+                T: GeoNum,
+            {
+                fn contains(&self, rhs: &$geometry<T>) -> bool {
+                    super::convex::contains(self, rhs)
+                }
+            }
+        )*
+    };
+}
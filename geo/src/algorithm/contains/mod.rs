@@ -36,6 +36,8 @@ pub trait Contains<Rhs = Self> {
     fn contains(&self, rhs: &Rhs) -> bool;
 }
 
+#[macro_use]
+mod convex;
 mod geometry;
 mod line;
 mod line_string;
@@ -518,4 +520,79 @@ mod test {
         let pt: Point<f64> = (0.5, 0.5).into();
         assert!(!tri.contains(&pt));
     }
+
+    #[test]
+    fn rect_and_triangle_contains_agree_with_relate() {
+        use crate::algorithm::relate::Relate;
+        use crate::{point, polygon, Geometry, GeometryCollection, MultiPoint};
+
+        let rect = Rect::new((0., 0.), (4., 4.));
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 1.).into(),
+            point!(x: 0., y: 0.).into(),
+            MultiPoint::from(vec![(0., 0.), (1., 1.)]).into(),
+            MultiPoint::from(vec![(0., 0.), (4., 0.)]).into(),
+            Line::from([(1., 1.), (2., 1.)]).into(),
+            Line::from([(0., 0.), (4., 0.)]).into(),
+            Line::from([(0., 0.), (1., 1.)]).into(),
+            Line::from([(1., 1.), (5., 1.)]).into(),
+            Line::from([(0., 4.), (4., 0.)]).into(),
+            LineString::from(vec![(0., 0.), (4., 0.), (0., 4.)]).into(),
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 4.)]).into(),
+            rect.to_polygon().into(),
+            triangle.to_polygon().into(),
+            polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)].into(),
+            polygon![(x: 3., y: 3.), (x: 5., y: 3.), (x: 3., y: 5.)].into(),
+            Rect::new((1., 1.), (3., 3.)).into(),
+            Rect::new((1., 1.), (1., 2.)).into(),
+            Rect::new((0., 1.), (0., 2.)).into(),
+            Triangle::from([(0., 0.), (1., 0.), (0., 1.)]).into(),
+            Triangle::from([(0., 0.), (1., 0.), (2., 0.)]).into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Line::from([(0., 0.), (4., 0.)]).into(),
+                point!(x: 1., y: 1.).into(),
+            ])),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                Line::from([(0., 0.), (4., 0.)]).into(),
+                point!(x: 0., y: 1.).into(),
+            ])),
+        ];
+
+        let (rect_geometry, triangle_geometry) = (Geometry::from(rect), Geometry::from(triangle));
+        for geometry in &geometries {
+            assert_eq!(
+                rect.contains(geometry),
+                rect_geometry.relate(geometry).is_contains(),
+                "rect contains {:?}",
+                geometry
+            );
+            assert_eq!(
+                triangle.contains(geometry),
+                triangle_geometry.relate(geometry).is_contains(),
+                "triangle contains {:?}",
+                geometry
+            );
+        }
+    }
+
+    #[test]
+    fn degenerate_rect_contains_nothing() {
+        let rect = Rect::new((0., 0.), (0., 4.));
+        assert!(!rect.contains(&Rect::new((0., 1.), (0., 2.))));
+        assert!(!rect.contains(&Line::from([(0., 1.), (0., 2.)])));
+        assert!(!rect.contains(&rect));
+
+        let triangle = Triangle::from([(0., 0.), (1., 1.), (2., 2.)]);
+        assert!(!triangle.contains(&Line::from([(0., 0.), (1., 1.)])));
+    }
+
+    #[test]
+    fn rect_contains_rect_on_its_edge() {
+        let rect = Rect::new((0., 0.), (4., 4.));
+        assert!(!rect.contains(&Rect::new((4., 1.), (4., 2.))));
+        assert!(!rect.contains(&Rect::new((1., 0.), (2., 0.))));
+        assert!(rect.contains(&Rect::new((1., 1.), (2., 1.))));
+        assert!(rect.contains(&Rect::new((4., 4.), (2., 2.))));
+    }
 }
@@ -31,11 +31,37 @@ where
     T: CoordNum,
 {
     fn contains(&self, other: &Rect<T>) -> bool {
-        // TODO: check for degenerate rectangle (which is a line or a point)
-        // All points of LineString must be in the polygon ?
-        self.min().x <= other.min().x
-            && self.max().x >= other.max().x
-            && self.min().y <= other.min().y
-            && self.max().y >= other.max().y
+        let (min, max) = (self.min(), self.max());
+        let (other_min, other_max) = (other.min(), other.max());
+        let is_covered = min.x <= other_min.x
+            && max.x >= other_max.x
+            && min.y <= other_min.y
+            && max.y >= other_max.y;
+        if !is_covered {
+            return false;
+        }
+
+        // The interiors must also intersect, so `self` can't be degenerate, and `other` can't be
+        // collapsed onto one of the edges of `self`.
+        let on_vertical_edge =
+            other_min.x == other_max.x && (other_min.x == min.x || other_min.x == max.x);
+        let on_horizontal_edge =
+            other_min.y == other_max.y && (other_min.y == min.y || other_min.y == max.y);
+        min.x != max.x && min.y != max.y && !on_vertical_edge && !on_horizontal_edge
     }
 }
+
+impl_contains_for_convex_area!(
+    Rect,
+    [
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Triangle,
+        GeometryCollection,
+        Geometry
+    ]
+);
@@ -1,5 +1,10 @@
+use super::convex::ConvexArea;
 use super::Contains;
-use crate::{Coordinate, GeoNum, LineString, Point, Triangle};
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::{
+    Coordinate, GeoNum, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 
 // ┌──────────────────────────────┐
 // │ Implementations for Triangle │
@@ -10,9 +15,8 @@ where
     T: GeoNum,
 {
     fn contains(&self, coord: &Coordinate<T>) -> bool {
-        let ls = LineString(vec![self.0, self.1, self.2, self.0]);
-        use crate::utils::{coord_pos_relative_to_ring, CoordPos};
-        coord_pos_relative_to_ring(*coord, &ls) == CoordPos::Inside
+        // a degenerate triangle has no interior
+        !self.is_degenerate() && self.coordinate_position(coord) == CoordPos::Inside
     }
 }
 
@@ -24,3 +28,19 @@ where
         self.contains(&point.0)
     }
 }
+
+impl_contains_for_convex_area!(
+    Triangle,
+    [
+        Line,
+        LineString,
+        Polygon,
+        MultiPoint,
+        MultiLineString,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        GeometryCollection,
+        Geometry
+    ]
+);
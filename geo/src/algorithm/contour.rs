@@ -0,0 +1,615 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::{Coordinate, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon, Rect};
+use std::collections::{HashMap, HashSet};
+
+/// Build the isolines (contour lines) of `threshold` over a grid of values using
+/// [marching squares](https://en.wikipedia.org/wiki/Marching_squares).
+///
+/// `grid` is indexed as `grid[row][column]`. The values are taken to lie on the nodes of a
+/// regular grid spanning `bounds`, with row `0` at `bounds.min().y` and column `0` at
+/// `bounds.min().x` (this is the layout produced by
+/// [`IdwInterpolator::interpolate_grid`](crate::algorithm::idw_interpolation::IdwInterpolator::interpolate_grid)).
+/// Values are interpolated linearly along cell edges, and saddle cells are resolved using the
+/// mean of their four corners.
+///
+/// Each resulting `LineString` is oriented so that values above `threshold` lie on its left.
+/// Isolines which form a loop are returned closed; isolines which reach the border of the grid
+/// are returned open. Grids with fewer than two rows or columns produce no isolines.
+///
+/// The grid must not contain `NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Rect};
+/// use geo::algorithm::contour::isolines;
+///
+/// let grid = vec![
+///     vec![0.0, 0.0, 0.0],
+///     vec![0.0, 2.0, 0.0],
+///     vec![0.0, 0.0, 0.0],
+/// ];
+/// let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 2.0, y: 2.0 });
+///
+/// let lines = isolines(&grid, bounds, 1.0);
+/// assert_eq!(lines.0.len(), 1);
+/// // a closed diamond around the peak
+/// assert!(lines.0[0].is_closed());
+/// assert_eq!(lines.0[0].0.len(), 5);
+/// ```
+pub fn isolines<T>(grid: &[Vec<T>], bounds: Rect<T>, threshold: T) -> MultiLineString<T>
+where
+    T: GeoFloat,
+{
+    let grid = match ValueGrid::new(grid, bounds) {
+        Some(grid) => grid,
+        None => return MultiLineString(vec![]),
+    };
+    let boundary = grid.region_boundary(&[Clip {
+        level: threshold,
+        keep_above: true,
+    }]);
+
+    // Only the parts of the region boundary which don't run along the border of the grid are
+    // isolines.
+    let edges: Vec<_> = boundary
+        .edges
+        .into_iter()
+        .filter(|(from, to)| grid.border_sides(*from) & grid.border_sides(*to) == 0)
+        .collect();
+
+    let mut outgoing: HashMap<VertexId, Vec<usize>> = HashMap::new();
+    let mut incoming: HashMap<VertexId, usize> = HashMap::new();
+    for (idx, (from, to)) in edges.iter().enumerate() {
+        outgoing.entry(*from).or_default().push(idx);
+        *incoming.entry(*to).or_default() += 1;
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut lines = vec![];
+
+    // open lines start where a vertex has more outgoing than incoming edges
+    let mut starts: Vec<usize> = (0..edges.len())
+        .filter(|idx| {
+            let from = edges[*idx].0;
+            incoming.get(&from).copied().unwrap_or(0) < outgoing[&from].len()
+        })
+        .collect();
+    // followed by any remaining loops
+    starts.extend(0..edges.len());
+
+    for start in starts {
+        if used[start] {
+            continue;
+        }
+        let mut coords = vec![boundary.coords[&edges[start].0]];
+        let mut next = Some(start);
+        while let Some(idx) = next {
+            used[idx] = true;
+            let to = edges[idx].1;
+            coords.push(boundary.coords[&to]);
+            next = outgoing
+                .get(&to)
+                .and_then(|candidates| candidates.iter().copied().find(|idx| !used[*idx]));
+        }
+        lines.push(LineString::from(coords));
+    }
+
+    MultiLineString(lines)
+}
+
+/// Build the filled isobands (contour bands) containing the values between `lower` and
+/// `upper` (inclusive) over a grid of values using
+/// [marching squares](https://en.wikipedia.org/wiki/Marching_squares).
+///
+/// The grid layout and interpolation are as described for [`isolines`]. Exterior rings of the
+/// resulting polygons are wound counter-clockwise and interior rings clockwise.
+///
+/// The grid must not contain `NaN` values.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Rect};
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::contour::isobands;
+///
+/// let grid = vec![
+///     vec![0.0, 0.0, 0.0],
+///     vec![0.0, 2.0, 0.0],
+///     vec![0.0, 0.0, 0.0],
+/// ];
+/// let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 2.0, y: 2.0 });
+///
+/// // everything but the peak: the grid with a diamond shaped hole
+/// let bands = isobands(&grid, bounds, -1.0, 1.0);
+/// assert_eq!(bands.0.len(), 1);
+/// assert_eq!(bands.0[0].interiors().len(), 1);
+/// assert_eq!(bands.unsigned_area(), 3.5);
+/// ```
+pub fn isobands<T>(grid: &[Vec<T>], bounds: Rect<T>, lower: T, upper: T) -> MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    let grid = match ValueGrid::new(grid, bounds) {
+        Some(grid) => grid,
+        None => return MultiPolygon(vec![]),
+    };
+    let boundary = grid.region_boundary(&[
+        Clip {
+            level: lower,
+            keep_above: true,
+        },
+        Clip {
+            level: upper,
+            keep_above: false,
+        },
+    ]);
+
+    let mut outgoing: HashMap<VertexId, Vec<usize>> = HashMap::new();
+    for (idx, (from, _)) in boundary.edges.iter().enumerate() {
+        outgoing.entry(*from).or_default().push(idx);
+    }
+
+    // Every vertex of the region boundary has as many incoming as outgoing edges, so following
+    // unused edges always leads back to the start.
+    let mut used = vec![false; boundary.edges.len()];
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for start in 0..boundary.edges.len() {
+        if used[start] {
+            continue;
+        }
+        let mut coords = vec![boundary.coords[&boundary.edges[start].0]];
+        let mut next = Some(start);
+        while let Some(idx) = next {
+            used[idx] = true;
+            let to = boundary.edges[idx].1;
+            coords.push(boundary.coords[&to]);
+            next = outgoing[&to].iter().copied().find(|idx| !used[*idx]);
+        }
+        let ring = LineString::from(coords);
+        match ring.winding_order() {
+            Some(WindingOrder::CounterClockwise) => shells.push(ring),
+            Some(WindingOrder::Clockwise) => holes.push(ring),
+            // degenerate rings enclose nothing
+            None => {}
+        }
+    }
+
+    let shell_areas: Vec<T> = shells
+        .iter()
+        .map(|shell| Polygon::new(shell.clone(), vec![]).unsigned_area())
+        .collect();
+    let mut interiors = vec![vec![]; shells.len()];
+    for hole in holes {
+        // holes may touch their shell, so classify a vertex which isn't on its boundary
+        let owner = (0..shells.len())
+            .filter(|idx| {
+                hole.0
+                    .iter()
+                    .map(|coord| coord_pos_relative_to_ring(*coord, &shells[*idx]))
+                    .find(|pos| *pos != CoordPos::OnBoundary)
+                    == Some(CoordPos::Inside)
+            })
+            // islands nest, so the innermost shell owns the hole
+            .min_by(|a, b| shell_areas[*a].partial_cmp(&shell_areas[*b]).unwrap());
+        if let Some(owner) = owner {
+            interiors[owner].push(hole);
+        }
+    }
+
+    MultiPolygon(
+        shells
+            .into_iter()
+            .zip(interiors)
+            .map(|(exterior, interiors)| Polygon::new(exterior, interiors))
+            .collect(),
+    )
+}
+
+/// A location on the grid: either a node, or the center of a cell, indexed by `(row, column)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum GridPoint {
+    Node(usize, usize),
+    Center(usize, usize),
+}
+
+/// Identifies a vertex of the traced geometry exactly, so that cells sharing an edge agree on
+/// its vertices without comparing floating point coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum VertexId {
+    Grid(GridPoint),
+    /// The point where the segment between two grid points crosses the level of a clip.
+    Crossing(GridPoint, GridPoint, usize),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Vertex<T: GeoFloat> {
+    id: VertexId,
+    coord: Coordinate<T>,
+    value: T,
+}
+
+/// Keep the part of a cell whose values are above (or below) `level`.
+#[derive(Clone, Copy, Debug)]
+struct Clip<T: GeoFloat> {
+    level: T,
+    keep_above: bool,
+}
+
+impl<T: GeoFloat> Clip<T> {
+    fn keeps(&self, value: T) -> bool {
+        if self.keep_above {
+            value >= self.level
+        } else {
+            value <= self.level
+        }
+    }
+}
+
+/// The directed boundary edges of a region, with its interior on their left.
+struct RegionBoundary<T: GeoFloat> {
+    edges: Vec<(VertexId, VertexId)>,
+    coords: HashMap<VertexId, Coordinate<T>>,
+}
+
+struct ValueGrid<'a, T: GeoFloat> {
+    values: &'a [Vec<T>],
+    rows: usize,
+    columns: usize,
+    origin: Coordinate<T>,
+    dx: T,
+    dy: T,
+}
+
+impl<'a, T: GeoFloat> ValueGrid<'a, T> {
+    fn new(values: &'a [Vec<T>], bounds: Rect<T>) -> Option<Self> {
+        let rows = values.len();
+        let columns = values.iter().map(Vec::len).min().unwrap_or(0);
+        if rows < 2 || columns < 2 {
+            return None;
+        }
+        Some(ValueGrid {
+            values,
+            rows,
+            columns,
+            origin: bounds.min(),
+            dx: bounds.width() / T::from(columns - 1)?,
+            dy: bounds.height() / T::from(rows - 1)?,
+        })
+    }
+
+    fn grid_vertex(&self, point: GridPoint) -> Vertex<T> {
+        let two = T::one() + T::one();
+        let (x, y, value) = match point {
+            GridPoint::Node(row, column) => (
+                T::from(column).unwrap(),
+                T::from(row).unwrap(),
+                self.values[row][column],
+            ),
+            GridPoint::Center(row, column) => {
+                let sum = self.values[row][column]
+                    + self.values[row][column + 1]
+                    + self.values[row + 1][column]
+                    + self.values[row + 1][column + 1];
+                (
+                    T::from(column).unwrap() + T::one() / two,
+                    T::from(row).unwrap() + T::one() / two,
+                    sum / (two + two),
+                )
+            }
+        };
+        Vertex {
+            id: VertexId::Grid(point),
+            coord: Coordinate {
+                x: self.origin.x + x * self.dx,
+                y: self.origin.y + y * self.dy,
+            },
+            value,
+        }
+    }
+
+    /// Bitmask of the sides of the grid (bottom, top, left, right) a vertex lies on.
+    fn border_sides(&self, vertex: VertexId) -> u8 {
+        let point_sides = |point: GridPoint| match point {
+            GridPoint::Node(row, column) => {
+                (row == 0) as u8
+                    | ((row == self.rows - 1) as u8) << 1
+                    | ((column == 0) as u8) << 2
+                    | ((column == self.columns - 1) as u8) << 3
+            }
+            GridPoint::Center(..) => 0,
+        };
+        match vertex {
+            VertexId::Grid(point) => point_sides(point),
+            VertexId::Crossing(a, b, _) => point_sides(a) & point_sides(b),
+        }
+    }
+
+    /// Trace the boundary of the region of the grid kept by every clip.
+    fn region_boundary(&self, clips: &[Clip<T>]) -> RegionBoundary<T> {
+        let mut edges: HashSet<(VertexId, VertexId)> = HashSet::new();
+        let mut coords = HashMap::new();
+
+        for row in 0..self.rows - 1 {
+            for column in 0..self.columns - 1 {
+                for fragment in self.cell_fragments(row, column, clips) {
+                    for (idx, from) in fragment.iter().enumerate() {
+                        let to = fragment[(idx + 1) % fragment.len()];
+                        coords.insert(from.id, from.coord);
+                        // an edge shared by two fragments is interior to the region
+                        if !edges.remove(&(to.id, from.id)) {
+                            edges.insert((from.id, to.id));
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut edges: Vec<_> = edges.into_iter().collect();
+        // make the output independent of hashing order
+        edges.sort_by_key(|(from, to)| (sort_key(*from), sort_key(*to)));
+        RegionBoundary { edges, coords }
+    }
+
+    /// The counter-clockwise polygons covering the part of a cell kept by every clip.
+    fn cell_fragments(&self, row: usize, column: usize, clips: &[Clip<T>]) -> Vec<Vec<Vertex<T>>> {
+        let corners = [
+            self.grid_vertex(GridPoint::Node(row, column)),
+            self.grid_vertex(GridPoint::Node(row, column + 1)),
+            self.grid_vertex(GridPoint::Node(row + 1, column + 1)),
+            self.grid_vertex(GridPoint::Node(row + 1, column)),
+        ];
+        let classes: Vec<Vec<bool>> = corners
+            .iter()
+            .map(|corner| clips.iter().map(|clip| clip.keeps(corner.value)).collect())
+            .collect();
+        let transitions = (0..4)
+            .filter(|idx| classes[*idx] != classes[(idx + 1) % 4])
+            .count();
+
+        let polygons = if transitions > 2 {
+            // A saddle: the kept region within the cell may be disconnected. Split the cell
+            // into triangles around its center, so the center's value decides which corners
+            // are connected.
+            let center = self.grid_vertex(GridPoint::Center(row, column));
+            (0..4)
+                .map(|idx| vec![corners[idx], corners[(idx + 1) % 4], center])
+                .collect()
+        } else {
+            vec![corners.to_vec()]
+        };
+
+        polygons
+            .into_iter()
+            .filter_map(|polygon| {
+                let clipped = clips
+                    .iter()
+                    .enumerate()
+                    .fold(polygon, |polygon, (clip_idx, clip)| {
+                        self.clip(&polygon, *clip, clip_idx)
+                    });
+                if clipped.len() < 3 {
+                    None
+                } else {
+                    Some(clipped)
+                }
+            })
+            .collect()
+    }
+
+    /// Sutherland–Hodgman clipping of a polygon against a level of the (piecewise linear)
+    /// value field.
+    fn clip(&self, polygon: &[Vertex<T>], clip: Clip<T>, clip_idx: usize) -> Vec<Vertex<T>> {
+        let mut output: Vec<Vertex<T>> = Vec::with_capacity(polygon.len() + 2);
+        let mut push = |vertex: Vertex<T>| {
+            if output.last().map(|last| last.id) != Some(vertex.id) {
+                output.push(vertex);
+            }
+        };
+        for (idx, current) in polygon.iter().enumerate() {
+            let previous = polygon[(idx + polygon.len() - 1) % polygon.len()];
+            match (clip.keeps(previous.value), clip.keeps(current.value)) {
+                (true, true) => push(*current),
+                (false, true) => {
+                    push(self.crossing(previous, *current, clip, clip_idx));
+                    push(*current);
+                }
+                (true, false) => push(self.crossing(previous, *current, clip, clip_idx)),
+                (false, false) => {}
+            }
+        }
+        if output.len() > 1 && output[0].id == output[output.len() - 1].id {
+            output.pop();
+        }
+        output
+    }
+
+    /// The point between `a` and `b` where the value field crosses the level of `clip`.
+    fn crossing(&self, a: Vertex<T>, b: Vertex<T>, clip: Clip<T>, clip_idx: usize) -> Vertex<T> {
+        if a.value == clip.level {
+            return a;
+        }
+        if b.value == clip.level {
+            return b;
+        }
+
+        // `a` and `b` lie on a segment between two grid points (an edge of a cell or of one of
+        // its triangles). Interpolate along the whole segment in a fixed direction, so that
+        // every cell sharing the segment computes exactly the same crossing.
+        let mut support = vec![];
+        for id in [a.id, b.id].iter() {
+            match *id {
+                VertexId::Grid(point) => support.push(point),
+                VertexId::Crossing(p, q, _) => {
+                    support.push(p);
+                    support.push(q);
+                }
+            }
+        }
+        support.sort();
+        support.dedup();
+        debug_assert_eq!(support.len(), 2, "crossing must lie on a single segment");
+        let start = self.grid_vertex(support[0]);
+        let end = self.grid_vertex(support[support.len() - 1]);
+
+        let fraction = (clip.level - start.value) / (end.value - start.value);
+        Vertex {
+            id: VertexId::Crossing(support[0], support[support.len() - 1], clip_idx),
+            coord: Coordinate {
+                x: start.coord.x + (end.coord.x - start.coord.x) * fraction,
+                y: start.coord.y + (end.coord.y - start.coord.y) * fraction,
+            },
+            value: clip.level,
+        }
+    }
+}
+
+fn sort_key(vertex: VertexId) -> (GridPoint, GridPoint, usize) {
+    match vertex {
+        VertexId::Grid(point) => (point, point, 0),
+        VertexId::Crossing(a, b, clip_idx) => (a, b, clip_idx + 1),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::line_string;
+
+    fn unit_bounds(size: f64) -> Rect<f64> {
+        Rect::new(
+            Coordinate { x: 0.0, y: 0.0 },
+            Coordinate { x: size, y: size },
+        )
+    }
+
+    fn peak() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ]
+    }
+
+    #[test]
+    fn degenerate_grid_test() {
+        let grid = vec![vec![1.0, 2.0, 3.0]];
+        assert!(isolines(&grid, unit_bounds(1.0), 1.5).0.is_empty());
+        assert!(isobands(&grid, unit_bounds(1.0), 1.0, 2.0).0.is_empty());
+    }
+
+    #[test]
+    fn peak_isoline_test() {
+        let lines = isolines(&peak(), unit_bounds(2.0), 1.0);
+        assert_eq!(lines.0.len(), 1);
+        let line = &lines.0[0];
+        assert!(line.is_closed());
+        // higher values are on the left
+        assert!(line.is_ccw());
+        let mut coords: Vec<_> = line.0[..4].iter().map(|c| (c.x, c.y)).collect();
+        coords.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(coords, vec![(0.5, 1.0), (1.0, 0.5), (1.0, 1.5), (1.5, 1.0)]);
+    }
+
+    #[test]
+    fn open_isoline_test() {
+        // values increase from left to right
+        let grid = vec![
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 2.0],
+            vec![0.0, 1.0, 2.0],
+        ];
+        let lines = isolines(&grid, unit_bounds(2.0), 0.5);
+        assert_eq!(
+            lines.0,
+            vec![line_string![(x: 0.5, y: 2.0), (x: 0.5, y: 1.0), (x: 0.5, y: 0.0)]]
+        );
+
+        // outside of the range of values
+        assert!(isolines(&grid, unit_bounds(2.0), 3.0).0.is_empty());
+        assert!(isolines(&grid, unit_bounds(2.0), -1.0).0.is_empty());
+    }
+
+    #[test]
+    fn saddle_test() {
+        // high corners on one diagonal, low on the other
+        let grid = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+
+        // a low center separates the high corners
+        let lines = isolines(&grid, unit_bounds(1.0), 0.6);
+        assert_eq!(lines.0.len(), 2);
+        let bands = isobands(&grid, unit_bounds(1.0), 0.6, 2.0);
+        assert_eq!(bands.0.len(), 2);
+
+        // a high center connects them
+        let lines = isolines(&grid, unit_bounds(1.0), 0.4);
+        assert_eq!(lines.0.len(), 2);
+        let bands = isobands(&grid, unit_bounds(1.0), 0.4, 2.0);
+        assert_eq!(bands.0.len(), 1);
+    }
+
+    #[test]
+    fn full_band_test() {
+        let bands = isobands(&peak(), unit_bounds(2.0), -1.0, 3.0);
+        assert_eq!(bands.0.len(), 1);
+        assert!(bands.0[0].interiors().is_empty());
+        assert_relative_eq!(bands.unsigned_area(), 4.0);
+    }
+
+    #[test]
+    fn band_with_hole_test() {
+        let bands = isobands(&peak(), unit_bounds(2.0), -1.0, 1.0);
+        assert_eq!(bands.0.len(), 1);
+        let polygon = &bands.0[0];
+        assert!(polygon.exterior().is_ccw());
+        assert_eq!(polygon.interiors().len(), 1);
+        assert!(polygon.interiors()[0].is_cw());
+        assert_relative_eq!(bands.unsigned_area(), 3.5);
+
+        // the hole is filled by the band above
+        let upper = isobands(&peak(), unit_bounds(2.0), 1.0, 3.0);
+        assert_relative_eq!(upper.unsigned_area(), 0.5);
+    }
+
+    #[test]
+    fn island_in_hole_test() {
+        // a ring of high values around a low moat around a high peak
+        let grid = vec![
+            vec![2.0, 2.0, 2.0, 2.0, 2.0],
+            vec![2.0, 0.0, 0.0, 0.0, 2.0],
+            vec![2.0, 0.0, 2.0, 0.0, 2.0],
+            vec![2.0, 0.0, 0.0, 0.0, 2.0],
+            vec![2.0, 2.0, 2.0, 2.0, 2.0],
+        ];
+        let bands = isobands(&grid, unit_bounds(4.0), 1.0, 3.0);
+        assert_eq!(bands.0.len(), 2);
+        let (outer, island): (Vec<_>, Vec<_>) = bands
+            .0
+            .iter()
+            .partition(|polygon| polygon.interiors().len() == 1);
+        assert_eq!(outer.len(), 1);
+        assert_eq!(island.len(), 1);
+        assert_relative_eq!(island[0].unsigned_area(), 0.5);
+        assert_relative_eq!(bands.unsigned_area(), 16.0 - 8.5 + 0.5);
+    }
+
+    #[test]
+    fn band_between_levels_test() {
+        let grid = vec![vec![0.0, 4.0], vec![0.0, 4.0]];
+        let bands = isobands(&grid, unit_bounds(1.0), 1.0, 2.0);
+        assert_eq!(bands.0.len(), 1);
+        assert_relative_eq!(bands.unsigned_area(), 0.25);
+        assert_eq!(
+            bands.bounding_rect().unwrap(),
+            Rect::new(
+                Coordinate { x: 0.25, y: 0.0 },
+                Coordinate { x: 0.5, y: 1.0 }
+            )
+        );
+    }
+}
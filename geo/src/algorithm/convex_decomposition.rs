@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoNum, Line, LineString, MultiPolygon, Polygon};
+
+/// Split a polygon, with or without holes, into convex polygons.
+///
+/// The polygon is triangulated by ear clipping, after bridging each of its holes to its
+/// exterior, and the triangles are then merged again wherever a merge stays convex, following
+/// Hertel and Mehlhorn. This gives at most four times as many parts as the fewest possible. The
+/// parts are wound counter-clockwise, without collinear vertices, and meet along edges or at
+/// vertices.
+///
+/// The polygon must be valid. Holes may touch the exterior, or each other, where both have a
+/// vertex.
+///
+/// PERF: bridging a hole tests each of the edges of the polygon, so the cost grows with the
+/// number of holes times the square of the number of vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::convex_decomposition::ConvexDecomposition;
+/// use geo::algorithm::is_convex::IsConvex;
+/// use geo::polygon;
+///
+/// // an L, which splits into two rectangles
+/// let ell = polygon![
+///     (x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.),
+///     (x: 1., y: 1.), (x: 1., y: 2.), (x: 0., y: 2.),
+/// ];
+/// let parts = ell.convex_decomposition();
+/// assert_eq!(parts.0.len(), 2);
+/// assert!(parts.0.iter().all(|part| part.exterior().is_ccw_convex()));
+/// ```
+pub trait ConvexDecomposition<T: GeoNum> {
+    fn convex_decomposition(&self) -> MultiPolygon<T>;
+}
+
+impl<T: GeoNum> ConvexDecomposition<T> for Polygon<T> {
+    fn convex_decomposition(&self) -> MultiPolygon<T> {
+        let exterior = ring_coords(self.exterior(), WindingOrder::CounterClockwise);
+        if exterior.len() < 3 {
+            return MultiPolygon(vec![]);
+        }
+        // with the holes wound clockwise, the polygon is on the left of each ring
+        let holes = self
+            .interiors()
+            .iter()
+            .map(|hole| ring_coords(hole, WindingOrder::Clockwise))
+            .filter(|hole| hole.len() >= 3)
+            .collect();
+        let ring = bridge_holes(exterior, holes);
+        let triangles = ear_clip(ring);
+        MultiPolygon(
+            merge_convex(&triangles)
+                .into_iter()
+                .map(|part| Polygon::new(LineString::from(part), vec![]))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoNum> ConvexDecomposition<T> for MultiPolygon<T> {
+    fn convex_decomposition(&self) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .flat_map(|polygon| polygon.convex_decomposition().0)
+                .collect(),
+        )
+    }
+}
+
+// The coordinates of a ring, wound in `order`, without its closing coordinate or repeated
+// coordinates.
+pub(crate) fn ring_coords<T: GeoNum>(
+    ring: &LineString<T>,
+    order: WindingOrder,
+) -> Vec<Coordinate<T>> {
+    let mut coords: Vec<Coordinate<T>> = ring.clone_to_winding_order(order).0;
+    coords.dedup();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    coords
+}
+
+fn is_left<T: GeoNum>(a: Coordinate<T>, b: Coordinate<T>, c: Coordinate<T>) -> bool {
+    T::Ker::orient2d(a, b, c) == Orientation::CounterClockwise
+}
+
+// Whether the direction from `vertex` towards `target` points into the polygon, which lies on
+// the left of the edges from `previous` to `vertex` and on to `next`.
+fn in_wedge<T: GeoNum>(
+    previous: Coordinate<T>,
+    vertex: Coordinate<T>,
+    next: Coordinate<T>,
+    target: Coordinate<T>,
+) -> bool {
+    if is_left(previous, vertex, next) {
+        is_left(previous, vertex, target) && is_left(vertex, next, target)
+    } else {
+        is_left(previous, vertex, target) || is_left(vertex, next, target)
+    }
+}
+
+// Splice each hole into the exterior ring along a bridge to a vertex it can see, giving a
+// single ring which touches itself along the bridges. The holes are taken from the one
+// reaching furthest east, whose easternmost vertex sees past the holes still to come.
+fn bridge_holes<T: GeoNum>(
+    mut ring: Vec<Coordinate<T>>,
+    mut holes: Vec<Vec<Coordinate<T>>>,
+) -> Vec<Coordinate<T>> {
+    let easternmost = |hole: &Vec<Coordinate<T>>| {
+        (0..hole.len())
+            .max_by(|a, b| lex_cmp(&hole[*a], &hole[*b]))
+            .unwrap()
+    };
+    holes.sort_by(|a, b| lex_cmp(&b[easternmost(b)], &a[easternmost(a)]));
+
+    for index in 0..holes.len() {
+        let hole = &holes[index];
+        let start = easternmost(hole);
+        let m = hole[start];
+        let (m_previous, m_next) = (
+            hole[(start + hole.len() - 1) % hole.len()],
+            hole[(start + 1) % hole.len()],
+        );
+
+        let mut candidates: Vec<usize> = (0..ring.len()).collect();
+        let distance = |coord: Coordinate<T>| {
+            let delta = coord - m;
+            delta.x * delta.x + delta.y * delta.y
+        };
+        candidates.sort_by(|a, b| distance(ring[*a]).partial_cmp(&distance(ring[*b])).unwrap());
+        let visible = |p: usize| {
+            let target = ring[p];
+            if target == m {
+                return true;
+            }
+            let previous = ring[(p + ring.len() - 1) % ring.len()];
+            let next = ring[(p + 1) % ring.len()];
+            if !in_wedge(previous, target, next, m) || !in_wedge(m_previous, m, m_next, target) {
+                return false;
+            }
+            let bridge = Line::new(m, target);
+            let rings = std::iter::once(&ring).chain(holes[index..].iter());
+            !rings.into_iter().any(|coords| {
+                (0..coords.len()).any(|edge| {
+                    let (a, b) = (coords[edge], coords[(edge + 1) % coords.len()]);
+                    let incident = a == m || a == target || b == m || b == target;
+                    !incident && bridge.intersects(&Line::new(a, b))
+                })
+            })
+        };
+        let p = candidates
+            .iter()
+            .copied()
+            .find(|p| visible(*p))
+            .unwrap_or(candidates[0]);
+
+        let mut spliced = Vec::with_capacity(ring.len() + hole.len() + 2);
+        spliced.extend_from_slice(&ring[..=p]);
+        spliced.extend((0..=hole.len()).map(|offset| hole[(start + offset) % hole.len()]));
+        spliced.extend_from_slice(&ring[p..]);
+        spliced.dedup();
+        ring = spliced;
+    }
+    ring
+}
+
+// Triangulate a counter-clockwise ring, which may touch itself, by clipping its ears. Degenerate
+// vertices are dropped without a triangle, and an invalid ring yields only the triangles clipped
+// before it stalls.
+pub(crate) fn ear_clip<T: GeoNum>(mut ring: Vec<Coordinate<T>>) -> Vec<[Coordinate<T>; 3]> {
+    let mut triangles = vec![];
+    let mut index = 0;
+    // the number of vertices since the last was clipped
+    let mut stalled = 0;
+    while ring.len() > 3 {
+        let len = ring.len();
+        let (a, b, c) = (
+            ring[(index + len - 1) % len],
+            ring[index],
+            ring[(index + 1) % len],
+        );
+        let orientation = T::Ker::orient2d(a, b, c);
+        let clip = match orientation {
+            // a straight run or a spike, which adds no area
+            Orientation::Collinear => true,
+            Orientation::CounterClockwise => {
+                // nothing else may be within the triangle, apart from copies of its corners
+                stalled > len
+                    || !ring.iter().any(|&p| {
+                        p != a
+                            && p != b
+                            && p != c
+                            && T::Ker::orient2d(a, b, p) != Orientation::Clockwise
+                            && T::Ker::orient2d(b, c, p) != Orientation::Clockwise
+                            && T::Ker::orient2d(c, a, p) != Orientation::Clockwise
+                    })
+            }
+            Orientation::Clockwise => false,
+        };
+        if clip {
+            if orientation == Orientation::CounterClockwise {
+                triangles.push([a, b, c]);
+            }
+            ring.remove(index);
+            stalled = 0;
+            if index == ring.len() {
+                index = 0;
+            }
+        } else {
+            index = (index + 1) % len;
+            stalled += 1;
+            // only possible for an invalid polygon
+            if stalled > 2 * len {
+                return triangles;
+            }
+        }
+    }
+    if ring.len() == 3 && is_left(ring[0], ring[1], ring[2]) {
+        triangles.push([ring[0], ring[1], ring[2]]);
+    }
+    triangles
+}
+
+// Merge triangles across their shared edges, wherever the merged part stays convex, and return
+// the parts as closed rings.
+fn merge_convex<T: GeoNum>(triangles: &[[Coordinate<T>; 3]]) -> Vec<Vec<Coordinate<T>>> {
+    // number the vertices, so that the copies of a vertex along a bridge are one
+    let mut vertices: Vec<Coordinate<T>> = triangles.iter().flatten().copied().collect();
+    vertices.sort_by(lex_cmp);
+    vertices.dedup();
+    let id = |coord: &Coordinate<T>| {
+        vertices
+            .binary_search_by(|vertex| lex_cmp(vertex, coord))
+            .unwrap()
+    };
+
+    let mut parts: Vec<Option<Vec<usize>>> = triangles
+        .iter()
+        .map(|triangle| Some(triangle.iter().map(id).collect()))
+        .collect();
+    let mut owners: HashMap<(usize, usize), usize> = HashMap::new();
+    for (index, part) in parts.iter().enumerate() {
+        let part = part.as_ref().unwrap();
+        for edge in 0..3 {
+            owners.insert((part[edge], part[(edge + 1) % 3]), index);
+        }
+    }
+    let mut diagonals: Vec<(usize, usize)> = owners
+        .keys()
+        .filter(|(u, v)| u < v && owners.contains_key(&(*v, *u)))
+        .copied()
+        .collect();
+    diagonals.sort_unstable();
+
+    for (u, v) in diagonals {
+        let (a, b) = (owners[&(u, v)], owners[&(v, u)]);
+        if a == b {
+            continue;
+        }
+        let (first, second) = (parts[a].as_ref().unwrap(), parts[b].as_ref().unwrap());
+        // `first` runs from `v` round to `u`, and `second` from `u` round to `v`
+        let rotate = |part: &Vec<usize>, from: usize| {
+            let start = part.iter().position(|vertex| *vertex == from).unwrap();
+            let mut rotated = part[start..].to_vec();
+            rotated.extend_from_slice(&part[..start]);
+            rotated
+        };
+        let (first, second) = (rotate(first, v), rotate(second, u));
+        let turns_left_or_straight = |previous: usize, vertex: usize, next: usize| {
+            T::Ker::orient2d(vertices[previous], vertices[vertex], vertices[next])
+                != Orientation::Clockwise
+        };
+        if !turns_left_or_straight(first[first.len() - 2], u, second[1])
+            || !turns_left_or_straight(second[second.len() - 2], v, first[1])
+        {
+            continue;
+        }
+        let mut merged = first;
+        merged.extend_from_slice(&second[1..second.len() - 1]);
+        owners.remove(&(u, v));
+        owners.remove(&(v, u));
+        for edge in 0..merged.len() {
+            let key = (merged[edge], merged[(edge + 1) % merged.len()]);
+            if let Some(owner) = owners.get_mut(&key) {
+                *owner = a;
+            }
+        }
+        parts[a] = Some(merged);
+        parts[b] = None;
+    }
+
+    parts
+        .into_iter()
+        .flatten()
+        .map(|part| {
+            let len = part.len();
+            let mut ring: Vec<Coordinate<T>> = (0..len)
+                .filter(|&index| {
+                    let previous = vertices[part[(index + len - 1) % len]];
+                    let next = vertices[part[(index + 1) % len]];
+                    T::Ker::orient2d(previous, vertices[part[index]], next)
+                        != Orientation::Collinear
+                })
+                .map(|index| vertices[part[index]])
+                .collect();
+            ring.push(ring[0]);
+            ring
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::centroid::Centroid;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::is_convex::IsConvex;
+    use crate::polygon;
+
+    fn assert_decomposes(polygon: &Polygon<f64>, parts: &MultiPolygon<f64>) {
+        for part in &parts.0 {
+            assert!(part.exterior().is_strictly_ccw_convex(), "{:?}", part);
+            assert!(polygon.contains(&part.centroid().unwrap()));
+        }
+        let area: f64 = parts.0.iter().map(|part| part.signed_area()).sum();
+        assert!((area - polygon.unsigned_area()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn square_with_holes() {
+        let polygon = polygon![
+            exterior: [
+                (x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.), (x: 10., y: 10.), (x: 0., y: 10.),
+            ],
+            interiors: [
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+                [(x: 6., y: 6.), (x: 8., y: 6.), (x: 7., y: 8.)],
+                // touching the exterior at a vertex
+                [(x: 10., y: 5.), (x: 9., y: 6.), (x: 9., y: 4.)],
+            ],
+        ];
+        let parts = polygon.convex_decomposition();
+        assert_decomposes(&polygon, &parts);
+        // rather than the 19 triangles
+        assert!(parts.0.len() <= 10, "{}", parts.0.len());
+    }
+
+    #[test]
+    fn comb_and_degenerate() {
+        // a comb of four teeth, clockwise, with collinear vertices along its spine
+        let comb = polygon![
+            (x: 0., y: 0.), (x: 0., y: 3.), (x: 1., y: 3.), (x: 1., y: 1.), (x: 2., y: 1.),
+            (x: 2., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.), (x: 4., y: 1.), (x: 4., y: 3.),
+            (x: 5., y: 3.), (x: 5., y: 1.), (x: 6., y: 1.), (x: 6., y: 3.), (x: 7., y: 3.),
+            (x: 7., y: 0.), (x: 3.5, y: 0.),
+        ];
+        let parts = comb.convex_decomposition();
+        assert_decomposes(&comb, &parts);
+        assert_eq!(parts.0.len(), 5);
+
+        let flat: Polygon<f64> = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert!(flat.convex_decomposition().0.is_empty());
+    }
+}
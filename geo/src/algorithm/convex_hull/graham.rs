@@ -1,6 +1,7 @@
 use super::{swap_remove_to_first, trivial_hull};
 use crate::algorithm::kernels::*;
 use crate::{Coordinate, GeoNum, LineString};
+use alloc::vec::Vec;
 
 /// The [Graham's scan] algorithm to compute the convex hull
 /// of a collection of points. This algorithm is less
@@ -32,7 +33,7 @@ where
 
     // Find lexicographically least point and add to hull
     use crate::utils::least_index;
-    use std::cmp::Ordering;
+    use core::cmp::Ordering;
     let min_idx = least_index(points);
     let head = swap_remove_to_first(&mut points, min_idx);
     output.push(*head);
@@ -70,11 +71,14 @@ where
                 }
             }
         }
-        // Corner case: if the lex. least point added before
-        // this loop is repeated, then we should not end up
-        // adding it here (because output.len() == 1 in the
-        // first iteration)
-        if include_on_hull || pt != output.last().unwrap() {
+        // Repeated points are adjacent once sorted. They must
+        // be skipped even when including points on the hull:
+        // a repeated point is collinear with its copy, so it
+        // would be kept, and then shield the copy from being
+        // popped by the points after it. This also covers the
+        // lex. least point added before this loop, which the
+        // loop above leaves in place.
+        if pt != output.last().unwrap() {
             output.push(*pt);
         }
     }
@@ -135,6 +139,31 @@ mod test {
         test_convexity(&v);
     }
 
+    #[test]
+    fn graham_hull_repeated_points() {
+        // Regression test: when including points on the hull, the
+        // repeated point inside the hull was kept, leaving a reflex
+        // vertex at (1, 3). Its neighbours are its own copy, so the
+        // turn there is collinear, and only `IsConvex`'s winding
+        // check catches it.
+        let v = vec![(0, 0), (4, 0), (4, 4), (1, 3), (1, 3), (0, 4)];
+        test_convexity(&v);
+
+        let mut v: Vec<_> = v.iter().map(|&c| Coordinate::from(c)).collect();
+        let hull = graham_hull(&mut v, true);
+        let expected: LineString<_> = vec![(0, 0), (4, 0), (4, 4), (0, 4), (0, 0)].into();
+        assert_eq!(hull, expected);
+
+        // repeated points on the hull are only included once
+        let mut v: Vec<_> = vec![(0, 0), (2, 0), (2, 0), (4, 0), (4, 4), (0, 4), (0, 4)]
+            .into_iter()
+            .map(Coordinate::from)
+            .collect();
+        let hull = graham_hull(&mut v, true);
+        let expected: LineString<_> = vec![(0, 0), (2, 0), (4, 0), (4, 4), (0, 4), (0, 0)].into();
+        assert_eq!(hull, expected);
+    }
+
     #[test]
     fn graham_test_complex() {
         let v = include!("../test_fixtures/poly1.rs");
@@ -2,6 +2,8 @@ use super::{swap_remove_to_first, trivial_hull};
 use crate::kernels::{HasKernel, Kernel, Orientation};
 use crate::utils::partition_slice;
 use crate::{Coordinate, GeoNum, LineString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 // Determines if `p_c` lies on the positive side of the
 // segment `p_a` to `p_b`. In other words, whether segment
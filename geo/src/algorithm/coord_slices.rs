@@ -0,0 +1,363 @@
+//! Run algorithms directly over coordinates held in separate slices of `x` and `y`.
+//!
+//! Columnar formats, such as GeoArrow's separated coordinate layout, and many FFI sources store
+//! a line's coordinates as one array of `x` and another of `y`, rather than interleaved as in a
+//! [`LineString`]. A [`LineStringView`], or a [`PolygonView`] of them, borrows those arrays, so
+//! a large dataset can be measured, simplified and tested against without first copying it
+//! into `Vec<Coordinate>`s.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::coord_slices::{LineStringView, PolygonView};
+//! use geo::Coordinate;
+//!
+//! // the columns of a ring, as read from a columnar file
+//! let xs = [0., 4., 4., 0., 0.];
+//! let ys = [0., 0., 3., 3., 0.];
+//! let ring = LineStringView::new(&xs, &ys).unwrap();
+//! assert_eq!(ring.euclidean_length(), 14.);
+//!
+//! let polygon = PolygonView::new(ring, vec![]);
+//! assert_eq!(polygon.unsigned_area(), 12.);
+//! assert!(polygon.contains_coord(Coordinate { x: 1., y: 1. }));
+//! ```
+
+use std::ops::Add;
+
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_lines, CoordPos};
+use crate::algorithm::simplify::rdp;
+use crate::{Coordinate, GeoFloat, Line, LineString, Rect};
+
+/// A line string whose coordinates are borrowed from separate slices of `x` and `y`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineStringView<'a, T: GeoFloat> {
+    xs: &'a [T],
+    ys: &'a [T],
+}
+
+impl<'a, T: GeoFloat> LineStringView<'a, T> {
+    /// A view of the coordinates `(xs[i], ys[i])`, or `None` if the slices' lengths differ.
+    pub fn new(xs: &'a [T], ys: &'a [T]) -> Option<Self> {
+        if xs.len() == ys.len() {
+            Some(LineStringView { xs, ys })
+        } else {
+            None
+        }
+    }
+
+    pub fn xs(&self) -> &'a [T] {
+        self.xs
+    }
+
+    pub fn ys(&self) -> &'a [T] {
+        self.ys
+    }
+
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    pub fn coord(&self, index: usize) -> Coordinate<T> {
+        Coordinate {
+            x: self.xs[index],
+            y: self.ys[index],
+        }
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coordinate<T>> + 'a {
+        let (xs, ys) = (self.xs, self.ys);
+        xs.iter().zip(ys).map(|(&x, &y)| Coordinate { x, y })
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Line<T>> + 'a {
+        let (xs, ys) = (self.xs, self.ys);
+        xs.windows(2).zip(ys.windows(2)).map(|(x, y)| {
+            Line::new(
+                Coordinate { x: x[0], y: y[0] },
+                Coordinate { x: x[1], y: y[1] },
+            )
+        })
+    }
+
+    /// The smallest [`Rect`] containing the coordinates, or `None` if there are none.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        let (min_x, max_x) = min_max(self.xs)?;
+        let (min_y, max_y) = min_max(self.ys)?;
+        Some(Rect::new(
+            Coordinate { x: min_x, y: min_y },
+            Coordinate { x: max_x, y: max_y },
+        ))
+    }
+
+    /// The sum of the lengths of the segments, as
+    /// [`EuclideanLength`](crate::algorithm::euclidean_length::EuclideanLength) calculates.
+    ///
+    /// Unlike it, this takes the square root of the sum of squares, rather than calling `hypot`,
+    /// which vectorizes, but may overflow for segments longer than the square root of `T::MAX`.
+    pub fn euclidean_length(&self) -> T {
+        sum_segments(self.xs, self.ys, T::zero(), |x0, y0, x1, y1| {
+            let (dx, dy) = (x1 - x0, y1 - y0);
+            (dx * dx + dy * dy).sqrt()
+        })
+    }
+
+    /// The signed area of the ring through the coordinates, as
+    /// [`Area`](crate::algorithm::area::Area) calculates: positive if it runs
+    /// counter-clockwise. The ring is closed, if it isn't already.
+    pub fn signed_area(&self) -> T {
+        let twice = sum_segments(self.xs, self.ys, T::zero(), |x0, y0, x1, y1| {
+            x0 * y1 - x1 * y0
+        });
+        let closing = match (self.xs.first(), self.xs.last()) {
+            (Some(&x0), Some(&x1)) => x1 * self.ys[0] - x0 * self.ys[self.ys.len() - 1],
+            _ => T::zero(),
+        };
+        (twice + closing) / (T::one() + T::one())
+    }
+
+    /// Simplify the line with the Ramer–Douglas–Peucker algorithm, as
+    /// [`Simplify`](crate::algorithm::simplify::Simplify) does.
+    pub fn simplify(&self, epsilon: &T) -> LineString<T> {
+        LineString(rdp(self.coords(), epsilon))
+    }
+
+    // The position of `coord` relative to the ring through the coordinates, which is closed if
+    // it isn't already.
+    fn coord_pos_relative_to_ring(&self, coord: Coordinate<T>) -> CoordPos {
+        match self.len() {
+            0 => CoordPos::Outside,
+            1 if self.coord(0) == coord => CoordPos::OnBoundary,
+            1 => CoordPos::Outside,
+            len => {
+                let closing = Line::new(self.coord(len - 1), self.coord(0));
+                coord_pos_relative_to_lines(coord, self.lines().chain(Some(closing)))
+            }
+        }
+    }
+}
+
+/// A polygon whose rings are [`LineStringView`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonView<'a, T: GeoFloat> {
+    exterior: LineStringView<'a, T>,
+    interiors: Vec<LineStringView<'a, T>>,
+}
+
+impl<'a, T: GeoFloat> PolygonView<'a, T> {
+    pub fn new(exterior: LineStringView<'a, T>, interiors: Vec<LineStringView<'a, T>>) -> Self {
+        PolygonView {
+            exterior,
+            interiors,
+        }
+    }
+
+    /// A view of the polygon whose rings run between consecutive `ring_offsets` into `xs` and
+    /// `ys`, as in GeoArrow's layout: the exterior from `ring_offsets[0]` to `ring_offsets[1]`,
+    /// then each interior ring.
+    ///
+    /// Returns `None` if the slices' lengths differ, there are fewer than two offsets, or an
+    /// offset is out of order or bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::coord_slices::PolygonView;
+    /// use geo::Coordinate;
+    ///
+    /// // a square, with a square hole
+    /// let xs = [0., 4., 4., 0., 0., 1., 1., 2., 2., 1.];
+    /// let ys = [0., 0., 4., 4., 0., 1., 2., 2., 1., 1.];
+    /// let polygon = PolygonView::from_ring_offsets(&xs, &ys, &[0, 5, 10]).unwrap();
+    /// assert_eq!(polygon.unsigned_area(), 15.);
+    /// assert!(!polygon.contains_coord(Coordinate { x: 1.5, y: 1.5 }));
+    /// ```
+    pub fn from_ring_offsets(xs: &'a [T], ys: &'a [T], ring_offsets: &[usize]) -> Option<Self> {
+        let view = LineStringView::new(xs, ys)?;
+        if ring_offsets.len() < 2 {
+            return None;
+        }
+        let mut rings = ring_offsets
+            .windows(2)
+            .map(|offsets| {
+                let (start, end) = (offsets[0], offsets[1]);
+                if start <= end && end <= view.len() {
+                    Some(LineStringView {
+                        xs: &xs[start..end],
+                        ys: &ys[start..end],
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let exterior = rings.remove(0);
+        Some(PolygonView::new(exterior, rings))
+    }
+
+    pub fn exterior(&self) -> &LineStringView<'a, T> {
+        &self.exterior
+    }
+
+    pub fn interiors(&self) -> &[LineStringView<'a, T>] {
+        &self.interiors
+    }
+
+    /// The area of the exterior ring, less those of the interior rings.
+    pub fn unsigned_area(&self) -> T {
+        self.interiors
+            .iter()
+            .fold(self.exterior.signed_area().abs(), |area, ring| {
+                area - ring.signed_area().abs()
+            })
+    }
+
+    /// Whether `coord` lies inside, outside, or on the boundary of the polygon, as
+    /// [`CoordinatePosition`](crate::algorithm::coordinate_position::CoordinatePosition)
+    /// finds.
+    pub fn coordinate_position(&self, coord: Coordinate<T>) -> CoordPos {
+        match self.exterior.coord_pos_relative_to_ring(coord) {
+            CoordPos::Inside => {}
+            position => return position,
+        }
+        for ring in &self.interiors {
+            match ring.coord_pos_relative_to_ring(coord) {
+                CoordPos::Outside => {}
+                CoordPos::OnBoundary => return CoordPos::OnBoundary,
+                CoordPos::Inside => return CoordPos::Outside,
+            }
+        }
+        CoordPos::Inside
+    }
+
+    /// Whether `coord` lies within the polygon, and not on its boundary, as
+    /// [`Contains`](crate::algorithm::contains::Contains) finds.
+    pub fn contains_coord(&self, coord: Coordinate<T>) -> bool {
+        self.coordinate_position(coord) == CoordPos::Inside
+    }
+}
+
+// The number of accumulators the chunked loops keep: enough to fill a 256-bit vector register
+// with `f32`s, or two with `f64`s.
+const LANES: usize = 8;
+
+// The least and greatest of `values`, or `None` if there are none, over chunks which the
+// compiler can vectorize.
+pub(crate) fn min_max<T: GeoFloat>(values: &[T]) -> Option<(T, T)> {
+    let first = *values.first()?;
+    let (mut mins, mut maxs) = ([first; LANES], [first; LANES]);
+    let mut chunks = values.chunks_exact(LANES);
+    for chunk in chunks.by_ref() {
+        for lane in 0..LANES {
+            mins[lane] = mins[lane].min(chunk[lane]);
+            maxs[lane] = maxs[lane].max(chunk[lane]);
+        }
+    }
+    let fold = |lanes: &[T], pick: fn(T, T) -> T| lanes.iter().fold(first, |a, b| pick(a, *b));
+    let (min, max) = (fold(&mins, T::min), fold(&maxs, T::max));
+    Some((
+        fold(chunks.remainder(), T::min).min(min),
+        fold(chunks.remainder(), T::max).max(max),
+    ))
+}
+
+// Sum `f` of the start and end of each segment through the coordinates, with a separate
+// accumulator for each lane of a chunk, so the compiler can vectorize the loop.
+pub(crate) fn sum_segments<T, S, F>(xs: &[T], ys: &[T], zero: S, f: F) -> S
+where
+    T: GeoFloat,
+    S: Copy + Add<Output = S>,
+    F: Fn(T, T, T, T) -> S,
+{
+    if xs.len() < 2 {
+        return zero;
+    }
+    let segments = xs.len() - 1;
+    let (x0, y0, x1, y1) = (&xs[..segments], &ys[..segments], &xs[1..], &ys[1..]);
+    let mut lanes = [zero; LANES];
+    let chunked = segments - segments % LANES;
+    for start in (0..chunked).step_by(LANES) {
+        let (x0, y0) = (&x0[start..start + LANES], &y0[start..start + LANES]);
+        let (x1, y1) = (&x1[start..start + LANES], &y1[start..start + LANES]);
+        for lane in 0..LANES {
+            lanes[lane] = lanes[lane] + f(x0[lane], y0[lane], x1[lane], y1[lane]);
+        }
+    }
+    (chunked..segments).fold(lanes.iter().fold(zero, |a, b| a + *b), |sum, i| {
+        sum + f(x0[i], y0[i], x1[i], y1[i])
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::coordinate_position::CoordinatePosition;
+    use crate::algorithm::simplify::Simplify;
+    use crate::{polygon, Polygon};
+
+    #[test]
+    fn matches_geometry_algorithms() {
+        let polygon: Polygon<f64> = polygon![
+            exterior: [
+                (x: 0., y: 0.), (x: 3., y: 0.1), (x: 6., y: 0.), (x: 6., y: 6.),
+                (x: 3., y: 5.9), (x: 0., y: 6.),
+            ],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.)]],
+        ];
+        let columns = |ring: &LineString<f64>| -> (Vec<f64>, Vec<f64>) {
+            ring.0.iter().map(|coord| (coord.x, coord.y)).unzip()
+        };
+        let (exterior_xs, exterior_ys) = columns(polygon.exterior());
+        let (interior_xs, interior_ys) = columns(&polygon.interiors()[0]);
+        let exterior = LineStringView::new(&exterior_xs, &exterior_ys).unwrap();
+        let view = PolygonView::new(
+            exterior,
+            vec![LineStringView::new(&interior_xs, &interior_ys).unwrap()],
+        );
+
+        assert_eq!(view.unsigned_area(), polygon.unsigned_area());
+        assert_eq!(exterior.simplify(&0.5), polygon.exterior().simplify(&0.5));
+        for i in 0..=14 {
+            for j in 0..=14 {
+                let coord = Coordinate {
+                    x: i as f64 * 0.5 - 0.5,
+                    y: j as f64 * 0.5 - 0.5,
+                };
+                assert_eq!(
+                    view.coordinate_position(coord),
+                    polygon.coordinate_position(&coord),
+                    "{:?}",
+                    coord
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_slices() {
+        let (xs, ys) = ([0., 1., 1.], [0., 0.]);
+        assert_eq!(LineStringView::new(&xs, &ys), None);
+        let ys = [0., 0., 1.];
+        assert_eq!(PolygonView::from_ring_offsets(&xs, &ys, &[0]), None);
+        assert_eq!(PolygonView::from_ring_offsets(&xs, &ys, &[0, 4]), None);
+        assert_eq!(PolygonView::from_ring_offsets(&xs, &ys, &[2, 1]), None);
+
+        // an open ring is closed
+        let triangle = PolygonView::from_ring_offsets(&xs, &ys, &[0, 3]).unwrap();
+        assert_eq!(triangle.unsigned_area(), 0.5);
+        assert_eq!(
+            triangle.coordinate_position(Coordinate { x: 0.5, y: 0.5 }),
+            CoordPos::OnBoundary
+        );
+        assert!(triangle.contains_coord(Coordinate { x: 0.9, y: 0.5 }));
+        assert_eq!(
+            triangle.exterior().bounding_rect(),
+            Some(Rect::new((0., 0.), (1., 1.)))
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use crate::algorithm::{
-    bounding_rect::BoundingRect, dimensions::HasDimensions, intersects::Intersects,
+    bounding_rect::BoundingRect,
+    dimensions::HasDimensions,
+    intersects::Intersects,
+    kernels::{Kernel, Orientation},
 };
+use crate::utils::lex_cmp;
 use crate::{
     Coordinate, GeoNum, Geometry, GeometryCollection, GeometryCow, Line, LineString,
     MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 
 /// The position of a `Coordinate` relative to a `Geometry`
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum CoordPos {
     OnBoundary,
@@ -46,9 +51,12 @@ pub trait CoordinatePosition {
         // application of the ‘mod 2’ union rule”
         //
         // ― OpenGIS Simple Feature Access § 6.1.15.1
+        //
+        // A coordinate on the boundaries of an even number of components isn't on the boundary of
+        // the whole, but it's still part of the geometry, so it's on the interior.
         if boundary_count % 2 == 1 {
             CoordPos::OnBoundary
-        } else if is_inside {
+        } else if is_inside || boundary_count > 0 {
             CoordPos::Inside
         } else {
             CoordPos::Outside
@@ -137,14 +145,14 @@ where
         is_inside: &mut bool,
         boundary_count: &mut usize,
     ) {
-        if self.0.len() < 2 {
-            debug_assert!(false, "invalid line string with less than 2 coords");
+        if self.0.is_empty() {
             return;
         }
 
-        if self.0.len() == 2 {
-            // line string with two coords is just a line
-            Line::new(self.0[0], self.0[1]).calculate_coordinate_position(
+        if self.0.len() <= 2 {
+            // line string with two coords is just a line, and one with a single coord is a
+            // degenerate line
+            Line::new(self.0[0], *self.0.last().unwrap()).calculate_coordinate_position(
                 coord,
                 is_inside,
                 boundary_count,
@@ -186,9 +194,31 @@ where
         is_inside: &mut bool,
         boundary_count: &mut usize,
     ) {
-        // PERF TODO: I'm sure there's a better way to calculate than converting to a polygon
-        self.to_polygon()
-            .calculate_coordinate_position(coord, is_inside, boundary_count);
+        let vertices = [self.0, self.1, self.2];
+        let orientation = T::Ker::orient2d(self.0, self.1, self.2);
+        if orientation == Orientation::Collinear {
+            // a degenerate triangle is a line between its extreme vertices, or a point
+            let start = vertices.iter().min_by(|a, b| lex_cmp(a, b)).unwrap();
+            let end = vertices.iter().max_by(|a, b| lex_cmp(a, b)).unwrap();
+            Line::new(*start, *end).calculate_coordinate_position(coord, is_inside, boundary_count);
+            return;
+        }
+
+        let mut is_on_edge = false;
+        for i in 0..3 {
+            match T::Ker::orient2d(vertices[i], vertices[(i + 1) % 3], *coord) {
+                Orientation::Collinear => is_on_edge = true,
+                side if side != orientation => return,
+                _ => {}
+            }
+        }
+
+        // the coord is on the same side of every edge as the triangle's interior, or on an edge
+        if is_on_edge {
+            *boundary_count += 1;
+        } else {
+            *is_inside = true;
+        }
     }
 }
 
@@ -203,9 +233,22 @@ where
         is_inside: &mut bool,
         boundary_count: &mut usize,
     ) {
-        // PERF TODO: I'm sure there's a better way to calculate than converting to a polygon
-        self.to_polygon()
-            .calculate_coordinate_position(coord, is_inside, boundary_count);
+        let (min, max) = (self.min(), self.max());
+        if min.x == max.x || min.y == max.y {
+            // a degenerate rect is a line, or a point
+            Line::new(min, max).calculate_coordinate_position(coord, is_inside, boundary_count);
+            return;
+        }
+
+        if coord.x < min.x || coord.x > max.x || coord.y < min.y || coord.y > max.y {
+            return;
+        }
+
+        if coord.x == min.x || coord.x == max.x || coord.y == min.y || coord.y == max.y {
+            *boundary_count += 1;
+        } else {
+            *is_inside = true;
+        }
     }
 }
 
@@ -373,9 +416,19 @@ where
             CoordPos::Outside
         };
     }
+    coord_pos_relative_to_lines(coord, linestring.lines())
+}
 
+// The position of a `Coordinate` relative to the ring made by `lines`, which join up.
+pub(crate) fn coord_pos_relative_to_lines<T>(
+    coord: Coordinate<T>,
+    lines: impl Iterator<Item = Line<T>>,
+) -> CoordPos
+where
+    T: GeoNum,
+{
     let mut crossings = 0;
-    for line in linestring.lines() {
+    for line in lines {
         // Check if coord lies on the line
         if line.intersects(&coord) {
             return CoordPos::OnBoundary;
@@ -650,11 +703,12 @@ mod test {
             CoordPos::OnBoundary
         );
 
-        // in boundary of first and second, so considered *not* in the boundary by mod 2 rule
+        // in boundary of first and second, so considered *not* in the boundary by mod 2 rule, but
+        // still in the interior
         let shared_start = Coordinate::zero();
         assert_eq!(
             multi_line_string.coordinate_position(&shared_start),
-            CoordPos::Outside
+            CoordPos::Inside
         );
 
         // *in* the first line, on the boundary of the third line
@@ -730,10 +784,106 @@ mod test {
             CoordPos::OnBoundary
         );
 
-        //  boundary of both
+        //  boundary of both, so not in the boundary by mod 2 rule, but still in the interior
         assert_eq!(
             collection.coordinate_position(&Coordinate { x: 5.0, y: 10.0 }),
+            CoordPos::Inside
+        );
+    }
+
+    #[test]
+    fn test_degenerate_rect_and_triangle() {
+        let line_rect = Rect::new((0.0, 0.0), (0.0, 10.0));
+        let line_triangle = Triangle((0.0, 5.0).into(), (0.0, 10.0).into(), (0.0, 0.0).into());
+        for geometry in &[Geometry::from(line_rect), Geometry::from(line_triangle)] {
+            assert_eq!(
+                geometry.coordinate_position(&Coordinate { x: 0.0, y: 5.0 }),
+                CoordPos::Inside
+            );
+            assert_eq!(
+                geometry.coordinate_position(&Coordinate { x: 0.0, y: 0.0 }),
+                CoordPos::OnBoundary
+            );
+            assert_eq!(
+                geometry.coordinate_position(&Coordinate { x: 0.0, y: 10.0 }),
+                CoordPos::OnBoundary
+            );
+            assert_eq!(
+                geometry.coordinate_position(&Coordinate { x: 1.0, y: 5.0 }),
+                CoordPos::Outside
+            );
+        }
+
+        let point_rect = Rect::new((1.0, 1.0), (1.0, 1.0));
+        assert_eq!(
+            point_rect.coordinate_position(&Coordinate { x: 1.0, y: 1.0 }),
+            CoordPos::Inside
+        );
+    }
+
+    #[test]
+    fn test_short_line_strings() {
+        let empty: LineString<f64> = LineString(vec![]);
+        assert_eq!(
+            empty.coordinate_position(&Coordinate::zero()),
             CoordPos::Outside
         );
+
+        let single = line_string![(x: 1.0, y: 1.0)];
+        assert_eq!(
+            single.coordinate_position(&Coordinate { x: 1.0, y: 1.0 }),
+            CoordPos::Inside
+        );
+        assert_eq!(
+            single.coordinate_position(&Coordinate::zero()),
+            CoordPos::Outside
+        );
+    }
+
+    #[test]
+    fn test_agrees_with_relate() {
+        use crate::algorithm::relate::Relate;
+        use crate::dimensions::Dimensions;
+
+        let geometries: Vec<Geometry<f64>> = vec![
+            line_string![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0)].into(),
+            MultiLineString(vec![
+                line_string![(x: 0.0, y: 0.0), (x: 2.0, y: 2.0)],
+                line_string![(x: 2.0, y: 2.0), (x: 4.0, y: 0.0)],
+            ])
+            .into(),
+            Rect::new((0.0, 0.0), (4.0, 4.0)).into(),
+            Triangle((0.0, 0.0).into(), (4.0, 0.0).into(), (0.0, 4.0).into()).into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                point!(x: 3.0, y: 3.0).into(),
+                line_string![(x: 0.0, y: 0.0), (x: 0.0, y: 4.0)].into(),
+            ])),
+        ];
+
+        for geometry in &geometries {
+            for x in 0..=8 {
+                for y in 0..=8 {
+                    let coord = Coordinate {
+                        x: x as f64 / 2.0,
+                        y: y as f64 / 2.0,
+                    };
+                    let matrix = Geometry::Point(Point(coord)).relate(geometry);
+                    let expected = [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside]
+                        .iter()
+                        .copied()
+                        .find(|position| {
+                            matrix.get(CoordPos::Inside, *position) != Dimensions::Empty
+                        })
+                        .unwrap();
+                    assert_eq!(
+                        geometry.coordinate_position(&coord),
+                        expected,
+                        "{:?} {:?}",
+                        geometry,
+                        coord
+                    );
+                }
+            }
+        }
     }
 }
@@ -1,11 +1,13 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 use crate::{
     CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
     MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 
-use std::{fmt, iter, marker, slice};
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::{fmt, iter, marker, slice};
 
 type CoordinateChainOnce<T> = iter::Chain<iter::Once<Coordinate<T>>, iter::Once<Coordinate<T>>>;
 
@@ -0,0 +1,224 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::arrangement::Arrangement;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::algorithm::segment::midpoint;
+use crate::{GeoFloat, Polygon};
+
+/// Where two or more polygons of a coverage overlap.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageOverlap<T: GeoFloat> {
+    pub polygon: Polygon<T>,
+    /// The indices of the polygons which overlap here, in ascending order
+    pub indices: Vec<usize>,
+}
+
+/// The problems found by [`validate_coverage`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport<T: GeoFloat> {
+    pub overlaps: Vec<CoverageOverlap<T>>,
+    /// Narrow areas between neighbouring polygons which none of them cover
+    pub gaps: Vec<Polygon<T>>,
+}
+
+impl<T: GeoFloat> CoverageReport<T> {
+    /// Whether no overlaps or gaps were found.
+    pub fn is_valid(&self) -> bool {
+        self.overlaps.is_empty() && self.gaps.is_empty()
+    }
+}
+
+/// Check that a layer of polygons, such as parcels or administrative areas, forms a clean
+/// coverage: one whose polygons meet along shared edges without overlapping, or leaving gaps.
+///
+/// The rings of all of the polygons are noded together, and each face of the resulting
+/// arrangement is checked against the polygons on either side of its edges. A face covered by
+/// more than one polygon is reported as an overlap, however small. A face covered by none is a
+/// gap if it lies between two or more polygons, and is narrower than `gap_width`, its width being
+/// estimated as twice its area over its perimeter. So a sliver between two parcels digitized
+/// separately is a gap, but a lake deliberately left uncovered isn't.
+///
+/// Coverages are usually validated like this before their polygons are dissolved, or their
+/// shared edges simplified, which both assume a clean coverage.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::coverage::validate_coverage;
+/// use geo::polygon;
+///
+/// let parcels = vec![
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     // overlapping the first parcel by 0.1
+///     polygon![(x: 9.9, y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 9.9, y: 10.)],
+/// ];
+/// let report = validate_coverage(&parcels, 0.5);
+/// assert_eq!(report.overlaps.len(), 1);
+/// assert_eq!(report.overlaps[0].indices, vec![0, 1]);
+///
+/// let parcels = vec![
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     // leaving a gap of 0.1 after the first parcel
+///     polygon![(x: 10.1, y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 10.1, y: 10.)],
+///     // closing the gap at either end
+///     polygon![(x: 0., y: 10.), (x: 20., y: 10.), (x: 20., y: 20.), (x: 0., y: 20.)],
+///     polygon![(x: 0., y: -10.), (x: 20., y: -10.), (x: 20., y: 0.), (x: 0., y: 0.)],
+/// ];
+/// let report = validate_coverage(&parcels, 0.5);
+/// assert!(report.overlaps.is_empty());
+/// assert_eq!(report.gaps.len(), 1);
+///
+/// // once the gap is closed, the coverage is clean
+/// let mut parcels = parcels;
+/// parcels[1] = polygon![(x: 10., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 10., y: 10.)];
+/// assert!(validate_coverage(&parcels, 0.5).is_valid());
+/// ```
+pub fn validate_coverage<T: GeoFloat>(polygons: &[Polygon<T>], gap_width: T) -> CoverageReport<T> {
+    let segments = polygons.iter().enumerate().flat_map(|(index, polygon)| {
+        let polygon = polygon.orient(Direction::Default);
+        let lines: Vec<_> = std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .flat_map(|ring| ring.lines())
+            .map(|line| (line, index))
+            .collect();
+        lines
+    });
+    let arrangement = Arrangement::new(segments);
+    let bounding_rects: Vec<_> = polygons
+        .iter()
+        .map(|polygon| polygon.bounding_rect())
+        .collect();
+
+    let mut report = CoverageReport {
+        overlaps: vec![],
+        gaps: vec![],
+    };
+    for face in arrangement.faces() {
+        // which side of each polygon's boundary the face lies on, from an edge of the face
+        let half_edge = face.half_edges[0];
+        let mut along = vec![0isize; polygons.len()];
+        let mut is_along = vec![false; polygons.len()];
+        for (index, same_way) in arrangement.sides(half_edge) {
+            along[index] += if same_way { 1 } else { -1 };
+            is_along[index] = true;
+        }
+        let midpoint = midpoint(arrangement.line(half_edge));
+        let indices: Vec<usize> = (0..polygons.len())
+            .filter(|index| {
+                if is_along[*index] {
+                    return along[*index] > 0;
+                }
+                match bounding_rects[*index] {
+                    Some(rect) if rect.intersects(&midpoint) => {
+                        polygons[*index].coordinate_position(&midpoint) == CoordPos::Inside
+                    }
+                    _ => false,
+                }
+            })
+            .collect();
+
+        if indices.len() > 1 {
+            report.overlaps.push(CoverageOverlap {
+                polygon: face.polygon,
+                indices,
+            });
+        } else if indices.is_empty() {
+            let mut neighbours: Vec<usize> = face
+                .half_edges
+                .iter()
+                .flat_map(|half_edge| arrangement.sides(*half_edge))
+                .map(|(index, _)| index)
+                .collect();
+            neighbours.sort_unstable();
+            neighbours.dedup();
+            let perimeter = std::iter::once(face.polygon.exterior())
+                .chain(face.polygon.interiors())
+                .flat_map(|ring| ring.lines())
+                .fold(T::zero(), |length, line| {
+                    length + line.dx().hypot(line.dy())
+                });
+            let width = (T::one() + T::one()) * face.polygon.unsigned_area() / perimeter;
+            if neighbours.len() > 1 && width < gap_width {
+                report.gaps.push(face.polygon);
+            }
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Rect};
+
+    #[test]
+    fn clean_coverage() {
+        // a grid of squares, and one filling a hole in another
+        let holey = polygon![
+            exterior: [(x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 0.5, y: 2.5), (x: 1.5, y: 2.5), (x: 1.5, y: 3.5), (x: 0.5, y: 3.5)]],
+        ];
+        let polygons = vec![
+            Rect::new((0., 0.), (1., 1.)).to_polygon_ccw(),
+            Rect::new((1., 0.), (2., 1.)).to_polygon_ccw(),
+            polygon![(x: 0., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            holey,
+            Rect::new((0.5, 2.5), (1.5, 3.5)).to_polygon_ccw(),
+        ];
+        assert!(validate_coverage(&polygons, 1.).is_valid());
+    }
+
+    #[test]
+    fn overlaps() {
+        let polygons = vec![
+            Rect::new((0., 0.), (2., 2.)).to_polygon_ccw(),
+            Rect::new((1., 1.), (3., 3.)).to_polygon_ccw(),
+            Rect::new((0.5, 0.5), (1.5, 1.5)).to_polygon_ccw(),
+        ];
+        let report = validate_coverage(&polygons, 1.);
+        assert!(report.gaps.is_empty());
+        let mut overlaps: Vec<(f64, Vec<usize>)> = report
+            .overlaps
+            .iter()
+            .map(|overlap| (overlap.polygon.unsigned_area(), overlap.indices.clone()))
+            .collect();
+        overlaps.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            overlaps,
+            vec![
+                (0.25, vec![0, 1, 2]),
+                (0.75, vec![0, 1]),
+                (0.75, vec![0, 2])
+            ]
+        );
+    }
+
+    #[test]
+    fn gaps() {
+        // a sliver between two squares, and a wider gap
+        let polygons = vec![
+            Rect::new((0., 0.), (2., 2.)).to_polygon_ccw(),
+            polygon![(x: 2.1, y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2.1, y: 2.)],
+            polygon![(x: 0., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            polygon![(x: 0., y: -2.), (x: 4., y: -2.), (x: 4., y: 0.), (x: 0., y: 0.)],
+            polygon![(x: 4., y: -2.), (x: 8., y: -2.), (x: 8., y: 4.), (x: 4., y: 4.)],
+        ];
+        let report = validate_coverage(&polygons, 0.5);
+        assert!(report.overlaps.is_empty());
+        assert_eq!(report.gaps.len(), 1);
+        assert_relative_eq!(report.gaps[0].unsigned_area(), 0.2);
+
+        assert!(validate_coverage(&polygons, 0.05).is_valid());
+
+        // a hole in a single polygon isn't a gap
+        let holey = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1.1, y: 1.), (x: 1.1, y: 3.), (x: 1., y: 3.)]],
+        ];
+        assert!(validate_coverage(&[holey], 1.).is_valid());
+    }
+}
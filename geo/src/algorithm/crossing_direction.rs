@@ -0,0 +1,289 @@
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coordinate, GeoFloat, Line, LineString};
+
+/// Which way a line crosses a `LineString`, looking along the `LineString`.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CrossingDirection {
+    /// The line crosses from the left of the `LineString` to its right
+    LeftToRight,
+    /// The line crosses from the right of the `LineString` to its left
+    RightToLeft,
+}
+
+/// A point where a line crosses a `LineString`.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crossing<T: GeoFloat> {
+    /// Where the line crosses
+    pub coord: Coordinate<T>,
+    /// Which way the line crosses
+    pub direction: CrossingDirection,
+    /// The index of the segment of the `LineString` which is crossed
+    pub segment_index: usize,
+}
+
+/// Find where, and which way, a line segment crosses a geometry.
+///
+/// A closed ring wound counter-clockwise has its interior on its left, so a crossing from
+/// [`RightToLeft`](CrossingDirection::RightToLeft) enters it, and one from
+/// [`LeftToRight`](CrossingDirection::LeftToRight) leaves it, as when a track enters or leaves a
+/// geofence.
+///
+/// The crossings are decided with the orientation kernel, as if the geometry had been moved an
+/// infinitesimal distance in the direction of the x axis. So nothing lies exactly on the
+/// geometry: a line passing through a vertex crosses once, rather than once for each of the
+/// segments meeting there, and a line ending on the geometry has crossed it if and only if a
+/// line starting there doesn't, so consecutive segments of a track count each crossing exactly
+/// once. A line which only touches the geometry, or runs along it, crosses it either twice in
+/// opposite directions, or not at all.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::crossing_direction::{CrossingDirection, Crossings};
+/// use geo::{line_string, Coordinate, Line};
+///
+/// // a fence running north
+/// let fence = line_string![(x: 0., y: 0.), (x: 0., y: 10.)];
+///
+/// // west is on its left, so a track heading east crosses from left to right
+/// let track = Line::new((-1., 5.), (1., 5.));
+/// let crossings = fence.crossings(&track);
+/// assert_eq!(crossings.len(), 1);
+/// assert_eq!(crossings[0].direction, CrossingDirection::LeftToRight);
+/// assert_eq!(crossings[0].coord, Coordinate { x: 0., y: 5. });
+///
+/// // of two consecutive tracks meeting on the fence, only one crosses it
+/// let first = Line::new((-1., 5.), (0., 5.));
+/// let second = Line::new((0., 5.), (1., 5.));
+/// assert_eq!(fence.crossings(&first).len() + fence.crossings(&second).len(), 1);
+/// ```
+pub trait Crossings<T: GeoFloat> {
+    /// The crossings of `self` by `line`, in order along `line`.
+    fn crossings(&self, line: &Line<T>) -> Vec<Crossing<T>>;
+}
+
+impl<T: GeoFloat> Crossings<T> for Line<T> {
+    fn crossings(&self, line: &Line<T>) -> Vec<Crossing<T>> {
+        crossing(*self, *line)
+            .map(|(coord, direction)| Crossing {
+                coord,
+                direction,
+                segment_index: 0,
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> Crossings<T> for LineString<T> {
+    fn crossings(&self, line: &Line<T>) -> Vec<Crossing<T>> {
+        let mut crossings: Vec<Crossing<T>> = self
+            .lines()
+            .enumerate()
+            .filter_map(|(segment_index, segment)| {
+                crossing(segment, *line).map(|(coord, direction)| Crossing {
+                    coord,
+                    direction,
+                    segment_index,
+                })
+            })
+            .collect();
+        let from_start =
+            |crossing: &Crossing<T>| T::Ker::square_euclidean_distance(line.start, crossing.coord);
+        crossings.sort_by(|a, b| from_start(a).partial_cmp(&from_start(b)).unwrap());
+        crossings
+    }
+}
+
+// Where, and which way, `line` crosses `segment`, if it does.
+fn crossing<T: GeoFloat>(
+    segment: Line<T>,
+    line: Line<T>,
+) -> Option<(Coordinate<T>, CrossingDirection)> {
+    // the sides of the line which the ends of the segment lie on
+    let start_side = T::Ker::orient2d(line.start, line.end, segment.start);
+    let end_side = T::Ker::orient2d(line.start, line.end, segment.end);
+    let direction = match (
+        is_left_of_line(start_side, line.delta()),
+        is_left_of_line(end_side, line.delta()),
+    ) {
+        // the segment runs from the right of the line to its left, so the line runs from the
+        // left of the segment to its right
+        (false, true) => CrossingDirection::LeftToRight,
+        (true, false) => CrossingDirection::RightToLeft,
+        _ => return None,
+    };
+
+    // the sides of the segment which the ends of the line lie on
+    let line_start_side = T::Ker::orient2d(segment.start, segment.end, line.start);
+    let line_end_side = T::Ker::orient2d(segment.start, segment.end, line.end);
+    if is_left_of_segment(line_start_side, segment.delta())
+        == is_left_of_segment(line_end_side, segment.delta())
+    {
+        return None;
+    }
+
+    let coord = if start_side == Orientation::Collinear {
+        segment.start
+    } else if end_side == Orientation::Collinear {
+        segment.end
+    } else if line_start_side == Orientation::Collinear {
+        line.start
+    } else if line_end_side == Orientation::Collinear {
+        line.end
+    } else {
+        match line_intersection(segment, line) {
+            Some(LineIntersection::SinglePoint { intersection, .. }) => intersection,
+            _ => unreachable!("segments crossing properly intersect in a single point"),
+        }
+    };
+    Some((coord, direction))
+}
+
+// Whether a point of the geometry, with `orientation` relative to a line in direction `delta`,
+// is on the line's left, once the geometry has been moved an infinitesimal distance along the x
+// axis, and then a far smaller distance along the y axis.
+fn is_left_of_line<T: GeoFloat>(orientation: Orientation, delta: Coordinate<T>) -> bool {
+    match orientation {
+        Orientation::CounterClockwise => true,
+        Orientation::Clockwise => false,
+        Orientation::Collinear if delta.y != T::zero() => delta.y < T::zero(),
+        Orientation::Collinear => delta.x > T::zero(),
+    }
+}
+
+// Whether a point, with `orientation` relative to a segment of the geometry in direction
+// `delta`, is on the segment's left, once the geometry has been moved as for `is_left_of_line`.
+fn is_left_of_segment<T: GeoFloat>(orientation: Orientation, delta: Coordinate<T>) -> bool {
+    match orientation {
+        Orientation::CounterClockwise => true,
+        Orientation::Clockwise => false,
+        Orientation::Collinear if delta.y != T::zero() => delta.y > T::zero(),
+        Orientation::Collinear => delta.x < T::zero(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn directions(line_string: &LineString<f64>, line: Line<f64>) -> Vec<CrossingDirection> {
+        line_string
+            .crossings(&line)
+            .iter()
+            .map(|crossing| crossing.direction)
+            .collect()
+    }
+
+    #[test]
+    fn through_a_vertex() {
+        use CrossingDirection::*;
+        let zigzag = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.), (x: 3., y: 1.)];
+
+        // through the vertex at (1, 1), from below
+        let line = Line::new((1., -1.), (1., 2.));
+        let crossings = zigzag.crossings(&line);
+        assert_eq!(crossings.len(), 1);
+        assert_eq!(crossings[0].coord, Coordinate { x: 1., y: 1. });
+        assert_eq!(crossings[0].direction, RightToLeft);
+
+        assert_eq!(
+            directions(&zigzag, Line::new((-1., 0.5), (4., 0.5))),
+            vec![LeftToRight, RightToLeft, LeftToRight]
+        );
+        // touching the vertices at (0, 0) and (2, 0) from below
+        assert!(directions(&zigzag, Line::new((-1., 0.), (4., 0.))).is_empty());
+    }
+
+    #[test]
+    fn ends_on_the_linestring() {
+        let fence = line_string![(x: 0., y: 0.), (x: 0., y: 5.), (x: 0., y: 10.)];
+        for (x, y) in &[(0., 2.), (0., 5.), (0., 0.)] {
+            let point = Coordinate { x: *x, y: *y };
+            for (dx, dy) in &[(-1., 0.), (1., 0.), (1., 1.), (-1., -3.)] {
+                let other = Coordinate {
+                    x: point.x + dx,
+                    y: point.y + dy,
+                };
+                // a track through `point` crosses once in total, whichever way it goes...
+                let across = Line::new(
+                    other,
+                    Coordinate {
+                        x: point.x - dx,
+                        y: point.y - dy,
+                    },
+                );
+                let halves = fence.crossings(&Line::new(across.start, point)).len()
+                    + fence.crossings(&Line::new(point, across.end)).len();
+                assert_eq!(halves, fence.crossings(&across).len());
+                // ...and a track which touches the fence and turns back crosses an even number
+                // of times, unless it passes around the end of the fence
+                if point.y == 0. {
+                    continue;
+                }
+                let back = Coordinate {
+                    x: point.x + dx,
+                    y: point.y - dy,
+                };
+                let there = fence.crossings(&Line::new(other, point)).len();
+                let back = fence.crossings(&Line::new(point, back)).len();
+                assert_eq!((there + back) % 2, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn along_the_linestring() {
+        let fence = line_string![(x: 0., y: 0.), (x: 0., y: 10.)];
+        assert!(fence.crossings(&Line::new((0., 2.), (0., 4.))).is_empty());
+        assert!(fence.crossings(&Line::new((0., -2.), (0., 12.))).is_empty());
+        assert!(fence.crossings(&Line::new((1., 2.), (1., 2.))).is_empty());
+    }
+
+    #[test]
+    fn rings() {
+        use CrossingDirection::*;
+        // counter-clockwise, so entering crosses from right to left
+        let square = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let crossings = square.crossings(&Line::new((-1., 2.), (5., 2.)));
+        assert_eq!(
+            crossings
+                .iter()
+                .map(|crossing| (crossing.coord, crossing.direction, crossing.segment_index))
+                .collect::<Vec<_>>(),
+            vec![
+                (Coordinate { x: 0., y: 2. }, RightToLeft, 3),
+                (Coordinate { x: 4., y: 2. }, LeftToRight, 1),
+            ]
+        );
+        // through opposite corners
+        assert_eq!(
+            directions(&square, Line::new((-1., -1.), (5., 5.))),
+            vec![RightToLeft, LeftToRight]
+        );
+        assert_eq!(
+            directions(&square, Line::new((5., 5.), (-1., -1.))),
+            vec![RightToLeft, LeftToRight]
+        );
+
+        let line = Line::new((0., 0.), (0., 4.));
+        assert_eq!(
+            line.crossings(&Line::new((1., 1.), (-1., 1.))),
+            vec![Crossing {
+                coord: Coordinate { x: 0., y: 1. },
+                direction: RightToLeft,
+                segment_index: 0,
+            }]
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+use std::mem::{discriminant, Discriminant};
+
+use crate::{Coordinate, GeoFloat, Geometry, LineString, Polygon};
+
+/// Whether two geometries are the same up to a tolerance: each coordinate of one is within
+/// `tolerance` of the matching coordinate of the other, in both `x` and `y`.
+///
+/// Differences which don't change the geometry are ignored: a ring may start at any of its
+/// vertices and run either way round, a line string may run either way, and the members of a
+/// multi-geometry or collection, like the holes of a polygon, may come in any order. Geometries
+/// of different types are never equal.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dedup::equals_within;
+/// use geo::{polygon, Geometry};
+///
+/// let square: Geometry<f64> =
+///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)].into();
+/// // the same square, starting from another corner and running clockwise, slightly off
+/// let copy: Geometry<f64> =
+///     polygon![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.0001), (x: 0., y: 1.)].into();
+///
+/// assert!(equals_within(&square, &copy, 0.001));
+/// assert!(!equals_within(&square, &copy, 0.00001));
+/// ```
+pub fn equals_within<T: GeoFloat>(a: &Geometry<T>, b: &Geometry<T>, tolerance: T) -> bool {
+    let close = |a: &Coordinate<T>, b: &Coordinate<T>| {
+        (a.x - b.x).abs() <= tolerance && (a.y - b.y).abs() <= tolerance
+    };
+    let sequences = |a: &[Coordinate<T>], b: &[Coordinate<T>]| {
+        a.len() == b.len()
+            && (a.iter().zip(b).all(|(a, b)| close(a, b))
+                || a.iter().zip(b.iter().rev()).all(|(a, b)| close(a, b)))
+    };
+    let rings = |a: &LineString<T>, b: &LineString<T>| {
+        let (a, b) = (open_ring(a), open_ring(b));
+        if a.len() != b.len() {
+            return false;
+        }
+        if a.is_empty() {
+            return true;
+        }
+        (0..b.len()).any(|start| {
+            let forward =
+                (0..a.len()).all(|offset| close(&a[offset], &b[(start + offset) % b.len()]));
+            let backward = (0..a.len())
+                .all(|offset| close(&a[offset], &b[(start + b.len() - offset) % b.len()]));
+            forward || backward
+        })
+    };
+    let polygons = |a: &Polygon<T>, b: &Polygon<T>| {
+        rings(a.exterior(), b.exterior()) && matches(a.interiors(), b.interiors(), rings)
+    };
+
+    match (a, b) {
+        (Geometry::Point(a), Geometry::Point(b)) => close(&a.0, &b.0),
+        (Geometry::Line(a), Geometry::Line(b)) => sequences(&[a.start, a.end], &[b.start, b.end]),
+        (Geometry::LineString(a), Geometry::LineString(b)) => sequences(&a.0, &b.0),
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => polygons(a, b),
+        (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => {
+            matches(&a.0, &b.0, |a, b| close(&a.0, &b.0))
+        }
+        (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => {
+            matches(&a.0, &b.0, |a, b| sequences(&a.0, &b.0))
+        }
+        (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => matches(&a.0, &b.0, polygons),
+        (Geometry::Rect(a), Geometry::Rect(b)) => {
+            close(&a.min(), &b.min()) && close(&a.max(), &b.max())
+        }
+        (Geometry::Triangle(a), Geometry::Triangle(b)) => rings(
+            &LineString(a.to_array().to_vec()),
+            &LineString(b.to_array().to_vec()),
+        ),
+        (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+            matches(&a.0, &b.0, |a, b| equals_within(a, b, tolerance))
+        }
+        _ => false,
+    }
+}
+
+// Whether each item of `a` equals a different item of `b`, taking the first unmatched one.
+fn matches<I, F: Fn(&I, &I) -> bool>(a: &[I], b: &[I], equal: F) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut matched = vec![false; b.len()];
+    a.iter().all(
+        |a| match (0..b.len()).find(|index| !matched[*index] && equal(a, &b[*index])) {
+            Some(index) => {
+                matched[index] = true;
+                true
+            }
+            None => false,
+        },
+    )
+}
+
+// The coordinates of a ring, without the repeated closing coordinate.
+fn open_ring<T: GeoFloat>(ring: &LineString<T>) -> &[Coordinate<T>] {
+    match ring.0.len() {
+        len if len > 1 && ring.0[0] == ring.0[len - 1] => &ring.0[..len - 1],
+        _ => &ring.0,
+    }
+}
+
+/// Detect duplicates among geometries, up to a tolerance, as when merging overlapping data
+/// sources.
+///
+/// Each geometry added is compared, with [`equals_within`], against the unique geometries
+/// added before it which could be its duplicate: those of the same type and number of
+/// coordinates, and whose mean coordinate hashes to the same or a neighbouring cell of a grid
+/// as fine as the tolerance.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dedup::GeometryDeduper;
+/// use geo::{line_string, point, Geometry};
+///
+/// let mut deduper = GeometryDeduper::new(0.01);
+/// let road: Geometry<f64> = line_string![(x: 0., y: 0.), (x: 5., y: 5.)].into();
+/// assert_eq!(deduper.insert(road), None);
+///
+/// // the same road from another source, digitised the other way
+/// let reversed: Geometry<f64> = line_string![(x: 5.001, y: 5.), (x: 0., y: 0.)].into();
+/// assert_eq!(deduper.insert(reversed), Some(0));
+///
+/// let town: Geometry<f64> = point!(x: 2., y: 2.).into();
+/// assert_eq!(deduper.insert(town), None);
+/// assert_eq!(deduper.unique().len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeometryDeduper<T: GeoFloat> {
+    tolerance: T,
+    unique: Vec<Geometry<T>>,
+    cells: HashMap<CellKey<T>, Vec<usize>>,
+}
+
+// The type and number of coordinates of a geometry, and the grid cell of its mean coordinate.
+type CellKey<T> = (Discriminant<Geometry<T>>, usize, i64, i64);
+
+impl<T: GeoFloat> GeometryDeduper<T> {
+    /// With a `tolerance` of zero, geometries must match exactly, though still regardless of
+    /// where their rings start, or of the order of their members.
+    ///
+    /// # Panics
+    ///
+    /// If `tolerance` is negative or NaN.
+    pub fn new(tolerance: T) -> Self {
+        assert!(
+            tolerance >= T::zero(),
+            "deduplication tolerance must not be negative"
+        );
+        GeometryDeduper {
+            tolerance,
+            unique: vec![],
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Add a geometry, returning the index, among the unique geometries, of the one it
+    /// duplicates, or `None` if it's new, in which case it's added to the end of them.
+    pub fn insert(&mut self, geometry: Geometry<T>) -> Option<usize> {
+        let (kind, count, column, row) = self.cell_key(&geometry);
+        let reach = if self.tolerance > T::zero() { 1 } else { 0 };
+        for column in column.saturating_sub(reach)..=column.saturating_add(reach) {
+            for row in row.saturating_sub(reach)..=row.saturating_add(reach) {
+                let candidates = match self.cells.get(&(kind, count, column, row)) {
+                    Some(candidates) => candidates,
+                    None => continue,
+                };
+                for &index in candidates {
+                    if equals_within(&self.unique[index], &geometry, self.tolerance) {
+                        return Some(index);
+                    }
+                }
+            }
+        }
+        self.cells
+            .entry((kind, count, column, row))
+            .or_default()
+            .push(self.unique.len());
+        self.unique.push(geometry);
+        None
+    }
+
+    /// The unique geometries, in the order they were added.
+    pub fn unique(&self) -> &[Geometry<T>] {
+        &self.unique
+    }
+
+    pub fn into_unique(self) -> Vec<Geometry<T>> {
+        self.unique
+    }
+
+    fn cell_key(&self, geometry: &Geometry<T>) -> CellKey<T> {
+        let mut coords = vec![];
+        key_coords(geometry, &mut coords);
+        let kind = discriminant(geometry);
+        if coords.is_empty() || self.tolerance == T::zero() {
+            // without a grid, every candidate of the same type and size is compared
+            return (kind, coords.len(), 0, 0);
+        }
+        let count = T::from(coords.len()).unwrap();
+        let sum = coords
+            .iter()
+            .fold(Coordinate::zero(), |sum, coord| sum + *coord);
+        let cell = |value: T| {
+            (value / count / self.tolerance)
+                .floor()
+                .to_i64()
+                .unwrap_or(i64::MAX)
+        };
+        (kind, coords.len(), cell(sum.x), cell(sum.y))
+    }
+}
+
+// The coordinates which duplicates must match, whatever their order, with rings left open.
+fn key_coords<T: GeoFloat>(geometry: &Geometry<T>, coords: &mut Vec<Coordinate<T>>) {
+    let mut polygon = |polygon: &Polygon<T>| {
+        for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+            coords.extend_from_slice(open_ring(ring));
+        }
+    };
+    match geometry {
+        Geometry::Point(point) => coords.push(point.0),
+        Geometry::Line(line) => coords.extend_from_slice(&[line.start, line.end]),
+        Geometry::LineString(line_string) => coords.extend_from_slice(&line_string.0),
+        Geometry::Polygon(p) => polygon(p),
+        Geometry::MultiPoint(points) => coords.extend(points.iter().map(|point| point.0)),
+        Geometry::MultiLineString(lines) => {
+            for line_string in lines.iter() {
+                coords.extend_from_slice(&line_string.0);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => polygons.iter().for_each(polygon),
+        Geometry::Rect(rect) => coords.extend_from_slice(&[rect.min(), rect.max()]),
+        Geometry::Triangle(triangle) => coords.extend_from_slice(&triangle.to_array()),
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection.iter() {
+                key_coords(geometry, coords);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, GeometryCollection, MultiPolygon};
+
+    #[test]
+    fn rings_holes_and_members() {
+        let with_holes = |shift: f64| -> Geometry<f64> {
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 9., y: 0.), (x: 9., y: 9.), (x: 0., y: 9.)],
+                interiors: [
+                    [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)],
+                    [(x: 5. + shift, y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)],
+                ],
+            ]
+            .into()
+        };
+        let holes_swapped: Geometry<f64> = polygon![
+            exterior: [(x: 9., y: 9.), (x: 0., y: 9.), (x: 0., y: 0.), (x: 9., y: 0.)],
+            interiors: [
+                [(x: 6., y: 6.), (x: 6., y: 5.), (x: 5., y: 5.)],
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)],
+            ],
+        ]
+        .into();
+        assert!(equals_within(&with_holes(0.), &holes_swapped, 0.));
+        assert!(equals_within(&with_holes(0.05), &holes_swapped, 0.1));
+        assert!(!equals_within(&with_holes(0.5), &holes_swapped, 0.1));
+
+        // the same polygon, but as a member of a multi-polygon
+        let multi: Geometry<f64> = MultiPolygon(vec![match with_holes(0.) {
+            Geometry::Polygon(polygon) => polygon,
+            _ => unreachable!(),
+        }])
+        .into();
+        assert!(!equals_within(&with_holes(0.), &multi, 0.1));
+
+        let collection = |a: Geometry<f64>, b: Geometry<f64>| -> Geometry<f64> {
+            Geometry::GeometryCollection(GeometryCollection(vec![a, b]))
+        };
+        let point: Geometry<f64> = point!(x: 1., y: 1.).into();
+        let line: Geometry<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 0.)].into();
+        assert!(equals_within(
+            &collection(point.clone(), line.clone()),
+            &collection(line, point),
+            0.
+        ));
+    }
+
+    #[test]
+    fn deduper_across_cells() {
+        let mut deduper = GeometryDeduper::new(0.1);
+        // the mean coordinates of these points lie in neighbouring cells
+        assert_eq!(deduper.insert(point!(x: 0.99, y: 0.5).into()), None);
+        assert_eq!(deduper.insert(point!(x: 1.01, y: 0.5).into()), Some(0));
+        assert_eq!(deduper.insert(point!(x: 1.2, y: 0.5).into()), None);
+        // a different type, at the same place
+        let square: Geometry<f64> =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.), (x: 0., y: 1.)].into();
+        assert_eq!(deduper.insert(square.clone()), None);
+        assert_eq!(deduper.insert(square), Some(2));
+        assert_eq!(deduper.into_unique().len(), 3);
+
+        let mut exact = GeometryDeduper::new(0.);
+        assert_eq!(exact.insert(point!(x: 1., y: 1.).into()), None);
+        assert_eq!(exact.insert(point!(x: 1., y: 1.0001).into()), None);
+        assert_eq!(exact.insert(point!(x: 1., y: 1.).into()), Some(0));
+    }
+}
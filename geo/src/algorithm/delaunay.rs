@@ -0,0 +1,230 @@
+// The Delaunay triangulation of a set of points: the points are swept in lexicographic order,
+// each joined to the edges of the hull so far which it can see, and the edges of the resulting
+// triangulation then flipped until every triangle's circumcircle is empty, after Lawson.
+//
+// PERF: each point is checked against every edge of the hull so far, so the sweep is quadratic
+// in the worst case, as is the flipping.
+
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat};
+use num_traits::NumCast;
+use std::collections::HashMap;
+use std::iter;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Triangulation<T: GeoFloat> {
+    // the distinct points, in lexicographic order
+    pub points: Vec<Coordinate<T>>,
+    // the triangles, as indices into `points`, counter-clockwise
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<T: GeoFloat> Triangulation<T> {
+    pub fn delaunay<I: IntoIterator<Item = Coordinate<T>>>(coords: I) -> Self {
+        let mut points: Vec<Coordinate<T>> = coords.into_iter().collect();
+        points.sort_by(lex_cmp);
+        points.dedup();
+        let mut triangulation = Triangulation {
+            points,
+            triangles: vec![],
+        };
+        triangulation.sweep();
+        triangulation.flip();
+        triangulation
+    }
+
+    fn orientation(&self, a: usize, b: usize, c: usize) -> Orientation {
+        T::Ker::orient2d(self.points[a], self.points[b], self.points[c])
+    }
+
+    // Triangulate the points, with no regard for the shapes of the triangles.
+    fn sweep(&mut self) {
+        let count = self.points.len();
+        // the first point not in line with the first two
+        let apex = match (2..count).find(|k| self.orientation(0, 1, *k) != Orientation::Collinear) {
+            Some(apex) => apex,
+            None => return,
+        };
+
+        // fan out from the apex to the points in line before it
+        let is_left = self.orientation(0, 1, apex) == Orientation::CounterClockwise;
+        for index in 0..apex - 1 {
+            self.triangles.push(if is_left {
+                [index, index + 1, apex]
+            } else {
+                [index + 1, index, apex]
+            });
+        }
+        // counter-clockwise
+        let mut hull: Vec<usize> = if is_left {
+            (0..=apex).collect()
+        } else {
+            (0..apex).rev().chain(iter::once(apex)).collect()
+        };
+
+        // each later point is outside the hull so far, and sees a run of its edges
+        for point in apex + 1..count {
+            let len = hull.len();
+            let is_visible: Vec<bool> = (0..len)
+                .map(|index| {
+                    self.orientation(hull[index], hull[(index + 1) % len], point)
+                        == Orientation::Clockwise
+                })
+                .collect();
+            let first = (0..len)
+                .find(|index| is_visible[*index] && !is_visible[(*index + len - 1) % len])
+                .expect("a point outside the hull sees some of it");
+            hull.rotate_left(first);
+            let visible = is_visible[first..]
+                .iter()
+                .chain(&is_visible[..first])
+                .take_while(|is_visible| **is_visible)
+                .count();
+            for index in 0..visible {
+                self.triangles.push([hull[index + 1], hull[index], point]);
+            }
+            // the vertices between the visible edges are now inside the hull
+            hull.splice(1..visible, iter::once(point));
+        }
+    }
+
+    // Flip the edges whose opposite corners are inside each other's circumcircles, until there
+    // are none.
+    fn flip(&mut self) {
+        // the triangle to the left of each directed edge
+        let mut lefts: HashMap<(usize, usize), usize> = HashMap::new();
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            for corner in 0..3 {
+                lefts.insert((triangle[corner], triangle[(corner + 1) % 3]), index);
+            }
+        }
+
+        let mut edges: Vec<(usize, usize)> = lefts.keys().filter(|(a, b)| a < b).copied().collect();
+        while let Some((a, b)) = edges.pop() {
+            let (left, right) = match (lefts.get(&(a, b)), lefts.get(&(b, a))) {
+                (Some(left), Some(right)) => (*left, *right),
+                _ => continue,
+            };
+            let c = opposite(self.triangles[left], a, b);
+            let d = opposite(self.triangles[right], b, a);
+            if !self.is_in_circle([a, b, c], d) {
+                continue;
+            }
+
+            // the quadrilateral a, d, b, c is split along d to c instead
+            self.triangles[left] = [d, b, c];
+            self.triangles[right] = [c, a, d];
+            lefts.remove(&(a, b));
+            lefts.remove(&(b, a));
+            lefts.insert((d, b), left);
+            lefts.insert((c, d), left);
+            lefts.insert((c, a), right);
+            lefts.insert((d, c), right);
+            edges.extend(&[(a, d), (d, b), (b, c), (c, a)]);
+        }
+    }
+
+    // Whether `point` is strictly inside the circumcircle of the counter-clockwise `triangle`.
+    fn is_in_circle(&self, triangle: [usize; 3], point: usize) -> bool {
+        let coord = |index: usize| {
+            let coord = self.points[index];
+            robust::Coord {
+                x: <f64 as NumCast>::from(coord.x).unwrap(),
+                y: <f64 as NumCast>::from(coord.y).unwrap(),
+            }
+        };
+        robust::incircle(
+            coord(triangle[0]),
+            coord(triangle[1]),
+            coord(triangle[2]),
+            coord(point),
+        ) > 0.
+    }
+}
+
+// The corner of `triangle` after its edge from `a` to `b`.
+fn opposite(triangle: [usize; 3], a: usize, b: usize) -> usize {
+    *triangle
+        .iter()
+        .find(|corner| **corner != a && **corner != b)
+        .expect("a triangle has three corners")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::convex_hull::ConvexHull;
+    use crate::{MultiPoint, Point, Triangle};
+
+    fn triangles(triangulation: &Triangulation<f64>) -> Vec<Triangle<f64>> {
+        triangulation
+            .triangles
+            .iter()
+            .map(|[a, b, c]| {
+                let points = &triangulation.points;
+                Triangle(points[*a], points[*b], points[*c])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn circumcircles_are_empty() {
+        // scattered points, from a linear congruential generator
+        let mut seed: u64 = 7;
+        let mut random = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 11) as f64 / (1u64 << 53) as f64 * 100.
+        };
+        let coords: Vec<Coordinate<f64>> = (0..200)
+            .map(|_| Coordinate {
+                x: random(),
+                y: random(),
+            })
+            .collect();
+        let triangulation = Triangulation::delaunay(coords.clone());
+
+        for [a, b, c] in &triangulation.triangles {
+            assert_eq!(
+                triangulation.orientation(*a, *b, *c),
+                Orientation::CounterClockwise
+            );
+            for point in 0..triangulation.points.len() {
+                assert!(!triangulation.is_in_circle([*a, *b, *c], point));
+            }
+        }
+
+        // the triangles tile the convex hull
+        let hull = MultiPoint(coords.into_iter().map(Point).collect()).convex_hull();
+        let area: f64 = triangles(&triangulation)
+            .iter()
+            .map(|triangle| triangle.unsigned_area())
+            .sum();
+        assert_relative_eq!(area, hull.unsigned_area(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn points_in_line() {
+        // three in a line, then one off it, and one beyond
+        let coords = vec![
+            Coordinate { x: 0., y: 0. },
+            Coordinate { x: 1., y: 0. },
+            Coordinate { x: 2., y: 0. },
+            Coordinate { x: 1., y: -1. },
+            Coordinate { x: 3., y: 0. },
+            Coordinate { x: 1., y: 0. },
+        ];
+        let triangulation = Triangulation::delaunay(coords);
+        assert_eq!(triangulation.points.len(), 5);
+        assert_eq!(triangulation.triangles.len(), 3);
+        let area: f64 = triangles(&triangulation)
+            .iter()
+            .map(|triangle| triangle.signed_area())
+            .sum();
+        assert_relative_eq!(area, 1.5);
+
+        let line = vec![Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. }];
+        assert!(Triangulation::delaunay(line).triangles.is_empty());
+    }
+}
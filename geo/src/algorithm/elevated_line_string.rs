@@ -0,0 +1,361 @@
+use crate::algorithm::clip_lines::clipped_pieces;
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::algorithm::map_coords::{MapCoords, MapCoordsInplace};
+use crate::algorithm::segment;
+use crate::algorithm::simplify::SimplifyIdx;
+use crate::utils::lerp;
+use crate::{
+    CoordFloat, CoordNum, Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPolygon,
+    Point,
+};
+
+/// A `LineString` with a Z value, such as an elevation, at each of its vertices.
+///
+/// The Z value is interpolated linearly along each segment. Transforms, through [`MapCoords`],
+/// [`Translate`](crate::algorithm::translate::Translate) and
+/// [`RotatePoint`](crate::algorithm::rotate::RotatePoint), move the vertices in the plane and
+/// keep their Z values. Simplification and clipping keep the Z values of the vertices which
+/// remain, and interpolate them at any new vertices.
+///
+/// This is the only geometry carrying Z: `Coordinate` has no Z value, so there are no Z-aware
+/// points or polygons, and no 3D distance; [`length_3d`](Self::length_3d) is the only
+/// measurement which takes Z into account.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElevatedLineString<T: CoordFloat> {
+    line: LineString<T>,
+    z: Vec<T>,
+}
+
+/// The parts of an [`ElevatedLineString`] within, and outside, a set of polygons, from
+/// [`ElevatedLineString::clip`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClippedElevatedLines<T: CoordFloat> {
+    /// The parts of the line within the polygons, or along their boundaries
+    pub inside: Vec<ElevatedLineString<T>>,
+    /// The parts of the line outside the polygons
+    pub outside: Vec<ElevatedLineString<T>>,
+}
+
+impl<T: CoordFloat> ElevatedLineString<T> {
+    /// Raise the vertices of `line` to `z`, one value for each vertex.
+    ///
+    /// Returns `None` if there are more or fewer values than vertices.
+    pub fn new(line: LineString<T>, z: Vec<T>) -> Option<Self> {
+        if line.0.len() != z.len() {
+            return None;
+        }
+        Some(ElevatedLineString { line, z })
+    }
+
+    /// The line, without its Z values.
+    pub fn line(&self) -> &LineString<T> {
+        &self.line
+    }
+
+    /// The Z values of the vertices of the line.
+    pub fn z(&self) -> &[T] {
+        &self.z
+    }
+
+    /// The length of the line in three dimensions, counting the change in Z along each segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::elevated_line_string::ElevatedLineString;
+    /// use geo::line_string;
+    ///
+    /// // a ramp, rising 3 over a run of 4
+    /// let ramp = ElevatedLineString::new(
+    ///     line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.)],
+    ///     vec![0., 3., 3.],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(ramp.length_3d(), 5. + 2.);
+    /// ```
+    pub fn length_3d(&self) -> T {
+        self.line
+            .lines()
+            .zip(self.z.windows(2))
+            .fold(T::zero(), |length, (line, z)| {
+                let delta = line.delta();
+                let dz = z[1] - z[0];
+                length + (delta.x * delta.x + delta.y * delta.y + dz * dz).sqrt()
+            })
+    }
+
+    /// The point a `fraction` of the way along the line, by its length in the plane, as
+    /// [`LineInterpolatePoint`](crate::algorithm::line_interpolate_point::LineInterpolatePoint)
+    /// finds it, along with its Z value, interpolated along the segment it falls in.
+    ///
+    /// Fractions below zero or above one are clamped. Returns `None` if the line is empty, or
+    /// `fraction` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::elevated_line_string::ElevatedLineString;
+    /// use geo::{line_string, point};
+    ///
+    /// let ramp = ElevatedLineString::new(
+    ///     line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+    ///     vec![0., 2., 2.],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(ramp.line_interpolate_point(0.25), Some((point!(x: 2., y: 0.), 1.)));
+    /// assert_eq!(ramp.line_interpolate_point(0.75), Some((point!(x: 4., y: 2.), 2.)));
+    /// ```
+    pub fn line_interpolate_point(&self, fraction: T) -> Option<(Point<T>, T)> {
+        if fraction.is_nan() || self.line.0.is_empty() {
+            return None;
+        }
+        let fraction = fraction.max(T::zero()).min(T::one());
+        let total = self
+            .line
+            .lines()
+            .fold(T::zero(), |length, line| length + line.euclidean_length());
+        let target = total * fraction;
+        let mut length = T::zero();
+        for (index, line) in self.line.lines().enumerate() {
+            let segment_length = line.euclidean_length();
+            if length + segment_length >= target {
+                let segment_fraction = if segment_length.is_zero() {
+                    T::zero()
+                } else {
+                    (target - length) / segment_length
+                };
+                return Some((
+                    Point(lerp(line.start, line.end, segment_fraction)),
+                    lerp(self.z[index], self.z[index + 1], segment_fraction),
+                ));
+            }
+            length = length + segment_length;
+        }
+        // a single vertex, or rounding left `target` beyond the end
+        let last = self.line.0.len() - 1;
+        Some((Point(self.line.0[last]), self.z[last]))
+    }
+
+    /// Simplify the line with the Ramer–Douglas–Peucker algorithm, as
+    /// [`Simplify`](crate::algorithm::simplify::Simplify) does, in the plane, keeping the Z
+    /// values of the vertices which remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::elevated_line_string::ElevatedLineString;
+    /// use geo::line_string;
+    ///
+    /// let trail = ElevatedLineString::new(
+    ///     line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)],
+    ///     vec![100., 120., 110.],
+    /// )
+    /// .unwrap();
+    /// let simplified = trail.simplify(&1.);
+    /// assert_eq!(simplified.line(), &line_string![(x: 0., y: 0.), (x: 10., y: 0.)]);
+    /// assert_eq!(simplified.z(), &[100., 110.]);
+    /// ```
+    pub fn simplify(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat,
+    {
+        let indices = self.line.simplify_idx(epsilon);
+        ElevatedLineString {
+            line: indices.iter().map(|index| self.line.0[*index]).collect(),
+            z: indices.iter().map(|index| self.z[*index]).collect(),
+        }
+    }
+
+    /// Clip the line to the area covered by `polygons`, as
+    /// [`clip_lines`](crate::algorithm::clip_lines::clip_lines) does, interpolating the Z
+    /// values where the line crosses the polygons' rings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::elevated_line_string::ElevatedLineString;
+    /// use geo::{line_string, polygon, MultiPolygon};
+    ///
+    /// let district = MultiPolygon(vec![polygon![
+    ///     (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.),
+    /// ]]);
+    /// let road = ElevatedLineString::new(
+    ///     line_string![(x: -2., y: 1.), (x: 2., y: 1.), (x: 2., y: 6.)],
+    ///     vec![10., 14., 24.],
+    /// )
+    /// .unwrap();
+    ///
+    /// let clipped = road.clip(&district);
+    /// assert_eq!(clipped.inside.len(), 1);
+    /// assert_eq!(
+    ///     clipped.inside[0].line(),
+    ///     &line_string![(x: 0., y: 1.), (x: 2., y: 1.), (x: 2., y: 4.)]
+    /// );
+    /// assert_eq!(clipped.inside[0].z(), &[12., 14., 20.]);
+    /// assert_eq!(clipped.outside.len(), 2);
+    /// ```
+    pub fn clip(&self, polygons: &MultiPolygon<T>) -> ClippedElevatedLines<T>
+    where
+        T: GeoFloat,
+    {
+        let mut clipped = ClippedElevatedLines {
+            inside: vec![],
+            outside: vec![],
+        };
+        let lines = MultiLineString(vec![self.line.clone()]);
+        let mut part: Option<Part<T>> = None;
+        for (source, inside, piece) in clipped_pieces(&lines, polygons) {
+            let segment = Line::new(self.line.0[source], self.line.0[source + 1]);
+            let z_at = |coord| {
+                let t = segment::closest_coord(segment, coord).t;
+                lerp(self.z[source], self.z[source + 1], t)
+            };
+            let end = (piece.end, z_at(piece.end));
+            match &mut part {
+                Some((part_inside, part_source, vertices))
+                    if *part_inside == inside
+                        && vertices.last().map(|(coord, _)| *coord) == Some(piece.start) =>
+                {
+                    // drop nodes within a segment
+                    if *part_source == source {
+                        *vertices.last_mut().unwrap() = end;
+                    } else {
+                        vertices.push(end);
+                        *part_source = source;
+                    }
+                }
+                _ => {
+                    flush(part.take(), &mut clipped);
+                    part = Some((inside, source, vec![(piece.start, z_at(piece.start)), end]));
+                }
+            }
+        }
+        flush(part, &mut clipped);
+        clipped
+    }
+}
+
+impl<T: CoordFloat> MapCoords<T, T> for ElevatedLineString<T> {
+    type Output = Self;
+
+    /// Move the vertices of the line in the plane, keeping their Z values.
+    fn map_coords(&self, func: impl Fn(&(T, T)) -> (T, T) + Copy) -> Self {
+        ElevatedLineString {
+            line: self.line.map_coords(func),
+            z: self.z.clone(),
+        }
+    }
+}
+
+impl<T: CoordFloat> MapCoordsInplace<T> for ElevatedLineString<T> {
+    /// Move the vertices of the line in the plane, in place, keeping their Z values.
+    fn map_coords_inplace(&mut self, func: impl Fn(&(T, T)) -> (T, T) + Copy)
+    where
+        T: CoordNum,
+    {
+        self.line.map_coords_inplace(func)
+    }
+}
+
+// A part of the line being clipped, with whether it's inside, the segment its last piece came
+// from, and its vertices with their Z values.
+type Part<T> = (bool, usize, Vec<(Coordinate<T>, T)>);
+
+fn flush<T: CoordFloat>(part: Option<Part<T>>, clipped: &mut ClippedElevatedLines<T>) {
+    if let Some((inside, _, vertices)) = part {
+        let lines = if inside {
+            &mut clipped.inside
+        } else {
+            &mut clipped.outside
+        };
+        lines.push(ElevatedLineString {
+            line: vertices.iter().map(|(coord, _)| *coord).collect(),
+            z: vertices.iter().map(|(_, z)| *z).collect(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::rotate::RotatePoint;
+    use crate::algorithm::translate::Translate;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn transforms_keep_z() {
+        let line = ElevatedLineString::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            vec![5., 6., 7.],
+        )
+        .unwrap();
+
+        let translated = line.translate(1., 2.);
+        assert_eq!(
+            translated.line(),
+            &line_string![(x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 3.)]
+        );
+        assert_eq!(translated.z(), line.z());
+
+        let rotated = line.rotate_around_point(180., point!(x: 0., y: 0.));
+        assert_eq!(rotated.z(), line.z());
+        assert_relative_eq!(rotated.line().0[2].x, -1.);
+
+        let mut scaled = line.clone();
+        scaled.map_coords_inplace(|&(x, y)| (x * 2., y * 2.));
+        assert_eq!(scaled.line().0[2], Coordinate { x: 2., y: 2. });
+        assert_eq!(scaled.z(), line.z());
+        assert_relative_eq!(scaled.length_3d(), 2. * 5f64.sqrt());
+    }
+
+    #[test]
+    fn clip_interpolates_z_at_crossings() {
+        let polygons = MultiPolygon(vec![polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        ]]);
+        // through the hole, climbing steadily
+        let line =
+            ElevatedLineString::new(line_string![(x: 1., y: 3.), (x: 8., y: 3.)], vec![0., 14.])
+                .unwrap();
+        let clipped = line.clip(&polygons);
+
+        let z: Vec<_> = clipped
+            .inside
+            .iter()
+            .map(|part| part.z().to_vec())
+            .collect();
+        assert_eq!(z, vec![vec![0., 2.], vec![6., 10.]]);
+        let z: Vec<_> = clipped
+            .outside
+            .iter()
+            .map(|part| part.z().to_vec())
+            .collect();
+        assert_eq!(z, vec![vec![2., 6.], vec![10., 14.]]);
+
+        let none = line.clip(&MultiPolygon(vec![]));
+        assert!(none.inside.is_empty());
+        assert_eq!(none.outside, vec![line]);
+    }
+
+    #[test]
+    fn degenerate_lines() {
+        assert!(ElevatedLineString::new(line_string![(x: 0., y: 0.)], vec![]).is_none());
+
+        let empty = ElevatedLineString::<f64>::new(LineString(vec![]), vec![]).unwrap();
+        assert_eq!(empty.length_3d(), 0.);
+        assert_eq!(empty.line_interpolate_point(0.5), None);
+
+        let vertex = ElevatedLineString::new(line_string![(x: 1., y: 1.)], vec![3.]).unwrap();
+        assert_eq!(
+            vertex.line_interpolate_point(0.5),
+            Some((point!(x: 1., y: 1.), 3.))
+        );
+
+        // a vertical segment has no length in the plane, but does in three dimensions
+        let shaft =
+            ElevatedLineString::new(line_string![(x: 1., y: 1.), (x: 1., y: 1.)], vec![0., 10.])
+                .unwrap();
+        assert_eq!(shaft.length_3d(), 10.);
+    }
+}
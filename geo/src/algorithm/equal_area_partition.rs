@@ -0,0 +1,226 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::arrangement::Arrangement;
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::erosion::interior_point;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Polygon};
+use std::iter;
+
+/// Split a polygon into a number of parts of equal area, such as sales territories or sampling
+/// frames, by recursive bisection.
+///
+/// The polygon is cut across the longer side of its bounding rectangle into two pieces, with
+/// areas in proportion to the numbers of parts each is to be split into, which are then split in
+/// turn. The position of each cut is found by bisection, so the areas are equal up to the
+/// precision of `T`. A part may itself be a `MultiPolygon`, where a cut crosses the polygon
+/// more than once.
+pub trait EqualAreaPartition<T: GeoFloat> {
+    /// Split the geometry into `parts` parts of equal area.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::equal_area_partition::EqualAreaPartition;
+    /// use geo::polygon;
+    ///
+    /// let field = polygon![(x: 0., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 0., y: 10.)];
+    /// let plots = field.equal_area_partition(3);
+    /// assert_eq!(plots.len(), 3);
+    /// for plot in &plots {
+    ///     assert_relative_eq!(plot.unsigned_area(), 100., epsilon = 1e-9);
+    /// }
+    /// ```
+    fn equal_area_partition(&self, parts: usize) -> Vec<MultiPolygon<T>>;
+}
+
+impl<T: GeoFloat> EqualAreaPartition<T> for Polygon<T> {
+    fn equal_area_partition(&self, parts: usize) -> Vec<MultiPolygon<T>> {
+        MultiPolygon(vec![self.clone()]).equal_area_partition(parts)
+    }
+}
+
+impl<T: GeoFloat> EqualAreaPartition<T> for MultiPolygon<T> {
+    fn equal_area_partition(&self, parts: usize) -> Vec<MultiPolygon<T>> {
+        assert!(parts > 0, "parts must be positive");
+        let mut partition = Vec::with_capacity(parts);
+        bisect(self.orient(Direction::Default), parts, &mut partition);
+        partition
+    }
+}
+
+// Split `polygons`, oriented with their exteriors counter-clockwise, into `parts` parts of equal
+// area, appended to `partition`.
+fn bisect<T: GeoFloat>(
+    polygons: MultiPolygon<T>,
+    parts: usize,
+    partition: &mut Vec<MultiPolygon<T>>,
+) {
+    let rect = match polygons.bounding_rect() {
+        Some(rect) if parts > 1 => rect,
+        _ => {
+            partition.push(polygons);
+            partition.resize(partition.len() + parts - 1, MultiPolygon(vec![]));
+            return;
+        }
+    };
+    let is_across_x = rect.width() >= rect.height();
+    let key = |coord: Coordinate<T>| if is_across_x { coord.x } else { coord.y };
+    let rings: Vec<&LineString<T>> = polygons
+        .iter()
+        .flat_map(|polygon| iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .collect();
+
+    let below = parts / 2;
+    let target = polygons.unsigned_area() * T::from(below).unwrap() / T::from(parts).unwrap();
+    let (mut low, mut high) = (key(rect.min()), key(rect.max()));
+    let two = T::one() + T::one();
+    let mut cut = (low + high) / two;
+    while low < cut && cut < high {
+        let area = rings
+            .iter()
+            .map(|ring| area_below(ring, key, cut))
+            .fold(T::zero(), |total, area| total + area);
+        if area < target {
+            low = cut;
+        } else {
+            high = cut;
+        }
+        cut = (low + high) / two;
+    }
+
+    // the line of the cut, reaching beyond the bounding rectangle
+    let span = rect.width() + rect.height();
+    let line = if is_across_x {
+        Line::new((cut, rect.min().y - span), (cut, rect.max().y + span))
+    } else {
+        Line::new((rect.min().x - span, cut), (rect.max().x + span, cut))
+    };
+    let segments = rings
+        .iter()
+        .flat_map(|ring| ring.lines())
+        .chain(iter::once(line))
+        .map(|line| (line, 0));
+    let (mut lower, mut upper) = (vec![], vec![]);
+    for face in Arrangement::new(segments).faces() {
+        let coord = match interior_point(&face.polygon) {
+            Some(coord) => coord,
+            None => continue,
+        };
+        if polygons.coordinate_position(&coord) != CoordPos::Inside {
+            continue;
+        }
+        if key(coord) < cut {
+            lower.push(face.polygon);
+        } else {
+            upper.push(face.polygon);
+        }
+    }
+    bisect(MultiPolygon(lower), below, partition);
+    bisect(MultiPolygon(upper), parts - below, partition);
+}
+
+// The signed area of the part of `ring` whose `key` is at most `cut`, found by clipping it to
+// that half-plane, after Sutherland and Hodgman. The clipped ring may run back and forth along
+// the cut, but those edges add nothing to its area.
+fn area_below<T, K>(ring: &LineString<T>, key: K, cut: T) -> T
+where
+    T: GeoFloat,
+    K: Fn(Coordinate<T>) -> T,
+{
+    let mut clipped = vec![];
+    for line in ring.lines() {
+        let (start, end) = (key(line.start), key(line.end));
+        if start <= cut {
+            clipped.push(line.start);
+        }
+        if (start <= cut) != (end <= cut) {
+            let fraction = (cut - start) / (end - start);
+            clipped.push(line.start + line.delta() * fraction);
+        }
+    }
+    let two = T::one() + T::one();
+    clipped
+        .iter()
+        .zip(clipped.iter().cycle().skip(1))
+        .fold(T::zero(), |area, (a, b)| {
+            area + (a.x * b.y - b.x * a.y) / two
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::intersects::Intersects;
+    use crate::polygon;
+
+    fn assert_partition(polygon: &Polygon<f64>, parts: usize) -> Vec<MultiPolygon<f64>> {
+        let partition = polygon.equal_area_partition(parts);
+        assert_eq!(partition.len(), parts);
+        let area = polygon.unsigned_area() / parts as f64;
+        for part in &partition {
+            assert_relative_eq!(part.unsigned_area(), area, epsilon = 1e-9);
+            // every part is inside the polygon
+            for face in part {
+                let coord = interior_point(face).unwrap();
+                assert_eq!(polygon.coordinate_position(&coord), CoordPos::Inside);
+            }
+        }
+        partition
+    }
+
+    #[test]
+    fn rectangles_are_cut_across() {
+        let strip: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 40., y: 0.), (x: 40., y: 10.), (x: 0., y: 10.)];
+        let partition = assert_partition(&strip, 4);
+        // cut into four squares, in order
+        for (index, part) in partition.iter().enumerate() {
+            let rect = part.bounding_rect().unwrap();
+            assert_relative_eq!(rect.min().x, index as f64 * 10.);
+            assert_relative_eq!(rect.height(), 10.);
+        }
+        assert_eq!(
+            strip.equal_area_partition(1),
+            vec![MultiPolygon(vec![strip])]
+        );
+    }
+
+    #[test]
+    fn concave_polygons_and_holes() {
+        // a U, where cuts across its arms split them apart
+        let u: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 9., y: 0.),
+            (x: 9., y: 30.),
+            (x: 6., y: 30.),
+            (x: 6., y: 3.),
+            (x: 3., y: 3.),
+            (x: 3., y: 30.),
+            (x: 0., y: 30.),
+        ];
+        let partition = assert_partition(&u, 3);
+        assert!(partition.iter().any(|part| part.0.len() > 1));
+
+        let frame: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 3., y: 3.), (x: 7., y: 3.), (x: 7., y: 7.), (x: 3., y: 7.)]],
+        ];
+        let partition = assert_partition(&frame, 5);
+        // neighbouring parts only meet along their edges
+        for (index, part) in partition.iter().enumerate() {
+            for other in &partition[index + 1..] {
+                for face in part {
+                    let coord = interior_point(face).unwrap();
+                    assert!(!other.intersects(&coord));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use crate::algorithm::arrangement::Arrangement;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Point, Polygon};
+use std::iter;
+
+// The number of segments approximating a quarter circle, around the vertices where a ring turns
+// away from the inside of the polygon
+const QUADRANT_SEGMENTS: usize = 8;
+
+/// Erode a polygon inwards: a negative buffer, keeping the points at least a given distance
+/// inside it.
+///
+/// The eroded polygon is bounded by the edges of the original moved inwards, joined by circular
+/// arcs, approximated by straight segments, around its reflex vertices. As it shrinks, it may
+/// split into several polygons, and eventually collapses altogether. Eroding by a series of
+/// distances gives building setbacks, or nested rings for contour-like drawings of a polygon.
+///
+/// The moved edges and arcs are noded together, and each face they bound is kept if it's inside
+/// the polygon, and farther than the distance from its boundary.
+pub trait Erosion<T: GeoFloat> {
+    /// The parts of the geometry farther than `distance` inside it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::erosion::Erosion;
+    /// use geo::polygon;
+    ///
+    /// let parcel = polygon![(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 30.), (x: 0., y: 30.)];
+    /// // the buildable area, with a setback of 5 from every side
+    /// let buildable = parcel.erode(5.);
+    /// assert_eq!(buildable.0.len(), 1);
+    /// assert_relative_eq!(buildable.unsigned_area(), 10. * 20.);
+    /// ```
+    fn erode(&self, distance: T) -> MultiPolygon<T>;
+
+    /// The geometry eroded by `step`, by twice `step`, and so on, until nothing is left.
+    ///
+    /// Each erosion is of the original geometry, so errors don't accumulate along the series.
+    /// The last erosion in it is the last which isn't empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::erosion::Erosion;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// // squares of side 7, 4 and 1
+    /// let rings = square.erosion_series(1.5);
+    /// assert_eq!(rings.len(), 3);
+    /// ```
+    fn erosion_series(&self, step: T) -> Vec<MultiPolygon<T>> {
+        assert!(step > T::zero(), "step must be positive");
+        let mut series = vec![];
+        for count in 1.. {
+            let eroded = self.erode(step * T::from(count).unwrap());
+            if eroded.0.is_empty() {
+                break;
+            }
+            series.push(eroded);
+        }
+        series
+    }
+}
+
+impl<T: GeoFloat> Erosion<T> for Polygon<T> {
+    fn erode(&self, distance: T) -> MultiPolygon<T> {
+        assert!(distance >= T::zero(), "distance must not be negative");
+        // with the inside of the polygon on the left of each ring
+        let polygon = self.orient(Direction::Default);
+        let rings: Vec<&LineString<T>> = iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .collect();
+
+        let mut segments = vec![];
+        for ring in &rings {
+            offset_ring(ring, distance, &mut segments);
+        }
+        MultiPolygon(faces_where(segments, |coord| {
+            polygon.coordinate_position(&coord) == CoordPos::Inside
+                && boundary_distance(&rings, coord) > distance
+        }))
+    }
+}
+
+/// Each polygon is eroded separately, so polygons sharing an edge are eroded from it.
+impl<T: GeoFloat> Erosion<T> for MultiPolygon<T> {
+    fn erode(&self, distance: T) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .flat_map(|polygon| polygon.erode(distance).0)
+                .collect(),
+        )
+    }
+}
+
+// The faces bounded by `segments` for which `keep` holds at a point inside them. The segments
+// must include every boundary between points where `keep` holds and where it doesn't.
+pub(crate) fn faces_where<T, F>(segments: Vec<Line<T>>, keep: F) -> Vec<Polygon<T>>
+where
+    T: GeoFloat,
+    F: Fn(Coordinate<T>) -> bool,
+{
+    Arrangement::new(segments.into_iter().map(|line| (line, 0)))
+        .faces()
+        .into_iter()
+        .map(|face| face.polygon)
+        .filter(|face| match interior_point(face) {
+            Some(coord) => keep(coord),
+            None => false,
+        })
+        .collect()
+}
+
+// The edges of `ring` moved `distance` to their left, and arcs of that radius joining them around
+// the vertices where the ring turns right: every point on the left of the ring at `distance`
+// from it lies on one of them.
+fn offset_ring<T: GeoFloat>(ring: &LineString<T>, distance: T, segments: &mut Vec<Line<T>>) {
+    let mut coords = ring.0.clone();
+    coords.dedup();
+    let normals: Vec<Coordinate<T>> = coords
+        .windows(2)
+        .map(|edge| left_normal(edge[0], edge[1]))
+        .collect();
+    for (edge, normal) in coords.windows(2).zip(&normals) {
+        segments.push(Line::new(
+            edge[0] + *normal * distance,
+            edge[1] + *normal * distance,
+        ));
+    }
+
+    let quadrant = T::from(core::f64::consts::FRAC_PI_2).unwrap();
+    let count = normals.len();
+    for (index, &next) in normals.iter().enumerate() {
+        let prev = normals[(index + count - 1) % count];
+        let cross = prev.x * next.y - prev.y * next.x;
+        let sweep = cross.abs().atan2(prev.x * next.x + prev.y * next.y);
+        if cross > T::zero() || sweep == T::zero() {
+            continue;
+        }
+
+        // clockwise around the vertex, from the end of one moved edge to the start of the next
+        let vertex = coords[index];
+        let steps = (sweep / quadrant * T::from(QUADRANT_SEGMENTS).unwrap())
+            .ceil()
+            .to_usize()
+            .unwrap()
+            .max(1);
+        let start_angle = prev.y.atan2(prev.x);
+        let mut last = vertex + prev * distance;
+        for step in 1..=steps {
+            let coord = if step == steps {
+                vertex + next * distance
+            } else {
+                let angle = start_angle - sweep * T::from(step).unwrap() / T::from(steps).unwrap();
+                let (sin, cos) = angle.sin_cos();
+                vertex + Coordinate { x: cos, y: sin } * distance
+            };
+            segments.push(Line::new(last, coord));
+            last = coord;
+        }
+    }
+}
+
+// The unit vector to the left of the direction from `start` to `end`.
+pub(crate) fn left_normal<T: GeoFloat>(start: Coordinate<T>, end: Coordinate<T>) -> Coordinate<T> {
+    let delta = end - start;
+    let length = delta.x.hypot(delta.y);
+    Coordinate {
+        x: -delta.y / length,
+        y: delta.x / length,
+    }
+}
+
+fn boundary_distance<T: GeoFloat>(rings: &[&LineString<T>], coord: Coordinate<T>) -> T {
+    rings
+        .iter()
+        .map(|ring| Point::from(coord).euclidean_distance(*ring))
+        .fold(T::infinity(), T::min)
+}
+
+// A point inside `polygon`, midway across its widest crossing of a horizontal line, between the
+// two successive heights of its vertices furthest apart.
+pub(crate) fn interior_point<T: GeoFloat>(polygon: &Polygon<T>) -> Option<Coordinate<T>> {
+    let rings = || iter::once(polygon.exterior()).chain(polygon.interiors());
+    let mut heights: Vec<T> = rings()
+        .flat_map(|ring| ring.0.iter().map(|coord| coord.y))
+        .collect();
+    heights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    heights.dedup();
+    let two = T::one() + T::one();
+    let y = heights
+        .windows(2)
+        .max_by(|a, b| (a[1] - a[0]).partial_cmp(&(b[1] - b[0])).unwrap())
+        .map(|pair| (pair[0] + pair[1]) / two)?;
+
+    let mut crossings: Vec<T> = rings()
+        .flat_map(|ring| ring.lines())
+        .filter(|line| (line.start.y < y) != (line.end.y < y))
+        .map(|line| {
+            line.start.x
+                + (y - line.start.y) * (line.end.x - line.start.x) / (line.end.y - line.start.y)
+        })
+        .collect();
+    crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    crossings
+        .chunks_exact(2)
+        .max_by(|a, b| (a[1] - a[0]).partial_cmp(&(b[1] - b[0])).unwrap())
+        .map(|pair| Coordinate {
+            x: (pair[0] + pair[1]) / two,
+            y,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::{polygon, Rect};
+    use core::f64::consts::PI;
+
+    #[test]
+    fn squares_shrink_until_they_collapse() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let series = square.erosion_series(1.5);
+        assert_eq!(series.len(), 3);
+        for (index, eroded) in series.iter().enumerate() {
+            let inset = 1.5 * (index + 1) as f64;
+            assert_eq!(eroded.0.len(), 1);
+            let rect = eroded.bounding_rect().unwrap();
+            assert_relative_eq!(rect, Rect::new((inset, inset), (10. - inset, 10. - inset)));
+            assert_relative_eq!(eroded.unsigned_area(), (10. - 2. * inset).powi(2));
+        }
+
+        assert_eq!(square.erode(0.).unsigned_area(), 100.);
+        assert!(square.erode(6.).0.is_empty());
+    }
+
+    #[test]
+    fn reflex_vertices_are_rounded() {
+        // an L, with a reflex vertex at (2, 2)
+        let l: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 2.),
+            (x: 2., y: 2.),
+            (x: 2., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let eroded = l.erode(0.5);
+        assert_eq!(eroded.0.len(), 1);
+        // two overlapping arms, and the corner between them outside a quarter circle around the
+        // reflex vertex
+        let area = 3. + 3. - 1. + 0.25 - PI * 0.25 / 4.;
+        assert_relative_eq!(eroded.unsigned_area(), area, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn holes_grow() {
+        let polygon: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        ];
+        let eroded = polygon.erode(1.);
+        assert_eq!(eroded.0.len(), 1);
+        assert_eq!(eroded.0[0].interiors().len(), 1);
+        // the hole grows by 1 all round, with rounded corners
+        let area = 8. * 8. - (4. + 4. * 2. + PI);
+        assert_relative_eq!(eroded.unsigned_area(), area, epsilon = 1e-1);
+
+        // the ring between the exterior and the hole collapses
+        assert_eq!(polygon.erosion_series(1.5).len(), 1);
+    }
+
+    #[test]
+    fn narrow_necks_split() {
+        // two squares joined by a corridor 1 wide
+        let dumbbell: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.5),
+            (x: 6., y: 1.5),
+            (x: 6., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 6., y: 4.),
+            (x: 6., y: 2.5),
+            (x: 4., y: 2.5),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let eroded = dumbbell.erode(1.);
+        assert_eq!(eroded.0.len(), 2);
+        let areas: Vec<f64> = eroded.0.iter().map(|part| part.unsigned_area()).collect();
+        assert_relative_eq!(areas[0], areas[1], epsilon = 1e-9);
+        assert!(areas[0] > 4.);
+
+        // wound clockwise, it erodes the same way
+        let mut reversed = dumbbell.clone();
+        reversed.exterior_mut(|exterior| exterior.0.reverse());
+        assert_eq!(reversed.erode(1.).0.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn erosion_series_needs_a_positive_step() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        square.erosion_series(0.);
+    }
+}
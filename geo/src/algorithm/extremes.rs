@@ -28,12 +28,14 @@ pub trait Extremes<'a, T: CoordNum> {
     fn extremes(&'a self) -> Option<Outcome<T>>;
 }
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq)]
 pub struct Extreme<T: CoordNum> {
     pub index: usize,
     pub coord: Coordinate<T>,
 }
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq)]
 pub struct Outcome<T: CoordNum> {
     pub x_min: Extreme<T>,
@@ -0,0 +1,280 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::{
+    CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Check whether every coordinate of a geometry is finite, neither `NaN` nor infinite.
+///
+/// Many algorithms assume finite coordinates, and panic, or quietly give nonsense, otherwise:
+/// sorting coordinates with [`lex_cmp`](crate::utils::lex_cmp), as the
+/// [`SweepLine`](crate::algorithm::sweepline::SweepLine) does, panics on a `NaN`. Checking a
+/// dataset up front finds the geometries to fix, or to [scrub](ScrubNonFinite).
+pub trait HasFiniteCoordinates<T: CoordFloat> {
+    /// Whether every coordinate of the geometry is finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::finite::HasFiniteCoordinates;
+    /// use geo::line_string;
+    ///
+    /// assert!(line_string![(x: 0., y: 0.), (x: 1., y: 1.)].has_finite_coordinates());
+    /// assert!(!line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.)].has_finite_coordinates());
+    /// ```
+    fn has_finite_coordinates(&self) -> bool;
+
+    /// The positions of the coordinates which aren't finite, among those of the geometry, in
+    /// the order of [`CoordsIter::coords_iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::finite::HasFiniteCoordinates;
+    /// use geo::{point, Geometry, MultiPoint};
+    ///
+    /// let dataset: Vec<Geometry<f64>> = vec![
+    ///     point!(x: 0., y: 0.).into(),
+    ///     MultiPoint(vec![point!(x: 1., y: 1.), point!(x: f64::INFINITY, y: 1.)]).into(),
+    /// ];
+    /// let report: Vec<(usize, Vec<usize>)> = dataset
+    ///     .iter()
+    ///     .map(|geometry| geometry.non_finite_coords())
+    ///     .enumerate()
+    ///     .filter(|(_, coords)| !coords.is_empty())
+    ///     .collect();
+    /// assert_eq!(report, vec![(1, vec![1])]);
+    /// ```
+    fn non_finite_coords(&self) -> Vec<usize>;
+}
+
+impl<T, G> HasFiniteCoordinates<T> for G
+where
+    T: CoordFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn has_finite_coordinates(&self) -> bool {
+        self.coords_iter().all(is_finite)
+    }
+
+    fn non_finite_coords(&self) -> Vec<usize> {
+        self.coords_iter()
+            .enumerate()
+            .filter(|(_, coord)| !is_finite(*coord))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+/// Remove the coordinates of a geometry which aren't finite, neither `NaN` nor infinite, along
+/// with whatever they leave too small to stand.
+///
+/// - A line string keeps its finite coordinates, and is dropped if fewer than two are left.
+/// - A ring keeps its finite coordinates, closed again if its first or last was removed, and is
+///   dropped if fewer than three distinct positions are left. A polygon is dropped with its
+///   exterior ring, and keeps the interior rings which are left.
+/// - A point, line, rectangle or triangle, which can't lose a coordinate, is dropped if any of
+///   its coordinates isn't finite.
+/// - A multi-geometry or geometry collection keeps the members which are left, and is dropped if
+///   none are.
+pub trait ScrubNonFinite: Sized {
+    /// The geometry without its non-finite coordinates, or `None` if nothing is left of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::finite::ScrubNonFinite;
+    /// use geo::{line_string, polygon};
+    ///
+    /// let line = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.), (x: 2., y: 0.)];
+    /// assert_eq!(
+    ///     line.scrub_non_finite(),
+    ///     Some(line_string![(x: 0., y: 0.), (x: 2., y: 0.)])
+    /// );
+    ///
+    /// // a square, left as a triangle once its infinite vertex is removed
+    /// let polygon = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: f64::INFINITY, y: 1.),
+    ///     (x: 0., y: 1.),
+    /// ];
+    /// assert_eq!(polygon.scrub_non_finite().unwrap().exterior().0.len(), 4);
+    ///
+    /// // a triangle, which is too small to be a polygon without it
+    /// let triangle = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: f64::INFINITY, y: 1.)];
+    /// assert_eq!(triangle.scrub_non_finite(), None);
+    /// ```
+    fn scrub_non_finite(&self) -> Option<Self>;
+}
+
+impl<T: CoordFloat> ScrubNonFinite for Point<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        Some(*self).filter(|point| is_finite(point.0))
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for Line<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        Some(*self).filter(|line| is_finite(line.start) && is_finite(line.end))
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for Rect<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        Some(*self).filter(|rect| is_finite(rect.min()) && is_finite(rect.max()))
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for Triangle<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        Some(*self).filter(|triangle| triangle.to_array().iter().copied().all(is_finite))
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for LineString<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        let coords: Vec<Coordinate<T>> = self
+            .0
+            .iter()
+            .copied()
+            .filter(|coord| is_finite(*coord))
+            .collect();
+        if coords.len() < 2 {
+            return None;
+        }
+        Some(LineString(coords))
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for Polygon<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        let exterior = scrub_ring(self.exterior())?;
+        let interiors = self.interiors().iter().filter_map(scrub_ring).collect();
+        Some(Polygon::new(exterior, interiors))
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for MultiPoint<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        scrub_all(&self.0).map(MultiPoint)
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for MultiLineString<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        scrub_all(&self.0).map(MultiLineString)
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for MultiPolygon<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        scrub_all(&self.0).map(MultiPolygon)
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for GeometryCollection<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        scrub_all(&self.0).map(GeometryCollection)
+    }
+}
+
+impl<T: CoordFloat> ScrubNonFinite for Geometry<T> {
+    fn scrub_non_finite(&self) -> Option<Self> {
+        match self {
+            Geometry::Point(g) => g.scrub_non_finite().map(Geometry::Point),
+            Geometry::Line(g) => g.scrub_non_finite().map(Geometry::Line),
+            Geometry::LineString(g) => g.scrub_non_finite().map(Geometry::LineString),
+            Geometry::Polygon(g) => g.scrub_non_finite().map(Geometry::Polygon),
+            Geometry::MultiPoint(g) => g.scrub_non_finite().map(Geometry::MultiPoint),
+            Geometry::MultiLineString(g) => g.scrub_non_finite().map(Geometry::MultiLineString),
+            Geometry::MultiPolygon(g) => g.scrub_non_finite().map(Geometry::MultiPolygon),
+            Geometry::GeometryCollection(g) => {
+                g.scrub_non_finite().map(Geometry::GeometryCollection)
+            }
+            Geometry::Rect(g) => g.scrub_non_finite().map(Geometry::Rect),
+            Geometry::Triangle(g) => g.scrub_non_finite().map(Geometry::Triangle),
+        }
+    }
+}
+
+fn is_finite<T: CoordFloat>(coord: Coordinate<T>) -> bool {
+    coord.x.is_finite() && coord.y.is_finite()
+}
+
+fn scrub_ring<T: CoordFloat>(ring: &LineString<T>) -> Option<LineString<T>> {
+    let mut coords: Vec<Coordinate<T>> = ring
+        .0
+        .iter()
+        .copied()
+        .filter(|coord| is_finite(*coord))
+        .collect();
+    coords.dedup();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    if coords.len() < 3 {
+        return None;
+    }
+    coords.push(coords[0]);
+    Some(LineString(coords))
+}
+
+fn scrub_all<G: ScrubNonFinite>(members: &[G]) -> Option<Vec<G>> {
+    let members: Vec<G> = members.iter().filter_map(G::scrub_non_finite).collect();
+    if members.is_empty() {
+        return None;
+    }
+    Some(members)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn scrubs_every_geometry_type() {
+        let nan = f64::NAN;
+        // the first vertex of the ring, and so its closing vertex, is removed
+        let polygon = polygon![
+            exterior: [
+                (x: nan, y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 5.),
+            ],
+            interiors: [
+                [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: nan)],
+                [(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)],
+            ],
+        ];
+        let scrubbed = polygon.scrub_non_finite().unwrap();
+        assert_eq!(
+            scrubbed,
+            polygon![
+                exterior: [(x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 5.)],
+                interiors: [[(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)]],
+            ]
+        );
+        assert!(scrubbed.has_finite_coordinates());
+        assert_eq!(polygon.non_finite_coords(), vec![0, 5, 8]);
+
+        let collection = Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: nan, y: 0.).into(),
+            Line::from([(0., 0.), (f64::NEG_INFINITY, 1.)]).into(),
+            line_string![(x: 0., y: 0.), (x: nan, y: 1.)].into(),
+            MultiPoint(vec![point!(x: 1., y: 1.), point!(x: nan, y: nan)]).into(),
+        ]));
+        assert_eq!(
+            collection.scrub_non_finite(),
+            Some(Geometry::GeometryCollection(GeometryCollection(vec![
+                MultiPoint(vec![point!(x: 1., y: 1.)]).into()
+            ])))
+        );
+        assert_eq!(Rect::new((0., 0.), (nan, 1.)).scrub_non_finite(), None);
+        let square = Rect::new((0., 0.), (1., 1.));
+        assert_eq!(square.scrub_non_finite(), Some(square));
+    }
+}
@@ -88,9 +88,113 @@ where
     }
 }
 
+/// The part of a line which best matches another, shorter, line, found by
+/// [`SublineMatch::subline_match`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Subline<T> {
+    /// The index of the first coordinate of the matching part of the line
+    pub start: usize,
+    /// The index of the last coordinate of the matching part of the line, inclusive
+    pub end: usize,
+    /// The discrete Frechet distance between the matching part and the pattern
+    pub distance: T,
+}
+
+impl<T: GeoFloat> Subline<T> {
+    /// The matching part of `line_string`, which should be the line that was searched.
+    pub fn line_string(&self, line_string: &LineString<T>) -> LineString<T> {
+        LineString(line_string.0[self.start..=self.end].to_vec())
+    }
+}
+
+/// Find the part of a long line which best matches a shorter one, such as where a partial
+/// trajectory runs along a route.
+pub trait SublineMatch<T, Rhs = Self> {
+    /// Find the consecutive coordinates of `self` with the least discrete [Frechet distance] to
+    /// `pattern`, or `None` if either is empty.
+    ///
+    /// Each coordinate of `self` may start the match, so this takes time in proportion to the
+    /// product of the numbers of coordinates of the lines, as
+    /// [`frechet_distance`](FrechetDistance::frechet_distance) does, rather than trying every
+    /// subline in turn. Of equally good matches, the one ending first is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::frechet_distance::SublineMatch;
+    /// use geo::line_string;
+    ///
+    /// let route = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.),
+    ///     (x: 2., y: 0.),
+    ///     (x: 2., y: 1.),
+    ///     (x: 2., y: 2.),
+    ///     (x: 3., y: 2.),
+    /// ];
+    /// // a trace of the corner, offset a little
+    /// let trace = line_string![(x: 1., y: 0.1), (x: 1.9, y: 0.1), (x: 1.9, y: 1.)];
+    ///
+    /// let subline = route.subline_match(&trace).unwrap();
+    /// assert_eq!((subline.start, subline.end), (1, 3));
+    /// assert!(subline.distance < 0.15);
+    /// assert_eq!(
+    ///     subline.line_string(&route),
+    ///     line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.)]
+    /// );
+    /// ```
+    ///
+    /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
+    fn subline_match(&self, pattern: &Rhs) -> Option<Subline<T>>;
+}
+
+impl<T> SublineMatch<T, LineString<T>> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn subline_match(&self, pattern: &LineString<T>) -> Option<Subline<T>> {
+        if self.0.is_empty() || pattern.0.is_empty() {
+            return None;
+        }
+        let distance =
+            |i: usize, j: usize| Point::from(pattern[i]).euclidean_distance(&Point::from(self[j]));
+
+        // For each coordinate of `self`, the least Frechet distance between the pattern so far
+        // and a subline ending there, and where that subline starts. The first coordinate of the
+        // pattern may be matched to any coordinate of `self`, starting a new subline.
+        let mut row: Vec<(T, usize)> = (0..self.0.len()).map(|j| (distance(0, j), j)).collect();
+        for i in 1..pattern.0.len() {
+            let mut next: Vec<(T, usize)> = Vec::with_capacity(row.len());
+            for j in 0..row.len() {
+                let mut best = row[j];
+                if j > 0 {
+                    for &candidate in &[row[j - 1], next[j - 1]] {
+                        if candidate.0 < best.0 {
+                            best = candidate;
+                        }
+                    }
+                }
+                next.push((best.0.max(distance(i, j)), best.1));
+            }
+            row = next;
+        }
+
+        let (end, &(distance, start)) = row
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.0.partial_cmp(&b.0).unwrap())?;
+        Some(Subline {
+            start,
+            end,
+            distance,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::algorithm::frechet_distance::FrechetDistance;
+    use crate::algorithm::frechet_distance::{FrechetDistance, Subline, SublineMatch};
     use crate::euclidean_distance::EuclideanDistance;
     use crate::LineString;
 
@@ -131,4 +235,52 @@ mod test {
         let ls_b = LineString::from(vec![(2., 2.), (0., 1.), (2., 4.)]);
         assert_relative_eq!(2., ls_a.frechet_distance(&ls_b));
     }
+
+    #[test]
+    fn subline_match_finds_the_closest_part() {
+        let long = LineString::from(vec![
+            (0., 0.),
+            (1., 0.),
+            (2., 1.),
+            (3., 0.),
+            (4., 1.),
+            (5., 0.),
+            (6., 0.),
+        ]);
+        let pattern = LineString::from(vec![(3., 0.2), (4., 1.2), (5., 0.2)]);
+        let subline = long.subline_match(&pattern).unwrap();
+        assert_eq!((subline.start, subline.end), (3, 5));
+        assert_relative_eq!(subline.distance, 0.2);
+        assert_relative_eq!(
+            subline.distance,
+            subline.line_string(&long).frechet_distance(&pattern)
+        );
+    }
+
+    #[test]
+    fn subline_match_pattern_may_dwell() {
+        // the pattern lingers at the corner, and the line passes it by quickly
+        let long = LineString::from(vec![(0., 0.), (1., 0.), (2., 0.), (2., 1.), (2., 2.)]);
+        let pattern = LineString::from(vec![(1., 0.), (2., 0.), (2., 0.), (2., 0.), (2., 1.)]);
+        assert_eq!(
+            long.subline_match(&pattern),
+            Some(Subline {
+                start: 1,
+                end: 3,
+                distance: 0.
+            })
+        );
+    }
+
+    #[test]
+    fn subline_match_of_empty_lines() {
+        let line = LineString::from(vec![(0., 0.), (1., 0.)]);
+        let empty = LineString::<f64>(vec![]);
+        assert!(line.subline_match(&empty).is_none());
+        assert!(empty.subline_match(&line).is_none());
+
+        let point = LineString::from(vec![(0.9, 0.1)]);
+        let subline = line.subline_match(&point).unwrap();
+        assert_eq!((subline.start, subline.end), (1, 1));
+    }
 }
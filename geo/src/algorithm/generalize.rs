@@ -0,0 +1,643 @@
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::algorithm::normalize::cmp_coords;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::algorithm::simplify::rdp;
+use crate::algorithm::sweepline::SweepLine;
+use crate::algorithm::winding_order::Winding;
+use crate::utils::{least_index, lex_cmp};
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPolygon, Polygon,
+};
+use std::cmp::Ordering;
+
+/// Simplify every member of a collection together, so that the boundaries they share are
+/// simplified the same way, and stay shared.
+///
+/// Simplifying the polygons of a coverage, such as a layer of administrative areas, one at a
+/// time opens gaps and overlaps between them, since each simplifies their shared edge
+/// differently, and may leave rings crossing each other or collapsed. Instead, the collection
+/// is generalized in one pass:
+///
+/// 1. Each member is repaired: repeated coordinates are removed, rings are closed, and rings
+///    enclosing no area are dropped, along with polygons whose exteriors enclose none.
+/// 2. The lines and rings of the members are noded wherever they cross or touch, and broken
+///    into arcs at the junctions where they part ways, as in a topological data structure.
+/// 3. Each arc is simplified once, with the Ramer–Douglas–Peucker algorithm, keeping its ends,
+///    and every line and ring along it is rebuilt from the simplified arc.
+/// 4. The output is validated: an arc whose simplification crosses or touches another arc, or
+///    itself, or collapses a ring, is restored, until none do.
+/// 5. Polygons are oriented as [`Orient`] does by default, with counter-clockwise exteriors
+///    and clockwise interiors.
+///
+/// `Point`s, `Line`s, `Rect`s and `Triangle`s are left as they are, but still pin the arcs of
+/// the other members, so a point on a boundary stays on it. The shape of the collection,
+/// including any collections nested in it, is kept. A `tolerance` of zero or less only repairs
+/// and orients the members.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::coverage::validate_coverage;
+/// use geo::algorithm::generalize::generalize;
+/// use geo::{polygon, Geometry, GeometryCollection, Polygon};
+///
+/// // two parcels sharing a wiggly edge
+/// let west: Polygon<f64> = polygon![
+///     (x: 0., y: 0.), (x: 5., y: 0.), (x: 5.2, y: 3.), (x: 4.9, y: 6.), (x: 5.1, y: 8.),
+///     (x: 5., y: 10.), (x: 0., y: 10.),
+/// ];
+/// let east: Polygon<f64> = polygon![
+///     (x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 10.), (x: 5.1, y: 8.),
+///     (x: 4.9, y: 6.), (x: 5.2, y: 3.),
+/// ];
+/// let parcels = GeometryCollection(vec![Geometry::from(west), Geometry::from(east)]);
+///
+/// let generalized = generalize(&parcels, 0.5);
+/// let polygons: Vec<Polygon<f64>> = generalized
+///     .into_iter()
+///     .map(|geometry| geometry.into_polygon().unwrap())
+///     .collect();
+/// // the shared edge is straightened, for both parcels
+/// assert_eq!(polygons[0].exterior().0.len(), 5);
+/// assert_eq!(polygons[1].exterior().0.len(), 5);
+/// assert!(validate_coverage(&polygons, 0.1).is_valid());
+/// ```
+pub fn generalize<T: GeoFloat>(
+    collection: &GeometryCollection<T>,
+    tolerance: T,
+) -> GeometryCollection<T> {
+    let repaired = repair_collection(collection);
+    let mut sequences = vec![];
+    for geometry in &repaired {
+        collect_sequences(geometry, &mut sequences);
+    }
+    node(&mut sequences);
+
+    let junctions = junctions(&sequences);
+    let is_junction = |coord: Coordinate<T>| {
+        junctions
+            .binary_search_by(|junction| lex_cmp(junction, &coord))
+            .is_ok()
+    };
+    let (arcs, paths) = arcs(&sequences, &is_junction);
+    let mut simplified: Vec<Vec<Coordinate<T>>> = arcs
+        .iter()
+        .map(|arc| rdp(arc.iter().copied(), &tolerance))
+        .collect();
+    while restore_invalid_arcs(&arcs, &mut simplified, &sequences, &paths, &is_junction) {}
+
+    let mut rebuilt = sequences
+        .iter()
+        .zip(&paths)
+        .map(|(sequence, path)| match path {
+            Some(path) => follow(path, &simplified),
+            None => sequence.coords.clone(),
+        });
+    GeometryCollection(
+        repaired
+            .iter()
+            .map(|geometry| rebuild(geometry, &mut rebuilt))
+            .collect(),
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Ring,
+    Line,
+    // A member which is left as it is, all of whose coordinates are junctions
+    Fixed,
+}
+
+// The coordinates of a line or ring of a member.
+struct Sequence<T: GeoFloat> {
+    coords: Vec<Coordinate<T>>,
+    kind: Kind,
+}
+
+// The arcs along a sequence, each with whether it's followed backwards.
+type Path = Vec<(usize, bool)>;
+
+fn repair_collection<T: GeoFloat>(collection: &GeometryCollection<T>) -> GeometryCollection<T> {
+    GeometryCollection(collection.iter().map(repair).collect())
+}
+
+fn repair<T: GeoFloat>(geometry: &Geometry<T>) -> Geometry<T> {
+    match geometry {
+        Geometry::LineString(line_string) => Geometry::LineString(repair_line(line_string)),
+        Geometry::MultiLineString(multi_line_string) => Geometry::MultiLineString(MultiLineString(
+            multi_line_string.iter().map(repair_line).collect(),
+        )),
+        Geometry::Polygon(polygon) => Geometry::Polygon(
+            repair_polygon(polygon).unwrap_or_else(|| Polygon::new(LineString(vec![]), vec![])),
+        ),
+        Geometry::MultiPolygon(multi_polygon) => Geometry::MultiPolygon(MultiPolygon(
+            multi_polygon.iter().filter_map(repair_polygon).collect(),
+        )),
+        Geometry::GeometryCollection(collection) => {
+            Geometry::GeometryCollection(repair_collection(collection))
+        }
+        _ => geometry.clone(),
+    }
+}
+
+fn repair_line<T: GeoFloat>(line_string: &LineString<T>) -> LineString<T> {
+    let mut coords = line_string.0.clone();
+    coords.dedup();
+    LineString(coords)
+}
+
+// The polygon without its degenerate rings, unless its exterior is.
+fn repair_polygon<T: GeoFloat>(polygon: &Polygon<T>) -> Option<Polygon<T>> {
+    let repair_ring = |ring: &LineString<T>| {
+        let mut ring = repair_line(ring);
+        if ring.0.is_empty() {
+            return None;
+        }
+        ring.close();
+        ring.winding_order().map(|_| ring)
+    };
+    Some(Polygon::new(
+        repair_ring(polygon.exterior())?,
+        polygon.interiors().iter().filter_map(repair_ring).collect(),
+    ))
+}
+
+fn collect_sequences<T: GeoFloat>(geometry: &Geometry<T>, sequences: &mut Vec<Sequence<T>>) {
+    match geometry {
+        Geometry::Point(point) => push(sequences, vec![point.0], Kind::Fixed),
+        Geometry::Line(line) => push(sequences, vec![line.start, line.end], Kind::Fixed),
+        Geometry::LineString(line_string) => {
+            push(sequences, line_string.0.clone(), line_kind(line_string))
+        }
+        Geometry::Polygon(polygon) => polygon_sequences(polygon, sequences),
+        Geometry::MultiPoint(multi_point) => {
+            for point in multi_point {
+                push(sequences, vec![point.0], Kind::Fixed);
+            }
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string {
+                push(sequences, line_string.0.clone(), line_kind(line_string));
+            }
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon {
+                polygon_sequences(polygon, sequences);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection {
+                collect_sequences(geometry, sequences);
+            }
+        }
+        Geometry::Rect(rect) => push(
+            sequences,
+            rect.to_polygon().exterior().0.clone(),
+            Kind::Fixed,
+        ),
+        Geometry::Triangle(triangle) => push(
+            sequences,
+            triangle.to_polygon().exterior().0.clone(),
+            Kind::Fixed,
+        ),
+    }
+}
+
+fn push<T: GeoFloat>(sequences: &mut Vec<Sequence<T>>, coords: Vec<Coordinate<T>>, kind: Kind) {
+    sequences.push(Sequence { coords, kind });
+}
+
+fn line_kind<T: GeoFloat>(line_string: &LineString<T>) -> Kind {
+    if line_string.0.len() < 2 {
+        Kind::Fixed
+    } else {
+        Kind::Line
+    }
+}
+
+fn polygon_sequences<T: GeoFloat>(polygon: &Polygon<T>, sequences: &mut Vec<Sequence<T>>) {
+    if polygon.exterior().0.is_empty() {
+        return;
+    }
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        push(sequences, ring.0.clone(), Kind::Ring);
+    }
+}
+
+// Add the points at which the sequences cross or touch each other to both, so that their shared
+// boundaries have the same coordinates.
+fn node<T: GeoFloat>(sequences: &mut [Sequence<T>]) {
+    let mut owners = vec![];
+    let mut lines = vec![];
+    for (index, sequence) in sequences.iter().enumerate() {
+        for (position, pair) in sequence.coords.windows(2).enumerate() {
+            owners.push((index, position));
+            lines.push(Line::new(pair[0], pair[1]));
+        }
+    }
+    let mut insertions: Vec<Vec<Vec<Coordinate<T>>>> = sequences
+        .iter()
+        .map(|sequence| vec![vec![]; sequence.coords.len().saturating_sub(1)])
+        .collect();
+    let sweep_line = SweepLine::new(lines.iter().copied());
+    for intersection in sweep_line.intersections() {
+        let points = match intersection.intersection {
+            LineIntersection::SinglePoint { intersection, .. } => vec![intersection],
+            LineIntersection::Collinear { intersection } => {
+                vec![intersection.start, intersection.end]
+            }
+        };
+        for segment in &[intersection.first, intersection.second] {
+            let line = lines[*segment];
+            let (index, position) = owners[*segment];
+            for point in &points {
+                if *point != line.start && *point != line.end {
+                    insertions[index][position].push(*point);
+                }
+            }
+        }
+    }
+
+    for (sequence, insertions) in sequences.iter_mut().zip(insertions) {
+        if insertions.iter().all(|points| points.is_empty()) {
+            continue;
+        }
+        let mut coords = vec![sequence.coords[0]];
+        for (pair, mut points) in sequence.coords.windows(2).zip(insertions) {
+            let distance = |coord: &Coordinate<T>| {
+                let delta = *coord - pair[0];
+                delta.x * delta.x + delta.y * delta.y
+            };
+            points.sort_by(|a, b| distance(a).partial_cmp(&distance(b)).unwrap());
+            points.dedup();
+            coords.extend(points);
+            coords.push(pair[1]);
+        }
+        sequence.coords = coords;
+    }
+}
+
+// The coordinates at which the sequences part ways, in lexicographic order: those which the
+// sequences through them reach from, or leave to, different neighbours, along with the ends of
+// lines and the coordinates of fixed members.
+fn junctions<T: GeoFloat>(sequences: &[Sequence<T>]) -> Vec<Coordinate<T>> {
+    let ordered = |a: Coordinate<T>, b: Coordinate<T>| {
+        if lex_cmp(&a, &b) == Ordering::Greater {
+            (b, a)
+        } else {
+            (a, b)
+        }
+    };
+    let mut occurrences = vec![];
+    for sequence in sequences {
+        let coords = &sequence.coords;
+        match sequence.kind {
+            Kind::Fixed => occurrences.extend(coords.iter().map(|coord| (*coord, None))),
+            Kind::Line => {
+                let last = coords.len() - 1;
+                occurrences.push((coords[0], None));
+                occurrences.push((coords[last], None));
+                for index in 1..last {
+                    let neighbours = ordered(coords[index - 1], coords[index + 1]);
+                    occurrences.push((coords[index], Some(neighbours)));
+                }
+            }
+            Kind::Ring => {
+                let count = coords.len() - 1;
+                for index in 0..count {
+                    let previous = coords[(index + count - 1) % count];
+                    let neighbours = ordered(previous, coords[index + 1]);
+                    occurrences.push((coords[index], Some(neighbours)));
+                }
+            }
+        }
+    }
+    occurrences.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+
+    let mut junctions = vec![];
+    let mut start = 0;
+    for index in 1..=occurrences.len() {
+        if index < occurrences.len() && occurrences[index].0 == occurrences[start].0 {
+            continue;
+        }
+        let group = &occurrences[start..index];
+        let neighbours = group[0].1;
+        if neighbours.is_none() || group.iter().any(|occurrence| occurrence.1 != neighbours) {
+            junctions.push(group[0].0);
+        }
+        start = index;
+    }
+    junctions
+}
+
+// The distinct arcs of the sequences, each running between junctions, or around a ring without
+// any, and the path of arcs along each sequence which isn't fixed.
+fn arcs<T: GeoFloat>(
+    sequences: &[Sequence<T>],
+    is_junction: &dyn Fn(Coordinate<T>) -> bool,
+) -> (Vec<Vec<Coordinate<T>>>, Vec<Option<Path>>) {
+    // each piece of each sequence, running in its canonical direction
+    let mut pieces: Vec<(Vec<Coordinate<T>>, usize, bool)> = vec![];
+    let mut paths = vec![];
+    for (index, sequence) in sequences.iter().enumerate() {
+        let coords = &sequence.coords;
+        let cuts: Vec<usize> = match sequence.kind {
+            Kind::Fixed => {
+                paths.push(None);
+                continue;
+            }
+            Kind::Line => (0..coords.len())
+                .filter(|index| is_junction(coords[*index]))
+                .collect(),
+            Kind::Ring => {
+                let count = coords.len() - 1;
+                let start = (0..count)
+                    .find(|index| is_junction(coords[*index]))
+                    .unwrap_or_else(|| least_index(&coords[..count]));
+                let rotated: Vec<_> = coords[start..count]
+                    .iter()
+                    .chain(&coords[..=start])
+                    .copied()
+                    .collect();
+                let mut cuts: Vec<usize> = (0..count)
+                    .filter(|index| is_junction(rotated[*index]))
+                    .collect();
+                if cuts.first() != Some(&0) {
+                    cuts.insert(0, 0);
+                }
+                cuts.push(count);
+                add_pieces(&rotated, &cuts, index, &mut pieces);
+                paths.push(Some(vec![]));
+                continue;
+            }
+        };
+        add_pieces(coords, &cuts, index, &mut pieces);
+        paths.push(Some(vec![]));
+    }
+
+    let mut order: Vec<usize> = (0..pieces.len()).collect();
+    order.sort_by(|a, b| cmp_coords(&pieces[*a].0, &pieces[*b].0));
+    let mut arcs: Vec<Vec<Coordinate<T>>> = vec![];
+    let mut arc_of_piece = vec![0; pieces.len()];
+    for piece in order {
+        if arcs.last() != Some(&pieces[piece].0) {
+            arcs.push(pieces[piece].0.clone());
+        }
+        arc_of_piece[piece] = arcs.len() - 1;
+    }
+    for (piece, (_, sequence, reversed)) in pieces.iter().enumerate() {
+        if let Some(path) = &mut paths[*sequence] {
+            path.push((arc_of_piece[piece], *reversed));
+        }
+    }
+    (arcs, paths)
+}
+
+fn add_pieces<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    cuts: &[usize],
+    sequence: usize,
+    pieces: &mut Vec<(Vec<Coordinate<T>>, usize, bool)>,
+) {
+    for pair in cuts.windows(2) {
+        let piece = coords[pair[0]..=pair[1]].to_vec();
+        let mut reversed = piece.clone();
+        reversed.reverse();
+        if cmp_coords(&reversed, &piece) == Ordering::Less {
+            pieces.push((reversed, sequence, true));
+        } else {
+            pieces.push((piece, sequence, false));
+        }
+    }
+}
+
+// The coordinates along a path of simplified arcs.
+fn follow<T: GeoFloat>(path: &[(usize, bool)], arcs: &[Vec<Coordinate<T>>]) -> Vec<Coordinate<T>> {
+    let mut coords: Vec<Coordinate<T>> = vec![];
+    for (arc, reversed) in path {
+        let arc = &arcs[*arc];
+        let skip = if coords.is_empty() { 0 } else { 1 };
+        if *reversed {
+            coords.extend(arc.iter().rev().skip(skip));
+        } else {
+            coords.extend(arc.iter().skip(skip));
+        }
+    }
+    coords
+}
+
+// Restore the original coordinates of the simplified arcs which collapse a ring, or cross or
+// touch an arc other than at a junction, returning whether any were.
+fn restore_invalid_arcs<T: GeoFloat>(
+    arcs: &[Vec<Coordinate<T>>],
+    simplified: &mut [Vec<Coordinate<T>>],
+    sequences: &[Sequence<T>],
+    paths: &[Option<Path>],
+    is_junction: &dyn Fn(Coordinate<T>) -> bool,
+) -> bool {
+    let mut invalid = vec![false; arcs.len()];
+    for (sequence, path) in sequences.iter().zip(paths) {
+        if let (Kind::Ring, Some(path)) = (sequence.kind, path) {
+            let ring = LineString(follow(path, simplified));
+            if ring.0.len() < 4 || ring.winding_order().is_none() {
+                for (arc, _) in path {
+                    invalid[*arc] = true;
+                }
+            }
+        }
+    }
+
+    // the segments of the simplified arcs, with those of the fixed members, which have no arc
+    let mut owners = vec![];
+    let mut lines = vec![];
+    for (arc, coords) in simplified.iter().enumerate() {
+        for (position, pair) in coords.windows(2).enumerate() {
+            owners.push((Some(arc), position));
+            lines.push(Line::new(pair[0], pair[1]));
+        }
+    }
+    for sequence in sequences {
+        if sequence.kind == Kind::Fixed {
+            for pair in sequence.coords.windows(2) {
+                owners.push((None, 0));
+                lines.push(Line::new(pair[0], pair[1]));
+            }
+        }
+    }
+    let sweep_line = SweepLine::new(lines.iter().copied());
+    for intersection in sweep_line.intersections() {
+        let (first, second) = (lines[intersection.first], lines[intersection.second]);
+        let ((first_arc, first_position), (second_arc, second_position)) =
+            (owners[intersection.first], owners[intersection.second]);
+        let allowed = match intersection.intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                let is_end = |line: Line<T>| intersection == line.start || intersection == line.end;
+                // consecutive segments of an arc, including the last and first of a closed one
+                let consecutive = match (first_arc, second_arc) {
+                    (Some(first_arc), Some(second_arc)) if first_arc == second_arc => {
+                        let last = simplified[first_arc].len() - 2;
+                        let closed = simplified[first_arc].first() == simplified[first_arc].last();
+                        first_position + 1 == second_position
+                            || second_position + 1 == first_position
+                            || closed
+                                && first_position.min(second_position) == 0
+                                && first_position.max(second_position) == last
+                    }
+                    _ => false,
+                };
+                is_end(first) && is_end(second) && (consecutive || is_junction(intersection))
+            }
+            LineIntersection::Collinear { .. } => false,
+        };
+        if !allowed {
+            for arc in [first_arc, second_arc].iter().flatten() {
+                invalid[*arc] = true;
+            }
+        }
+    }
+
+    let mut restored = false;
+    for (arc, invalid) in invalid.into_iter().enumerate() {
+        if invalid && simplified[arc] != arcs[arc] {
+            simplified[arc] = arcs[arc].clone();
+            restored = true;
+        }
+    }
+    restored
+}
+
+// The member, with its lines and rings taken from `sequences`, in the order they were collected.
+fn rebuild<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    sequences: &mut impl Iterator<Item = Vec<Coordinate<T>>>,
+) -> Geometry<T> {
+    match geometry {
+        Geometry::LineString(_) => Geometry::LineString(LineString(sequences.next().unwrap())),
+        Geometry::MultiLineString(multi_line_string) => Geometry::MultiLineString(MultiLineString(
+            multi_line_string
+                .iter()
+                .map(|_| LineString(sequences.next().unwrap()))
+                .collect(),
+        )),
+        Geometry::Polygon(polygon) => Geometry::Polygon(rebuild_polygon(polygon, sequences)),
+        Geometry::MultiPolygon(multi_polygon) => Geometry::MultiPolygon(MultiPolygon(
+            multi_polygon
+                .iter()
+                .map(|polygon| rebuild_polygon(polygon, sequences))
+                .collect(),
+        )),
+        Geometry::GeometryCollection(collection) => {
+            Geometry::GeometryCollection(GeometryCollection(
+                collection
+                    .iter()
+                    .map(|geometry| rebuild(geometry, sequences))
+                    .collect(),
+            ))
+        }
+        // fixed members are left as they are
+        Geometry::MultiPoint(multi_point) => {
+            sequences.take(multi_point.0.len()).for_each(drop);
+            geometry.clone()
+        }
+        Geometry::Point(_) | Geometry::Line(_) | Geometry::Rect(_) | Geometry::Triangle(_) => {
+            sequences.next();
+            geometry.clone()
+        }
+    }
+}
+
+fn rebuild_polygon<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    sequences: &mut impl Iterator<Item = Vec<Coordinate<T>>>,
+) -> Polygon<T> {
+    if polygon.exterior().0.is_empty() {
+        return polygon.clone();
+    }
+    let exterior = LineString(sequences.next().unwrap());
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .map(|_| LineString(sequences.next().unwrap()))
+        .collect();
+    Polygon::new(exterior, interiors).orient(Direction::Default)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn lines_and_points_pin_arcs() {
+        // a road along the bottom of a parcel, and a survey marker on its top
+        let parcel = polygon![
+            (x: 0., y: 0.), (x: 2., y: 0.1), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 4.1),
+            (x: 0., y: 4.),
+        ];
+        let road = line_string![(x: 0., y: 0.), (x: 2., y: 0.1), (x: 4., y: 0.)];
+        let marker = point!(x: 2., y: 4.1);
+        let collection = GeometryCollection(vec![
+            Geometry::from(parcel),
+            Geometry::from(road.clone()),
+            Geometry::from(marker),
+        ]);
+        let generalized = generalize(&collection, 0.5);
+
+        // the road is simplified along with the parcel's edge
+        let simplified_road = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(generalized.0[1], Geometry::from(simplified_road));
+        // but the marker keeps its corner of the parcel
+        assert_eq!(
+            generalized.0[0],
+            Geometry::from(polygon![
+                (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 2., y: 4.1), (x: 0., y: 4.),
+            ])
+        );
+        assert_eq!(generalized.0[2], Geometry::from(marker));
+    }
+
+    #[test]
+    fn rings_neither_collapse_nor_cross() {
+        // a small square island, which would simplify to a line
+        let island: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 0.2, y: 0.), (x: 0.2, y: 0.2), (x: 0., y: 0.2)];
+        // a bay whose hole lies just inside a bump of its exterior, which would cross the
+        // hole if it were straightened
+        let bay = polygon![
+            exterior: [
+                (x: 10., y: 0.), (x: 15., y: 0.), (x: 15.3, y: 5.), (x: 15., y: 10.),
+                (x: 10., y: 10.),
+            ],
+            interiors: [[
+                (x: 14.9, y: 4.9), (x: 15.1, y: 4.9), (x: 15.1, y: 5.1), (x: 14.9, y: 5.1),
+            ]],
+        ];
+        // and a polygon with a repeated coordinate and a degenerate hole, wound clockwise
+        let messy = polygon![
+            exterior: [
+                (x: 20., y: 0.), (x: 20., y: 1.), (x: 20., y: 1.), (x: 21., y: 1.),
+                (x: 21., y: 0.),
+            ],
+            interiors: [[(x: 20.2, y: 0.2), (x: 20.4, y: 0.4), (x: 20.2, y: 0.2)]],
+        ];
+        let collection = GeometryCollection(vec![
+            Geometry::from(island.clone()),
+            Geometry::from(bay.clone()),
+            Geometry::from(messy),
+        ]);
+        let generalized = generalize(&collection, 0.5);
+        assert_eq!(generalized.0[0], Geometry::from(island));
+        assert_eq!(
+            generalized.0[1],
+            Geometry::from(bay.orient(Direction::Default))
+        );
+        assert_eq!(
+            generalized.0[2],
+            Geometry::from(polygon![
+                (x: 20., y: 0.), (x: 21., y: 0.), (x: 21., y: 1.), (x: 20., y: 1.),
+            ])
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::cancel::{self, Cancel, NeverCancelled};
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::erosion::{faces_where, Erosion};
+use crate::algorithm::map_coords::MapCoords;
+use crate::algorithm::variable_buffer::{union, VariableBuffer};
+use crate::{Coordinate, GeoError, LineString, MultiPolygon, Point, Polygon, Rect};
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+use std::iter;
+
+/// Buffer a geometry of longitudes and latitudes by a distance in metres on an ellipsoidal model
+/// of the earth, where a planar buffer by a distance in degrees would be stretched north to south
+/// away from the equator.
+///
+/// Each geometry is projected onto a plane by the azimuthal equidistant projection centred on its
+/// bounding rectangle, in which distances from the centre are true geodesic distances, using the
+/// algorithms presented in _Algorithms for geodesics_ by Charles Karney (2013). It's buffered
+/// there, with circles approximated by polygons, as by
+/// [`VariableBuffer`](crate::algorithm::variable_buffer::VariableBuffer), and the buffer
+/// projected back. The projection distorts distances away from its centre, so the buffer is most
+/// accurate for geometries spanning no more than a few hundred kilometres.
+///
+/// The longitudes of the buffer run on past ±180° rather than wrapping around, where it crosses
+/// the antimeridian, so it stays in one piece.
+pub trait GeodesicBuffer {
+    /// The points within `distance` metres of the geometry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::bounding_rect::BoundingRect;
+    /// use geo::algorithm::geodesic_buffer::GeodesicBuffer;
+    /// use geo::algorithm::geodesic_distance::GeodesicDistance;
+    /// use geo::Point;
+    ///
+    /// let oslo = Point::new(10.75, 59.91);
+    /// let buffer = oslo.geodesic_buffer(10_000.);
+    /// for coord in buffer.0[0].exterior().0.iter() {
+    ///     let distance = oslo.geodesic_distance(&Point(*coord));
+    ///     assert!((distance - 10_000.).abs() < 1e-3);
+    /// }
+    ///
+    /// // twice as many degrees of longitude as of latitude, at 60° north
+    /// let rect = buffer.bounding_rect().unwrap();
+    /// assert!(rect.width() > rect.height() * 1.9);
+    /// ```
+    fn geodesic_buffer(&self, distance: f64) -> MultiPolygon<f64> {
+        self.geodesic_buffer_cancellable(distance, &NeverCancelled)
+            .expect("never cancelled")
+    }
+
+    /// The buffer, as given by [`geodesic_buffer`](Self::geodesic_buffer), failing with
+    /// [`GeoError::Cancelled`] once `cancel` is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is negative.
+    fn geodesic_buffer_cancellable(
+        &self,
+        distance: f64,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<f64>, GeoError>;
+}
+
+impl GeodesicBuffer for Point<f64> {
+    fn geodesic_buffer_cancellable(
+        &self,
+        distance: f64,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<f64>, GeoError> {
+        LineString(vec![self.0]).geodesic_buffer_cancellable(distance, cancel)
+    }
+}
+
+impl GeodesicBuffer for LineString<f64> {
+    fn geodesic_buffer_cancellable(
+        &self,
+        distance: f64,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<f64>, GeoError> {
+        assert!(distance >= 0., "distance must not be negative");
+        let projection = match self.bounding_rect() {
+            Some(rect) => Projection::centred_on(rect),
+            None => return Ok(MultiPolygon(vec![])),
+        };
+        let projected = self.map_coords(|&(x, y)| projection.project(x, y));
+        let distances = vec![distance; projected.0.len()];
+        let buffer = projected.variable_buffer_cancellable(&distances, cancel)?;
+        Ok(projection.unproject(&buffer))
+    }
+}
+
+impl GeodesicBuffer for Polygon<f64> {
+    fn geodesic_buffer_cancellable(
+        &self,
+        distance: f64,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<f64>, GeoError> {
+        assert!(distance >= 0., "distance must not be negative");
+        let projection = match self.bounding_rect() {
+            Some(rect) => Projection::centred_on(rect),
+            None => return Ok(MultiPolygon(vec![])),
+        };
+        let projected = self.map_coords(|&(x, y)| projection.project(x, y));
+
+        // the polygon without its holes, and the buffers of its rings
+        let mut pieces = vec![Polygon::new(projected.exterior().clone(), vec![])];
+        for ring in iter::once(projected.exterior()).chain(projected.interiors()) {
+            let distances = vec![distance; ring.0.len()];
+            pieces.extend(ring.variable_buffer_cancellable(&distances, cancel)?.0);
+        }
+        let outer = union(&pieces, cancel)?;
+        // what's left of the holes, shrunk by the distance
+        let holes: Vec<Polygon<f64>> = projected
+            .interiors()
+            .iter()
+            .flat_map(|ring| Polygon::new(ring.clone(), vec![]).erode(distance).0)
+            .collect();
+        if holes.is_empty() {
+            return Ok(projection.unproject(&outer));
+        }
+        cancel::check(cancel)?;
+
+        let segments = outer
+            .iter()
+            .chain(&holes)
+            .flat_map(|polygon| iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .flat_map(|ring| ring.lines())
+            .collect();
+        let buffer = faces_where(segments, |coord| {
+            outer.coordinate_position(&coord) == CoordPos::Inside
+                && !holes
+                    .iter()
+                    .any(|hole| hole.coordinate_position(&coord) == CoordPos::Inside)
+        });
+        Ok(projection.unproject(&MultiPolygon(buffer)))
+    }
+}
+
+// The azimuthal equidistant projection on the WGS 84 ellipsoid, in metres, centred on a point.
+struct Projection {
+    geodesic: Geodesic,
+    center: Coordinate<f64>,
+}
+
+impl Projection {
+    fn centred_on(rect: Rect<f64>) -> Self {
+        Projection {
+            geodesic: Geodesic::wgs84(),
+            center: rect.center(),
+        }
+    }
+
+    fn project(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let Coordinate { x: lon0, y: lat0 } = self.center;
+        let (distance, azimuth, _, _) = self.geodesic.inverse(lat0, lon0, lat, lon);
+        let (sin, cos) = azimuth.to_radians().sin_cos();
+        (distance * sin, distance * cos)
+    }
+
+    fn unproject(&self, buffer: &MultiPolygon<f64>) -> MultiPolygon<f64> {
+        let Coordinate { x: lon0, y: lat0 } = self.center;
+        buffer.map_coords(|&(x, y)| {
+            let azimuth = x.atan2(y).to_degrees();
+            let (lat, lon) = self.geodesic.direct(lat0, lon0, azimuth, x.hypot(y));
+            // the longitude nearest the centre's, rather than wrapped into ±180°
+            let mut delta = (lon - lon0) % 360.;
+            if delta > 180. {
+                delta -= 360.;
+            } else if delta < -180. {
+                delta += 360.;
+            }
+            (lon0 + delta, lat)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geodesic_distance::GeodesicDistance;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn lines_near_the_antimeridian() {
+        let line = line_string![(x: 179.9, y: -16.), (x: 180.1, y: -16.)];
+        let buffer = line.geodesic_buffer(5_000.);
+        assert_eq!(buffer.0.len(), 1);
+        let rect = buffer.bounding_rect().unwrap();
+        assert!(rect.min().x > 179.8 && rect.max().x < 180.2);
+        // the buffer is 5 km either side of the line
+        let south = Point::new(180., rect.min().y);
+        assert_relative_eq!(
+            south.geodesic_distance(&Point::new(180., -16.)),
+            5_000.,
+            epsilon = 1.
+        );
+        assert!(line.geodesic_buffer(0.).0.is_empty());
+    }
+
+    #[test]
+    fn polygons_grow_and_holes_shrink() {
+        // a frame of about 11 by 11 km, around a hole of about 4.4 by 4.4 km
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 0.1, y: 0.), (x: 0.1, y: 0.1), (x: 0., y: 0.1)],
+            interiors: [[
+                (x: 0.03, y: 0.03),
+                (x: 0.07, y: 0.03),
+                (x: 0.07, y: 0.07),
+                (x: 0.03, y: 0.07),
+            ]],
+        ];
+        let buffer = frame.geodesic_buffer(1_000.);
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 1);
+        let hole = Polygon::new(buffer.0[0].interiors()[0].clone(), vec![]);
+        let hole = hole.bounding_rect().unwrap();
+        // a degree is about 111 km at the equator
+        assert_relative_eq!(hole.width(), 0.04 - 2. / 111.32, epsilon = 1e-4);
+        let outer = buffer.bounding_rect().unwrap();
+        assert_relative_eq!(outer.width(), 0.1 + 2. / 111.32, epsilon = 1e-4);
+
+        // a wider buffer fills the hole
+        let buffer = frame.geodesic_buffer(3_000.);
+        assert!(buffer.0[0].interiors().is_empty());
+    }
+}
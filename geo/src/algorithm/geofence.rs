@@ -0,0 +1,388 @@
+use crate::algorithm::crossing_direction::{CrossingDirection, Crossings};
+use crate::algorithm::orient::{Direction, Orient};
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Point};
+use std::collections::VecDeque;
+
+/// What happened to a track at a [`GeofenceEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeofenceEventKind {
+    /// The track entered the fence, or started inside it
+    Enter,
+    /// The track left the fence
+    Exit,
+    /// The track has been inside the fence for the dwell time since it entered
+    Dwell,
+}
+
+/// An event on a track, at a point which is interpolated between the points of the track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeofenceEvent<T: GeoFloat> {
+    /// What happened
+    pub kind: GeofenceEventKind,
+    /// Where the event happened
+    pub coord: Coordinate<T>,
+    /// When the event happened, interpolated linearly between the times of the track's points
+    pub time: T,
+}
+
+/// Detect a track entering, leaving, and dwelling in, an area.
+///
+/// The track is a sequence of timestamped points, joined by straight lines. Where it crosses the
+/// boundary of the fence, the crossing is found with [`Crossings`], so crossings at the fence's
+/// vertices, and at the track's points, are counted exactly once, and the track is never on the
+/// boundary. Events are emitted in order, with the coordinate and time of the crossing
+/// interpolated along the segment of the track which crosses.
+///
+/// The parts of the fence may overlap: the track is inside the fence while it is inside any of
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geofence::{Geofence, GeofenceEventKind::*};
+/// use geo::{point, polygon, Coordinate};
+///
+/// let fence = Geofence::new(
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     Some(5.),
+/// );
+///
+/// // a track crossing the fence from west to east at one unit per second
+/// let track = vec![
+///     (point!(x: -2., y: 5.), 0.),
+///     (point!(x: 4., y: 5.), 6.),
+///     (point!(x: 14., y: 5.), 16.),
+/// ];
+/// let events: Vec<_> = fence
+///     .events(track)
+///     .map(|event| (event.kind, event.coord, event.time))
+///     .collect();
+/// assert_eq!(
+///     events,
+///     vec![
+///         (Enter, Coordinate { x: 0., y: 5. }, 2.),
+///         (Dwell, Coordinate { x: 5., y: 5. }, 7.),
+///         (Exit, Coordinate { x: 10., y: 5. }, 12.),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Geofence<T: GeoFloat> {
+    /// The rings of the fence, with exteriors counter-clockwise and interiors clockwise, so the
+    /// fence is on their left
+    rings: Vec<LineString<T>>,
+    max_x: T,
+    dwell_time: Option<T>,
+}
+
+impl<T: GeoFloat> Geofence<T> {
+    /// A fence around `area`, which emits a [`Dwell`](GeofenceEventKind::Dwell) event once a
+    /// track has been inside it for `dwell_time`, if given.
+    pub fn new(area: impl Into<MultiPolygon<T>>, dwell_time: Option<T>) -> Self {
+        let area = area.into().orient(Direction::Default);
+        let rings: Vec<LineString<T>> = area
+            .into_iter()
+            .flat_map(|polygon| {
+                let (exterior, interiors) = polygon.into_inner();
+                std::iter::once(exterior).chain(interiors)
+            })
+            .collect();
+        let max_x = rings
+            .iter()
+            .flat_map(|ring| ring.0.iter())
+            .fold(T::neg_infinity(), |max_x, coord| max_x.max(coord.x));
+        Geofence {
+            rings,
+            max_x,
+            dwell_time,
+        }
+    }
+
+    /// Whether `coord` is inside the fence, as decided by [`Crossings`], so that a track
+    /// starting at `coord` is treated consistently.
+    pub fn contains(&self, coord: Coordinate<T>) -> bool {
+        self.depth(coord) > 0
+    }
+
+    /// The events of a track of `(point, time)` pairs, ordered by time.
+    pub fn events<I>(&self, track: I) -> GeofenceEvents<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = (Point<T>, T)>,
+    {
+        GeofenceEvents {
+            fence: self,
+            track: track.into_iter(),
+            previous: None,
+            depth: 0,
+            entered_at: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    // The number of parts of the fence which contain `coord`, found by counting the crossings of
+    // a ray from `coord` to beyond the fence.
+    fn depth(&self, coord: Coordinate<T>) -> i32 {
+        if coord.x > self.max_x {
+            return 0;
+        }
+        let ray = Line::new(
+            coord,
+            Coordinate {
+                x: self.max_x + T::one(),
+                y: coord.y,
+            },
+        );
+        self.crossings(ray)
+            .iter()
+            .map(|(_, direction)| match direction {
+                CrossingDirection::LeftToRight => 1,
+                CrossingDirection::RightToLeft => -1,
+            })
+            .sum()
+    }
+
+    // The crossings of all of the rings by `line`, in order along it.
+    fn crossings(&self, line: Line<T>) -> Vec<(Coordinate<T>, CrossingDirection)> {
+        let mut crossings: Vec<(T, Coordinate<T>, CrossingDirection)> = self
+            .rings
+            .iter()
+            .flat_map(|ring| ring.crossings(&line))
+            .map(|crossing| {
+                let offset = crossing.coord - line.start;
+                (
+                    offset.x * offset.x + offset.y * offset.y,
+                    crossing.coord,
+                    crossing.direction,
+                )
+            })
+            .collect();
+        crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        crossings
+            .into_iter()
+            .map(|(_, coord, direction)| (coord, direction))
+            .collect()
+    }
+}
+
+/// An iterator over the [`GeofenceEvent`]s of a track, created by [`Geofence::events`].
+#[derive(Debug)]
+pub struct GeofenceEvents<'a, T: GeoFloat, I> {
+    fence: &'a Geofence<T>,
+    track: I,
+    previous: Option<(Coordinate<T>, T)>,
+    /// The number of parts of the fence which the track is inside
+    depth: i32,
+    /// When the track entered the fence, until it leaves, or dwells
+    entered_at: Option<T>,
+    pending: VecDeque<GeofenceEvent<T>>,
+}
+
+impl<'a, T, I> Iterator for GeofenceEvents<'a, T, I>
+where
+    T: GeoFloat,
+    I: Iterator<Item = (Point<T>, T)>,
+{
+    type Item = GeofenceEvent<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let (point, time) = self.track.next()?;
+            self.advance(point.0, time);
+        }
+        self.pending.pop_front()
+    }
+}
+
+impl<'a, T, I> GeofenceEvents<'a, T, I>
+where
+    T: GeoFloat,
+{
+    // Move the track on to `coord` at `time`, queueing the events along the way.
+    fn advance(&mut self, coord: Coordinate<T>, time: T) {
+        let (start, start_time) = match self.previous.replace((coord, time)) {
+            Some(previous) => previous,
+            None => {
+                self.depth = self.fence.depth(coord);
+                if self.depth > 0 {
+                    self.enter(coord, time);
+                }
+                return;
+            }
+        };
+
+        let segment = Line::new(start, coord);
+        let length = segment.delta().x.hypot(segment.delta().y);
+        let duration = time - start_time;
+        for (crossing, direction) in self.fence.crossings(segment) {
+            let offset = crossing - start;
+            let ratio = if length.is_zero() {
+                T::zero()
+            } else {
+                offset.x.hypot(offset.y) / length
+            };
+            let crossing_time = start_time + duration * ratio;
+            self.dwell(segment, start_time, duration, crossing_time);
+
+            let was_inside = self.depth > 0;
+            self.depth += match direction {
+                CrossingDirection::RightToLeft => 1,
+                CrossingDirection::LeftToRight => -1,
+            };
+            match (was_inside, self.depth > 0) {
+                (false, true) => self.enter(crossing, crossing_time),
+                (true, false) => {
+                    self.entered_at = None;
+                    self.emit(GeofenceEventKind::Exit, crossing, crossing_time);
+                }
+                _ => {}
+            }
+        }
+        self.dwell(segment, start_time, duration, time);
+    }
+
+    fn enter(&mut self, coord: Coordinate<T>, time: T) {
+        self.entered_at = Some(time);
+        self.emit(GeofenceEventKind::Enter, coord, time);
+    }
+
+    // Queue a dwell event on `segment`, which the track took `duration` to follow from
+    // `start_time`, if the dwell time has passed by `until`.
+    fn dwell(&mut self, segment: Line<T>, start_time: T, duration: T, until: T) {
+        let dwell_time = match (self.entered_at, self.fence.dwell_time) {
+            (Some(entered_at), Some(dwell_time)) => entered_at + dwell_time,
+            _ => return,
+        };
+        if dwell_time > until {
+            return;
+        }
+        let ratio = if duration.is_zero() {
+            T::one()
+        } else {
+            ((dwell_time - start_time) / duration).max(T::zero())
+        };
+        self.entered_at = None;
+        self.emit(
+            GeofenceEventKind::Dwell,
+            segment.start + segment.delta() * ratio,
+            dwell_time,
+        );
+    }
+
+    fn emit(&mut self, kind: GeofenceEventKind, coord: Coordinate<T>, time: T) {
+        self.pending.push_back(GeofenceEvent { kind, coord, time });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, Polygon, Rect};
+    use GeofenceEventKind::*;
+
+    fn kinds<T: GeoFloat>(
+        events: impl Iterator<Item = GeofenceEvent<T>>,
+    ) -> Vec<GeofenceEventKind> {
+        events.map(|event| event.kind).collect()
+    }
+
+    #[test]
+    fn starting_inside() {
+        let fence = Geofence::new(Rect::new((0., 0.), (10., 10.)).to_polygon_ccw(), None);
+        let events: Vec<_> = fence
+            .events(vec![
+                (point!(x: 5., y: 5.), 0.),
+                (point!(x: 15., y: 5.), 10.),
+            ])
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                GeofenceEvent {
+                    kind: Enter,
+                    coord: Coordinate { x: 5., y: 5. },
+                    time: 0.
+                },
+                GeofenceEvent {
+                    kind: Exit,
+                    coord: Coordinate { x: 10., y: 5. },
+                    time: 5.
+                },
+            ]
+        );
+        assert!(fence.contains(Coordinate { x: 5., y: 5. }));
+        assert!(!fence.contains(Coordinate { x: 15., y: 5. }));
+    }
+
+    #[test]
+    fn points_on_the_boundary() {
+        let fence = Geofence::new(Rect::new((0., 0.), (10., 10.)).to_polygon_ccw(), None);
+        // stopping on the boundary, and turning back
+        let track = vec![
+            (point!(x: -5., y: 5.), 0.),
+            (point!(x: 0., y: 5.), 1.),
+            (point!(x: -5., y: 6.), 2.),
+        ];
+        assert_eq!(kinds(fence.events(track)).len() % 2, 0);
+        // passing through a corner, and along an edge
+        let track = vec![
+            (point!(x: -5., y: -5.), 0.),
+            (point!(x: 0., y: 0.), 1.),
+            (point!(x: 10., y: 0.), 2.),
+            (point!(x: 10., y: 5.), 3.),
+            (point!(x: 5., y: 5.), 4.),
+            (point!(x: 5., y: 15.), 5.),
+        ];
+        assert_eq!(kinds(fence.events(track)), vec![Enter, Exit]);
+    }
+
+    #[test]
+    fn holes_and_overlapping_parts() {
+        let with_hole: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        ];
+        let fence = Geofence::new(with_hole, None);
+        let track = vec![(point!(x: -1., y: 5.), 0.), (point!(x: 11., y: 5.), 12.)];
+        let events: Vec<_> = fence.events(track).collect();
+        assert_eq!(
+            kinds(events.iter().cloned()),
+            vec![Enter, Exit, Enter, Exit]
+        );
+        assert_eq!(
+            events.iter().map(|event| event.time).collect::<Vec<_>>(),
+            vec![1., 5., 7., 11.]
+        );
+
+        // the track is inside throughout the overlap
+        let fence = Geofence::new(
+            MultiPolygon(vec![
+                Rect::new((0., 0.), (6., 6.)).to_polygon_ccw(),
+                Rect::new((4., 4.), (10., 10.)).to_polygon_ccw(),
+            ]),
+            None,
+        );
+        let track = vec![(point!(x: -1., y: 5.), 0.), (point!(x: 11., y: 5.), 12.)];
+        assert_eq!(kinds(fence.events(track)), vec![Enter, Exit]);
+    }
+
+    #[test]
+    fn dwelling() {
+        let fence = Geofence::new(Rect::new((0., 0.), (10., 10.)).to_polygon_ccw(), Some(3.));
+        // entering, leaving too soon, then entering and staying
+        let track = vec![
+            (point!(x: -1., y: 5.), 0.),
+            (point!(x: 1., y: 5.), 2.),
+            (point!(x: -1., y: 5.), 4.),
+            (point!(x: 5., y: 5.), 10.),
+            (point!(x: 5., y: 5.), 20.),
+        ];
+        let events: Vec<_> = fence.events(track).collect();
+        assert_eq!(
+            kinds(events.iter().cloned()),
+            vec![Enter, Exit, Enter, Dwell]
+        );
+        // 3 seconds after entering at 5, part of the way along the segment
+        assert_eq!(events[3].time, 8.);
+        assert_eq!(events[3].coord, Coordinate { x: 3., y: 5. });
+    }
+}
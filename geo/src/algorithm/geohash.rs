@@ -0,0 +1,304 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::rasterize::{rasterize, BurnRule};
+use crate::{Coordinate, GeoFloat, Geometry, Point, Rect};
+
+/// The digits of geohashes, each encoding five bits.
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The longest geohash which fits the 64 bits cells are numbered with, locating a point to
+/// within a few centimetres.
+pub const MAX_PRECISION: usize = 12;
+
+/// Encode a point, in degrees of longitude and latitude, as the geohash with `precision`
+/// digits of the cell it lies in.
+///
+/// Points on the boundary between cells lie in the cell to their north or east, except at the
+/// antimeridian and the north pole, where they lie in the cells at the edge of the world.
+///
+/// # Panics
+///
+/// If `precision` is more than [`MAX_PRECISION`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geohash::encode_geohash;
+/// use geo::point;
+///
+/// let jutland = point!(x: 10.40744, y: 57.64911);
+/// assert_eq!(encode_geohash(jutland, 11), "u4pruydqqvj");
+/// assert_eq!(encode_geohash(jutland, 3), "u4p");
+/// ```
+pub fn encode_geohash<T: GeoFloat>(point: Point<T>, precision: usize) -> String {
+    let grid = GeohashGrid::new(precision);
+    let index = |value: T, min: T, extent: T, count: u64| {
+        let count_t = T::from(count).unwrap();
+        ((value - min) / extent * count_t)
+            .floor()
+            .max(T::zero())
+            .min(count_t - T::one())
+            .to_u64()
+            .unwrap()
+    };
+    let column = index(
+        point.x(),
+        T::from(-180).unwrap(),
+        T::from(360).unwrap(),
+        grid.columns(),
+    );
+    let row = index(
+        point.y(),
+        T::from(-90).unwrap(),
+        T::from(180).unwrap(),
+        grid.rows(),
+    );
+    grid.geohash(column, row)
+}
+
+/// Decode a geohash into the cell it names, in degrees of longitude and latitude.
+///
+/// Returns `None` if `geohash` has a character which isn't a geohash digit, or more than
+/// [`MAX_PRECISION`] of them. The empty geohash names the whole world.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geohash::decode_geohash;
+/// use geo::Rect;
+///
+/// assert_eq!(
+///     decode_geohash::<f64>("s"),
+///     Some(Rect::new((0., 0.), (45., 45.)))
+/// );
+/// assert_eq!(decode_geohash::<f64>("sa"), None);
+/// ```
+pub fn decode_geohash<T: GeoFloat>(geohash: &str) -> Option<Rect<T>> {
+    if geohash.len() > MAX_PRECISION {
+        return None;
+    }
+    let grid = GeohashGrid::new(geohash.len());
+    let mut bits = 0u64;
+    for digit in geohash.bytes() {
+        let value = BASE32.iter().position(|candidate| *candidate == digit)?;
+        bits = bits << 5 | value as u64;
+    }
+    let (column, row) = grid.deinterleave(bits);
+    Some(grid.cell(column, row))
+}
+
+/// The geohashes with `precision` digits of the cells which a geometry, in degrees of
+/// longitude and latitude, intersects, in order.
+///
+/// The cells are tested exactly, as [`rasterize`] does under [`BurnRule::AllTouched`]:
+/// polygons cover the cells they share some area with, and lines and points the cells they
+/// touch, even at their edges. Together the cells cover the geometry, and no cell could be
+/// left out, so querying a backend keyed by geohash with them finds everything within the
+/// geometry.
+///
+/// PERF: each cell within the bounding rectangle of the geometry is tested, so the cost grows
+/// with the square of the ratio of the geometry's size to the cells'.
+///
+/// # Panics
+///
+/// If `precision` is more than [`MAX_PRECISION`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geohash::geohash_cover;
+/// use geo::{polygon, Geometry};
+///
+/// // a triangle over the cells around null island
+/// let triangle: Geometry<f64> =
+///     polygon![(x: -10., y: -10.), (x: 10., y: -10.), (x: -10., y: 10.)].into();
+/// assert_eq!(geohash_cover(&triangle, 1), vec!["7", "e", "k"]);
+/// ```
+pub fn geohash_cover<T: GeoFloat>(geometry: &Geometry<T>, precision: usize) -> Vec<String> {
+    let grid = GeohashGrid::new(precision);
+    let extent = match geometry.bounding_rect() {
+        Some(extent) => extent,
+        None => return vec![],
+    };
+
+    // the cells whose closed extents may meet the geometry's, including those it only touches
+    let range = |min: T, max: T, world_min: T, world_extent: T, count: u64| {
+        let count_t = T::from(count).unwrap();
+        let index = |value: T| (value - world_min) / world_extent * count_t;
+        let clamp = |index: T| {
+            index
+                .max(T::zero())
+                .min(count_t - T::one())
+                .to_u64()
+                .unwrap()
+        };
+        (
+            clamp(index(min).ceil() - T::one()),
+            clamp(index(max).floor()),
+        )
+    };
+    let (min_column, max_column) = range(
+        extent.min().x,
+        extent.max().x,
+        T::from(-180).unwrap(),
+        T::from(360).unwrap(),
+        grid.columns(),
+    );
+    let (min_row, max_row) = range(
+        extent.min().y,
+        extent.max().y,
+        T::from(-90).unwrap(),
+        T::from(180).unwrap(),
+        grid.rows(),
+    );
+    let bounds = Rect::new(
+        grid.cell(min_column, min_row).min(),
+        grid.cell(max_column, max_row).max(),
+    );
+    let rows = (max_row - min_row + 1) as usize;
+    let columns = (max_column - min_column + 1) as usize;
+    let mask = rasterize(geometry, bounds, rows, columns, BurnRule::AllTouched);
+
+    let mut cover = vec![];
+    for (row, cells) in mask.iter().enumerate() {
+        for (column, cell) in cells.iter().enumerate() {
+            if *cell {
+                cover.push(grid.geohash(min_column + column as u64, min_row + row as u64));
+            }
+        }
+    }
+    cover.sort();
+    cover
+}
+
+// The grid of cells named by geohashes with `precision` digits. Their bits alternate between
+// the column and the row of the cell, starting with the column.
+struct GeohashGrid {
+    precision: usize,
+}
+
+impl GeohashGrid {
+    fn new(precision: usize) -> Self {
+        assert!(
+            precision <= MAX_PRECISION,
+            "geohash precision {} is beyond {}",
+            precision,
+            MAX_PRECISION
+        );
+        GeohashGrid { precision }
+    }
+
+    fn column_bits(&self) -> usize {
+        self.precision * 5 - self.row_bits()
+    }
+
+    fn row_bits(&self) -> usize {
+        self.precision * 5 / 2
+    }
+
+    fn columns(&self) -> u64 {
+        1 << self.column_bits()
+    }
+
+    fn rows(&self) -> u64 {
+        1 << self.row_bits()
+    }
+
+    fn cell<T: GeoFloat>(&self, column: u64, row: u64) -> Rect<T> {
+        let x = |column: u64| {
+            T::from(-180).unwrap()
+                + T::from(360).unwrap() * T::from(column).unwrap()
+                    / T::from(self.columns()).unwrap()
+        };
+        let y = |row: u64| {
+            T::from(-90).unwrap()
+                + T::from(180).unwrap() * T::from(row).unwrap() / T::from(self.rows()).unwrap()
+        };
+        Rect::new(
+            Coordinate {
+                x: x(column),
+                y: y(row),
+            },
+            Coordinate {
+                x: x(column + 1),
+                y: y(row + 1),
+            },
+        )
+    }
+
+    fn geohash(&self, column: u64, row: u64) -> String {
+        let (mut column_bit, mut row_bit) = (self.column_bits(), self.row_bits());
+        let mut bits = 0u64;
+        for index in 0..self.precision * 5 {
+            let bit = if index % 2 == 0 {
+                column_bit -= 1;
+                column >> column_bit & 1
+            } else {
+                row_bit -= 1;
+                row >> row_bit & 1
+            };
+            bits = bits << 1 | bit;
+        }
+        (0..self.precision)
+            .rev()
+            .map(|digit| BASE32[(bits >> (digit * 5) & 31) as usize] as char)
+            .collect()
+    }
+
+    fn deinterleave(&self, bits: u64) -> (u64, u64) {
+        let (mut column, mut row) = (0u64, 0u64);
+        let length = self.precision * 5;
+        for index in 0..length {
+            let bit = bits >> (length - 1 - index) & 1;
+            if index % 2 == 0 {
+                column = column << 1 | bit;
+            } else {
+                row = row << 1 | bit;
+            }
+        }
+        (column, row)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn round_trip() {
+        let points = vec![
+            point!(x: -122.4194, y: 37.7749),
+            point!(x: 151.2093, y: -33.8688),
+            point!(x: -180., y: -90.),
+            point!(x: 180., y: 90.),
+        ];
+        for point in points {
+            for precision in 0..=MAX_PRECISION {
+                let geohash = encode_geohash(point, precision);
+                assert_eq!(geohash.len(), precision);
+                let cell: Rect<f64> = decode_geohash(&geohash).unwrap();
+                assert!(cell.min().x <= point.x() && point.x() <= cell.max().x);
+                assert!(cell.min().y <= point.y() && point.y() <= cell.max().y);
+                // and each digit narrows the cell down within its prefix
+                if precision > 0 {
+                    let parent: Rect<f64> = decode_geohash(&geohash[..precision - 1]).unwrap();
+                    assert!(parent.min().x <= cell.min().x && cell.max().x <= parent.max().x);
+                    assert!(parent.min().y <= cell.min().y && cell.max().y <= parent.max().y);
+                }
+            }
+        }
+        assert_eq!(encode_geohash(point!(x: -122.4194, y: 37.7749), 5), "9q8yy");
+        assert_eq!(decode_geohash::<f64>("9q8yyk8ytpxr1"), None);
+    }
+
+    #[test]
+    fn cover_of_a_line() {
+        // along the equator, on the boundary between the cells to its north and south
+        let equator: Geometry<f64> = line_string![(x: 1., y: 0.), (x: 50., y: 0.)].into();
+        assert_eq!(geohash_cover(&equator, 1), vec!["k", "m", "s", "t"]);
+        // a cover at a finer precision lies within the cover at a coarser one
+        let cover = geohash_cover(&equator, 2);
+        assert_eq!(cover.len(), 2 * 5);
+        assert!(cover.iter().all(|geohash| "kmst".contains(&geohash[..1])));
+    }
+}
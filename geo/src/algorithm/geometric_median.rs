@@ -0,0 +1,330 @@
+use crate::{Coordinate, GeoFloat, MultiPoint, Point};
+
+const DEFAULT_MAX_ITERATIONS: usize = 1000;
+
+/// Calculate the [geometric median](https://en.wikipedia.org/wiki/Geometric_median): the point
+/// minimising the sum of the Euclidean distances to a set of points.
+///
+/// Where the centroid minimises the sum of *squared* distances, and so is easily dragged around
+/// by outliers, the geometric median is the best place to put a single facility serving all of
+/// the points. It is computed with
+/// [Weiszfeld's algorithm](https://en.wikipedia.org/wiki/Geometric_median#Computation), using the
+/// modification of Vardi and Zhang so that the iteration can step off, or settle on, one of the
+/// input points.
+///
+/// If the points are collinear, and even in number, any point between the middle two is a median.
+/// One of them is returned.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::centroid::Centroid;
+/// use geo::algorithm::geometric_median::GeometricMedian;
+/// use geo::{point, MultiPoint};
+///
+/// let points = MultiPoint(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 1., y: 0.),
+///     point!(x: 0., y: 1.),
+///     point!(x: 100., y: 100.),
+/// ]);
+///
+/// // the outlier pulls the centroid far away from the cluster of points
+/// assert_eq!(points.centroid(), Some(point!(x: 25.25, y: 25.25)));
+///
+/// // while the median stays close to them
+/// let median = points.geometric_median().unwrap();
+/// assert!(median.x() < 1. && median.y() < 1.);
+/// ```
+pub trait GeometricMedian<T: GeoFloat> {
+    /// The geometric median, or `None` if there are no points.
+    ///
+    /// The iteration stops once an iteration moves the estimate by no more than
+    /// `sqrt(T::epsilon())` times the extent of the points, or after 1000 iterations.
+    fn geometric_median(&self) -> Option<Point<T>>;
+
+    /// The geometric median, or `None` if there are no points.
+    ///
+    /// The iteration stops once an iteration moves the estimate by no more than `tolerance`, or
+    /// after `max_iterations` iterations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::geometric_median::GeometricMedian;
+    /// use geo::Coordinate;
+    ///
+    /// let coords: Vec<Coordinate<f64>> =
+    ///     vec![(0., 0.).into(), (4., 0.).into(), (0., 3.).into(), (4., 3.).into()];
+    ///
+    /// let median = coords.geometric_median_with_tolerance(1e-9, 100).unwrap();
+    /// assert!((median.x() - 2.).abs() < 1e-9 && (median.y() - 1.5).abs() < 1e-9);
+    /// ```
+    fn geometric_median_with_tolerance(
+        &self,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Option<Point<T>>;
+}
+
+impl<T: GeoFloat> GeometricMedian<T> for [Coordinate<T>] {
+    fn geometric_median(&self) -> Option<Point<T>> {
+        self.geometric_median_with_tolerance(default_tolerance(self), DEFAULT_MAX_ITERATIONS)
+    }
+
+    fn geometric_median_with_tolerance(
+        &self,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Option<Point<T>> {
+        weiszfeld(self, tolerance, max_iterations).map(Point)
+    }
+}
+
+impl<T: GeoFloat> GeometricMedian<T> for MultiPoint<T> {
+    fn geometric_median(&self) -> Option<Point<T>> {
+        self.geometric_median_with_tolerance(
+            default_tolerance(&coords(self)),
+            DEFAULT_MAX_ITERATIONS,
+        )
+    }
+
+    fn geometric_median_with_tolerance(
+        &self,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Option<Point<T>> {
+        coords(self).geometric_median_with_tolerance(tolerance, max_iterations)
+    }
+}
+
+fn coords<T: GeoFloat>(multi_point: &MultiPoint<T>) -> Vec<Coordinate<T>> {
+    multi_point.iter().map(|point| point.0).collect()
+}
+
+// A tolerance proportionate to the extent of the coordinates.
+fn default_tolerance<T: GeoFloat>(coords: &[Coordinate<T>]) -> T {
+    let first = match coords.first() {
+        Some(first) => *first,
+        None => return T::zero(),
+    };
+    let (min, max) = coords.iter().fold((first, first), |(min, max), coord| {
+        (
+            Coordinate {
+                x: min.x.min(coord.x),
+                y: min.y.min(coord.y),
+            },
+            Coordinate {
+                x: max.x.max(coord.x),
+                y: max.y.max(coord.y),
+            },
+        )
+    });
+    let extent = (max.x - min.x).max(max.y - min.y);
+    extent * T::epsilon().sqrt()
+}
+
+fn weiszfeld<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    tolerance: T,
+    max_iterations: usize,
+) -> Option<Coordinate<T>> {
+    if coords.is_empty() {
+        return None;
+    }
+
+    // start from the centroid, which is where the median is for symmetric point sets
+    let count = T::from(coords.len()).unwrap();
+    let mut median = coords
+        .iter()
+        .fold(Coordinate::zero(), |sum, coord| sum + *coord)
+        / count;
+
+    for _ in 0..max_iterations {
+        let step = weiszfeld_step(coords, median);
+        if step.is_settled {
+            return Some(median);
+        }
+        let offset = step.next - median;
+        median = step.next;
+        if offset.x.hypot(offset.y) <= tolerance {
+            break;
+        }
+    }
+
+    // The iteration only creeps towards a median which lies on one of the points, so check
+    // whether the nearest one is optimal, and return it exactly if so.
+    let nearest = coords
+        .iter()
+        .map(|coord| (*coord, (*coord - median).x.hypot((*coord - median).y)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(coord, _)| coord)
+        .unwrap();
+    if weiszfeld_step(coords, nearest).is_settled {
+        return Some(nearest);
+    }
+
+    Some(median)
+}
+
+struct WeiszfeldStep<T: GeoFloat> {
+    next: Coordinate<T>,
+    /// Whether `median` is already optimal
+    is_settled: bool,
+}
+
+fn weiszfeld_step<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    median: Coordinate<T>,
+) -> WeiszfeldStep<T> {
+    let mut weighted_sum = Coordinate::zero();
+    let mut weight = T::zero();
+    // The sum of the unit vectors from the estimate towards each of the points
+    let mut pull = Coordinate::zero();
+    // The number of points the estimate coincides with
+    let mut coincident = T::zero();
+
+    for coord in coords {
+        let offset = *coord - median;
+        let distance = offset.x.hypot(offset.y);
+        if distance.is_zero() {
+            coincident = coincident + T::one();
+            continue;
+        }
+        weighted_sum = weighted_sum + *coord / distance;
+        weight = weight + T::one() / distance;
+        pull = pull + offset / distance;
+    }
+
+    if weight.is_zero() {
+        // every point coincides with the estimate
+        return WeiszfeldStep {
+            next: median,
+            is_settled: true,
+        };
+    }
+
+    let next = weighted_sum / weight;
+    if coincident.is_zero() {
+        return WeiszfeldStep {
+            next,
+            is_settled: false,
+        };
+    }
+
+    // The estimate is on an input point. It is the median unless the other points pull harder
+    // than the coincident ones do, in which case step towards the plain Weiszfeld estimate, by as
+    // much as they outweigh them.
+    let pull = pull.x.hypot(pull.y);
+    if pull <= coincident {
+        return WeiszfeldStep {
+            next: median,
+            is_settled: true,
+        };
+    }
+    let ratio = coincident / pull;
+    WeiszfeldStep {
+        next: next * (T::one() - ratio) + median * ratio,
+        is_settled: false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    // The sum of the distances from `median` to `coords`.
+    fn cost(coords: &[Coordinate<f64>], median: Point<f64>) -> f64 {
+        coords
+            .iter()
+            .map(|coord| (*coord - median.0).x.hypot((*coord - median.0).y))
+            .sum()
+    }
+
+    #[test]
+    fn empty() {
+        let coords: Vec<Coordinate<f64>> = vec![];
+        assert_eq!(coords.geometric_median(), None);
+        assert_eq!(MultiPoint::<f64>(vec![]).geometric_median(), None);
+    }
+
+    #[test]
+    fn single_and_repeated_points() {
+        let points = MultiPoint(vec![point!(x: 3., y: 4.)]);
+        assert_eq!(points.geometric_median(), Some(point!(x: 3., y: 4.)));
+
+        let points = MultiPoint(vec![point!(x: 3., y: 4.); 3]);
+        assert_eq!(points.geometric_median(), Some(point!(x: 3., y: 4.)));
+    }
+
+    #[test]
+    fn symmetric_points() {
+        let coords: Vec<Coordinate<f64>> = vec![
+            (-1., -1.).into(),
+            (1., -1.).into(),
+            (1., 1.).into(),
+            (-1., 1.).into(),
+        ];
+        assert_eq!(coords.geometric_median(), Some(point!(x: 0., y: 0.)));
+    }
+
+    #[test]
+    fn median_on_an_input_point() {
+        // collinear points: the median is the middle one
+        let coords: Vec<Coordinate<f64>> = vec![(0., 0.).into(), (1., 0.).into(), (10., 0.).into()];
+        assert_eq!(coords.geometric_median(), Some(point!(x: 1., y: 0.)));
+
+        // a triangle with an angle of at least 120 degrees has its median at that vertex
+        let coords: Vec<Coordinate<f64>> =
+            vec![(0., 0.).into(), (-10., 1.).into(), (10., 1.).into()];
+        assert_eq!(coords.geometric_median(), Some(point!(x: 0., y: 0.)));
+    }
+
+    #[test]
+    fn fermat_point() {
+        // for a triangle with all angles below 120 degrees, the median is the point from which
+        // each side subtends 120 degrees
+        let coords: Vec<Coordinate<f64>> =
+            vec![(0., 0.).into(), (2., 0.).into(), (1., 3f64.sqrt()).into()];
+        let median = coords.geometric_median().unwrap();
+        assert_relative_eq!(median, point!(x: 1., y: 3f64.sqrt() / 3.), epsilon = 1e-7);
+    }
+
+    #[test]
+    fn minimises_total_distance() {
+        let coords: Vec<Coordinate<f64>> = vec![
+            (0., 0.).into(),
+            (5., 1.).into(),
+            (2., 7.).into(),
+            (-3., 4.).into(),
+            (9., 9.).into(),
+            (1., -6.).into(),
+        ];
+        let median = coords.geometric_median().unwrap();
+        let best = cost(&coords, median);
+        for (dx, dy) in &[(1e-4, 0.), (-1e-4, 0.), (0., 1e-4), (0., -1e-4)] {
+            let nearby = Point::new(median.x() + dx, median.y() + dy);
+            assert!(best < cost(&coords, nearby));
+        }
+
+        let multi_point: MultiPoint<_> = coords.iter().map(|coord| Point(*coord)).collect();
+        assert_eq!(multi_point.geometric_median(), Some(median));
+    }
+
+    #[test]
+    fn iterations_are_limited() {
+        let coords: Vec<Coordinate<f64>> = vec![(0., 0.).into(), (4., 0.).into(), (0., 3.).into()];
+        // with no iterations, the estimate is the starting point: the centroid
+        let centroid = point!(x: 4. / 3., y: 1.);
+        assert_eq!(
+            coords.geometric_median_with_tolerance(0., 0),
+            Some(centroid)
+        );
+
+        let once = coords.geometric_median_with_tolerance(0., 1).unwrap();
+        let converged = coords.geometric_median().unwrap();
+        assert!(cost(&coords, once) < cost(&coords, centroid));
+        assert!(cost(&coords, converged) < cost(&coords, once));
+    }
+}
@@ -0,0 +1,228 @@
+use std::hash::{Hash, Hasher};
+
+use crate::algorithm::map_coords::MapCoords;
+use crate::algorithm::normalize::Normalize;
+use crate::{Coordinate, GeoFloat, Geometry, Polygon};
+
+/// A geometry which can be used as the key of a `HashMap` or `HashSet`, as when deduplicating or
+/// caching results by geometry.
+///
+/// The geometry is [normalized](Normalize) when the key is made, so keys of geometries which
+/// differ only in where their rings start, which way they run, or the order of their members
+/// are equal and hash alike. Two keys are equal when their normalized geometries have the same
+/// type, structure, and coordinates, bit for bit, except that `0.0` and `-0.0` are the same.
+///
+/// With [`quantized`](GeometryKey::quantized), each coordinate is first rounded to the nearest
+/// multiple of a quantum, so that geometries which differ by less than floating point noise
+/// share a key. Geometries either side of a rounding boundary still differ, however close they
+/// are; [`GeometryDeduper`](crate::algorithm::dedup::GeometryDeduper) finds duplicates within a
+/// tolerance without that edge.
+///
+/// Requires the `hash` feature.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geometry_hash::GeometryKey;
+/// use geo::{line_string, polygon};
+/// use std::collections::HashSet;
+///
+/// let mut seen = HashSet::new();
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert!(seen.insert(GeometryKey::new(square)));
+///
+/// // the same square, clockwise from another corner
+/// let copy = polygon![(x: 1., y: 1.), (x: 1., y: 0.), (x: 0., y: 0.), (x: 0., y: 1.)];
+/// assert!(!seen.insert(GeometryKey::new(copy)));
+///
+/// // lines which differ by floating point noise, rounded to a millimetre grid
+/// let a = line_string![(x: 0.1 + 0.2, y: 0.), (x: 5., y: 5.)];
+/// let b = line_string![(x: 0.3, y: 0.), (x: 5., y: 5.)];
+/// assert_ne!(GeometryKey::new(a.clone()), GeometryKey::new(b.clone()));
+/// assert_eq!(
+///     GeometryKey::quantized(a, 0.001_f64),
+///     GeometryKey::quantized(b, 0.001_f64)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct GeometryKey<T: GeoFloat> {
+    geometry: Geometry<T>,
+    // the type, sizes, and coordinate bits of the geometry, in order
+    words: Vec<u64>,
+}
+
+impl<T: GeoFloat> GeometryKey<T> {
+    /// The key of a geometry, which is normalized.
+    pub fn new(geometry: impl Into<Geometry<T>>) -> Self {
+        let geometry = geometry.into().normalize();
+        let mut words = vec![];
+        encode_geometry(&geometry, &mut words);
+        GeometryKey { geometry, words }
+    }
+
+    /// The key of a geometry, whose coordinates are rounded to the nearest multiple of
+    /// `quantum` before it's normalized.
+    ///
+    /// # Panics
+    ///
+    /// If `quantum` is not positive.
+    pub fn quantized(geometry: impl Into<Geometry<T>>, quantum: T) -> Self {
+        assert!(quantum > T::zero(), "quantum must be positive");
+        let geometry = geometry.into().map_coords(|&(x, y)| {
+            (
+                (x / quantum).round() * quantum,
+                (y / quantum).round() * quantum,
+            )
+        });
+        Self::new(geometry)
+    }
+
+    /// The normalized, and perhaps quantized, geometry.
+    pub fn geometry(&self) -> &Geometry<T> {
+        &self.geometry
+    }
+
+    pub fn into_geometry(self) -> Geometry<T> {
+        self.geometry
+    }
+}
+
+impl<T: GeoFloat> PartialEq for GeometryKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.words == other.words
+    }
+}
+
+impl<T: GeoFloat> Eq for GeometryKey<T> {}
+
+impl<T: GeoFloat> Hash for GeometryKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words.hash(state)
+    }
+}
+
+fn encode_geometry<T: GeoFloat>(geometry: &Geometry<T>, words: &mut Vec<u64>) {
+    match geometry {
+        Geometry::Point(point) => {
+            words.push(0);
+            encode_coords(&[point.0], words);
+        }
+        Geometry::Line(line) => {
+            words.push(1);
+            encode_coords(&[line.start, line.end], words);
+        }
+        Geometry::LineString(line) => {
+            words.push(2);
+            encode_coords(&line.0, words);
+        }
+        Geometry::Polygon(polygon) => {
+            words.push(3);
+            encode_polygon(polygon, words);
+        }
+        Geometry::MultiPoint(points) => {
+            words.push(4);
+            let coords: Vec<Coordinate<T>> = points.0.iter().map(|point| point.0).collect();
+            encode_coords(&coords, words);
+        }
+        Geometry::MultiLineString(lines) => {
+            words.push(5);
+            words.push(lines.0.len() as u64);
+            for line in &lines.0 {
+                encode_coords(&line.0, words);
+            }
+        }
+        Geometry::MultiPolygon(polygons) => {
+            words.push(6);
+            words.push(polygons.0.len() as u64);
+            for polygon in &polygons.0 {
+                encode_polygon(polygon, words);
+            }
+        }
+        Geometry::GeometryCollection(collection) => {
+            words.push(7);
+            words.push(collection.0.len() as u64);
+            for geometry in &collection.0 {
+                encode_geometry(geometry, words);
+            }
+        }
+        Geometry::Rect(rect) => {
+            words.push(8);
+            encode_coords(&[rect.min(), rect.max()], words);
+        }
+        Geometry::Triangle(triangle) => {
+            words.push(9);
+            encode_coords(&triangle.to_array(), words);
+        }
+    }
+}
+
+fn encode_polygon<T: GeoFloat>(polygon: &Polygon<T>, words: &mut Vec<u64>) {
+    words.push(polygon.interiors().len() as u64);
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        encode_coords(&ring.0, words);
+    }
+}
+
+fn encode_coords<T: GeoFloat>(coords: &[Coordinate<T>], words: &mut Vec<u64>) {
+    words.push(coords.len() as u64);
+    for coord in coords {
+        for value in [coord.x, coord.y].iter() {
+            // adding zero turns -0.0 into 0.0
+            let (mantissa, exponent, sign) = (*value + T::zero()).integer_decode();
+            words.push(mantissa);
+            words.push(((exponent as u16 as u64) << 8) | sign as u8 as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, GeometryCollection, MultiPoint};
+    use std::collections::HashMap;
+
+    #[test]
+    fn keys_of_equivalent_geometries() {
+        let mut counts = HashMap::new();
+        let geometries: Vec<Geometry<f64>> = vec![
+            MultiPoint(vec![point!(x: 1., y: 1.), point!(x: 0., y: 0.)]).into(),
+            MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]).into(),
+            MultiPoint(vec![point!(x: -0., y: 0.), point!(x: 1., y: 1.)]).into(),
+            // the same coordinates, but a different type
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+            line_string![(x: 1., y: 1.), (x: 0., y: 0.)].into(),
+        ];
+        for geometry in geometries {
+            *counts.entry(GeometryKey::new(geometry)).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 2);
+        let points: Geometry<f64> =
+            MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]).into();
+        assert_eq!(counts[&GeometryKey::new(points)], 3);
+    }
+
+    #[test]
+    fn collections_and_quantization() {
+        let a = Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: 1.04, y: 2.).into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)].into(),
+        ]));
+        let b = Geometry::GeometryCollection(GeometryCollection(vec![
+            line_string![(x: 0.96, y: 0.), (x: 0., y: 0.)].into(),
+            point!(x: 1., y: 2.).into(),
+        ]));
+        assert_ne!(GeometryKey::new(a.clone()), GeometryKey::new(b.clone()));
+        let (a, b) = (
+            GeometryKey::quantized(a, 0.5),
+            GeometryKey::quantized(b, 0.5),
+        );
+        assert_eq!(a, b);
+        assert_eq!(
+            a.geometry(),
+            &Geometry::GeometryCollection(GeometryCollection(vec![
+                point!(x: 1., y: 2.).into(),
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)].into(),
+            ]))
+        );
+    }
+}
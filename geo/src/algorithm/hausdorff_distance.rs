@@ -0,0 +1,132 @@
+use crate::coords_iter::CoordsIter;
+use crate::euclidean_distance::EuclideanDistance;
+use crate::{GeoFloat, Point};
+
+/// Determine how far apart two geometries are at their most dissimilar, using the discrete
+/// [Hausdorff distance].
+///
+/// The distances are measured from the coordinates of each geometry to the whole of the other,
+/// as JTS's `DiscreteHausdorffDistance` does. Between coordinates, a geometry may stray further
+/// from the other: [densify](crate::algorithm::line_measures::Densify) it first for a closer
+/// approximation.
+///
+/// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+pub trait HausdorffDistance<T, Rhs = Self> {
+    /// The greatest distance from a coordinate of `self` to the nearest point of `rhs`: the
+    /// directed Hausdorff distance from `self` to `rhs`.
+    ///
+    /// It's `0` when `self` lies entirely on, or in, `rhs`, however much more of `rhs` there is,
+    /// so it's the measure of how well a short line is covered by a longer one. It's `0` if
+    /// `self` is empty, and infinite if only `rhs` is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    /// use geo::line_string;
+    ///
+    /// let road = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+    /// let trace = line_string![(x: 2., y: 1.), (x: 4., y: -1.), (x: 6., y: 0.5)];
+    ///
+    /// // the trace stays within 1 of the road
+    /// assert_relative_eq!(trace.max_distance_from(&road), 1.);
+    /// // but most of the road is far from the trace
+    /// assert_relative_eq!(road.max_distance_from(&trace), 4f64.hypot(0.5));
+    /// ```
+    fn max_distance_from(&self, rhs: &Rhs) -> T;
+
+    /// The Hausdorff distance between `self` and `rhs`: the greater of the directed distances
+    /// each way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    /// use geo::{line_string, polygon};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    /// let line = line_string![(x: 2., y: 2.), (x: 6., y: 2.)];
+    ///
+    /// // from the corners of the square at (0, 0) and (0, 4) to the line
+    /// assert_relative_eq!(square.hausdorff_distance(&line), 2f64.hypot(2.));
+    /// assert_relative_eq!(line.hausdorff_distance(&square), 2f64.hypot(2.));
+    /// ```
+    fn hausdorff_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T, A, B> HausdorffDistance<T, B> for A
+where
+    T: GeoFloat,
+    A: for<'a> CoordsIter<'a, Scalar = T>,
+    B: for<'a> CoordsIter<'a, Scalar = T>,
+    Point<T>: EuclideanDistance<T, A> + EuclideanDistance<T, B>,
+{
+    fn max_distance_from(&self, rhs: &B) -> T {
+        directed_distance(self, rhs)
+    }
+
+    fn hausdorff_distance(&self, rhs: &B) -> T {
+        directed_distance(self, rhs).max(directed_distance(rhs, self))
+    }
+}
+
+fn directed_distance<T, A, B>(from: &A, to: &B) -> T
+where
+    T: GeoFloat,
+    A: for<'a> CoordsIter<'a, Scalar = T>,
+    B: for<'a> CoordsIter<'a, Scalar = T>,
+    Point<T>: EuclideanDistance<T, B>,
+{
+    if from.coords_count() == 0 {
+        return T::zero();
+    }
+    if to.coords_count() == 0 {
+        return T::infinity();
+    }
+    from.coords_iter()
+        .map(|coord| Point::from(coord).euclidean_distance(to))
+        .fold(T::zero(), T::max)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, LineString, MultiPoint};
+
+    #[test]
+    fn directed_distance_is_asymmetric() {
+        let long = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let short = line_string![(x: 1., y: 0.5), (x: 9., y: -0.5)];
+
+        assert_relative_eq!(short.max_distance_from(&long), 0.5);
+        // from (10, 10) to the middle of `short`
+        let farthest = 85. / 65f64.sqrt();
+        assert_relative_eq!(long.max_distance_from(&short), farthest);
+        assert_relative_eq!(long.hausdorff_distance(&short), farthest);
+        assert_relative_eq!(short.hausdorff_distance(&long), farthest);
+    }
+
+    #[test]
+    fn distance_into_polygons() {
+        let square = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let inside = point!(x: 1., y: 1.);
+        assert_eq!(inside.max_distance_from(&square), 0.);
+        assert_relative_eq!(square.max_distance_from(&inside), 3f64.hypot(3.));
+    }
+
+    #[test]
+    fn identical_and_empty() {
+        let line = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 5., y: 0.)];
+        assert_eq!(line.hausdorff_distance(&line.clone()), 0.);
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.max_distance_from(&line), 0.);
+        assert_eq!(line.max_distance_from(&empty), f64::INFINITY);
+
+        let points: MultiPoint<f64> = vec![(0., 0.), (3., 4.)].into();
+        // from (3, 4) to the segment from (1, 1) to (5, 0)
+        assert_relative_eq!(points.max_distance_from(&line), 14. / 17f64.sqrt());
+    }
+}
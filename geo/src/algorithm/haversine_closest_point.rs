@@ -0,0 +1,409 @@
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::{
+    Closest, CoordFloat, GeoFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use num_traits::FromPrimitive;
+use std::iter;
+
+/// Find the closest point of a geometry to a given point, on a sphere, where lines follow great
+/// circles.
+///
+/// Coordinates are longitude and latitude, in degrees. Unlike [`ClosestPoint`], which works in
+/// the plane, this is correct near the poles, across the antimeridian, and over long distances.
+/// Areas are treated as their rings.
+///
+/// The closest point of a line segment may lie between its ends, or be one of them. The
+/// distance to it, in meters, is the [`HaversineDistance`] from the point to the segment. If a
+/// point is equally far from every point of a segment, because it is a pole of the segment's
+/// great circle, or the ends of the segment are antipodal, so that it doesn't define a great
+/// circle, the closest point is [`Closest::Indeterminate`].
+///
+/// [`ClosestPoint`]: crate::algorithm::closest_point::ClosestPoint
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// #
+/// use geo::algorithm::haversine_closest_point::HaversineClosestPoint;
+/// use geo::prelude::*;
+/// use geo::{point, Closest, Line};
+///
+/// // a great-circle segment from New York City to London
+/// let line = Line::new((-74.006, 40.7128), (-0.1278, 51.5074));
+///
+/// // the great circle bulges north of the straight line between the two in longitude and
+/// // latitude, passing north of St John's, Newfoundland, which the straight line passes south of
+/// let st_johns = point!(x: -52.7126, y: 47.5615);
+/// let closest = match line.haversine_closest_point(&st_johns) {
+///     Closest::SinglePoint(closest) => closest,
+///     _ => unreachable!(),
+/// };
+/// assert!(closest.y() > st_johns.y());
+///
+/// // the distance to the segment is the distance to the closest point
+/// let distance = st_johns.haversine_distance(&line);
+/// assert_relative_eq!(distance, st_johns.haversine_distance(&closest), epsilon = 1e-6);
+/// ```
+pub trait HaversineClosestPoint<T: GeoFloat> {
+    /// The closest point of `self` to `from`.
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T>;
+}
+
+impl<T> HaversineClosestPoint<T> for Point<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self == from {
+            Closest::Intersection(*self)
+        } else {
+            Closest::SinglePoint(*self)
+        }
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Line<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self.start == self.end {
+            return self.start_point().haversine_closest_point(from);
+        }
+
+        let start = Vector::from_point(self.start_point());
+        let end = Vector::from_point(self.end_point());
+        let p = Vector::from_point(*from);
+
+        // the pole of the great circle through the segment
+        let normal = match start.cross(end).normalize() {
+            Some(normal) => normal,
+            // the ends are antipodal
+            None => return Closest::Indeterminate,
+        };
+
+        // the closest point of the great circle, by projecting onto its plane, as
+        // `normal × (p × normal)`, which stays accurate when `p` is close to the pole
+        let foot = match p.cross(normal).normalize() {
+            Some(across) => normal.cross(across),
+            // `from` is a pole of the great circle
+            None => return Closest::Indeterminate,
+        };
+
+        let is_within =
+            start.cross(foot).dot(normal) >= T::zero() && foot.cross(end).dot(normal) >= T::zero();
+        if is_within {
+            if p.dot(normal) == T::zero() {
+                Closest::Intersection(*from)
+            } else {
+                Closest::SinglePoint(foot.to_point())
+            }
+        } else if p.sub(start).norm_squared() <= p.sub(end).norm_squared() {
+            self.start_point().haversine_closest_point(from)
+        } else {
+            self.end_point().haversine_closest_point(from)
+        }
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for LineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self.0.len() == 1 {
+            return Point(self.0[0]).haversine_closest_point(from);
+        }
+        closest_of(
+            self.lines().map(|line| line.haversine_closest_point(from)),
+            from,
+        )
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Polygon<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(
+            iter::once(self.exterior())
+                .chain(self.interiors())
+                .map(|ring| ring.haversine_closest_point(from)),
+            from,
+        )
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiPoint<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(
+            self.iter()
+                .map(|geometry| geometry.haversine_closest_point(from)),
+            from,
+        )
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiLineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(
+            self.iter()
+                .map(|geometry| geometry.haversine_closest_point(from)),
+            from,
+        )
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiPolygon<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(
+            self.iter()
+                .map(|geometry| geometry.haversine_closest_point(from)),
+            from,
+        )
+    }
+}
+
+// The closest of the `candidates` to `from`, preferring intersections.
+fn closest_of<T, I>(candidates: I, from: &Point<T>) -> Closest<T>
+where
+    T: GeoFloat + FromPrimitive,
+    I: IntoIterator<Item = Closest<T>>,
+{
+    let mut best = Closest::Indeterminate;
+    let mut best_distance = T::infinity();
+    for candidate in candidates {
+        match candidate {
+            Closest::Intersection(point) => return Closest::Intersection(point),
+            Closest::SinglePoint(point) => {
+                let distance = from.haversine_distance(&point);
+                if distance < best_distance {
+                    best = candidate;
+                    best_distance = distance;
+                }
+            }
+            Closest::Indeterminate => {}
+        }
+    }
+    best
+}
+
+// A point on the unit sphere, or a direction.
+#[derive(Clone, Copy)]
+pub(crate) struct Vector<T: CoordFloat> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T: CoordFloat> Vector<T> {
+    pub(crate) fn from_point(point: Point<T>) -> Self {
+        let (lat_sin, lat_cos) = point.y().to_radians().sin_cos();
+        let (lon_sin, lon_cos) = point.x().to_radians().sin_cos();
+        Vector {
+            x: lat_cos * lon_cos,
+            y: lat_cos * lon_sin,
+            z: lat_sin,
+        }
+    }
+
+    pub(crate) fn to_point(self) -> Point<T> {
+        let lat = self.z.atan2(self.x.hypot(self.y));
+        let lon = self.y.atan2(self.x);
+        Point::new(lon.to_degrees(), lat.to_degrees())
+    }
+
+    pub(crate) fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub(crate) fn cross(self, other: Self) -> Self {
+        Vector {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    pub(crate) fn sub(self, other: Self) -> Self {
+        Vector {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+
+    pub(crate) fn scale(self, factor: T) -> Self {
+        Vector {
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+
+    pub(crate) fn norm_squared(self) -> T {
+        self.dot(self)
+    }
+
+    // The unit vector in the same direction, unless this is too short to have one.
+    pub(crate) fn normalize(self) -> Option<Self> {
+        let norm = self.norm_squared().sqrt();
+        if norm <= T::epsilon() {
+            None
+        } else {
+            Some(self.scale(T::one() / norm))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn single_point(closest: Closest<f64>) -> Point<f64> {
+        match closest {
+            Closest::SinglePoint(point) => point,
+            _ => panic!("expected a single closest point, got {:?}", closest),
+        }
+    }
+
+    #[test]
+    fn along_the_equator() {
+        let line = Line::new((0., 0.), (10., 0.));
+        let closest = single_point(line.haversine_closest_point(&point!(x: 5., y: 1.)));
+        assert_relative_eq!(closest, point!(x: 5., y: 0.), epsilon = 1e-12);
+
+        // beyond the ends
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: -3., y: 1.)),
+            Closest::SinglePoint(point!(x: 0., y: 0.))
+        );
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: 12., y: -1.)),
+            Closest::SinglePoint(point!(x: 10., y: 0.))
+        );
+
+        // on the segment
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: 5., y: 0.)),
+            Closest::Intersection(point!(x: 5., y: 0.))
+        );
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let line = Line::new((170., 10.), (-170., 10.));
+        let closest = single_point(line.haversine_closest_point(&point!(x: 180., y: 0.)));
+        assert_relative_eq!(closest.x().abs(), 180., epsilon = 1e-9);
+        // the great circle bulges towards the pole
+        assert!(closest.y() > 10.);
+
+        // a planar projection would take the long way round, through the prime meridian
+        let distance = point!(x: 180., y: 0.).haversine_distance(&line);
+        assert_relative_eq!(
+            distance,
+            point!(x: 180., y: 0.).haversine_distance(&closest),
+            epsilon = 1e-6
+        );
+        assert!(distance < point!(x: 180., y: 0.).haversine_distance(&line.start_point()));
+    }
+
+    #[test]
+    fn near_the_pole() {
+        // a segment passing over the pole
+        let line = Line::new((0., 80.), (180., 80.));
+        let closest = single_point(line.haversine_closest_point(&point!(x: 90., y: 85.)));
+        assert_relative_eq!(closest.y(), 90., epsilon = 1e-9);
+        assert_relative_eq!(
+            point!(x: 90., y: 85.).haversine_distance(&line),
+            point!(x: 90., y: 85.).haversine_distance(&point!(x: 0., y: 90.)),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn cross_track_distance() {
+        // one degree north of a meridian segment is one degree of arc away from it
+        let line = Line::new((0., -10.), (0., 10.));
+        let from = point!(x: 1., y: 0.);
+        assert_relative_eq!(
+            from.haversine_distance(&line),
+            from.haversine_distance(&point!(x: 0., y: 0.)),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            line.haversine_distance(&from),
+            from.haversine_distance(&line)
+        );
+    }
+
+    #[test]
+    fn indeterminate() {
+        // the north pole is equally far from every point of the equator
+        let line = Line::new((0., 0.), (10., 0.));
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: 0., y: 90.)),
+            Closest::Indeterminate
+        );
+        // antipodal ends don't define a great circle
+        let line = Line::new((0., 0.), (180., 0.));
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: 10., y: 10.)),
+            Closest::Indeterminate
+        );
+
+        // a zero-length segment is a point
+        let line = Line::new((5., 5.), (5., 5.));
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: 0., y: 90.)),
+            Closest::SinglePoint(point!(x: 5., y: 5.))
+        );
+    }
+
+    #[test]
+    fn larger_geometries() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let closest = single_point(line_string.haversine_closest_point(&point!(x: 11., y: 5.)));
+        assert_relative_eq!(closest.x(), 10., epsilon = 1e-12);
+        assert_eq!(
+            line_string.haversine_closest_point(&point!(x: 10., y: 0.)),
+            Closest::Intersection(point!(x: 10., y: 0.))
+        );
+
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_eq!(
+            polygon.haversine_closest_point(&point!(x: -5., y: -5.)),
+            Closest::SinglePoint(point!(x: 0., y: 0.))
+        );
+        let multi_polygon = MultiPolygon(vec![polygon]);
+        assert_eq!(
+            multi_polygon.haversine_closest_point(&point!(x: -5., y: -5.)),
+            Closest::SinglePoint(point!(x: 0., y: 0.))
+        );
+
+        let multi_point = MultiPoint(vec![point!(x: 170., y: 0.), point!(x: -175., y: 0.)]);
+        assert_eq!(
+            multi_point.haversine_closest_point(&point!(x: 179., y: 0.)),
+            Closest::SinglePoint(point!(x: -175., y: 0.))
+        );
+
+        let empty: MultiLineString<f64> = MultiLineString(vec![]);
+        assert_eq!(
+            empty.haversine_closest_point(&point!(x: 0., y: 0.)),
+            Closest::Indeterminate
+        );
+    }
+}
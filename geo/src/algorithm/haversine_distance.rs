@@ -1,4 +1,5 @@
-use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use crate::algorithm::haversine_closest_point::HaversineClosestPoint;
+use crate::{Closest, CoordFloat, GeoFloat, Line, Point, MEAN_EARTH_RADIUS};
 use num_traits::FromPrimitive;
 
 /// Determine the distance between two geometries using the [haversine formula].
@@ -56,6 +57,36 @@ where
     }
 }
 
+/// The distance from a point to the closest point of a great-circle segment, in meters.
+///
+/// Where the closest point lies between the ends of the segment, this is the cross-track
+/// distance, from the point to the great circle. If the ends of the segment are antipodal, it is
+/// the distance to the nearer one.
+impl<T> HaversineDistance<T, Line<T>> for Point<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_distance(&self, line: &Line<T>) -> T {
+        match line.haversine_closest_point(self) {
+            Closest::Intersection(_) => T::zero(),
+            Closest::SinglePoint(closest) => self.haversine_distance(&closest),
+            Closest::Indeterminate => self
+                .haversine_distance(&line.start_point())
+                .min(self.haversine_distance(&line.end_point())),
+        }
+    }
+}
+
+/// The distance from the closest point of a great-circle segment to a point, in meters.
+impl<T> HaversineDistance<T, Point<T>> for Line<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_distance(&self, point: &Point<T>) -> T {
+        point.haversine_distance(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::algorithm::haversine_distance::HaversineDistance;
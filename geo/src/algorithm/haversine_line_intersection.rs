@@ -0,0 +1,340 @@
+use crate::algorithm::haversine_closest_point::Vector;
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::{Coordinate, GeoFloat, Line};
+
+/// Returns the intersection between two great-circle segments.
+///
+/// Coordinates are longitude and latitude, in degrees, and each [`Line`] is the shorter arc of
+/// the great circle through its ends. Like [`line_intersection`], segments can intersect in a
+/// point or, when they lie on the same great circle, in a segment. See [`LineIntersection`] for
+/// more details about the result.
+///
+/// Where the segments touch, the touching end is returned exactly. Segments whose ends are
+/// antipodal don't define a great circle, and intersect nothing.
+///
+/// Unlike [`line_intersection`], which treats longitude and latitude as planar coordinates, this
+/// is correct over long distances, near the poles and across the antimeridian.
+///
+/// [`line_intersection`]: crate::algorithm::line_intersection::line_intersection
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// #
+/// use geo::algorithm::haversine_line_intersection::haversine_line_intersection;
+/// use geo::algorithm::line_intersection::{line_intersection, LineIntersection};
+/// use geo::Line;
+///
+/// // a route between two points at 50°N heads far to the north, over the prime meridian
+/// let route = Line::new((-80., 50.), (80., 50.));
+/// let meridian = Line::new((0., 60.), (0., 89.));
+///
+/// // so crosses the meridian, which it appears not to when drawn as a straight line
+/// assert_eq!(line_intersection(route, meridian), None);
+/// match haversine_line_intersection(route, meridian) {
+///     Some(LineIntersection::SinglePoint { intersection, is_proper }) => {
+///         assert!(is_proper);
+///         assert_relative_eq!(intersection.x, 0., epsilon = 1e-9);
+///         assert_relative_eq!(intersection.y, 81.7099, epsilon = 1e-4);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // segments on the same great circle overlap in a segment
+/// let p = Line::new((0., 0.), (10., 0.));
+/// let q = Line::new((20., 0.), (5., 0.));
+/// let expected = LineIntersection::Collinear {
+///     intersection: Line::new((5., 0.), (10., 0.)),
+/// };
+/// assert_eq!(haversine_line_intersection(p, q), Some(expected));
+/// ```
+pub fn haversine_line_intersection<F>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>>
+where
+    F: GeoFloat,
+{
+    let tolerance = F::epsilon() * F::from(16).unwrap();
+    let p_arc = Arc::new(p, tolerance)?;
+    let q_arc = Arc::new(q, tolerance)?;
+
+    let (p_normal, q_normal) = match (p_arc.normal, q_arc.normal) {
+        (Some(p_normal), Some(q_normal)) => (p_normal, q_normal),
+        // at least one of the segments is a point
+        (_, None) if p_arc.contains(q_arc.start) => return Some(improper(q.start)),
+        (None, _) if q_arc.contains(p_arc.start) => return Some(improper(p.start)),
+        _ => return None,
+    };
+
+    let direction = p_normal.cross(q_normal);
+    if direction.norm_squared().sqrt() <= tolerance {
+        return collinear_intersection(&p_arc, &q_arc);
+    }
+
+    // As in `line_intersection`, return an end lying on the other segment exactly, rather than
+    // computing it.
+    if p.start == q.start || p.start == q.end {
+        return Some(improper(p.start));
+    }
+    if p.end == q.start || p.end == q.end {
+        return Some(improper(p.end));
+    }
+    let ends = [
+        (q.start, q_arc.start, &p_arc),
+        (q.end, q_arc.end, &p_arc),
+        (p.start, p_arc.start, &q_arc),
+        (p.end, p_arc.end, &q_arc),
+    ];
+    for (coord, vector, arc) in ends.iter() {
+        if arc.contains(*vector) {
+            return Some(improper(*coord));
+        }
+    }
+
+    // The great circles meet at two antipodal points, which the segments may share one of.
+    let direction = direction.normalize()?;
+    let crossings = [direction, direction.scale(-F::one())];
+    crossings
+        .iter()
+        .find(|crossing| p_arc.contains(**crossing) && q_arc.contains(**crossing))
+        .map(|crossing| LineIntersection::SinglePoint {
+            intersection: crossing.to_point().0,
+            is_proper: true,
+        })
+}
+
+// The overlap of two segments on the same great circle, which, as each is shorter than half of
+// the circle, is bounded by their ends.
+fn collinear_intersection<F: GeoFloat>(p: &Arc<F>, q: &Arc<F>) -> Option<LineIntersection<F>> {
+    let mut ends: Vec<(Coordinate<F>, Vector<F>)> = vec![];
+    if q.contains(p.start) {
+        ends.push((p.line.start, p.start));
+    }
+    if q.contains(p.end) {
+        ends.push((p.line.end, p.end));
+    }
+    if p.contains(q.start) {
+        ends.push((q.line.start, q.start));
+    }
+    if p.contains(q.end) {
+        ends.push((q.line.end, q.end));
+    }
+
+    // order the ends along `p`
+    let normal = p.normal?;
+    let angle = |vector: Vector<F>| p.start.cross(vector).dot(normal).atan2(p.start.dot(vector));
+    let first = ends
+        .iter()
+        .min_by(|a, b| angle(a.1).partial_cmp(&angle(b.1)).unwrap())?;
+    let last = ends
+        .iter()
+        .max_by(|a, b| angle(a.1).partial_cmp(&angle(b.1)).unwrap())?;
+
+    if first.0 == last.0 || p.is_same_point(first.1, last.1) {
+        Some(improper(first.0))
+    } else {
+        Some(LineIntersection::Collinear {
+            intersection: Line::new(first.0, last.0),
+        })
+    }
+}
+
+fn improper<F: GeoFloat>(intersection: Coordinate<F>) -> LineIntersection<F> {
+    LineIntersection::SinglePoint {
+        intersection,
+        is_proper: false,
+    }
+}
+
+// A great-circle segment, as unit vectors.
+struct Arc<F: GeoFloat> {
+    line: Line<F>,
+    start: Vector<F>,
+    end: Vector<F>,
+    /// The pole of the segment's great circle, or `None` if the segment is a point
+    normal: Option<Vector<F>>,
+    tolerance: F,
+}
+
+impl<F: GeoFloat> Arc<F> {
+    // The arc of `line`, or `None` if its ends are antipodal.
+    fn new(line: Line<F>, tolerance: F) -> Option<Self> {
+        let start = Vector::from_point(line.start_point());
+        let end = Vector::from_point(line.end_point());
+        let cross = start.cross(end);
+        let normal = if line.start == line.end || cross.norm_squared().sqrt() <= tolerance {
+            if start.dot(end) < F::zero() {
+                return None;
+            }
+            None
+        } else {
+            cross.normalize()
+        };
+        Some(Arc {
+            line,
+            start,
+            end,
+            normal,
+            tolerance,
+        })
+    }
+
+    fn contains(&self, vector: Vector<F>) -> bool {
+        let normal = match self.normal {
+            Some(normal) => normal,
+            None => return self.is_same_point(self.start, vector),
+        };
+        let tolerance = self.tolerance;
+        vector.dot(normal).abs() <= tolerance
+            && self.start.cross(vector).dot(normal) >= -tolerance
+            && vector.cross(self.end).dot(normal) >= -tolerance
+    }
+
+    fn is_same_point(&self, a: Vector<F>, b: Vector<F>) -> bool {
+        a.dot(b) > F::zero() && a.cross(b).norm_squared().sqrt() <= self.tolerance
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::line_intersection::line_intersection;
+
+    fn crossing(p: Line<f64>, q: Line<f64>) -> Coordinate<f64> {
+        match haversine_line_intersection(p, q) {
+            Some(LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            }) => intersection,
+            other => panic!("expected a proper intersection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn crossing_segments() {
+        let equator = Line::new((-10., 0.), (10., 0.));
+        let meridian = Line::new((0., -10.), (0., 10.));
+        let intersection = crossing(equator, meridian);
+        assert_relative_eq!(intersection, Coordinate { x: 0., y: 0. }, epsilon = 1e-12);
+        assert_eq!(
+            haversine_line_intersection(meridian, equator),
+            haversine_line_intersection(equator, meridian)
+        );
+    }
+
+    #[test]
+    fn long_distances() {
+        let route = Line::new((-80., 50.), (80., 50.));
+        let meridian = Line::new((0., 60.), (0., 89.));
+        assert_eq!(line_intersection(route, meridian), None);
+        let intersection = crossing(route, meridian);
+        let max_latitude = (50f64.to_radians().tan() / 80f64.to_radians().cos())
+            .atan()
+            .to_degrees();
+        assert_relative_eq!(intersection.x, 0., epsilon = 1e-9);
+        assert_relative_eq!(intersection.y, max_latitude, epsilon = 1e-9);
+
+        // the segment misses the lower part of the meridian, which a straight line would cross
+        let meridian = Line::new((0., 40.), (0., 60.));
+        assert!(line_intersection(route, meridian).is_some());
+        assert_eq!(haversine_line_intersection(route, meridian), None);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let p = Line::new((170., 0.), (-170., 0.));
+        let q = Line::new((180., -10.), (180., 10.));
+        let intersection = crossing(p, q);
+        assert_relative_eq!(intersection.x.abs(), 180., epsilon = 1e-9);
+        assert_relative_eq!(intersection.y, 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn disjoint_segments() {
+        let p = Line::new((0., 0.), (10., 0.));
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((20., -10.), (20., 10.))),
+            None
+        );
+        // the great circles meet at (5, 0) and (-175, 0), but each segment contains only one
+        // of them
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((-175., -10.), (-175., 10.))),
+            None
+        );
+    }
+
+    #[test]
+    fn touching_segments() {
+        let p = Line::new((0., 0.), (10., 0.));
+        // an end in the interior of the other segment
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((5., 0.), (5., 10.))),
+            Some(improper(Coordinate { x: 5., y: 0. }))
+        );
+        // shared ends
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((10., 10.), (10., 0.))),
+            Some(improper(Coordinate { x: 10., y: 0. }))
+        );
+    }
+
+    #[test]
+    fn collinear_segments() {
+        let p = Line::new((0., 0.), (10., 0.));
+        let expected = Some(LineIntersection::Collinear {
+            intersection: Line::new((5., 0.), (10., 0.)),
+        });
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((5., 0.), (20., 0.))),
+            expected
+        );
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((20., 0.), (5., 0.))),
+            expected
+        );
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((10., 0.), (20., 0.))),
+            Some(improper(Coordinate { x: 10., y: 0. }))
+        );
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((11., 0.), (20., 0.))),
+            None
+        );
+
+        // overlapping across the antimeridian
+        let p = Line::new((170., 0.), (-175., 0.));
+        let q = Line::new((175., 0.), (-170., 0.));
+        assert_eq!(
+            haversine_line_intersection(p, q),
+            Some(LineIntersection::Collinear {
+                intersection: Line::new((175., 0.), (-175., 0.)),
+            })
+        );
+    }
+
+    #[test]
+    fn degenerate_segments() {
+        let p = Line::new((0., 0.), (10., 0.));
+        let point = Line::new((5., 0.), (5., 0.));
+        assert_eq!(
+            haversine_line_intersection(p, point),
+            Some(improper(Coordinate { x: 5., y: 0. }))
+        );
+        assert_eq!(
+            haversine_line_intersection(point, p),
+            Some(improper(Coordinate { x: 5., y: 0. }))
+        );
+        assert_eq!(
+            haversine_line_intersection(point, point),
+            Some(improper(point.start))
+        );
+        assert_eq!(
+            haversine_line_intersection(p, Line::new((5., 1.), (5., 1.))),
+            None
+        );
+
+        // antipodal ends don't define a great circle
+        let antipodal = Line::new((5., -10.), (-175., 10.));
+        assert_eq!(haversine_line_intersection(p, antipodal), None);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{Coordinate, GeoFloat, Rect};
+use rstar::primitives::PointWithData;
+use rstar::{RTree, RTreeNum};
+
+/// Interpolate values at arbitrary coordinates from a set of scattered samples using
+/// [Inverse Distance Weighting](https://en.wikipedia.org/wiki/Inverse_distance_weighting).
+///
+/// The value at a coordinate is the weighted average of the values of its `max_neighbors`
+/// nearest samples, where each sample is weighted by `1 / distance^power`. Samples are stored
+/// in an R*-tree, so each query only visits the neighbours it uses.
+///
+/// If the query coordinate coincides with one or more samples, the (average) value of those
+/// samples is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Coordinate;
+/// use geo::algorithm::idw_interpolation::IdwInterpolator;
+///
+/// let samples = vec![
+///     (Coordinate { x: 0.0, y: 0.0 }, 0.0),
+///     (Coordinate { x: 10.0, y: 0.0 }, 10.0),
+/// ];
+/// let idw = IdwInterpolator::new(samples, 2.0, 12);
+///
+/// assert_eq!(idw.interpolate(Coordinate { x: 5.0, y: 0.0 }), Some(5.0));
+/// assert_eq!(idw.interpolate(Coordinate { x: 10.0, y: 0.0 }), Some(10.0));
+/// ```
+pub struct IdwInterpolator<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    tree: RTree<PointWithData<T, Coordinate<T>>>,
+    power: T,
+    max_neighbors: usize,
+}
+
+impl<T> IdwInterpolator<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    /// Create an interpolator from `(coordinate, value)` samples.
+    ///
+    /// `power` controls how quickly the influence of a sample decays with distance (`2` is the
+    /// customary choice), and `max_neighbors` limits the number of nearest samples which
+    /// contribute to each interpolated value.
+    pub fn new<I>(samples: I, power: T, max_neighbors: usize) -> Self
+    where
+        I: IntoIterator<Item = (Coordinate<T>, T)>,
+    {
+        let samples = samples
+            .into_iter()
+            .map(|(coord, value)| PointWithData::new(value, coord))
+            .collect();
+        IdwInterpolator {
+            tree: RTree::bulk_load(samples),
+            power,
+            max_neighbors,
+        }
+    }
+
+    /// The number of samples in the interpolator.
+    pub fn len(&self) -> usize {
+        self.tree.size()
+    }
+
+    /// Returns `true` if the interpolator has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.tree.size() == 0
+    }
+
+    /// Interpolate the value at `coord`.
+    ///
+    /// Returns `None` if there are no samples or `max_neighbors` is zero.
+    pub fn interpolate(&self, coord: Coordinate<T>) -> Option<T> {
+        let half_power = self.power / (T::one() + T::one());
+        let mut weighted_sum = T::zero();
+        let mut weight_total = T::zero();
+        let mut coincident_sum = T::zero();
+        let mut coincident_count = T::zero();
+
+        for (sample, distance_2) in self
+            .tree
+            .nearest_neighbor_iter_with_distance_2(&coord)
+            .take(self.max_neighbors)
+        {
+            if distance_2 == T::zero() {
+                coincident_sum = coincident_sum + sample.data;
+                coincident_count = coincident_count + T::one();
+            } else if coincident_count == T::zero() {
+                // `distance_2` is squared, so halve the exponent
+                let weight = T::one() / distance_2.powf(half_power);
+                weighted_sum = weighted_sum + weight * sample.data;
+                weight_total = weight_total + weight;
+            } else {
+                // the remaining neighbours are farther away than the coincident samples
+                break;
+            }
+        }
+
+        if coincident_count > T::zero() {
+            Some(coincident_sum / coincident_count)
+        } else if weight_total > T::zero() {
+            Some(weighted_sum / weight_total)
+        } else {
+            None
+        }
+    }
+
+    /// Interpolate values over a regular grid of `columns` × `rows` nodes spanning `bounds`.
+    ///
+    /// The first and last nodes of each row and column lie on the edges of `bounds`. The
+    /// result is indexed as `grid[row][column]`, with row `0` at `bounds.min().y` and column
+    /// `0` at `bounds.min().x`.
+    ///
+    /// Returns `None` if there are no samples or `max_neighbors` is zero.
+    pub fn interpolate_grid(
+        &self,
+        bounds: Rect<T>,
+        columns: usize,
+        rows: usize,
+    ) -> Option<Vec<Vec<T>>> {
+        if self.is_empty() || self.max_neighbors == 0 {
+            return None;
+        }
+        let step = |extent: T, count: usize| {
+            if count > 1 {
+                extent / T::from(count - 1).unwrap()
+            } else {
+                T::zero()
+            }
+        };
+        let dx = step(bounds.width(), columns);
+        let dy = step(bounds.height(), rows);
+        let min = bounds.min();
+
+        (0..rows)
+            .map(|row| {
+                let y = min.y + dy * T::from(row).unwrap();
+                (0..columns)
+                    .map(|column| {
+                        let x = min.x + dx * T::from(column).unwrap();
+                        self.interpolate(Coordinate { x, y })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn square_samples() -> Vec<(Coordinate<f64>, f64)> {
+        vec![
+            (Coordinate { x: 0.0, y: 0.0 }, 1.0),
+            (Coordinate { x: 2.0, y: 0.0 }, 2.0),
+            (Coordinate { x: 2.0, y: 2.0 }, 3.0),
+            (Coordinate { x: 0.0, y: 2.0 }, 4.0),
+        ]
+    }
+
+    #[test]
+    fn empty_test() {
+        let idw = IdwInterpolator::<f64>::new(vec![], 2.0, 4);
+        assert!(idw.is_empty());
+        assert_eq!(idw.interpolate(Coordinate { x: 0.0, y: 0.0 }), None);
+        let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 1.0, y: 1.0 });
+        assert_eq!(idw.interpolate_grid(bounds, 2, 2), None);
+    }
+
+    #[test]
+    fn coincident_sample_test() {
+        let idw = IdwInterpolator::new(square_samples(), 2.0, 4);
+        assert_eq!(idw.interpolate(Coordinate { x: 2.0, y: 2.0 }), Some(3.0));
+
+        let mut samples = square_samples();
+        samples.push((Coordinate { x: 2.0, y: 2.0 }, 5.0));
+        let idw = IdwInterpolator::new(samples, 2.0, 5);
+        assert_eq!(idw.interpolate(Coordinate { x: 2.0, y: 2.0 }), Some(4.0));
+    }
+
+    #[test]
+    fn equidistant_test() {
+        // every sample is equally far from the center, so the result is the plain mean
+        let idw = IdwInterpolator::new(square_samples(), 2.0, 4);
+        assert_relative_eq!(idw.interpolate(Coordinate { x: 1.0, y: 1.0 }).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn weighting_test() {
+        let samples = vec![
+            (Coordinate { x: 0.0, y: 0.0 }, 0.0),
+            (Coordinate { x: 3.0, y: 0.0 }, 3.0),
+        ];
+        // distances 1 and 2, weights 1 and 1/4
+        let idw = IdwInterpolator::new(samples.clone(), 2.0, 2);
+        assert_relative_eq!(idw.interpolate(Coordinate { x: 1.0, y: 0.0 }).unwrap(), 0.6);
+        // weights 1 and 1/2
+        let idw = IdwInterpolator::new(samples, 1.0, 2);
+        assert_relative_eq!(idw.interpolate(Coordinate { x: 1.0, y: 0.0 }).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn max_neighbors_test() {
+        let idw = IdwInterpolator::new(square_samples(), 2.0, 1);
+        assert_eq!(idw.interpolate(Coordinate { x: 0.4, y: 1.8 }), Some(4.0));
+        let idw = IdwInterpolator::new(square_samples(), 2.0, 0);
+        assert_eq!(idw.interpolate(Coordinate { x: 0.4, y: 1.8 }), None);
+    }
+
+    #[test]
+    fn grid_test() {
+        let idw = IdwInterpolator::new(square_samples(), 2.0, 4);
+        let bounds = Rect::new(Coordinate { x: 0.0, y: 0.0 }, Coordinate { x: 2.0, y: 2.0 });
+        let grid = idw.interpolate_grid(bounds, 3, 2).unwrap();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid[0].len(), 3);
+        assert_eq!(grid[0][0], 1.0);
+        assert_eq!(grid[0][2], 2.0);
+        assert_eq!(grid[1][2], 3.0);
+        assert_eq!(grid[1][0], 4.0);
+        assert_relative_eq!(
+            grid[0][1],
+            idw.interpolate(Coordinate { x: 1.0, y: 0.0 }).unwrap()
+        );
+    }
+}
@@ -0,0 +1,548 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::map_coords::MapCoords;
+use crate::algorithm::rasterize::{rasterize, BurnRule};
+use crate::{
+    Coordinate, GeoFloat, Geometry, LineString, MultiLineString, MultiPolygon, Point, Polygon, Rect,
+};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::iter;
+
+/// The largest circle inside a geometry.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InscribedCircle<T: GeoFloat> {
+    /// The point of the geometry farthest from its boundary, its pole of inaccessibility
+    pub center: Point<T>,
+    pub radius: T,
+}
+
+/// Find the largest circle inside a polygon, centred on its pole of inaccessibility: the point
+/// inside it farthest from its boundary, which is a good place for a label.
+///
+/// The circle is found by Mapbox's [polylabel] search: the polygon is covered by square cells,
+/// and the cells which could contain a point farther from the boundary than the best found so
+/// far are split into quarters, in order of how far that could be, until none could improve on
+/// it by more than `tolerance`. The centre lies on the medial axis of the polygon, so the circle
+/// is exact to within the tolerance.
+///
+/// Where the farthest points form a line, as along the middle of a long strip, every cell along
+/// it has to be split down to the tolerance, so choose a tolerance in proportion to the size of
+/// the polygon.
+///
+/// [polylabel]: https://github.com/mapbox/polylabel
+pub trait LargestInscribedCircle<T: GeoFloat> {
+    /// The largest circle inside the geometry, to within `tolerance` of its radius, or `None` if
+    /// the geometry has no area.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::inscribed::LargestInscribedCircle;
+    /// use geo::{point, polygon};
+    ///
+    /// // an L, whose widest part is its corner
+    /// let l = polygon![
+    ///     (x: 0., y: 0.),
+    ///     (x: 10., y: 0.),
+    ///     (x: 10., y: 4.),
+    ///     (x: 4., y: 4.),
+    ///     (x: 4., y: 10.),
+    ///     (x: 0., y: 10.),
+    /// ];
+    /// let circle = l.largest_inscribed_circle(1e-6).unwrap();
+    /// // touching the outer walls, and the reflex vertex
+    /// let radius = 8. - 32f64.sqrt();
+    /// assert_relative_eq!(circle.radius, radius, epsilon = 1e-6);
+    /// assert_relative_eq!(circle.center, point!(x: radius, y: radius), epsilon = 1e-5);
+    /// ```
+    fn largest_inscribed_circle(&self, tolerance: T) -> Option<InscribedCircle<T>>;
+}
+
+impl<T: GeoFloat> LargestInscribedCircle<T> for Polygon<T> {
+    fn largest_inscribed_circle(&self, tolerance: T) -> Option<InscribedCircle<T>> {
+        assert!(tolerance > T::zero(), "tolerance must be positive");
+        let bounds = self.bounding_rect()?;
+        let size = bounds.width().min(bounds.height());
+        if size <= T::zero() {
+            return None;
+        }
+
+        let two = T::one() + T::one();
+        let cell = |center: Coordinate<T>, half: T| {
+            let distance = signed_distance(self, center);
+            Cell {
+                center,
+                half,
+                distance,
+                // the farthest any point of the cell could be from the boundary
+                potential: distance + half * two.sqrt(),
+            }
+        };
+
+        let half = size / two;
+        let mut cells = BinaryHeap::new();
+        let mut y = bounds.min().y;
+        while y < bounds.max().y {
+            let mut x = bounds.min().x;
+            while x < bounds.max().x {
+                cells.push(cell(
+                    Coordinate {
+                        x: x + half,
+                        y: y + half,
+                    },
+                    half,
+                ));
+                x = x + size;
+            }
+            y = y + size;
+        }
+
+        let mut best = cell(bounds.center(), T::zero());
+        while let Some(next) = cells.pop() {
+            if next.distance > best.distance {
+                best = next;
+            }
+            if next.potential - best.distance <= tolerance {
+                // nor could any of the cells after it
+                break;
+            }
+            let half = next.half / two;
+            for (dx, dy) in &[(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                let offset = Coordinate {
+                    x: half * T::from(*dx).unwrap(),
+                    y: half * T::from(*dy).unwrap(),
+                };
+                cells.push(cell(next.center + offset, half));
+            }
+        }
+
+        if best.distance <= T::zero() {
+            return None;
+        }
+        Some(InscribedCircle {
+            center: Point(best.center),
+            radius: best.distance,
+        })
+    }
+}
+
+/// The largest circle inside any of the polygons.
+impl<T: GeoFloat> LargestInscribedCircle<T> for MultiPolygon<T> {
+    fn largest_inscribed_circle(&self, tolerance: T) -> Option<InscribedCircle<T>> {
+        self.0
+            .iter()
+            .filter_map(|polygon| polygon.largest_inscribed_circle(tolerance))
+            .max_by(|a, b| a.radius.partial_cmp(&b.radius).unwrap())
+    }
+}
+
+/// A rectangle inside a geometry, rotated by `angle` degrees counter-clockwise.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InscribedRect<T: GeoFloat> {
+    pub polygon: Polygon<T>,
+    /// The length of the sides at `angle` to the x axis
+    pub width: T,
+    pub height: T,
+    pub angle: T,
+}
+
+/// Find a large rectangle inside a polygon, such as a place for a label or a solar panel array.
+///
+/// The largest rectangle is approximated: the bounding rectangle of the polygon is divided into
+/// a grid of square cells, the largest rectangle of cells wholly inside the polygon found, and
+/// each of its sides then pushed out as far as it will go. The finer the grid, the closer the
+/// rectangle is to the largest, and the longer finding it takes.
+pub trait LargestInscribedRect<T: GeoFloat> {
+    /// A large axis-aligned rectangle inside the geometry, found with a grid `cells` across its
+    /// longer side, or `None` if no cell of the grid is inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::inscribed::LargestInscribedRect;
+    /// use geo::polygon;
+    ///
+    /// let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)];
+    /// // the largest is the square of side 2 in the corner
+    /// let rect = triangle.largest_inscribed_rect(100).unwrap();
+    /// assert!(rect.width() * rect.height() > 3.9);
+    /// ```
+    fn largest_inscribed_rect(&self, cells: usize) -> Option<Rect<T>>;
+
+    /// A large rectangle inside the geometry, at whichever of `angles` angles evenly spaced
+    /// between `0°` and `90°` gives the largest, found with a grid `cells` across the geometry at
+    /// each angle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::inscribed::LargestInscribedRect;
+    /// use geo::polygon;
+    ///
+    /// let diamond = polygon![(x: 0., y: -5.), (x: 5., y: 0.), (x: 0., y: 5.), (x: -5., y: 0.)];
+    /// // at 45°, the rectangle fills the diamond
+    /// let rect = diamond.largest_inscribed_rotated_rect(100, 4).unwrap();
+    /// assert_eq!(rect.angle, 45.);
+    /// assert_relative_eq!(rect.width * rect.height, 50., epsilon = 1e-6);
+    /// ```
+    fn largest_inscribed_rotated_rect(
+        &self,
+        cells: usize,
+        angles: usize,
+    ) -> Option<InscribedRect<T>>;
+}
+
+impl<T: GeoFloat> LargestInscribedRect<T> for Polygon<T> {
+    fn largest_inscribed_rect(&self, cells: usize) -> Option<Rect<T>> {
+        let bounds = self.bounding_rect()?;
+        let size = bounds.width().max(bounds.height()) / T::from(cells).unwrap();
+        if cells == 0 || size.is_nan() || size <= T::zero() {
+            return None;
+        }
+        let columns = (bounds.width() / size).ceil().to_usize().unwrap().max(1);
+        let rows = (bounds.height() / size).ceil().to_usize().unwrap().max(1);
+        let grid = Rect::new(
+            bounds.min(),
+            bounds.min()
+                + Coordinate {
+                    x: size * T::from(columns).unwrap(),
+                    y: size * T::from(rows).unwrap(),
+                },
+        );
+
+        // the cells whose centres are inside, and which no ring touches
+        let rings: Vec<&LineString<T>> = iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        let boundary: Geometry<T> =
+            MultiLineString(rings.iter().map(|ring| (*ring).clone()).collect()).into();
+        let centres = rasterize(
+            &self.clone().into(),
+            grid,
+            rows,
+            columns,
+            BurnRule::CellCenter,
+        );
+        let touched = rasterize(&boundary, grid, rows, columns, BurnRule::AllTouched);
+        let inside: Vec<Vec<bool>> = centres
+            .iter()
+            .zip(&touched)
+            .map(|(centres, touched)| {
+                centres
+                    .iter()
+                    .zip(touched)
+                    .map(|(centre, touched)| *centre && !*touched)
+                    .collect()
+            })
+            .collect();
+
+        let (bottom, top, left, right) = largest_rectangle(&inside)?;
+        let corner = |column: usize, row: usize| {
+            grid.min()
+                + Coordinate {
+                    x: size * T::from(column).unwrap(),
+                    y: size * T::from(row).unwrap(),
+                }
+        };
+        // inset by a quarter of a cell, to keep clear of rings the cells only just miss
+        let inset = Coordinate {
+            x: size / T::from(4).unwrap(),
+            y: size / T::from(4).unwrap(),
+        };
+        let rect = Rect::new(corner(left, bottom) + inset, corner(right, top) - inset);
+        Some(expand(self, &rings, rect, bounds))
+    }
+
+    fn largest_inscribed_rotated_rect(
+        &self,
+        cells: usize,
+        angles: usize,
+    ) -> Option<InscribedRect<T>> {
+        let quadrant = T::from(90).unwrap();
+        (0..angles)
+            .filter_map(|index| {
+                let angle = quadrant * T::from(index).unwrap() / T::from(angles).unwrap();
+                let (sin, cos) = angle.to_radians().sin_cos();
+                // rotated by `-angle`, so the rectangle is upright
+                let upright = self.map_coords(|&(x, y)| (x * cos + y * sin, y * cos - x * sin));
+                let rect = upright.largest_inscribed_rect(cells)?;
+                Some(InscribedRect {
+                    polygon: rect
+                        .to_polygon()
+                        .map_coords(|&(x, y)| (x * cos - y * sin, x * sin + y * cos)),
+                    width: rect.width(),
+                    height: rect.height(),
+                    angle,
+                })
+            })
+            .max_by(|a, b| {
+                (a.width * a.height)
+                    .partial_cmp(&(b.width * b.height))
+                    .unwrap()
+            })
+    }
+}
+
+/// The largest rectangle inside any of the polygons.
+impl<T: GeoFloat> LargestInscribedRect<T> for MultiPolygon<T> {
+    fn largest_inscribed_rect(&self, cells: usize) -> Option<Rect<T>> {
+        self.0
+            .iter()
+            .filter_map(|polygon| polygon.largest_inscribed_rect(cells))
+            .max_by(|a, b| {
+                (a.width() * a.height())
+                    .partial_cmp(&(b.width() * b.height()))
+                    .unwrap()
+            })
+    }
+
+    fn largest_inscribed_rotated_rect(
+        &self,
+        cells: usize,
+        angles: usize,
+    ) -> Option<InscribedRect<T>> {
+        self.0
+            .iter()
+            .filter_map(|polygon| polygon.largest_inscribed_rotated_rect(cells, angles))
+            .max_by(|a, b| {
+                (a.width * a.height)
+                    .partial_cmp(&(b.width * b.height))
+                    .unwrap()
+            })
+    }
+}
+
+// A square cell of the search for the pole of inaccessibility, ordered by how far from the
+// boundary a point in it could be.
+#[derive(Clone, Copy)]
+struct Cell<T: GeoFloat> {
+    center: Coordinate<T>,
+    half: T,
+    distance: T,
+    potential: T,
+}
+
+impl<T: GeoFloat> PartialEq for Cell<T> {
+    fn eq(&self, other: &Cell<T>) -> bool {
+        self.potential == other.potential
+    }
+}
+
+impl<T: GeoFloat> Eq for Cell<T> {}
+
+impl<T: GeoFloat> Ord for Cell<T> {
+    fn cmp(&self, other: &Cell<T>) -> Ordering {
+        self.potential.partial_cmp(&other.potential).unwrap()
+    }
+}
+
+impl<T: GeoFloat> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Cell<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The distance from `coord` to the boundary of `polygon`, negative outside it.
+fn signed_distance<T: GeoFloat>(polygon: &Polygon<T>, coord: Coordinate<T>) -> T {
+    let distance = iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(|ring| Point(coord).euclidean_distance(ring))
+        .fold(T::infinity(), T::min);
+    if polygon.coordinate_position(&coord) == CoordPos::Inside {
+        distance
+    } else {
+        -distance
+    }
+}
+
+// The bottom and top rows, and left and right columns, of the corners of the largest rectangle
+// of set cells in `mask`, by the largest rectangle under the histogram of set cells running down
+// from each row.
+fn largest_rectangle(mask: &[Vec<bool>]) -> Option<(usize, usize, usize, usize)> {
+    let columns = mask.first()?.len();
+    let mut heights = vec![0; columns];
+    let mut best = None;
+    let mut best_area = 0;
+    for (row, cells) in mask.iter().enumerate() {
+        for (height, set) in heights.iter_mut().zip(cells) {
+            *height = if *set { *height + 1 } else { 0 };
+        }
+
+        // columns of increasing height, each with the leftmost column it extends back to
+        let mut stack: Vec<(usize, usize)> = vec![];
+        for column in 0..=columns {
+            let height = heights.get(column).copied().unwrap_or(0);
+            let mut start = column;
+            while let Some(&(left, left_height)) = stack.last() {
+                if left_height < height {
+                    break;
+                }
+                stack.pop();
+                let area = left_height * (column - left);
+                if area > best_area {
+                    best_area = area;
+                    best = Some((row + 1 - left_height, row + 1, left, column));
+                }
+                start = left;
+            }
+            stack.push((start, height));
+        }
+    }
+    best
+}
+
+// `rect`, which is inside `polygon`, with each of its sides pushed out in turn until it meets
+// a ring of the polygon, or reaches `bounds`.
+fn expand<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    rings: &[&LineString<T>],
+    rect: Rect<T>,
+    bounds: Rect<T>,
+) -> Rect<T> {
+    let fits = |min: Coordinate<T>, max: Coordinate<T>| {
+        let rect = Rect::new(min, max);
+        polygon.coordinate_position(&rect.center()) == CoordPos::Inside
+            && !rings.iter().any(|ring| rect.intersects(*ring))
+    };
+    let two = T::one() + T::one();
+    let (mut min, mut max) = (rect.min(), rect.max());
+    for side in 0..4 {
+        let set = |min: &mut Coordinate<T>, max: &mut Coordinate<T>, value: T| match side {
+            0 => min.x = value,
+            1 => max.x = value,
+            2 => min.y = value,
+            _ => max.y = value,
+        };
+        let (mut inside, mut outside) = match side {
+            0 => (min.x, bounds.min().x),
+            1 => (max.x, bounds.max().x),
+            2 => (min.y, bounds.min().y),
+            _ => (max.y, bounds.max().y),
+        };
+        let (mut trial_min, mut trial_max) = (min, max);
+        set(&mut trial_min, &mut trial_max, outside);
+        if fits(trial_min, trial_max) {
+            inside = outside;
+        } else {
+            for _ in 0..52 {
+                let middle = (inside + outside) / two;
+                if middle == inside || middle == outside {
+                    break;
+                }
+                set(&mut trial_min, &mut trial_max, middle);
+                if fits(trial_min, trial_max) {
+                    inside = middle;
+                } else {
+                    outside = middle;
+                }
+            }
+        }
+        set(&mut min, &mut max, inside);
+    }
+    Rect::new(min, max)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{point, polygon};
+
+    #[test]
+    fn circles() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let circle = square.largest_inscribed_circle(1e-9).unwrap();
+        assert_relative_eq!(circle.center, point!(x: 5., y: 5.), epsilon = 1e-6);
+        assert_relative_eq!(circle.radius, 5., epsilon = 1e-9);
+
+        let strip: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.), (x: 0., y: 4.)];
+        // the farthest points form a line, along which the search has to split every cell
+        let circle = strip.largest_inscribed_circle(1e-3).unwrap();
+        assert_relative_eq!(circle.radius, 2., epsilon = 1e-3);
+        assert_relative_eq!(circle.center.y(), 2., epsilon = 1e-3);
+
+        // a square frame, 2 wide
+        let frame: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let circle = frame.largest_inscribed_circle(1e-9).unwrap();
+        // in a corner, touching the corner of the hole
+        let radius = 2. * 2f64.sqrt() / (1. + 2f64.sqrt());
+        assert_relative_eq!(circle.radius, radius, epsilon = 1e-9);
+
+        let multi = MultiPolygon(vec![frame, strip]);
+        assert_relative_eq!(
+            multi.largest_inscribed_circle(1e-3).unwrap().radius,
+            2.,
+            epsilon = 1e-3
+        );
+
+        let flat: Polygon<f64> = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 5., y: 0.)];
+        assert!(flat.largest_inscribed_circle(1e-9).is_none());
+    }
+
+    #[test]
+    fn rects() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let rect = square.largest_inscribed_rect(10).unwrap();
+        assert_relative_eq!(rect.to_polygon().unsigned_area(), 100., epsilon = 1e-6);
+
+        // an L, whose largest rectangle runs along its longer arm
+        let l: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 3.),
+            (x: 3., y: 3.),
+            (x: 3., y: 6.),
+            (x: 0., y: 6.),
+        ];
+        let rect = l.largest_inscribed_rect(20).unwrap();
+        assert_relative_eq!(rect.min(), Coordinate { x: 0., y: 0. }, epsilon = 1e-6);
+        assert_relative_eq!(rect.max(), Coordinate { x: 10., y: 3. }, epsilon = 1e-6);
+
+        // the rectangle avoids holes
+        let frame: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 3., y: 3.), (x: 8., y: 3.), (x: 8., y: 8.), (x: 3., y: 8.)]],
+        ];
+        let rect = frame.largest_inscribed_rect(20).unwrap();
+        assert_relative_eq!(rect.width() * rect.height(), 30., epsilon = 1e-6);
+
+        // too coarse a grid to find anything
+        let sliver: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.1), (x: 0., y: 0.1)];
+        assert!(sliver.largest_inscribed_rect(10).is_none());
+        assert!(sliver.largest_inscribed_rect(1000).is_some());
+    }
+
+    #[test]
+    fn rotated_rects() {
+        // a rectangle 8 by 2, turned by 30°
+        let (sin, cos) = 30f64.to_radians().sin_cos();
+        let turned: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 2.), (x: 0., y: 2.)]
+                .map_coords(|&(x, y)| (x * cos - y * sin, x * sin + y * cos));
+        let rect = turned.largest_inscribed_rotated_rect(50, 9).unwrap();
+        assert_relative_eq!(rect.angle, 30.);
+        assert_relative_eq!(rect.width, 8., epsilon = 1e-6);
+        assert_relative_eq!(rect.height, 2., epsilon = 1e-6);
+        assert_relative_eq!(rect.polygon.unsigned_area(), 16., epsilon = 1e-6);
+
+        assert!(turned.largest_inscribed_rotated_rect(50, 0).is_none());
+    }
+}
@@ -78,7 +78,7 @@ mod triangle;
 #[inline]
 fn value_in_range<T>(value: T, min: T, max: T) -> bool
 where
-    T: std::cmp::PartialOrd,
+    T: core::cmp::PartialOrd,
 {
     value >= min && value <= max
 }
@@ -88,7 +88,7 @@ where
 #[inline]
 fn value_in_between<T>(value: T, bound_1: T, bound_2: T) -> bool
 where
-    T: std::cmp::PartialOrd,
+    T: core::cmp::PartialOrd,
 {
     if bound_1 < bound_2 {
         value_in_range(value, bound_1, bound_2)
@@ -113,6 +113,7 @@ mod test {
     use crate::algorithm::intersects::Intersects;
     use crate::{
         line_string, polygon, Coordinate, Geometry, Line, LineString, Point, Polygon, Rect,
+        Triangle,
     };
 
     /// Tests: intersection LineString and LineString
@@ -575,4 +576,48 @@ mod test {
         let geom: Geometry<_> = Line::from([(0.5, 0.5), (2., 1.)]).into();
         assert!(geom.intersects(&geom));
     }
+
+    #[test]
+    fn triangle_intersects_agrees_with_polygon_test() {
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        let geometries: Vec<Geometry<f64>> = vec![
+            Point::new(1., 1.).into(),
+            Point::new(2., 2.).into(),
+            Point::new(3., 3.).into(),
+            Line::from([(3., 3.), (5., 5.)]).into(),
+            Line::from([(-1., 2.), (5., 2.)]).into(),
+            Line::from([(4., 0.), (5., 0.)]).into(),
+            line_string![(x: 3., y: 3.), (x: 5., y: 3.), (x: 1., y: 1.)].into(),
+            Rect::new((3., 3.), (5., 5.)).into(),
+            Rect::new((2., 2.), (5., 5.)).into(),
+            Rect::new((-1., -1.), (5., 5.)).into(),
+            Triangle::from([(1., 1.), (2., 1.), (1., 2.)]).into(),
+            Triangle::from([(3., 3.), (5., 3.), (3., 5.)]).into(),
+            polygon![(x: 2., y: 2.), (x: 5., y: 2.), (x: 2., y: 5.)].into(),
+            polygon!(
+                exterior: [(x: -1., y: -1.), (x: 6., y: -1.), (x: 6., y: 6.), (x: -1., y: 6.)],
+                interiors: [[(x: -0.5, y: -0.5), (x: 5., y: -0.5), (x: -0.5, y: 5.)]],
+            )
+            .into(),
+        ];
+        let polygon = triangle.to_polygon();
+        for geometry in &geometries {
+            assert_eq!(
+                triangle.intersects(geometry),
+                polygon.intersects(geometry),
+                "triangle intersects {:?}",
+                geometry
+            );
+            assert_eq!(triangle.intersects(geometry), geometry.intersects(&triangle));
+        }
+    }
+
+    #[test]
+    fn rect_intersects_line_string_test() {
+        let rect = Rect::new((0., 0.), (4., 4.));
+        assert!(rect.intersects(&line_string![(x: -1., y: 2.), (x: 5., y: 2.)]));
+        assert!(!rect.intersects(&line_string![(x: 5., y: 2.), (x: 5., y: 5.)]));
+        assert!(rect.intersects(&polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 1., y: 2.)]));
+        assert!(rect.intersects(&polygon![(x: -1., y: -1.), (x: 5., y: -1.), (x: 5., y: 5.)]));
+    }
 }
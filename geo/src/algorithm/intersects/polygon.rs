@@ -40,7 +40,11 @@ where
     T: GeoNum,
 {
     fn intersects(&self, rect: &Rect<T>) -> bool {
-        self.intersects(&rect.clone().to_polygon())
+        // either a ring of self intersects rect, or rect lies within self
+        core::iter::once(self.exterior())
+            .chain(self.interiors())
+            .any(|ring| ring.lines().any(|line| rect.intersects(&line)))
+            || self.intersects(&rect.min())
     }
 }
 symmetric_intersects_impl!(Rect<T>, Polygon<T>);
@@ -53,3 +53,5 @@ where
     }
 }
 symmetric_intersects_impl!(Line<T>, Rect<T>);
+symmetric_intersects_impl!(Rect<T>, LineString<T>);
+symmetric_intersects_impl!(Rect<T>, MultiLineString<T>);
@@ -1,16 +1,67 @@
 use super::Intersects;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
 use crate::*;
 
-impl<T, G> Intersects<G> for Triangle<T>
+impl<T> Intersects<Coordinate<T>> for Triangle<T>
 where
-    T: CoordNum,
-    Polygon<T>: Intersects<G>,
+    T: GeoNum,
 {
-    fn intersects(&self, rhs: &G) -> bool {
-        self.clone().to_polygon().intersects(rhs)
+    fn intersects(&self, rhs: &Coordinate<T>) -> bool {
+        self.coordinate_position(rhs) != CoordPos::Outside
     }
 }
 symmetric_intersects_impl!(Coordinate<T>, Triangle<T>);
+symmetric_intersects_impl!(Triangle<T>, Point<T>);
+symmetric_intersects_impl!(Triangle<T>, MultiPoint<T>);
+
+// Same logic as Polygon<T>: Intersects<Line<T>>, but avoid
+// an allocation.
+impl<T> Intersects<Line<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Line<T>) -> bool {
+        self.intersects(&rhs.start)
+            || self.intersects(&rhs.end)
+            || self.to_lines().iter().any(|edge| edge.intersects(rhs))
+    }
+}
 symmetric_intersects_impl!(Line<T>, Triangle<T>);
+symmetric_intersects_impl!(Triangle<T>, LineString<T>);
+symmetric_intersects_impl!(Triangle<T>, MultiLineString<T>);
+
+impl<T> Intersects<Rect<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Rect<T>) -> bool {
+        // either an edge of self intersects rhs, or self lies within rhs
+        self.to_lines().iter().any(|edge| rhs.intersects(edge)) || self.intersects(&rhs.min())
+    }
+}
 symmetric_intersects_impl!(Rect<T>, Triangle<T>);
+
+impl<T> Intersects<Triangle<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Triangle<T>) -> bool {
+        // either an edge of rhs intersects self, or rhs lies within self
+        rhs.to_lines().iter().any(|edge| self.intersects(edge)) || rhs.intersects(&self.0)
+    }
+}
+
+impl<T> Intersects<Polygon<T>> for Triangle<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Polygon<T>) -> bool {
+        // either a ring of rhs intersects self, or self lies within rhs
+        core::iter::once(rhs.exterior())
+            .chain(rhs.interiors())
+            .any(|ring| ring.lines().any(|line| self.intersects(&line)))
+            || rhs.intersects(&self.0)
+    }
+}
 symmetric_intersects_impl!(Polygon<T>, Triangle<T>);
+symmetric_intersects_impl!(Triangle<T>, MultiPolygon<T>);
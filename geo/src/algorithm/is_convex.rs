@@ -1,13 +1,21 @@
 use crate::kernels::*;
-use crate::{Coordinate, LineString};
+use crate::{CoordNum, Coordinate, LineString, Polygon};
 
-/// Predicates to test the convexity of a [ `LineString` ].
+/// Predicates to test the convexity of a [ `LineString` ]
+/// or [`Polygon`].
 /// A closed `LineString` is said to be _convex_ if it
 /// encloses a [convex set]. It is said to be _strictly
 /// convex_ if in addition, no three consecutive vertices
 /// are collinear. It is _collinear_ if all the vertices lie
 /// on the same line.
 ///
+/// The tests take linear time in the number of coordinates, using
+/// the orientation [`Kernel`], so they are cheap enough to
+/// decide whether an algorithm's convex-only fast path
+/// applies. Turning consistently in one direction isn't
+/// enough: a ring which winds around more than once, like
+/// a pentagram, is not convex.
+///
 /// # Remarks
 ///
 /// - Collinearity does not require that the `LineString`
@@ -35,6 +43,20 @@ use crate::{Coordinate, LineString};
 /// both convex and collinear. However, the strict convexity
 /// is _unspecified_ and must not be relied upon.
 ///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::is_convex::IsConvex;
+/// use geo::polygon;
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert!(square.is_convex());
+/// assert!(square.exterior().is_ccw_convex());
+///
+/// let notched = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 0.5, y: 0.5), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// assert!(!notched.is_convex());
+/// ```
+///
 /// [convex combination]: //en.wikipedia.org/wiki/Convex_combination
 /// [convex set]: //en.wikipedia.org/wiki/Convex_set
 /// [`ConvexHull`]: crate::algorithm::convex_hull::ConvexHull
@@ -127,6 +149,26 @@ impl<T: HasKernel> IsConvex for LineString<T> {
     }
 }
 
+impl<T: HasKernel> IsConvex for Polygon<T> {
+    fn convex_orientation(
+        &self,
+        allow_collinear: bool,
+        specific_orientation: Option<Orientation>,
+    ) -> Option<Orientation> {
+        if !self.interiors().is_empty() {
+            return None;
+        }
+        self.exterior()
+            .convex_orientation(allow_collinear, specific_orientation)
+    }
+
+    // Interiors lie within the exterior, so are collinear
+    // whenever it is.
+    fn is_collinear(&self) -> bool {
+        self.exterior().is_collinear()
+    }
+}
+
 /// A utility that tests convexity of a sequence of
 /// coordinates. It verifies that for all `0 <= i < n`, the
 /// vertices at positions `i`, `i+1`, `i+2` (mod `n`) have
@@ -196,6 +238,7 @@ where
             orientation => orientation != first_non_collinear,
         })
         .is_none()
+        && winds_once(coords)
     {
         Some(first_non_collinear)
     } else {
@@ -203,10 +246,49 @@ where
     }
 }
 
+/// Whether the closed ring through `coords` winds around
+/// at most once: the x and y components of its edges each
+/// change sign at most twice, going around it. A ring
+/// which turns consistently in one direction, but winds
+/// around more than once, must change sign at least four
+/// times.
+fn winds_once<T: CoordNum>(coords: &[Coordinate<T>]) -> bool {
+    let n = coords.len();
+    let sign_changes = |component: fn(Coordinate<T>) -> T| {
+        let signs = (0..n).filter_map(|i| {
+            let delta = component(coords[(i + 1) % n]) - component(coords[i]);
+            if delta > T::zero() {
+                Some(true)
+            } else if delta < T::zero() {
+                Some(false)
+            } else {
+                None
+            }
+        });
+        let mut changes = 0;
+        let mut first = None;
+        let mut previous = None;
+        for sign in signs {
+            if first.is_none() {
+                first = Some(sign);
+            }
+            if previous.is_some() && previous != Some(sign) {
+                changes += 1;
+            }
+            previous = Some(sign);
+        }
+        if first != previous {
+            changes += 1;
+        }
+        changes
+    };
+    sign_changes(|coord| coord.x) <= 2 && sign_changes(|coord| coord.y) <= 2
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use geo_types::line_string;
+    use geo_types::{line_string, polygon};
 
     #[test]
     fn test_corner_cases() {
@@ -246,4 +328,52 @@ mod tests {
         assert!(!two.is_strictly_ccw_convex());
         assert!(!two.is_strictly_cw_convex());
     }
+
+    #[test]
+    fn test_winding_more_than_once() {
+        // every turn of a pentagram is clockwise, but it winds
+        // around twice
+        let pentagram = line_string![
+            (x: 0., y: 10.),
+            (x: 6., y: -8.),
+            (x: -9.5, y: 3.),
+            (x: 9.5, y: 3.),
+            (x: -6., y: -8.),
+            (x: 0., y: 10.),
+        ];
+        assert!(!pentagram.is_convex());
+        assert!(!pentagram.is_strictly_cw_convex());
+
+        // the same points in order around the star are convex
+        let pentagon = line_string![
+            (x: 0., y: 10.),
+            (x: 9.5, y: 3.),
+            (x: 6., y: -8.),
+            (x: -6., y: -8.),
+            (x: -9.5, y: 3.),
+            (x: 0., y: 10.),
+        ];
+        assert!(pentagon.is_strictly_cw_convex());
+    }
+
+    #[test]
+    fn test_polygons() {
+        let square = polygon![(x: 0, y: 0), (x: 4, y: 0), (x: 4, y: 4), (x: 0, y: 4)];
+        assert!(square.is_convex());
+        assert!(square.is_strictly_ccw_convex());
+        assert!(!square.is_cw_convex());
+        assert!(!square.is_collinear());
+
+        let holey = Polygon::new(
+            square.exterior().clone(),
+            vec![line_string![(x: 1, y: 1), (x: 2, y: 1), (x: 2, y: 2), (x: 1, y: 1)]],
+        );
+        assert!(!holey.is_convex());
+        assert!(!holey.is_collinear());
+
+        let flat = polygon![(x: 0, y: 0), (x: 4, y: 0), (x: 2, y: 0)];
+        assert!(flat.is_collinear());
+        assert!(flat.is_convex());
+        assert!(!flat.is_strictly_convex());
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{CoordNum, Coordinate};
 use num_traits::Zero;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum Orientation {
     CounterClockwise,
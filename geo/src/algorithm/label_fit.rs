@@ -0,0 +1,231 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::erosion::{faces_where, interior_point};
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::map_coords::MapCoords;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiPolygon, Point, Polygon, Rect};
+use std::iter;
+
+/// Find where a rectangle, such as the box around a label or a stamp, fits inside a polygon.
+///
+/// The rectangle is `width` by `height`, centred on the point it's placed at, and rotated by
+/// `angle` degrees counter-clockwise about it, so its width runs at `angle` to the x axis. The
+/// centres at which it fits are the polygon eroded by the rectangle, their Minkowski difference:
+/// the edges of the polygon moved by each corner of the rectangle, and the rectangle around each
+/// vertex of the polygon, are noded together, and the faces they bound kept where the rectangle
+/// crosses no ring of the polygon.
+pub trait LabelFit<T: GeoFloat> {
+    /// The region of centres at which the rectangle fits inside the geometry, without crossing
+    /// its boundary. It's empty if the rectangle fits nowhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::label_fit::LabelFit;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// // a 4 by 2 label can be centred anywhere from 2 to 8 across, and 1 to 9 up
+    /// let region = square.label_region(4., 2., 0.);
+    /// assert_relative_eq!(region.unsigned_area(), 6. * 8.);
+    /// ```
+    fn label_region(&self, width: T, height: T, angle: T) -> MultiPolygon<T>;
+
+    /// A centre at which the rectangle fits inside the geometry, well inside the largest part of
+    /// its `label_region`, or `None` if it fits nowhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::label_fit::LabelFit;
+    /// use geo::polygon;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// // a label 12 long only fits along a diagonal
+    /// assert!(square.fit_label(12., 1., 0.).is_none());
+    /// assert!(square.fit_label(12., 1., 45.).is_some());
+    /// ```
+    fn fit_label(&self, width: T, height: T, angle: T) -> Option<Point<T>> {
+        self.label_region(width, height, angle)
+            .0
+            .iter()
+            .max_by(|a, b| a.unsigned_area().partial_cmp(&b.unsigned_area()).unwrap())
+            .and_then(interior_point)
+            .map(Point)
+    }
+}
+
+impl<T: GeoFloat> LabelFit<T> for Polygon<T> {
+    fn label_region(&self, width: T, height: T, angle: T) -> MultiPolygon<T> {
+        assert!(
+            width >= T::zero() && height >= T::zero(),
+            "width and height must not be negative"
+        );
+        let (mut sin, mut cos) = angle.to_radians().sin_cos();
+        // Edges parallel to the sides of the rectangle give overlapping segments, which must
+        // overlap exactly, rather than nearly, to be noded cleanly
+        if angle % T::from(90).unwrap() == T::zero() {
+            sin = sin.round();
+            cos = cos.round();
+        }
+        // rotated by `-angle`, so the rectangle is upright
+        let upright = self.map_coords(|&(x, y)| (x * cos + y * sin, y * cos - x * sin));
+        let rings: Vec<&LineString<T>> = iter::once(upright.exterior())
+            .chain(upright.interiors())
+            .collect();
+
+        let two = T::one() + T::one();
+        let half = Coordinate {
+            x: width / two,
+            y: height / two,
+        };
+        let corners = [
+            Coordinate {
+                x: -half.x,
+                y: -half.y,
+            },
+            Coordinate {
+                x: half.x,
+                y: -half.y,
+            },
+            half,
+            Coordinate {
+                x: -half.x,
+                y: half.y,
+            },
+        ];
+        let mut segments = vec![];
+        for ring in &rings {
+            // where a corner of the rectangle touches an edge
+            for line in ring.lines() {
+                for corner in &corners {
+                    segments.push(Line::new(line.start - *corner, line.end - *corner));
+                }
+            }
+            // and where a vertex touches a side of the rectangle
+            for coord in &ring.0 {
+                for (index, corner) in corners.iter().enumerate() {
+                    let next = corners[(index + 1) % corners.len()];
+                    segments.push(Line::new(*coord + *corner, *coord + next));
+                }
+            }
+        }
+
+        let region = faces_where(segments, |centre| {
+            let rect = Rect::new(centre - half, centre + half);
+            upright.coordinate_position(&centre) == CoordPos::Inside
+                && !rings.iter().any(|ring| rect.intersects(*ring))
+        });
+        MultiPolygon(region).map_coords(|&(x, y)| (x * cos - y * sin, x * sin + y * cos))
+    }
+}
+
+/// The rectangle may be placed inside any of the polygons, but not across them.
+impl<T: GeoFloat> LabelFit<T> for MultiPolygon<T> {
+    fn label_region(&self, width: T, height: T, angle: T) -> MultiPolygon<T> {
+        MultiPolygon(
+            self.0
+                .iter()
+                .flat_map(|polygon| polygon.label_region(width, height, angle).0)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::polygon;
+
+    // The corners of the rectangle centred on `centre`
+    fn label_corners(centre: Point<f64>, width: f64, height: f64, angle: f64) -> Vec<Point<f64>> {
+        let (sin, cos) = angle.to_radians().sin_cos();
+        [(-1., -1.), (1., -1.), (1., 1.), (-1., 1.)]
+            .iter()
+            .map(|(u, v)| {
+                let (x, y) = (u * width / 2., v * height / 2.);
+                centre + Point::new(x * cos - y * sin, x * sin + y * cos)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn regions_in_a_square() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let region = square.label_region(4., 2., 0.);
+        assert_eq!(region.0.len(), 1);
+        assert_relative_eq!(
+            region.bounding_rect().unwrap(),
+            Rect::new((2., 1.), (8., 9.))
+        );
+
+        // turned on end
+        let region = square.label_region(4., 2., 90.);
+        assert_relative_eq!(
+            region.bounding_rect().unwrap(),
+            Rect::new((1., 2.), (9., 8.)),
+            epsilon = 1e-9
+        );
+
+        // a label as large as the square only just fits, nowhere
+        assert!(square.label_region(10., 10., 0.).0.is_empty());
+        assert!(square.fit_label(10., 10., 0.).is_none());
+    }
+
+    #[test]
+    fn fitted_labels_are_inside() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        for angle in &[45., 135., -45., 225.] {
+            let centre = square.fit_label(12., 1., *angle).unwrap();
+            for corner in label_corners(centre, 12., 1., *angle) {
+                assert_eq!(square.coordinate_position(&corner.0), CoordPos::Inside);
+            }
+        }
+        assert!(square.fit_label(12., 1., 0.).is_none());
+        assert!(square.fit_label(12., 1., 10.).is_none());
+    }
+
+    #[test]
+    fn labels_avoid_holes_and_narrow_arms() {
+        // an L, with a wide arm along the x axis and a narrow one up the y axis
+        let l: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let centre = l.fit_label(6., 3., 0.).unwrap();
+        assert!(centre.y() > 1.5 && centre.y() < 2.5);
+        // on end, it only fits in the wide arm
+        assert!(l.fit_label(6., 3., 90.).is_none());
+        assert!(l.fit_label(6., 1.5, 90.).is_some());
+
+        let frame: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        // the label fits along each side of the frame, but not across the hole
+        let region = frame.label_region(5., 1., 0.);
+        assert_eq!(region.0.len(), 2);
+        assert!(frame.label_region(3., 3., 0.).0.is_empty());
+        let centre = frame.fit_label(5., 1., 0.).unwrap();
+        for corner in label_corners(centre, 5., 1., 0.) {
+            assert_eq!(frame.coordinate_position(&corner.0), CoordPos::Inside);
+        }
+    }
+}
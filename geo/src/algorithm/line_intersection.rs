@@ -3,6 +3,7 @@ use crate::{Coordinate, GeoFloat, Line};
 use crate::algorithm::bounding_rect::BoundingRect;
 use crate::algorithm::intersects::Intersects;
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum LineIntersection<F: GeoFloat> {
     /// Lines intersect in a single point
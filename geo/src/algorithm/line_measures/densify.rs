@@ -0,0 +1,202 @@
+use super::InterpolatePoint;
+use crate::{CoordFloat, Coordinate, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// Add points to a line, or the rings of a polygon, until no segment is longer than a maximum
+/// length in a metric space.
+///
+/// The new points lie along the shortest line between the existing ones in the metric space, so
+/// densifying with [`Haversine`](super::Haversine) turns each segment into an approximation of a
+/// great circle, which is useful before projecting, or drawing on a flat map.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Densify, Euclidean, Haversine};
+/// use geo::line_string;
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 0., y: 6.), (x: 3., y: 6.)];
+/// assert_eq!(
+///     line_string.densify::<Euclidean>(2.),
+///     line_string![
+///         (x: 0., y: 0.),
+///         (x: 0., y: 2.),
+///         (x: 0., y: 4.),
+///         (x: 0., y: 6.),
+///         (x: 1.5, y: 6.),
+///         (x: 3., y: 6.),
+///     ]
+/// );
+///
+/// // a great circle from New York City to London, with segments of at most 100km
+/// let route = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+/// let densified = route.densify::<Haversine>(100_000.);
+/// assert_eq!(densified.0.len(), 57);
+/// ```
+pub trait Densify<F: CoordFloat> {
+    type Output;
+
+    /// `self`, with points added so that no segment is longer than `max_segment_length`, in the
+    /// units of `MetricSpace`.
+    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: InterpolatePoint<F>;
+}
+
+impl<F: CoordFloat> Densify<F> for Line<F> {
+    type Output = LineString<F>;
+
+    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: InterpolatePoint<F>,
+    {
+        MetricSpace::points_along_line(
+            self.start_point(),
+            self.end_point(),
+            max_segment_length,
+            true,
+        )
+        .into()
+    }
+}
+
+impl<F: CoordFloat> Densify<F> for LineString<F> {
+    type Output = LineString<F>;
+
+    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: InterpolatePoint<F>,
+    {
+        let mut coords: Vec<Coordinate<F>> = vec![];
+        for line in self.lines() {
+            coords.push(line.start);
+            coords.extend(
+                MetricSpace::points_along_line(
+                    line.start_point(),
+                    line.end_point(),
+                    max_segment_length,
+                    false,
+                )
+                .into_iter()
+                .map(|point| point.0),
+            );
+        }
+        if let Some(last) = self.0.last() {
+            coords.push(*last);
+        }
+        LineString(coords)
+    }
+}
+
+impl<F: CoordFloat> Densify<F> for MultiLineString<F> {
+    type Output = MultiLineString<F>;
+
+    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: InterpolatePoint<F>,
+    {
+        self.iter()
+            .map(|line_string| line_string.densify::<MetricSpace>(max_segment_length))
+            .collect()
+    }
+}
+
+impl<F: CoordFloat> Densify<F> for Polygon<F> {
+    type Output = Polygon<F>;
+
+    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: InterpolatePoint<F>,
+    {
+        Polygon::new(
+            self.exterior().densify::<MetricSpace>(max_segment_length),
+            self.interiors()
+                .iter()
+                .map(|interior| interior.densify::<MetricSpace>(max_segment_length))
+                .collect(),
+        )
+    }
+}
+
+impl<F: CoordFloat> Densify<F> for MultiPolygon<F> {
+    type Output = MultiPolygon<F>;
+
+    fn densify<MetricSpace>(&self, max_segment_length: F) -> Self::Output
+    where
+        MetricSpace: InterpolatePoint<F>,
+    {
+        self.iter()
+            .map(|polygon| polygon.densify::<MetricSpace>(max_segment_length))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::line_measures::{Distance, Euclidean, Geodesic, Haversine, Rhumb};
+    use crate::{line_string, polygon, Point};
+
+    fn longest_segment<MetricSpace>(line_string: &LineString<f64>) -> f64
+    where
+        MetricSpace: Distance<f64, Point<f64>, Point<f64>>,
+    {
+        line_string
+            .lines()
+            .map(|line| MetricSpace::distance(line.start_point(), line.end_point()))
+            .fold(0., f64::max)
+    }
+
+    #[test]
+    fn segments_are_short_enough() {
+        let line_string = line_string![
+            (x: -74.006, y: 40.7128),
+            (x: -0.1278, y: 51.5074),
+            (x: 2.3522, y: 48.8566),
+        ];
+        let max = 50_000.;
+
+        let densified = line_string.densify::<Haversine>(max);
+        assert!(longest_segment::<Haversine>(&densified) <= max);
+        let densified = line_string.densify::<Geodesic>(max);
+        assert!(longest_segment::<Geodesic>(&densified) <= max * (1. + 1e-9));
+        let densified = line_string.densify::<Rhumb>(max);
+        assert!(longest_segment::<Rhumb>(&densified) <= max * (1. + 1e-9));
+
+        // the original points are kept
+        assert_eq!(densified.0.first(), line_string.0.first());
+        assert_eq!(densified.0.last(), line_string.0.last());
+        assert!(line_string
+            .0
+            .iter()
+            .all(|coord| densified.0.contains(coord)));
+    }
+
+    #[test]
+    fn short_segments_are_unchanged() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.)];
+        assert_eq!(line_string.densify::<Euclidean>(2.), line_string);
+        assert_eq!(line_string.densify::<Euclidean>(0.), line_string);
+
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.densify::<Euclidean>(2.), empty);
+    }
+
+    #[test]
+    fn polygons() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let densified = polygon.densify::<Euclidean>(2.);
+        assert_eq!(densified.exterior().0.len(), 9);
+        assert!(densified.exterior().is_closed());
+
+        let multi_polygon = MultiPolygon(vec![polygon]);
+        assert_eq!(
+            multi_polygon.densify::<Euclidean>(2.),
+            MultiPolygon(vec![densified])
+        );
+        let line = Line::new((0., 0.), (4., 0.));
+        assert_eq!(
+            line.densify::<Euclidean>(2.),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)]
+        );
+    }
+}
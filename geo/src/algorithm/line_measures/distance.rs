@@ -0,0 +1,24 @@
+/// Calculate the distance between the `Origin` and `Destination` geometry in a metric space.
+///
+/// The metric space, such as [`Euclidean`](super::Euclidean) or
+/// [`Haversine`](super::Haversine), implements this for each pair of geometries it can measure
+/// between.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Distance, Euclidean, Haversine};
+/// use geo::Point;
+///
+/// let new_york_city = Point::new(-74.006f64, 40.7128);
+/// let london = Point::new(-0.1278, 51.5074);
+///
+/// // meters
+/// assert_eq!(Haversine::distance(new_york_city, london).round(), 5_570_230.);
+/// // degrees, which is rarely what you want for longitude and latitude
+/// assert_eq!(Euclidean::distance(new_york_city, london).round(), 75.);
+/// ```
+pub trait Distance<F, Origin, Destination> {
+    /// The distance from `origin` to `destination`, in the units of the metric space.
+    fn distance(origin: Origin, destination: Destination) -> F;
+}
@@ -0,0 +1,72 @@
+use super::Distance;
+use crate::{CoordFloat, Point};
+
+/// Interpolate points along the shortest line between two points in a metric space: a straight
+/// line in the plane, a great circle on a sphere, or a geodesic on an ellipsoid.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Euclidean, Haversine, InterpolatePoint};
+/// use geo::Point;
+///
+/// let start = Point::new(-80., 50.);
+/// let end = Point::new(80., 50.);
+///
+/// let halfway = Euclidean::point_at_ratio_between(start, end, 0.5);
+/// assert_eq!(halfway, Point::new(0., 50.));
+///
+/// // the great circle between the points passes far to the north
+/// let halfway = Haversine::point_at_ratio_between(start, end, 0.5);
+/// assert!(halfway.y() > 80.);
+/// ```
+pub trait InterpolatePoint<F: CoordFloat>: Distance<F, Point<F>, Point<F>> {
+    /// The point `ratio_from_start` of the way from `start` to `end`.
+    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F>;
+
+    /// Evenly spaced points between `start` and `end`, no more than `max_distance` apart.
+    ///
+    /// `start` and `end` are only included if `include_ends` is `true`. If `max_distance` isn't
+    /// positive, no points are added between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::line_measures::{Euclidean, InterpolatePoint};
+    /// use geo::Point;
+    ///
+    /// let start = Point::new(0., 0.);
+    /// let end = Point::new(9., 0.);
+    /// assert_eq!(
+    ///     Euclidean::points_along_line(start, end, 4., false),
+    ///     vec![Point::new(3., 0.), Point::new(6., 0.)]
+    /// );
+    /// assert_eq!(Euclidean::points_along_line(start, end, 4., true).len(), 4);
+    /// ```
+    fn points_along_line(
+        start: Point<F>,
+        end: Point<F>,
+        max_distance: F,
+        include_ends: bool,
+    ) -> Vec<Point<F>> {
+        let mut points = vec![];
+        if include_ends {
+            points.push(start);
+        }
+
+        let distance = Self::distance(start, end);
+        if max_distance > F::zero() && distance > max_distance {
+            let segments = (distance / max_distance).ceil();
+            let count = segments.to_usize().unwrap();
+            for i in 1..count {
+                let ratio = F::from(i).unwrap() / segments;
+                points.push(Self::point_at_ratio_between(start, end, ratio));
+            }
+        }
+
+        if include_ends {
+            points.push(end);
+        }
+        points
+    }
+}
@@ -0,0 +1,105 @@
+use super::Distance;
+use crate::{CoordFloat, Line, LineString, MultiLineString, Point};
+
+/// Calculate the length of a line in a metric space.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Euclidean, Haversine, Length};
+/// use geo::line_string;
+///
+/// let line_string = line_string![
+///     // New York City
+///     (x: -74.006f64, y: 40.7128),
+///     // London
+///     (x: -0.1278, y: 51.5074),
+/// ];
+///
+/// // meters
+/// assert_eq!(line_string.length::<Haversine>().round(), 5_570_230.);
+/// // degrees
+/// assert_eq!(line_string.length::<Euclidean>().round(), 75.);
+/// ```
+pub trait Length<F: CoordFloat> {
+    /// The length of `self`, in the units of `MetricSpace`.
+    fn length<MetricSpace>(&self) -> F
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>>;
+}
+
+impl<F: CoordFloat> Length<F> for Line<F> {
+    fn length<MetricSpace>(&self) -> F
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>>,
+    {
+        MetricSpace::distance(self.start_point(), self.end_point())
+    }
+}
+
+impl<F: CoordFloat> Length<F> for LineString<F> {
+    fn length<MetricSpace>(&self) -> F
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>>,
+    {
+        self.lines().fold(F::zero(), |total, line| {
+            total + line.length::<MetricSpace>()
+        })
+    }
+}
+
+impl<F: CoordFloat> Length<F> for MultiLineString<F> {
+    fn length<MetricSpace>(&self) -> F
+    where
+        MetricSpace: Distance<F, Point<F>, Point<F>>,
+    {
+        self.iter().fold(F::zero(), |total, line_string| {
+            total + line_string.length::<MetricSpace>()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::algorithm::geodesic_length::GeodesicLength;
+    use crate::algorithm::haversine_length::HaversineLength;
+    use crate::algorithm::line_measures::{Euclidean, Geodesic, Haversine, Rhumb};
+    use crate::line_string;
+
+    #[test]
+    fn matches_the_per_metric_traits() {
+        let line_string = line_string![
+            (x: -74.006, y: 40.7128),
+            (x: -0.1278, y: 51.5074),
+            (x: 2.3522, y: 48.8566),
+        ];
+        assert_eq!(
+            line_string.length::<Euclidean>(),
+            line_string.euclidean_length()
+        );
+        assert_eq!(
+            line_string.length::<Haversine>(),
+            line_string.haversine_length()
+        );
+        assert_eq!(
+            line_string.length::<Geodesic>(),
+            line_string.geodesic_length()
+        );
+
+        let multi_line_string = MultiLineString(vec![line_string.clone(), line_string.clone()]);
+        assert_eq!(
+            multi_line_string.length::<Rhumb>(),
+            2. * line_string.length::<Rhumb>()
+        );
+    }
+
+    #[test]
+    fn empty_lines() {
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.length::<Haversine>(), 0.);
+        let point = Line::new((1., 1.), (1., 1.));
+        assert_eq!(point.length::<Rhumb>(), 0.);
+    }
+}
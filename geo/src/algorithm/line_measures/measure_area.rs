@@ -0,0 +1,23 @@
+/// Calculate the unsigned area of a geometry in a metric space.
+///
+/// The metric space implements this for each geometry it can measure the area of. Areas are in
+/// the square of the metric space's units.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Euclidean, Geodesic, Haversine, MeasureArea};
+/// use geo::polygon;
+///
+/// // one degree square, at the equator
+/// let polygon = polygon![(x: 0f64, y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+///
+/// assert_eq!(Euclidean::unsigned_area(&polygon), 1.);
+/// // about 12,364km² on a sphere, and 12,309km² on the WGS84 ellipsoid
+/// assert_eq!((Haversine::unsigned_area(&polygon) / 1e6).round(), 12_364.);
+/// assert_eq!((Geodesic::unsigned_area(&polygon) / 1e6).round(), 12_309.);
+/// ```
+pub trait MeasureArea<F, G> {
+    /// The unsigned area of `geometry`, in the square of the units of the metric space.
+    fn unsigned_area(geometry: &G) -> F;
+}
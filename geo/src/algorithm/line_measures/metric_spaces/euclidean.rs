@@ -0,0 +1,50 @@
+use super::super::{Distance, InterpolatePoint, MeasureArea};
+use crate::algorithm::area::Area;
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{GeoFloat, Line, MultiPolygon, Point, Polygon};
+
+/// The plane, measured with [`EuclideanDistance`] and [`Area`].
+///
+/// Distances are in the units of the coordinates, and areas in their square. Longitude and
+/// latitude should be projected first, or measured in one of the other metric spaces.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Distance, Euclidean};
+/// use geo::Point;
+///
+/// assert_eq!(Euclidean::distance(Point::new(0., 0.), Point::new(3., 4.)), 5.);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Euclidean;
+
+impl<F: GeoFloat> Distance<F, Point<F>, Point<F>> for Euclidean {
+    fn distance(origin: Point<F>, destination: Point<F>) -> F {
+        origin.euclidean_distance(&destination)
+    }
+}
+
+impl<F: GeoFloat> Distance<F, Point<F>, Line<F>> for Euclidean {
+    fn distance(origin: Point<F>, destination: Line<F>) -> F {
+        origin.euclidean_distance(&destination)
+    }
+}
+
+impl<F: GeoFloat> InterpolatePoint<F> for Euclidean {
+    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F> {
+        start + (end - start) * ratio_from_start
+    }
+}
+
+impl<F: GeoFloat> MeasureArea<F, Polygon<F>> for Euclidean {
+    fn unsigned_area(geometry: &Polygon<F>) -> F {
+        geometry.unsigned_area()
+    }
+}
+
+impl<F: GeoFloat> MeasureArea<F, MultiPolygon<F>> for Euclidean {
+    fn unsigned_area(geometry: &MultiPolygon<F>) -> F {
+        geometry.unsigned_area()
+    }
+}
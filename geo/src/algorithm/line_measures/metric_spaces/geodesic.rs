@@ -0,0 +1,101 @@
+use super::super::{Distance, InterpolatePoint, MeasureArea};
+use crate::algorithm::geodesic_distance::GeodesicDistance;
+use crate::algorithm::geodesic_intermediate::GeodesicIntermediate;
+use crate::{LineString, MultiPolygon, Point, Polygon};
+use geographiclib_rs::{Geodesic as Ellipsoid, PolygonArea, Winding};
+
+/// The WGS84 ellipsoid, measured along geodesics with the methods of [Karney (2013)], as in
+/// [`GeodesicDistance`].
+///
+/// Coordinates are longitude and latitude, in degrees. Distances are in meters, and areas in
+/// square meters. This is the most accurate of the metric spaces for the earth, and the slowest.
+///
+/// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Distance, Geodesic};
+/// use geo::Point;
+///
+/// // New York City to London, in meters
+/// let distance = Geodesic::distance(Point::new(-74.006, 40.7128), Point::new(-0.1278, 51.5074));
+/// assert_eq!(distance.round(), 5_585_234.);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Geodesic;
+
+impl Distance<f64, Point<f64>, Point<f64>> for Geodesic {
+    fn distance(origin: Point<f64>, destination: Point<f64>) -> f64 {
+        origin.geodesic_distance(&destination)
+    }
+}
+
+impl InterpolatePoint<f64> for Geodesic {
+    fn point_at_ratio_between(
+        start: Point<f64>,
+        end: Point<f64>,
+        ratio_from_start: f64,
+    ) -> Point<f64> {
+        start.geodesic_intermediate(&end, ratio_from_start)
+    }
+}
+
+impl MeasureArea<f64, Polygon<f64>> for Geodesic {
+    fn unsigned_area(geometry: &Polygon<f64>) -> f64 {
+        geometry
+            .interiors()
+            .iter()
+            .fold(ring_area(geometry.exterior()), |total, interior| {
+                total - ring_area(interior)
+            })
+    }
+}
+
+impl MeasureArea<f64, MultiPolygon<f64>> for Geodesic {
+    fn unsigned_area(geometry: &MultiPolygon<f64>) -> f64 {
+        geometry.iter().map(Geodesic::unsigned_area).sum()
+    }
+}
+
+// The unsigned area enclosed by `ring`.
+fn ring_area(ring: &LineString<f64>) -> f64 {
+    let ellipsoid = Ellipsoid::wgs84();
+    let mut polygon_area = PolygonArea::new(&ellipsoid, Winding::CounterClockwise);
+    // the closing coordinate repeats the first, which adds nothing
+    for coord in &ring.0 {
+        polygon_area.add_point(coord.y, coord.x);
+    }
+    let (_perimeter, area, _count) = polygon_area.compute(true);
+    area.abs()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn area_of_rings_and_holes() {
+        let exterior = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let interior =
+            line_string![(x: 0.5, y: 0.5), (x: 1.5, y: 0.5), (x: 1.5, y: 1.5), (x: 0.5, y: 1.5)];
+        let reversed: LineString<f64> = exterior.0.iter().rev().cloned().collect();
+
+        let whole = Geodesic::unsigned_area(&Polygon::new(exterior.clone(), vec![]));
+        assert_eq!(
+            whole,
+            Geodesic::unsigned_area(&Polygon::new(reversed, vec![]))
+        );
+        let hole = Geodesic::unsigned_area(&Polygon::new(interior.clone(), vec![]));
+        assert_relative_eq!(
+            Geodesic::unsigned_area(&Polygon::new(exterior, vec![interior])),
+            whole - hole
+        );
+        // about 4 square degrees at the equator
+        assert_relative_eq!(whole, 4. * 12_308e6, max_relative = 1e-3);
+
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(Geodesic::unsigned_area(&empty), 0.);
+    }
+}
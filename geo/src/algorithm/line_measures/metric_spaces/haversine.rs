@@ -0,0 +1,110 @@
+use super::super::{Distance, InterpolatePoint, MeasureArea};
+use crate::algorithm::chamberlain_duquette_area::signed_area_on_sphere;
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::algorithm::haversine_intermediate::HaversineIntermediate;
+use crate::{CoordFloat, GeoFloat, Line, MultiPolygon, Point, Polygon, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// A sphere with the earth's mean radius, measured with [`HaversineDistance`], along great
+/// circles.
+///
+/// Coordinates are longitude and latitude, in degrees. Distances are in meters, and areas, which
+/// are calculated as by
+/// [`ChamberlainDuquetteArea`](crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea)
+/// but on a sphere with the same radius, in square meters.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Distance, Haversine};
+/// use geo::Point;
+///
+/// // New York City to London, in meters
+/// let distance = Haversine::distance(Point::new(-74.006f64, 40.7128), Point::new(-0.1278, 51.5074));
+/// assert_eq!(distance.round(), 5_570_230.);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Haversine;
+
+impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Haversine {
+    fn distance(origin: Point<F>, destination: Point<F>) -> F {
+        origin.haversine_distance(&destination)
+    }
+}
+
+impl<F: GeoFloat + FromPrimitive> Distance<F, Point<F>, Line<F>> for Haversine {
+    fn distance(origin: Point<F>, destination: Line<F>) -> F {
+        origin.haversine_distance(&destination)
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Haversine {
+    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F> {
+        if start == end {
+            return start;
+        }
+        start.haversine_intermediate(&end, ratio_from_start)
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> MeasureArea<F, Polygon<F>> for Haversine {
+    fn unsigned_area(geometry: &Polygon<F>) -> F {
+        signed_area_on_sphere(geometry, F::from(MEAN_EARTH_RADIUS).unwrap()).abs()
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> MeasureArea<F, MultiPolygon<F>> for Haversine {
+    fn unsigned_area(geometry: &MultiPolygon<F>) -> F {
+        geometry.iter().fold(F::zero(), |total, polygon| {
+            total + Haversine::unsigned_area(polygon)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    use crate::polygon;
+
+    #[test]
+    fn interpolation() {
+        let start = Point::new(10., 20.);
+        let end = Point::new(125., 25.);
+        assert_eq!(Haversine::point_at_ratio_between(start, start, 0.5), start);
+        assert_relative_eq!(
+            Haversine::point_at_ratio_between(start, end, 0.5),
+            start.haversine_intermediate(&end, 0.5)
+        );
+
+        let points = Haversine::points_along_line(start, end, 1_000_000., true);
+        assert_eq!(points.first(), Some(&start));
+        assert_eq!(points.last(), Some(&end));
+        for pair in points.windows(2) {
+            assert!(Haversine::distance(pair[0], pair[1]) <= 1_000_000.);
+        }
+    }
+
+    #[test]
+    fn area_scales_with_the_radius() {
+        let polygon = polygon![
+            (x: 125., y: -15.),
+            (x: 144., y: -15.),
+            (x: 154., y: -27.),
+            (x: 148., y: -39.),
+            (x: 130., y: -33.),
+            (x: 117., y: -37.),
+            (x: 113., y: -22.),
+        ];
+        let scale = (6_371_008.8f64 / 6_378_137.).powi(2);
+        assert_relative_eq!(
+            Haversine::unsigned_area(&polygon),
+            polygon.chamberlain_duquette_unsigned_area() * scale,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            Haversine::unsigned_area(&MultiPolygon(vec![polygon.clone(), polygon.clone()])),
+            2. * Haversine::unsigned_area(&polygon)
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! The metric spaces which [`line_measures`](super) can measure in.
+
+mod euclidean;
+pub use euclidean::Euclidean;
+
+mod geodesic;
+pub use geodesic::Geodesic;
+
+mod haversine;
+pub use haversine::Haversine;
+
+mod rhumb;
+pub use rhumb::Rhumb;
@@ -0,0 +1,191 @@
+use super::super::{Distance, InterpolatePoint};
+use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// A sphere with the earth's mean radius, measured along [rhumb lines]: lines of constant
+/// bearing, which are straight on a Mercator projection.
+///
+/// Coordinates are longitude and latitude, in degrees, and distances are in meters. A rhumb line
+/// is never shorter than the great circle between the same points, but is easy to navigate, as
+/// its bearing never changes.
+///
+/// [rhumb lines]: https://en.wikipedia.org/wiki/Rhumb_line
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::{Distance, Haversine, Rhumb};
+/// use geo::Point;
+///
+/// let new_york_city = Point::new(-74.006f64, 40.7128);
+/// let london = Point::new(-0.1278, 51.5074);
+///
+/// // meters
+/// assert_eq!(Rhumb::distance(new_york_city, london).round(), 5_794_129.);
+/// assert!(Rhumb::distance(new_york_city, london) > Haversine::distance(new_york_city, london));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rhumb;
+
+impl<F: CoordFloat + FromPrimitive> Distance<F, Point<F>, Point<F>> for Rhumb {
+    fn distance(origin: Point<F>, destination: Point<F>) -> F {
+        RhumbLine::new(origin, destination).distance
+    }
+}
+
+impl<F: CoordFloat + FromPrimitive> InterpolatePoint<F> for Rhumb {
+    fn point_at_ratio_between(start: Point<F>, end: Point<F>, ratio_from_start: F) -> Point<F> {
+        if start == end {
+            return start;
+        }
+        RhumbLine::new(start, end).point_at(ratio_from_start)
+    }
+}
+
+// The rhumb line from `origin` to a destination, after the formulae of
+// https://www.movable-type.co.uk/scripts/latlong.html
+struct RhumbLine<F: CoordFloat> {
+    origin: Point<F>,
+    /// The bearing, in radians clockwise from north
+    bearing: F,
+    /// The distance, in meters
+    distance: F,
+}
+
+impl<F: CoordFloat + FromPrimitive> RhumbLine<F> {
+    fn new(origin: Point<F>, destination: Point<F>) -> Self {
+        let pi = F::from(std::f64::consts::PI).unwrap();
+        let phi1 = origin.y().to_radians();
+        let phi2 = destination.y().to_radians();
+        let delta_phi = phi2 - phi1;
+        let delta_psi = stretched_latitude_difference(phi1, phi2);
+
+        // take the shorter way around, across the antimeridian if need be
+        let mut delta_lambda = (destination.x() - origin.x()).to_radians();
+        if delta_lambda.abs() > pi {
+            delta_lambda = if delta_lambda > F::zero() {
+                delta_lambda - (pi + pi)
+            } else {
+                delta_lambda + (pi + pi)
+            };
+        }
+
+        let q = east_west_scale(phi1, delta_phi, delta_psi);
+        let angle = (delta_phi * delta_phi + q * q * delta_lambda * delta_lambda).sqrt();
+        RhumbLine {
+            origin,
+            bearing: delta_lambda.atan2(delta_psi),
+            distance: angle * F::from(MEAN_EARTH_RADIUS).unwrap(),
+        }
+    }
+
+    // The point `ratio` of the way along the line.
+    fn point_at(&self, ratio: F) -> Point<F> {
+        let pi = F::from(std::f64::consts::PI).unwrap();
+        let half_pi = pi / (F::one() + F::one());
+        let angle = self.distance * ratio / F::from(MEAN_EARTH_RADIUS).unwrap();
+
+        let phi1 = self.origin.y().to_radians();
+        let delta_phi = angle * self.bearing.cos();
+        let mut phi2 = phi1 + delta_phi;
+        // a line past a pole continues down the other side
+        if phi2.abs() > half_pi {
+            phi2 = if phi2 > F::zero() {
+                pi - phi2
+            } else {
+                -pi - phi2
+            };
+        }
+
+        let delta_psi = stretched_latitude_difference(phi1, phi2);
+        let q = east_west_scale(phi1, delta_phi, delta_psi);
+        let delta_lambda = angle * self.bearing.sin() / q;
+
+        let mut lambda2 = self.origin.x().to_radians() + delta_lambda;
+        if lambda2 > pi {
+            lambda2 = lambda2 - (pi + pi);
+        } else if lambda2 < -pi {
+            lambda2 = lambda2 + (pi + pi);
+        }
+        Point::new(lambda2.to_degrees(), phi2.to_degrees())
+    }
+}
+
+// The difference between the latitudes on a Mercator projection.
+fn stretched_latitude_difference<F: CoordFloat + FromPrimitive>(phi1: F, phi2: F) -> F {
+    let quarter_pi = F::from(std::f64::consts::FRAC_PI_4).unwrap();
+    let two = F::one() + F::one();
+    ((quarter_pi + phi2 / two).tan() / (quarter_pi + phi1 / two).tan()).ln()
+}
+
+// The ratio of a change of latitude to the change of stretched latitude, which scales distances
+// east and west. Along a parallel, where both changes vanish, it is the cosine of the latitude.
+fn east_west_scale<F: CoordFloat + FromPrimitive>(phi1: F, delta_phi: F, delta_psi: F) -> F {
+    if delta_psi.abs() > F::from(1e-12).unwrap() {
+        delta_phi / delta_psi
+    } else {
+        phi1.cos()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn along_meridians_and_parallels() {
+        // one degree of latitude, or of longitude at the equator
+        let degree = MEAN_EARTH_RADIUS * std::f64::consts::PI / 180.;
+        assert_relative_eq!(
+            Rhumb::distance(Point::new(0., 0.), Point::new(0., 1.)),
+            degree,
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(
+            Rhumb::distance(Point::new(0., 0.), Point::new(1., 0.)),
+            degree,
+            max_relative = 1e-12
+        );
+        // along the 60th parallel, a degree of longitude is half as long
+        assert_relative_eq!(
+            Rhumb::distance(Point::new(0., 60.), Point::new(1., 60.)),
+            degree / 2.,
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let start = Point::new(179f64, 0.);
+        let end = Point::new(-179., 0.);
+        assert_relative_eq!(
+            Rhumb::distance(start, end),
+            Rhumb::distance(Point::new(0., 0.), Point::new(2., 0.)),
+            max_relative = 1e-12
+        );
+        let halfway = Rhumb::point_at_ratio_between(start, end, 0.5);
+        assert_relative_eq!(halfway.x().abs(), 180., epsilon = 1e-9);
+        assert_relative_eq!(halfway.y(), 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn constant_bearing() {
+        let start = Point::new(-74.006, 40.7128);
+        let end = Point::new(-0.1278, 51.5074);
+        let bearing = RhumbLine::new(start, end).bearing;
+        let points = Rhumb::points_along_line(start, end, 100_000., true);
+        assert_relative_eq!(*points.last().unwrap(), end);
+        for pair in points.windows(2) {
+            assert_relative_eq!(
+                RhumbLine::new(pair[0], pair[1]).bearing,
+                bearing,
+                epsilon = 1e-9
+            );
+        }
+        assert_relative_eq!(
+            Rhumb::point_at_ratio_between(start, end, 1.),
+            end,
+            epsilon = 1e-9
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Measure lines and areas in a choice of metric spaces.
+//!
+//! Each measurement has a single trait, generic over the [metric space](metric_spaces) it is
+//! made in, rather than a trait per measurement and metric space, such as
+//! [`EuclideanLength`](crate::algorithm::euclidean_length::EuclideanLength) and
+//! [`HaversineLength`](crate::algorithm::haversine_length::HaversineLength). This makes it
+//! possible to write code which works in any of them:
+//!
+//! - [`Distance`]: the distance between two points, such as `Haversine::distance(a, b)`
+//! - [`InterpolatePoint`]: points between two others, along the shortest line between them
+//! - [`Length`]: the length of a line, such as `line_string.length::<Haversine>()`
+//! - [`Densify`]: add points to a line until no segment is longer than a maximum
+//! - [`MeasureArea`]: the area of a polygon, such as `Haversine::unsigned_area(&polygon)`
+//!
+//! The metric spaces are [`Euclidean`], for planar coordinates, and [`Haversine`], [`Geodesic`]
+//! and [`Rhumb`], for longitude and latitude in degrees, which measure in meters.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::line_measures::{
+//!     Densify, Distance, Euclidean, Haversine, InterpolatePoint, Length,
+//! };
+//! use geo::{line_string, Point};
+//!
+//! // the length of the first segment, as a fraction of the whole line, in any metric space
+//! fn first_segment_share<M>(points: &[Point<f64>]) -> f64
+//! where
+//!     M: Distance<f64, Point<f64>, Point<f64>>,
+//! {
+//!     let total: f64 = points.windows(2).map(|w| M::distance(w[0], w[1])).sum();
+//!     M::distance(points[0], points[1]) / total
+//! }
+//!
+//! let points = [
+//!     Point::new(0., 0.),
+//!     Point::new(0., 60.),
+//!     Point::new(90., 60.),
+//! ];
+//! // in the plane, the first segment is 40% of the line
+//! assert_eq!(first_segment_share::<Euclidean>(&points), 0.4);
+//! // but on the globe, 90° of longitude at 60°N is shorter than 60° of latitude
+//! assert!(first_segment_share::<Haversine>(&points) > 0.5);
+//!
+//! let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+//! assert_eq!(line_string.length::<Euclidean>(), 10.);
+//! assert_eq!(line_string.densify::<Euclidean>(4.).0.len(), 4);
+//! assert_eq!(
+//!     Euclidean::point_at_ratio_between(Point::new(0., 0.), Point::new(10., 0.), 0.25),
+//!     Point::new(2.5, 0.),
+//! );
+//! ```
+
+mod densify;
+pub use densify::Densify;
+
+mod distance;
+pub use distance::Distance;
+
+mod interpolate_point;
+pub use interpolate_point::InterpolatePoint;
+
+mod length;
+pub use length::Length;
+
+mod measure_area;
+pub use measure_area::MeasureArea;
+
+pub mod metric_spaces;
+pub use metric_spaces::{Euclidean, Geodesic, Haversine, Rhumb};
@@ -0,0 +1,401 @@
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::segment;
+use crate::algorithm::sweepline::SweepLine;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// An edge of a [`LineNetwork`], between two of its nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkEdge<T: GeoFloat> {
+    pub line: Line<T>,
+    /// The index of the node at the start of `line`
+    pub start: usize,
+    /// The index of the node at the end of `line`
+    pub end: usize,
+    /// The index of the `LineString` this edge was split from
+    pub line_string_index: usize,
+}
+
+impl<T: GeoFloat> NetworkEdge<T> {
+    pub fn length(&self) -> T {
+        self.line.start.euclidean_distance(&self.line.end)
+    }
+}
+
+/// A coordinate on an edge of a [`LineNetwork`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetworkLocation<T: GeoFloat> {
+    /// The index of the edge
+    pub edge: usize,
+    /// The coordinate, which lies on the edge
+    pub coord: Coordinate<T>,
+}
+
+/// A path along the edges of a [`LineNetwork`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkPath<T: GeoFloat> {
+    pub line_string: LineString<T>,
+    pub length: T,
+}
+
+/// The graph of a network of `LineString`s, such as roads, noded at every point where they meet.
+///
+/// The `LineString`s are split, with a [`SweepLine`], wherever they intersect, touch or overlap,
+/// and the pieces become the edges of the graph. Its nodes are the ends of the edges, so a path
+/// can turn from one `LineString` onto another wherever they meet. Pieces shared by overlapping
+/// `LineString`s appear once, and pieces of zero length are left out.
+///
+/// Distances are Euclidean, so geographic coordinates should be projected first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_network::LineNetwork;
+/// use geo::{line_string, Coordinate, MultiLineString};
+///
+/// // two roads crossing at (5, 0)
+/// let roads = MultiLineString(vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 5., y: -5.), (x: 5., y: 5.)],
+/// ]);
+/// let network = LineNetwork::new(&roads);
+/// assert_eq!(network.nodes().len(), 5);
+/// assert_eq!(network.edges().len(), 4);
+///
+/// // snap two points onto the network, and find the route between them
+/// let from = network.locate(Coordinate { x: 2., y: 1. }).unwrap();
+/// let to = network.locate(Coordinate { x: 6., y: 3. }).unwrap();
+/// let path = network.shortest_path(from, to).unwrap();
+/// assert_eq!(path.length, 6.);
+/// assert_eq!(
+///     path.line_string,
+///     line_string![(x: 2., y: 0.), (x: 5., y: 0.), (x: 5., y: 3.)]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineNetwork<T: GeoFloat> {
+    nodes: Vec<Coordinate<T>>,
+    edges: Vec<NetworkEdge<T>>,
+    node_edges: Vec<Vec<usize>>,
+}
+
+impl<T: GeoFloat> LineNetwork<T> {
+    /// Node `network`, and build its graph.
+    pub fn new(network: &MultiLineString<T>) -> Self {
+        let (segments, sources): (Vec<Line<T>>, Vec<usize>) = network
+            .0
+            .iter()
+            .enumerate()
+            .flat_map(|(line_string_index, line_string)| {
+                line_string
+                    .lines()
+                    .map(move |line| (line, line_string_index))
+            })
+            .unzip();
+        let pieces: Vec<_> = SweepLine::new(segments)
+            .noded_segments()
+            .into_iter()
+            .filter(|piece| piece.line.start != piece.line.end)
+            .collect();
+
+        let mut nodes: Vec<Coordinate<T>> = pieces
+            .iter()
+            .flat_map(|piece| vec![piece.line.start, piece.line.end])
+            .collect();
+        nodes.sort_by(lex_cmp);
+        nodes.dedup();
+        let node_index = |coord: Coordinate<T>| {
+            nodes
+                .binary_search_by(|node| lex_cmp(node, &coord))
+                .expect("every end of a piece is a node")
+        };
+
+        let mut edges: Vec<NetworkEdge<T>> = vec![];
+        let mut node_edges = vec![vec![]; nodes.len()];
+        for piece in pieces {
+            let start = node_index(piece.line.start);
+            let end = node_index(piece.line.end);
+            let is_duplicate = node_edges[start].iter().any(|index: &usize| {
+                let edge = &edges[*index];
+                (edge.start, edge.end) == (start, end) || (edge.start, edge.end) == (end, start)
+            });
+            if is_duplicate {
+                continue;
+            }
+            node_edges[start].push(edges.len());
+            node_edges[end].push(edges.len());
+            edges.push(NetworkEdge {
+                line: piece.line,
+                start,
+                end,
+                line_string_index: sources[piece.source],
+            });
+        }
+
+        LineNetwork {
+            nodes,
+            edges,
+            node_edges,
+        }
+    }
+
+    /// The nodes, ordered by x and then y.
+    pub fn nodes(&self) -> &[Coordinate<T>] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[NetworkEdge<T>] {
+        &self.edges
+    }
+
+    /// The indices of the edges starting or ending at `node`.
+    pub fn node_edges(&self, node: usize) -> &[usize] {
+        &self.node_edges[node]
+    }
+
+    /// Snap `coord` to the closest coordinate on the network, or `None` if it has no edges.
+    pub fn locate(&self, coord: Coordinate<T>) -> Option<NetworkLocation<T>> {
+        self.edges
+            .iter()
+            .enumerate()
+            .map(|(edge, network_edge)| NetworkLocation {
+                edge,
+                coord: segment::closest_coord(network_edge.line, coord).coord,
+            })
+            .min_by(|a, b| {
+                a.coord
+                    .euclidean_distance(&coord)
+                    .partial_cmp(&b.coord.euclidean_distance(&coord))
+                    .unwrap()
+            })
+    }
+
+    /// The shortest path along the network from `from` to `to`, found with Dijkstra's algorithm,
+    /// or `None` if they aren't connected.
+    pub fn shortest_path(
+        &self,
+        from: NetworkLocation<T>,
+        to: NetworkLocation<T>,
+    ) -> Option<NetworkPath<T>> {
+        let from_edge = &self.edges[from.edge];
+        let to_edge = &self.edges[to.edge];
+
+        // the shortest distance to each node, and the node it was reached from
+        let mut costs: Vec<Option<(T, Option<usize>)>> = vec![None; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+        for node in &[from_edge.start, from_edge.end] {
+            queue.push(Visit {
+                cost: from.coord.euclidean_distance(&self.nodes[*node]),
+                node: *node,
+                previous: None,
+            });
+        }
+        // along the edge, if both locations are on it
+        let mut best: Option<(T, Option<usize>)> = if from.edge == to.edge {
+            Some((from.coord.euclidean_distance(&to.coord), None))
+        } else {
+            None
+        };
+
+        while let Some(visit) = queue.pop() {
+            if let Some((best_cost, _)) = best {
+                if visit.cost >= best_cost {
+                    break;
+                }
+            }
+            if costs[visit.node].is_some() {
+                continue;
+            }
+            costs[visit.node] = Some((visit.cost, visit.previous));
+
+            if visit.node == to_edge.start || visit.node == to_edge.end {
+                let cost = visit.cost + self.nodes[visit.node].euclidean_distance(&to.coord);
+                let is_better = match best {
+                    Some((best_cost, _)) => cost < best_cost,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((cost, Some(visit.node)));
+                }
+            }
+            for edge in &self.node_edges[visit.node] {
+                let edge = &self.edges[*edge];
+                let next = if edge.start == visit.node {
+                    edge.end
+                } else {
+                    edge.start
+                };
+                if costs[next].is_none() {
+                    queue.push(Visit {
+                        cost: visit.cost + edge.length(),
+                        node: next,
+                        previous: Some(visit.node),
+                    });
+                }
+            }
+        }
+
+        let (length, mut node) = best?;
+        let mut coords = vec![to.coord];
+        while let Some(index) = node {
+            coords.push(self.nodes[index]);
+            node = costs[index].and_then(|(_, previous)| previous);
+        }
+        coords.push(from.coord);
+        coords.reverse();
+        coords.dedup();
+        if coords.len() == 1 {
+            coords.push(coords[0]);
+        }
+        Some(NetworkPath {
+            line_string: LineString(coords),
+            length,
+        })
+    }
+}
+
+// A node reached by Dijkstra's algorithm, ordered so that the cheapest is visited first.
+struct Visit<T: GeoFloat> {
+    cost: T,
+    node: usize,
+    previous: Option<usize>,
+}
+
+// These impls give us a min-heap
+impl<T: GeoFloat> Ord for Visit<T> {
+    fn cmp(&self, other: &Visit<T>) -> Ordering {
+        other.cost.partial_cmp(&self.cost).unwrap()
+    }
+}
+
+impl<T: GeoFloat> PartialOrd for Visit<T> {
+    fn partial_cmp(&self, other: &Visit<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Eq for Visit<T> {}
+
+impl<T: GeoFloat> PartialEq for Visit<T> {
+    fn eq(&self, other: &Visit<T>) -> bool {
+        self.cost == other.cost
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn location(network: &LineNetwork<f64>, x: f64, y: f64) -> NetworkLocation<f64> {
+        network.locate(Coordinate { x, y }).unwrap()
+    }
+
+    #[test]
+    fn noding() {
+        let roads = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+            // overlapping the first road between (2, 0) and (4, 0)
+            line_string![(x: 2., y: 0.), (x: 6., y: 0.)],
+            // touching the first road at (4, 2)
+            line_string![(x: 4., y: 2.), (x: 8., y: 2.)],
+        ]);
+        let network = LineNetwork::new(&roads);
+        assert_eq!(
+            network.nodes(),
+            &[
+                Coordinate { x: 0., y: 0. },
+                Coordinate { x: 2., y: 0. },
+                Coordinate { x: 4., y: 0. },
+                Coordinate { x: 4., y: 2. },
+                Coordinate { x: 4., y: 4. },
+                Coordinate { x: 6., y: 0. },
+                Coordinate { x: 8., y: 2. },
+            ]
+        );
+        assert_eq!(network.edges().len(), 6);
+        // the node at (4, 2) joins three edges
+        assert_eq!(network.node_edges(3).len(), 3);
+        let sources: Vec<usize> = network
+            .edges()
+            .iter()
+            .map(|edge| edge.line_string_index)
+            .collect();
+        assert_eq!(sources, vec![0, 0, 0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn shortest_path() {
+        // a square block, with a diagonal shortcut
+        let roads = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+            line_string![(x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.)],
+            line_string![(x: 0., y: 10.), (x: 3., y: 6.)],
+        ]);
+        let network = LineNetwork::new(&roads);
+
+        let path = network
+            .shortest_path(location(&network, 1., -1.), location(&network, 9., 11.))
+            .unwrap();
+        assert_eq!(path.length, 20.);
+        assert_eq!(path.line_string.0.len(), 4);
+
+        // the shortcut is a dead end
+        let path = network
+            .shortest_path(location(&network, 3., 6.), location(&network, 10., 9.))
+            .unwrap();
+        assert_eq!(path.length, 5. + 10. + 1.);
+        assert_eq!(
+            path.line_string,
+            line_string![(x: 3., y: 6.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 9.)]
+        );
+    }
+
+    #[test]
+    fn on_the_same_edge() {
+        let roads = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 10., y: 0.), (x: 10., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.)],
+        ]);
+        let network = LineNetwork::new(&roads);
+        let from = location(&network, 2., -1.);
+        let to = location(&network, 7., -1.);
+        let path = network.shortest_path(from, to).unwrap();
+        assert_eq!(path.length, 5.);
+        assert_eq!(
+            path.line_string,
+            line_string![(x: 2., y: 0.), (x: 7., y: 0.)]
+        );
+
+        let path = network.shortest_path(from, from).unwrap();
+        assert_eq!(path.length, 0.);
+        assert_eq!(
+            path.line_string,
+            line_string![(x: 2., y: 0.), (x: 2., y: 0.)]
+        );
+
+        // round the nearer corner
+        let from = location(&network, 0.5, -1.);
+        let to = location(&network, 0.5, 2.);
+        assert_eq!(network.shortest_path(from, to).unwrap().length, 2.);
+    }
+
+    #[test]
+    fn disconnected() {
+        let roads = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+        ]);
+        let network = LineNetwork::new(&roads);
+        let from = location(&network, 1., 1.);
+        let to = location(&network, 1., 4.);
+        assert_eq!(from.edge, 0);
+        assert_eq!(to.edge, 1);
+        assert!(network.shortest_path(from, to).is_none());
+
+        let empty = LineNetwork::<f64>::new(&MultiLineString(vec![]));
+        assert!(empty.locate(Coordinate { x: 0., y: 0. }).is_none());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{CoordFloat, Coordinate, LineString, Polygon};
+
+/// A series of circular arcs, each through three coordinates: its start, a point along it, and
+/// its end, which is the start of the next arc.
+///
+/// An arc whose three coordinates are collinear is a straight segment, and one which ends where
+/// it starts is a full circle, through the point along it, drawn counter-clockwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircularString<T: CoordFloat>(Vec<Coordinate<T>>);
+
+impl<T: CoordFloat> CircularString<T> {
+    /// Returns `None` unless there are an odd number of coordinates, at least three, or none.
+    pub fn new(coords: Vec<Coordinate<T>>) -> Option<Self> {
+        if !coords.is_empty() && (coords.len() < 3 || coords.len() % 2 != 1) {
+            return None;
+        }
+        Some(CircularString(coords))
+    }
+
+    /// The coordinates of the arcs.
+    pub fn coords(&self) -> &[Coordinate<T>] {
+        &self.0
+    }
+}
+
+/// A curve of straight segments, circular arcs, or a sequence of both, each of which starts
+/// where the last ends.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve<T: CoordFloat> {
+    LineString(LineString<T>),
+    CircularString(CircularString<T>),
+    Compound(Vec<Curve<T>>),
+}
+
+/// A polygon whose rings are closed [`Curve`]s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurvePolygon<T: CoordFloat> {
+    pub exterior: Curve<T>,
+    pub interiors: Vec<Curve<T>>,
+}
+
+/// Approximate circular arcs by straight segments.
+pub trait Linearize<T: CoordFloat> {
+    type Output;
+
+    /// Replace each arc by the fewest equal chords, with their ends on the arc, which lie within
+    /// `tolerance` of it. Straight segments are kept as they are.
+    ///
+    /// # Panics
+    ///
+    /// If `tolerance` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::linearize::{CircularString, Linearize};
+    /// use geo::Coordinate;
+    ///
+    /// // a semicircle of radius 10, over the top of the origin
+    /// let arc = CircularString::new(vec![
+    ///     Coordinate { x: 10_f64, y: 0. },
+    ///     Coordinate { x: 0., y: 10. },
+    ///     Coordinate { x: -10., y: 0. },
+    /// ])
+    /// .unwrap();
+    ///
+    /// let line = arc.linearize(0.1);
+    /// assert_eq!(line.0.len(), 13);
+    /// assert_eq!(line.0[12], Coordinate { x: -10., y: 0. });
+    /// // each vertex lies on the circle
+    /// assert!(line.0.iter().all(|c| (c.x.hypot(c.y) - 10.).abs() < 1e-9 && c.y >= 0.));
+    /// ```
+    fn linearize(&self, tolerance: T) -> Self::Output;
+}
+
+impl<T: CoordFloat> Linearize<T> for CircularString<T> {
+    type Output = LineString<T>;
+
+    fn linearize(&self, tolerance: T) -> LineString<T> {
+        assert!(
+            tolerance > T::zero(),
+            "linearize tolerance must be positive"
+        );
+        let mut coords = self.0.first().cloned().into_iter().collect();
+        for arc in (1..self.0.len()).step_by(2) {
+            linearize_arc(
+                self.0[arc - 1],
+                self.0[arc],
+                self.0[arc + 1],
+                tolerance,
+                &mut coords,
+            );
+        }
+        LineString(coords)
+    }
+}
+
+impl<T: CoordFloat> Linearize<T> for Curve<T> {
+    type Output = LineString<T>;
+
+    fn linearize(&self, tolerance: T) -> LineString<T> {
+        match self {
+            Curve::LineString(line) => line.clone(),
+            Curve::CircularString(arcs) => arcs.linearize(tolerance),
+            Curve::Compound(curves) => {
+                let mut coords: Vec<Coordinate<T>> = vec![];
+                for curve in curves {
+                    let line = curve.linearize(tolerance);
+                    // each curve starts where the last ends
+                    let skip = match (coords.last(), line.0.first()) {
+                        (Some(last), Some(first)) if last == first => 1,
+                        _ => 0,
+                    };
+                    coords.extend(line.0.into_iter().skip(skip));
+                }
+                LineString(coords)
+            }
+        }
+    }
+}
+
+impl<T: CoordFloat> Linearize<T> for CurvePolygon<T> {
+    type Output = Polygon<T>;
+
+    fn linearize(&self, tolerance: T) -> Polygon<T> {
+        Polygon::new(
+            self.exterior.linearize(tolerance),
+            self.interiors
+                .iter()
+                .map(|interior| interior.linearize(tolerance))
+                .collect(),
+        )
+    }
+}
+
+// Append the chords approximating the arc from `start` through `mid` to `end`, after `start`.
+fn linearize_arc<T: CoordFloat>(
+    start: Coordinate<T>,
+    mid: Coordinate<T>,
+    end: Coordinate<T>,
+    tolerance: T,
+    coords: &mut Vec<Coordinate<T>>,
+) {
+    let two = T::one() + T::one();
+    let tau = T::from(std::f64::consts::PI).unwrap() * two;
+    let (u, v) = (mid - start, end - start);
+    let cross = u.x * v.y - u.y * v.x;
+
+    let (center, sweep) = if start == end && start != mid {
+        ((start + mid) / two, tau)
+    } else if cross == T::zero() {
+        coords.push(end);
+        return;
+    } else {
+        // the circumcentre, relative to `start`
+        let (u2, v2) = (u.x * u.x + u.y * u.y, v.x * v.x + v.y * v.y);
+        let offset = Coordinate {
+            x: (v.y * u2 - u.y * v2) / (two * cross),
+            y: (u.x * v2 - v.x * u2) / (two * cross),
+        };
+        let center = start + offset;
+        let angle = |coord: Coordinate<T>| (coord.y - center.y).atan2(coord.x - center.x);
+        // counter-clockwise when `mid` is to the left of the chord
+        let mut sweep = angle(end) - angle(start);
+        if cross > T::zero() && sweep <= T::zero() {
+            sweep = sweep + tau;
+        } else if cross < T::zero() && sweep >= T::zero() {
+            sweep = sweep - tau;
+        }
+        (center, sweep)
+    };
+
+    let radius = (start.x - center.x).hypot(start.y - center.y);
+    // the widest angle whose chord lies within `tolerance` of the arc
+    let step = if tolerance >= radius {
+        T::from(std::f64::consts::PI).unwrap()
+    } else {
+        two * (T::one() - tolerance / radius).acos()
+    };
+    let chords = (sweep.abs() / step).ceil().max(T::one());
+    let first = (start.y - center.y).atan2(start.x - center.x);
+    let count = chords.to_usize().unwrap();
+    for chord in 1..count {
+        let angle = first + sweep * T::from(chord).unwrap() / chords;
+        coords.push(Coordinate {
+            x: center.x + radius * angle.cos(),
+            y: center.y + radius * angle.sin(),
+        });
+    }
+    coords.push(end);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::line_string;
+
+    fn coords(coords: &[(f64, f64)]) -> Vec<Coordinate<f64>> {
+        coords.iter().map(|coord| (*coord).into()).collect()
+    }
+
+    #[test]
+    fn clockwise_arcs_and_circles() {
+        // a quarter circle around (0, 1) drawn clockwise, then a straight segment
+        let arcs = CircularString::new(coords(&[
+            (-1., 1.),
+            (-0.5, 0.75_f64.sqrt() + 1.),
+            (0., 2.),
+            (1., 2.),
+            (2., 2.),
+        ]))
+        .unwrap();
+        let line = arcs.linearize(1e-3);
+        assert_eq!(line.0.last(), Some(&(2., 2.).into()));
+        for coord in &line.0[..line.0.len() - 1] {
+            assert!(((coord.x).hypot(coord.y - 1.) - 1.).abs() < 1e-9);
+            assert!(coord.x <= 0. && coord.y >= 1.);
+        }
+        // within the tolerance, the chords are exactly as long as they need to be
+        let chords = line.0.len() - 2;
+        let step = 2. * (1. - 1e-3_f64).acos();
+        assert_eq!(chords, (std::f64::consts::FRAC_PI_2 / step).ceil() as usize);
+
+        let circle = CircularString::new(coords(&[(1., 0.), (-1., 0.), (1., 0.)])).unwrap();
+        let polygon = Polygon::new(circle.linearize(1e-4), vec![]);
+        assert!((polygon.signed_area() - std::f64::consts::PI).abs() < 1e-3);
+
+        assert!(CircularString::new(coords(&[(1., 0.), (-1., 0.)])).is_none());
+    }
+
+    #[test]
+    fn compound_polygon() {
+        // a stadium: two semicircles joined by straight sides, with a square hole
+        let exterior = Curve::Compound(vec![
+            Curve::LineString(line_string![(x: 0., y: 0.), (x: 4., y: 0.)]),
+            Curve::CircularString(
+                CircularString::new(coords(&[(4., 0.), (5., 1.), (4., 2.)])).unwrap(),
+            ),
+            Curve::LineString(line_string![(x: 4., y: 2.), (x: 0., y: 2.)]),
+            Curve::CircularString(
+                CircularString::new(coords(&[(0., 2.), (-1., 1.), (0., 0.)])).unwrap(),
+            ),
+        ]);
+        let hole = Curve::LineString(
+            line_string![(x: 1., y: 0.5), (x: 2., y: 0.5), (x: 2., y: 1.5), (x: 1., y: 1.5), (x: 1., y: 0.5)],
+        );
+        let polygon = CurvePolygon {
+            exterior,
+            interiors: vec![hole],
+        }
+        .linearize(1e-6);
+        assert!(polygon.exterior().is_closed());
+        let area = 8. + std::f64::consts::PI - 1.;
+        assert!((polygon.unsigned_area() - area).abs() < 1e-4);
+        // no vertex is repeated where the curves join
+        let ring = &polygon.exterior().0;
+        assert!(ring.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+}
@@ -0,0 +1,118 @@
+//! The segments making up the linework of geometries, which the algorithms comparing geometries
+//! segment by segment work with.
+
+use crate::{
+    Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+// The sides of `rect`, anticlockwise from its minimum corner.
+pub(crate) fn rect_lines<F: GeoFloat>(rect: &Rect<F>) -> [Line<F>; 4] {
+    let (min, max) = (rect.min(), rect.max());
+    let corners = [
+        min,
+        Coordinate { x: max.x, y: min.y },
+        max,
+        Coordinate { x: min.x, y: max.y },
+    ];
+    [
+        Line::new(corners[0], corners[1]),
+        Line::new(corners[1], corners[2]),
+        Line::new(corners[2], corners[3]),
+        Line::new(corners[3], corners[0]),
+    ]
+}
+
+// The segments of `geometry`, with each point as a segment of no length.
+pub(crate) fn segments<F: GeoFloat, G: Segments<F>>(geometry: &G) -> Vec<Line<F>> {
+    let mut segments = Vec::new();
+    geometry.push_segments(&mut segments);
+    segments
+}
+
+// The linework of a geometry: the segments of its lines and of the rings of its areas, and its
+// points, as zero-length segments.
+pub(crate) trait Segments<F: GeoFloat> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>);
+}
+
+impl<F: GeoFloat> Segments<F> for Point<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        segments.push(Line::new(self.0, self.0));
+    }
+}
+
+impl<F: GeoFloat> Segments<F> for Line<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        segments.push(*self);
+    }
+}
+
+impl<F: GeoFloat> Segments<F> for LineString<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        if self.0.len() == 1 {
+            segments.push(Line::new(self.0[0], self.0[0]));
+        } else {
+            segments.extend(self.lines());
+        }
+    }
+}
+
+impl<F: GeoFloat> Segments<F> for Polygon<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        self.exterior().push_segments(segments);
+        for interior in self.interiors() {
+            interior.push_segments(segments);
+        }
+    }
+}
+
+impl<F: GeoFloat> Segments<F> for Rect<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        segments.extend(rect_lines(self).iter());
+    }
+}
+
+impl<F: GeoFloat> Segments<F> for Triangle<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        segments.extend(self.to_lines().iter());
+    }
+}
+
+macro_rules! impl_segments_for_collection {
+    ($($collection:ident),*) => {
+        $(
+            impl<F: GeoFloat> Segments<F> for $collection<F> {
+                fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+                    for geometry in self.iter() {
+                        geometry.push_segments(segments);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_segments_for_collection!(
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<F: GeoFloat> Segments<F> for Geometry<F> {
+    fn push_segments(&self, segments: &mut Vec<Line<F>>) {
+        match self {
+            Geometry::Point(g) => g.push_segments(segments),
+            Geometry::Line(g) => g.push_segments(segments),
+            Geometry::LineString(g) => g.push_segments(segments),
+            Geometry::Polygon(g) => g.push_segments(segments),
+            Geometry::MultiPoint(g) => g.push_segments(segments),
+            Geometry::MultiLineString(g) => g.push_segments(segments),
+            Geometry::MultiPolygon(g) => g.push_segments(segments),
+            Geometry::GeometryCollection(g) => g.push_segments(segments),
+            Geometry::Rect(g) => g.push_segments(segments),
+            Geometry::Triangle(g) => g.push_segments(segments),
+        }
+    }
+}
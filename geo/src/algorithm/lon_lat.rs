@@ -0,0 +1,248 @@
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::map_coords::MapCoords;
+use crate::{CoordFloat, Coordinate};
+use std::{error, fmt};
+
+/// Wrap a longitude, in degrees, into the range -180° to 180°, by whole turns.
+///
+/// Longitudes already in range, including 180° itself, are left as they are, and non-finite
+/// longitudes can't be wrapped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::lon_lat::wrap_longitude;
+///
+/// assert_eq!(wrap_longitude(190.), -170.);
+/// assert_eq!(wrap_longitude(-540.), -180.);
+/// assert_eq!(wrap_longitude(180.), 180.);
+/// ```
+pub fn wrap_longitude<T: CoordFloat>(lon: T) -> T {
+    let half_turn = T::from(180).unwrap();
+    if lon.abs() <= half_turn || !lon.is_finite() {
+        return lon;
+    }
+    let turn = half_turn + half_turn;
+    let mut wrapped = (lon + half_turn) % turn;
+    if wrapped < T::zero() {
+        wrapped = wrapped + turn;
+    }
+    wrapped - half_turn
+}
+
+/// Clamp a latitude, in degrees, to the range -90° to 90°.
+///
+/// A `NaN` latitude is left as it is.
+pub fn clamp_latitude<T: CoordFloat>(lat: T) -> T {
+    let quarter_turn = T::from(90).unwrap();
+    if lat.is_nan() {
+        return lat;
+    }
+    lat.max(-quarter_turn).min(quarter_turn)
+}
+
+/// What's wrong with a coordinate as a longitude and latitude.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LonLatProblem {
+    /// The longitude or latitude is `NaN` or infinite
+    NotFinite,
+    /// The longitude is beyond ±180°
+    LongitudeOutOfRange,
+    /// The latitude is beyond ±90°
+    LatitudeOutOfRange,
+}
+
+/// A coordinate of a geometry which isn't a valid longitude and latitude.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidLonLat<T: CoordFloat> {
+    /// The position of the coordinate among those of the geometry, in the order of
+    /// [`CoordsIter::coords_iter`]
+    pub index: usize,
+    pub coord: Coordinate<T>,
+    pub problem: LonLatProblem,
+}
+
+impl<T: CoordFloat> fmt::Display for InvalidLonLat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let problem = match self.problem {
+            LonLatProblem::NotFinite => "isn't finite",
+            LonLatProblem::LongitudeOutOfRange => "has a longitude beyond ±180°",
+            LonLatProblem::LatitudeOutOfRange => "has a latitude beyond ±90°",
+        };
+        write!(
+            f,
+            "coordinate {} ({:?}, {:?}) {}",
+            self.index, self.coord.x, self.coord.y, problem
+        )
+    }
+}
+
+impl<T: CoordFloat> error::Error for InvalidLonLat<T> {}
+
+/// Validate and normalize the coordinates of a geometry as longitudes and latitudes, in
+/// degrees, as a check before spherical and geodesic algorithms, which give `NaN`s, or
+/// nonsense, for coordinates out of range rather than failing.
+pub trait LonLat<T: CoordFloat> {
+    type Output;
+
+    /// Every coordinate of the geometry which isn't a valid longitude and latitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::lon_lat::{LonLat, LonLatProblem};
+    /// use geo::line_string;
+    ///
+    /// let line = line_string![(x: 10.75, y: 59.91), (x: 120.5, y: 31.2)];
+    /// assert!(line.invalid_lon_lats().is_empty());
+    ///
+    /// // with the longitude and latitude of its last coordinate swapped
+    /// let swapped = line_string![(x: 10.75, y: 59.91), (x: 31.2, y: 120.5)];
+    /// let invalid = swapped.invalid_lon_lats();
+    /// assert_eq!(invalid.len(), 1);
+    /// assert_eq!(invalid[0].index, 1);
+    /// assert_eq!(invalid[0].problem, LonLatProblem::LatitudeOutOfRange);
+    /// ```
+    fn invalid_lon_lats(&self) -> Vec<InvalidLonLat<T>>;
+
+    /// Check that every coordinate of the geometry is a valid longitude and latitude, failing
+    /// with the first which isn't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::lon_lat::LonLat;
+    /// use geo::point;
+    ///
+    /// assert!(point!(x: 10.75, y: 59.91).check_lon_lat().is_ok());
+    /// let error = point!(x: 190., y: 59.91).check_lon_lat().unwrap_err();
+    /// assert_eq!(error.to_string(), "coordinate 0 (190.0, 59.91) has a longitude beyond ±180°");
+    /// ```
+    fn check_lon_lat(&self) -> Result<(), InvalidLonLat<T>>;
+
+    /// The geometry with its longitudes wrapped into the range -180° to 180°, and its latitudes
+    /// clamped to -90° to 90°.
+    ///
+    /// Coordinates which aren't finite are left as they are. Wrapping the longitudes of a
+    /// geometry which crosses the antimeridian splits it across the map, so it may no longer be
+    /// valid in the plane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::lon_lat::LonLat;
+    /// use geo::line_string;
+    ///
+    /// let line = line_string![(x: 370., y: 95.), (x: -185., y: -10.)];
+    /// assert_eq!(
+    ///     line.normalize_lon_lat(),
+    ///     line_string![(x: 10., y: 90.), (x: 175., y: -10.)]
+    /// );
+    /// ```
+    fn normalize_lon_lat(&self) -> Self::Output;
+}
+
+impl<T, G> LonLat<T> for G
+where
+    T: CoordFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T> + MapCoords<T, T>,
+{
+    type Output = G::Output;
+
+    fn invalid_lon_lats(&self) -> Vec<InvalidLonLat<T>> {
+        self.coords_iter()
+            .enumerate()
+            .filter_map(|(index, coord)| {
+                problem(coord).map(|problem| InvalidLonLat {
+                    index,
+                    coord,
+                    problem,
+                })
+            })
+            .collect()
+    }
+
+    fn check_lon_lat(&self) -> Result<(), InvalidLonLat<T>> {
+        for (index, coord) in self.coords_iter().enumerate() {
+            if let Some(problem) = problem(coord) {
+                return Err(InvalidLonLat {
+                    index,
+                    coord,
+                    problem,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn normalize_lon_lat(&self) -> Self::Output {
+        self.map_coords(|&(lon, lat)| (wrap_longitude(lon), clamp_latitude(lat)))
+    }
+}
+
+fn problem<T: CoordFloat>(coord: Coordinate<T>) -> Option<LonLatProblem> {
+    if !coord.x.is_finite() || !coord.y.is_finite() {
+        Some(LonLatProblem::NotFinite)
+    } else if coord.x.abs() > T::from(180).unwrap() {
+        Some(LonLatProblem::LongitudeOutOfRange)
+    } else if coord.y.abs() > T::from(90).unwrap() {
+        Some(LonLatProblem::LatitudeOutOfRange)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Geometry, Point};
+
+    #[test]
+    fn wraps_and_clamps() {
+        assert_eq!(wrap_longitude(360.), 0.);
+        assert_eq!(wrap_longitude(-190.), 170.);
+        assert_eq!(wrap_longitude(-180.), -180.);
+        assert_eq!(wrap_longitude(725.5), 5.5);
+        assert!(wrap_longitude(f64::NAN).is_nan());
+        assert_eq!(wrap_longitude(f64::INFINITY), f64::INFINITY);
+        assert_eq!(clamp_latitude(-91.), -90.);
+        assert_eq!(clamp_latitude(45.), 45.);
+        assert!(clamp_latitude(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn reports_every_invalid_coordinate() {
+        let geometry: Geometry<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 200., y: 0.),
+            (x: f64::NAN, y: 10.),
+            (x: 0., y: -100.),
+        ]
+        .into();
+        let invalid = geometry.invalid_lon_lats();
+        let problems: Vec<(usize, LonLatProblem)> = invalid
+            .iter()
+            .map(|invalid| (invalid.index, invalid.problem))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (1, LonLatProblem::LongitudeOutOfRange),
+                (2, LonLatProblem::NotFinite),
+                (3, LonLatProblem::LatitudeOutOfRange),
+            ]
+        );
+        assert_eq!(geometry.check_lon_lat().unwrap_err().index, 1);
+
+        let normalized = geometry.normalize_lon_lat();
+        let invalid = normalized.invalid_lon_lats();
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].problem, LonLatProblem::NotFinite);
+        assert_eq!(
+            Point::new(-200., 0.).normalize_lon_lat(),
+            Point::new(160., 0.)
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use crate::algorithm::segment;
+use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString, Point};
+use rstar::{PointDistance, RTree, RTreeNum, RTreeObject, AABB};
+
+/// Where a point of a trace was matched onto the network.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchedPoint<T: GeoFloat> {
+    /// The index of the matched edge, a `LineString` of the network
+    pub line_string_index: usize,
+    /// The index of the matched segment of the edge
+    pub segment_index: usize,
+    /// The matched coordinate, on the edge
+    pub coord: Coordinate<T>,
+    /// The distance along the edge, from its start, to `coord`
+    pub offset: T,
+    /// The distance from the point of the trace to `coord`
+    pub distance: T,
+}
+
+/// Snap the points of a trace, such as a GPS track, to a network of lines, such as roads.
+///
+/// Each `LineString` of the network is an edge, and edges are connected where an end of one
+/// coincides with an end of another. Each point of the trace is matched to one of the edges
+/// within `search_radius` of it, found with an R*-tree, choosing the most likely sequence of
+/// edges with a hidden Markov model, as described by
+/// [Newson and Krumm](https://www.microsoft.com/en-us/research/publication/hidden-markov-map-matching-noise-sparseness/):
+///
+/// - a point is more likely to match an edge the closer it is, the distance being normally
+///   distributed with standard deviation `measurement_noise`
+/// - consecutive points are more likely to match where the distance between them is close to
+///   the distance along the network between their matches, the difference being exponentially
+///   distributed with mean `transition_scale`
+///
+/// So a point closer to another edge is still matched to the one the trace is following. The
+/// distance along the network is only known between points on the same edge, or on edges
+/// sharing an end; where the trace jumps further than this, its matching restarts.
+///
+/// Distances are Euclidean, so geographic coordinates should be projected first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::map_match::MapMatcher;
+/// use geo::{line_string, Coordinate, MultiLineString};
+///
+/// let network = MultiLineString(vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 10., y: 0.), (x: 10., y: 10.)],
+///     // a parallel road, close to the first
+///     line_string![(x: 0., y: 1.2), (x: 10., y: 1.2)],
+/// ]);
+/// let matcher = MapMatcher::new(&network, 2., 0.5, 1.);
+///
+/// let trace = line_string![(x: 1., y: 0.5), (x: 3., y: 0.4), (x: 5., y: 0.7), (x: 9.6, y: 2.5)];
+/// let matched = matcher.match_trace(&trace);
+///
+/// // the third point is closer to the parallel road, but the trace follows the first
+/// let edges: Vec<_> = matched.iter().map(|m| m.unwrap().line_string_index).collect();
+/// assert_eq!(edges, vec![0, 0, 0, 1]);
+/// assert_eq!(matched[2].unwrap().coord, Coordinate { x: 5., y: 0. });
+/// assert_eq!(matched[3].unwrap().offset, 2.5);
+/// ```
+pub struct MapMatcher<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    network: MultiLineString<T>,
+    // for each edge, the distance along it to the start of each segment, and then its length
+    offsets: Vec<Vec<T>>,
+    tree: RTree<IndexedSegment<T>>,
+    search_radius: T,
+    measurement_noise: T,
+    transition_scale: T,
+}
+
+impl<T> MapMatcher<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    /// Create a matcher for `network`.
+    ///
+    /// Points are only matched to edges within `search_radius`. `measurement_noise` is the
+    /// standard deviation of the distance from a point to its match, and `transition_scale` the
+    /// mean difference between the distance separating consecutive points and that separating
+    /// their matches along the network.
+    pub fn new(
+        network: &MultiLineString<T>,
+        search_radius: T,
+        measurement_noise: T,
+        transition_scale: T,
+    ) -> Self {
+        let mut offsets = Vec::with_capacity(network.0.len());
+        let mut segments = vec![];
+        for (line_string_index, line_string) in network.0.iter().enumerate() {
+            let mut offset = T::zero();
+            let mut edge_offsets = vec![offset];
+            for (segment_index, line) in line_string.lines().enumerate() {
+                offset = offset + length(line);
+                edge_offsets.push(offset);
+                segments.push(IndexedSegment {
+                    line,
+                    line_string_index,
+                    segment_index,
+                });
+            }
+            offsets.push(edge_offsets);
+        }
+        MapMatcher {
+            network: network.clone(),
+            offsets,
+            tree: RTree::bulk_load(segments),
+            search_radius,
+            measurement_noise,
+            transition_scale,
+        }
+    }
+
+    /// Match each point of `trace` to the network.
+    ///
+    /// The result has an entry for each point of `trace`, which is `None` if no edge is within
+    /// the search radius of the point.
+    pub fn match_trace(&self, trace: &LineString<T>) -> Vec<Option<MatchedPoint<T>>> {
+        let mut matched = vec![None; trace.0.len()];
+        // the points of the trace since matching last restarted, with their states
+        let mut chain: Vec<(usize, Vec<State<T>>)> = vec![];
+
+        for (point_index, coord) in trace.0.iter().enumerate() {
+            let candidates = self.candidates(*coord);
+            if candidates.is_empty() {
+                self.decode(&mut chain, &mut matched);
+                continue;
+            }
+
+            let mut layer = vec![];
+            if let Some((previous_index, previous)) = chain.last() {
+                let trace_distance = length(Line::new(trace.0[*previous_index], *coord));
+                for candidate in &candidates {
+                    let best = previous
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, state)| {
+                            let route_distance =
+                                self.route_distance(&state.candidate, candidate)?;
+                            let transition =
+                                -(trace_distance - route_distance).abs() / self.transition_scale;
+                            Some((state.score + transition, index))
+                        })
+                        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                    if let Some((score, previous)) = best {
+                        layer.push(State {
+                            candidate: *candidate,
+                            score: score + self.emission(candidate),
+                            previous,
+                        });
+                    }
+                }
+            }
+            if layer.is_empty() {
+                // no candidate can be reached from the previous point, so start a new chain
+                self.decode(&mut chain, &mut matched);
+                layer = candidates
+                    .into_iter()
+                    .map(|candidate| State {
+                        candidate,
+                        score: self.emission(&candidate),
+                        previous: 0,
+                    })
+                    .collect();
+            }
+            chain.push((point_index, layer));
+        }
+        self.decode(&mut chain, &mut matched);
+        matched
+    }
+
+    // The closest coordinate of each edge within the search radius of `coord`.
+    fn candidates(&self, coord: Coordinate<T>) -> Vec<MatchedPoint<T>> {
+        let mut candidates: Vec<MatchedPoint<T>> = vec![];
+        let square_radius = self.search_radius * self.search_radius;
+        for segment in self
+            .tree
+            .locate_within_distance(Point(coord), square_radius)
+        {
+            let closest = segment::closest_coord(segment.line, coord).coord;
+            let candidate = MatchedPoint {
+                line_string_index: segment.line_string_index,
+                segment_index: segment.segment_index,
+                coord: closest,
+                offset: self.offsets[segment.line_string_index][segment.segment_index]
+                    + length(Line::new(segment.line.start, closest)),
+                distance: length(Line::new(coord, closest)),
+            };
+            match candidates
+                .iter_mut()
+                .find(|other| other.line_string_index == candidate.line_string_index)
+            {
+                Some(other) if other.distance > candidate.distance => *other = candidate,
+                Some(_) => {}
+                None => candidates.push(candidate),
+            }
+        }
+        candidates.sort_by_key(|candidate| candidate.line_string_index);
+        candidates
+    }
+
+    fn emission(&self, candidate: &MatchedPoint<T>) -> T {
+        let normalized = candidate.distance / self.measurement_noise;
+        -normalized * normalized / (T::one() + T::one())
+    }
+
+    // The distance along the network between two matched coordinates, if they are on the same
+    // edge or on edges sharing an end.
+    fn route_distance(&self, from: &MatchedPoint<T>, to: &MatchedPoint<T>) -> Option<T> {
+        if from.line_string_index == to.line_string_index {
+            return Some((to.offset - from.offset).abs());
+        }
+        let from_ends = self.ends(from.line_string_index)?;
+        let to_ends = self.ends(to.line_string_index)?;
+        let mut route_distance: Option<T> = None;
+        for (from_coord, from_offset) in from_ends.iter() {
+            for (to_coord, to_offset) in to_ends.iter() {
+                if from_coord != to_coord {
+                    continue;
+                }
+                let distance = (*from_offset - from.offset).abs() + (to.offset - *to_offset).abs();
+                route_distance = Some(match route_distance {
+                    Some(shortest) => shortest.min(distance),
+                    None => distance,
+                });
+            }
+        }
+        route_distance
+    }
+
+    // The ends of an edge, with their distance along it.
+    fn ends(&self, line_string_index: usize) -> Option<[(Coordinate<T>, T); 2]> {
+        let line_string = &self.network.0[line_string_index];
+        let first = *line_string.0.first()?;
+        let last = *line_string.0.last()?;
+        let length = *self.offsets[line_string_index].last()?;
+        Some([(first, T::zero()), (last, length)])
+    }
+
+    // Match the points of `chain` to the candidates on its most likely path, and clear it.
+    fn decode(
+        &self,
+        chain: &mut Vec<(usize, Vec<State<T>>)>,
+        matched: &mut [Option<MatchedPoint<T>>],
+    ) {
+        let last = match chain.last() {
+            Some((_, layer)) => layer,
+            None => return,
+        };
+        let mut index = last
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.score.partial_cmp(&b.1.score).unwrap())
+            .map(|(index, _)| index)
+            .expect("every layer of a chain has a candidate");
+        for (point_index, layer) in chain.iter().rev() {
+            let state = &layer[index];
+            matched[*point_index] = Some(state.candidate);
+            index = state.previous;
+        }
+        chain.clear();
+    }
+}
+
+// A candidate match of a point of the trace, with the log-probability of the most likely path
+// ending at it, and the index of the previous point's candidate on that path.
+struct State<T: GeoFloat> {
+    candidate: MatchedPoint<T>,
+    score: T,
+    previous: usize,
+}
+
+fn length<T: GeoFloat>(line: Line<T>) -> T {
+    line.dx().hypot(line.dy())
+}
+
+// A segment of an edge of the network, as stored in the R*-tree.
+struct IndexedSegment<T: GeoFloat> {
+    line: Line<T>,
+    line_string_index: usize,
+    segment_index: usize,
+}
+
+impl<T> RTreeObject for IndexedSegment<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.line.envelope()
+    }
+}
+
+impl<T> PointDistance for IndexedSegment<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    fn distance_2(&self, point: &Point<T>) -> T {
+        segment::coord_square_distance(self.line, point.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn edges(matched: &[Option<MatchedPoint<f64>>]) -> Vec<Option<usize>> {
+        matched
+            .iter()
+            .map(|m| m.map(|m| m.line_string_index))
+            .collect()
+    }
+
+    #[test]
+    fn along_a_single_edge() {
+        let network = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)],
+        ]);
+        let matcher = MapMatcher::new(&network, 1., 0.5, 1.);
+        let trace = line_string![(x: 1., y: 0.2), (x: 4., y: -0.3), (x: 5.3, y: 2.)];
+        let matched: Vec<MatchedPoint<f64>> = matcher
+            .match_trace(&trace)
+            .into_iter()
+            .map(Option::unwrap)
+            .collect();
+        assert_eq!(
+            matched
+                .iter()
+                .map(|m| (m.coord, m.segment_index, m.offset))
+                .collect::<Vec<_>>(),
+            vec![
+                (Coordinate { x: 1., y: 0. }, 0, 1.),
+                (Coordinate { x: 4., y: 0. }, 0, 4.),
+                (Coordinate { x: 5., y: 2. }, 1, 7.),
+            ]
+        );
+        assert_relative_eq!(matched[1].distance, 0.3);
+    }
+
+    #[test]
+    fn follows_connected_edges() {
+        let network = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            // connected to the end of the first edge, but running backwards
+            line_string![(x: 10., y: 10.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 1.2), (x: 10., y: 1.2)],
+            line_string![(x: 10.8, y: 0.), (x: 10.8, y: 10.)],
+        ]);
+        let matcher = MapMatcher::new(&network, 2., 0.5, 1.);
+        let trace = line_string![
+            (x: 1., y: 0.5),
+            (x: 3., y: 0.4),
+            (x: 5., y: 0.7),
+            (x: 10.5, y: 3.),
+            (x: 10.5, y: 6.),
+        ];
+        let matched = matcher.match_trace(&trace);
+        assert_eq!(
+            edges(&matched),
+            vec![Some(0), Some(0), Some(0), Some(1), Some(1)]
+        );
+        assert_eq!(matched[3].unwrap().offset, 7.);
+        assert_eq!(matched[4].unwrap().offset, 4.);
+    }
+
+    #[test]
+    fn restarts_after_a_gap() {
+        let network = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 20.), (x: 10., y: 20.)],
+        ]);
+        let matcher = MapMatcher::new(&network, 1., 0.5, 1.);
+        let trace = line_string![
+            (x: 1., y: 0.5),
+            (x: 2., y: 10.),
+            (x: 3., y: 19.5),
+            (x: 4., y: 19.9),
+        ];
+        assert_eq!(
+            edges(&matcher.match_trace(&trace)),
+            vec![Some(0), None, Some(1), Some(1)]
+        );
+        // disconnected edges, with no point in between
+        let trace = line_string![(x: 1., y: 0.5), (x: 3., y: 19.5)];
+        assert_eq!(edges(&matcher.match_trace(&trace)), vec![Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn empty() {
+        let matcher = MapMatcher::new(&MultiLineString(vec![]), 1., 0.5, 1.);
+        assert_eq!(
+            edges(&matcher.match_trace(&line_string![(x: 1., y: 0.5)])),
+            vec![None]
+        );
+        let network = MultiLineString(vec![line_string![(x: 0., y: 0.), (x: 10., y: 0.)]]);
+        let matcher = MapMatcher::new(&network, 1., 0.5, 1.);
+        assert!(matcher.match_trace(&LineString(vec![])).is_empty());
+    }
+}
@@ -0,0 +1,378 @@
+use crate::algorithm::simplify::SimplifyIdx;
+use crate::utils::lerp;
+use crate::{CoordFloat, Coordinate, GeoFloat, LineString, MultiPoint, Point};
+
+/// A `LineString` with a measure, or M value, at each of its vertices, as used by linear
+/// referencing systems to locate events, such as the mileposts of a road, along a line.
+///
+/// The measure is interpolated linearly along each segment. It need not increase along the
+/// line, so a measure may be reached at several places.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasuredLineString<T: CoordFloat> {
+    line: LineString<T>,
+    measures: Vec<T>,
+}
+
+impl<T: CoordFloat> MeasuredLineString<T> {
+    /// Measure the vertices of `line` with `measures`, one for each vertex.
+    ///
+    /// Returns `None` if there are more or fewer measures than vertices.
+    pub fn new(line: LineString<T>, measures: Vec<T>) -> Option<Self> {
+        if line.0.len() != measures.len() {
+            return None;
+        }
+        Some(MeasuredLineString { line, measures })
+    }
+
+    /// Measure the vertices of `line` by their Euclidean distance along it from its start.
+    pub fn from_lengths(line: LineString<T>) -> Self {
+        let mut measures = Vec::with_capacity(line.0.len());
+        let mut length = T::zero();
+        for (index, coord) in line.0.iter().enumerate() {
+            if index > 0 {
+                let delta = *coord - line.0[index - 1];
+                length = length + delta.x.hypot(delta.y);
+            }
+            measures.push(length);
+        }
+        MeasuredLineString { line, measures }
+    }
+
+    /// The line, without its measures.
+    pub fn line(&self) -> &LineString<T> {
+        &self.line
+    }
+
+    /// The measures of the vertices of the line.
+    pub fn measures(&self) -> &[T] {
+        &self.measures
+    }
+
+    /// The points of the line at which the measure is `measure`.
+    ///
+    /// A stretch of the line over which the measure is constant contributes its vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::measured_line_string::MeasuredLineString;
+    /// use geo::{line_string, point, MultiPoint};
+    ///
+    /// // a road which doubles back, measured in kilometres
+    /// let road = MeasuredLineString::new(
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+    ///     vec![0., 10., 0.],
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     road.locate_along(4.),
+    ///     MultiPoint(vec![point!(x: 4., y: 0.), point!(x: 10., y: 6.)])
+    /// );
+    /// assert_eq!(road.locate_along(11.), MultiPoint(vec![]));
+    /// ```
+    pub fn locate_along(&self, measure: T) -> MultiPoint<T> {
+        let coords = &self.line.0;
+        let mut points = vec![];
+        for (index, coord) in coords.iter().enumerate() {
+            if self.measures[index] == measure {
+                points.push(Point(*coord));
+            }
+            if index + 1 == coords.len() {
+                break;
+            }
+            let (start, end) = (self.measures[index], self.measures[index + 1]);
+            if (start < measure && measure < end) || (end < measure && measure < start) {
+                let fraction = (measure - start) / (end - start);
+                points.push(Point(lerp(*coord, coords[index + 1], fraction)));
+            }
+        }
+        points.into()
+    }
+
+    /// The parts of the line over which the measure is between `from` and `to`, inclusive,
+    /// with their measures.
+    ///
+    /// This splits the line where the measure crosses either bound, carrying the measures at
+    /// the splits over to both sides. Places where the line only touches the range, at a single
+    /// point, are left out; [`locate_along`](Self::locate_along) finds those.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::measured_line_string::MeasuredLineString;
+    /// use geo::line_string;
+    ///
+    /// let road = MeasuredLineString::from_lengths(
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)],
+    /// );
+    /// let roadworks = road.locate_between(8., 15.);
+    /// assert_eq!(roadworks.len(), 1);
+    /// assert_eq!(
+    ///     roadworks[0].line(),
+    ///     &line_string![(x: 8., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.)]
+    /// );
+    /// assert_eq!(roadworks[0].measures(), &[8., 10., 15.]);
+    /// ```
+    pub fn locate_between(&self, from: T, to: T) -> Vec<Self> {
+        let (low, high) = if from <= to { (from, to) } else { (to, from) };
+        let coords = &self.line.0;
+        let mut parts = vec![];
+        let mut part: Vec<(Coordinate<T>, T)> = vec![];
+        // whether `part` runs up to the end of the previous segment
+        let mut open = false;
+        for index in 1..coords.len() {
+            let (start, end) = (self.measures[index - 1], self.measures[index]);
+            // the fractions of the segment, in order, between which the measure is in range
+            let range = if start == end {
+                if low <= start && start <= high {
+                    Some((T::zero(), T::one()))
+                } else {
+                    None
+                }
+            } else {
+                let (a, b) = (
+                    (low - start) / (end - start),
+                    (high - start) / (end - start),
+                );
+                let (a, b) = if a <= b { (a, b) } else { (b, a) };
+                let (a, b) = (a.max(T::zero()), b.min(T::one()));
+                if a <= b {
+                    Some((a, b))
+                } else {
+                    None
+                }
+            };
+            let (a, b) = match range {
+                Some(range) => range,
+                None => {
+                    flush(&mut part, &mut parts);
+                    open = false;
+                    continue;
+                }
+            };
+            if !(open && a == T::zero()) {
+                flush(&mut part, &mut parts);
+            }
+            let (segment_start, segment_end) = (coords[index - 1], coords[index]);
+            for &fraction in &[a, b] {
+                let vertex = (
+                    lerp(segment_start, segment_end, fraction),
+                    start + (end - start) * fraction,
+                );
+                if part.last() != Some(&vertex) {
+                    part.push(vertex);
+                }
+            }
+            open = b == T::one();
+        }
+        flush(&mut part, &mut parts);
+        parts
+    }
+
+    /// Simplify the line with the Ramer–Douglas–Peucker algorithm, as
+    /// [`Simplify`](crate::algorithm::simplify::Simplify) does, keeping the measures of the
+    /// vertices which remain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::measured_line_string::MeasuredLineString;
+    /// use geo::line_string;
+    ///
+    /// let track = MeasuredLineString::new(
+    ///     line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)],
+    ///     vec![100., 105., 110.],
+    /// )
+    /// .unwrap();
+    /// let simplified = track.simplify(&1.);
+    /// assert_eq!(simplified.line(), &line_string![(x: 0., y: 0.), (x: 10., y: 0.)]);
+    /// assert_eq!(simplified.measures(), &[100., 110.]);
+    /// ```
+    pub fn simplify(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat,
+    {
+        let indices = self.line.simplify_idx(epsilon);
+        MeasuredLineString {
+            line: indices.iter().map(|index| self.line.0[*index]).collect(),
+            measures: indices.iter().map(|index| self.measures[*index]).collect(),
+        }
+    }
+
+    /// Resample the line at every `interval` of the measure from its first, and at its last,
+    /// interpolating the location at each linearly along the segment it falls in.
+    ///
+    /// With timestamps as the measures, this resamples a track at fixed intervals of time, so
+    /// tracks recorded at different rates can be compared point for point. The measures should
+    /// not decrease along the line, as timestamps don't. Where the measure stays the same along
+    /// a stretch of the line, the first location at that measure is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::measured_line_string::MeasuredLineString;
+    /// use geo::line_string;
+    ///
+    /// // a track with a fix at 0 seconds, then at 5 and 6 seconds
+    /// let track = MeasuredLineString::new(
+    ///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.)],
+    ///     vec![0., 5., 6.],
+    /// )
+    /// .unwrap();
+    /// let every_two_seconds = track.resample_by_measure(2.);
+    /// assert_eq!(
+    ///     every_two_seconds.line(),
+    ///     &line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 8., y: 0.), (x: 10., y: 4.)]
+    /// );
+    /// assert_eq!(every_two_seconds.measures(), &[0., 2., 4., 6.]);
+    /// ```
+    pub fn resample_by_measure(&self, interval: T) -> Self {
+        assert!(interval > T::zero(), "interval must be positive");
+        let coords = &self.line.0;
+        let (first, last) = match (self.measures.first(), self.measures.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return self.clone(),
+        };
+
+        let mut line = vec![];
+        let mut measures = vec![];
+        let mut index = 0;
+        let mut count = 0;
+        loop {
+            let measure = first + interval * T::from(count).unwrap();
+            if measure >= last {
+                break;
+            }
+            // the first segment which reaches the measure
+            while index + 2 < coords.len() && self.measures[index + 1] < measure {
+                index += 1;
+            }
+            let (start, end) = (self.measures[index], self.measures[index + 1]);
+            let fraction = if start == end {
+                T::zero()
+            } else {
+                ((measure - start) / (end - start))
+                    .max(T::zero())
+                    .min(T::one())
+            };
+            line.push(lerp(coords[index], coords[index + 1], fraction));
+            measures.push(measure);
+            count += 1;
+        }
+        line.push(coords[coords.len() - 1]);
+        measures.push(last);
+
+        MeasuredLineString {
+            line: line.into(),
+            measures,
+        }
+    }
+}
+
+// Move a part of a line into `parts`, unless it has collapsed to a point.
+fn flush<T: CoordFloat>(
+    part: &mut Vec<(Coordinate<T>, T)>,
+    parts: &mut Vec<MeasuredLineString<T>>,
+) {
+    if part.len() >= 2 {
+        parts.push(MeasuredLineString {
+            line: part.iter().map(|(coord, _)| *coord).collect(),
+            measures: part.iter().map(|(_, measure)| *measure).collect(),
+        });
+    }
+    part.clear();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn locate_between_splits_where_the_measure_turns() {
+        // the measure rises to 10, falls to 2 and rises again to 6
+        let line = MeasuredLineString::new(
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 8.), (x: 14., y: 8.)],
+            vec![0., 10., 2., 6.],
+        )
+        .unwrap();
+        let parts = line.locate_between(7., 4.);
+        let lines: Vec<_> = parts.iter().map(|part| part.line().clone()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                line_string![(x: 4., y: 0.), (x: 7., y: 0.)],
+                line_string![(x: 10., y: 3.), (x: 10., y: 6.)],
+                line_string![(x: 12., y: 8.), (x: 14., y: 8.)],
+            ]
+        );
+        assert_eq!(parts[2].measures(), &[4., 6.]);
+
+        // touching the range at the turn only gives a point
+        assert!(line.locate_between(10., 12.).is_empty());
+        assert_eq!(line.locate_along(10.), point!(x: 10., y: 0.).into());
+    }
+
+    #[test]
+    fn constant_measures() {
+        let line = MeasuredLineString::new(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)],
+            vec![0., 5., 5., 9.],
+        )
+        .unwrap();
+        assert_eq!(
+            line.locate_along(5.),
+            vec![point!(x: 1., y: 0.), point!(x: 2., y: 0.)].into()
+        );
+        let parts = line.locate_between(0., 5.);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].measures(), &[0., 5., 5.]);
+
+        assert!(MeasuredLineString::new(line_string![(x: 0., y: 0.)], vec![]).is_none());
+        let empty = MeasuredLineString::<f64>::from_lengths(LineString(vec![]));
+        assert!(empty.locate_between(0., 1.).is_empty());
+        assert_eq!(empty.locate_along(0.), MultiPoint(vec![]));
+    }
+
+    #[test]
+    fn resample_by_measure() {
+        let track = MeasuredLineString::new(
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+            vec![10., 14., 16., 18.],
+        )
+        .unwrap();
+        // the track stops for two seconds
+        let resampled = track.resample_by_measure(1.5);
+        assert_eq!(
+            resampled.measures(),
+            &[10., 11.5, 13., 14.5, 16., 17.5, 18.]
+        );
+        assert_eq!(
+            resampled.line(),
+            &line_string![
+                (x: 0., y: 0.),
+                (x: 1.5, y: 0.),
+                (x: 3., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 0.),
+                (x: 4., y: 3.),
+                (x: 4., y: 4.),
+            ]
+        );
+
+        let fix = MeasuredLineString::new(line_string![(x: 1., y: 1.)], vec![5.]).unwrap();
+        assert_eq!(fix.resample_by_measure(1.), fix);
+        // a line with no extent in measure collapses to its end
+        let stopped =
+            MeasuredLineString::new(line_string![(x: 0., y: 0.), (x: 1., y: 1.)], vec![5., 5.])
+                .unwrap();
+        assert_eq!(
+            stopped.resample_by_measure(1.).line(),
+            &line_string![(x: 1., y: 1.)]
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use crate::algorithm::transform::CoordTransform;
+use crate::{GeoFloat, Point};
+use std::error::Error;
+
+/// The radius, in metres, of the sphere which the Web Mercator projection treats the earth as:
+/// the equatorial radius of WGS 84.
+const EARTH_RADIUS: f64 = 6_378_137.;
+
+/// The flattening of the WGS 84 ellipsoid.
+const FLATTENING: f64 = 1. / 298.257_223_563;
+
+/// The latitude, in degrees, at which the Web Mercator projection is usually cut off, making the
+/// projected world, and the tiles of slippy maps, square.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Project a coordinate, in degrees of longitude and latitude, onto the Web Mercator plane
+/// (EPSG:3857), in metres.
+///
+/// Latitudes beyond [`MAX_LATITUDE`] are clamped to it. The signature suits
+/// [`MapCoords`](crate::algorithm::map_coords::MapCoords), so a whole geometry is projected with
+/// `geometry.map_coords(to_web_mercator)`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::map_coords::MapCoords;
+/// use geo::algorithm::mercator::{from_web_mercator, to_web_mercator};
+/// use geo::line_string;
+///
+/// let line = line_string![(x: 0_f64, y: 0.), (x: 180., y: 0.)];
+/// let projected = line.map_coords(to_web_mercator);
+/// assert_eq!(projected.0[1].x, 20_037_508.342_789_244);
+///
+/// let unprojected = projected.map_coords(from_web_mercator);
+/// assert!((unprojected.0[1].x - 180.).abs() < 1e-9);
+/// ```
+pub fn to_web_mercator<T: GeoFloat>(&(lon, lat): &(T, T)) -> (T, T) {
+    let radius = T::from(EARTH_RADIUS).unwrap();
+    let max_latitude = T::from(MAX_LATITUDE).unwrap();
+    let lat = lat.max(-max_latitude).min(max_latitude).to_radians();
+    let quarter_turn = T::from(std::f64::consts::FRAC_PI_4).unwrap();
+    (
+        radius * lon.to_radians(),
+        radius * (quarter_turn + lat / (T::one() + T::one())).tan().ln(),
+    )
+}
+
+/// Unproject a coordinate on the Web Mercator plane (EPSG:3857), in metres, to degrees of
+/// longitude and latitude.
+///
+/// The inverse of [`to_web_mercator`].
+pub fn from_web_mercator<T: GeoFloat>(&(x, y): &(T, T)) -> (T, T) {
+    let radius = T::from(EARTH_RADIUS).unwrap();
+    (
+        (x / radius).to_degrees(),
+        (y / radius).sinh().atan().to_degrees(),
+    )
+}
+
+/// The Web Mercator projection, as a [`CoordTransform`] from longitude and latitude, which
+/// never fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WebMercator;
+
+impl<T: GeoFloat> CoordTransform<T> for WebMercator {
+    fn forward(&self, coord: &(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>> {
+        Ok(to_web_mercator(coord))
+    }
+
+    fn inverse(&self, coord: &(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>> {
+        Ok(from_web_mercator(coord))
+    }
+}
+
+/// A zone of the Universal Transverse Mercator (UTM) system, in the northern or southern
+/// hemisphere.
+///
+/// Each of the sixty zones is six degrees of longitude wide, and is projected with a transverse
+/// Mercator projection of the WGS 84 ellipsoid along its central meridian, scaled by 0.9996.
+/// Eastings are offset by 500 km, and in the southern hemisphere, northings by 10,000 km, so
+/// that both are positive.
+///
+/// The projection uses Krüger's series to the fourth order in the third flattening, which is
+/// accurate to well under a millimetre within the zone, and stays accurate several zones beyond
+/// it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::map_coords::MapCoords;
+/// use geo::algorithm::mercator::UtmZone;
+/// use geo::point;
+///
+/// let eiffel_tower = point!(x: 2.2945_f64, y: 48.8583);
+/// let zone = UtmZone::containing(eiffel_tower);
+/// assert_eq!(zone, UtmZone::new(31, true).unwrap());
+///
+/// let projected = eiffel_tower.map_coords(|coord| zone.forward(coord));
+/// assert!(projected.x() > 448_000. && projected.x() < 448_500.);
+/// assert!(projected.y() > 5_411_500. && projected.y() < 5_412_500.);
+///
+/// let unprojected = projected.map_coords(|coord| zone.inverse(coord));
+/// assert!((unprojected.x() - eiffel_tower.x()).abs() < 1e-9);
+/// assert!((unprojected.y() - eiffel_tower.y()).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UtmZone {
+    number: u8,
+    north: bool,
+}
+
+impl UtmZone {
+    /// The zone with `number`, from 1 to 60, in the northern hemisphere if `north`.
+    pub fn new(number: u8, north: bool) -> Option<Self> {
+        if (1..=60).contains(&number) {
+            Some(UtmZone { number, north })
+        } else {
+            None
+        }
+    }
+
+    /// The zone containing a point, in degrees of longitude and latitude, including the wider
+    /// zones of south-western Norway and of Svalbard.
+    pub fn containing<T: GeoFloat>(point: Point<T>) -> Self {
+        let lon = point.x().to_f64().unwrap();
+        let lat = point.y().to_f64().unwrap();
+        let mut number = (((lon + 180.) / 6.).floor() as i64).clamp(0, 59) as u8 + 1;
+        if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lon) {
+            number = 32;
+        } else if (72. ..84.).contains(&lat) && (0. ..42.).contains(&lon) {
+            number = if lon < 9. {
+                31
+            } else if lon < 21. {
+                33
+            } else if lon < 33. {
+                35
+            } else {
+                37
+            };
+        }
+        UtmZone {
+            number,
+            north: lat >= 0.,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+
+    /// The longitude of the zone's central meridian, in degrees.
+    pub fn central_meridian<T: GeoFloat>(&self) -> T {
+        T::from(i32::from(self.number) * 6 - 183).unwrap()
+    }
+
+    /// Project a coordinate, in degrees of longitude and latitude, onto the zone's plane, as an
+    /// easting and a northing in metres.
+    pub fn forward<T: GeoFloat>(&self, &(lon, lat): &(T, T)) -> (T, T) {
+        let series = KruegerSeries::<T>::new();
+        let lat = lat.to_radians();
+        let lon = (lon - self.central_meridian()).to_radians();
+
+        let two = T::one() + T::one();
+        let e = two * series.n.sqrt() / (T::one() + series.n);
+        let sin_lat = lat.sin();
+        let t = (sin_lat.atanh() - e * (e * sin_lat).atanh()).sinh();
+        let xi_prime = t.atan2(lon.cos());
+        let eta_prime = (lon.sin() / (T::one() + t * t).sqrt()).atanh();
+
+        let mut xi = xi_prime;
+        let mut eta = eta_prime;
+        for (j, alpha) in series.alpha.iter().enumerate() {
+            let k = two * T::from(j + 1).unwrap();
+            xi = xi + *alpha * (k * xi_prime).sin() * (k * eta_prime).cosh();
+            eta = eta + *alpha * (k * xi_prime).cos() * (k * eta_prime).sinh();
+        }
+        let scale = series.scale();
+        (
+            T::from(FALSE_EASTING).unwrap() + scale * eta,
+            self.false_northing::<T>() + scale * xi,
+        )
+    }
+
+    /// Unproject an easting and a northing, in metres, on the zone's plane, to degrees of
+    /// longitude and latitude.
+    ///
+    /// The inverse of [`forward`](Self::forward).
+    pub fn inverse<T: GeoFloat>(&self, &(easting, northing): &(T, T)) -> (T, T) {
+        let series = KruegerSeries::<T>::new();
+        let scale = series.scale();
+        let xi = (northing - self.false_northing()) / scale;
+        let eta = (easting - T::from(FALSE_EASTING).unwrap()) / scale;
+
+        let two = T::one() + T::one();
+        let mut xi_prime = xi;
+        let mut eta_prime = eta;
+        for (j, beta) in series.beta.iter().enumerate() {
+            let k = two * T::from(j + 1).unwrap();
+            xi_prime = xi_prime - *beta * (k * xi).sin() * (k * eta).cosh();
+            eta_prime = eta_prime - *beta * (k * xi).cos() * (k * eta).sinh();
+        }
+        let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+        let mut lat = chi;
+        for (j, delta) in series.delta.iter().enumerate() {
+            let k = two * T::from(j + 1).unwrap();
+            lat = lat + *delta * (k * chi).sin();
+        }
+        let lon = eta_prime.sinh().atan2(xi_prime.cos());
+        (
+            self.central_meridian::<T>() + lon.to_degrees(),
+            lat.to_degrees(),
+        )
+    }
+
+    fn false_northing<T: GeoFloat>(&self) -> T {
+        if self.north {
+            T::zero()
+        } else {
+            T::from(10_000_000.).unwrap()
+        }
+    }
+}
+
+impl<T: GeoFloat> CoordTransform<T> for UtmZone {
+    fn forward(&self, coord: &(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>> {
+        Ok(UtmZone::forward(self, coord))
+    }
+
+    fn inverse(&self, coord: &(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>> {
+        Ok(UtmZone::inverse(self, coord))
+    }
+}
+
+const FALSE_EASTING: f64 = 500_000.;
+
+const SCALE_FACTOR: f64 = 0.9996;
+
+// The coefficients of Krüger's series for the transverse Mercator projection of the WGS 84
+// ellipsoid, in powers of its third flattening `n`.
+struct KruegerSeries<T: GeoFloat> {
+    n: T,
+    // the radius of the circle with the same circumference as a meridian
+    rectifying_radius: T,
+    alpha: [T; 4],
+    beta: [T; 4],
+    delta: [T; 4],
+}
+
+impl<T: GeoFloat> KruegerSeries<T> {
+    fn new() -> Self {
+        let n = FLATTENING / (2. - FLATTENING);
+        let (n2, n3, n4) = (n * n, n * n * n, n * n * n * n);
+        let coefficients = |values: [f64; 4]| {
+            [
+                T::from(values[0]).unwrap(),
+                T::from(values[1]).unwrap(),
+                T::from(values[2]).unwrap(),
+                T::from(values[3]).unwrap(),
+            ]
+        };
+        KruegerSeries {
+            n: T::from(n).unwrap(),
+            rectifying_radius: T::from(EARTH_RADIUS / (1. + n) * (1. + n2 / 4. + n4 / 64.))
+                .unwrap(),
+            alpha: coefficients([
+                n / 2. - 2. * n2 / 3. + 5. * n3 / 16. + 41. * n4 / 180.,
+                13. * n2 / 48. - 3. * n3 / 5. + 557. * n4 / 1440.,
+                61. * n3 / 240. - 103. * n4 / 140.,
+                49561. * n4 / 161_280.,
+            ]),
+            beta: coefficients([
+                n / 2. - 2. * n2 / 3. + 37. * n3 / 96. - n4 / 360.,
+                n2 / 48. + n3 / 15. - 437. * n4 / 1440.,
+                17. * n3 / 480. - 37. * n4 / 840.,
+                4397. * n4 / 161_280.,
+            ]),
+            delta: coefficients([
+                2. * n - 2. * n2 / 3. - 2. * n3 + 116. * n4 / 45.,
+                7. * n2 / 3. - 8. * n3 / 5. - 227. * n4 / 45.,
+                56. * n3 / 15. - 136. * n4 / 35.,
+                4279. * n4 / 630.,
+            ]),
+        }
+    }
+
+    fn scale(&self) -> T {
+        T::from(SCALE_FACTOR).unwrap() * self.rectifying_radius
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn web_mercator_corners() {
+        let (x, y) = to_web_mercator(&(-180., 90.));
+        assert_relative_eq!(x, -20_037_508.342_789_244);
+        assert_relative_eq!(y, 20_037_508.342_789_244, epsilon = 1e-6);
+        let (lon, lat) = from_web_mercator(&(x, y));
+        assert_relative_eq!(lon, -180.);
+        assert_relative_eq!(lat, MAX_LATITUDE, epsilon = 1e-12);
+        let (x, y) = to_web_mercator(&(0., 0.));
+        assert_eq!(x, 0.);
+        assert_relative_eq!(y, 0., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn utm_meridian_and_round_trips() {
+        let zone = UtmZone::new(33, true).unwrap();
+        assert_eq!(zone.central_meridian::<f64>(), 15.);
+        // along the central meridian, the northing is the scaled length of the meridian, a
+        // quarter of which is 10,001,965.729 m
+        let (easting, northing) = zone.forward(&(15., 90.));
+        assert_relative_eq!(easting, 500_000., epsilon = 1e-6);
+        assert_relative_eq!(northing, 0.9996 * 10_001_965.729, epsilon = 1e-3);
+        assert_eq!(zone.forward(&(15., 0.)), (500_000., 0.));
+
+        let south = UtmZone::new(33, false).unwrap();
+        for lon in &[12., 13.5, 15., 16.2, 18.] {
+            for lat in &[-80., -33.3, -0.5, 0., 12., 60., 84.] {
+                let zone = if *lat < 0. { south } else { zone };
+                let (easting, northing) = zone.forward(&(*lon, *lat));
+                assert!(easting > 160_000. && easting < 840_000.);
+                assert!((0. ..10_000_000.).contains(&northing));
+                let (lon_again, lat_again) = zone.inverse(&(easting, northing));
+                assert_relative_eq!(lon_again, *lon, epsilon = 1e-10);
+                assert_relative_eq!(lat_again, *lat, epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn zones_containing_points() {
+        let zone = |lon: f64, lat: f64| {
+            let zone = UtmZone::containing(point!(x: lon, y: lat));
+            (zone.number(), zone.is_north())
+        };
+        assert_eq!(zone(-180., 10.), (1, true));
+        assert_eq!(zone(180., -10.), (60, false));
+        assert_eq!(zone(-74.0, 40.7), (18, true));
+        // Bergen, in the widened zone 32
+        assert_eq!(zone(5.32, 60.39), (32, true));
+        // Longyearbyen, on Svalbard
+        assert_eq!(zone(15.63, 78.22), (33, true));
+        assert_eq!(UtmZone::new(0, true), None);
+        assert_eq!(UtmZone::new(61, true), None);
+    }
+}
@@ -1,88 +1,325 @@
 /// Kernels to compute various predicates
 pub mod kernels;
 
+/// Find which of a set of polygons share edges or touch at points.
+#[cfg(feature = "std")]
+pub mod adjacency;
+/// Find the alpha shapes of a set of points, and the alphas at which they change.
+#[cfg(feature = "std")]
+pub mod alpha_shape;
+/// Calculate the interior angles of polygons and the turn angles along lines.
+pub mod angles;
 /// Calculate the area of the surface of a `Geometry`.
 pub mod area;
+/// Redistribute values between polygons in proportion to their overlapping areas.
+#[cfg(feature = "std")]
+pub mod area_interpolation;
+/// The planar arrangement of noded rings, and its faces.
+#[cfg(feature = "std")]
+pub(crate) mod arrangement;
 /// Calculate the bearing to another `Point`, in degrees.
+#[cfg(feature = "std")]
 pub mod bearing;
+/// Generate square and hexagonal grids, and count the points in each of their cells.
+#[cfg(feature = "std")]
+pub mod binning;
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
+/// Measure the diameter and width of a `Geometry` by rotating calipers around its convex hull.
+pub mod calipers;
+/// Abandon long-running algorithms, by a token, a deadline or a callback checked as they run.
+#[cfg(feature = "std")]
+pub mod cancel;
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
+#[cfg(feature = "std")]
 pub mod chaikin_smoothing;
 /// Calculate the signed approximate geodesic area of a `Geometry`.
+#[cfg(feature = "std")]
 pub mod chamberlain_duquette_area;
+/// Clip lines to the inside and outside of polygons.
+#[cfg(feature = "std")]
+pub mod clip_lines;
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
+#[cfg(feature = "std")]
 pub mod closest_point;
 /// Calculate the concave hull of a `Geometry`.
+#[cfg(feature = "std")]
 pub mod concave_hull;
 /// Determine whether `Geometry` `A` is completely enclosed by `Geometry` `B`.
+#[cfg(feature = "std")]
 pub mod contains;
+/// Build isolines and isobands from a grid of values using marching squares.
+#[cfg(feature = "std")]
+pub mod contour;
+/// Split a polygon into convex polygons.
+#[cfg(feature = "std")]
+pub mod convex_decomposition;
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
+/// Run algorithms over coordinates held in separate slices of `x` and `y`, without copying them.
+#[cfg(feature = "std")]
+pub mod coord_slices;
 /// Determine whether a `Coordinate` lies inside, outside, or on the boundary of a geometry.
 pub mod coordinate_position;
 /// Iterate over geometry coordinates.
 pub mod coords_iter;
+/// Check that a layer of polygons meets along shared edges, without overlaps or gaps.
+#[cfg(feature = "std")]
+pub mod coverage;
+/// Find where, and which way, a line crosses a `LineString`.
+#[cfg(feature = "std")]
+pub mod crossing_direction;
+/// Compare geometries up to a tolerance, and detect duplicates among them.
+#[cfg(feature = "std")]
+pub mod dedup;
+/// The Delaunay triangulation of a set of points.
+#[cfg(feature = "std")]
+pub(crate) mod delaunay;
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
+/// Attach Z values, such as elevations, to the vertices of a `LineString`, and keep them through
+/// transforms, simplification and clipping.
+#[cfg(feature = "std")]
+pub mod elevated_line_string;
+/// Split polygons into parts of equal area by recursive bisection.
+#[cfg(feature = "std")]
+pub mod equal_area_partition;
+/// Erode polygons inwards, by a distance or a series of steps until they collapse.
+#[cfg(feature = "std")]
+pub mod erosion;
 /// Calculate the minimum Euclidean distance between two `Geometries`.
+#[cfg(feature = "std")]
 pub mod euclidean_distance;
 /// Calculate the length of a planar line between two `Geometries`.
 pub mod euclidean_length;
 /// Calculate the extreme coordinates and indices of a geometry.
+#[cfg(feature = "std")]
 pub mod extremes;
-/// Calculate the Frechet distance between two `LineStrings`.
+/// Check whether the coordinates of a geometry are finite, and scrub those which aren't.
+#[cfg(feature = "std")]
+pub mod finite;
+/// Calculate the Frechet distance between two `LineStrings`, and find the part of a line best
+/// matching another.
+#[cfg(feature = "std")]
 pub mod frechet_distance;
+/// Detect tracks entering, leaving and dwelling in an area.
+#[cfg(feature = "std")]
+pub mod geofence;
+/// Simplify the members of a collection together, keeping their shared boundaries coherent.
+#[cfg(feature = "std")]
+pub mod generalize;
+/// Encode and decode geohashes, and find the geohash cells covering a geometry.
+#[cfg(feature = "std")]
+pub mod geohash;
+/// Buffer geometries of longitudes and latitudes by a distance in metres.
+#[cfg(feature = "std")]
+pub mod geodesic_buffer;
 /// Calculate the Geodesic distance between two `Point`s.
+#[cfg(feature = "std")]
 pub mod geodesic_distance;
 /// Calculate a new `Point` lying on a Geodesic arc between two `Point`s.
+#[cfg(feature = "std")]
 pub mod geodesic_intermediate;
 /// Calculate the Geodesic length of a line.
+#[cfg(feature = "std")]
 pub mod geodesic_length;
+/// Calculate the geometric median of a set of points.
+#[cfg(feature = "std")]
+pub mod geometric_median;
+/// Use normalized, optionally quantized, geometries as the keys of hash maps and sets.
+#[cfg(feature = "hash")]
+pub mod geometry_hash;
+/// Calculate the discrete Hausdorff distance between geometries, and the directed distance from
+/// one to another.
+#[cfg(feature = "std")]
+pub mod hausdorff_distance;
+/// Find the closest point of a geometry to a `Point`, following great circles.
+#[cfg(feature = "std")]
+pub mod haversine_closest_point;
 /// Calculate a destination `Point`, given a distance and a bearing.
+#[cfg(feature = "std")]
 pub mod haversine_destination;
 /// Calculate the Haversine distance between two `Geometries`.
+#[cfg(feature = "std")]
 pub mod haversine_distance;
 /// Calculate a new `Point` lying on a Great Circle arc between two `Point`s.
+#[cfg(feature = "std")]
 pub mod haversine_intermediate;
 /// Calculate the Haversine length of a Line.
+#[cfg(feature = "std")]
 pub mod haversine_length;
+/// Determine the intersection of two great-circle segments.
+#[cfg(feature = "std")]
+pub mod haversine_line_intersection;
+/// Interpolate values from scattered samples using Inverse Distance Weighting.
+#[cfg(feature = "std")]
+pub mod idw_interpolation;
+/// Find the largest circle, and a large rectangle, inside a polygon.
+#[cfg(feature = "std")]
+pub mod inscribed;
 /// Determine whether `Geometry` `A` intersects `Geometry` `B`.
 pub mod intersects;
 /// Determines whether a `LineString` is convex.
 pub mod is_convex;
 /// Calculate concave hull using k-nearest algorithm
+#[cfg(feature = "std")]
 pub mod k_nearest_concave_hull;
+/// Find where a rotated rectangle, such as a label, fits inside a polygon.
+#[cfg(feature = "std")]
+pub mod label_fit;
 /// Interpolate a point along a `Line` or `LineString`.
+#[cfg(feature = "std")]
 pub mod line_interpolate_point;
 /// Computes the intersection of two Lines.
+#[cfg(feature = "std")]
 pub mod line_intersection;
 /// Locate a point along a `Line` or `LineString`.
+#[cfg(feature = "std")]
 pub mod line_locate_point;
+/// Measure lines and areas in a choice of metric spaces.
+#[cfg(feature = "std")]
+pub mod line_measures;
+/// Node a network of `LineString`s, and find shortest paths along it.
+#[cfg(feature = "std")]
+pub mod line_network;
+/// Approximate circular arcs and curve polygons by `LineString`s and `Polygon`s.
+#[cfg(feature = "std")]
+pub mod linearize;
+/// The segments of geometries, with points as segments of no length.
+#[cfg(feature = "std")]
+pub(crate) mod linework;
+/// Validate, wrap and clamp the longitudes and latitudes of geometries.
+#[cfg(feature = "std")]
+pub mod lon_lat;
 /// Apply a function to all `Coordinates` of a `Geometry`.
+#[cfg(feature = "std")]
 pub mod map_coords;
+/// Snap the points of a trace to a network of lines.
+#[cfg(feature = "std")]
+pub mod map_match;
+/// Attach measures to the vertices of a `LineString`, and locate places along it by measure.
+#[cfg(feature = "std")]
+pub mod measured_line_string;
+/// Project between longitude and latitude and the Web Mercator and UTM planes.
+#[cfg(feature = "std")]
+pub mod mercator;
+/// Put geometries into a canonical form, for stable equality, hashing and diffing.
+#[cfg(feature = "std")]
+pub mod normalize;
+/// Bulk measures and point-in-polygon tests over coordinates packed for vectorization.
+#[cfg(feature = "simd")]
+pub mod packed;
 /// Orient a `Polygon`'s exterior and interior rings.
+#[cfg(feature = "std")]
 pub mod orient;
+/// Build the planar graph of a set of geometries, to walk their shared topology.
+#[cfg(feature = "std")]
+pub mod planar_graph;
 /// Helper functions for the "fast path" variant of the Polygon-Polygon Euclidean distance method.
+#[cfg(feature = "std")]
 pub(crate) mod polygon_distance_fast_path;
+/// Report the progress of batch operations, for progress bars over long runs.
+pub mod progress;
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
+/// Burn geometries into a grid of cells covering a `Rect`.
+#[cfg(feature = "std")]
+pub mod rasterize;
+/// Decompose rectilinear polygons into rectangles.
+#[cfg(feature = "std")]
+pub mod rectangle_decomposition;
 /// Relate two geometries based on DE-9IM
+#[cfg(feature = "std")]
 pub mod relate;
+/// Resample `LineString`s to evenly spaced points along them.
+#[cfg(feature = "std")]
+pub mod resample;
+/// Drop small holes from polygons, and keep the largest polygons of a `MultiPolygon`.
+#[cfg(feature = "std")]
+pub mod ring_filters;
 /// Rotate a `Geometry` around either its centroid or a `Point` by an angle given in degrees.
+#[cfg(feature = "std")]
 pub mod rotate;
+/// Draw random points from geometries.
+#[cfg(feature = "use-rand")]
+pub mod sample;
+/// Measure distances and closest points between line segments and coordinates.
+pub mod segment;
+/// Describe the shapes of `Polygon`s by their compactness, elongation and convexity.
+#[cfg(feature = "std")]
+pub mod shape_descriptors;
+/// Convert between the rings of a shapefile polygon and a `MultiPolygon`.
+#[cfg(feature = "std")]
+pub mod shapefile_rings;
+/// Measure how far a simplified geometry strays from its original, and how much smaller it is.
+#[cfg(feature = "std")]
+pub mod simplification_quality;
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
+#[cfg(feature = "std")]
 pub mod simplifyvw;
+/// Buffer lines on one side only.
+#[cfg(feature = "std")]
+pub mod single_sided_buffer;
+/// Measure the sinuosity and curvature of `LineString`s.
+#[cfg(feature = "std")]
+pub mod sinuosity;
+/// Smooth lines and polygon rings by corner cutting or spline interpolation.
+#[cfg(feature = "std")]
+pub mod smooth;
+/// Sort points by their direction from an origin, robustly.
+#[cfg(feature = "std")]
+pub mod sort_around_point;
+/// Join two sets of geometries by a spatial predicate, using an R*-tree to find candidate pairs.
+#[cfg(feature = "std")]
+pub mod spatial_join;
+/// Cut polygons into pieces along lines.
+#[cfg(feature = "std")]
+pub mod split;
+/// Measure, densify and simplify lines given as iterators of coordinates, in bounded memory.
+#[cfg(feature = "std")]
+pub mod streaming;
+/// Find the intersections within a set of line segments using a sweep line.
+#[cfg(feature = "std")]
+pub mod sweepline;
+/// Find the tiles of a slippy map which a geometry intersects.
+#[cfg(feature = "std")]
+pub mod tile_cover;
+/// Check the geometries of a layer against topology rules, such as polygons not overlapping.
+#[cfg(feature = "std")]
+pub mod topology_rules;
+/// Trace the regions of set cells in a boolean raster mask into `Polygon`s.
+#[cfg(feature = "std")]
+pub mod trace_mask;
+/// Measure the speed of timestamped tracks, find where they stop, and split them at gaps.
+#[cfg(feature = "std")]
+pub mod track;
+/// Reproject geometries with pluggable, fallible coordinate transformations.
+#[cfg(feature = "std")]
+pub mod transform;
 /// Translate a `Geometry` along the given offsets.
+#[cfg(feature = "std")]
 pub mod translate;
 /// Calculate the Vincenty distance between two `Point`s.
+#[cfg(feature = "std")]
 pub mod vincenty_distance;
 /// Calculate the Vincenty length of a `LineString`.
+#[cfg(feature = "std")]
 pub mod vincenty_length;
+/// Buffer lines by distances which vary along them.
+#[cfg(feature = "std")]
+pub mod variable_buffer;
+/// Find the region of a polygon visible from a point inside it.
+#[cfg(feature = "std")]
+pub mod visibility;
+/// Walk the parts of any geometry with a visitor, reading or changing them.
+pub mod visit;
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
+/// Aggregate the values of points by the polygons they fall within.
+#[cfg(feature = "std")]
+pub mod zonal_stats;
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::utils::lex_cmp;
+use crate::{
+    Coordinate, GeoNum, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Put a geometry into a canonical form, so that geometries which differ only in how they're
+/// written down compare equal with `==`, hash alike, and diff cleanly.
+///
+/// - A line, or line string, runs from the lexicographically lesser of its ends, comparing
+///   coordinates from each end in turn until they differ.
+/// - A ring starts and ends at its lexicographically least vertex. The exterior ring of a
+///   polygon, like a triangle, runs counter-clockwise, and its interior rings clockwise, as with
+///   [`Orient`](crate::algorithm::orient::Orient), and sorted.
+/// - The members of a multi-geometry are normalized and sorted.
+/// - The members of a geometry collection are normalized and sorted by type, in the order of
+///   the variants of [`Geometry`], then as the members of a multi-geometry.
+///
+/// Coordinates are compared by `x`, then `y`, so normalizing a geometry with a `NaN`
+/// coordinate will panic.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::normalize::Normalize;
+/// use geo::{polygon, MultiPolygon};
+///
+/// let squares = MultiPolygon(vec![
+///     // starting from the top-right corner, clockwise
+///     polygon![(x: 3., y: 1.), (x: 3., y: 0.), (x: 2., y: 0.), (x: 2., y: 1.)],
+///     polygon![(x: 0., y: 1.), (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+/// ]);
+///
+/// assert_eq!(
+///     squares.normalize(),
+///     MultiPolygon(vec![
+///         polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+///         polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.), (x: 2., y: 1.)],
+///     ])
+/// );
+/// ```
+pub trait Normalize {
+    fn normalize(&self) -> Self;
+}
+
+impl<T: GeoNum> Normalize for Point<T> {
+    fn normalize(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GeoNum> Normalize for Line<T> {
+    fn normalize(&self) -> Self {
+        if lex_cmp(&self.end, &self.start) == Ordering::Less {
+            Line::new(self.end, self.start)
+        } else {
+            *self
+        }
+    }
+}
+
+impl<T: GeoNum> Normalize for LineString<T> {
+    fn normalize(&self) -> Self {
+        let coords = &self.0;
+        let reversed = coords
+            .iter()
+            .zip(coords.iter().rev())
+            .map(|(forward, backward)| lex_cmp(forward, backward))
+            .find(|ordering| *ordering != Ordering::Equal)
+            == Some(Ordering::Greater);
+        if reversed {
+            LineString(coords.iter().rev().cloned().collect())
+        } else {
+            self.clone()
+        }
+    }
+}
+
+impl<T: GeoNum> Normalize for Polygon<T> {
+    fn normalize(&self) -> Self {
+        let mut interiors: Vec<LineString<T>> = self
+            .interiors()
+            .iter()
+            .map(|ring| normalize_ring(ring, WindingOrder::Clockwise))
+            .collect();
+        interiors.sort_by(|a, b| cmp_coords(&a.0, &b.0));
+        Polygon::new(
+            normalize_ring(self.exterior(), WindingOrder::CounterClockwise),
+            interiors,
+        )
+    }
+}
+
+impl<T: GeoNum> Normalize for MultiPoint<T> {
+    fn normalize(&self) -> Self {
+        let mut points = self.0.clone();
+        points.sort_by(|a, b| lex_cmp(&a.0, &b.0));
+        MultiPoint(points)
+    }
+}
+
+impl<T: GeoNum> Normalize for MultiLineString<T> {
+    fn normalize(&self) -> Self {
+        let mut lines: Vec<_> = self.0.iter().map(Normalize::normalize).collect();
+        lines.sort_by(|a, b| cmp_coords(&a.0, &b.0));
+        MultiLineString(lines)
+    }
+}
+
+impl<T: GeoNum> Normalize for MultiPolygon<T> {
+    fn normalize(&self) -> Self {
+        let mut polygons: Vec<_> = self.0.iter().map(Normalize::normalize).collect();
+        polygons.sort_by(cmp_polygons);
+        MultiPolygon(polygons)
+    }
+}
+
+impl<T: GeoNum> Normalize for Rect<T> {
+    fn normalize(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GeoNum> Normalize for Triangle<T> {
+    fn normalize(&self) -> Self {
+        let mut ring = LineString(self.to_array().to_vec());
+        ring.close();
+        let ring = normalize_ring(&ring, WindingOrder::CounterClockwise);
+        Triangle(ring.0[0], ring.0[1], ring.0[2])
+    }
+}
+
+impl<T: GeoNum> Normalize for GeometryCollection<T> {
+    fn normalize(&self) -> Self {
+        let mut geometries: Vec<_> = self.0.iter().map(Normalize::normalize).collect();
+        geometries.sort_by(cmp_geometries);
+        GeometryCollection(geometries)
+    }
+}
+
+impl<T: GeoNum> Normalize for Geometry<T> {
+    fn normalize(&self) -> Self {
+        match self {
+            Geometry::Point(g) => Geometry::Point(g.normalize()),
+            Geometry::Line(g) => Geometry::Line(g.normalize()),
+            Geometry::LineString(g) => Geometry::LineString(g.normalize()),
+            Geometry::Polygon(g) => Geometry::Polygon(g.normalize()),
+            Geometry::MultiPoint(g) => Geometry::MultiPoint(g.normalize()),
+            Geometry::MultiLineString(g) => Geometry::MultiLineString(g.normalize()),
+            Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.normalize()),
+            Geometry::GeometryCollection(g) => Geometry::GeometryCollection(g.normalize()),
+            Geometry::Rect(g) => Geometry::Rect(g.normalize()),
+            Geometry::Triangle(g) => Geometry::Triangle(g.normalize()),
+        }
+    }
+}
+
+// Wind a closed ring in the given order, if it has one, and start it at its least vertex.
+fn normalize_ring<T: GeoNum>(ring: &LineString<T>, order: WindingOrder) -> LineString<T> {
+    let len = ring.0.len();
+    if len < 2 || !ring.is_closed() {
+        return ring.clone();
+    }
+    let mut ring = ring.clone_to_winding_order(order);
+    ring.0.pop();
+    let least = crate::utils::least_index(&ring.0);
+    ring.0.rotate_left(least);
+    ring.0.push(ring.0[0]);
+    ring
+}
+
+// Compare coordinate sequences lexicographically, with a prefix before the longer sequence.
+pub(crate) fn cmp_coords<T: GeoNum>(a: &[Coordinate<T>], b: &[Coordinate<T>]) -> Ordering {
+    cmp_sequences(a, b, lex_cmp)
+}
+
+fn cmp_sequences<I, F: Fn(&I, &I) -> Ordering>(a: &[I], b: &[I], cmp: F) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp(a, b))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn cmp_polygons<T: GeoNum>(a: &Polygon<T>, b: &Polygon<T>) -> Ordering {
+    cmp_coords(&a.exterior().0, &b.exterior().0)
+        .then_with(|| cmp_sequences(a.interiors(), b.interiors(), |a, b| cmp_coords(&a.0, &b.0)))
+}
+
+// The position of a geometry's type among the variants of `Geometry`.
+fn type_rank<T: GeoNum>(geometry: &Geometry<T>) -> usize {
+    match geometry {
+        Geometry::Point(_) => 0,
+        Geometry::Line(_) => 1,
+        Geometry::LineString(_) => 2,
+        Geometry::Polygon(_) => 3,
+        Geometry::MultiPoint(_) => 4,
+        Geometry::MultiLineString(_) => 5,
+        Geometry::MultiPolygon(_) => 6,
+        Geometry::GeometryCollection(_) => 7,
+        Geometry::Rect(_) => 8,
+        Geometry::Triangle(_) => 9,
+    }
+}
+
+fn cmp_geometries<T: GeoNum>(a: &Geometry<T>, b: &Geometry<T>) -> Ordering {
+    let points = |a: &MultiPoint<T>, b: &MultiPoint<T>| {
+        cmp_sequences(&a.0, &b.0, |a, b| lex_cmp(&a.0, &b.0))
+    };
+    let lines = |a: &MultiLineString<T>, b: &MultiLineString<T>| {
+        cmp_sequences(&a.0, &b.0, |a, b| cmp_coords(&a.0, &b.0))
+    };
+    type_rank(a).cmp(&type_rank(b)).then_with(|| match (a, b) {
+        (Geometry::Point(a), Geometry::Point(b)) => lex_cmp(&a.0, &b.0),
+        (Geometry::Line(a), Geometry::Line(b)) => cmp_coords(&[a.start, a.end], &[b.start, b.end]),
+        (Geometry::LineString(a), Geometry::LineString(b)) => cmp_coords(&a.0, &b.0),
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => cmp_polygons(a, b),
+        (Geometry::MultiPoint(a), Geometry::MultiPoint(b)) => points(a, b),
+        (Geometry::MultiLineString(a), Geometry::MultiLineString(b)) => lines(a, b),
+        (Geometry::MultiPolygon(a), Geometry::MultiPolygon(b)) => {
+            cmp_sequences(&a.0, &b.0, cmp_polygons)
+        }
+        (Geometry::GeometryCollection(a), Geometry::GeometryCollection(b)) => {
+            cmp_sequences(&a.0, &b.0, cmp_geometries)
+        }
+        (Geometry::Rect(a), Geometry::Rect(b)) => {
+            cmp_coords(&[a.min(), a.max()], &[b.min(), b.max()])
+        }
+        (Geometry::Triangle(a), Geometry::Triangle(b)) => cmp_coords(&a.to_array(), &b.to_array()),
+        _ => Ordering::Equal,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn rings_start_at_least_vertex() {
+        let polygon = polygon![
+            exterior: [(x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.), (x: 4., y: 0.)],
+            interiors: [
+                [(x: 3., y: 3.), (x: 3., y: 2.), (x: 2., y: 2.), (x: 2., y: 3.)],
+                [(x: 1., y: 2.), (x: 2., y: 1.), (x: 1., y: 1.)],
+            ],
+        ];
+        let expected = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.)],
+                [(x: 2., y: 2.), (x: 2., y: 3.), (x: 3., y: 3.), (x: 3., y: 2.)],
+            ],
+        ];
+        assert_eq!(polygon.normalize(), expected);
+        assert_eq!(expected.normalize(), expected);
+
+        let triangle = Triangle(
+            Coordinate { x: 1., y: 1. },
+            Coordinate { x: 0., y: 1. },
+            Coordinate { x: 1., y: 0. },
+        );
+        assert_eq!(
+            triangle.normalize().to_array(),
+            [
+                Coordinate { x: 0., y: 1. },
+                Coordinate { x: 1., y: 0. },
+                Coordinate { x: 1., y: 1. },
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_and_collections() {
+        let line = line_string![(x: 2., y: 0.), (x: 1., y: 5.), (x: 2., y: 0.5)];
+        assert_eq!(
+            line.normalize(),
+            line_string![(x: 2., y: 0.), (x: 1., y: 5.), (x: 2., y: 0.5)]
+        );
+        assert_eq!(
+            line_string![(x: 2., y: 0.5), (x: 1., y: 5.), (x: 2., y: 0.)].normalize(),
+            line
+        );
+
+        let collection = GeometryCollection(vec![
+            line_string![(x: 1., y: 1.), (x: 0., y: 0.)].into(),
+            point!(x: 5., y: 5.).into(),
+            point!(x: 1., y: 2.).into(),
+            Line::new((3., 3.), (2., 2.)).into(),
+        ]);
+        assert_eq!(
+            collection.normalize(),
+            GeometryCollection(vec![
+                point!(x: 1., y: 2.).into(),
+                point!(x: 5., y: 5.).into(),
+                Line::new((2., 2.), (3., 3.)).into(),
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into(),
+            ])
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::algorithm::arrangement::Arrangement;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Geometry, Line, MultiLineString};
+
+/// The planar graph formed by the segments of a set of geometries, noded wherever they meet.
+///
+/// Each node has a star of directed edges leaving it, sorted counter-clockwise from the positive
+/// x axis. Each edge of the graph is a pair of directed edges, one either way along it, and
+/// where the segments of several geometries run along the same edge, it bundles them all,
+/// remembering which geometries they came from and which way each of them ran. So custom
+/// topology rules can be written by walking the graph: the boundary shared by two parcels, for
+/// example, is made of the edges with segments from both.
+///
+/// Directed edges, like nodes, are numbered from zero. The twin of directed edge `e`, running
+/// the other way along the same edge, is `e ^ 1`.
+///
+/// Points contribute nothing to the graph, nor do segments of zero length.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::planar_graph::PlanarGraph;
+/// use geo::{polygon, Coordinate, Geometry};
+///
+/// // two parcels sharing part of an edge, both wound counter-clockwise
+/// let parcels: Vec<Geometry<f64>> = vec![
+///     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into(),
+///     polygon![(x: 2., y: 1.), (x: 4., y: 1.), (x: 4., y: 3.), (x: 2., y: 3.)].into(),
+/// ];
+/// let graph = PlanarGraph::new(&parcels);
+///
+/// // the parcels' shared boundary, which they run along in opposite directions
+/// let shared: Vec<_> = (0..graph.directed_edge_count())
+///     .filter(|edge| graph.sources(*edge).count() == 2)
+///     .filter(|edge| graph.sources(*edge).any(|(index, same_way)| index == 0 && same_way))
+///     .map(|edge| graph.line(edge))
+///     .collect();
+/// assert_eq!(shared.len(), 1);
+/// assert_eq!(shared[0].start, Coordinate { x: 2., y: 1. });
+/// assert_eq!(shared[0].end, Coordinate { x: 2., y: 2. });
+///
+/// // where the second parcel's corner lies on the first parcel's edge, three edges meet
+/// let node = graph.node_at(Coordinate { x: 2., y: 1. }).unwrap();
+/// let ends: Vec<_> = graph
+///     .edge_star(node)
+///     .iter()
+///     .map(|edge| graph.line(*edge).end)
+///     .collect();
+/// assert_eq!(
+///     ends,
+///     vec![(4., 1.).into(), (2., 2.).into(), (2., 0.).into()]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlanarGraph<T: GeoFloat> {
+    arrangement: Arrangement<T>,
+}
+
+impl<T: GeoFloat> PlanarGraph<T> {
+    pub fn new(geometries: &[Geometry<T>]) -> Self {
+        let segments = geometries.iter().enumerate().flat_map(|(index, geometry)| {
+            let mut lines = vec![];
+            add_segments(geometry, &mut lines);
+            lines.into_iter().map(move |line| (line, index))
+        });
+        PlanarGraph {
+            arrangement: Arrangement::new(segments),
+        }
+    }
+
+    /// The coordinates of the nodes, in lexicographic order.
+    pub fn nodes(&self) -> &[Coordinate<T>] {
+        self.arrangement.nodes()
+    }
+
+    /// The node at `coord`, if there is one.
+    pub fn node_at(&self, coord: Coordinate<T>) -> Option<usize> {
+        self.nodes()
+            .binary_search_by(|node| lex_cmp(node, &coord))
+            .ok()
+    }
+
+    /// The directed edges leaving `node`, counter-clockwise from the positive x axis.
+    pub fn edge_star(&self, node: usize) -> &[usize] {
+        self.arrangement.outgoing(node)
+    }
+
+    /// The number of directed edges, twice the number of edges.
+    pub fn directed_edge_count(&self) -> usize {
+        self.arrangement.half_edge_count()
+    }
+
+    /// The node which `edge` leaves.
+    pub fn origin(&self, edge: usize) -> usize {
+        self.arrangement.origin(edge)
+    }
+
+    /// The node which `edge` enters.
+    pub fn destination(&self, edge: usize) -> usize {
+        self.arrangement.origin(edge ^ 1)
+    }
+
+    /// The segment from the origin of `edge` to its destination.
+    pub fn line(&self, edge: usize) -> Line<T> {
+        self.arrangement.line(edge)
+    }
+
+    /// The directed edge running the other way along the same edge as `edge`.
+    pub fn twin(&self, edge: usize) -> usize {
+        edge ^ 1
+    }
+
+    /// The directed edge following `edge` around the face on its left: of the edges leaving its
+    /// destination, the next clockwise from its twin.
+    ///
+    /// Following `next` from any directed edge walks around a face counter-clockwise, or around
+    /// the outside of a connected part of the graph clockwise, back to the edge it started from.
+    pub fn next(&self, edge: usize) -> usize {
+        self.arrangement.next(edge)
+    }
+
+    /// The geometries with segments along `edge`, by their index in the slice the graph was
+    /// built from, and whether each segment runs the same way as `edge`.
+    ///
+    /// A geometry appears more than once if several of its segments run along the same edge.
+    pub fn sources(&self, edge: usize) -> impl Iterator<Item = (usize, bool)> + '_ {
+        self.arrangement.sides(edge)
+    }
+}
+
+/// A segment of a network of lines noded by [`planarize`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanarSegment<T: GeoFloat> {
+    pub line: Line<T>,
+    /// The line strings with a segment along this one, by their index, in order and without
+    /// repeats
+    pub sources: Vec<usize>,
+}
+
+/// Node a network of lines against itself, splitting them wherever they meet and merging the
+/// segments which coincide, as routing and polygonization need.
+///
+/// Each segment of the result runs between two nodes, without meeting any other segment except
+/// at its ends, and the same way as the first line string along it. The segments are in order
+/// of the first line string along them, and then of where they lie along it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::planar_graph::planarize;
+/// use geo::{line_string, Line, MultiLineString};
+///
+/// let roads = MultiLineString(vec![
+///     line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+///     // crossing the first road
+///     line_string![(x: 1., y: -1.), (x: 1., y: 1.)],
+///     // along part of the first road, the other way
+///     line_string![(x: 3., y: 0.), (x: 2., y: 0.)],
+/// ]);
+/// let network = planarize(&roads);
+///
+/// assert_eq!(network.len(), 6);
+/// assert_eq!(network[2].line, Line::from([(2., 0.), (3., 0.)]));
+/// assert_eq!(network[2].sources, vec![0, 2]);
+/// ```
+pub fn planarize<T: GeoFloat>(lines: &MultiLineString<T>) -> Vec<PlanarSegment<T>> {
+    let arrangement = Arrangement::new(
+        lines
+            .0
+            .iter()
+            .enumerate()
+            .flat_map(|(index, line)| line.lines().map(move |segment| (segment, index))),
+    );
+    (0..arrangement.half_edge_count())
+        .step_by(2)
+        .map(|edge| {
+            let mut sources: Vec<usize> = arrangement.sides(edge).map(|(index, _)| index).collect();
+            sources.sort_unstable();
+            sources.dedup();
+            PlanarSegment {
+                line: arrangement.line(edge),
+                sources,
+            }
+        })
+        .collect()
+}
+
+fn add_segments<T: GeoFloat>(geometry: &Geometry<T>, lines: &mut Vec<Line<T>>) {
+    match geometry {
+        Geometry::Point(_) | Geometry::MultiPoint(_) => {}
+        Geometry::Line(line) => lines.push(*line),
+        Geometry::LineString(line_string) => lines.extend(line_string.lines()),
+        Geometry::Polygon(polygon) => lines.extend(
+            std::iter::once(polygon.exterior())
+                .chain(polygon.interiors())
+                .flat_map(|ring| ring.lines()),
+        ),
+        Geometry::MultiLineString(multi_line_string) => lines.extend(
+            multi_line_string
+                .iter()
+                .flat_map(|line_string| line_string.lines()),
+        ),
+        Geometry::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon.iter() {
+                add_segments(&Geometry::Polygon(polygon.clone()), lines);
+            }
+        }
+        Geometry::Rect(rect) => add_segments(&Geometry::Polygon(rect.to_polygon()), lines),
+        Geometry::Triangle(triangle) => lines.extend(triangle.to_lines().iter().cloned()),
+        Geometry::GeometryCollection(geometry_collection) => {
+            for geometry in geometry_collection.iter() {
+                add_segments(geometry, lines);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Rect};
+
+    #[test]
+    fn crossing_lines() {
+        let lines: Vec<Geometry<f64>> = vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.)].into(),
+            line_string![(x: 0., y: 2.), (x: 2., y: 0.)].into(),
+            point!(x: 5., y: 5.).into(),
+        ];
+        let graph = PlanarGraph::new(&lines);
+        assert_eq!(graph.nodes().len(), 5);
+        assert_eq!(graph.directed_edge_count(), 8);
+
+        let centre = graph.node_at(Coordinate { x: 1., y: 1. }).unwrap();
+        let star = graph.edge_star(centre);
+        let ends: Vec<Coordinate<f64>> = star.iter().map(|edge| graph.line(*edge).end).collect();
+        assert_eq!(
+            ends,
+            vec![
+                (2., 2.).into(),
+                (0., 2.).into(),
+                (0., 0.).into(),
+                (2., 0.).into()
+            ]
+        );
+        for edge in star {
+            assert_eq!(graph.origin(*edge), centre);
+            assert_eq!(graph.destination(graph.twin(*edge)), centre);
+            let sources: Vec<_> = graph.sources(*edge).collect();
+            assert_eq!(sources.len(), 1);
+        }
+        // the pieces of the first line, running away from and towards its start
+        let towards_start = star[2];
+        assert_eq!(
+            graph.sources(towards_start).collect::<Vec<_>>(),
+            vec![(0, false)]
+        );
+        assert_eq!(
+            graph.sources(graph.twin(towards_start)).collect::<Vec<_>>(),
+            vec![(0, true)]
+        );
+        assert_eq!(graph.node_at(Coordinate { x: 5., y: 5. }), None);
+    }
+
+    #[test]
+    fn planarize_duplicates_and_touches() {
+        let lines = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)],
+            // the same line, backwards
+            line_string![(x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 0.)],
+            // ending on the first line's first segment
+            line_string![(x: 1., y: 1.), (x: 1., y: 0.)],
+        ]);
+        let network = planarize(&lines);
+        let segments: Vec<_> = network
+            .iter()
+            .map(|segment| (segment.line, segment.sources.clone()))
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                (Line::from([(0., 0.), (1., 0.)]), vec![0, 1]),
+                (Line::from([(1., 0.), (2., 0.)]), vec![0, 1]),
+                (Line::from([(2., 0.), (2., 2.)]), vec![0, 1]),
+                (Line::from([(1., 1.), (1., 0.)]), vec![2]),
+            ]
+        );
+        assert!(planarize(&MultiLineString::<f64>(vec![])).is_empty());
+    }
+
+    #[test]
+    fn faces() {
+        // a square and a triangle on top of it, sharing an edge
+        let geometries: Vec<Geometry<f64>> = vec![
+            Rect::new((0., 0.), (2., 2.)).into(),
+            polygon![(x: 0., y: 2.), (x: 2., y: 2.), (x: 1., y: 3.)].into(),
+        ];
+        let graph = PlanarGraph::new(&geometries);
+        assert_eq!(graph.nodes().len(), 5);
+        assert_eq!(graph.directed_edge_count(), 12);
+
+        // walk around each face from its edges along the shared edge
+        let top = graph.node_at(Coordinate { x: 2., y: 2. }).unwrap();
+        let shared = *graph
+            .edge_star(top)
+            .iter()
+            .find(|edge| graph.sources(**edge).count() == 2)
+            .unwrap();
+        let face_size = |first: usize| {
+            let mut size = 1;
+            let mut edge = graph.next(first);
+            while edge != first {
+                size += 1;
+                edge = graph.next(edge);
+            }
+            size
+        };
+        // running west, with the square on its left, and the triangle on its twin's
+        assert_eq!(graph.line(shared).end, Coordinate { x: 0., y: 2. });
+        assert_eq!(face_size(shared), 4);
+        assert_eq!(face_size(graph.twin(shared)), 3);
+    }
+}
@@ -0,0 +1,34 @@
+/// A report of how far a batch operation has got, for rendering a progress bar over a long run.
+///
+/// The batch operations taking one, such as
+/// [`spatial_join_with_progress`](crate::algorithm::spatial_join::spatial_join_with_progress) and
+/// [`RelateMany::relate_many_with_progress`](crate::algorithm::relate::RelateMany::relate_many_with_progress),
+/// report once before they start, with none of the items processed, and again after each item.
+///
+/// Any closure taking the counts is a `Progress`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::relate::RelateMany;
+/// use geo::{point, polygon, Geometry};
+///
+/// let parcel = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let features: Vec<Geometry<f64>> = (0..4).map(|i| point!(x: i as f64, y: 5.).into()).collect();
+///
+/// let mut reports = vec![];
+/// parcel.relate_many_with_progress(&features, &mut |processed, total| {
+///     reports.push(format!("{}/{}", processed, total))
+/// });
+/// assert_eq!(reports, vec!["0/4", "1/4", "2/4", "3/4", "4/4"]);
+/// ```
+pub trait Progress {
+    /// `processed` of the `total` items have been processed.
+    fn report(&mut self, processed: usize, total: usize);
+}
+
+impl<F: FnMut(usize, usize)> Progress for F {
+    fn report(&mut self, processed: usize, total: usize) {
+        self(processed, total)
+    }
+}
@@ -1 +1,48 @@
 pub use proj::{Area, Info, Proj, ProjBuilder, ProjError, Projinfo};
+
+use crate::algorithm::transform::CoordTransform;
+use std::error::Error;
+
+/// A pair of PROJ transformations, from one coordinate reference system to another and back,
+/// as a [`CoordTransform`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::proj::{Proj, ProjTransform};
+/// use geo::algorithm::transform::Transform;
+/// use geo::Point;
+///
+/// // from WGS 84 to NAD83 / California zone 6 (ftUS)
+/// let to_feet = ProjTransform::new(
+///     Proj::new_known_crs("EPSG:4326", "EPSG:2230", None).unwrap(),
+///     Proj::new_known_crs("EPSG:2230", "EPSG:4326", None).unwrap(),
+/// );
+/// let usa_m = Point::new(-115.797615, 37.2647978);
+/// let usa_ft = usa_m.transform(&to_feet).unwrap();
+/// assert_eq!(6693625.67217475, usa_ft.x());
+/// assert_eq!(3497301.5918027186, usa_ft.y());
+/// ```
+pub struct ProjTransform {
+    forward: Proj,
+    inverse: Proj,
+}
+
+impl ProjTransform {
+    /// Pair a transformation with its inverse.
+    pub fn new(forward: Proj, inverse: Proj) -> Self {
+        ProjTransform { forward, inverse }
+    }
+}
+
+impl CoordTransform<f64> for ProjTransform {
+    fn forward(&self, &(x, y): &(f64, f64)) -> Result<(f64, f64), Box<dyn Error + Send + Sync>> {
+        let converted = self.forward.convert((x, y))?;
+        Ok((converted.x(), converted.y()))
+    }
+
+    fn inverse(&self, &(x, y): &(f64, f64)) -> Result<(f64, f64), Box<dyn Error + Send + Sync>> {
+        let converted = self.inverse.convert((x, y))?;
+        Ok((converted.x(), converted.y()))
+    }
+}
@@ -0,0 +1,275 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::dimensions::{Dimensions, HasDimensions};
+use crate::algorithm::relate::Relate;
+use crate::{Coordinate, GeoFloat, Geometry, Rect};
+
+/// Which cells of a grid a geometry is burned into by [`rasterize`] and [`rasterize_counts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BurnRule {
+    /// Polygons burn the cells whose centres they cover, including centres on their boundary
+    CellCenter,
+    /// Polygons burn every cell they share some area with
+    AllTouched,
+}
+
+/// Burn a geometry into a grid of `rows` by `columns` cells tiling `bounds`, returning which
+/// cells it was burned into.
+///
+/// The grid is indexed as `mask[row][column]`, with row `0` at `bounds.min().y` and column `0`
+/// at `bounds.min().x`, the layout which [`trace_mask`](crate::algorithm::trace_mask::trace_mask)
+/// traces back into polygons.
+///
+/// Polygons are burned according to `rule`. Lines and points have no area, and would cover the
+/// centres of hardly any cells, so they burn every cell they touch under either rule, including
+/// cells whose edges or corners they only touch.
+///
+/// PERF: each cell within the bounding rectangle of each part of the geometry is tested against
+/// that part, with [`Relate`] for `AllTouched`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::rasterize::{rasterize, BurnRule};
+/// use geo::{polygon, Geometry, Rect};
+///
+/// // a triangle over a 4 by 4 grid of unit cells
+/// let triangle: Geometry<f64> = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 0., y: 4.)].into();
+/// let bounds = Rect::new((0., 0.), (4., 4.));
+///
+/// // the hypotenuse runs through the centres of the cells along the diagonal
+/// let centres = rasterize(&triangle, bounds, 4, 4, BurnRule::CellCenter);
+/// assert_eq!(centres[0], vec![true, true, true, true]);
+/// assert_eq!(centres[3], vec![true, false, false, false]);
+///
+/// // and only touches the corners of the cells beyond them
+/// let touched = rasterize(&triangle, bounds, 4, 4, BurnRule::AllTouched);
+/// assert_eq!(touched, centres);
+/// ```
+pub fn rasterize<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+    rule: BurnRule,
+) -> Vec<Vec<bool>> {
+    let mut mask = vec![vec![false; columns]; rows];
+    let grid = Grid {
+        bounds,
+        rows,
+        columns,
+    };
+    burn(geometry, &grid, rule, &mut mask);
+    mask
+}
+
+/// Burn each of a set of geometries into a grid of `rows` by `columns` cells tiling `bounds`,
+/// counting the geometries burned into each cell.
+///
+/// The grid and the burning of each geometry are as in [`rasterize`]. Counting parcels, say,
+/// finds the cells where they overlap; and the count of a single zone over a grid of values
+/// weights the values for zonal statistics, without a separate raster library.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::rasterize::{rasterize_counts, BurnRule};
+/// use geo::{line_string, Geometry, Rect};
+///
+/// // two tracks crossing a 3 by 3 grid of unit cells
+/// let tracks: Vec<Geometry<f64>> = vec![
+///     line_string![(x: 0.5, y: 0.5), (x: 2.5, y: 0.5)].into(),
+///     line_string![(x: 1.5, y: 0.5), (x: 1.5, y: 2.5)].into(),
+/// ];
+/// let counts = rasterize_counts(&tracks, Rect::new((0., 0.), (3., 3.)), 3, 3, BurnRule::CellCenter);
+/// assert_eq!(counts, vec![vec![1, 2, 1], vec![0, 1, 0], vec![0, 1, 0]]);
+/// ```
+pub fn rasterize_counts<T: GeoFloat>(
+    geometries: &[Geometry<T>],
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+    rule: BurnRule,
+) -> Vec<Vec<usize>> {
+    let mut counts = vec![vec![0; columns]; rows];
+    let grid = Grid {
+        bounds,
+        rows,
+        columns,
+    };
+    for geometry in geometries {
+        let mut mask = vec![vec![false; columns]; rows];
+        burn(geometry, &grid, rule, &mut mask);
+        for (count_row, mask_row) in counts.iter_mut().zip(mask) {
+            for (count, burned) in count_row.iter_mut().zip(mask_row) {
+                if burned {
+                    *count += 1;
+                }
+            }
+        }
+    }
+    counts
+}
+
+struct Grid<T: GeoFloat> {
+    bounds: Rect<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T: GeoFloat> Grid<T> {
+    fn x(&self, column: usize) -> T {
+        self.bounds.min().x
+            + self.bounds.width() * T::from(column).unwrap() / T::from(self.columns).unwrap()
+    }
+
+    fn y(&self, row: usize) -> T {
+        self.bounds.min().y
+            + self.bounds.height() * T::from(row).unwrap() / T::from(self.rows).unwrap()
+    }
+
+    fn cell(&self, row: usize, column: usize) -> Rect<T> {
+        Rect::new(
+            Coordinate {
+                x: self.x(column),
+                y: self.y(row),
+            },
+            Coordinate {
+                x: self.x(column + 1),
+                y: self.y(row + 1),
+            },
+        )
+    }
+
+    // The range of cells along an axis which may touch the span from `min` to `max`, padded by
+    // a cell either way in case rounding puts a span ending on a cell edge on the wrong side.
+    fn range(min: T, max: T, start: T, extent: T, count: usize) -> std::ops::Range<usize> {
+        let count_t = T::from(count).unwrap();
+        let index = |value: T| ((value - start) / extent * count_t).floor();
+        let first = (index(min) - T::one()).max(T::zero());
+        let last = (index(max) + T::one()).min(count_t - T::one());
+        if first > last {
+            return 0..0;
+        }
+        first.to_usize().unwrap()..last.to_usize().unwrap() + 1
+    }
+}
+
+fn burn<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    grid: &Grid<T>,
+    rule: BurnRule,
+    mask: &mut [Vec<bool>],
+) {
+    if let Geometry::GeometryCollection(collection) = geometry {
+        for geometry in collection.iter() {
+            burn(geometry, grid, rule, mask);
+        }
+        return;
+    }
+    if grid.rows == 0 || grid.columns == 0 {
+        return;
+    }
+    let extent = match geometry.bounding_rect() {
+        Some(extent) => extent,
+        None => return,
+    };
+    let is_areal = geometry.dimensions() == Dimensions::TwoDimensional;
+    let bounds = grid.bounds;
+    let rows = Grid::range(
+        extent.min().y,
+        extent.max().y,
+        bounds.min().y,
+        bounds.height(),
+        grid.rows,
+    );
+    let columns = Grid::range(
+        extent.min().x,
+        extent.max().x,
+        bounds.min().x,
+        bounds.width(),
+        grid.columns,
+    );
+    for row in rows {
+        for column in columns.clone() {
+            if mask[row][column] {
+                continue;
+            }
+            let cell = grid.cell(row, column);
+            mask[row][column] = match (is_areal, rule) {
+                (true, BurnRule::CellCenter) => {
+                    geometry.coordinate_position(&cell.center()) != CoordPos::Outside
+                }
+                (true, BurnRule::AllTouched) => {
+                    Geometry::Rect(cell)
+                        .relate(geometry)
+                        .get(CoordPos::Inside, CoordPos::Inside)
+                        != Dimensions::Empty
+                }
+                (false, _) => Geometry::Rect(cell).relate(geometry).is_intersects(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::trace_mask::trace_mask;
+    use crate::{line_string, point, polygon, GeometryCollection, MultiPolygon};
+
+    #[test]
+    fn all_touched_covers_cell_centres() {
+        let shape: Geometry<f64> = polygon![
+            exterior: [(x: 0.3, y: 0.2), (x: 5.7, y: 1.1), (x: 4.2, y: 5.9), (x: 0.6, y: 4.4)],
+            interiors: [[(x: 2., y: 2.), (x: 3.3, y: 2.), (x: 3.3, y: 3.3), (x: 2., y: 3.3)]],
+        ]
+        .into();
+        let bounds = Rect::new((0., 0.), (6., 6.));
+        let centres = rasterize(&shape, bounds, 12, 12, BurnRule::CellCenter);
+        let touched = rasterize(&shape, bounds, 12, 12, BurnRule::AllTouched);
+        let count = |mask: &[Vec<bool>]| mask.iter().flatten().filter(|cell| **cell).count();
+        assert!(count(&centres) < count(&touched));
+        for (centres_row, touched_row) in centres.iter().zip(&touched) {
+            for (centre, touched) in centres_row.iter().zip(touched_row) {
+                assert!(!centre || *touched);
+            }
+        }
+        // the cells which the hole covers entirely are burned under neither rule
+        assert!(!touched[4][4] && !touched[5][5]);
+        assert!(touched[4][6] && !centres[4][6]);
+    }
+
+    #[test]
+    fn round_trips_through_trace_mask() {
+        let bounds = Rect::new((0., 0.), (8., 4.));
+        let shapes = MultiPolygon(vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.), (x: 0., y: 3.)],
+                interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+            ],
+            polygon![(x: 5., y: 1.), (x: 7., y: 1.), (x: 7., y: 4.), (x: 5., y: 4.)],
+        ]);
+        // the shapes run along cell edges, so neither rule burns the cells beside them
+        for rule in &[BurnRule::CellCenter, BurnRule::AllTouched] {
+            let mask = rasterize(&shapes.clone().into(), bounds, 4, 8, *rule);
+            assert_eq!(trace_mask(&mask, bounds), shapes);
+        }
+    }
+
+    #[test]
+    fn lines_and_points_burn_what_they_touch() {
+        let bounds = Rect::new((0., 0.), (2., 2.));
+        let geometries: Vec<Geometry<f64>> = vec![
+            // along the edge between the bottom two cells, and on past the grid
+            line_string![(x: 1., y: 0.5), (x: 1., y: -3.)].into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![
+                point!(x: 1.5, y: 1.5).into(),
+                point!(x: 10., y: 10.).into(),
+            ])),
+        ];
+        let counts = rasterize_counts(&geometries, bounds, 2, 2, BurnRule::CellCenter);
+        assert_eq!(counts, vec![vec![1, 1], vec![0, 1]]);
+        assert!(rasterize(&geometries[0], bounds, 0, 0, BurnRule::AllTouched).is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{Coordinate, GeoNum, LineString, MultiPolygon, Polygon, Rect};
+
+/// Decompose a rectilinear polygon, whose edges are all horizontal or vertical, into rectangles.
+///
+/// The polygon is divided into a grid along the lines through its vertices. Starting from the
+/// lowest, then westernmost, cell not yet covered, each rectangle is grown as far east as it
+/// can go, then as far north. This gives a near-minimal set of rectangles, which don't overlap
+/// and together cover the polygon exactly, as floorplans and chip layouts need for spatial
+/// indexes and rasterizers.
+///
+/// Returns `None` if an edge of the polygon is neither horizontal nor vertical.
+///
+/// PERF: the grid has a cell for each pair of distinct `x` and `y` coordinates, so its size
+/// grows with the square of the number of vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::rectangle_decomposition::RectangleDecomposition;
+/// use geo::{polygon, Rect};
+///
+/// // a U, in integer coordinates
+/// let u = polygon![
+///     (x: 0, y: 0), (x: 3, y: 0), (x: 3, y: 2), (x: 2, y: 2),
+///     (x: 2, y: 1), (x: 1, y: 1), (x: 1, y: 2), (x: 0, y: 2),
+/// ];
+/// assert_eq!(
+///     u.rectangle_decomposition(),
+///     Some(vec![
+///         Rect::new((0, 0), (3, 1)),
+///         Rect::new((0, 1), (1, 2)),
+///         Rect::new((2, 1), (3, 2)),
+///     ])
+/// );
+///
+/// let triangle = polygon![(x: 0, y: 0), (x: 1, y: 0), (x: 0, y: 1)];
+/// assert_eq!(triangle.rectangle_decomposition(), None);
+/// ```
+pub trait RectangleDecomposition<T: GeoNum> {
+    fn rectangle_decomposition(&self) -> Option<Vec<Rect<T>>>;
+}
+
+impl<T: GeoNum> RectangleDecomposition<T> for Polygon<T> {
+    fn rectangle_decomposition(&self) -> Option<Vec<Rect<T>>> {
+        decompose(std::iter::once(self))
+    }
+}
+
+impl<T: GeoNum> RectangleDecomposition<T> for MultiPolygon<T> {
+    fn rectangle_decomposition(&self) -> Option<Vec<Rect<T>>> {
+        decompose(self.0.iter())
+    }
+}
+
+fn decompose<'a, T: GeoNum + 'a>(
+    polygons: impl Iterator<Item = &'a Polygon<T>>,
+) -> Option<Vec<Rect<T>>> {
+    let rings: Vec<&LineString<T>> = polygons
+        .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .collect();
+
+    // the vertical edges, as their `x` and their least and greatest `y`
+    let mut verticals = vec![];
+    let (mut xs, mut ys) = (vec![], vec![]);
+    for ring in &rings {
+        for line in ring.lines() {
+            let (start, end) = (line.start, line.end);
+            if start.x == end.x {
+                if start.y != end.y {
+                    let (low, high) = if start.y < end.y {
+                        (start.y, end.y)
+                    } else {
+                        (end.y, start.y)
+                    };
+                    verticals.push((start.x, low, high));
+                }
+            } else if start.y != end.y {
+                return None;
+            }
+            xs.push(start.x);
+            ys.push(start.y);
+        }
+    }
+    for values in [&mut xs, &mut ys].iter_mut() {
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+    }
+    if xs.len() < 2 || ys.len() < 2 {
+        return Some(vec![]);
+    }
+
+    // each cell is inside if an odd number of the vertical edges across its row lie west of it
+    let (columns, rows) = (xs.len() - 1, ys.len() - 1);
+    let mut inside = vec![vec![false; columns]; rows];
+    for (row, cells) in inside.iter_mut().enumerate() {
+        for &(x, low, high) in &verticals {
+            if low <= ys[row] && ys[row + 1] <= high {
+                for (column, cell) in cells.iter_mut().enumerate() {
+                    if x <= xs[column] {
+                        *cell = !*cell;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut rects = vec![];
+    for row in 0..rows {
+        for column in 0..columns {
+            if !inside[row][column] {
+                continue;
+            }
+            let mut end_column = column + 1;
+            while end_column < columns && inside[row][end_column] {
+                end_column += 1;
+            }
+            let mut end_row = row + 1;
+            while end_row < rows && inside[end_row][column..end_column].iter().all(|cell| *cell) {
+                end_row += 1;
+            }
+            for cells in &mut inside[row..end_row] {
+                for cell in &mut cells[column..end_column] {
+                    *cell = false;
+                }
+            }
+            rects.push(Rect::new(
+                Coordinate {
+                    x: xs[column],
+                    y: ys[row],
+                },
+                Coordinate {
+                    x: xs[end_column],
+                    y: ys[end_row],
+                },
+            ));
+        }
+    }
+    Some(rects)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::polygon;
+
+    #[test]
+    fn holes_and_collinear_vertices() {
+        // a square frame, with a vertex along its bottom edge
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+        ];
+        let rects = frame.rectangle_decomposition().unwrap();
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new((0., 0.), (4., 1.)),
+                Rect::new((0., 1.), (1., 4.)),
+                Rect::new((3., 1.), (4., 4.)),
+                Rect::new((1., 3.), (3., 4.)),
+            ]
+        );
+        let area: f64 = rects.iter().map(|rect| rect.unsigned_area()).sum();
+        assert_eq!(area, frame.unsigned_area());
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let polygons = MultiPolygon(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            // a staircase, touching the square at a corner
+            polygon![
+                (x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 3., y: 4.),
+                (x: 3., y: 3.), (x: 2., y: 3.), (x: 2., y: 2.), (x: 1., y: 2.),
+            ],
+        ]);
+        let rects = polygons.rectangle_decomposition().unwrap();
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new((0., 0.), (1., 1.)),
+                Rect::new((1., 1.), (4., 2.)),
+                Rect::new((2., 2.), (4., 3.)),
+                Rect::new((3., 3.), (4., 4.)),
+            ]
+        );
+        assert_eq!(
+            MultiPolygon::<f64>(vec![]).rectangle_decomposition(),
+            Some(vec![])
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Read-only views of the topology graphs constructed by [`Relate`](super::Relate).
+//!
+//! When a relate result looks wrong, the graph state is usually the quickest way to find out why.
+//! The views in this module are owned snapshots of each topology graph's nodes and edges,
+//! including their topology labels and the intersections found along each edge. They implement
+//! `Debug`, so they can be attached to bug reports, and `PartialEq`, so regression tests can
+//! assert against them.
+
+use super::geomgraph::{
+    CoordNode, CoordPos, Direction, Edge, EdgeIntersection, GeometryGraph, Label,
+    RobustLineIntersector,
+};
+use super::relate_operation::RelateOperation;
+use super::IntersectionMatrix;
+use crate::io::svg::{SvgDocument, SvgStyle};
+use crate::{Coordinate, GeoFloat, Geometry, GeometryCow, LineString, Point};
+
+/// The topology graph of `geometry`, after it has been noded against itself.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo::relate::debug::geometry_graph;
+///
+/// // a self-intersecting "bow tie"
+/// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// let graph = geometry_graph(&line_string.into());
+///
+/// // both endpoints, plus the self-intersection at (1, 1)
+/// assert_eq!(graph.nodes.len(), 3);
+/// assert!(graph.nodes.iter().any(|node| node.coordinate == (1., 1.).into()));
+/// ```
+pub fn geometry_graph<F: GeoFloat>(geometry: &Geometry<F>) -> GeometryGraphView<F> {
+    let geometry = GeometryCow::from(geometry);
+    let mut graph = GeometryGraph::new(0, &geometry);
+    graph.compute_self_nodes(Box::new(RobustLineIntersector::new()));
+    GeometryGraphView::new(&graph)
+}
+
+/// Compute the [`IntersectionMatrix`] of `a` and `b`, returning it along with the state of both
+/// topology graphs once the computation has finished.
+///
+/// Unlike [`Relate::relate`](super::Relate::relate), this never takes a shortcut, like skipping
+/// the graphs of geometries whose bounding boxes don't intersect, so the graphs are always fully
+/// computed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Coordinate};
+/// use geo::relate::debug::relate_graphs;
+/// use geo::coordinate_position::CoordPos;
+///
+/// let a = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+/// let b = line_string![(x: 1., y: 1.), (x: 2., y: 0.)];
+/// let graphs = relate_graphs(&a.into(), &b.into());
+///
+/// assert!(graphs.intersection_matrix.is_intersects());
+///
+/// // the end of `b` was recorded as an intersection along the edge of `a`, between its endpoints
+/// let intersections = &graphs.a.edges[0].intersections;
+/// assert_eq!(intersections.len(), 3);
+/// assert_eq!(intersections[1].coordinate, Coordinate { x: 1., y: 1. });
+///
+/// // and the edge of `a` is labeled as being in the interior of `a`
+/// let label = graphs.a.edges[0].label;
+/// assert_eq!(label.positions[0].on(), Some(CoordPos::Inside));
+/// ```
+pub fn relate_graphs<F: GeoFloat>(a: &Geometry<F>, b: &Geometry<F>) -> RelateGraphs<F> {
+    let a = GeometryCow::from(a);
+    let b = GeometryCow::from(b);
+    let mut relate_operation = RelateOperation::new(&a, &b);
+    let intersection_matrix = relate_operation.compute_intersection_matrix();
+    let (graph_a, graph_b) = relate_operation.graphs();
+    RelateGraphs {
+        a: GeometryGraphView::new(graph_a),
+        b: GeometryGraphView::new(graph_b),
+        intersection_matrix,
+    }
+}
+
+/// The result of [`relate_graphs`].
+#[derive(Debug)]
+pub struct RelateGraphs<F: GeoFloat> {
+    /// The topology graph of the first geometry
+    pub a: GeometryGraphView<F>,
+    /// The topology graph of the second geometry
+    pub b: GeometryGraphView<F>,
+    /// The same result [`Relate::relate`](super::Relate::relate) would return
+    pub intersection_matrix: IntersectionMatrix,
+}
+
+/// A snapshot of a topology graph's nodes and edges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometryGraphView<F: GeoFloat> {
+    /// Nodes, ordered by coordinate
+    pub nodes: Vec<NodeView<F>>,
+    /// Edges, in insertion order
+    pub edges: Vec<EdgeView<F>>,
+}
+
+impl<F: GeoFloat> GeometryGraphView<F> {
+    fn new(graph: &GeometryGraph<F>) -> Self {
+        GeometryGraphView {
+            nodes: graph.nodes_iter().map(NodeView::new).collect(),
+            edges: graph
+                .edges()
+                .iter()
+                .map(|edge| EdgeView::new(&*edge.borrow()))
+                .collect(),
+        }
+    }
+
+    /// Render the graph's edges and nodes, annotated with their labels, as an SVG image.
+    ///
+    /// Edge intersections are drawn as small unlabeled points along their edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo::relate::debug::geometry_graph;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+    /// let svg = geometry_graph(&line_string.into()).to_svg();
+    ///
+    /// assert_eq!(svg.matches("<polyline").count(), 1);
+    /// // one circle and label per end point node, and one label for the edge
+    /// assert_eq!(svg.matches("<circle").count(), 2);
+    /// assert_eq!(svg.matches("<text").count(), 3);
+    /// ```
+    pub fn to_svg(&self) -> String {
+        let edge_style = SvgStyle::default();
+        let intersection_style = SvgStyle {
+            point_radius: 2.0,
+            ..SvgStyle::default()
+        };
+        let node_style = SvgStyle {
+            fill: "#de2d26".to_string(),
+            fill_opacity: 1.0,
+            stroke: "#a50f15".to_string(),
+            ..SvgStyle::default()
+        };
+
+        let mut document = SvgDocument::new();
+        for edge in &self.edges {
+            document.add_geometry(&LineString(edge.coords.clone()).into(), &edge_style);
+            for intersection in &edge.intersections {
+                document.add_geometry(&Point(intersection.coordinate).into(), &intersection_style);
+            }
+            // label the middle of the middle segment, away from the nodes' labels
+            if edge.coords.len() >= 2 {
+                let middle = (edge.coords.len() - 2) / 2;
+                let (start, end) = (edge.coords[middle], edge.coords[middle + 1]);
+                let two = F::one() + F::one();
+                let label_at = Coordinate {
+                    x: (start.x + end.x) / two,
+                    y: (start.y + end.y) / two,
+                };
+                document.add_label(label_at, &format!("{:?}", edge.label), &edge_style);
+            }
+        }
+        for node in &self.nodes {
+            document.add_geometry(&Point(node.coordinate).into(), &node_style);
+            document.add_label(node.coordinate, &format!("{:?}", node.label), &node_style);
+        }
+        document.render()
+    }
+}
+
+/// A node of a topology graph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeView<F: GeoFloat> {
+    pub coordinate: Coordinate<F>,
+    pub label: LabelView,
+}
+
+impl<F: GeoFloat> NodeView<F> {
+    fn new(node: &CoordNode<F>) -> Self {
+        NodeView {
+            coordinate: *node.coordinate(),
+            label: LabelView::new(node.label()),
+        }
+    }
+}
+
+/// An edge of a topology graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeView<F: GeoFloat> {
+    pub coords: Vec<Coordinate<F>>,
+    pub label: LabelView,
+    /// An edge is isolated if no other edge touches it
+    pub is_isolated: bool,
+    /// The points at which other edges intersect this one, ordered along the edge
+    ///
+    /// Like JTS, relate doesn't record *proper* intersections between the two geometries, where
+    /// their edges cross at a point interior to both segments. Those only contribute to the
+    /// intersection matrix directly.
+    pub intersections: Vec<EdgeIntersectionView<F>>,
+}
+
+impl<F: GeoFloat> EdgeView<F> {
+    fn new(edge: &Edge<F>) -> Self {
+        EdgeView {
+            coords: edge.coords().to_vec(),
+            label: LabelView::new(edge.label()),
+            is_isolated: edge.is_isolated(),
+            intersections: edge
+                .edge_intersections()
+                .iter()
+                .map(EdgeIntersectionView::new)
+                .collect(),
+        }
+    }
+}
+
+/// A point at which an edge is intersected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeIntersectionView<F: GeoFloat> {
+    pub coordinate: Coordinate<F>,
+    /// The index of the edge segment containing the intersection
+    pub segment_index: usize,
+    /// The distance of the intersection from the start of its segment
+    pub distance: F,
+}
+
+impl<F: GeoFloat> EdgeIntersectionView<F> {
+    fn new(edge_intersection: &EdgeIntersection<F>) -> Self {
+        EdgeIntersectionView {
+            coordinate: edge_intersection.coordinate(),
+            segment_index: edge_intersection.segment_index(),
+            distance: edge_intersection.distance(),
+        }
+    }
+}
+
+/// The topological relationship of a graph component to each of the two geometries being related.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelView {
+    /// Indexed by geometry: `0` for the first geometry, `1` for the second
+    pub positions: [TopologyPositionView; 2],
+}
+
+impl LabelView {
+    fn new(label: &Label) -> Self {
+        let position = |geom_index| {
+            if label.is_geom_area(geom_index) {
+                TopologyPositionView::Area {
+                    on: label.position(geom_index, Direction::On),
+                    left: label.position(geom_index, Direction::Left),
+                    right: label.position(geom_index, Direction::Right),
+                }
+            } else {
+                TopologyPositionView::LineOrPoint {
+                    on: label.on_position(geom_index),
+                }
+            }
+        };
+        LabelView {
+            positions: [position(0), position(1)],
+        }
+    }
+}
+
+/// The position of a graph component relative to a single geometry.
+///
+/// Area edges have a position for the edge itself and for each of its sides, while line edges and
+/// nodes only have the former. A position of `None` means the component has no incidence with the
+/// geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TopologyPositionView {
+    Area {
+        on: Option<CoordPos>,
+        left: Option<CoordPos>,
+        right: Option<CoordPos>,
+    },
+    LineOrPoint {
+        on: Option<CoordPos>,
+    },
+}
+
+impl TopologyPositionView {
+    /// The position of the component itself.
+    pub fn on(&self) -> Option<CoordPos> {
+        match self {
+            TopologyPositionView::Area { on, .. } | TopologyPositionView::LineOrPoint { on } => *on,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Polygon, Rect};
+
+    #[test]
+    fn polygon_graph() {
+        let polygon: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let graph = geometry_graph(&polygon.into());
+
+        // rings get a single node at their start
+        assert_eq!(graph.nodes.len(), 1);
+        assert_eq!(graph.nodes[0].coordinate, Coordinate { x: 0., y: 0. });
+        assert_eq!(
+            graph.nodes[0].label.positions[0].on(),
+            Some(CoordPos::OnBoundary)
+        );
+
+        assert_eq!(graph.edges.len(), 1);
+        let edge = &graph.edges[0];
+        assert_eq!(edge.coords.len(), 5);
+        assert!(edge.intersections.is_empty());
+        match edge.label.positions[0] {
+            TopologyPositionView::Area { on, left, right } => {
+                assert_eq!(on, Some(CoordPos::OnBoundary));
+                // the exterior ring is counter-clockwise, so the interior is on the left
+                assert_eq!(left, Some(CoordPos::Inside));
+                assert_eq!(right, Some(CoordPos::Outside));
+            }
+            position => panic!("expected an area position, found {:?}", position),
+        }
+        assert_eq!(
+            edge.label.positions[1],
+            TopologyPositionView::Area {
+                on: None,
+                left: None,
+                right: None
+            }
+        );
+    }
+
+    #[test]
+    fn relate_graphs_of_overlapping_rects() {
+        let a: Geometry<f64> = Rect::new((0., 0.), (2., 2.)).into();
+        let b: Geometry<f64> = Rect::new((1., 0.), (3., 2.)).into();
+        let graphs = relate_graphs(&a, &b);
+
+        assert_eq!(graphs.intersection_matrix, "212111212".parse().unwrap());
+
+        // the corners of `b` which lie on the boundary of `a`
+        let crossings = [Coordinate { x: 1., y: 2. }, Coordinate { x: 1., y: 0. }];
+        for crossing in &crossings {
+            assert!(graphs.a.edges[0]
+                .intersections
+                .iter()
+                .any(|intersection| intersection.coordinate == *crossing));
+        }
+    }
+
+    #[test]
+    fn disjoint_graphs_are_computed() {
+        let a: Geometry<f64> = Rect::new((0., 0.), (1., 1.)).into();
+        let b: Geometry<f64> = Rect::new((2., 2.), (3., 3.)).into();
+        let graphs = relate_graphs(&a, &b);
+
+        assert!(graphs.intersection_matrix.is_disjoint());
+        for graph in &[&graphs.a, &graphs.b] {
+            assert_eq!(graph.nodes.len(), 1);
+            assert!(graph.edges[0].is_isolated);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use super::geomgraph::{Edge, GeometryGraph, RobustLineIntersector};
+use super::relate_operation::{trivial_intersection_matrix, RelateOperation};
+use super::{
+    check_finite, IntersectionMatrix, Relate, RelateCancellable, RelateMany, RelateStats,
+    RelateWithStats, TryRelate,
+};
+use crate::algorithm::cancel::{self, Cancel};
+use crate::algorithm::progress::Progress;
+use crate::{GeoError, GeoFloat, Geometry, GeometryCow, Rect};
+
+/// A geometry prepared to be [related](Relate) to others, which can be edited without preparing
+/// it again from scratch.
+///
+/// Relating a geometry first nodes its topology graph against itself, intersecting every pair
+/// of its segments. An editor which checks the topology of a feature against its neighbours on
+/// each edit would repeat all of that work each time a single vertex moved. Instead,
+/// [`update`](Self::update) finds the segments which have moved, and only intersects those
+/// segments which meet the region they moved within, keeping the rest of the noding as it was.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::coordinate_position::CoordPos;
+/// use geo::algorithm::dimensions::Dimensions;
+/// use geo::algorithm::relate::{EditableGeometry, Relate};
+/// use geo::{polygon, Geometry, Rect};
+///
+/// let parcel = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let neighbour: Geometry<f64> =
+///     polygon![(x: 10., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 10., y: 10.)].into();
+/// let mut editable = EditableGeometry::new(parcel.into());
+/// let matrix = editable.relate(&neighbour);
+/// assert!(matrix.is_intersects());
+/// assert_eq!(matrix.get(CoordPos::Inside, CoordPos::Inside), Dimensions::Empty);
+///
+/// // drag the parcel's corner at (10, 10) into its neighbour
+/// let edited = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 12., y: 10.), (x: 0., y: 10.)];
+/// let dirty_region = editable.update(edited.into());
+/// assert_eq!(
+///     dirty_region,
+///     Some(Rect::new((0., 0.), (12., 10.)))
+/// );
+/// let matrix = editable.relate(&neighbour);
+/// assert_eq!(matrix.get(CoordPos::Inside, CoordPos::Inside), Dimensions::TwoDimensional);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EditableGeometry<F: GeoFloat> {
+    geometry: Geometry<F>,
+    // the edges of the geometry's topology graph, noded against each other
+    edges: Vec<Edge<F>>,
+}
+
+impl<F: GeoFloat> EditableGeometry<F> {
+    pub fn new(geometry: Geometry<F>) -> Self {
+        let edges = {
+            let geometry_cow = GeometryCow::from(&geometry);
+            let mut graph = GeometryGraph::new(0, &geometry_cow);
+            graph.compute_self_nodes(Box::new(RobustLineIntersector::new()));
+            Self::snapshot(&graph)
+        };
+        EditableGeometry { geometry, edges }
+    }
+
+    pub fn geometry(&self) -> &Geometry<F> {
+        &self.geometry
+    }
+
+    /// Replace the geometry with an edited version of it, updating its noding.
+    ///
+    /// Returns the dirty region: the bounding rectangle of the segments which moved, in both
+    /// their old and new positions, within which the noding was recomputed. Or `None`, if no
+    /// segment moved. If vertices were added or removed, the noding is recomputed from scratch,
+    /// and the dirty region covers both versions of the geometry.
+    pub fn update(&mut self, geometry: Geometry<F>) -> Option<Rect<F>> {
+        let (edges, dirty_region) = {
+            let geometry_cow = GeometryCow::from(&geometry);
+            let mut graph = GeometryGraph::new(0, &geometry_cow);
+            let dirty_region = graph.compute_self_nodes_incrementally(
+                &self.edges,
+                Box::new(RobustLineIntersector::new()),
+            );
+            (Self::snapshot(&graph), dirty_region)
+        };
+        self.geometry = geometry;
+        self.edges = edges;
+        dirty_region
+    }
+
+    fn snapshot(graph: &GeometryGraph<F>) -> Vec<Edge<F>> {
+        graph
+            .edges()
+            .iter()
+            .map(|edge| edge.borrow().clone())
+            .collect()
+    }
+}
+
+impl<F: GeoFloat> Relate<F, Geometry<F>> for EditableGeometry<F> {
+    fn relate(&self, other: &Geometry<F>) -> IntersectionMatrix {
+        self.relate_with_stats(other).0
+    }
+}
+
+impl<F: GeoFloat> RelateCancellable<F, Geometry<F>> for EditableGeometry<F> {
+    fn relate_cancellable(
+        &self,
+        other: &Geometry<F>,
+        cancel: &dyn Cancel,
+    ) -> Result<IntersectionMatrix, GeoError> {
+        let geometry_a = GeometryCow::from(&self.geometry);
+        let geometry_b = GeometryCow::from(other);
+        check_finite(&geometry_a, 0)?;
+        check_finite(&geometry_b, 1)?;
+        cancel::check(cancel)?;
+        if let Some(intersection_matrix) = trivial_intersection_matrix(&geometry_a, &geometry_b) {
+            return Ok(intersection_matrix);
+        }
+
+        let mut graph_a = GeometryGraph::new(0, &geometry_a);
+        graph_a.compute_self_nodes_incrementally_cancellable(
+            &self.edges,
+            Box::new(RobustLineIntersector::new()),
+            cancel,
+        )?;
+        RelateOperation::with_graph_a(graph_a, &geometry_b)
+            .compute_intersection_matrix_cancellable(cancel)
+    }
+}
+
+impl<F: GeoFloat> RelateWithStats<F, Geometry<F>> for EditableGeometry<F> {
+    fn relate_with_stats(&self, other: &Geometry<F>) -> (IntersectionMatrix, RelateStats) {
+        let geometry_a = GeometryCow::from(&self.geometry);
+        let geometry_b = GeometryCow::from(other);
+        if let Some(intersection_matrix) = trivial_intersection_matrix(&geometry_a, &geometry_b) {
+            let stats = RelateStats {
+                is_trivial: true,
+                ..RelateStats::default()
+            };
+            return (intersection_matrix, stats);
+        }
+
+        // the graph's structure is rebuilt, but its noding is restored rather than recomputed
+        let mut graph_a = GeometryGraph::new(0, &geometry_a);
+        graph_a
+            .compute_self_nodes_incrementally(&self.edges, Box::new(RobustLineIntersector::new()));
+        let mut relate_operation = RelateOperation::with_graph_a(graph_a, &geometry_b);
+        let intersection_matrix = relate_operation.compute_intersection_matrix();
+        (intersection_matrix, relate_operation.stats())
+    }
+}
+
+impl<F: GeoFloat> TryRelate<F, Geometry<F>> for EditableGeometry<F> {
+    fn try_relate(&self, other: &Geometry<F>) -> Result<IntersectionMatrix, GeoError> {
+        check_finite(&GeometryCow::from(&self.geometry), 0)?;
+        check_finite(&GeometryCow::from(other), 1)?;
+        Ok(self.relate(other))
+    }
+}
+
+impl<F: GeoFloat> RelateMany<F> for EditableGeometry<F> {
+    fn relate_many_with_progress(
+        &self,
+        others: &[Geometry<F>],
+        progress: &mut dyn Progress,
+    ) -> Vec<IntersectionMatrix> {
+        progress.report(0, others.len());
+        others
+            .iter()
+            .enumerate()
+            .map(|(index, other)| {
+                let intersection_matrix = self.relate(other);
+                progress.report(index + 1, others.len());
+                intersection_matrix
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn moving_a_vertex_matches_relating_from_scratch() {
+        let zigzag: Geometry<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 0., y: 2.),
+            (x: 10., y: 4.),
+            (x: 0., y: 6.),
+        ]
+        .into();
+        let others: Vec<Geometry<f64>> = vec![
+            line_string![(x: 5., y: -1.), (x: 5., y: 7.)].into(),
+            polygon![(x: 2., y: 1.), (x: 8., y: 1.), (x: 8., y: 5.), (x: 2., y: 5.)].into(),
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)].into(),
+        ];
+        let mut editable = EditableGeometry::new(zigzag);
+
+        // fold the last segment back across the others, then out of the way again
+        let edits: Vec<Geometry<f64>> = vec![
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 0., y: 2.),
+                (x: 10., y: 4.),
+                (x: 5., y: -2.),
+            ]
+            .into(),
+            line_string![
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 0., y: 2.),
+                (x: 10., y: 4.),
+                (x: 20., y: 4.),
+            ]
+            .into(),
+        ];
+        for edited in edits {
+            assert!(editable.update(edited.clone()).is_some());
+            assert_eq!(editable.geometry(), &edited);
+            // the same noding as from scratch
+            assert_eq!(
+                format!("{:?}", editable.edges),
+                format!("{:?}", EditableGeometry::new(edited.clone()).edges)
+            );
+            for other in &others {
+                assert_eq!(editable.relate(other), edited.relate(other), "{:?}", other);
+            }
+            let unchanged = editable.edges.clone();
+            assert_eq!(editable.update(edited), None);
+            assert_eq!(format!("{:?}", editable.edges), format!("{:?}", unchanged));
+        }
+    }
+
+    #[test]
+    fn adding_a_vertex_recomputes_everything() {
+        let mut editable = EditableGeometry::new(
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)].into(),
+        );
+        let edited: Geometry<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 6., y: 2.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 4.), (x: 1., y: 1.)]],
+        ]
+        .into();
+        assert_eq!(
+            editable.update(edited.clone()),
+            Some(Rect::new((0., 0.), (6., 4.)))
+        );
+        // across the point where the hole touches the exterior
+        let line: Geometry<f64> = line_string![(x: 2., y: 4.), (x: 3., y: 5.)].into();
+        assert_eq!(editable.relate(&line), edited.relate(&line));
+    }
+}
@@ -3,9 +3,17 @@ use super::{
     CoordNode, CoordPos, Direction, Edge, Label, LineIntersector, PlanarGraph, TopologyPosition,
 };
 
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::cancel::{self, Cancel, NeverCancelled};
 use crate::algorithm::dimensions::HasDimensions;
-use crate::{Coordinate, GeoFloat, GeometryCow, Line, LineString, Point, Polygon};
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{
+    Coordinate, GeoError, GeoFloat, GeometryCow, Line, LineString, Point, Polygon, Rect, Triangle,
+};
 
+use geo_types::private_utils::get_bounding_rect;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -24,6 +32,7 @@ use std::rc::Rc;
 ///   - Computing the intersections between the edges and nodes of two different graphs
 ///
 /// GeometryGraph is based on [JTS's `GeomGraph` as of 1.18.1](https://github.com/locationtech/jts/blob/jts-1.18.1/modules/core/src/main/java/org/locationtech/jts/geomgraph/GeometryGraph.java)
+#[derive(Clone)]
 pub(crate) struct GeometryGraph<'a, F>
 where
     F: GeoFloat,
@@ -31,6 +40,7 @@ where
     arg_index: usize,
     parent_geometry: &'a GeometryCow<'a, F>,
     use_boundary_determination_rule: bool,
+    has_computed_self_nodes: bool,
     planar_graph: PlanarGraph<F>,
 }
 
@@ -72,6 +82,7 @@ where
             arg_index,
             parent_geometry,
             use_boundary_determination_rule: true,
+            has_computed_self_nodes: false,
             planar_graph: PlanarGraph::new(),
         };
         graph.add_geometry(parent_geometry);
@@ -82,6 +93,12 @@ where
         self.parent_geometry
     }
 
+    /// Whether this graph has been self-noded, with [`compute_self_nodes`](Self::compute_self_nodes)
+    /// or [`compute_self_nodes_incrementally`](Self::compute_self_nodes_incrementally).
+    pub fn has_computed_self_nodes(&self) -> bool {
+        self.has_computed_self_nodes
+    }
+
     /// Determine whether a component (node or edge) that appears multiple times in elements
     /// of a Multi-Geometry is in the boundary or the interior of the Geometry
     pub fn determine_boundary(boundary_count: usize) -> CoordPos {
@@ -110,18 +127,12 @@ where
         }
         match geometry {
             GeometryCow::Line(line) => self.add_line(line),
-            GeometryCow::Rect(rect) => {
-                // PERF: avoid this conversion/clone?
-                self.add_polygon(&rect.to_polygon());
-            }
+            GeometryCow::Rect(rect) => self.add_rect(rect),
             GeometryCow::Point(point) => {
                 self.add_point(point);
             }
             GeometryCow::Polygon(polygon) => self.add_polygon(polygon),
-            GeometryCow::Triangle(triangle) => {
-                // PERF: avoid this conversion/clone?
-                self.add_polygon(&triangle.to_polygon());
-            }
+            GeometryCow::Triangle(triangle) => self.add_triangle(triangle),
             GeometryCow::LineString(line_string) => self.add_line_string(line_string),
             GeometryCow::MultiPoint(multi_point) => {
                 for point in &multi_point.0 {
@@ -174,8 +185,6 @@ where
             // return a Result?
             warn!("encountered invalid ring, which has undefined results");
         }
-        let first_point = coords[0];
-
         use crate::algorithm::winding_order::{Winding, WindingOrder};
         let (left, right) = match linear_ring.winding_order() {
             Some(WindingOrder::Clockwise) => (cw_left, cw_right),
@@ -186,6 +195,13 @@ where
             }
         };
 
+        self.add_ring_edge(coords, left, right);
+    }
+
+    /// Add a ring, whose `coords` have already been cleaned of repeated coordinates, with the
+    /// given positions on its `left` and `right`.
+    fn add_ring_edge(&mut self, coords: Vec<Coordinate<F>>, left: CoordPos, right: CoordPos) {
+        let first_point = coords[0];
         let edge = Edge::new(
             coords,
             Label::new(
@@ -209,6 +225,51 @@ where
         }
     }
 
+    /// Add a rect's ring directly, without allocating a `Polygon`. A degenerate rect is added as
+    /// the line or point it has collapsed to.
+    fn add_rect(&mut self, rect: &Rect<F>) {
+        let (min, max) = (rect.min(), rect.max());
+        if min == max {
+            self.add_point(&min.into());
+        } else if min.x == max.x || min.y == max.y {
+            self.add_line(&Line::new(min, max));
+        } else {
+            // counter-clockwise, so the interior is on the left
+            let coords = vec![
+                min,
+                Coordinate { x: max.x, y: min.y },
+                max,
+                Coordinate { x: min.x, y: max.y },
+                min,
+            ];
+            self.add_ring_edge(coords, CoordPos::Inside, CoordPos::Outside);
+        }
+    }
+
+    /// Add a triangle's ring directly, without allocating a `Polygon`. A degenerate triangle is
+    /// added as the line between its extreme vertices, or the point it has collapsed to.
+    fn add_triangle(&mut self, triangle: &Triangle<F>) {
+        let coords = vec![triangle.0, triangle.1, triangle.2, triangle.0];
+        match F::Ker::orient2d(triangle.0, triangle.1, triangle.2) {
+            Orientation::CounterClockwise => {
+                self.add_ring_edge(coords, CoordPos::Inside, CoordPos::Outside)
+            }
+            Orientation::Clockwise => {
+                self.add_ring_edge(coords, CoordPos::Outside, CoordPos::Inside)
+            }
+            Orientation::Collinear => {
+                let vertices = triangle.to_array();
+                let start = *vertices.iter().min_by(|a, b| lex_cmp(a, b)).unwrap();
+                let end = *vertices.iter().max_by(|a, b| lex_cmp(a, b)).unwrap();
+                if start == end {
+                    self.add_point(&start.into());
+                } else {
+                    self.add_line(&Line::new(start, end));
+                }
+            }
+        }
+    }
+
     fn add_line_string(&mut self, line_string: &LineString<F>) {
         if line_string.is_empty() {
             return;
@@ -270,35 +331,174 @@ where
         &mut self,
         line_intersector: Box<dyn LineIntersector<F>>,
     ) -> SegmentIntersector<F> {
+        self.compute_self_nodes_cancellable(line_intersector, &NeverCancelled)
+            .expect("never cancelled")
+    }
+
+    /// Compute self-nodes, as [`compute_self_nodes`](Self::compute_self_nodes) does, failing
+    /// with [`GeoError::Cancelled`] once `cancel` is, and leaving the graph without its
+    /// self-nodes.
+    pub fn compute_self_nodes_cancellable(
+        &mut self,
+        line_intersector: Box<dyn LineIntersector<F>>,
+        cancel: &dyn Cancel,
+    ) -> Result<SegmentIntersector<F>, GeoError> {
         let mut segment_intersector = SegmentIntersector::new(line_intersector, true);
 
         let mut edge_set_intersector = Self::create_edge_set_intersector();
 
-        // optimize intersection search for valid Polygons and LinearRings
-        let is_rings = match self.geometry() {
-            GeometryCow::LineString(ls) => ls.is_closed(),
-            GeometryCow::MultiLineString(ls) => ls.is_closed(),
-            GeometryCow::Polygon(_) | GeometryCow::MultiPolygon(_) => true,
-            _ => false,
-        };
-        let check_for_self_intersecting_edges = !is_rings;
+        let check_for_self_intersecting_edges = !self.is_rings();
 
         edge_set_intersector.compute_intersections_within_set(
             self.edges(),
             check_for_self_intersecting_edges,
             &mut segment_intersector,
-        );
+            cancel,
+        )?;
 
         self.add_self_intersection_nodes();
+        self.has_computed_self_nodes = true;
+
+        Ok(segment_intersector)
+    }
 
-        segment_intersector
+    /// Compute self-nodes, as [`compute_self_nodes`](Self::compute_self_nodes) does, reusing the
+    /// self-noded `previous_edges` of a graph of an earlier version of this graph's geometry.
+    ///
+    /// The dirty region is the bounding rectangle of the segments which have moved, in both their
+    /// previous and current positions. Intersections outside it are copied from `previous_edges`,
+    /// since they can only be between segments which haven't moved, and only pairs of segments
+    /// which both meet the dirty region are intersected again. If the geometry has gained or lost
+    /// any vertices, nothing is reused, and the dirty region covers the whole of both versions.
+    ///
+    /// Returns the dirty region, or `None` if no segment has moved.
+    pub fn compute_self_nodes_incrementally(
+        &mut self,
+        previous_edges: &[Edge<F>],
+        line_intersector: Box<dyn LineIntersector<F>>,
+    ) -> Option<Rect<F>> {
+        self.compute_self_nodes_incrementally_cancellable(
+            previous_edges,
+            line_intersector,
+            &NeverCancelled,
+        )
+        .expect("never cancelled")
     }
 
+    /// Compute self-nodes incrementally, as
+    /// [`compute_self_nodes_incrementally`](Self::compute_self_nodes_incrementally) does,
+    /// failing with [`GeoError::Cancelled`] once `cancel` is.
+    pub fn compute_self_nodes_incrementally_cancellable(
+        &mut self,
+        previous_edges: &[Edge<F>],
+        line_intersector: Box<dyn LineIntersector<F>>,
+        cancel: &dyn Cancel,
+    ) -> Result<Option<Rect<F>>, GeoError> {
+        let edges = self.edges().to_vec();
+        let is_same_shape = edges.len() == previous_edges.len()
+            && edges
+                .iter()
+                .zip(previous_edges)
+                .all(|(edge, previous)| edge.borrow().coords().len() == previous.coords().len());
+        if !is_same_shape {
+            self.compute_self_nodes_cancellable(line_intersector, cancel)?;
+            let coords = edges
+                .iter()
+                .flat_map(|edge| edge.borrow().coords().to_vec())
+                .chain(
+                    previous_edges
+                        .iter()
+                        .flat_map(|edge| edge.coords().to_vec()),
+                );
+            return Ok(get_bounding_rect(coords));
+        }
+
+        let moved_coords = edges
+            .iter()
+            .zip(previous_edges)
+            .flat_map(|(edge, previous)| {
+                let edge = edge.borrow();
+                let segments = edge.coords().windows(2).zip(previous.coords().windows(2));
+                segments
+                    .filter(|(segment, previous)| segment != previous)
+                    .flat_map(|(segment, previous)| segment.iter().chain(previous).cloned())
+                    .collect::<Vec<_>>()
+            });
+        let dirty_region = get_bounding_rect(moved_coords);
+
+        for (edge, previous) in edges.iter().zip(previous_edges) {
+            let reused = previous
+                .edge_intersections()
+                .iter()
+                .filter(|edge_intersection| match dirty_region {
+                    Some(dirty_region) => !dirty_region.intersects(&edge_intersection.coordinate()),
+                    None => true,
+                })
+                .cloned();
+            edge.borrow_mut().edge_intersections_mut().extend(reused);
+        }
+
+        if let Some(dirty_region) = dirty_region {
+            let dirty_segments: Vec<Vec<usize>> = edges
+                .iter()
+                .map(|edge| {
+                    let edge = edge.borrow();
+                    (0..edge.coords().len() - 1)
+                        .filter(|i| {
+                            let segment = Line::new(edge.coords()[*i], edge.coords()[*i + 1]);
+                            segment.bounding_rect().intersects(&dirty_region)
+                        })
+                        .collect()
+                })
+                .collect();
+            let mut segment_intersector = SegmentIntersector::new(line_intersector, true);
+            let check_for_self_intersecting_edges = !self.is_rings();
+            for (edge0, segments0) in edges.iter().zip(&dirty_segments) {
+                for (edge1, segments1) in edges.iter().zip(&dirty_segments) {
+                    if !check_for_self_intersecting_edges && edge0.as_ptr() == edge1.as_ptr() {
+                        continue;
+                    }
+                    for segment_index_0 in segments0 {
+                        cancel::check(cancel)?;
+                        for segment_index_1 in segments1 {
+                            segment_intersector.add_intersections(
+                                edge0,
+                                *segment_index_0,
+                                edge1,
+                                *segment_index_1,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        self.add_self_intersection_nodes();
+        self.has_computed_self_nodes = true;
+
+        Ok(dirty_region)
+    }
+
+    /// Whether the edges are all rings, which are assumed not to intersect themselves, as those of
+    /// valid Polygons and LinearRings don't, so that only intersections between edges need to be
+    /// searched for.
+    fn is_rings(&self) -> bool {
+        match self.geometry() {
+            GeometryCow::LineString(ls) => ls.is_closed(),
+            GeometryCow::MultiLineString(ls) => ls.is_closed(),
+            GeometryCow::Polygon(_) | GeometryCow::MultiPolygon(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Compute the intersections between the edges of this graph and those of `other`, failing
+    /// with [`GeoError::Cancelled`] once `cancel` is.
     pub fn compute_edge_intersections(
         &self,
         other: &GeometryGraph<F>,
         line_intersector: Box<dyn LineIntersector<F>>,
-    ) -> SegmentIntersector<F> {
+        cancel: &dyn Cancel,
+    ) -> Result<SegmentIntersector<F>, GeoError> {
         let mut segment_intersector = SegmentIntersector::new(line_intersector, false);
         segment_intersector.set_boundary_nodes(
             self.boundary_nodes().into_iter().cloned().collect(),
@@ -310,9 +510,10 @@ where
             self.edges(),
             other.edges(),
             &mut segment_intersector,
-        );
+            cancel,
+        )?;
 
-        segment_intersector
+        Ok(segment_intersector)
     }
 
     fn insert_point(&mut self, arg_index: usize, coord: Coordinate<F>, position: CoordPos) {
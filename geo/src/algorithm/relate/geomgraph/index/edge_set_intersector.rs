@@ -1,6 +1,7 @@
 use super::super::Edge;
 use super::SegmentIntersector;
-use crate::{Coordinate, GeoFloat};
+use crate::algorithm::cancel::Cancel;
+use crate::{Coordinate, GeoError, GeoFloat};
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -12,12 +13,15 @@ pub(crate) trait EdgeSetIntersector<F: GeoFloat> {
     /// `edges`: the set of edges to check. Mutated to record any intersections.
     /// `check_for_self_intersecting_edges`: if false, an edge is not checked for intersections with itself.
     /// `segment_intersector`: the SegmentIntersector to use
+    /// `cancel`: checked as the segments are intersected, failing with [`GeoError::Cancelled`]
+    /// once it's cancelled
     fn compute_intersections_within_set(
         &mut self,
         edges: &[Rc<RefCell<Edge<F>>>],
         check_for_self_intersecting_edges: bool,
         segment_intersector: &mut SegmentIntersector<F>,
-    );
+        cancel: &dyn Cancel,
+    ) -> Result<(), GeoError>;
 
     /// Compute all intersections between two sets of edges, recording those intersections on
     /// the intersecting edges.
@@ -26,5 +30,6 @@ pub(crate) trait EdgeSetIntersector<F: GeoFloat> {
         edges0: &[Rc<RefCell<Edge<F>>>],
         edges1: &[Rc<RefCell<Edge<F>>>],
         segment_intersector: &mut SegmentIntersector<F>,
-    );
+        cancel: &dyn Cancel,
+    ) -> Result<(), GeoError>;
 }
@@ -15,6 +15,8 @@ where
     proper_intersection_point: Option<Coordinate<F>>,
     has_proper_interior_intersection: bool,
     boundary_nodes: Option<[Vec<CoordNode<F>>; 2]>,
+    num_tests: usize,
+    num_intersections: usize,
 }
 
 impl<F> SegmentIntersector<F>
@@ -36,6 +38,8 @@ where
             has_proper_interior_intersection: false,
             proper_intersection_point: None,
             boundary_nodes: None,
+            num_tests: 0,
+            num_intersections: 0,
         }
     }
     pub fn set_boundary_nodes(
@@ -58,6 +62,17 @@ where
         self.has_proper_interior_intersection
     }
 
+    /// The number of pairs of segments tested for intersection.
+    pub fn num_tests(&self) -> usize {
+        self.num_tests
+    }
+
+    /// The number of pairs of segments found to intersect, including adjacent segments of an
+    /// edge at the vertex they share.
+    pub fn num_intersections(&self) -> usize {
+        self.num_intersections
+    }
+
     /// A trivial intersection is an apparent self-intersection which in fact is simply the point
     /// shared by adjacent line segments.  Note that closed edges require a special check for the
     /// point shared by the beginning and end segments.
@@ -106,6 +121,7 @@ where
         if edge0.as_ptr() == edge1.as_ptr() && segment_index_0 == segment_index_1 {
             return;
         }
+        self.num_tests += 1;
 
         let line_0 = Line::new(
             edge0.borrow().coords()[segment_index_0],
@@ -122,6 +138,7 @@ where
             return;
         }
         let intersection = intersection.unwrap();
+        self.num_intersections += 1;
 
         if !self.edges_are_from_same_geometry {
             edge0.borrow_mut().mark_as_unisolated();
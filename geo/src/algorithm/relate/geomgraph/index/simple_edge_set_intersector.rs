@@ -1,6 +1,7 @@
 use super::super::Edge;
 use super::{EdgeSetIntersector, SegmentIntersector};
-use crate::GeoFloat;
+use crate::algorithm::cancel::{self, Cancel};
+use crate::{GeoError, GeoFloat};
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -17,14 +18,18 @@ impl SimpleEdgeSetIntersector {
         edge0: &Rc<RefCell<Edge<F>>>,
         edge1: &Rc<RefCell<Edge<F>>>,
         segment_intersector: &mut SegmentIntersector<F>,
-    ) {
+        cancel: &dyn Cancel,
+    ) -> Result<(), GeoError> {
         let edge0_coords_len = edge0.borrow().coords().len() - 1;
         let edge1_coords_len = edge1.borrow().coords().len() - 1;
         for i0 in 0..edge0_coords_len {
+            // a single ring may have many thousands of segments, so check once per row
+            cancel::check(cancel)?;
             for i1 in 0..edge1_coords_len {
                 segment_intersector.add_intersections(edge0, i0, edge1, i1);
             }
         }
+        Ok(())
     }
 }
 
@@ -34,14 +39,16 @@ impl<F: GeoFloat> EdgeSetIntersector<F> for SimpleEdgeSetIntersector {
         edges: &[Rc<RefCell<Edge<F>>>],
         check_for_self_intersecting_edges: bool,
         segment_intersector: &mut SegmentIntersector<F>,
-    ) {
+        cancel: &dyn Cancel,
+    ) -> Result<(), GeoError> {
         for edge0 in edges.iter() {
             for edge1 in edges.iter() {
                 if check_for_self_intersecting_edges || edge0.as_ptr() != edge1.as_ptr() {
-                    self.compute_intersects(edge0, edge1, segment_intersector);
+                    self.compute_intersects(edge0, edge1, segment_intersector, cancel)?;
                 }
             }
         }
+        Ok(())
     }
 
     fn compute_intersections_between_sets(
@@ -49,11 +56,13 @@ impl<F: GeoFloat> EdgeSetIntersector<F> for SimpleEdgeSetIntersector {
         edges0: &[Rc<RefCell<Edge<F>>>],
         edges1: &[Rc<RefCell<Edge<F>>>],
         segment_intersector: &mut SegmentIntersector<F>,
-    ) {
+        cancel: &dyn Cancel,
+    ) -> Result<(), GeoError> {
         for edge0 in edges0 {
             for edge1 in edges1 {
-                self.compute_intersects(edge0, edge1, segment_intersector);
+                self.compute_intersects(edge0, edge1, segment_intersector, cancel)?;
             }
         }
+        Ok(())
     }
 }
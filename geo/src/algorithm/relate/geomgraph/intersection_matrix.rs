@@ -20,8 +20,18 @@ use crate::algorithm::dimensions::Dimensions;
 /// - [OGC 06-103r4 OpenGIS Implementation Standard for Geographic information - Simple feature access - Part 1: Common architecture](http://portal.opengeospatial.org/files/?artifact_id=25355), Section 6.1.15 (which provides some further details on certain predicate specifications).
 /// - Wikipedia article on [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM)
 ///
+/// Matrices are formatted, and can be parsed, in the conventional row-major string form, with
+/// one character per entry: `F` for [`Dimensions::Empty`], or the number of dimensions.
+///
+/// ```
+/// use geo::relate::IntersectionMatrix;
+///
+/// let matrix: IntersectionMatrix = "212101212".parse().unwrap();
+/// assert_eq!(matrix.to_string(), "212101212");
+/// ```
+///
 /// This implementation is heavily based on that from the [JTS project](https://github.com/locationtech/jts/blob/master/modules/core/src/main/java/org/locationtech/jts/geom/IntersectionMatrix.java).
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct IntersectionMatrix(LocationArray<LocationArray<Dimensions>>);
 
 /// Helper struct so we can index IntersectionMatrix by CoordPos
@@ -62,6 +72,7 @@ impl<T> std::ops::IndexMut<CoordPos> for LocationArray<T> {
     }
 }
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct InvalidInputError {
     message: String,
@@ -81,6 +92,12 @@ impl std::fmt::Display for InvalidInputError {
 }
 
 impl std::fmt::Debug for IntersectionMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IntersectionMatrix({})", self)
+    }
+}
+
+impl std::fmt::Display for IntersectionMatrix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fn char_for_dim(dim: &Dimensions) -> &'static str {
             match dim {
@@ -97,7 +114,7 @@ impl std::fmt::Debug for IntersectionMatrix {
             .collect::<Vec<&str>>()
             .join("");
 
-        write!(f, "IntersectionMatrix({})", &text)
+        write!(f, "{}", &text)
     }
 }
 
@@ -106,6 +123,61 @@ impl IntersectionMatrix {
         IntersectionMatrix(LocationArray([LocationArray([Dimensions::Empty; 3]); 3]))
     }
 
+    /// The dimensions of the intersection between `position_a` of the first geometry and
+    /// `position_b` of the second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, Polygon};
+    /// use geo::coordinate_position::CoordPos;
+    /// use geo::dimensions::Dimensions;
+    /// use geo::relate::Relate;
+    ///
+    /// let square = Polygon::new(
+    ///     line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+    ///     vec![],
+    /// );
+    /// let line = line_string![(x: 1., y: 1.), (x: 3., y: 1.)];
+    /// let matrix = square.relate(&line);
+    ///
+    /// // the line crosses the square's boundary at a point
+    /// assert_eq!(
+    ///     matrix.get(CoordPos::OnBoundary, CoordPos::Inside),
+    ///     Dimensions::ZeroDimensional
+    /// );
+    /// // the line's start point is inside the square, but its end point isn't
+    /// assert_eq!(matrix.get(CoordPos::Inside, CoordPos::OnBoundary), Dimensions::ZeroDimensional);
+    /// assert_eq!(matrix.get(CoordPos::Outside, CoordPos::OnBoundary), Dimensions::ZeroDimensional);
+    /// ```
+    pub fn get(&self, position_a: CoordPos, position_b: CoordPos) -> Dimensions {
+        self.0[position_a][position_b]
+    }
+
+    /// The matrix relating the second geometry to the first, i.e. with the rows and columns
+    /// swapped.
+    ///
+    /// For any geometries `a` and `b`, `a.relate(&b).transpose() == b.relate(&a)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::relate::IntersectionMatrix;
+    ///
+    /// let matrix: IntersectionMatrix = "212FF1FF2".parse().unwrap();
+    /// assert_eq!(matrix.transpose().to_string(), "2FF1FF212");
+    /// assert_eq!(matrix.transpose().transpose(), matrix);
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut transposed = IntersectionMatrix::empty();
+        for a in &[CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+            for b in &[CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+                transposed.0[*b][*a] = self.0[*a][*b];
+            }
+        }
+        transposed
+    }
+
     /// Set `dimensions` of the cell specified by the positions.
     ///
     /// `position_a`: which position `dimensions` applies to within the first geometry
@@ -230,3 +302,164 @@ impl std::str::FromStr for IntersectionMatrix {
         Ok(im)
     }
 }
+
+/// A named predicate of an [`IntersectionMatrix`], chosen at runtime, such as from a query or the
+/// configuration of a plugin, rather than by calling one of the matrix's `is_*` methods.
+///
+/// Predicates are parsed from their names in lower case, and displayed as them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::relate::{Relate, RelatePredicate};
+/// use geo::{point, polygon, Geometry};
+///
+/// let parcel: Geometry<f64> =
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into();
+/// let well: Geometry<f64> = point!(x: 5., y: 5.).into();
+///
+/// // as chosen by a user
+/// let predicate: RelatePredicate = "contains".parse().unwrap();
+/// assert!(predicate.evaluate(&parcel.relate(&well)));
+/// assert!(!predicate.evaluate(&well.relate(&parcel)));
+/// assert!("touches".parse::<RelatePredicate>().is_err());
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelatePredicate {
+    /// [`IntersectionMatrix::is_intersects`]
+    Intersects,
+    /// [`IntersectionMatrix::is_disjoint`]
+    Disjoint,
+    /// [`IntersectionMatrix::is_contains`]
+    Contains,
+    /// [`IntersectionMatrix::is_within`]
+    Within,
+}
+
+impl RelatePredicate {
+    /// Whether the geometries related by `matrix` satisfy the predicate.
+    pub fn evaluate(self, matrix: &IntersectionMatrix) -> bool {
+        match self {
+            RelatePredicate::Intersects => matrix.is_intersects(),
+            RelatePredicate::Disjoint => matrix.is_disjoint(),
+            RelatePredicate::Contains => matrix.is_contains(),
+            RelatePredicate::Within => matrix.is_within(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            RelatePredicate::Intersects => "intersects",
+            RelatePredicate::Disjoint => "disjoint",
+            RelatePredicate::Contains => "contains",
+            RelatePredicate::Within => "within",
+        }
+    }
+}
+
+impl std::fmt::Display for RelatePredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for RelatePredicate {
+    type Err = InvalidInputError;
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let predicates = [
+            RelatePredicate::Intersects,
+            RelatePredicate::Disjoint,
+            RelatePredicate::Contains,
+            RelatePredicate::Within,
+        ];
+        predicates
+            .iter()
+            .copied()
+            .find(|predicate| predicate.name() == str)
+            .ok_or_else(|| InvalidInputError::new(format!("unknown predicate: {}", str)))
+    }
+}
+
+/// Serialized as its DE-9IM string, such as `"212101212"`.
+#[cfg(feature = "use-serde")]
+impl serde::Serialize for IntersectionMatrix {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialized from its DE-9IM string, such as `"212101212"`.
+#[cfg(feature = "use-serde")]
+impl<'de> serde::Deserialize<'de> for IntersectionMatrix {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::relate::Relate;
+    use crate::{line_string, point, polygon, Geometry};
+
+    #[test]
+    fn display_round_trip() {
+        for text in &["FFFFFFFFF", "212101212", "0F1FF0102", "FF2FF1212"] {
+            let matrix: IntersectionMatrix = text.parse().unwrap();
+            assert_eq!(&matrix.to_string(), text);
+        }
+        assert!("21210121".parse::<IntersectionMatrix>().is_err());
+        assert!("21210121X".parse::<IntersectionMatrix>().is_err());
+    }
+
+    #[test]
+    fn predicate_round_trip() {
+        for name in &["intersects", "disjoint", "contains", "within"] {
+            let predicate: RelatePredicate = name.parse().unwrap();
+            assert_eq!(&predicate.to_string(), name);
+        }
+        assert!("Contains".parse::<RelatePredicate>().is_err());
+    }
+
+    #[cfg(feature = "use-serde")]
+    #[test]
+    fn deserialize_from_string() {
+        use serde::de::value::{Error, StrDeserializer};
+        use serde::Deserialize;
+
+        let deserializer = StrDeserializer::<Error>::new("0F1FF0102");
+        let matrix = IntersectionMatrix::deserialize(deserializer).unwrap();
+        assert_eq!(matrix, "0F1FF0102".parse().unwrap());
+
+        let deserializer = StrDeserializer::<Error>::new("0F1FF010");
+        assert!(IntersectionMatrix::deserialize(deserializer).is_err());
+    }
+
+    #[test]
+    fn transpose_swaps_geometries() {
+        let geometries: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 1.).into(),
+            line_string![(x: 1., y: 1.), (x: 3., y: 1.)].into(),
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into(),
+            polygon![(x: 1., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 1., y: 3.)].into(),
+        ];
+        for a in &geometries {
+            for b in &geometries {
+                let matrix = a.relate(b);
+                assert_eq!(matrix.transpose(), b.relate(a));
+                assert_eq!(
+                    matrix.get(CoordPos::Inside, CoordPos::Outside),
+                    matrix.transpose().get(CoordPos::Outside, CoordPos::Inside)
+                );
+            }
+        }
+    }
+}
@@ -21,6 +21,20 @@ pub(crate) trait NodeFactory<F: GeoFloat> {
     fn create_node(coordinate: Coordinate<F>) -> Self::Node;
 }
 
+impl<F, NF> Clone for NodeMap<F, NF>
+where
+    F: GeoFloat,
+    NF: NodeFactory<F>,
+    NF::Node: Clone,
+{
+    fn clone(&self) -> Self {
+        NodeMap {
+            map: self.map.clone(),
+            _node_factory: PhantomData,
+        }
+    }
+}
+
 impl<F, NF> fmt::Debug for NodeMap<F, NF>
 where
     F: GeoFloat,
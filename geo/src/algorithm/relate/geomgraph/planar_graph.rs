@@ -25,6 +25,21 @@ pub(crate) struct PlanarGraph<F: GeoFloat> {
     edges: Vec<Rc<RefCell<Edge<F>>>>,
 }
 
+/// A deep copy: the edges of the copy are shared with neither the original nor each other, so
+/// that relating the copy to another geometry leaves the original untouched.
+impl<F: GeoFloat> Clone for PlanarGraph<F> {
+    fn clone(&self) -> Self {
+        PlanarGraph {
+            nodes: self.nodes.clone(),
+            edges: self
+                .edges
+                .iter()
+                .map(|edge| Rc::new(RefCell::new(edge.borrow().clone())))
+                .collect(),
+        }
+    }
+}
+
 impl<F: GeoFloat> PlanarGraph<F> {
     pub fn edges(&self) -> &[Rc<RefCell<Edge<F>>>] {
         &self.edges
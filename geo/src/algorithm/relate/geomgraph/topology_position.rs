@@ -80,9 +80,8 @@ impl TopologyPosition {
             (Direction::On, Self::LineOrPoint { on }) | (Direction::On, Self::Area { on, .. }) => {
                 *on
             }
-            (_, Self::LineOrPoint { .. }) => {
-                panic!("LineOrPoint only has a position for `Direction::On`")
-            }
+            // a line or point has no sides, so no position on them
+            (_, Self::LineOrPoint { .. }) => None,
         }
     }
 
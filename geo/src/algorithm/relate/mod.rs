@@ -1,12 +1,22 @@
 pub(crate) use edge_end_builder::EdgeEndBuilder;
-pub use geomgraph::intersection_matrix::IntersectionMatrix;
+pub use editable_geometry::EditableGeometry;
+pub use geomgraph::intersection_matrix::{IntersectionMatrix, RelatePredicate};
+pub(crate) use geomgraph::Quadrant;
+pub use relate_operation::RelateStats;
 
+use crate::algorithm::cancel::Cancel;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::progress::Progress;
 use crate::{
-    GeoFloat, Geometry, GeometryCollection, GeometryCow, Line, LineString, MultiLineString,
-    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    GeoError, GeoFloat, Geometry, GeometryCollection, GeometryCow, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 
+/// Inspect the topology graphs built while relating geometries.
+#[cfg(feature = "debug")]
+pub mod debug;
 mod edge_end_builder;
+mod editable_geometry;
 mod geomgraph;
 mod relate_operation;
 
@@ -52,15 +62,172 @@ mod relate_operation;
 /// assert!(!intersection_matrix.is_within());
 /// ```
 ///
-/// Note: `Relate` must not be called on geometries containing `NaN` coordinates.
+/// Every geometry can be related to a [`Geometry`], and the trait is object safe, so geometries
+/// whose types are only known at runtime can be related through a `dyn Relate<F, Geometry<F>>`,
+/// and tested against a [`RelatePredicate`] chosen at runtime, without matching on their types.
+///
+/// ```
+/// use geo::algorithm::relate::{Relate, RelatePredicate};
+/// use geo::{line_string, point, polygon, Geometry};
+///
+/// let layer: Vec<Box<dyn Relate<f64, Geometry<f64>>>> = vec![
+///     Box::new(point!(x: 5., y: 5.)),
+///     Box::new(line_string![(x: 20., y: 0.), (x: 30., y: 0.)]),
+///     Box::new(polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.)]),
+/// ];
+/// let query: Geometry<f64> =
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into();
+///
+/// let predicate: RelatePredicate = "within".parse().unwrap();
+/// let matches: Vec<bool> = layer
+///     .iter()
+///     .map(|geometry| predicate.evaluate(&geometry.relate(&query)))
+///     .collect();
+/// assert_eq!(matches, vec![true, false, true]);
+/// ```
+///
+/// Note: `relate` must not be called on geometries containing `NaN` coordinates. Where the input
+/// may contain them, use [`TryRelate`].
 pub trait Relate<F, T> {
     fn relate(&self, other: &T) -> IntersectionMatrix;
 }
 
 impl<F: GeoFloat> Relate<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
     fn relate(&self, other: &GeometryCow<F>) -> IntersectionMatrix {
-        let mut relate_computer = relate_operation::RelateOperation::new(self, other);
-        relate_computer.compute_intersection_matrix()
+        relate_operation::relate(self, other)
+    }
+}
+
+/// Topologically relate two geometries, as with [`Relate`], which may contain coordinates that
+/// aren't finite.
+pub trait TryRelate<F, T> {
+    /// Relate the geometries, as with `relate`, but fail with
+    /// [`GeoError::NonFiniteCoordinate`], rather than panic, on the first coordinate of either
+    /// which is `NaN` or infinite. `self` is geometry 0, and `other` geometry 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::relate::TryRelate;
+    /// use geo::{line_string, point, GeoError};
+    ///
+    /// let line = line_string![(x: 0., y: 0.), (x: f64::NAN, y: 1.)];
+    /// assert_eq!(
+    ///     line.try_relate(&point!(x: 0., y: 0.)),
+    ///     Err(GeoError::NonFiniteCoordinate { geometry: 0, index: 1 })
+    /// );
+    /// ```
+    fn try_relate(&self, other: &T) -> Result<IntersectionMatrix, GeoError>;
+}
+
+impl<F: GeoFloat> TryRelate<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
+    fn try_relate(&self, other: &GeometryCow<F>) -> Result<IntersectionMatrix, GeoError> {
+        check_finite(self, 0)?;
+        check_finite(other, 1)?;
+        Ok(self.relate(other))
+    }
+}
+
+/// Topologically relate two geometries, as with [`Relate`], counting the work done.
+pub trait RelateWithStats<F, T> {
+    /// Relate the geometries, as with `relate`, along with [`RelateStats`] counting the work
+    /// done: the edges and nodes of their topology graphs, and the pairs of segments tested for
+    /// intersection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::relate::{Relate, RelateWithStats};
+    /// use geo::{line_string, polygon};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// let line = line_string![(x: -5., y: 5.), (x: 5., y: 5.), (x: 5., y: 15.)];
+    /// let (matrix, stats) = square.relate_with_stats(&line);
+    /// assert_eq!(matrix, square.relate(&line));
+    /// assert!(!stats.is_trivial);
+    /// assert_eq!(stats.edges, 2);
+    /// // the line's two segments against each other, in both orders, and against the square's four
+    /// assert_eq!(stats.self_segment_tests, 2);
+    /// assert_eq!(stats.segment_tests, 2 * 4);
+    /// // meeting each other at the vertex they share, and crossing the square twice
+    /// assert_eq!(stats.intersections, 2 + 2);
+    /// ```
+    fn relate_with_stats(&self, other: &T) -> (IntersectionMatrix, RelateStats);
+}
+
+impl<F: GeoFloat> RelateWithStats<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
+    fn relate_with_stats(&self, other: &GeometryCow<F>) -> (IntersectionMatrix, RelateStats) {
+        relate_operation::relate_with_stats(self, other)
+    }
+}
+
+/// Topologically relate two geometries, as with [`Relate`], abandoning the computation once it's
+/// [cancelled](Cancel).
+pub trait RelateCancellable<F, T> {
+    /// Relate the geometries, as with [`TryRelate::try_relate`], checking `cancel` between the phases of the
+    /// computation and while intersecting their segments, and failing with
+    /// [`GeoError::Cancelled`] once it's cancelled, so that relating pathological geometries
+    /// can be abandoned rather than block a thread indefinitely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::cancel::{CancelToken, Deadline};
+    /// use geo::algorithm::relate::{Relate, RelateCancellable};
+    /// use geo::{line_string, polygon, GeoError};
+    /// use std::time::Duration;
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// let line = line_string![(x: -5., y: 5.), (x: 15., y: 5.)];
+    /// let deadline = Deadline::after(Duration::from_secs(10));
+    /// assert_eq!(square.relate_cancellable(&line, &deadline), Ok(square.relate(&line)));
+    ///
+    /// let token = CancelToken::new();
+    /// token.cancel();
+    /// assert_eq!(square.relate_cancellable(&line, &token), Err(GeoError::Cancelled));
+    /// ```
+    fn relate_cancellable(
+        &self,
+        other: &T,
+        cancel: &dyn Cancel,
+    ) -> Result<IntersectionMatrix, GeoError>;
+}
+
+impl<F: GeoFloat> RelateCancellable<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
+    fn relate_cancellable(
+        &self,
+        other: &GeometryCow<F>,
+        cancel: &dyn Cancel,
+    ) -> Result<IntersectionMatrix, GeoError> {
+        check_finite(self, 0)?;
+        check_finite(other, 1)?;
+        relate_operation::relate_cancellable(self, other, cancel)
+    }
+}
+
+// Fail on the first coordinate of `geometry` which isn't finite.
+pub(crate) fn check_finite<F: GeoFloat>(
+    geometry: &GeometryCow<F>,
+    number: usize,
+) -> Result<(), GeoError> {
+    let mut coords: Box<dyn Iterator<Item = _>> = match geometry {
+        GeometryCow::Point(g) => Box::new(g.coords_iter()),
+        GeometryCow::Line(g) => Box::new(g.coords_iter()),
+        GeometryCow::LineString(g) => Box::new(g.coords_iter()),
+        GeometryCow::Polygon(g) => Box::new(g.coords_iter()),
+        GeometryCow::MultiPoint(g) => Box::new(g.coords_iter()),
+        GeometryCow::MultiLineString(g) => Box::new(g.coords_iter()),
+        GeometryCow::MultiPolygon(g) => Box::new(g.coords_iter()),
+        GeometryCow::GeometryCollection(g) => Box::new(g.coords_iter()),
+        GeometryCow::Rect(g) => Box::new(g.coords_iter()),
+        GeometryCow::Triangle(g) => Box::new(g.coords_iter()),
+    };
+    match coords.position(|coord| !coord.x.is_finite() || !coord.y.is_finite()) {
+        Some(position) => Err(GeoError::NonFiniteCoordinate {
+            geometry: number,
+            index: position,
+        }),
+        None => Ok(()),
     }
 }
 
@@ -75,6 +242,28 @@ macro_rules! relate_impl {
                     GeometryCow::from(self).relate(&GeometryCow::from(other))
                 }
             }
+
+            impl<F: GeoFloat> TryRelate<F, $t> for $k {
+                fn try_relate(&self, other: &$t) -> Result<IntersectionMatrix, GeoError> {
+                    GeometryCow::from(self).try_relate(&GeometryCow::from(other))
+                }
+            }
+
+            impl<F: GeoFloat> RelateWithStats<F, $t> for $k {
+                fn relate_with_stats(&self, other: &$t) -> (IntersectionMatrix, RelateStats) {
+                    GeometryCow::from(self).relate_with_stats(&GeometryCow::from(other))
+                }
+            }
+
+            impl<F: GeoFloat> RelateCancellable<F, $t> for $k {
+                fn relate_cancellable(
+                    &self,
+                    other: &$t,
+                    cancel: &dyn Cancel,
+                ) -> Result<IntersectionMatrix, GeoError> {
+                    GeometryCow::from(self).relate_cancellable(&GeometryCow::from(other), cancel)
+                }
+            }
         )*
     };
 }
@@ -116,3 +305,187 @@ macro_rules! cartesian_pairs_helper {
 // but I don't know that we want to make GeometryCow public (yet?).
 cartesian_pairs!(relate_impl, [Point<F>, Line<F>, LineString<F>, Polygon<F>, MultiPoint<F>, MultiLineString<F>, MultiPolygon<F>, Rect<F>, Triangle<F>, GeometryCollection<F>]);
 relate_impl!(Geometry<F>, Geometry<F>);
+// And every geometry to a `Geometry`, and a `Geometry` to every geometry, so that any geometry can
+// be related through a `dyn Relate<F, Geometry<F>>`.
+relate_impl![
+    (Point<F>, Geometry<F>),
+    (Line<F>, Geometry<F>),
+    (LineString<F>, Geometry<F>),
+    (Polygon<F>, Geometry<F>),
+    (MultiPoint<F>, Geometry<F>),
+    (MultiLineString<F>, Geometry<F>),
+    (MultiPolygon<F>, Geometry<F>),
+    (Rect<F>, Geometry<F>),
+    (Triangle<F>, Geometry<F>),
+    (GeometryCollection<F>, Geometry<F>),
+    (Geometry<F>, Point<F>),
+    (Geometry<F>, Line<F>),
+    (Geometry<F>, LineString<F>),
+    (Geometry<F>, Polygon<F>),
+    (Geometry<F>, MultiPoint<F>),
+    (Geometry<F>, MultiLineString<F>),
+    (Geometry<F>, MultiPolygon<F>),
+    (Geometry<F>, Rect<F>),
+    (Geometry<F>, Triangle<F>),
+    (Geometry<F>, GeometryCollection<F>),
+];
+
+/// Topologically relate a geometry to each of many others, as with [`Relate`].
+///
+/// Relating two geometries builds a topology graph of each, and nodes each graph where its
+/// segments intersect one another. When one geometry is related to many, its graph is built and
+/// self-noded only once, and a copy of it reused for each of the others. Others whose bounding
+/// rectangles don't intersect its own are related without building any graph at all.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Geometry};
+/// use geo::algorithm::relate::{Relate, RelateMany};
+///
+/// let parcel = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let features: Vec<Geometry<f64>> = vec![
+///     point!(x: 5., y: 5.).into(),
+///     polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)].into(),
+///     polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.), (x: 20., y: 10.)].into(),
+/// ];
+///
+/// let matrices = parcel.relate_many(&features);
+/// assert!(matrices[0].is_contains());
+/// assert!(matrices[1].is_intersects() && !matrices[1].is_contains());
+/// assert!(matrices[2].is_disjoint());
+///
+/// // the same as relating the parcel to each feature in turn
+/// let parcel = Geometry::from(parcel);
+/// for (matrix, feature) in matrices.iter().zip(&features) {
+///     assert_eq!(matrix, &parcel.relate(feature));
+/// }
+/// ```
+///
+/// Note: `RelateMany` must not be called on geometries containing `NaN` coordinates.
+pub trait RelateMany<F: GeoFloat> {
+    /// The [`IntersectionMatrix`] of `self` and each of `others`, in order.
+    fn relate_many(&self, others: &[Geometry<F>]) -> Vec<IntersectionMatrix> {
+        self.relate_many_with_progress(others, &mut |_, _| {})
+    }
+
+    /// The [`IntersectionMatrix`] of `self` and each of `others`, in order, reporting to
+    /// `progress` how many of `others` have been related, out of them all.
+    fn relate_many_with_progress(
+        &self,
+        others: &[Geometry<F>],
+        progress: &mut dyn Progress,
+    ) -> Vec<IntersectionMatrix>;
+}
+
+impl<F: GeoFloat> RelateMany<F> for GeometryCow<'_, F> {
+    fn relate_many_with_progress(
+        &self,
+        others: &[Geometry<F>],
+        progress: &mut dyn Progress,
+    ) -> Vec<IntersectionMatrix> {
+        relate_operation::relate_many(self, others, progress)
+    }
+}
+
+macro_rules! relate_many_impl {
+    ($($k:ty),*) => {
+        $(
+            impl<F: GeoFloat> RelateMany<F> for $k {
+                fn relate_many_with_progress(
+                    &self,
+                    others: &[Geometry<F>],
+                    progress: &mut dyn Progress,
+                ) -> Vec<IntersectionMatrix> {
+                    GeometryCow::from(self).relate_many_with_progress(others, progress)
+                }
+            }
+        )*
+    };
+}
+
+relate_many_impl!(Point<F>, Line<F>, LineString<F>, Polygon<F>, MultiPoint<F>, MultiLineString<F>, MultiPolygon<F>, Rect<F>, Triangle<F>, GeometryCollection<F>, Geometry<F>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+    use std::cell::Cell;
+
+    #[test]
+    fn try_relate_fails_on_non_finite_coordinates() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let line = line_string![(x: 5., y: 5.), (x: 15., y: 5.)];
+        assert_eq!(square.try_relate(&line), Ok(square.relate(&line)));
+
+        let collection = GeometryCollection(vec![
+            point!(x: 1., y: 1.).into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: f64::INFINITY)].into(),
+        ]);
+        assert_eq!(
+            square.try_relate(&collection),
+            Err(GeoError::NonFiniteCoordinate {
+                geometry: 1,
+                index: 2
+            })
+        );
+
+        let editable = EditableGeometry::new(Geometry::GeometryCollection(collection));
+        let error = editable.try_relate(&square.into()).unwrap_err();
+        assert_eq!(error.to_string(), "coordinate 2 of geometry 0 isn't finite");
+    }
+
+    #[test]
+    fn relate_cancellable_can_be_cancelled_at_every_check() {
+        let zigzag: Geometry<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 0., y: 2.),
+            (x: 10., y: 4.),
+            (x: 0., y: 6.),
+        ]
+        .into();
+        let square: Geometry<f64> =
+            polygon![(x: 2., y: 1.), (x: 8., y: 1.), (x: 8., y: 5.), (x: 2., y: 5.)].into();
+        let editable = EditableGeometry::new(zigzag.clone());
+
+        // cancelled after the given number of checks
+        let cancel_after = |checks: usize| {
+            let count = Cell::new(0);
+            move || {
+                count.set(count.get() + 1);
+                count.get() > checks
+            }
+        };
+        let never = || false;
+        let matrix = zigzag.relate(&square);
+        assert_eq!(zigzag.relate_cancellable(&square, &never), Ok(matrix));
+        assert_eq!(editable.relate_cancellable(&square, &never), Ok(matrix));
+
+        let count = Cell::new(0);
+        let counting = || {
+            count.set(count.get() + 1);
+            false
+        };
+        zigzag.relate_cancellable(&square, &counting).unwrap();
+        let checks = count.get();
+        // at the start, once per segment of the zigzag as it's noded against itself and the
+        // square, whose ring isn't noded against itself, and twice while labelling
+        assert_eq!(checks, 1 + 4 + 4 + 2);
+        for cancelled_at in 0..checks {
+            assert_eq!(
+                zigzag.relate_cancellable(&square, &cancel_after(cancelled_at)),
+                Err(GeoError::Cancelled)
+            );
+        }
+        assert_eq!(
+            zigzag.relate_cancellable(&square, &cancel_after(checks)),
+            Ok(matrix)
+        );
+        assert_eq!(
+            editable.relate_cancellable(&square, &cancel_after(0)),
+            Err(GeoError::Cancelled)
+        );
+    }
+}
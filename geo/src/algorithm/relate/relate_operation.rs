@@ -1,13 +1,17 @@
 use super::{EdgeEndBuilder, IntersectionMatrix};
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::cancel::{self, Cancel, NeverCancelled};
 use crate::algorithm::coordinate_position::CoordinatePosition;
 use crate::algorithm::dimensions::{Dimensions, HasDimensions};
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::progress::Progress;
 use crate::algorithm::relate::geomgraph::{
     index::SegmentIntersector,
     node_map::{NodeFactory, NodeMap},
     CoordNode, CoordPos, Edge, EdgeEnd, EdgeEndBundleStar, GeometryGraph, LabeledEdgeEndBundleStar,
     RobustLineIntersector,
 };
-use crate::{Coordinate, GeoFloat, GeometryCow};
+use crate::{Coordinate, GeoError, GeoFloat, Geometry, GeometryCow, Point};
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -30,6 +34,231 @@ where
     nodes: NodeMap<F, RelateNodeFactory>,
     line_intersector: RobustLineIntersector,
     isolated_edges: Vec<Rc<RefCell<Edge<F>>>>,
+    stats: RelateStats,
+}
+
+/// Counts of the work done to relate two geometries, returned by
+/// [`RelateWithStats::relate_with_stats`](super::RelateWithStats::relate_with_stats), for finding where the time
+/// goes when relating large geometries, such as long coastlines.
+///
+/// Relating two geometries nodes the segments of each against the others of the same geometry,
+/// then the segments of one against those of the other, testing each pair of segments whose
+/// monotone chains' bounding rectangles intersect, like JTS's `numTests`. Tests made in noding a
+/// geometry which was already noded, by [`RelateMany`](super::RelateMany) or an
+/// [`EditableGeometry`](super::EditableGeometry), aren't counted.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RelateStats {
+    /// Whether the geometries were related without building their topology graphs, because
+    /// their bounding rectangles don't intersect, or one is a point
+    pub is_trivial: bool,
+    /// The number of edges of both geometries' topology graphs: the lines of each, and the rings
+    /// of each polygon
+    pub edges: usize,
+    /// The number of nodes at which the edges were labelled
+    pub nodes: usize,
+    /// The number of pairs of segments of the same geometry tested for intersection
+    pub self_segment_tests: usize,
+    /// The number of pairs of segments, one of each geometry, tested for intersection
+    pub segment_tests: usize,
+    /// The number of those pairs of segments, of either kind, found to intersect
+    pub intersections: usize,
+}
+
+/// Compute the [`IntersectionMatrix`] of `geometry_a` and `geometry_b`, avoiding the construction
+/// of their [`GeometryGraph`]s when the answer can be found more cheaply.
+pub(crate) fn relate<F: GeoFloat>(
+    geometry_a: &GeometryCow<F>,
+    geometry_b: &GeometryCow<F>,
+) -> IntersectionMatrix {
+    if let Some(intersection_matrix) = trivial_intersection_matrix(geometry_a, geometry_b) {
+        return intersection_matrix;
+    }
+
+    RelateOperation::new(geometry_a, geometry_b).compute_intersection_matrix()
+}
+
+/// Compute the [`IntersectionMatrix`] of `geometry_a` and `geometry_b`, as [`relate`] does, with
+/// counts of the work done.
+pub(crate) fn relate_with_stats<F: GeoFloat>(
+    geometry_a: &GeometryCow<F>,
+    geometry_b: &GeometryCow<F>,
+) -> (IntersectionMatrix, RelateStats) {
+    if let Some(intersection_matrix) = trivial_intersection_matrix(geometry_a, geometry_b) {
+        let stats = RelateStats {
+            is_trivial: true,
+            ..RelateStats::default()
+        };
+        return (intersection_matrix, stats);
+    }
+
+    let mut relate_operation = RelateOperation::new(geometry_a, geometry_b);
+    let intersection_matrix = relate_operation.compute_intersection_matrix();
+    (intersection_matrix, relate_operation.stats())
+}
+
+/// Compute the [`IntersectionMatrix`] of `geometry_a` and `geometry_b`, as [`relate`] does,
+/// failing with [`GeoError::Cancelled`] once `cancel` is.
+pub(crate) fn relate_cancellable<F: GeoFloat>(
+    geometry_a: &GeometryCow<F>,
+    geometry_b: &GeometryCow<F>,
+    cancel: &dyn Cancel,
+) -> Result<IntersectionMatrix, GeoError> {
+    cancel::check(cancel)?;
+    if let Some(intersection_matrix) = trivial_intersection_matrix(geometry_a, geometry_b) {
+        return Ok(intersection_matrix);
+    }
+
+    RelateOperation::new(geometry_a, geometry_b).compute_intersection_matrix_cancellable(cancel)
+}
+
+/// Compute the [`IntersectionMatrix`] of `geometry` and each of `others`, reporting the
+/// `others` related to `progress`.
+///
+/// The [`GeometryGraph`] of `geometry` is built and self-noded once, and a copy of it is related
+/// to each of `others` which can't be related more cheaply.
+pub(crate) fn relate_many<F: GeoFloat>(
+    geometry: &GeometryCow<F>,
+    others: &[Geometry<F>],
+    progress: &mut dyn Progress,
+) -> Vec<IntersectionMatrix> {
+    let mut graph: Option<GeometryGraph<F>> = None;
+    let mut intersection_matrices = Vec::with_capacity(others.len());
+    progress.report(0, others.len());
+    for other in others {
+        let other = GeometryCow::from(other);
+        let intersection_matrix = match trivial_intersection_matrix(geometry, &other) {
+            Some(intersection_matrix) => intersection_matrix,
+            None => {
+                let graph = graph.get_or_insert_with(|| {
+                    let mut graph = GeometryGraph::new(0, geometry);
+                    graph.compute_self_nodes(Box::new(RobustLineIntersector::new()));
+                    graph
+                });
+                RelateOperation::with_graph_a(graph.clone(), &other).compute_intersection_matrix()
+            }
+        };
+        intersection_matrices.push(intersection_matrix);
+        progress.report(intersection_matrices.len(), others.len());
+    }
+    intersection_matrices
+}
+
+/// The [`IntersectionMatrix`] of `geometry_a` and `geometry_b`, if it can be found without
+/// building their [`GeometryGraph`]s: when their bounding rectangles don't intersect, or when one
+/// of them is a `Point`.
+pub(crate) fn trivial_intersection_matrix<F: GeoFloat>(
+    geometry_a: &GeometryCow<F>,
+    geometry_b: &GeometryCow<F>,
+) -> Option<IntersectionMatrix> {
+    match (geometry_a.bounding_rect(), geometry_b.bounding_rect()) {
+        (Some(bounding_rect_a), Some(bounding_rect_b))
+            if bounding_rect_a.intersects(&bounding_rect_b) => {}
+        _ => {
+            // since Geometries don't overlap, we can skip most of the work
+            return Some(disjoint_intersection_matrix(geometry_a, geometry_b));
+        }
+    }
+
+    match (geometry_a, geometry_b) {
+        (GeometryCow::Point(point), other) => point_intersection_matrix(point, other),
+        (other, GeometryCow::Point(point)) => {
+            point_intersection_matrix(point, other).map(|matrix| matrix.transpose())
+        }
+        _ => None,
+    }
+}
+
+/// If the Geometries are disjoint, we need to enter their dimension and boundary dimension in
+/// the `Outside` rows in the IM
+fn disjoint_intersection_matrix<F: GeoFloat>(
+    geometry_a: &GeometryCow<F>,
+    geometry_b: &GeometryCow<F>,
+) -> IntersectionMatrix {
+    let mut intersection_matrix = IntersectionMatrix::empty();
+    intersection_matrix.set(
+        CoordPos::Outside,
+        CoordPos::Outside,
+        Dimensions::TwoDimensional,
+    );
+
+    let dimensions = geometry_a.dimensions();
+    if dimensions != Dimensions::Empty {
+        intersection_matrix.set(CoordPos::Inside, CoordPos::Outside, dimensions);
+
+        let boundary_dimensions = geometry_a.boundary_dimensions();
+        if boundary_dimensions != Dimensions::Empty {
+            intersection_matrix.set(CoordPos::OnBoundary, CoordPos::Outside, boundary_dimensions);
+        }
+    }
+
+    let dimensions = geometry_b.dimensions();
+    if dimensions != Dimensions::Empty {
+        intersection_matrix.set(CoordPos::Outside, CoordPos::Inside, dimensions);
+
+        let boundary_dimensions = geometry_b.boundary_dimensions();
+        if boundary_dimensions != Dimensions::Empty {
+            intersection_matrix.set(CoordPos::Outside, CoordPos::OnBoundary, boundary_dimensions);
+        }
+    }
+
+    intersection_matrix
+}
+
+/// Relate a `Point` to another `Point`, or to an area, by locating the point.
+///
+/// Returns `None` for other kinds of geometries, whose interior or boundary the point might
+/// cover entirely.
+fn point_intersection_matrix<F: GeoFloat>(
+    point: &Point<F>,
+    other: &GeometryCow<F>,
+) -> Option<IntersectionMatrix> {
+    let is_point = match other {
+        GeometryCow::Point(_) => true,
+        GeometryCow::Polygon(_)
+        | GeometryCow::MultiPolygon(_)
+        | GeometryCow::Rect(_)
+        | GeometryCow::Triangle(_)
+            if other.dimensions() == Dimensions::TwoDimensional =>
+        {
+            false
+        }
+        _ => return None,
+    };
+
+    let mut intersection_matrix = IntersectionMatrix::empty();
+    intersection_matrix.set(
+        CoordPos::Outside,
+        CoordPos::Outside,
+        Dimensions::TwoDimensional,
+    );
+
+    let position = other.coordinate_position(&point.0);
+    intersection_matrix.set(CoordPos::Inside, position, Dimensions::ZeroDimensional);
+
+    if is_point {
+        if position == CoordPos::Outside {
+            intersection_matrix.set(
+                CoordPos::Outside,
+                CoordPos::Inside,
+                Dimensions::ZeroDimensional,
+            );
+        }
+    } else {
+        // a point can't cover any part of an area's interior or boundary
+        intersection_matrix.set(
+            CoordPos::Outside,
+            CoordPos::Inside,
+            Dimensions::TwoDimensional,
+        );
+        intersection_matrix.set(
+            CoordPos::Outside,
+            CoordPos::OnBoundary,
+            other.boundary_dimensions(),
+        );
+    }
+
+    Some(intersection_matrix)
 }
 
 pub(crate) struct RelateNodeFactory;
@@ -57,10 +286,38 @@ where
             nodes: NodeMap::new(),
             isolated_edges: vec![],
             line_intersector: RobustLineIntersector::new(),
+            stats: RelateStats::default(),
+        }
+    }
+
+    /// Relate the geometry of `graph_a`, which may already have been self-noded, to `geom_b`.
+    pub(crate) fn with_graph_a(
+        graph_a: GeometryGraph<'a, F>,
+        geom_b: &'a GeometryCow<'a, F>,
+    ) -> RelateOperation<'a, F> {
+        Self {
+            graph_a,
+            graph_b: GeometryGraph::new(1, geom_b),
+            nodes: NodeMap::new(),
+            isolated_edges: vec![],
+            line_intersector: RobustLineIntersector::new(),
+            stats: RelateStats::default(),
         }
     }
 
     pub(crate) fn compute_intersection_matrix(&mut self) -> IntersectionMatrix {
+        self.compute_intersection_matrix_cancellable(&NeverCancelled)
+            .expect("never cancelled")
+    }
+
+    /// Compute the [`IntersectionMatrix`], as
+    /// [`compute_intersection_matrix`](Self::compute_intersection_matrix) does, checking
+    /// `cancel` between its phases and while intersecting segments, and failing with
+    /// [`GeoError::Cancelled`] once it's cancelled.
+    pub(crate) fn compute_intersection_matrix_cancellable(
+        &mut self,
+        cancel: &dyn Cancel,
+    ) -> Result<IntersectionMatrix, GeoError> {
         let mut intersection_matrix = IntersectionMatrix::empty();
         // since Geometries are finite and embedded in a 2-D space,
         // the `(Outside, Outside)` element must always be 2-D
@@ -70,32 +327,36 @@ where
             Dimensions::TwoDimensional,
         );
 
-        use crate::algorithm::bounding_rect::BoundingRect;
-        use crate::algorithm::intersects::Intersects;
-        match (
-            self.graph_a.geometry().bounding_rect(),
-            self.graph_b.geometry().bounding_rect(),
-        ) {
-            (Some(bounding_rect_a), Some(bounding_rect_b))
-                if bounding_rect_a.intersects(&bounding_rect_b) => {}
-            _ => {
-                // since Geometries don't overlap, we can skip most of the work
-                self.compute_disjoint_intersection_matrix(&mut intersection_matrix);
-                return intersection_matrix;
-            }
-        }
-
         // Since changes to topology are inspected at nodes, we must crate a node for each
         // intersection.
-        self.graph_a
-            .compute_self_nodes(Box::new(self.line_intersector.clone()));
-        self.graph_b
-            .compute_self_nodes(Box::new(self.line_intersector.clone()));
+        let mut self_intersectors = vec![];
+        if !self.graph_a.has_computed_self_nodes() {
+            self_intersectors.push(
+                self.graph_a.compute_self_nodes_cancellable(
+                    Box::new(self.line_intersector.clone()),
+                    cancel,
+                )?,
+            );
+        }
+        self_intersectors.push(
+            self.graph_b
+                .compute_self_nodes_cancellable(Box::new(self.line_intersector.clone()), cancel)?,
+        );
 
         // compute intersections between edges of the two input geometries
-        let segment_intersector = self
-            .graph_a
-            .compute_edge_intersections(&self.graph_b, Box::new(self.line_intersector.clone()));
+        let segment_intersector = self.graph_a.compute_edge_intersections(
+            &self.graph_b,
+            Box::new(self.line_intersector.clone()),
+            cancel,
+        )?;
+
+        self.stats.edges = self.graph_a.edges().len() + self.graph_b.edges().len();
+        for self_intersector in &self_intersectors {
+            self.stats.self_segment_tests += self_intersector.num_tests();
+            self.stats.intersections += self_intersector.num_intersections();
+        }
+        self.stats.segment_tests = segment_intersector.num_tests();
+        self.stats.intersections += segment_intersector.num_intersections();
 
         self.compute_intersection_nodes(0);
         self.compute_intersection_nodes(1);
@@ -115,13 +376,16 @@ where
         self.insert_edge_ends(edge_ends_a);
         let edge_ends_b: Vec<_> = edge_end_builder.compute_ends_for_edges(self.graph_b.edges());
         self.insert_edge_ends(edge_ends_b);
+        cancel::check(cancel)?;
 
         let mut nodes = NodeMap::new();
         std::mem::swap(&mut self.nodes, &mut nodes);
-        let labeled_node_edges = nodes
+        let labeled_node_edges: Vec<_> = nodes
             .into_iter()
             .map(|(node, edges)| (node, edges.into_labeled(&self.graph_a, &self.graph_b)))
             .collect();
+        self.stats.nodes = labeled_node_edges.len();
+        cancel::check(cancel)?;
 
         // Compute the labeling for "isolated" components
         //
@@ -142,7 +406,18 @@ where
         );
         self.update_intersection_matrix(labeled_node_edges, &mut intersection_matrix);
 
-        intersection_matrix
+        Ok(intersection_matrix)
+    }
+
+    /// Counts of the work done by [`compute_intersection_matrix`](Self::compute_intersection_matrix).
+    pub(crate) fn stats(&self) -> RelateStats {
+        self.stats
+    }
+
+    /// The topology graphs of both input geometries.
+    #[cfg(feature = "debug")]
+    pub(crate) fn graphs(&self) -> (&GeometryGraph<'a, F>, &GeometryGraph<'a, F>) {
+        (&self.graph_a, &self.graph_b)
     }
 
     fn insert_edge_ends(&mut self, edge_ends: Vec<EdgeEnd<F>>) {
@@ -293,44 +568,6 @@ where
         }
     }
 
-    /// If the Geometries are disjoint, we need to enter their dimension and boundary dimension in
-    /// the `Outside` rows in the IM
-    fn compute_disjoint_intersection_matrix(&self, intersection_matrix: &mut IntersectionMatrix) {
-        {
-            let geometry_a = self.graph_a.geometry();
-            let dimensions = geometry_a.dimensions();
-            if dimensions != Dimensions::Empty {
-                intersection_matrix.set(CoordPos::Inside, CoordPos::Outside, dimensions);
-
-                let boundary_dimensions = geometry_a.boundary_dimensions();
-                if boundary_dimensions != Dimensions::Empty {
-                    intersection_matrix.set(
-                        CoordPos::OnBoundary,
-                        CoordPos::Outside,
-                        boundary_dimensions,
-                    );
-                }
-            }
-        }
-
-        {
-            let geometry_b = self.graph_b.geometry();
-            let dimensions = geometry_b.dimensions();
-            if dimensions != Dimensions::Empty {
-                intersection_matrix.set(CoordPos::Outside, CoordPos::Inside, dimensions);
-
-                let boundary_dimensions = geometry_b.boundary_dimensions();
-                if boundary_dimensions != Dimensions::Empty {
-                    intersection_matrix.set(
-                        CoordPos::Outside,
-                        CoordPos::OnBoundary,
-                        boundary_dimensions,
-                    );
-                }
-            }
-        }
-    }
-
     fn update_intersection_matrix(
         &self,
         labeled_node_edges: Vec<(CoordNode<F>, LabeledEdgeEndBundleStar<F>)>,
@@ -432,7 +669,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use geo_types::{polygon, Geometry};
+    use geo_types::{polygon, Geometry, LineString, Polygon};
     use std::str::FromStr;
 
     #[test]
@@ -524,4 +761,216 @@ mod test {
             IntersectionMatrix::from_str("212101212").unwrap()
         );
     }
+
+    #[test]
+    fn test_shortcuts_match_full_computation() {
+        use geo_types::{point, Rect, Triangle};
+
+        let polygon_with_hole: Geometry<f64> = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (4., 4.),
+                (6., 4.),
+                (6., 6.),
+                (4., 6.),
+                (4., 4.),
+            ])],
+        )
+        .into();
+        let areas: Vec<Geometry<f64>> = vec![
+            polygon_with_hole,
+            Rect::new((0., 0.), (10., 10.)).into(),
+            Triangle((0., 0.).into(), (10., 0.).into(), (0., 10.).into()).into(),
+        ];
+        let points: Vec<Geometry<f64>> = vec![
+            point!(x: 2., y: 2.).into(),
+            point!(x: 5., y: 5.).into(),
+            point!(x: 4., y: 5.).into(),
+            point!(x: 0., y: 0.).into(),
+            point!(x: 10., y: 5.).into(),
+            point!(x: 12., y: 5.).into(),
+            point!(x: 20., y: 20.).into(),
+        ];
+
+        let pairs = points
+            .iter()
+            .flat_map(|point| areas.iter().chain(&points).map(move |other| (point, other)));
+        for (a, b) in pairs {
+            for (a, b) in &[(a, b), (b, a)] {
+                let a = GeometryCow::from(*a);
+                let b = GeometryCow::from(*b);
+                assert_eq!(
+                    relate(&a, &b),
+                    RelateOperation::new(&a, &b).compute_intersection_matrix(),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rect_and_triangle_match_polygons() {
+        use geo_types::{line_string, point, Line, Rect, Triangle};
+
+        let others: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 1.).into(),
+            Line::from([(0., 0.), (10., 0.)]).into(),
+            line_string![(x: -5., y: 5.), (x: 5., y: 5.), (x: 5., y: 15.)].into(),
+            polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)].into(),
+            Rect::new((2., 2.), (4., 4.)).into(),
+            Triangle((10., 0.).into(), (10., 10.).into(), (0., 10.).into()).into(),
+        ];
+        let areas: Vec<(Geometry<f64>, Geometry<f64>)> = vec![
+            (
+                Rect::new((0., 0.), (10., 10.)).into(),
+                Rect::new((0., 0.), (10., 10.)).to_polygon().into(),
+            ),
+            (
+                Triangle((0., 0.).into(), (10., 0.).into(), (0., 10.).into()).into(),
+                Triangle((0., 0.).into(), (10., 0.).into(), (0., 10.).into())
+                    .to_polygon()
+                    .into(),
+            ),
+            // clockwise
+            (
+                Triangle((0., 0.).into(), (0., 10.).into(), (10., 0.).into()).into(),
+                Triangle((0., 0.).into(), (0., 10.).into(), (10., 0.).into())
+                    .to_polygon()
+                    .into(),
+            ),
+        ];
+
+        for (area, polygon) in &areas {
+            for other in &others {
+                assert_eq!(
+                    relate(&area.into(), &other.into()),
+                    relate(&polygon.into(), &other.into()),
+                    "{:?} {:?}",
+                    area,
+                    other
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_degenerate_rect_and_triangle_relate_as_lines_and_points() {
+        use geo_types::{point, Line, Point, Rect, Triangle};
+
+        let line: Geometry<f64> = Line::from([(0., 0.), (0., 10.)]).into();
+        let point: Geometry<f64> = point!(x: 0., y: 5.).into();
+        let others: Vec<Geometry<f64>> = vec![
+            point.clone(),
+            point!(x: 0., y: 0.).into(),
+            Line::from([(-5., 5.), (5., 5.)]).into(),
+            Line::from([(0., 5.), (0., 15.)]).into(),
+            polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.), (x: 0., y: 5.)].into(),
+        ];
+        let degenerates: Vec<(Geometry<f64>, Geometry<f64>)> = vec![
+            (Rect::new((0., 0.), (0., 10.)).into(), line.clone()),
+            (
+                Triangle((0., 10.).into(), (0., 0.).into(), (0., 4.).into()).into(),
+                line,
+            ),
+            (Rect::new((0., 5.), (0., 5.)).into(), point.clone()),
+            (
+                Triangle((0., 5.).into(), (0., 5.).into(), (0., 5.).into()).into(),
+                Point::new(0., 5.).into(),
+            ),
+        ];
+
+        for (degenerate, equivalent) in &degenerates {
+            for other in &others {
+                let a = GeometryCow::from(degenerate);
+                let b = GeometryCow::from(other);
+                let expected =
+                    RelateOperation::new(&equivalent.into(), &b).compute_intersection_matrix();
+                assert_eq!(
+                    RelateOperation::new(&a, &b).compute_intersection_matrix(),
+                    expected,
+                    "{:?} {:?}",
+                    degenerate,
+                    other
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_relate_many_matches_relate() {
+        use geo_types::{line_string, point, MultiPoint, Rect};
+
+        // a bowtie, whose graph must be self-noded where it crosses itself
+        let bowtie: Geometry<f64> =
+            line_string![(x: 0., y: 0.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 10.)].into();
+        let square: Geometry<f64> = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![],
+        )
+        .into();
+        let others: Vec<Geometry<f64>> = vec![
+            square.clone(),
+            bowtie.clone(),
+            Rect::new((5., 0.), (15., 10.)).into(),
+            line_string![(x: 5., y: -5.), (x: 5., y: 15.)].into(),
+            line_string![(x: 0., y: 5.), (x: 5., y: 5.)].into(),
+            MultiPoint(vec![point!(x: 5., y: 5.), point!(x: 2., y: 8.)]).into(),
+            point!(x: 5., y: 5.).into(),
+            point!(x: 20., y: 20.).into(),
+            Rect::new((20., 20.), (30., 30.)).into(),
+            // relating to the same geometry twice mustn't depend on the first time
+            Rect::new((5., 0.), (15., 10.)).into(),
+        ];
+        for geometry in &[bowtie, square] {
+            let a = GeometryCow::from(geometry);
+            let matrices = relate_many(&a, &others, &mut |_, _| {});
+            assert_eq!(matrices.len(), others.len());
+            for (matrix, other) in matrices.iter().zip(&others) {
+                assert_eq!(
+                    matrix,
+                    &relate(&a, &GeometryCow::from(other)),
+                    "{:?}",
+                    other
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_relate_stats() {
+        use geo_types::{line_string, point};
+
+        let bowtie: Geometry<f64> =
+            line_string![(x: 0., y: 0.), (x: 10., y: 10.), (x: 10., y: 0.), (x: 0., y: 10.)].into();
+        let line: Geometry<f64> = line_string![(x: 5., y: -5.), (x: 5., y: 15.)].into();
+        let (a, b) = (GeometryCow::from(&bowtie), GeometryCow::from(&line));
+        let (matrix, stats) = relate_with_stats(&a, &b);
+        assert_eq!(matrix, relate(&a, &b));
+        assert!(!stats.is_trivial);
+        // each ordered pair of the bowtie's three segments
+        assert_eq!(stats.self_segment_tests, 3 * 2);
+        assert_eq!(stats.segment_tests, 3);
+        // adjacent segments twice each, the bowtie's crossing twice, and the line crossing both
+        // of the segments there
+        assert_eq!(stats.intersections, 4 + 2 + 2);
+
+        // a graph already self-noded isn't noded, or counted, again
+        let mut graph_a = GeometryGraph::new(0, &a);
+        graph_a.compute_self_nodes(Box::new(RobustLineIntersector::new()));
+        let mut relate_operation = RelateOperation::with_graph_a(graph_a, &b);
+        relate_operation.compute_intersection_matrix();
+        assert_eq!(relate_operation.stats().self_segment_tests, 0);
+
+        let point: Geometry<f64> = point!(x: 20., y: 20.).into();
+        let (_, stats) = relate_with_stats(&a, &GeometryCow::from(&point));
+        assert_eq!(
+            stats,
+            RelateStats {
+                is_trivial: true,
+                ..RelateStats::default()
+            }
+        );
+    }
 }
@@ -0,0 +1,234 @@
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::{Coordinate, GeoFloat, LineString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Resample a line to evenly spaced points along it.
+///
+/// Lines digitized at uneven rates, such as GPS traces, make poor inputs to vertex-based
+/// comparisons like [`FrechetDistance`] and [`HausdorffDistance`]. Resampling both lines at the
+/// same spacing first compares their shapes rather than where their vertices happened to fall.
+///
+/// The new points are interpolated linearly along the segments of the line, so they cut its
+/// corners. To resample a track at fixed intervals of time, or of any other measure, see
+/// [`MeasuredLineString::resample_by_measure`].
+///
+/// [`FrechetDistance`]: crate::algorithm::frechet_distance::FrechetDistance
+/// [`HausdorffDistance`]: crate::algorithm::hausdorff_distance::HausdorffDistance
+/// [`MeasuredLineString::resample_by_measure`]: crate::algorithm::measured_line_string::MeasuredLineString::resample_by_measure
+pub trait Resample<T: GeoFloat> {
+    /// Points every `spacing` along the line from its start, and its end.
+    ///
+    /// The last segment is shorter than `spacing`, unless the length of the line is a multiple
+    /// of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spacing` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::resample::Resample;
+    /// use geo::line_string;
+    ///
+    /// let line = line_string![(x: 0., y: 0.), (x: 0., y: 3.), (x: 2., y: 3.)];
+    /// assert_eq!(
+    ///     line.resample_by_distance(2.),
+    ///     line_string![(x: 0., y: 0.), (x: 0., y: 2.), (x: 1., y: 3.), (x: 2., y: 3.)]
+    /// );
+    /// ```
+    fn resample_by_distance(&self, spacing: T) -> LineString<T>;
+
+    /// `count` points evenly spaced along the line, including both of its ends.
+    ///
+    /// A `count` of one gives the start of the line, and of zero, or resampling an empty line,
+    /// an empty line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::resample::Resample;
+    /// use geo::line_string;
+    ///
+    /// let line = line_string![(x: 0., y: 0.), (x: 0., y: 3.), (x: 3., y: 3.)];
+    /// assert_eq!(
+    ///     line.resample_by_count(3),
+    ///     line_string![(x: 0., y: 0.), (x: 0., y: 3.), (x: 3., y: 3.)]
+    /// );
+    /// assert_eq!(
+    ///     line.resample_by_count(4),
+    ///     line_string![(x: 0., y: 0.), (x: 0., y: 2.), (x: 1., y: 3.), (x: 3., y: 3.)]
+    /// );
+    /// ```
+    fn resample_by_count(&self, count: usize) -> LineString<T>;
+}
+
+impl<T: GeoFloat> Resample<T> for LineString<T> {
+    fn resample_by_distance(&self, spacing: T) -> LineString<T> {
+        assert!(spacing > T::zero(), "spacing must be positive");
+        if self.0.is_empty() {
+            return LineString(vec![]);
+        }
+        LineString(resample_at_spacing(&self.0, spacing).1)
+    }
+
+    fn resample_by_count(&self, count: usize) -> LineString<T> {
+        let (first, last) = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) if count > 0 => (*first, *last),
+            _ => return LineString(vec![]),
+        };
+        if count == 1 {
+            return LineString(vec![first]);
+        }
+
+        let length = self.0.windows(2).fold(T::zero(), |length, segment| {
+            length + segment[0].euclidean_distance(&segment[1])
+        });
+        let intervals = T::from(count - 1).unwrap();
+        let mut coords = points_at_distances(
+            &self.0,
+            (0..count - 1).map(|i| length * T::from(i).unwrap() / intervals),
+        );
+        coords.push(last);
+        LineString(coords)
+    }
+}
+
+// The points at each of `distances` along the line through `coords`, which must not be empty.
+// The distances must not decrease, and are clamped to the ends of the line.
+fn points_at_distances<T, I>(coords: &[Coordinate<T>], distances: I) -> Vec<Coordinate<T>>
+where
+    T: GeoFloat,
+    I: IntoIterator<Item = T>,
+{
+    let mut points = vec![];
+    // the segment from `coords[index]`, and its distance along the line
+    let mut index = 0;
+    let mut travelled = T::zero();
+    for target in distances {
+        while index + 2 < coords.len() {
+            let length = coords[index].euclidean_distance(&coords[index + 1]);
+            if travelled + length >= target {
+                break;
+            }
+            travelled = travelled + length;
+            index += 1;
+        }
+
+        let start = coords[index];
+        let end = *coords.get(index + 1).unwrap_or(&start);
+        let length = start.euclidean_distance(&end);
+        if length == T::zero() {
+            points.push(start);
+        } else {
+            let fraction = ((target - travelled) / length).max(T::zero()).min(T::one());
+            points.push(start + (end - start) * fraction);
+        }
+    }
+    points
+}
+
+// The points every `spacing` along the line through `coords`, which must not be empty, and at
+// its end, with their distances along the line.
+pub(crate) fn resample_at_spacing<T: GeoFloat>(
+    coords: &[Coordinate<T>],
+    spacing: T,
+) -> (Vec<T>, Vec<Coordinate<T>>) {
+    let mut distances = vec![T::zero()];
+    let mut samples = vec![coords[0]];
+    let mut travelled = T::zero();
+    let mut count = 1;
+    for segment in coords.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let length = start.euclidean_distance(&end);
+        if length == T::zero() {
+            continue;
+        }
+        loop {
+            let next = spacing * T::from(count).unwrap();
+            if next > travelled + length {
+                break;
+            }
+            let fraction = (next - travelled) / length;
+            distances.push(next);
+            samples.push(start + (end - start) * fraction);
+            count += 1;
+        }
+        travelled = travelled + length;
+    }
+
+    if distances[distances.len() - 1] < travelled {
+        distances.push(travelled);
+        samples.push(coords[coords.len() - 1]);
+    }
+    (distances, samples)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn resample_by_distance() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(
+            line.resample_by_distance(2.),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)]
+        );
+        assert_eq!(
+            line.resample_by_distance(5.),
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)]
+        );
+
+        // repeated coordinates are passed over
+        let line: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 0.), (x: 1., y: 2.)];
+        assert_eq!(
+            line.resample_by_distance(1.5),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.5), (x: 1., y: 2.)]
+        );
+
+        let point: LineString<f64> = line_string![(x: 1., y: 1.)];
+        assert_eq!(point.resample_by_distance(1.), point);
+        let empty: LineString<f64> = line_string![];
+        assert_eq!(empty.resample_by_distance(1.), empty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn resample_by_distance_needs_a_positive_spacing() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 4., y: 0.)];
+        line.resample_by_distance(0.);
+    }
+
+    #[test]
+    fn resample_by_count() {
+        let line: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 3., y: 1.),
+        ];
+        let resampled = line.resample_by_count(9);
+        assert_eq!(resampled.0.len(), 9);
+        assert_eq!(resampled.0[0], line.0[0]);
+        assert_eq!(resampled.0[8], line.0[4]);
+        // every half unit along the line
+        for segment in resampled.lines() {
+            assert_relative_eq!(segment.start.euclidean_distance(&segment.end), 0.5);
+        }
+        assert_eq!(resampled.0[2], Coordinate { x: 1., y: 0. });
+
+        assert_eq!(line.resample_by_count(1), line_string![(x: 0., y: 0.)]);
+        assert!(line.resample_by_count(0).0.is_empty());
+
+        let point: LineString<f64> = line_string![(x: 1., y: 1.)];
+        assert_eq!(
+            point.resample_by_count(3).0,
+            vec![Coordinate { x: 1., y: 1. }; 3]
+        );
+    }
+}
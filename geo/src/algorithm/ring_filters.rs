@@ -0,0 +1,155 @@
+use crate::algorithm::area::get_linestring_area;
+use crate::{CoordFloat, MultiPolygon, Polygon};
+use std::cmp::Ordering;
+
+/// Drop the interior rings of a polygon enclosing less than a given area.
+///
+/// Removing small holes, like courtyards in a building footprint, is a common step in
+/// generalizing a map for a smaller scale.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::ring_filters::RemoveSmallHoles;
+/// use geo::polygon;
+///
+/// let polygon = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         [(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)],
+///         [(x: 4., y: 4.), (x: 8., y: 4.), (x: 8., y: 8.), (x: 4., y: 8.)],
+///     ],
+/// ];
+///
+/// let cleaned = polygon.remove_small_holes(2.);
+/// assert_eq!(cleaned.exterior(), polygon.exterior());
+/// assert_eq!(cleaned.interiors(), &polygon.interiors()[1..]);
+/// ```
+pub trait RemoveSmallHoles<T> {
+    /// A copy of `self` without the interior rings whose area is less than `min_area`.
+    fn remove_small_holes(&self, min_area: T) -> Self;
+}
+
+impl<T: CoordFloat> RemoveSmallHoles<T> for Polygon<T> {
+    fn remove_small_holes(&self, min_area: T) -> Self {
+        let interiors = self
+            .interiors()
+            .iter()
+            .filter(|interior| get_linestring_area(interior).abs() >= min_area)
+            .cloned()
+            .collect();
+        Polygon::new(self.exterior().clone(), interiors)
+    }
+}
+
+impl<T: CoordFloat> RemoveSmallHoles<T> for MultiPolygon<T> {
+    fn remove_small_holes(&self, min_area: T) -> Self {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| polygon.remove_small_holes(min_area))
+                .collect(),
+        )
+    }
+}
+
+/// Keep only the polygons of a `MultiPolygon` with the largest exterior rings.
+///
+/// Polygons are compared by the area enclosed by their exterior ring, ignoring holes, so an
+/// island keeps its place however many lakes it has. Keeping only the largest is a common step
+/// in generalizing a map for a smaller scale, such as dropping the small islands off a coast.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::ring_filters::KeepLargestShells;
+/// use geo::{polygon, MultiPolygon};
+///
+/// let small = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+/// let large = polygon![(x: 5., y: 0.), (x: 9., y: 0.), (x: 9., y: 4.), (x: 5., y: 4.)];
+/// let medium = polygon![(x: 0., y: 5.), (x: 2., y: 5.), (x: 2., y: 7.), (x: 0., y: 7.)];
+/// let islands = MultiPolygon(vec![small.clone(), large.clone(), medium.clone()]);
+///
+/// assert_eq!(islands.keep_largest_shells(1), MultiPolygon(vec![large.clone()]));
+/// // the polygons which are kept stay in their original order
+/// assert_eq!(islands.keep_largest_shells(2), MultiPolygon(vec![large, medium]));
+/// ```
+pub trait KeepLargestShells {
+    /// A copy of `self` with only its `n` polygons whose exterior rings enclose the largest
+    /// areas, in their original order. Of polygons with equal areas, the first are kept.
+    fn keep_largest_shells(&self, n: usize) -> Self;
+}
+
+impl<T: CoordFloat> KeepLargestShells for MultiPolygon<T> {
+    fn keep_largest_shells(&self, n: usize) -> Self {
+        let areas: Vec<T> = self
+            .0
+            .iter()
+            .map(|polygon| get_linestring_area(polygon.exterior()).abs())
+            .collect();
+        let mut indices: Vec<usize> = (0..self.0.len()).collect();
+        // a stable sort, so that the first of equal polygons are kept
+        indices.sort_by(|a, b| areas[*b].partial_cmp(&areas[*a]).unwrap_or(Ordering::Equal));
+        indices.truncate(n);
+        indices.sort_unstable();
+        MultiPolygon(indices.into_iter().map(|i| self.0[i].clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::polygon;
+
+    #[test]
+    fn remove_small_holes() {
+        // a clockwise hole of area 1, and a counter-clockwise hole of area 4
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)],
+                [(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)],
+            ],
+        ];
+        assert_eq!(polygon.remove_small_holes(1.), polygon);
+        assert_eq!(polygon.remove_small_holes(1.5).interiors().len(), 1);
+        assert_eq!(
+            polygon.remove_small_holes(1.5).interiors()[0],
+            polygon.interiors()[1]
+        );
+        assert!(polygon.remove_small_holes(4.5).interiors().is_empty());
+
+        let multi_polygon = MultiPolygon(vec![polygon.clone(), polygon]);
+        let cleaned = multi_polygon.remove_small_holes(4.5);
+        assert!(cleaned
+            .0
+            .iter()
+            .all(|polygon| polygon.interiors().is_empty()));
+        assert_eq!(cleaned.0.len(), 2);
+    }
+
+    #[test]
+    fn keep_largest_shells() {
+        let square = |x: f64, size: f64| {
+            let corner = x + size;
+            polygon![(x: x, y: 0.), (x: corner, y: 0.), (x: corner, y: size), (x: x, y: size)]
+        };
+        // the holes of the first polygon don't count against it
+        let holey = polygon![
+            exterior: [(x: 0., y: 0.), (x: 3., y: 0.), (x: 3., y: 3.), (x: 0., y: 3.)],
+            interiors: [[(x: 0.5, y: 0.5), (x: 2.5, y: 0.5), (x: 2.5, y: 2.5), (x: 0.5, y: 2.5)]],
+        ];
+        let multi_polygon = MultiPolygon(vec![
+            holey.clone(),
+            square(10., 2.),
+            square(20., 2.),
+            square(30., 1.),
+        ]);
+        assert_eq!(
+            multi_polygon.keep_largest_shells(2),
+            MultiPolygon(vec![holey, square(10., 2.)])
+        );
+        assert_eq!(multi_polygon.keep_largest_shells(10), multi_polygon);
+        assert!(multi_polygon.keep_largest_shells(0).0.is_empty());
+    }
+}
@@ -0,0 +1,469 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::convex_decomposition::{ear_clip, ring_coords};
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::algorithm::winding_order::WindingOrder;
+use crate::{
+    Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon, Rect, Triangle,
+};
+use rand::Rng;
+
+/// Draw uniformly distributed random points from a geometry.
+///
+/// Areal geometries are sampled uniformly by area, and linear geometries uniformly by length.
+/// Points are drawn independently, using the supplied random number generator so that results
+/// can be reproduced by seeding it.
+///
+/// Geometries without area (or, for linear geometries, without length) produce no points.
+///
+/// This trait requires the `use-rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::contains::Contains;
+/// use geo::algorithm::sample::Sample;
+/// use geo::polygon;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// let points = polygon.sample_points(100, &mut rng);
+/// assert_eq!(points.0.len(), 100);
+/// assert!(points.iter().all(|point| polygon.contains(point)));
+/// ```
+pub trait Sample<T: GeoFloat> {
+    /// Draw `count` random points from the geometry.
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T>;
+}
+
+impl<T: GeoFloat> Sample<T> for Line<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        LinearSampler::new(std::iter::once(*self)).sample(count, rng)
+    }
+}
+
+impl<T: GeoFloat> Sample<T> for LineString<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        LinearSampler::new(self.lines()).sample(count, rng)
+    }
+}
+
+impl<T: GeoFloat> Sample<T> for MultiLineString<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        LinearSampler::new(self.iter().flat_map(|line_string| line_string.lines()))
+            .sample(count, rng)
+    }
+}
+
+impl<T: GeoFloat> Sample<T> for Triangle<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        let mut sampler = ArealSampler::default();
+        sampler.push_triangle(*self, None);
+        sampler.sample(count, rng)
+    }
+}
+
+impl<T: GeoFloat> Sample<T> for Rect<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        if self.width() == T::zero() || self.height() == T::zero() {
+            return MultiPoint(vec![]);
+        }
+        (0..count)
+            .map(|_| {
+                Point::new(
+                    self.min().x + self.width() * random_unit(rng),
+                    self.min().y + self.height() * random_unit(rng),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> Sample<T> for Polygon<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        let mut sampler = ArealSampler::default();
+        sampler.push_polygon(self);
+        sampler.sample(count, rng)
+    }
+}
+
+impl<T: GeoFloat> Sample<T> for MultiPolygon<T> {
+    fn sample_points<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        let mut sampler = ArealSampler::default();
+        for polygon in self {
+            sampler.push_polygon(polygon);
+        }
+        sampler.sample(count, rng)
+    }
+}
+
+/// Generate a [Poisson-disk](https://en.wikipedia.org/wiki/Supersampling#Poisson_disk)
+/// sample of `bounds`: random points which are all at least `min_distance` apart, and which
+/// fill `bounds` so that no more points could be added.
+///
+/// Uses [Bridson's algorithm](https://www.cs.ubc.ca/~rbridson/docs/bridson-siggraph07-poissondisk.pdf),
+/// which runs in time linear in the number of generated points. Returns no points if
+/// `min_distance` isn't positive and finite.
+///
+/// This function requires the `use-rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Coordinate, Rect};
+/// use geo::algorithm::euclidean_distance::EuclideanDistance;
+/// use geo::algorithm::sample::poisson_disk_sample;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let bounds = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 10., y: 10. });
+/// let mut rng = StdRng::seed_from_u64(42);
+///
+/// let points = poisson_disk_sample(bounds, 1.0, &mut rng);
+/// for (i, a) in points.iter().enumerate() {
+///     for b in points.iter().skip(i + 1) {
+///         assert!(a.euclidean_distance(b) >= 1.0);
+///     }
+/// }
+/// ```
+pub fn poisson_disk_sample<T, R>(bounds: Rect<T>, min_distance: T, rng: &mut R) -> MultiPoint<T>
+where
+    T: GeoFloat,
+    R: Rng + ?Sized,
+{
+    // number of candidates tried around each active point before it is retired
+    const ATTEMPTS: usize = 30;
+
+    if !(min_distance > T::zero() && min_distance.is_finite()) {
+        return MultiPoint(vec![]);
+    }
+    let two = T::one() + T::one();
+    // each cell of the acceleration grid can hold at most one point
+    let cell_size = min_distance / two.sqrt();
+    let grid_size = |extent: T| (extent / cell_size).floor().to_usize().map(|n| n + 1);
+    let (columns, rows) = match (grid_size(bounds.width()), grid_size(bounds.height())) {
+        (Some(columns), Some(rows)) => (columns, rows),
+        _ => return MultiPoint(vec![]),
+    };
+    let cell_of = |coord: Coordinate<T>| {
+        let column = ((coord.x - bounds.min().x) / cell_size)
+            .to_usize()
+            .unwrap_or(0);
+        let row = ((coord.y - bounds.min().y) / cell_size)
+            .to_usize()
+            .unwrap_or(0);
+        (row.min(rows - 1), column.min(columns - 1))
+    };
+
+    let first = Coordinate {
+        x: bounds.min().x + bounds.width() * random_unit(rng),
+        y: bounds.min().y + bounds.height() * random_unit(rng),
+    };
+    let (row, column) = cell_of(first);
+    let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+    grid[row * columns + column] = Some(0);
+    let mut points: Vec<Coordinate<T>> = vec![first];
+    let mut active: Vec<usize> = vec![0];
+
+    let min_distance_2 = min_distance * min_distance;
+    let tau = T::from(std::f64::consts::PI).unwrap() * two;
+    while !active.is_empty() {
+        let active_idx = rng.gen_range(0..active.len());
+        let origin = points[active[active_idx]];
+
+        let candidate = (0..ATTEMPTS).find_map(|_| {
+            // uniformly distributed by area within the annulus between `min_distance` and
+            // twice that
+            let three = two + T::one();
+            let radius = min_distance * (T::one() + three * random_unit(rng)).sqrt();
+            let angle = tau * random_unit(rng);
+            let candidate = Coordinate {
+                x: origin.x + radius * angle.cos(),
+                y: origin.y + radius * angle.sin(),
+            };
+            if candidate.x < bounds.min().x
+                || candidate.x > bounds.max().x
+                || candidate.y < bounds.min().y
+                || candidate.y > bounds.max().y
+            {
+                return None;
+            }
+            let (row, column) = cell_of(candidate);
+            let too_close = (row.saturating_sub(2)..(row + 3).min(rows))
+                .flat_map(|row| {
+                    (column.saturating_sub(2)..(column + 3).min(columns))
+                        .map(move |column| (row, column))
+                })
+                .filter_map(|(row, column)| grid[row * columns + column])
+                .any(|idx| {
+                    let other = points[idx];
+                    (other.x - candidate.x).powi(2) + (other.y - candidate.y).powi(2)
+                        < min_distance_2
+                });
+            if too_close {
+                None
+            } else {
+                Some(candidate)
+            }
+        });
+
+        match candidate {
+            Some(candidate) => {
+                let (row, column) = cell_of(candidate);
+                grid[row * columns + column] = Some(points.len());
+                active.push(points.len());
+                points.push(candidate);
+            }
+            None => {
+                active.swap_remove(active_idx);
+            }
+        }
+    }
+
+    points.into_iter().map(Point).collect()
+}
+
+/// A uniformly distributed random number in `[0, 1)`.
+fn random_unit<T: GeoFloat, R: Rng + ?Sized>(rng: &mut R) -> T {
+    T::from(rng.gen::<f64>()).unwrap()
+}
+
+/// Picks an index with probability proportional to its weight, given cumulative weights.
+fn pick_weighted<T: GeoFloat, R: Rng + ?Sized>(cumulative: &[T], rng: &mut R) -> usize {
+    let total = *cumulative.last().unwrap();
+    let target = total * random_unit(rng);
+    cumulative
+        .iter()
+        .position(|weight| target < *weight)
+        .unwrap_or(cumulative.len() - 1)
+}
+
+/// Samples points along a set of lines, uniformly by length.
+struct LinearSampler<T: GeoFloat> {
+    lines: Vec<Line<T>>,
+    cumulative_lengths: Vec<T>,
+}
+
+impl<T: GeoFloat> LinearSampler<T> {
+    fn new(lines: impl Iterator<Item = Line<T>>) -> Self {
+        let mut sampler = LinearSampler {
+            lines: vec![],
+            cumulative_lengths: vec![],
+        };
+        let mut total = T::zero();
+        for line in lines {
+            let length = line.euclidean_length();
+            if length > T::zero() {
+                total = total + length;
+                sampler.lines.push(line);
+                sampler.cumulative_lengths.push(total);
+            }
+        }
+        sampler
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        if self.lines.is_empty() {
+            return MultiPoint(vec![]);
+        }
+        (0..count)
+            .map(|_| {
+                let line = self.lines[pick_weighted(&self.cumulative_lengths, rng)];
+                let fraction = random_unit(rng);
+                Point::new(
+                    line.start.x + line.dx() * fraction,
+                    line.start.y + line.dy() * fraction,
+                )
+            })
+            .collect()
+    }
+}
+
+/// A triangle cut from a polygon, along with the holes of that polygon.
+type TriangleWithHoles<'a, T> = (Triangle<T>, Option<&'a [LineString<T>]>);
+
+/// Samples points within a set of triangles, uniformly by area, rejecting points which fall in
+/// the holes of the polygon a triangle was cut from.
+struct ArealSampler<'a, T: GeoFloat> {
+    triangles: Vec<TriangleWithHoles<'a, T>>,
+    cumulative_areas: Vec<T>,
+}
+
+impl<'a, T: GeoFloat> Default for ArealSampler<'a, T> {
+    fn default() -> Self {
+        ArealSampler {
+            triangles: vec![],
+            cumulative_areas: vec![],
+        }
+    }
+}
+
+impl<'a, T: GeoFloat> ArealSampler<'a, T> {
+    fn push_triangle(&mut self, triangle: Triangle<T>, holes: Option<&'a [LineString<T>]>) {
+        let area = triangle.unsigned_area();
+        if area > T::zero() {
+            let total = self
+                .cumulative_areas
+                .last()
+                .cloned()
+                .unwrap_or_else(T::zero);
+            self.triangles.push((triangle, holes));
+            self.cumulative_areas.push(total + area);
+        }
+    }
+
+    fn push_polygon(&mut self, polygon: &'a Polygon<T>) {
+        // rejection sampling can't terminate if the holes cover the whole exterior
+        if polygon.unsigned_area() <= T::zero() {
+            return;
+        }
+        let holes = if polygon.interiors().is_empty() {
+            None
+        } else {
+            Some(polygon.interiors())
+        };
+        for triangle in triangulate_ring(polygon.exterior()) {
+            self.push_triangle(triangle, holes);
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, count: usize, rng: &mut R) -> MultiPoint<T> {
+        if self.triangles.is_empty() {
+            return MultiPoint(vec![]);
+        }
+        let mut points = Vec::with_capacity(count);
+        while points.len() < count {
+            let (triangle, holes) = self.triangles[pick_weighted(&self.cumulative_areas, rng)];
+            let coord = sample_triangle(triangle, rng);
+            let in_hole = match holes {
+                Some(holes) => holes
+                    .iter()
+                    .any(|hole| coord_pos_relative_to_ring(coord, hole) != CoordPos::Outside),
+                None => false,
+            };
+            if !in_hole {
+                points.push(Point(coord));
+            }
+        }
+        MultiPoint(points)
+    }
+}
+
+/// A uniformly distributed random point within a triangle.
+fn sample_triangle<T: GeoFloat, R: Rng + ?Sized>(
+    triangle: Triangle<T>,
+    rng: &mut R,
+) -> Coordinate<T> {
+    let Triangle(a, b, c) = triangle;
+    let mut u: T = random_unit(rng);
+    let mut v: T = random_unit(rng);
+    // reflect points from the far half of the parallelogram spanned by the triangle's edges
+    if u + v > T::one() {
+        u = T::one() - u;
+        v = T::one() - v;
+    }
+    Coordinate {
+        x: a.x + (b.x - a.x) * u + (c.x - a.x) * v,
+        y: a.y + (b.y - a.y) * u + (c.y - a.y) * v,
+    }
+}
+
+/// Triangulate a simple ring by ear clipping.
+fn triangulate_ring<T: GeoFloat>(ring: &LineString<T>) -> Vec<Triangle<T>> {
+    ear_clip(ring_coords(ring, WindingOrder::CounterClockwise))
+        .into_iter()
+        .map(Triangle::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::contains::Contains;
+    use crate::algorithm::euclidean_distance::EuclideanDistance;
+    use crate::{line_string, polygon};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(7)
+    }
+
+    #[test]
+    fn triangulate_concave_ring_test() {
+        // a "U" shape, wound clockwise
+        let ring = line_string![
+            (x: 0., y: 0.), (x: 0., y: 3.), (x: 1., y: 3.), (x: 1., y: 1.),
+            (x: 2., y: 1.), (x: 2., y: 3.), (x: 3., y: 3.), (x: 3., y: 0.), (x: 0., y: 0.)
+        ];
+        let triangles = triangulate_ring(&ring);
+        assert_eq!(triangles.len(), 6);
+        let area = triangles.iter().fold(0., |acc, t| acc + t.unsigned_area());
+        assert_relative_eq!(area, 7.);
+        // no triangle bridges the notch
+        let notch = Point::new(1.5, 2.);
+        assert!(triangles.iter().all(|t| !t.to_polygon().contains(&notch)));
+    }
+
+    #[test]
+    fn polygon_with_hole_test() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+        );
+        let points = polygon.sample_points(500, &mut rng());
+        assert_eq!(points.0.len(), 500);
+        assert!(points.iter().all(|point| polygon.contains(point)));
+    }
+
+    #[test]
+    fn uniform_by_area_test() {
+        let small = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let large = polygon![(x: 2., y: 0.), (x: 5., y: 0.), (x: 5., y: 1.), (x: 2., y: 1.)];
+        let multi = MultiPolygon(vec![small.clone(), large]);
+        let points = multi.sample_points(4000, &mut rng());
+        let in_small = points.iter().filter(|point| small.contains(*point)).count();
+        // the expected share is a quarter
+        assert!((800..1200).contains(&in_small));
+    }
+
+    #[test]
+    fn degenerate_geometry_test() {
+        let empty: Polygon<f64> = Polygon::new(LineString(vec![]), vec![]);
+        assert!(empty.sample_points(10, &mut rng()).0.is_empty());
+        let flat = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(flat.unsigned_area(), 0.);
+        assert!(flat.sample_points(10, &mut rng()).0.is_empty());
+        let point_line = line_string![(x: 1., y: 1.), (x: 1., y: 1.)];
+        assert!(point_line.sample_points(10, &mut rng()).0.is_empty());
+    }
+
+    #[test]
+    fn line_string_test() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 3.)];
+        let points = line_string.sample_points(4000, &mut rng());
+        assert!(points
+            .iter()
+            .all(|point| point.euclidean_distance(&line_string) < 1e-12));
+        let on_first = points.iter().filter(|point| point.y() == 0.).count();
+        // the first segment is a quarter of the length
+        assert!((800..1200).contains(&on_first));
+    }
+
+    #[test]
+    fn poisson_disk_test() {
+        let bounds = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 10., y: 5. });
+        let points = poisson_disk_sample(bounds, 1.0, &mut rng());
+        // a maximal packing at this density holds many points
+        assert!(points.0.len() > 30);
+        for (i, a) in points.iter().enumerate() {
+            assert!(bounds.to_polygon().contains(a) || bounds.to_polygon().exterior().contains(a));
+            for b in points.iter().skip(i + 1) {
+                assert!(a.euclidean_distance(b) >= 1.0);
+            }
+        }
+        assert!(poisson_disk_sample(bounds, 0.0, &mut rng()).0.is_empty());
+    }
+}
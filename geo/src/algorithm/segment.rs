@@ -0,0 +1,208 @@
+//! Low-level measurements of single line segments.
+//!
+//! These are the building blocks of distance and closest-point calculations between larger
+//! geometries. Distances are squared, which avoids a square root when they're only compared.
+
+use crate::algorithm::intersects::Intersects;
+use crate::{Coordinate, GeoFloat, Line};
+
+/// The projection of a coordinate onto the line through a segment.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentProjection<T: GeoFloat> {
+    /// How far along the segment the projection lies, as a multiple of the segment: `0` at its
+    /// start and `1` at its end
+    pub t: T,
+    /// The projected coordinate, `line.start + t * (line.end - line.start)`
+    pub coord: Coordinate<T>,
+}
+
+/// Project `coord` onto the infinite line through `line`.
+///
+/// `t` is unbounded, so the projection may lie beyond either end of the segment. The projection
+/// onto a zero-length segment is its start, with `t` of `0`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::segment::project;
+/// use geo::{Coordinate, Line};
+///
+/// let line = Line::new((0., 0.), (2., 0.));
+/// let projection = project(line, Coordinate { x: 3., y: 5. });
+///
+/// assert_eq!(projection.t, 1.5);
+/// assert_eq!(projection.coord, Coordinate { x: 3., y: 0. });
+/// ```
+pub fn project<T: GeoFloat>(line: Line<T>, coord: Coordinate<T>) -> SegmentProjection<T> {
+    let delta = line.delta();
+    let square_length = dot(delta, delta);
+    if square_length.is_zero() {
+        return SegmentProjection {
+            t: T::zero(),
+            coord: line.start,
+        };
+    }
+    let t = dot(coord - line.start, delta) / square_length;
+    SegmentProjection {
+        t,
+        coord: line.start + delta * t,
+    }
+}
+
+/// The coordinate on `line` closest to `coord`.
+///
+/// This is [`project`], with `t` clamped to the segment. The ends of the segment are returned
+/// exactly, rather than being recomputed from `t`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::segment::closest_coord;
+/// use geo::{Coordinate, Line};
+///
+/// let line = Line::new((0., 0.), (2., 0.));
+///
+/// let closest = closest_coord(line, Coordinate { x: 1., y: 5. });
+/// assert_eq!(closest.t, 0.5);
+/// assert_eq!(closest.coord, Coordinate { x: 1., y: 0. });
+///
+/// let closest = closest_coord(line, Coordinate { x: 3., y: 5. });
+/// assert_eq!(closest.t, 1.);
+/// assert_eq!(closest.coord, Coordinate { x: 2., y: 0. });
+/// ```
+pub fn closest_coord<T: GeoFloat>(line: Line<T>, coord: Coordinate<T>) -> SegmentProjection<T> {
+    let projection = project(line, coord);
+    if projection.t <= T::zero() {
+        SegmentProjection {
+            t: T::zero(),
+            coord: line.start,
+        }
+    } else if projection.t >= T::one() {
+        SegmentProjection {
+            t: T::one(),
+            coord: line.end,
+        }
+    } else {
+        projection
+    }
+}
+
+/// The squared distance from `coord` to the closest coordinate on `line`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::segment::coord_square_distance;
+/// use geo::{Coordinate, Line};
+///
+/// let line = Line::new((0., 0.), (2., 0.));
+/// assert_eq!(coord_square_distance(line, Coordinate { x: 1., y: 3. }), 9.);
+/// assert_eq!(coord_square_distance(line, Coordinate { x: 5., y: 4. }), 25.);
+/// ```
+pub fn coord_square_distance<T: GeoFloat>(line: Line<T>, coord: Coordinate<T>) -> T {
+    let offset = coord - closest_coord(line, coord).coord;
+    dot(offset, offset)
+}
+
+/// The squared distance between the closest coordinates of two segments.
+///
+/// Whether the segments intersect is decided exactly, with the orientation kernel, so
+/// intersecting segments are always zero apart. Otherwise, the closest coordinates include an
+/// end of one of the segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::segment::line_square_distance;
+/// use geo::Line;
+///
+/// let a = Line::new((0., 0.), (2., 0.));
+/// assert_eq!(line_square_distance(a, Line::new((1., -1.), (1., 1.))), 0.);
+/// assert_eq!(line_square_distance(a, Line::new((3., 1.), (5., 1.))), 2.);
+/// ```
+pub fn line_square_distance<T: GeoFloat>(a: Line<T>, b: Line<T>) -> T {
+    if a.intersects(&b) {
+        return T::zero();
+    }
+    coord_square_distance(b, a.start)
+        .min(coord_square_distance(b, a.end))
+        .min(coord_square_distance(a, b.start))
+        .min(coord_square_distance(a, b.end))
+}
+
+pub(crate) fn dot<T: GeoFloat>(u: Coordinate<T>, v: Coordinate<T>) -> T {
+    u.x * v.x + u.y * v.y
+}
+
+// The z component of the cross product of `u` and `v`, which is positive when `v` turns
+// counter-clockwise from `u`.
+pub(crate) fn cross<T: GeoFloat>(u: Coordinate<T>, v: Coordinate<T>) -> T {
+    u.x * v.y - u.y * v.x
+}
+
+// The midpoint of `line`, at which the overlay algorithms, all needing `std`, classify pieces
+// of segments.
+#[cfg(feature = "std")]
+pub(crate) fn midpoint<T: GeoFloat>(line: Line<T>) -> Coordinate<T> {
+    let two = T::one() + T::one();
+    Coordinate {
+        x: (line.start.x + line.end.x) / two,
+        y: (line.start.y + line.end.y) / two,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn projection_is_unclamped() {
+        let line = Line::new((1., 1.), (3., 3.));
+        let before = project(line, Coordinate { x: 0., y: -2. });
+        assert_eq!(before.t, -1.);
+        assert_eq!(before.coord, Coordinate { x: -1., y: -1. });
+
+        let closest = closest_coord(line, Coordinate { x: 0., y: -2. });
+        assert_eq!(closest.t, 0.);
+        assert_eq!(closest.coord, line.start);
+    }
+
+    #[test]
+    fn zero_length_segments() {
+        let point = Line::new((1., 1.), (1., 1.));
+        let coord = Coordinate { x: 4., y: 5. };
+        assert_eq!(
+            project(point, coord),
+            SegmentProjection {
+                t: 0.,
+                coord: point.start
+            }
+        );
+        assert_eq!(coord_square_distance(point, coord), 25.);
+        assert_eq!(line_square_distance(point, point), 0.);
+        assert_eq!(
+            line_square_distance(point, Line::new((4., 5.), (4., 9.))),
+            25.
+        );
+    }
+
+    #[test]
+    fn segment_distances() {
+        let a = Line::new((0., 0.), (4., 0.));
+        // parallel
+        assert_eq!(line_square_distance(a, Line::new((1., 2.), (3., 2.))), 4.);
+        // collinear and disjoint
+        assert_eq!(line_square_distance(a, Line::new((6., 0.), (9., 0.))), 4.);
+        // collinear and overlapping
+        assert_eq!(line_square_distance(a, Line::new((3., 0.), (9., 0.))), 0.);
+        // touching at an end
+        assert_eq!(line_square_distance(a, Line::new((4., 0.), (5., 5.))), 0.);
+        // crossing
+        assert_eq!(line_square_distance(a, Line::new((2., -1.), (3., 1.))), 0.);
+        // symmetric
+        let b = Line::new((5., 3.), (7., 1.));
+        assert_eq!(line_square_distance(a, b), line_square_distance(b, a));
+        assert_eq!(line_square_distance(a, b), 8.);
+    }
+}
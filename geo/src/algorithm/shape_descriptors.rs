@@ -0,0 +1,221 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::convex_hull::ConvexHull;
+use crate::algorithm::euclidean_length::EuclideanLength;
+use crate::{Coordinate, GeoFloat, MultiPolygon, Polygon};
+use num_traits::FloatConst;
+use std::iter::Sum;
+
+/// Dimensionless measures of the shape of a `Polygon`, for classifying shapes such as building
+/// footprints independently of their size and orientation.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::shape_descriptors::ShapeDescriptors;
+/// use geo::polygon;
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let descriptors = ShapeDescriptors::new(&square).unwrap();
+/// assert_relative_eq!(descriptors.compactness, std::f64::consts::PI / 4.);
+/// assert_eq!(descriptors.elongation, 0.);
+/// assert_eq!(descriptors.convexity, 1.);
+///
+/// // an L-shaped footprint
+/// let l_shape = polygon![
+///     (x: 0., y: 0.),
+///     (x: 4., y: 0.),
+///     (x: 4., y: 1.),
+///     (x: 1., y: 1.),
+///     (x: 1., y: 4.),
+///     (x: 0., y: 4.),
+/// ];
+/// let descriptors = ShapeDescriptors::new(&l_shape).unwrap();
+/// assert_eq!(descriptors.area, 7.);
+/// assert_eq!(descriptors.perimeter, 16.);
+/// assert_relative_eq!(descriptors.convexity, 7. / 11.5);
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeDescriptors<T: GeoFloat> {
+    /// The unsigned area of the polygon
+    pub area: T,
+    /// The length of all of the polygon's rings, including its interiors
+    pub perimeter: T,
+    /// The [Polsby–Popper](https://en.wikipedia.org/wiki/Polsby%E2%80%93Popper_test) score,
+    /// `4πA / P²`: `1` for a circle, decreasing towards `0` as the boundary becomes longer or
+    /// more convoluted relative to the area it encloses
+    pub compactness: T,
+    /// `1 - w / l`, where `w` and `l` are the short and long sides of the polygon's minimum-area
+    /// bounding rectangle, at any rotation: `0` for shapes which fit a square best, approaching
+    /// `1` for long, thin shapes
+    pub elongation: T,
+    /// The ratio of the polygon's area to the area of its convex hull: `1` for convex polygons,
+    /// decreasing with the size of their concavities and holes
+    pub convexity: T,
+}
+
+impl<T: GeoFloat + FloatConst + Sum> ShapeDescriptors<T> {
+    /// Describe the shape of `polygon`, or return `None` if it has no area.
+    pub fn new(polygon: &Polygon<T>) -> Option<Self> {
+        let area = polygon.unsigned_area();
+        if area.is_zero() {
+            return None;
+        }
+
+        let perimeter = polygon.exterior().euclidean_length()
+            + polygon
+                .interiors()
+                .iter()
+                .fold(T::zero(), |sum, interior| sum + interior.euclidean_length());
+        let four = T::from(4).unwrap();
+        let compactness = four * T::PI() * area / (perimeter * perimeter);
+
+        let hull = polygon.convex_hull();
+        let convexity = area / hull.unsigned_area();
+
+        let (short, long) = minimum_rotated_rect_sides(&hull.exterior().0);
+        let elongation = T::one() - short / long;
+
+        Some(ShapeDescriptors {
+            area,
+            perimeter,
+            compactness,
+            elongation,
+            convexity,
+        })
+    }
+
+    /// Describe the shape of each polygon of `multi_polygon` which has an area.
+    pub fn from_multi_polygon(multi_polygon: &MultiPolygon<T>) -> Vec<Self> {
+        multi_polygon.iter().filter_map(Self::new).collect()
+    }
+}
+
+// The short and long sides of the minimum-area rectangle enclosing `hull`, a closed, convex ring
+// with area.
+//
+// The minimum-area rectangle has a side collinear with an edge of the hull, so it's found by
+// measuring the extent of the hull along and across each of its edges in turn.
+fn minimum_rotated_rect_sides<T: GeoFloat>(hull: &[Coordinate<T>]) -> (T, T) {
+    let mut best: Option<(T, T, T)> = None;
+    for edge in hull.windows(2) {
+        let direction = edge[1] - edge[0];
+        let length = direction.x.hypot(direction.y);
+        if length.is_zero() {
+            continue;
+        }
+        let along = direction / length;
+        let across = Coordinate {
+            x: -along.y,
+            y: along.x,
+        };
+
+        let (mut min_along, mut max_along) = (T::zero(), T::zero());
+        let (mut min_across, mut max_across) = (T::zero(), T::zero());
+        for coord in hull {
+            let offset = *coord - edge[0];
+            let a = offset.x * along.x + offset.y * along.y;
+            let b = offset.x * across.x + offset.y * across.y;
+            min_along = min_along.min(a);
+            max_along = max_along.max(a);
+            min_across = min_across.min(b);
+            max_across = max_across.max(b);
+        }
+
+        let (width, height) = (max_along - min_along, max_across - min_across);
+        let area = width * height;
+        let is_better = match best {
+            Some((best_area, _, _)) => area < best_area,
+            None => true,
+        };
+        if is_better {
+            best = Some((area, width.min(height), width.max(height)));
+        }
+    }
+    let (_, short, long) = best.expect("a hull with area has edges");
+    (short, long)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Rect};
+
+    #[test]
+    fn rectangles() {
+        let rect: Polygon<f64> = Rect::new((0., 0.), (4., 1.)).into();
+        let descriptors = ShapeDescriptors::new(&rect).unwrap();
+        assert_eq!(descriptors.area, 4.);
+        assert_eq!(descriptors.perimeter, 10.);
+        assert_relative_eq!(descriptors.compactness, 16. * std::f64::consts::PI / 100.);
+        assert_relative_eq!(descriptors.elongation, 0.75);
+        assert_relative_eq!(descriptors.convexity, 1.);
+    }
+
+    #[test]
+    fn rotation_does_not_change_descriptors() {
+        let rect = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.), (x: 0., y: 1.)];
+        // the same rectangle, rotated by 30 degrees
+        let (sin, cos) = (0.5, 3f64.sqrt() / 2.);
+        let rotated = polygon![
+            (x: 0., y: 0.),
+            (x: 4. * cos, y: 4. * sin),
+            (x: 4. * cos - sin, y: 4. * sin + cos),
+            (x: -sin, y: cos),
+        ];
+
+        let expected = ShapeDescriptors::new(&rect).unwrap();
+        let actual = ShapeDescriptors::new(&rotated).unwrap();
+        assert_relative_eq!(actual.area, expected.area, epsilon = 1e-12);
+        assert_relative_eq!(actual.perimeter, expected.perimeter, epsilon = 1e-12);
+        assert_relative_eq!(actual.compactness, expected.compactness, epsilon = 1e-12);
+        assert_relative_eq!(actual.elongation, expected.elongation, epsilon = 1e-12);
+        assert_relative_eq!(actual.convexity, expected.convexity, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn circles_are_compact() {
+        let circle: Polygon<f64> = Polygon::new(
+            (0..1000)
+                .map(|i| {
+                    let angle = i as f64 * std::f64::consts::PI / 500.;
+                    (angle.cos(), angle.sin())
+                })
+                .collect(),
+            vec![],
+        );
+        let descriptors = ShapeDescriptors::new(&circle).unwrap();
+        assert_relative_eq!(descriptors.compactness, 1., epsilon = 1e-5);
+        assert_relative_eq!(descriptors.elongation, 0., epsilon = 1e-5);
+        assert_relative_eq!(descriptors.convexity, 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn holes() {
+        let exterior = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let interior = line_string![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+        let polygon = Polygon::new(exterior, vec![interior]);
+
+        let descriptors = ShapeDescriptors::new(&polygon).unwrap();
+        assert_eq!(descriptors.area, 12.);
+        assert_eq!(descriptors.perimeter, 24.);
+        assert_relative_eq!(descriptors.convexity, 0.75);
+        assert_relative_eq!(descriptors.elongation, 0.);
+    }
+
+    #[test]
+    fn polygons_without_area() {
+        let flat = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(ShapeDescriptors::new(&flat), None);
+        let empty: Polygon<f64> = polygon![];
+        assert_eq!(ShapeDescriptors::new(&empty), None);
+
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let multi_polygon = MultiPolygon(vec![flat, square.clone()]);
+        assert_eq!(
+            ShapeDescriptors::from_multi_polygon(&multi_polygon),
+            vec![ShapeDescriptors::new(&square).unwrap()]
+        );
+    }
+}
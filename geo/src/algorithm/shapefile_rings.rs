@@ -0,0 +1,176 @@
+//! Convert between the rings of a shapefile polygon and a [`MultiPolygon`].
+//!
+//! A shapefile stores a polygon, or multipolygon, as a flat list of rings, without saying which
+//! holes belong to which outer ring: outer rings run clockwise, and holes counter-clockwise,
+//! and the holes must be assigned to the outer rings enclosing them when the shapefile is read.
+
+use crate::algorithm::area::Area;
+use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::algorithm::orient::{Direction, Orient};
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::{GeoFloat, LineString, MultiPolygon, Polygon};
+
+/// Assemble the rings of a shapefile polygon into a [`MultiPolygon`], following the shapefile
+/// conventions: clockwise rings are outer rings, and counter-clockwise rings are holes.
+///
+/// Each hole is assigned to the smallest outer ring enclosing it, so that islands inside the
+/// holes of other polygons keep their own holes. Rings which aren't closed are closed, and
+/// rings enclosing no area dropped. A hole inside no outer ring, which many shapefile writers
+/// produce by winding an outer ring the wrong way, is taken as an outer ring without holes.
+///
+/// The polygons are wound as this crate's [`Orient`] does by default, with counter-clockwise
+/// exteriors and clockwise interiors, in the order of their outer rings.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::shapefile_rings::from_shapefile_rings;
+/// use geo::line_string;
+///
+/// let rings = vec![
+///     // clockwise, so an outer ring
+///     line_string![(x: 0., y: 0.), (x: 0., y: 10.), (x: 10., y: 10.), (x: 10., y: 0.)],
+///     // another, with no holes
+///     line_string![(x: 20., y: 0.), (x: 20., y: 5.), (x: 25., y: 5.), (x: 25., y: 0.)],
+///     // counter-clockwise, so a hole, inside the first outer ring
+///     line_string![(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)],
+/// ];
+///
+/// let multi_polygon = from_shapefile_rings(rings);
+/// assert_eq!(multi_polygon.0.len(), 2);
+/// assert_eq!(multi_polygon.0[0].interiors().len(), 1);
+/// assert!(multi_polygon.0[1].interiors().is_empty());
+/// ```
+pub fn from_shapefile_rings<T, I>(rings: I) -> MultiPolygon<T>
+where
+    T: GeoFloat,
+    I: IntoIterator<Item = LineString<T>>,
+{
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for mut ring in rings {
+        ring.close();
+        match ring.winding_order() {
+            Some(WindingOrder::Clockwise) => shells.push(ring),
+            Some(WindingOrder::CounterClockwise) => holes.push(ring),
+            // degenerate rings enclose nothing
+            None => {}
+        }
+    }
+
+    let shell_areas: Vec<T> = shells
+        .iter()
+        .map(|shell| Polygon::new(shell.clone(), vec![]).unsigned_area())
+        .collect();
+    let mut interiors = vec![vec![]; shells.len()];
+    let mut orphans = vec![];
+    for hole in holes {
+        // holes may touch their shell, so classify a vertex which isn't on its boundary
+        let owner = (0..shells.len())
+            .filter(|index| {
+                hole.0
+                    .iter()
+                    .map(|coord| coord_pos_relative_to_ring(*coord, &shells[*index]))
+                    .find(|position| *position != CoordPos::OnBoundary)
+                    == Some(CoordPos::Inside)
+            })
+            .min_by(|a, b| shell_areas[*a].partial_cmp(&shell_areas[*b]).unwrap());
+        match owner {
+            Some(owner) => interiors[owner].push(hole),
+            None => orphans.push(hole),
+        }
+    }
+
+    let polygons = shells
+        .into_iter()
+        .zip(interiors)
+        .chain(orphans.into_iter().map(|orphan| (orphan, vec![])))
+        .map(|(exterior, interiors)| Polygon::new(exterior, interiors).orient(Direction::Default))
+        .collect();
+    MultiPolygon(polygons)
+}
+
+/// The rings of a [`MultiPolygon`], as a shapefile stores them: the exterior of each polygon,
+/// wound clockwise, followed by its interiors, wound counter-clockwise.
+///
+/// Polygons with an empty exterior are left out.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::shapefile_rings::{from_shapefile_rings, to_shapefile_rings};
+/// use geo::algorithm::winding_order::Winding;
+/// use geo::{polygon, MultiPolygon};
+///
+/// let frame = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [[(x: 2., y: 2.), (x: 2., y: 8.), (x: 8., y: 8.), (x: 8., y: 2.)]],
+/// ];
+/// let rings = to_shapefile_rings(&MultiPolygon(vec![frame.clone()]));
+/// assert_eq!(rings.len(), 2);
+/// assert!(rings[0].is_cw());
+/// assert!(rings[1].is_ccw());
+///
+/// assert_eq!(from_shapefile_rings(rings), MultiPolygon(vec![frame]));
+/// ```
+pub fn to_shapefile_rings<T: GeoFloat>(multi_polygon: &MultiPolygon<T>) -> Vec<LineString<T>> {
+    let mut rings = vec![];
+    for polygon in multi_polygon {
+        if polygon.exterior().0.is_empty() {
+            continue;
+        }
+        let (exterior, interiors) = polygon.orient(Direction::Reversed).into_inner();
+        rings.push(exterior);
+        rings.extend(interiors);
+    }
+    rings
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon};
+
+    #[test]
+    fn islands_inside_holes() {
+        // a frame, with an island in its hole, which has a pond of its own
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 20., y: 0.), (x: 20., y: 20.), (x: 0., y: 20.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 18.), (x: 18., y: 18.), (x: 18., y: 2.)]],
+        ];
+        let island = polygon![
+            exterior: [(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)],
+            interiors: [[(x: 8., y: 8.), (x: 8., y: 12.), (x: 12., y: 12.), (x: 12., y: 8.)]],
+        ];
+        let multi_polygon = MultiPolygon(vec![frame, island]);
+        let mut rings = to_shapefile_rings(&multi_polygon);
+        assert_eq!(rings.len(), 4);
+        assert_eq!(from_shapefile_rings(rings.clone()), multi_polygon);
+
+        // however the rings are ordered
+        rings.reverse();
+        let mut reordered = from_shapefile_rings(rings);
+        reordered.0.reverse();
+        assert_eq!(reordered, multi_polygon);
+    }
+
+    #[test]
+    fn unclosed_degenerate_and_orphaned_rings() {
+        let rings = vec![
+            // clockwise, and not closed
+            line_string![(x: 0., y: 0.), (x: 0., y: 4.), (x: 4., y: 4.), (x: 4., y: 0.)],
+            // enclosing nothing
+            line_string![(x: 1., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)],
+            // counter-clockwise, but outside any outer ring
+            line_string![(x: 10., y: 0.), (x: 14., y: 0.), (x: 14., y: 4.), (x: 10., y: 4.)],
+        ];
+        let multi_polygon = from_shapefile_rings(rings);
+        assert_eq!(
+            multi_polygon,
+            MultiPolygon(vec![
+                polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                polygon![(x: 10., y: 0.), (x: 14., y: 0.), (x: 14., y: 4.), (x: 10., y: 4.)],
+            ])
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::algorithm::area::Area;
+use crate::algorithm::coords_iter::CoordsIter;
+use crate::algorithm::hausdorff_distance::HausdorffDistance;
+use crate::{GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+use std::iter;
+
+/// How far a simplified geometry strays from its original, and how much smaller it is, as
+/// measured by [`SimplificationQuality::simplification_quality`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimplificationReport<T: GeoFloat> {
+    /// The discrete Hausdorff distance between the geometries: the furthest a coordinate of
+    /// either is from the lines, or rings, of the other
+    pub max_deviation: T,
+    /// The unsigned area of the simplified geometry, less that of the original
+    pub area_change: T,
+    /// The area change as a fraction of the original's area, which is `0` if neither has any
+    /// area, and infinite if only the simplified geometry has
+    pub relative_area_change: T,
+    /// The number of coordinates of the original geometry
+    pub original_coords: usize,
+    /// The number of coordinates of the simplified geometry
+    pub simplified_coords: usize,
+    /// The fraction of the original's coordinates which the simplified geometry has done
+    /// without, `0` if the original has none
+    pub vertex_reduction: T,
+}
+
+/// Measure the quality of a simplification, against the size it saves, so that a tolerance can
+/// be chosen by its quantified trade-off rather than by eye.
+///
+/// The report can be made for the output of any simplification: by
+/// [`Simplify`](crate::algorithm::simplify::Simplify),
+/// [`SimplifyVW`](crate::algorithm::simplifyvw::SimplifyVW), or another library altogether.
+/// The deviation is the discrete Hausdorff distance, as given by
+/// [`HausdorffDistance`](crate::algorithm::hausdorff_distance::HausdorffDistance), between the lines, or the rings of the polygons: from each coordinate
+/// of either to the nearest point on the lines or rings of the other. Since a simplification
+/// keeps a subset of the original's coordinates, the deviation is where the original is
+/// furthest from the simplified geometry, at one of the coordinates which was removed.
+pub trait SimplificationQuality<T: GeoFloat> {
+    /// The report of how `simplified` differs from `self`, the original geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::simplification_quality::SimplificationQuality;
+    /// use geo::algorithm::simplify::Simplify;
+    /// use geo::{line_string, LineString};
+    ///
+    /// let coast: LineString<f64> = line_string![
+    ///     (x: 0., y: 0.),
+    ///     (x: 1., y: 0.2),
+    ///     (x: 2., y: -0.1),
+    ///     (x: 3., y: 1.5),
+    ///     (x: 4., y: 0.),
+    ///     (x: 5., y: 0.3),
+    ///     (x: 6., y: 0.),
+    /// ];
+    ///
+    /// // the reduction in coordinates bought by each tolerance, at its cost in accuracy
+    /// let reports: Vec<_> = [0.1, 0.5, 2.]
+    ///     .iter()
+    ///     .map(|tolerance| coast.simplification_quality(&coast.simplify(tolerance)))
+    ///     .collect();
+    /// assert_eq!(reports[0].simplified_coords, 7);
+    /// assert_eq!(reports[1].simplified_coords, 5);
+    /// assert_eq!(reports[1].max_deviation, 0.3);
+    /// assert_eq!(reports[2].simplified_coords, 2);
+    /// assert_eq!(reports[2].max_deviation, 1.5);
+    /// assert_relative_eq!(reports[2].vertex_reduction, 5. / 7.);
+    /// // lines have no area to change
+    /// assert_eq!(reports[2].area_change, 0.);
+    /// ```
+    fn simplification_quality(&self, simplified: &Self) -> SimplificationReport<T>;
+}
+
+impl<T: GeoFloat + 'static> SimplificationQuality<T> for LineString<T> {
+    fn simplification_quality(&self, simplified: &Self) -> SimplificationReport<T> {
+        report(
+            self,
+            simplified,
+            &MultiLineString(vec![self.clone()]),
+            &MultiLineString(vec![simplified.clone()]),
+        )
+    }
+}
+
+impl<T: GeoFloat + 'static> SimplificationQuality<T> for MultiLineString<T> {
+    fn simplification_quality(&self, simplified: &Self) -> SimplificationReport<T> {
+        report(self, simplified, self, simplified)
+    }
+}
+
+impl<T: GeoFloat + 'static> SimplificationQuality<T> for Polygon<T> {
+    fn simplification_quality(&self, simplified: &Self) -> SimplificationReport<T> {
+        report(
+            self,
+            simplified,
+            &MultiLineString(rings(self).collect()),
+            &MultiLineString(rings(simplified).collect()),
+        )
+    }
+}
+
+impl<T: GeoFloat + 'static> SimplificationQuality<T> for MultiPolygon<T> {
+    fn simplification_quality(&self, simplified: &Self) -> SimplificationReport<T> {
+        report(
+            self,
+            simplified,
+            &MultiLineString(self.iter().flat_map(rings).collect()),
+            &MultiLineString(simplified.iter().flat_map(rings).collect()),
+        )
+    }
+}
+
+// The report on the geometries, with the deviation measured between their outlines: lines, or
+// the rings of polygons, so that a coordinate removed from inside the simplified polygon still
+// counts.
+fn report<'a, T, G>(
+    original: &'a G,
+    simplified: &'a G,
+    original_outline: &MultiLineString<T>,
+    simplified_outline: &MultiLineString<T>,
+) -> SimplificationReport<T>
+where
+    T: GeoFloat + 'static,
+    G: CoordsIter<'a, Scalar = T> + Area<T>,
+{
+    let original_area = original.unsigned_area();
+    let area_change = simplified.unsigned_area() - original_area;
+    let relative_area_change = if area_change == T::zero() {
+        T::zero()
+    } else {
+        area_change / original_area
+    };
+
+    let original_coords = original.coords_count();
+    let simplified_coords = simplified.coords_count();
+    let vertex_reduction = if original_coords == 0 {
+        T::zero()
+    } else {
+        T::one() - T::from(simplified_coords).unwrap() / T::from(original_coords).unwrap()
+    };
+
+    SimplificationReport {
+        max_deviation: original_outline
+            .max_distance_from(simplified_outline)
+            .max(simplified_outline.max_distance_from(original_outline)),
+        area_change,
+        relative_area_change,
+        original_coords,
+        simplified_coords,
+        vertex_reduction,
+    }
+}
+
+fn rings<T: GeoFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = LineString<T>> + '_ {
+    iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .cloned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::simplifyvw::SimplifyVW;
+    use crate::polygon;
+
+    #[test]
+    fn polygons_lose_area() {
+        // a square with a notch cut into its top, which a simplification fills in
+        let notched: Polygon<f64> = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 6., y: 10.),
+            (x: 5., y: 9.),
+            (x: 4., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let simplified = notched.simplifyvw(&2.);
+        assert_eq!(simplified.exterior().0.len(), 5);
+        let report = notched.simplification_quality(&simplified);
+        assert_eq!(
+            report,
+            SimplificationReport {
+                max_deviation: 1.,
+                area_change: 1.,
+                relative_area_change: 1. / 99.,
+                original_coords: 8,
+                simplified_coords: 5,
+                vertex_reduction: 3. / 8.,
+            }
+        );
+
+        let unchanged = notched.simplification_quality(&notched);
+        assert_eq!(unchanged.max_deviation, 0.);
+        assert_eq!(unchanged.relative_area_change, 0.);
+        assert_eq!(unchanged.vertex_reduction, 0.);
+
+        // everything removed
+        let empty = MultiPolygon::<f64>(vec![]);
+        let report = MultiPolygon(vec![notched]).simplification_quality(&empty);
+        assert_eq!(report.relative_area_change, -1.);
+        assert_eq!(report.vertex_reduction, 1.);
+        assert_eq!(report.max_deviation, f64::INFINITY);
+        assert_eq!(empty.simplification_quality(&empty).vertex_reduction, 0.);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::algorithm::coords_iter::CoordsIter;
-use crate::algorithm::euclidean_distance::EuclideanDistance;
 use crate::{Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+use alloc::vec;
+use alloc::vec::Vec;
+use geo_types::private_utils::point_line_euclidean_distance;
 
 // Because the RDP algorithm is recursive, we can't assign an index to a point inside the loop
 // instead, we wrap a simple struct around index and point in a wrapper function,
@@ -15,7 +17,7 @@ where
 }
 
 // Wrapper for the RDP algorithm, returning simplified points
-fn rdp<T>(coords: impl Iterator<Item = Coordinate<T>>, epsilon: &T) -> Vec<Coordinate<T>>
+pub(crate) fn rdp<T>(coords: impl Iterator<Item = Coordinate<T>>, epsilon: &T) -> Vec<Coordinate<T>>
 where
     T: GeoFloat,
 {
@@ -73,7 +75,12 @@ where
         .enumerate()
         .take(rdp_indices.len() - 1) // Don't include the last index
         .skip(1) // Don't include the first index
-        .map(|(index, rdp_index)| (index, rdp_index.coord.euclidean_distance(&first_last_line)))
+        .map(|(index, rdp_index)| {
+            (
+                index,
+                point_line_euclidean_distance(rdp_index.coord, first_last_line),
+            )
+        })
         .fold(
             (0usize, T::zero()),
             |(farthest_index, farthest_distance), (index, distance)| {
@@ -99,6 +106,55 @@ where
     }
 }
 
+// The epsilon at which the RDP algorithm would first remove each coordinate: the least of its
+// distance from the line it's tested against, and the distances of the coordinates whose
+// retention led to it being tested. The ends of the line are always retained.
+fn rdp_importance<T>(coords: &[Coordinate<T>]) -> Vec<T>
+where
+    T: GeoFloat,
+{
+    let mut importance = vec![T::zero(); coords.len()];
+    if coords.is_empty() {
+        return importance;
+    }
+    let last = coords.len() - 1;
+    importance[0] = T::infinity();
+    importance[last] = T::infinity();
+
+    // The spans still to be split, with the importance of the coordinates which bound them
+    let mut spans = vec![(0, last, T::infinity())];
+    while let Some((first, last, bound)) = spans.pop() {
+        let first_last_line = Line::new(coords[first], coords[last]);
+        // Find the farthest coordinate from `first_last_line`, as `compute_rdp` does
+        let (farthest_index, farthest_distance) = (first + 1..last)
+            .map(|index| {
+                (
+                    index,
+                    point_line_euclidean_distance(coords[index], first_last_line),
+                )
+            })
+            .fold(
+                (first, T::zero()),
+                |(farthest_index, farthest_distance), (index, distance)| {
+                    if distance > farthest_distance {
+                        (index, distance)
+                    } else {
+                        (farthest_index, farthest_distance)
+                    }
+                },
+            );
+        if farthest_index == first {
+            // Every coordinate in between is on the line, and is removed at any epsilon
+            continue;
+        }
+        let farthest_importance = farthest_distance.min(bound);
+        importance[farthest_index] = farthest_importance;
+        spans.push((first, farthest_index, farthest_importance));
+        spans.push((farthest_index, last, farthest_importance));
+    }
+    importance
+}
+
 /// Simplifies a geometry.
 ///
 /// The [Ramer–Douglas–Peucker
@@ -181,6 +237,50 @@ pub trait SimplifyIdx<T, Epsilon = T> {
         T: GeoFloat;
 }
 
+/// Ranks the coordinates of a geometry by how long they survive simplification, so a geometry
+/// can be simplified once, then drawn at any tolerance.
+///
+/// The importance of a coordinate is the epsilon at which the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm) would first remove
+/// it: [`simplify`](Simplify::simplify) and [`simplify_idx`](SimplifyIdx::simplify_idx) keep
+/// exactly the coordinates whose importance is greater than a positive epsilon. This makes
+/// zoom-dependent rendering cheap: store the importance alongside each coordinate, and filter on
+/// it for each zoom level rather than simplifying again.
+pub trait SimplifyImportance<T> {
+    /// Returns the importance of each coordinate of a geometry, in order, using the
+    /// [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm. The first and last coordinates are never removed, and have an infinite
+    /// importance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::simplify::{SimplifyIdx, SimplifyImportance};
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 4.0),
+    ///     (x: 11.0, y: 5.5),
+    ///     (x: 17.3, y: 3.2),
+    ///     (x: 27.8, y: 0.1),
+    /// ];
+    ///
+    /// let importance = line_string.simplify_importance();
+    /// assert_eq!(importance[0], f64::INFINITY);
+    ///
+    /// for &epsilon in &[0.5, 1.0, 2.0, 5.0] {
+    ///     let kept: Vec<usize> = (0..importance.len())
+    ///         .filter(|&index| importance[index] > epsilon)
+    ///         .collect();
+    ///     assert_eq!(kept, line_string.simplify_idx(&epsilon));
+    /// }
+    /// ```
+    fn simplify_importance(&self) -> Vec<T>
+    where
+        T: GeoFloat;
+}
+
 impl<T> Simplify<T> for LineString<T>
 where
     T: GeoFloat,
@@ -210,6 +310,15 @@ where
     }
 }
 
+impl<T> SimplifyImportance<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_importance(&self) -> Vec<T> {
+        rdp_importance(&self.0)
+    }
+}
+
 impl<T> Simplify<T> for MultiLineString<T>
 where
     T: GeoFloat,
@@ -287,6 +396,49 @@ mod test {
         assert_eq!(simplified, compare);
     }
 
+    #[test]
+    fn importance_matches_simplification() {
+        // a wiggly line, with some collinear and repeated coordinates
+        let mut coords: Vec<Coordinate<f64>> = (0..200)
+            .map(|i| {
+                let x = i as f64 / 10.;
+                Coordinate {
+                    x,
+                    y: (x * 1.7).sin() * 3. + (x * 5.3).cos(),
+                }
+            })
+            .collect();
+        coords.extend((0..5).map(|i| Coordinate {
+            x: 20. + i as f64,
+            y: 0.,
+        }));
+        coords.push(coords[coords.len() - 1]);
+        let line_string = LineString(coords);
+
+        let importance = line_string.simplify_importance();
+        assert_eq!(importance.len(), line_string.0.len());
+        for &epsilon in &[0.001, 0.01, 0.1, 0.5, 1., 2., 10.] {
+            let kept: Vec<usize> = (0..importance.len())
+                .filter(|&index| importance[index] > epsilon)
+                .collect();
+            assert_eq!(kept, line_string.simplify_idx(&epsilon), "{}", epsilon);
+        }
+    }
+
+    #[test]
+    fn importance_of_short_lines() {
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.simplify_importance().is_empty());
+        let point: LineString<f64> = line_string![(x: 1., y: 1.)];
+        assert_eq!(point.simplify_importance(), vec![f64::INFINITY]);
+        let straight: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+        assert_eq!(
+            straight.simplify_importance(),
+            vec![f64::INFINITY, 0., f64::INFINITY]
+        );
+    }
+
     #[test]
     fn multilinestring() {
         let mline = MultiLineString(vec![LineString::from(vec![
@@ -186,6 +186,70 @@ where
         .collect::<Vec<usize>>()
 }
 
+// The effective area of each point of a line: the greatest triangle area at which the
+// Visvalingam-Whyatt algorithm has removed a point by the time it removes this one, as in
+// Visvalingam and Whyatt's "If [the new triangle's] calculated area is less than that of the
+// last point to be eliminated, use the latter's area instead". The ends of the line are never
+// removed, and are infinitely important.
+fn visvalingam_importance<T>(orig: &LineString<T>) -> Vec<T>
+where
+    T: CoordFloat,
+{
+    let mut importance = vec![T::infinity(); orig.0.len()];
+    if orig.0.len() < 3 {
+        return importance;
+    }
+
+    // Adjacent retained points, as in `visvalingam_indices`
+    let mut adjacent: Vec<(usize, usize)> = (0..orig.0.len())
+        .map(|i| (i.wrapping_sub(1), i + 1))
+        .collect();
+    let mut pq = orig
+        .triangles()
+        .enumerate()
+        .map(|(i, triangle)| VScore {
+            area: triangle.unsigned_area(),
+            current: i + 1,
+            left: i,
+            right: i + 2,
+            intersector: (),
+        })
+        .collect::<BinaryHeap<VScore<T, ()>>>();
+
+    let mut effective_area = T::zero();
+    while let Some(smallest) = pq.pop() {
+        // A point in this triangle has been removed since this VScore was created, so skip it
+        if adjacent[smallest.current] != (smallest.left, smallest.right) {
+            continue;
+        }
+        effective_area = effective_area.max(smallest.area);
+        importance[smallest.current] = effective_area;
+
+        let (left, right) = (smallest.left, smallest.right);
+        let ll = adjacent[left].0;
+        let rr = adjacent[right].1;
+        adjacent[left].1 = right;
+        adjacent[right].0 = left;
+        adjacent[smallest.current] = (usize::MAX, usize::MAX);
+
+        // Now recompute the adjacent triangle(s), using left and right adjacent points
+        for &(ai, current_point, bi) in &[(ll, left, right), (left, right, rr)] {
+            if ai >= orig.0.len() || bi >= orig.0.len() {
+                // Out of bounds, i.e. we're on one edge
+                continue;
+            }
+            pq.push(VScore {
+                area: Triangle(orig.0[ai], orig.0[current_point], orig.0[bi]).unsigned_area(),
+                current: current_point,
+                left: ai,
+                right: bi,
+                intersector: (),
+            });
+        }
+    }
+    importance
+}
+
 // Wrapper for visvalingam_indices, mapping indices back to points
 fn visvalingam<T>(orig: &LineString<T>, epsilon: &T) -> Vec<Coordinate<T>>
 where
@@ -505,6 +569,48 @@ pub trait SimplifyVwIdx<T, Epsilon = T> {
         T: CoordFloat;
 }
 
+/// Ranks the points of a geometry by how long they survive simplification, so a geometry can be
+/// simplified once, then drawn at any tolerance.
+///
+/// The importance of a point is its _effective area_: the epsilon at which the
+/// [Visvalingam-Whyatt](http://www.tandfonline.com/doi/abs/10.1179/000870493786962263)
+/// algorithm would first remove it. [`simplifyvw`](SimplifyVW::simplifyvw) and
+/// [`simplifyvw_idx`](SimplifyVwIdx::simplifyvw_idx) keep exactly the points whose importance is
+/// greater than a positive epsilon, so filtering on a stored importance replaces simplifying
+/// again at each zoom level.
+pub trait SimplifyVwImportance<T> {
+    /// Returns the effective area of each point of a geometry, in order. The first and last
+    /// points are never removed, and have an infinite importance.
+    ///
+    /// See [here](https://bost.ocks.org/mike/simplify/) for a graphical explanation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::simplifyvw::{SimplifyVwIdx, SimplifyVwImportance};
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![
+    ///     (x: 5.0, y: 2.0),
+    ///     (x: 3.0, y: 8.0),
+    ///     (x: 6.0, y: 20.0),
+    ///     (x: 7.0, y: 25.0),
+    ///     (x: 10.0, y: 10.0),
+    /// ];
+    ///
+    /// let importance = line_string.simplifyvw_importance();
+    /// assert_eq!(importance, vec![f64::INFINITY, 29.0, 1.5, 49.5, f64::INFINITY]);
+    ///
+    /// let kept: Vec<usize> = (0..importance.len())
+    ///     .filter(|&index| importance[index] > 30.0)
+    ///     .collect();
+    /// assert_eq!(kept, line_string.simplifyvw_idx(&30.0));
+    /// ```
+    fn simplifyvw_importance(&self) -> Vec<T>
+    where
+        T: CoordFloat;
+}
+
 /// Simplifies a geometry, preserving its topology by removing self-intersections
 ///
 /// An epsilon less than or equal to zero will return an unaltered version of the geometry.
@@ -644,6 +750,15 @@ where
     }
 }
 
+impl<T> SimplifyVwImportance<T> for LineString<T>
+where
+    T: CoordFloat,
+{
+    fn simplifyvw_importance(&self) -> Vec<T> {
+        visvalingam_importance(self)
+    }
+}
+
 impl<T> SimplifyVW<T> for MultiLineString<T>
 where
     T: CoordFloat,
@@ -681,13 +796,36 @@ where
 mod test {
     use super::{
         cartesian_intersect, visvalingam, vwp_wrapper, GeomSettings, GeomType, SimplifyVW,
-        SimplifyVWPreserve,
+        SimplifyVWPreserve, SimplifyVwIdx, SimplifyVwImportance,
     };
     use crate::{
         line_string, point, polygon, Coordinate, LineString, MultiLineString, MultiPolygon, Point,
         Polygon,
     };
 
+    #[test]
+    fn importance_matches_simplification() {
+        let line_string: LineString<f64> = (0..300)
+            .map(|i| {
+                let x = i as f64 / 10.;
+                (x, (x * 1.3).sin() * 4. + (x * 7.1).cos() * 0.5)
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        let importance = line_string.simplifyvw_importance();
+        assert_eq!(importance.len(), 300);
+        for &epsilon in &[0.0001, 0.001, 0.01, 0.1, 1., 10.] {
+            let kept: Vec<usize> = (0..importance.len())
+                .filter(|&index| importance[index] > epsilon)
+                .collect();
+            assert_eq!(kept, line_string.simplifyvw_idx(&epsilon), "{}", epsilon);
+        }
+
+        let short = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(short.simplifyvw_importance(), vec![f64::INFINITY; 2]);
+    }
+
     #[test]
     fn visvalingam_test() {
         // this is the PostGIS example
@@ -0,0 +1,226 @@
+use crate::algorithm::cancel::{Cancel, NeverCancelled};
+use crate::algorithm::erosion::left_normal;
+use crate::algorithm::variable_buffer::union;
+use crate::{Coordinate, GeoError, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+
+// The number of segments approximating a quarter circle, around the vertices where a line turns
+// away from the side buffered
+const QUADRANT_SEGMENTS: usize = 8;
+
+/// Which side of a line to buffer, looking along it.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Buffer a line on one side only, such as the land within a distance inland of a coastline,
+/// where a buffer on both sides would reach out to sea.
+///
+/// The buffer is the union of the rectangle on that side of each segment, as wide as the
+/// distance, and a circular sector, approximated by a polygon, filling the gap between the
+/// rectangles around each vertex where the line turns away from that side. Its ends are flat,
+/// square to the first and last segments, rather than rounded, so it never reaches around the
+/// ends of the line to its other side. A closed line is joined at its first vertex, with no
+/// ends at all.
+pub trait SingleSidedBuffer<T: GeoFloat> {
+    /// The points within `distance` of the line on the given `side` of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::bounding_rect::BoundingRect;
+    /// use geo::algorithm::single_sided_buffer::{SingleSidedBuffer, Side};
+    /// use geo::{line_string, Rect};
+    ///
+    /// // a coastline with the land to its left, and a turn along the coast into a bay
+    /// let coast = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+    /// let landward = coast.single_sided_buffer(2., Side::Left);
+    /// // the strips along each leg of the coast, overlapping at the corner
+    /// assert_relative_eq!(landward.unsigned_area(), 10. * 2. + 10. * 2. - 2. * 2.);
+    /// assert_eq!(landward.bounding_rect().unwrap(), Rect::new((0., 0.), (10., 10.)));
+    /// ```
+    fn single_sided_buffer(&self, distance: T, side: Side) -> MultiPolygon<T> {
+        self.single_sided_buffer_cancellable(distance, side, &NeverCancelled)
+            .expect("never cancelled")
+    }
+
+    /// The buffer, as given by [`single_sided_buffer`](Self::single_sided_buffer), failing with
+    /// [`GeoError::Cancelled`] once `cancel` is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `distance` is negative.
+    fn single_sided_buffer_cancellable(
+        &self,
+        distance: T,
+        side: Side,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<T>, GeoError>;
+}
+
+impl<T: GeoFloat> SingleSidedBuffer<T> for LineString<T> {
+    fn single_sided_buffer_cancellable(
+        &self,
+        distance: T,
+        side: Side,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<T>, GeoError> {
+        assert!(distance >= T::zero(), "distance must not be negative");
+        let mut pieces = vec![];
+        left_pieces(self, distance, side, &mut pieces);
+        union(&pieces, cancel)
+    }
+}
+
+impl<T: GeoFloat> SingleSidedBuffer<T> for MultiLineString<T> {
+    fn single_sided_buffer_cancellable(
+        &self,
+        distance: T,
+        side: Side,
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<T>, GeoError> {
+        assert!(distance >= T::zero(), "distance must not be negative");
+        let mut pieces = vec![];
+        for line_string in self {
+            left_pieces(line_string, distance, side, &mut pieces);
+        }
+        union(&pieces, cancel)
+    }
+}
+
+// The rectangles along the segments of `line_string`, and the sectors joining them, on its
+// `side`, as buffering the line reversed on its left.
+fn left_pieces<T: GeoFloat>(
+    line_string: &LineString<T>,
+    distance: T,
+    side: Side,
+    pieces: &mut Vec<Polygon<T>>,
+) {
+    if distance == T::zero() {
+        return;
+    }
+    let mut coords = line_string.0.clone();
+    coords.dedup();
+    if side == Side::Right {
+        coords.reverse();
+    }
+    let normals: Vec<Coordinate<T>> = coords
+        .windows(2)
+        .map(|edge| left_normal(edge[0], edge[1]) * distance)
+        .collect();
+    for (edge, normal) in coords.windows(2).zip(&normals) {
+        pieces.push(Polygon::new(
+            vec![edge[0], edge[1], edge[1] + *normal, edge[0] + *normal].into(),
+            vec![],
+        ));
+    }
+
+    // the joins between consecutive segments, and between the last and first of a closed line
+    let is_closed = coords.len() > 3 && coords.first() == coords.last();
+    let joins = normals
+        .windows(2)
+        .enumerate()
+        .map(|(index, pair)| (index + 1, pair[0], pair[1]));
+    let closing = match (normals.first(), normals.last()) {
+        (Some(first), Some(last)) if is_closed => Some((0, *last, *first)),
+        _ => None,
+    };
+    let quadrant = T::from(core::f64::consts::FRAC_PI_2).unwrap();
+    for (vertex, prev, next) in joins.chain(closing) {
+        // only where the line turns right, away from its left
+        let cross = prev.x * next.y - prev.y * next.x;
+        if cross >= T::zero() {
+            continue;
+        }
+        let sweep = cross.abs().atan2(prev.x * next.x + prev.y * next.y);
+        let steps = (sweep / quadrant * T::from(QUADRANT_SEGMENTS).unwrap())
+            .ceil()
+            .to_usize()
+            .unwrap()
+            .max(1);
+        let start_angle = prev.y.atan2(prev.x);
+        let vertex = coords[vertex];
+        let mut sector = vec![vertex, vertex + prev];
+        for step in 1..steps {
+            let angle = start_angle - sweep * T::from(step).unwrap() / T::from(steps).unwrap();
+            let (sin, cos) = angle.sin_cos();
+            sector.push(vertex + Coordinate { x: cos, y: sin } * distance);
+        }
+        sector.push(vertex + next);
+        pieces.push(Polygon::new(sector.into(), vec![]));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+    use crate::{line_string, Rect};
+
+    #[test]
+    fn either_side_of_a_line() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let left = line.single_sided_buffer(2., Side::Left);
+        assert_eq!(
+            left.bounding_rect().unwrap(),
+            Rect::new((0., 0.), (10., 2.))
+        );
+        let right = line.single_sided_buffer(2., Side::Right);
+        assert_eq!(
+            right.bounding_rect().unwrap(),
+            Rect::new((0., -2.), (10., 0.))
+        );
+        assert!(line.single_sided_buffer(0., Side::Left).0.is_empty());
+    }
+
+    #[test]
+    fn corners_are_rounded_outside_turns() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let outside = line.single_sided_buffer(2., Side::Right);
+        // the strips, and a quarter circle approximated by 8 triangles
+        let sector = 8. * 2. * (std::f64::consts::PI / 16.).sin();
+        assert_relative_eq!(outside.unsigned_area(), 40. + sector, epsilon = 1e-9);
+        let corner = Coordinate { x: 11.2, y: -1.2 };
+        assert_eq!(outside.coordinate_position(&corner), CoordPos::Inside);
+        // with no corner squared off beyond the circle
+        let beyond = Coordinate { x: 11.8, y: -1.8 };
+        assert_eq!(outside.coordinate_position(&beyond), CoordPos::Outside);
+    }
+
+    #[test]
+    fn closed_lines_and_parts() {
+        // a counter-clockwise square, buffered inwards and outwards
+        let ring: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let inwards = ring.single_sided_buffer(1., Side::Left);
+        assert_eq!(inwards.0.len(), 1);
+        assert_eq!(inwards.0[0].interiors().len(), 1);
+        assert_relative_eq!(inwards.unsigned_area(), 100. - 64.);
+        let outwards = ring.single_sided_buffer(1., Side::Right);
+        assert_eq!(outwards.0[0].interiors().len(), 1);
+        assert!(outwards.unsigned_area() > 40. + 3.);
+
+        let lines = MultiLineString(vec![
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+            line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+        ]);
+        let strips = lines.single_sided_buffer(1., Side::Left);
+        assert_eq!(strips.0.len(), 2);
+        assert_relative_eq!(strips.unsigned_area(), 20.);
+    }
+}
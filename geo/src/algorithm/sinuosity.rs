@@ -0,0 +1,231 @@
+use crate::algorithm::angles::{runs, turn_angle};
+use crate::algorithm::euclidean_distance::EuclideanDistance;
+use crate::algorithm::resample::resample_at_spacing;
+use crate::{Coordinate, GeoFloat, LineString};
+use alloc::vec::Vec;
+
+/// The sinuosity of a line: how much longer it is than the straight line between its ends.
+///
+/// Sinuosity is the ratio of the length of a line to the distance between its first and last
+/// coordinates, so it's `1` for a straight line, and grows as the line meanders. It's a common
+/// measure of how much a river, or a road, winds.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::sinuosity::Sinuosity;
+/// use geo::{line_string, LineString};
+///
+/// let meander: LineString<f64> = line_string![
+///     (x: 0., y: 0.),
+///     (x: 3., y: 4.),
+///     (x: 6., y: 0.),
+/// ];
+/// assert_relative_eq!(meander.sinuosity().unwrap(), 10. / 6.);
+///
+/// // a loop has no straight line distance to compare against
+/// let ring: LineString<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)];
+/// assert!(ring.sinuosity().is_none());
+/// ```
+pub trait Sinuosity<T: GeoFloat> {
+    /// The length of the line divided by the distance between its ends, or `None` if its ends
+    /// are the same point.
+    fn sinuosity(&self) -> Option<T>;
+}
+
+impl<T: GeoFloat> Sinuosity<T> for LineString<T> {
+    fn sinuosity(&self) -> Option<T> {
+        let (first, last) = (self.0.first()?, self.0.last()?);
+        let chord = first.euclidean_distance(last);
+        if chord == T::zero() {
+            return None;
+        }
+        let length = self.0.windows(2).fold(T::zero(), |length, segment| {
+            length + segment[0].euclidean_distance(&segment[1])
+        });
+        Some(length / chord)
+    }
+}
+
+/// Discrete curvature along a line.
+///
+/// The curvature at a vertex is the angle turned through there, in radians, divided by the mean
+/// length of the segments on either side of it, so it's the reciprocal of the radius of a
+/// circle through evenly spaced vertices. As with [`TurnAngles`], it's positive where the line
+/// turns left, counter-clockwise, and negative where it turns right.
+///
+/// Curvature at the vertices depends on how densely the line was digitized. A profile sampled at
+/// a fixed spacing along the line is comparable between lines, and smooths out detail finer than
+/// the spacing.
+///
+/// [`TurnAngles`]: crate::algorithm::angles::TurnAngles
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::sinuosity::Curvature;
+/// use geo::{line_string, LineString};
+///
+/// // a left turn of 90°, between segments of length 2
+/// let bend: LineString<f64> = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+/// let curvature = bend.curvature();
+/// assert_relative_eq!(curvature[0], std::f64::consts::FRAC_PI_2 / 2.);
+///
+/// // sampled every 1 along the line, the segments around the corner are half as long
+/// let profile = bend.curvature_profile(1.);
+/// let distances: Vec<f64> = profile.iter().map(|(distance, _)| *distance).collect();
+/// assert_eq!(distances, vec![1., 2., 3.]);
+/// assert_relative_eq!(profile[1].1, std::f64::consts::FRAC_PI_2);
+/// ```
+pub trait Curvature<T: GeoFloat> {
+    /// The curvature at each vertex of the line but its first and last, in order.
+    ///
+    /// Repeated coordinates are skipped when finding the segments on either side of a vertex, so
+    /// each repetition of a vertex gets the same curvature. A vertex with no distinct coordinate
+    /// before or after it has a curvature of `0`.
+    fn curvature(&self) -> Vec<T>;
+
+    /// The curvature at points every `spacing` along the line, as pairs of the distance along
+    /// the line and the curvature there.
+    ///
+    /// The line is resampled at every multiple of `spacing`, and at its end, and the curvature
+    /// is taken at each sample but the first and last. A `spacing` which isn't positive gives an
+    /// empty profile.
+    fn curvature_profile(&self, spacing: T) -> Vec<(T, T)>;
+}
+
+impl<T: GeoFloat> Curvature<T> for LineString<T> {
+    fn curvature(&self) -> Vec<T> {
+        vertex_curvature(&self.0)
+    }
+
+    fn curvature_profile(&self, spacing: T) -> Vec<(T, T)> {
+        if spacing.is_nan() || spacing <= T::zero() || self.0.is_empty() {
+            return Vec::new();
+        }
+        let (distances, coords) = resample_at_spacing(&self.0, spacing);
+        distances[1..]
+            .iter()
+            .copied()
+            .zip(vertex_curvature(&coords))
+            .collect()
+    }
+}
+
+// The curvature at each vertex of `coords` but the first and last.
+fn vertex_curvature<T: GeoFloat>(coords: &[Coordinate<T>]) -> Vec<T> {
+    if coords.len() < 3 {
+        return Vec::new();
+    }
+
+    let runs = runs(coords);
+    let two = T::one() + T::one();
+    let mut curvatures = Vec::with_capacity(coords.len());
+    for (index, &(coord, count)) in runs.iter().enumerate() {
+        let curvature = if index == 0 || index == runs.len() - 1 {
+            T::zero()
+        } else {
+            let (prev, next) = (runs[index - 1].0, runs[index + 1].0);
+            let turn = turn_angle(prev, coord, next).to_radians();
+            turn * two / (prev.euclidean_distance(&coord) + coord.euclidean_distance(&next))
+        };
+        curvatures.resize(curvatures.len() + count, curvature);
+    }
+
+    // The ends of the line aren't vertices of it
+    curvatures.pop();
+    curvatures.remove(0);
+    curvatures
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn sinuosity() {
+        let straight: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        assert_relative_eq!(straight.sinuosity().unwrap(), 1.);
+
+        let zigzag: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 1.),
+            (x: 2., y: 0.),
+            (x: 3., y: 1.),
+            (x: 4., y: 0.),
+        ];
+        assert_relative_eq!(zigzag.sinuosity().unwrap(), 2f64.sqrt());
+
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.sinuosity().is_none());
+        let point: LineString<f64> = line_string![(x: 1., y: 1.)];
+        assert!(point.sinuosity().is_none());
+    }
+
+    #[test]
+    fn curvature_of_a_circle() {
+        // a semicircle of radius 10, with 100 segments
+        let radius = 10.;
+        let arc: LineString<f64> = (0..=100)
+            .map(|i| {
+                let angle = PI * i as f64 / 100.;
+                (radius * angle.cos(), radius * angle.sin())
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        let curvature = arc.curvature();
+        assert_eq!(curvature.len(), 99);
+        for k in &curvature {
+            assert_relative_eq!(*k, 1. / radius, epsilon = 1e-4);
+        }
+
+        // clockwise, it curves the other way
+        let mut reversed = arc.clone();
+        reversed.0.reverse();
+        assert!(reversed.curvature().iter().all(|&k| k < 0.));
+
+        let profile = arc.curvature_profile(0.5);
+        // the arc is 10π long
+        assert_eq!(profile.len(), 62);
+        assert_relative_eq!(profile[61].0, 31.);
+        for (_, k) in &profile {
+            assert_relative_eq!(*k, 1. / radius, epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn curvature_skips_repeated_coordinates() {
+        let bend: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+        ];
+        let curvature = bend.curvature();
+        assert_eq!(curvature.len(), 2);
+        assert_relative_eq!(curvature[0], PI / 2.);
+        assert_relative_eq!(curvature[1], PI / 2.);
+
+        let straight: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 3., y: 0.)];
+        assert_eq!(straight.curvature(), vec![0.]);
+    }
+
+    #[test]
+    fn profile_ends_at_the_end_of_the_line() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 2.5, y: 0.)];
+        let profile = line.curvature_profile(1.);
+        assert_eq!(profile, vec![(1., 0.), (2., 0.)]);
+
+        assert!(line.curvature_profile(0.).is_empty());
+        assert!(line.curvature_profile(f64::NAN).is_empty());
+        let empty: LineString<f64> = line_string![];
+        assert!(empty.curvature_profile(1.).is_empty());
+    }
+}
@@ -0,0 +1,207 @@
+use num_traits::FromPrimitive;
+
+use crate::algorithm::chaikin_smoothing::ChaikinSmoothing;
+use crate::{CoordFloat, LineString, MultiLineString, MultiPolygon, Polygon};
+
+/// How [`Smooth`] smoothes a geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmoothingMethod<T> {
+    /// Cut each corner, `iterations` times, as [`ChaikinSmoothing`] does. The result no longer
+    /// passes through the vertices of the geometry, except the ends of open lines.
+    Chaikin { iterations: usize },
+    /// Interpolate a cardinal spline through the vertices of the geometry, dividing the curve
+    /// between each pair into `segments` straight segments.
+    ///
+    /// A `tension` of zero gives a Catmull–Rom spline, and one of one follows the original
+    /// lines; in between, the curve bulges less beyond them.
+    CatmullRom { segments: usize, tension: T },
+}
+
+/// Smooth jagged lines and the rings of polygons, for display at a larger scale.
+///
+/// Closed rings remain closed, and smoothed without a corner where they start and end.
+pub trait Smooth<T: CoordFloat + FromPrimitive> {
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::smooth::{Smooth, SmoothingMethod};
+    /// use geo::{line_string, Coordinate};
+    ///
+    /// let zigzag = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.), (x: 3., y: 1.)];
+    /// let spline = zigzag.smooth(SmoothingMethod::CatmullRom {
+    ///     segments: 4,
+    ///     tension: 0.,
+    /// });
+    /// assert_eq!(spline.0.len(), 3 * 4 + 1);
+    /// // through each of the vertices
+    /// assert_eq!(spline.0[4], Coordinate { x: 1., y: 1. });
+    /// assert_eq!(spline.0[8], Coordinate { x: 2., y: 0. });
+    /// // and curving between them, rather than running straight
+    /// assert!(spline.0[3].y > 0.75);
+    ///
+    /// let cut = zigzag.smooth(SmoothingMethod::Chaikin { iterations: 2 });
+    /// assert_eq!(cut.0.len(), 16);
+    /// ```
+    fn smooth(&self, method: SmoothingMethod<T>) -> Self;
+}
+
+impl<T> Smooth<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn smooth(&self, method: SmoothingMethod<T>) -> Self {
+        match method {
+            SmoothingMethod::Chaikin { iterations } => self.chaikin_smoothing(iterations),
+            SmoothingMethod::CatmullRom { segments, tension } => {
+                cardinal_spline(self, segments, tension)
+            }
+        }
+    }
+}
+
+impl<T> Smooth<T> for MultiLineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn smooth(&self, method: SmoothingMethod<T>) -> Self {
+        MultiLineString(self.0.iter().map(|line| line.smooth(method)).collect())
+    }
+}
+
+impl<T> Smooth<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn smooth(&self, method: SmoothingMethod<T>) -> Self {
+        Polygon::new(
+            self.exterior().smooth(method),
+            self.interiors()
+                .iter()
+                .map(|ring| ring.smooth(method))
+                .collect(),
+        )
+    }
+}
+
+impl<T> Smooth<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn smooth(&self, method: SmoothingMethod<T>) -> Self {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|polygon| polygon.smooth(method))
+                .collect(),
+        )
+    }
+}
+
+fn cardinal_spline<T>(line: &LineString<T>, segments: usize, tension: T) -> LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    let closed = line.is_closed() && line.0.len() >= 4;
+    // a closed ring's vertices, without the repeated last one
+    let coords = if closed {
+        &line.0[..line.0.len() - 1]
+    } else {
+        &line.0[..]
+    };
+    if segments <= 1 || coords.len() < 2 {
+        return line.clone();
+    }
+    let count = coords.len();
+    let two = T::one() + T::one();
+    let three = two + T::one();
+    let scale = (T::one() - tension) / two;
+
+    // the tangent at each vertex, from its neighbours, or from the vertex itself at the ends of
+    // an open line
+    let tangent = |index: usize| {
+        let (previous, next) = if closed {
+            ((index + count - 1) % count, (index + 1) % count)
+        } else {
+            (index.saturating_sub(1), (index + 1).min(count - 1))
+        };
+        let scale = if !closed && (index == 0 || index == count - 1) {
+            scale * two
+        } else {
+            scale
+        };
+        (coords[next] - coords[previous]) * scale
+    };
+
+    let spans = if closed { count } else { count - 1 };
+    let mut out = Vec::with_capacity(spans * segments + 1);
+    for span in 0..spans {
+        let (start, end) = (coords[span], coords[(span + 1) % count]);
+        let (start_tangent, end_tangent) = (tangent(span), tangent((span + 1) % count));
+        out.push(start);
+        for step in 1..segments {
+            // the cubic Hermite basis functions
+            let t = T::from(step).unwrap() / T::from(segments).unwrap();
+            let (t2, t3) = (t * t, t * t * t);
+            let h00 = two * t3 - three * t2 + T::one();
+            let h10 = t3 - two * t2 + t;
+            let h01 = three * t2 - two * t3;
+            let h11 = t3 - t2;
+            out.push(start * h00 + start_tangent * h10 + end * h01 + end_tangent * h11);
+        }
+    }
+    out.push(if closed { coords[0] } else { coords[count - 1] });
+    LineString(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Coordinate};
+
+    #[test]
+    fn closed_rings_stay_closed() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let round = square.smooth(SmoothingMethod::CatmullRom {
+            segments: 8,
+            tension: 0.,
+        });
+        let ring = &round.exterior().0;
+        assert_eq!(ring.len(), 4 * 8 + 1);
+        assert_eq!(ring[0], ring[ring.len() - 1]);
+        assert_eq!(ring[8], Coordinate { x: 2., y: 0. });
+        // symmetric around the centre, without a kink where the ring starts
+        for coord in ring {
+            let mirrored = Coordinate {
+                x: 2. - coord.x,
+                y: 2. - coord.y,
+            };
+            assert!(ring.iter().any(|other| (other.x - mirrored.x).abs() < 1e-12
+                && (other.y - mirrored.y).abs() < 1e-12));
+        }
+        assert!(round
+            .smooth(SmoothingMethod::Chaikin { iterations: 1 })
+            .exterior()
+            .is_closed());
+    }
+
+    #[test]
+    fn full_tension_keeps_the_lines() {
+        let line = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let smoothed = line.smooth(SmoothingMethod::CatmullRom {
+            segments: 4,
+            tension: 1.,
+        });
+        assert_eq!(smoothed.0.len(), 9);
+        assert_eq!(smoothed.0[4], Coordinate { x: 4., y: 0. });
+        // each point lies on one of the lines, though not evenly along it
+        for coord in smoothed.0 {
+            assert!((coord.y == 0. && coord.x <= 4.) || (coord.x == 4. && coord.y <= 4.));
+        }
+        let unchanged = line.smooth(SmoothingMethod::CatmullRom {
+            segments: 1,
+            tension: 0.,
+        });
+        assert_eq!(unchanged, line);
+    }
+}
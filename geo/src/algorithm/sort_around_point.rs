@@ -0,0 +1,141 @@
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::algorithm::relate::Quadrant;
+use crate::{Coordinate, GeoNum};
+use std::cmp::Ordering;
+
+/// Sort `points` by the direction of the ray from `origin` to each of them, counter-clockwise
+/// from the positive x axis.
+///
+/// Directions are compared exactly, as [`compare_around_point`] does, so rays which are nearly
+/// parallel are never misordered by rounding, as they can be when sorting by angle. The sort
+/// is stable: points in the same direction from `origin` keep their order. Points at `origin`
+/// come first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::sort_around_point::sort_around_point;
+/// use geo::Coordinate;
+///
+/// let origin = Coordinate { x: 1., y: 1. };
+/// let mut points: Vec<Coordinate<f64>> =
+///     vec![(1., 0.).into(), (0., 1.).into(), (2., 1.).into(), (1., 2.).into()];
+/// sort_around_point(origin, &mut points);
+///
+/// // east, north, west, then south
+/// assert_eq!(
+///     points,
+///     vec![(2., 1.).into(), (1., 2.).into(), (0., 1.).into(), (1., 0.).into()]
+/// );
+/// ```
+pub fn sort_around_point<T: GeoNum>(origin: Coordinate<T>, points: &mut [Coordinate<T>]) {
+    points.sort_by(|a, b| compare_around_point(origin, *a, *b));
+}
+
+/// Compare the directions of the rays from `origin` to `a` and to `b`, counter-clockwise from
+/// the positive x axis.
+///
+/// The rays are first compared by the quadrant they point into, and then, within a quadrant,
+/// by the orientation of `origin`, `a` and `b`, using the robust predicates of
+/// [`Kernel::orient2d`]. This is the ordering used to sort the edges around each node of the
+/// topology graphs built by [`Relate`](crate::algorithm::relate::Relate).
+///
+/// Rays in the same direction compare equal, however long they are. A point at `origin` has no
+/// direction, and compares less than any other.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::sort_around_point::compare_around_point;
+/// use geo::Coordinate;
+/// use std::cmp::Ordering;
+///
+/// let origin = Coordinate { x: 0, y: 0 };
+/// let north = Coordinate { x: 0, y: 1 };
+/// let south_east = Coordinate { x: 1, y: -1 };
+/// assert_eq!(compare_around_point(origin, north, south_east), Ordering::Less);
+/// assert_eq!(
+///     compare_around_point(origin, north, Coordinate { x: 0, y: 5 }),
+///     Ordering::Equal
+/// );
+/// ```
+pub fn compare_around_point<T: GeoNum>(
+    origin: Coordinate<T>,
+    a: Coordinate<T>,
+    b: Coordinate<T>,
+) -> Ordering {
+    let quadrant_a = Quadrant::new(a.x - origin.x, a.y - origin.y);
+    let quadrant_b = Quadrant::new(b.x - origin.x, b.y - origin.y);
+    match (quadrant_a, quadrant_b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(quadrant_a), Some(quadrant_b)) if quadrant_a < quadrant_b => Ordering::Less,
+        (Some(quadrant_a), Some(quadrant_b)) if quadrant_a > quadrant_b => Ordering::Greater,
+        _ => match T::Ker::orient2d(origin, a, b) {
+            Orientation::CounterClockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn axes_and_diagonals() {
+        let origin = Coordinate { x: 0., y: 0. };
+        // every 45 degrees, counter-clockwise from east
+        let expected: Vec<Coordinate<f64>> = vec![
+            (1., 0.).into(),
+            (1., 1.).into(),
+            (0., 1.).into(),
+            (-1., 1.).into(),
+            (-1., 0.).into(),
+            (-1., -1.).into(),
+            (0., -1.).into(),
+            (1., -1.).into(),
+        ];
+        let mut points: Vec<Coordinate<f64>> = expected.iter().rev().cloned().collect();
+        points.rotate_left(3);
+        sort_around_point(origin, &mut points);
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn nearly_parallel_rays() {
+        // rays about 1e-33 radians apart
+        let origin = Coordinate { x: 0., y: 0. };
+        let a = Coordinate { x: 1e17, y: 1. };
+        let b = Coordinate {
+            x: 1e17 - 16.,
+            y: 1.,
+        };
+        assert_eq!(compare_around_point(origin, a, b), Ordering::Less);
+        assert_eq!(compare_around_point(origin, b, a), Ordering::Greater);
+    }
+
+    #[test]
+    fn same_direction_and_origin() {
+        let origin = Coordinate { x: 2, y: 2 };
+        let mut points = vec![
+            Coordinate { x: 6, y: 6 },
+            Coordinate { x: 2, y: 0 },
+            Coordinate { x: 3, y: 3 },
+            origin,
+        ];
+        sort_around_point(origin, &mut points);
+        // the origin first, then the rays to the north east, in their original order
+        assert_eq!(
+            points,
+            vec![
+                origin,
+                Coordinate { x: 6, y: 6 },
+                Coordinate { x: 3, y: 3 },
+                Coordinate { x: 2, y: 0 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,346 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::linework::segments;
+use crate::algorithm::progress::Progress;
+use crate::algorithm::relate::Relate;
+use crate::algorithm::segment::line_square_distance;
+use crate::{GeoFloat, Geometry, Line, Point, Rect};
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+/// The predicate by which [`spatial_join`] pairs geometries, each from the left to the right.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinPredicate<T> {
+    /// The geometries share at least one point
+    Intersects,
+    /// The left geometry contains the right
+    Contains,
+    /// The left geometry is within the right
+    Within,
+    /// The geometries are no further apart than the given distance
+    DWithin(T),
+}
+
+/// Join two sets of geometries by a predicate, returning the index of each matching pair, with
+/// the index into `left` first, in order.
+///
+/// The bounding rectangles of `right` are indexed in an R*-tree, and each geometry of `left` is
+/// only tested against those whose rectangles intersect its own, or come within the distance of
+/// [`JoinPredicate::DWithin`]. Pairs are tested with [`Relate`], and for `DWithin` by the least
+/// distance between their segments.
+///
+/// # Panics
+///
+/// Panics if the distance of `DWithin` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::spatial_join::{spatial_join, JoinPredicate};
+/// use geo::{point, polygon};
+///
+/// let stops = vec![point!(x: 1., y: 1.), point!(x: 5., y: 1.), point!(x: 12., y: 1.)];
+/// let zones = vec![
+///     polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     polygon![(x: 4., y: 0.), (x: 8., y: 0.), (x: 8., y: 4.), (x: 4., y: 4.)],
+/// ];
+///
+/// assert_eq!(
+///     spatial_join(&stops, &zones, JoinPredicate::Within),
+///     vec![(0, 0), (1, 1)]
+/// );
+/// assert_eq!(
+///     spatial_join(&zones, &stops, JoinPredicate::DWithin(4.)),
+///     vec![(0, 0), (0, 1), (1, 0), (1, 1), (1, 2)]
+/// );
+/// ```
+pub fn spatial_join<T, L, R>(
+    left: &[L],
+    right: &[R],
+    predicate: JoinPredicate<T>,
+) -> Vec<(usize, usize)>
+where
+    T: GeoFloat + RTreeNum,
+    L: BoundingRect<T> + Relate<T, R> + Clone + Into<Geometry<T>>,
+    L::Output: Into<Option<Rect<T>>>,
+    R: BoundingRect<T> + Clone + Into<Geometry<T>>,
+    R::Output: Into<Option<Rect<T>>>,
+{
+    spatial_join_with_progress(left, right, predicate, &mut |_, _| {})
+}
+
+/// Join two sets of geometries by a predicate, as [`spatial_join`] does, reporting to `progress`
+/// how many of the geometries of `left` have been joined, out of them all.
+///
+/// # Panics
+///
+/// Panics if the distance of `DWithin` is negative.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::spatial_join::{spatial_join_with_progress, JoinPredicate};
+/// use geo::{point, polygon};
+///
+/// let stops = vec![point!(x: 1., y: 1.), point!(x: 5., y: 1.), point!(x: 12., y: 1.)];
+/// let zones = vec![polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)]];
+///
+/// let mut percentages = vec![];
+/// let pairs = spatial_join_with_progress(
+///     &stops,
+///     &zones,
+///     JoinPredicate::Within,
+///     &mut |processed, total| percentages.push(100 * processed / total),
+/// );
+/// assert_eq!(pairs, vec![(0, 0)]);
+/// assert_eq!(percentages, vec![0, 33, 66, 100]);
+/// ```
+pub fn spatial_join_with_progress<T, L, R>(
+    left: &[L],
+    right: &[R],
+    predicate: JoinPredicate<T>,
+    progress: &mut dyn Progress,
+) -> Vec<(usize, usize)>
+where
+    T: GeoFloat + RTreeNum,
+    L: BoundingRect<T> + Relate<T, R> + Clone + Into<Geometry<T>>,
+    L::Output: Into<Option<Rect<T>>>,
+    R: BoundingRect<T> + Clone + Into<Geometry<T>>,
+    R::Output: Into<Option<Rect<T>>>,
+{
+    let margin = match predicate {
+        JoinPredicate::DWithin(distance) => {
+            assert!(distance >= T::zero(), "distance must not be negative");
+            distance
+        }
+        _ => T::zero(),
+    };
+    let envelope = |rect: Rect<T>| {
+        let offset = Point::new(margin, margin);
+        AABB::from_corners(Point(rect.min()) - offset, Point(rect.max()) + offset)
+    };
+    let tree = RTree::bulk_load(
+        right
+            .iter()
+            .enumerate()
+            .filter_map(|(index, geometry)| {
+                geometry.bounding_rect().into().map(|rect| IndexedEnvelope {
+                    envelope: AABB::from_corners(rect.min().into(), rect.max().into()),
+                    index,
+                })
+            })
+            .collect(),
+    );
+
+    // the segments of each geometry, only needed for `DWithin`
+    let (left_segments, right_segments) = match predicate {
+        JoinPredicate::DWithin(_) => (
+            left.iter()
+                .map(|g| segments::<T, Geometry<T>>(&g.clone().into()))
+                .collect(),
+            right
+                .iter()
+                .map(|g| segments::<T, Geometry<T>>(&g.clone().into()))
+                .collect(),
+        ),
+        _ => (vec![], vec![]),
+    };
+
+    let mut pairs = vec![];
+    progress.report(0, left.len());
+    for (a, geometry) in left.iter().enumerate() {
+        let rect = match geometry.bounding_rect().into() {
+            Some(rect) => rect,
+            None => {
+                progress.report(a + 1, left.len());
+                continue;
+            }
+        };
+        let mut candidates: Vec<usize> = tree
+            .locate_in_envelope_intersecting(&envelope(rect))
+            .map(|other| other.index)
+            .collect();
+        candidates.sort_unstable();
+        for b in candidates {
+            let is_match = match predicate {
+                JoinPredicate::Intersects => geometry.relate(&right[b]).is_intersects(),
+                JoinPredicate::Contains => geometry.relate(&right[b]).is_contains(),
+                JoinPredicate::Within => geometry.relate(&right[b]).is_within(),
+                JoinPredicate::DWithin(distance) => {
+                    is_within_distance(&left_segments[a], &right_segments[b], distance)
+                        // one may be inside the other, far from its boundary
+                        || geometry.relate(&right[b]).is_intersects()
+                }
+            };
+            if is_match {
+                pairs.push((a, b));
+            }
+        }
+        progress.report(a + 1, left.len());
+    }
+    pairs
+}
+
+// Whether any segment of `a` comes within `distance` of one of `b`.
+fn is_within_distance<T: GeoFloat>(a: &[Line<T>], b: &[Line<T>], distance: T) -> bool {
+    let square = distance * distance;
+    a.iter()
+        .any(|a| b.iter().any(|b| line_square_distance(*a, *b) <= square))
+}
+
+// The bounding rectangle of a geometry, as stored in the R*-tree.
+#[derive(Clone)]
+struct IndexedEnvelope<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    envelope: AABB<Point<T>>,
+    index: usize,
+}
+
+impl<T> RTreeObject for IndexedEnvelope<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, LineString};
+
+    // Every pair matching the predicate, by testing them all.
+    fn nested_loop_join(
+        left: &[Geometry<f64>],
+        right: &[Geometry<f64>],
+        predicate: JoinPredicate<f64>,
+    ) -> Vec<(usize, usize)> {
+        let mut pairs = vec![];
+        for (a, l) in left.iter().enumerate() {
+            for (b, r) in right.iter().enumerate() {
+                let matrix = l.relate(r);
+                let is_match = match predicate {
+                    JoinPredicate::Intersects => matrix.is_intersects(),
+                    JoinPredicate::Contains => matrix.is_contains(),
+                    JoinPredicate::Within => matrix.is_within(),
+                    JoinPredicate::DWithin(distance) => {
+                        matrix.is_intersects()
+                            || is_within_distance(&segments(l), &segments(r), distance)
+                    }
+                };
+                if is_match {
+                    pairs.push((a, b));
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn matches_a_nested_loop() {
+        // a grid of squares, against points and lines scattered over it
+        let squares: Vec<Geometry<f64>> = (0..5)
+            .flat_map(|i| {
+                (0..5).map(move |j| {
+                    let (x, y) = (i as f64 * 2., j as f64 * 2.);
+                    polygon![
+                        (x: x, y: y),
+                        (x: x + 1.5, y: y),
+                        (x: x + 1.5, y: y + 1.5),
+                        (x: x, y: y + 1.5),
+                    ]
+                    .into()
+                })
+            })
+            .collect();
+        let mut seed: u64 = 3;
+        let mut random = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 11) as f64 / (1u64 << 53) as f64 * 10.
+        };
+        let mut others: Vec<Geometry<f64>> = vec![];
+        for _ in 0..30 {
+            others.push(point!(x: random(), y: random()).into());
+            let (x, y) = (random(), random());
+            others.push(line_string![(x: x, y: y), (x: x + 0.3, y: y + 0.2)].into());
+        }
+
+        for predicate in &[
+            JoinPredicate::Intersects,
+            JoinPredicate::Contains,
+            JoinPredicate::Within,
+            JoinPredicate::DWithin(0.4),
+        ] {
+            let pairs = spatial_join(&squares, &others, *predicate);
+            assert_eq!(pairs, nested_loop_join(&squares, &others, *predicate));
+            let pairs = spatial_join(&others, &squares, *predicate);
+            assert_eq!(pairs, nested_loop_join(&others, &squares, *predicate));
+        }
+        assert!(!spatial_join(&others, &squares, JoinPredicate::Within).is_empty());
+    }
+
+    #[test]
+    fn distance_joins() {
+        let frame = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let points = vec![
+            // deep inside the frame, far from its rings
+            point!(x: 1., y: 5.),
+            // in the hole
+            point!(x: 5., y: 5.),
+            point!(x: 3., y: 5.),
+            // outside
+            point!(x: 11., y: 5.),
+        ];
+        assert_eq!(
+            spatial_join(&[frame.clone()], &points, JoinPredicate::DWithin(0.5)),
+            vec![(0, 0)]
+        );
+        assert_eq!(
+            spatial_join(&[frame.clone()], &points, JoinPredicate::DWithin(1.)),
+            vec![(0, 0), (0, 2), (0, 3)]
+        );
+        assert_eq!(
+            spatial_join(&points, &[frame], JoinPredicate::DWithin(0.)),
+            vec![(0, 0)]
+        );
+
+        // lines are as far apart as their closest segments
+        let lines = vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.)],
+            line_string![(x: 0., y: 3.), (x: 4., y: 3.)],
+            line_string![(x: 6., y: 0.), (x: 6., y: 1.)],
+        ];
+        assert_eq!(
+            spatial_join(&lines, &lines, JoinPredicate::DWithin(2.)),
+            vec![(0, 0), (0, 2), (1, 1), (2, 0), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn progress_counts_every_left_geometry() {
+        let square: Geometry<f64> =
+            polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)].into();
+        // the empty line has no bounding rectangle, and so nothing to join
+        let left: Vec<Geometry<f64>> = vec![
+            point!(x: 1., y: 1.).into(),
+            LineString(vec![]).into(),
+            point!(x: 9., y: 9.).into(),
+        ];
+        let mut reports = vec![];
+        let pairs = spatial_join_with_progress(
+            &left,
+            &[square],
+            JoinPredicate::Intersects,
+            &mut |processed, total| reports.push((processed, total)),
+        );
+        assert_eq!(pairs, vec![(0, 0)]);
+        assert_eq!(reports, vec![(0, 3), (1, 3), (2, 3), (3, 3)]);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::algorithm::arrangement::Arrangement;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::erosion::interior_point;
+use crate::algorithm::sweepline::SweepLine;
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoFloat, Line, LineString, Polygon};
+use std::cmp::Ordering;
+use std::iter;
+
+/// Cut a polygon along a line into pieces, like a cookie cutter.
+///
+/// The line may cross the polygon any number of times, through its holes, and cross itself. The
+/// rings of the polygon and the line are noded together, and the parts of the line outside the
+/// polygon dropped. So are the parts inside it which separate nothing: loose ends, running into
+/// the polygon without cutting all the way across, and cuts joining one ring to another, such
+/// as from the exterior into a hole. The faces the remaining edges bound inside the polygon are
+/// the pieces. A line which cuts nothing off leaves the polygon whole, as a single piece.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::split::split_polygon;
+/// use geo::{line_string, polygon};
+///
+/// // a U, whose arms are cut off by a line across them both
+/// let u = polygon![
+///     (x: 0., y: 0.),
+///     (x: 9., y: 0.),
+///     (x: 9., y: 10.),
+///     (x: 6., y: 10.),
+///     (x: 6., y: 3.),
+///     (x: 3., y: 3.),
+///     (x: 3., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+/// let cut = line_string![(x: -1., y: 5.), (x: 10., y: 5.)];
+/// let mut pieces = split_polygon(&u, &cut);
+/// pieces.sort_by(|a, b| a.unsigned_area().partial_cmp(&b.unsigned_area()).unwrap());
+///
+/// assert_eq!(pieces.len(), 3);
+/// assert_relative_eq!(pieces[0].unsigned_area(), 15.);
+/// assert_relative_eq!(pieces[1].unsigned_area(), 15.);
+/// assert_relative_eq!(pieces[2].unsigned_area(), 39.);
+/// ```
+pub fn split_polygon<T: GeoFloat>(polygon: &Polygon<T>, line: &LineString<T>) -> Vec<Polygon<T>> {
+    let rings: Vec<Line<T>> = iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.lines())
+        .collect();
+    let ring_count = rings.len();
+    let two = T::one() + T::one();
+    // the pieces of the rings, and of the line inside the polygon
+    let mut pieces: Vec<(Line<T>, bool)> = SweepLine::new(rings.into_iter().chain(line.lines()))
+        .noded_segments()
+        .into_iter()
+        .filter(|piece| piece.line.start != piece.line.end)
+        .filter_map(|piece| {
+            if piece.source < ring_count {
+                return Some((piece.line, false));
+            }
+            let midpoint = (piece.line.start + piece.line.end) / two;
+            match polygon.coordinate_position(&midpoint) {
+                CoordPos::Inside => Some((piece.line, true)),
+                _ => None,
+            }
+        })
+        .map(|(line, is_cut)| match lex_cmp(&line.start, &line.end) {
+            Ordering::Greater => (Line::new(line.end, line.start), is_cut),
+            _ => (line, is_cut),
+        })
+        .collect();
+    // where the line runs back over itself
+    pieces.sort_by(|a, b| lex_cmp(&a.0.start, &b.0.start).then(lex_cmp(&a.0.end, &b.0.end)));
+    pieces.dedup_by(|a, b| a.0 == b.0);
+    remove_bridges(&mut pieces);
+
+    Arrangement::new(pieces.into_iter().map(|(line, _)| (line, 0)))
+        .faces()
+        .into_iter()
+        .map(|face| face.polygon)
+        .filter(|face| match interior_point(face) {
+            Some(coord) => polygon.coordinate_position(&coord) == CoordPos::Inside,
+            None => false,
+        })
+        .collect()
+}
+
+// Drop the pieces of the line which aren't on any cycle of the graph of pieces, and so have the
+// same face on either side: its loose ends, and cuts which only join one ring to another.
+//
+// PERF: a search of the graph for each piece of the line
+fn remove_bridges<T: GeoFloat>(pieces: &mut Vec<(Line<T>, bool)>) {
+    let mut nodes: Vec<Coordinate<T>> = pieces
+        .iter()
+        .flat_map(|(line, _)| vec![line.start, line.end])
+        .collect();
+    nodes.sort_by(lex_cmp);
+    nodes.dedup();
+    let node_index = |coord: Coordinate<T>| {
+        nodes
+            .binary_search_by(|node| lex_cmp(node, &coord))
+            .expect("every end of a piece is a node")
+    };
+    let ends: Vec<(usize, usize)> = pieces
+        .iter()
+        .map(|(line, _)| (node_index(line.start), node_index(line.end)))
+        .collect();
+    // for each node, the pieces meeting it, and their other ends
+    let mut adjacent = vec![vec![]; nodes.len()];
+    for (index, (start, end)) in ends.iter().enumerate() {
+        adjacent[*start].push((index, *end));
+        adjacent[*end].push((index, *start));
+    }
+
+    let is_bridge: Vec<bool> = pieces
+        .iter()
+        .enumerate()
+        .map(|(bridge, (_, is_cut))| {
+            if !is_cut {
+                return false;
+            }
+            // whether its ends are still joined without it
+            let (start, end) = ends[bridge];
+            let mut is_reached = vec![false; nodes.len()];
+            is_reached[start] = true;
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                for (index, other) in &adjacent[node] {
+                    if *index != bridge && !is_reached[*other] {
+                        is_reached[*other] = true;
+                        stack.push(*other);
+                    }
+                }
+            }
+            !is_reached[end]
+        })
+        .collect();
+    let mut is_bridge = is_bridge.into_iter();
+    pieces.retain(|_| !is_bridge.next().unwrap());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, polygon};
+
+    fn areas(pieces: &[Polygon<f64>]) -> Vec<f64> {
+        let mut areas: Vec<f64> = pieces.iter().map(|piece| piece.unsigned_area()).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        areas
+    }
+
+    #[test]
+    fn cuts_across_a_square() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let pieces = split_polygon(&square, &line_string![(x: 2., y: -1.), (x: 2., y: 11.)]);
+        assert_eq!(areas(&pieces), vec![20., 80.]);
+
+        // in through one side and out through the same side
+        let notch = line_string![(x: 3., y: -1.), (x: 5., y: 2.), (x: 7., y: -1.)];
+        let pieces = split_polygon(&square, &notch);
+        assert_eq!(pieces.len(), 2);
+        assert_relative_eq!(areas(&pieces)[0], 8. / 3.);
+
+        // a line along the boundary, or missing the square, cuts nothing
+        let along = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let outside = line_string![(x: 20., y: 0.), (x: 20., y: 10.)];
+        for line in &[along, outside] {
+            assert_eq!(areas(&split_polygon(&square, line)), vec![100.]);
+        }
+    }
+
+    #[test]
+    fn loose_ends_are_dropped() {
+        let square: Polygon<f64> =
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        // running into the square, and stopping short of cutting across it
+        let stub = line_string![(x: -1., y: 5.), (x: 5., y: 5.), (x: 5., y: 8.)];
+        let pieces = split_polygon(&square, &stub);
+        assert_eq!(pieces.len(), 1);
+        assert_relative_eq!(pieces[0].unsigned_area(), 100.);
+        // where the line met the square, with no slit along it
+        assert_eq!(pieces[0].exterior().0.len(), 6);
+
+        // cutting across, with a loose end branching off the cut
+        let branch = line_string![
+            (x: 3., y: 5.),
+            (x: 5., y: 5.),
+            (x: 5., y: 11.),
+            (x: 12., y: 11.),
+            (x: 12., y: -1.),
+            (x: 5., y: -1.),
+            (x: 5., y: 5.),
+            // and back along the loose end
+            (x: 2., y: 5.),
+        ];
+        assert_eq!(areas(&split_polygon(&square, &branch)), vec![50., 50.]);
+
+        // a loop inside the square is cut out of it
+        let lasso = line_string![
+            (x: -1., y: 5.),
+            (x: 4., y: 5.),
+            (x: 6., y: 3.),
+            (x: 6., y: 7.),
+            (x: 4., y: 5.),
+        ];
+        assert_eq!(areas(&split_polygon(&square, &lasso)), vec![4., 96.]);
+    }
+
+    #[test]
+    fn cuts_through_holes() {
+        let frame: Polygon<f64> = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 3., y: 3.), (x: 7., y: 3.), (x: 7., y: 7.), (x: 3., y: 7.)]],
+        ];
+        // across the frame and its hole, leaving two pieces
+        let pieces = split_polygon(&frame, &line_string![(x: 5., y: -1.), (x: 5., y: 11.)]);
+        assert_eq!(areas(&pieces), vec![42., 42.]);
+        // only through one side, leaving the frame in one piece
+        let pieces = split_polygon(&frame, &line_string![(x: 5., y: -1.), (x: 5., y: 5.)]);
+        assert_eq!(pieces.len(), 1);
+        assert_relative_eq!(pieces[0].unsigned_area(), 84.);
+        assert_eq!(pieces[0].interiors().len(), 1);
+    }
+}
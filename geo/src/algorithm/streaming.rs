@@ -0,0 +1,301 @@
+//! Measure, densify and simplify lines given as iterators of coordinates, in bounded memory.
+//!
+//! These work on lines too large to hold in a [`LineString`](crate::LineString), such as a
+//! multi-gigabyte archive of GPS fixes read from disk: [`length`] and [`bounding_rect`] consume
+//! the coordinates one at a time, and [`densify`] and [`simplify`] are iterator adapters, which
+//! hold no more than a segment, or a chunk, of the line at once.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::line_measures::Euclidean;
+//! use geo::algorithm::streaming;
+//! use geo::Coordinate;
+//!
+//! // a long zigzag, generated rather than stored
+//! let fixes = || {
+//!     (0..=1_000_000).map(|i| Coordinate {
+//!         x: i as f64,
+//!         y: (i % 2) as f64 * 0.001,
+//!     })
+//! };
+//!
+//! let rect = streaming::bounding_rect(fixes()).unwrap();
+//! assert_eq!(rect.max().x, 1_000_000.);
+//!
+//! // the zigzag is within a millimetre of a straight line, so only the ends of its chunks remain
+//! let simplified: Vec<_> = streaming::simplify(fixes(), 0.01, 10_001).collect();
+//! assert_eq!(simplified.len(), 101);
+//! assert_eq!(streaming::length::<Euclidean, _, _>(simplified), 1_000_000.);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::algorithm::line_measures::{Distance, InterpolatePoint};
+use crate::algorithm::simplify::rdp;
+use crate::{CoordFloat, CoordNum, Coordinate, GeoFloat, Point, Rect};
+
+/// The length of the line through `coords`, in the units of `MetricSpace`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::Haversine;
+/// use geo::algorithm::streaming;
+/// use geo::Coordinate;
+///
+/// let track = vec![
+///     Coordinate { x: -0.1278_f64, y: 51.5074 },
+///     Coordinate { x: 2.3522, y: 48.8566 },
+/// ];
+/// // London to Paris, in meters
+/// assert_eq!(streaming::length::<Haversine, _, _>(track).round(), 343_557.);
+/// ```
+pub fn length<MetricSpace, F, I>(coords: I) -> F
+where
+    MetricSpace: Distance<F, Point<F>, Point<F>>,
+    F: CoordFloat,
+    I: IntoIterator<Item = Coordinate<F>>,
+{
+    let mut coords = coords.into_iter();
+    let mut previous = match coords.next() {
+        Some(coord) => coord,
+        None => return F::zero(),
+    };
+    coords.fold(F::zero(), |length, coord| {
+        let segment = MetricSpace::distance(previous.into(), coord.into());
+        previous = coord;
+        length + segment
+    })
+}
+
+/// The smallest [`Rect`] containing `coords`, or `None` if there are none.
+pub fn bounding_rect<T, I>(coords: I) -> Option<Rect<T>>
+where
+    T: CoordNum,
+    I: IntoIterator<Item = Coordinate<T>>,
+{
+    geo_types::private_utils::get_bounding_rect(coords)
+}
+
+/// Add points to the line through `coords` until no segment is longer than
+/// `max_segment_length`, in the units of `MetricSpace`, as
+/// [`Densify`](crate::algorithm::line_measures::Densify) does.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::line_measures::Euclidean;
+/// use geo::algorithm::streaming;
+/// use geo::Coordinate;
+///
+/// let coords = vec![Coordinate { x: 0., y: 0. }, Coordinate { x: 0., y: 6. }];
+/// let densified: Vec<_> = streaming::densify::<Euclidean, _, _>(coords, 2.).collect();
+/// assert_eq!(
+///     densified,
+///     vec![
+///         Coordinate { x: 0., y: 0. },
+///         Coordinate { x: 0., y: 2. },
+///         Coordinate { x: 0., y: 4. },
+///         Coordinate { x: 0., y: 6. },
+///     ]
+/// );
+/// ```
+pub fn densify<MetricSpace, F, I>(
+    coords: I,
+    max_segment_length: F,
+) -> Densify<MetricSpace, F, I::IntoIter>
+where
+    MetricSpace: InterpolatePoint<F>,
+    F: CoordFloat,
+    I: IntoIterator<Item = Coordinate<F>>,
+{
+    Densify {
+        coords: coords.into_iter(),
+        max_segment_length,
+        previous: None,
+        added: vec![].into_iter(),
+        end: None,
+        metric_space: PhantomData,
+    }
+}
+
+/// An iterator over the coordinates of a densified line, from [`densify`].
+#[derive(Debug, Clone)]
+pub struct Densify<MetricSpace, F: CoordFloat, I> {
+    coords: I,
+    max_segment_length: F,
+    previous: Option<Coordinate<F>>,
+    // the points added within the current segment, and its end
+    added: std::vec::IntoIter<Point<F>>,
+    end: Option<Coordinate<F>>,
+    metric_space: PhantomData<MetricSpace>,
+}
+
+impl<MetricSpace, F, I> Iterator for Densify<MetricSpace, F, I>
+where
+    MetricSpace: InterpolatePoint<F>,
+    F: CoordFloat,
+    I: Iterator<Item = Coordinate<F>>,
+{
+    type Item = Coordinate<F>;
+
+    fn next(&mut self) -> Option<Coordinate<F>> {
+        if let Some(point) = self.added.next() {
+            return Some(point.0);
+        }
+        if let Some(end) = self.end.take() {
+            return Some(end);
+        }
+        let coord = self.coords.next()?;
+        match self.previous.replace(coord) {
+            None => Some(coord),
+            Some(previous) => {
+                self.added = MetricSpace::points_along_line(
+                    previous.into(),
+                    coord.into(),
+                    self.max_segment_length,
+                    false,
+                )
+                .into_iter();
+                self.end = Some(coord);
+                self.next()
+            }
+        }
+    }
+}
+
+/// Simplify the line through `coords` with the Ramer–Douglas–Peucker algorithm, as
+/// [`Simplify`](crate::algorithm::simplify::Simplify) does, a chunk of `chunk_size`
+/// coordinates at a time.
+///
+/// Each chunk starts at the last point kept from the one before, so the result is continuous,
+/// and no point removed is further than `epsilon` from it. The points where chunks meet are
+/// always kept, so the result may keep a few more points than simplifying the whole line at
+/// once; larger chunks keep fewer, at the cost of memory.
+///
+/// # Panics
+///
+/// If `chunk_size` is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::streaming;
+/// use geo::Coordinate;
+///
+/// let coords = (0..=6).map(|x| Coordinate { x: x as f64, y: 0. });
+/// let simplified: Vec<_> = streaming::simplify(coords, 0.1, 4).collect();
+/// assert_eq!(
+///     simplified,
+///     vec![
+///         Coordinate { x: 0., y: 0. },
+///         Coordinate { x: 3., y: 0. },
+///         Coordinate { x: 6., y: 0. },
+///     ]
+/// );
+/// ```
+pub fn simplify<T, I>(coords: I, epsilon: T, chunk_size: usize) -> Simplify<T, I::IntoIter>
+where
+    T: GeoFloat,
+    I: IntoIterator<Item = Coordinate<T>>,
+{
+    assert!(chunk_size >= 2, "chunk_size must be at least two");
+    Simplify {
+        coords: coords.into_iter().fuse(),
+        epsilon,
+        chunk_size,
+        chunk: Vec::with_capacity(chunk_size),
+        simplified: vec![].into_iter(),
+    }
+}
+
+/// An iterator over the coordinates of a simplified line, from [`simplify`].
+#[derive(Debug, Clone)]
+pub struct Simplify<T: GeoFloat, I> {
+    coords: std::iter::Fuse<I>,
+    epsilon: T,
+    chunk_size: usize,
+    // the last point kept from the previous chunk, followed by the coordinates of the next one
+    chunk: Vec<Coordinate<T>>,
+    simplified: std::vec::IntoIter<Coordinate<T>>,
+}
+
+impl<T, I> Iterator for Simplify<T, I>
+where
+    T: GeoFloat,
+    I: Iterator<Item = Coordinate<T>>,
+{
+    type Item = Coordinate<T>;
+
+    fn next(&mut self) -> Option<Coordinate<T>> {
+        loop {
+            if let Some(coord) = self.simplified.next() {
+                return Some(coord);
+            }
+            let carried = self.chunk.len();
+            self.chunk
+                .extend(self.coords.by_ref().take(self.chunk_size - carried));
+            if self.chunk.len() == carried {
+                // the end of the line
+                return self.chunk.pop();
+            }
+            let mut kept = rdp(self.chunk.drain(..), &self.epsilon);
+            // the last point kept starts the next chunk, and is returned with it
+            self.chunk.push(kept.pop().unwrap());
+            self.simplified = kept.into_iter();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::line_measures::{Densify, Euclidean, Haversine, Length};
+    use crate::algorithm::simplify::Simplify;
+    use crate::{line_string, LineString};
+
+    #[test]
+    fn matches_whole_line_algorithms() {
+        let line: LineString<f64> = line_string![
+            (x: -0.1278, y: 51.5074), (x: 2.3522, y: 48.8566), (x: 13.405, y: 52.52),
+        ];
+        assert_eq!(
+            length::<Haversine, _, _>(line.0.iter().cloned()),
+            line.length::<Haversine>()
+        );
+        let densified: Vec<_> =
+            densify::<Haversine, _, _>(line.0.iter().cloned(), 50_000.).collect();
+        assert_eq!(densified, line.densify::<Haversine>(50_000.).0);
+
+        // the whole line fits in a chunk
+        let simplified: Vec<_> = simplify(line.0.iter().cloned(), 1., 3).collect();
+        assert_eq!(simplified, line.simplify(&1.).0);
+
+        let empty: Vec<Coordinate<f64>> = vec![];
+        assert_eq!(length::<Euclidean, _, _>(empty.clone()), 0.);
+        assert_eq!(bounding_rect(empty.clone()), None);
+        assert_eq!(densify::<Euclidean, _, _>(empty.clone(), 1.).count(), 0);
+        assert_eq!(simplify(empty, 1., 2).count(), 0);
+    }
+
+    #[test]
+    fn chunk_boundaries() {
+        // a square wave, whose corners all stay
+        let wave: Vec<Coordinate<f64>> = (0..20)
+            .map(|i| Coordinate {
+                x: (i / 2 + i % 2) as f64,
+                y: ((i / 2) % 2) as f64,
+            })
+            .collect();
+        for chunk_size in 2..25 {
+            let simplified: Vec<_> = simplify(wave.iter().cloned(), 0.1, chunk_size).collect();
+            assert_eq!(simplified, wave, "chunks of {}", chunk_size);
+        }
+        // a straight line keeps the ends of its chunks
+        let straight = (0..10).map(|x| Coordinate { x: x as f64, y: 0. });
+        let simplified: Vec<_> = simplify(straight, 0.1, 5).collect();
+        let xs: Vec<f64> = simplified.iter().map(|coord| coord.x).collect();
+        assert_eq!(xs, vec![0., 4., 8., 9.]);
+    }
+}
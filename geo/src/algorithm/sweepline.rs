@@ -0,0 +1,583 @@
+//! Find the intersections within a set of line segments by sweeping a vertical line across them.
+//!
+//! Segments can come from any iterator of [`Line`]s, such as the segments of a geometry's rings
+//! generated on the fly, and are identified by their index in that iterator.
+
+use crate::algorithm::cancel::{Cancel, NeverCancelled};
+use crate::algorithm::line_intersection::{line_intersection, LineIntersection};
+use crate::utils::lex_cmp;
+use crate::{Coordinate, GeoError, GeoFloat, Line};
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+
+/// The intersection between two segments of a [`SweepLine`].
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentIntersection<T: GeoFloat> {
+    /// The index of the first segment, which is always lower than `second`
+    pub first: usize,
+    /// The index of the second segment
+    pub second: usize,
+    pub intersection: LineIntersection<T>,
+}
+
+/// A piece of a [`SweepLine`] segment, between consecutive points at which it intersects other
+/// segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodedSegment<T: GeoFloat> {
+    pub line: Line<T>,
+    /// The index of the segment this was split from
+    pub source: usize,
+}
+
+/// A set of line segments, whose intersections can be found by sweeping a vertical line across
+/// them from left to right, only testing pairs of segments which cross it at the same time.
+///
+/// Vertical segments, and segments which share endpoints or only touch at a single point, are all
+/// handled exactly, without any tolerance. Coordinates must not be NaN: where they may be, collect
+/// the segments with [`try_new`](Self::try_new).
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::sweepline::SweepLine;
+///
+/// // a self-intersecting "bow tie"
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// let sweep_line: SweepLine<f64> = polygon.exterior().lines().collect();
+///
+/// let proper: Vec<_> = sweep_line
+///     .intersections()
+///     .into_iter()
+///     .filter(|intersection| intersection.intersection.is_proper())
+///     .collect();
+///
+/// // the first and third segments cross
+/// assert_eq!(proper.len(), 1);
+/// assert_eq!((proper[0].first, proper[0].second), (0, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepLine<T: GeoFloat> {
+    segments: Vec<Line<T>>,
+}
+
+impl<T: GeoFloat> SweepLine<T> {
+    /// Collect `segments`, which are identified by their order from here on.
+    pub fn new<I: IntoIterator<Item = Line<T>>>(segments: I) -> Self {
+        SweepLine {
+            segments: segments.into_iter().collect(),
+        }
+    }
+
+    /// Collect `segments`, as with [`new`](Self::new), failing with
+    /// [`GeoError::NonFiniteSegment`] on the first segment with an end which is `NaN` or
+    /// infinite, rather than panicking when it's swept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::sweepline::SweepLine;
+    /// use geo::{GeoError, Line};
+    ///
+    /// let segments = vec![
+    ///     Line::from([(0., 0.), (1., 1.)]),
+    ///     Line::from([(0., 1.), (f64::INFINITY, 0.)]),
+    /// ];
+    /// assert_eq!(
+    ///     SweepLine::try_new(segments),
+    ///     Err(GeoError::NonFiniteSegment { segment: 1 })
+    /// );
+    /// ```
+    pub fn try_new<I: IntoIterator<Item = Line<T>>>(segments: I) -> Result<Self, GeoError> {
+        let sweep_line = Self::new(segments);
+        let is_finite = |coord: Coordinate<T>| coord.x.is_finite() && coord.y.is_finite();
+        match sweep_line
+            .segments
+            .iter()
+            .position(|segment| !is_finite(segment.start) || !is_finite(segment.end))
+        {
+            Some(segment) => Err(GeoError::NonFiniteSegment { segment }),
+            None => Ok(sweep_line),
+        }
+    }
+
+    /// The segments, in their original order.
+    pub fn segments(&self) -> &[Line<T>] {
+        &self.segments
+    }
+
+    /// All intersections between pairs of segments, ordered by segment indices.
+    ///
+    /// Segments which share an endpoint, like consecutive segments of a `LineString`, intersect
+    /// there.
+    pub fn intersections(&self) -> Vec<SegmentIntersection<T>> {
+        let mut intersections = Vec::new();
+        self.sweep(|intersection| {
+            intersections.push(intersection);
+            true
+        });
+        intersections.sort_by_key(|intersection| (intersection.first, intersection.second));
+        intersections
+    }
+
+    /// Whether any pair of segments intersects, stopping at the first intersection found.
+    pub fn has_intersection(&self) -> bool {
+        self.find_intersection(|_| true).is_some()
+    }
+
+    /// The first intersection found which satisfies `predicate`, stopping the sweep there.
+    ///
+    /// Intersections are found in sweep order, rather than in order of segment indices.
+    ///
+    /// # Examples
+    ///
+    /// Check whether a `LineString` is simple, ignoring where consecutive segments meet:
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use geo::sweepline::SweepLine;
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 1., y: -1.)];
+    /// let sweep_line: SweepLine<f64> = line_string.lines().collect();
+    ///
+    /// let crossing = sweep_line.find_intersection(|intersection| {
+    ///     intersection.second != intersection.first + 1 || intersection.intersection.is_proper()
+    /// });
+    /// assert_eq!(crossing.map(|crossing| (crossing.first, crossing.second)), Some((0, 2)));
+    /// ```
+    pub fn find_intersection<P>(&self, mut predicate: P) -> Option<SegmentIntersection<T>>
+    where
+        P: FnMut(&SegmentIntersection<T>) -> bool,
+    {
+        let mut found = None;
+        self.sweep(|intersection| {
+            if predicate(&intersection) {
+                found = Some(intersection);
+                false
+            } else {
+                true
+            }
+        });
+        found
+    }
+
+    /// The number of intersecting pairs of segments, without collecting the intersections.
+    pub fn count_intersections(&self) -> usize {
+        let mut count = 0;
+        self.sweep(|_| {
+            count += 1;
+            true
+        });
+        count
+    }
+
+    /// Split every segment at each point where it intersects another, producing the noded
+    /// segments of the planar arrangement.
+    ///
+    /// Pieces are ordered by the index of their source segment, and then run from its start to
+    /// its end. Where segments overlap, each contributes its own copy of the shared pieces.
+    /// Segments of zero length are kept whole.
+    ///
+    /// Intersection points aren't snapped to a grid, so a point computed where two segments cross
+    /// can lie slightly off either of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Line;
+    /// use geo::sweepline::SweepLine;
+    ///
+    /// let sweep_line = SweepLine::new(vec![
+    ///     Line::from([(0., 0.), (4., 0.)]),
+    ///     Line::from([(1., -1.), (1., 1.)]),
+    ///     Line::from([(3., -1.), (3., 1.)]),
+    /// ]);
+    /// let noded = sweep_line.noded_segments();
+    ///
+    /// // the first segment is split in three, and the others in two
+    /// assert_eq!(noded.len(), 7);
+    /// assert_eq!(noded[1].line, Line::from([(1., 0.), (3., 0.)]));
+    /// assert_eq!(noded[1].source, 0);
+    /// ```
+    pub fn noded_segments(&self) -> Vec<NodedSegment<T>> {
+        self.noded_segments_cancellable(&NeverCancelled)
+            .expect("never cancelled")
+    }
+
+    /// Split every segment where it intersects another, as
+    /// [`noded_segments`](Self::noded_segments) does, checking `cancel` at each intersection,
+    /// and failing with [`GeoError::Cancelled`] once it's cancelled.
+    pub fn noded_segments_cancellable(
+        &self,
+        cancel: &dyn Cancel,
+    ) -> Result<Vec<NodedSegment<T>>, GeoError> {
+        let mut nodes: Vec<Vec<Coordinate<T>>> = vec![vec![]; self.segments.len()];
+        let mut is_cancelled = false;
+        self.sweep(|intersection| {
+            if cancel.is_cancelled() {
+                is_cancelled = true;
+                return false;
+            }
+            let points = match intersection.intersection {
+                LineIntersection::SinglePoint { intersection, .. } => [intersection, intersection],
+                LineIntersection::Collinear { intersection } => {
+                    [intersection.start, intersection.end]
+                }
+            };
+            for index in &[intersection.first, intersection.second] {
+                nodes[*index].extend_from_slice(&points);
+            }
+            true
+        });
+        if is_cancelled {
+            return Err(GeoError::Cancelled);
+        }
+
+        let mut noded = Vec::with_capacity(self.segments.len());
+        for (source, (segment, mut nodes)) in self.segments.iter().zip(nodes).enumerate() {
+            if segment.start == segment.end {
+                noded.push(NodedSegment {
+                    line: *segment,
+                    source,
+                });
+                continue;
+            }
+
+            // order the nodes by their distance along the segment
+            let delta = segment.delta();
+            let along = |coord: &Coordinate<T>| {
+                (coord.x - segment.start.x) * delta.x + (coord.y - segment.start.y) * delta.y
+            };
+            nodes.push(segment.start);
+            nodes.push(segment.end);
+            nodes.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal));
+            nodes.dedup();
+
+            noded.extend(nodes.windows(2).map(|pair| NodedSegment {
+                line: Line::new(pair[0], pair[1]),
+                source,
+            }));
+        }
+        Ok(noded)
+    }
+
+    // Visit the intersections in sweep order, until `visit` returns false.
+    fn sweep<V>(&self, mut visit: V)
+    where
+        V: FnMut(SegmentIntersection<T>) -> bool,
+    {
+        let mut events: Vec<Event<T>> = Vec::with_capacity(2 * self.segments.len());
+        for (index, segment) in self.segments.iter().enumerate() {
+            let (left, right) = match lex_cmp(&segment.start, &segment.end) {
+                Ordering::Greater => (segment.end, segment.start),
+                _ => (segment.start, segment.end),
+            };
+            events.push(Event {
+                coord: left,
+                kind: EventKind::Start,
+                segment: index,
+            });
+            events.push(Event {
+                coord: right,
+                kind: EventKind::End,
+                segment: index,
+            });
+        }
+        events.sort_by(Event::cmp);
+
+        // the segments crossing the sweep line
+        let mut active: Vec<usize> = Vec::new();
+        for event in events {
+            let index = event.segment;
+            if event.kind == EventKind::End {
+                if let Some(position) = active.iter().position(|other| *other == index) {
+                    active.remove(position);
+                }
+                continue;
+            }
+
+            for other in &active {
+                // intersect in index order, so that results don't depend on the sweep order
+                let (first, second) = if index < *other {
+                    (index, *other)
+                } else {
+                    (*other, index)
+                };
+                if let Some(intersection) =
+                    line_intersection(self.segments[first], self.segments[second])
+                {
+                    let intersection = SegmentIntersection {
+                        first,
+                        second,
+                        intersection,
+                    };
+                    if !visit(intersection) {
+                        return;
+                    }
+                }
+            }
+            active.push(index);
+        }
+    }
+}
+
+// Segments start at their lexicographically lowest endpoint, and end at their highest.
+//
+// Events are ordered by x, then y, so the sweep line is tilted infinitesimally, and vertical
+// segments cross it from bottom to top. At the same coordinate, segments start before others end,
+// so that segments sharing an endpoint are both active when it's reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum EventKind {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Event<T: GeoFloat> {
+    coord: Coordinate<T>,
+    kind: EventKind,
+    segment: usize,
+}
+
+impl<T: GeoFloat> Event<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        lex_cmp(&self.coord, &other.coord)
+            .then(self.kind.cmp(&other.kind))
+            .then(self.segment.cmp(&other.segment))
+    }
+}
+
+impl<T: GeoFloat> FromIterator<Line<T>> for SweepLine<T> {
+    fn from_iter<I: IntoIterator<Item = Line<T>>>(iter: I) -> Self {
+        SweepLine::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    // every pair of segments, for comparison
+    fn brute_force(segments: &[Line<f64>]) -> Vec<SegmentIntersection<f64>> {
+        let mut intersections = vec![];
+        for first in 0..segments.len() {
+            for second in first + 1..segments.len() {
+                if let Some(intersection) = line_intersection(segments[first], segments[second]) {
+                    intersections.push(SegmentIntersection {
+                        first,
+                        second,
+                        intersection,
+                    });
+                }
+            }
+        }
+        intersections
+    }
+
+    #[test]
+    fn crossing_segments() {
+        let sweep_line = SweepLine::new(vec![
+            Line::from([(0., 0.), (4., 4.)]),
+            Line::from([(5., 0.), (6., 1.)]),
+            Line::from([(0., 4.), (4., 0.)]),
+        ]);
+        assert_eq!(
+            sweep_line.intersections(),
+            vec![SegmentIntersection {
+                first: 0,
+                second: 2,
+                intersection: LineIntersection::SinglePoint {
+                    intersection: Coordinate { x: 2., y: 2. },
+                    is_proper: true,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn segments_generated_from_line_strings() {
+        let a = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let b = line_string![(x: 2., y: -1.), (x: 2., y: 5.)];
+        let sweep_line: SweepLine<f64> = a.lines().chain(b.lines()).collect();
+        let intersections = sweep_line.intersections();
+
+        // consecutive segments of `a` touch, and `b` crosses the first segment of `a`
+        assert_eq!(intersections.len(), 2);
+        assert_eq!((intersections[0].first, intersections[0].second), (0, 1));
+        assert_eq!((intersections[1].first, intersections[1].second), (0, 2));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // a simple LCG, to generate a repeatable jumble of segments
+        let mut state = 7u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as f64 / (1u64 << 31) as f64 * 100.
+        };
+        let segments: Vec<Line<f64>> = (0..200)
+            .map(|_| Line::from([(next(), next()), (next(), next())]))
+            .collect();
+
+        let sweep_line = SweepLine::new(segments.clone());
+        assert_eq!(sweep_line.intersections(), brute_force(&segments));
+    }
+
+    #[test]
+    fn degenerate_arrangements() {
+        let lines = |coords: &[[(f64, f64); 2]]| -> Vec<Line<f64>> {
+            coords.iter().map(|coords| Line::from(*coords)).collect()
+        };
+        let arrangements = vec![
+            // a grid of vertical and horizontal segments
+            lines(&[
+                [(1., 0.), (1., 4.)],
+                [(2., 4.), (2., 0.)],
+                [(3., 0.), (3., 4.)],
+                [(0., 1.), (4., 1.)],
+                [(4., 2.), (0., 2.)],
+                [(0., 3.), (4., 3.)],
+            ]),
+            // a star of segments meeting at its center, with another crossing through it
+            lines(&[
+                [(0., 0.), (1., 1.)],
+                [(1., 1.), (2., 0.)],
+                [(1., 1.), (1., 2.)],
+                [(1., 1.), (1., 0.)],
+                [(0., 1.), (2., 1.)],
+            ]),
+            // stacked vertical segments, touching end to end and overlapping
+            lines(&[
+                [(0., 0.), (0., 1.)],
+                [(0., 1.), (0., 2.)],
+                [(0., 1.5), (0., 3.)],
+                [(0., 4.), (0., 5.)],
+            ]),
+            // a vertical segment touched at its ends, and in its middle, by horizontal ones
+            lines(&[
+                [(1., -1.), (1., 1.)],
+                [(0., -1.), (1., -1.)],
+                [(1., 1.), (2., 1.)],
+                [(0., 0.), (1., 0.)],
+                [(2., 0.5), (1., 0.5)],
+            ]),
+            // collinear segments sharing endpoints, and degenerate segments on vertices and edges
+            lines(&[
+                [(0., 0.), (1., 1.)],
+                [(1., 1.), (2., 2.)],
+                [(2., 2.), (2., 2.)],
+                [(0.5, 0.5), (0.5, 0.5)],
+                [(3., 3.), (3., 3.)],
+            ]),
+        ];
+
+        for segments in arrangements {
+            let sweep_line = SweepLine::new(segments.clone());
+            assert_eq!(
+                sweep_line.intersections(),
+                brute_force(&segments),
+                "{:?}",
+                segments
+            );
+        }
+    }
+
+    #[test]
+    fn segments_ending_where_others_start() {
+        // each segment only touches the next, at a single point
+        let sweep_line = SweepLine::new(vec![
+            Line::from([(0., 0.), (1., 0.)]),
+            Line::from([(1., 0.), (1., 1.)]),
+            Line::from([(1., 1.), (2., 1.)]),
+        ]);
+        let pairs: Vec<_> = sweep_line
+            .intersections()
+            .iter()
+            .map(|intersection| (intersection.first, intersection.second))
+            .collect();
+        assert_eq!(pairs, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn early_exit_and_counting() {
+        let segments = vec![
+            Line::from([(0., 0.), (4., 4.)]),
+            Line::from([(0., 4.), (4., 0.)]),
+            Line::from([(0., 2.), (4., 2.)]),
+            Line::from([(5., 0.), (5., 4.)]),
+        ];
+        let sweep_line = SweepLine::new(segments.clone());
+        assert!(sweep_line.has_intersection());
+        assert_eq!(sweep_line.count_intersections(), 3);
+        assert_eq!(
+            sweep_line.count_intersections(),
+            sweep_line.intersections().len()
+        );
+
+        let with_last = sweep_line.find_intersection(|intersection| intersection.second == 3);
+        assert_eq!(with_last, None);
+
+        let disjoint = SweepLine::new(vec![segments[0], segments[3]]);
+        assert!(!disjoint.has_intersection());
+        assert_eq!(disjoint.count_intersections(), 0);
+    }
+
+    #[test]
+    fn noded_segments() {
+        let sweep_line = SweepLine::new(vec![
+            Line::from([(4., 4.), (0., 0.)]),
+            Line::from([(0., 4.), (4., 0.)]),
+            // overlaps the end of the first segment
+            Line::from([(3., 3.), (5., 5.)]),
+            Line::from([(6., 6.), (6., 6.)]),
+        ]);
+        let noded: Vec<(usize, Line<f64>)> = sweep_line
+            .noded_segments()
+            .into_iter()
+            .map(|noded| (noded.source, noded.line))
+            .collect();
+        assert_eq!(
+            noded,
+            vec![
+                (0, Line::from([(4., 4.), (3., 3.)])),
+                (0, Line::from([(3., 3.), (2., 2.)])),
+                (0, Line::from([(2., 2.), (0., 0.)])),
+                (1, Line::from([(0., 4.), (2., 2.)])),
+                (1, Line::from([(2., 2.), (4., 0.)])),
+                (2, Line::from([(3., 3.), (4., 4.)])),
+                (2, Line::from([(4., 4.), (5., 5.)])),
+                (3, Line::from([(6., 6.), (6., 6.)])),
+            ]
+        );
+    }
+
+    #[test]
+    fn noded_segments_only_meet_at_their_ends() {
+        let a = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.), (x: 0., y: 0.)];
+        let b = line_string![(x: -1., y: 2.), (x: 5., y: 2.), (x: 2., y: -1.), (x: 2., y: 5.)];
+        let sweep_line: SweepLine<f64> = a.lines().chain(b.lines()).collect();
+        let noded = SweepLine::new(
+            sweep_line
+                .noded_segments()
+                .into_iter()
+                .map(|noded| noded.line),
+        );
+        let segments = noded.segments();
+        for intersection in noded.intersections() {
+            let point = match intersection.intersection {
+                LineIntersection::SinglePoint { intersection, .. } => intersection,
+                LineIntersection::Collinear { intersection } => {
+                    panic!("noded segments overlap along {:?}", intersection)
+                }
+            };
+            for index in &[intersection.first, intersection.second] {
+                let segment = segments[*index];
+                assert!(point == segment.start || point == segment.end);
+            }
+        }
+    }
+}
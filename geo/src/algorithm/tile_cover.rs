@@ -0,0 +1,176 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::map_coords::MapCoords;
+use crate::algorithm::mercator::to_web_mercator;
+use crate::algorithm::rasterize::{rasterize, BurnRule};
+use crate::{Coordinate, GeoFloat, Geometry, Rect};
+
+/// A tile of a slippy map, such as OpenStreetMap's: at zoom level `z`, the world is divided into
+/// `2^z` by `2^z` tiles, numbered from the west in `x`, and from the north in `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub z: u8,
+}
+
+/// The tiles at zoom level `zoom` of a slippy map which a geometry, in degrees of longitude and
+/// latitude, intersects, in order of `x`, then `y`.
+///
+/// The geometry is projected into the Web Mercator tile space of the map, and tested against
+/// each tile within its bounding rectangle exactly, as [`rasterize`] does under
+/// [`BurnRule::AllTouched`]: polygons cover the tiles they share some area with, and lines and
+/// points the tiles they touch, even at their edges. Segments are taken to be straight in tile
+/// space, as they are drawn on the map. Latitudes beyond the cut-off of the projection,
+/// [`MAX_LATITUDE`](crate::algorithm::mercator::MAX_LATITUDE), are clamped to it. A geometry
+/// crossing the antimeridian should be split there first.
+///
+/// PERF: as with [`rasterize`], each tile within the bounding rectangle of the geometry is
+/// tested, so covering a long diagonal line at a high zoom level is slow.
+///
+/// # Panics
+///
+/// If `zoom` is more than 31.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::tile_cover::{tile_cover, Tile};
+/// use geo::{line_string, polygon, Geometry};
+///
+/// // a square in the Gulf of Guinea, where the equator meets the prime meridian
+/// let square: Geometry<f64> =
+///     polygon![(x: -1., y: -1.), (x: 1., y: -1.), (x: 1., y: 1.), (x: -1., y: 1.)].into();
+/// assert_eq!(
+///     tile_cover(&square, 1),
+///     vec![
+///         Tile { x: 0, y: 0, z: 1 },
+///         Tile { x: 0, y: 1, z: 1 },
+///         Tile { x: 1, y: 0, z: 1 },
+///         Tile { x: 1, y: 1, z: 1 },
+///     ]
+/// );
+///
+/// // a line from London to Paris, at zoom level 6
+/// let line: Geometry<f64> = line_string![(x: -0.13, y: 51.51), (x: 2.35, y: 48.86)].into();
+/// assert_eq!(
+///     tile_cover(&line, 6),
+///     vec![Tile { x: 31, y: 21, z: 6 }, Tile { x: 32, y: 21, z: 6 }, Tile { x: 32, y: 22, z: 6 }]
+/// );
+/// ```
+pub fn tile_cover<T: GeoFloat>(geometry: &Geometry<T>, zoom: u8) -> Vec<Tile> {
+    assert!(zoom <= 31, "zoom level {} is beyond 31", zoom);
+    let tiles = 1u32 << zoom;
+    let tiles_t = T::from(tiles).unwrap();
+    let two = T::one() + T::one();
+
+    // from Web Mercator, where the world spans twice `half_world` each way, to tiles
+    let (half_world, _) = to_web_mercator(&(T::from(180).unwrap(), T::zero()));
+    let projected = geometry.map_coords(|coord| {
+        let (x, y) = to_web_mercator(coord);
+        (
+            (T::one() + x / half_world) / two * tiles_t,
+            (T::one() - y / half_world) / two * tiles_t,
+        )
+    });
+    let extent = match projected.bounding_rect() {
+        Some(extent) => extent,
+        None => return vec![],
+    };
+
+    // the tiles whose closed extents may meet the geometry's, including those it only touches
+    let last = tiles_t - T::one();
+    let clamp = |index: T| index.max(T::zero()).min(last).to_u32().unwrap();
+    let first = |value: T| clamp(value.ceil() - T::one());
+    let (min_x, max_x) = (first(extent.min().x), clamp(extent.max().x.floor()));
+    let (min_y, max_y) = (first(extent.min().y), clamp(extent.max().y.floor()));
+    let bounds = Rect::new(
+        Coordinate {
+            x: T::from(min_x).unwrap(),
+            y: T::from(min_y).unwrap(),
+        },
+        Coordinate {
+            x: T::from(max_x + 1).unwrap(),
+            y: T::from(max_y + 1).unwrap(),
+        },
+    );
+    let rows = (max_y - min_y + 1) as usize;
+    let columns = (max_x - min_x + 1) as usize;
+    let mask = rasterize(&projected, bounds, rows, columns, BurnRule::AllTouched);
+
+    let mut cover = vec![];
+    for column in 0..columns {
+        for (row, cells) in mask.iter().enumerate() {
+            if cells[column] {
+                cover.push(Tile {
+                    x: min_x + column as u32,
+                    y: min_y + row as u32,
+                    z: zoom,
+                });
+            }
+        }
+    }
+    cover
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, LineString, Polygon};
+
+    #[test]
+    fn exact_rather_than_bounding_rect() {
+        // an L across a 3 by 3 block of tiles at zoom level 8, from tile coordinates
+        let corner = |x: f64, y: f64| {
+            let lat = (std::f64::consts::PI * (1. - 2. * y / 256.)).sinh().atan();
+            (x * 360. / 256. - 180., lat.to_degrees())
+        };
+        let ell: Geometry<f64> = Polygon::new(
+            LineString::from(vec![
+                corner(100.2, 50.2),
+                corner(100.8, 50.2),
+                corner(100.8, 52.2),
+                corner(102.8, 52.2),
+                corner(102.8, 52.8),
+                corner(100.2, 52.8),
+                corner(100.2, 50.2),
+            ]),
+            vec![],
+        )
+        .into();
+        let tile = |x: u32, y: u32| Tile { x, y, z: 8 };
+        assert_eq!(
+            tile_cover(&ell, 8),
+            vec![
+                tile(100, 50),
+                tile(100, 51),
+                tile(100, 52),
+                tile(101, 52),
+                tile(102, 52)
+            ]
+        );
+    }
+
+    #[test]
+    fn poles_and_points() {
+        let world: Geometry<f64> =
+            polygon![(x: -180., y: -90.), (x: 180., y: -90.), (x: 180., y: 90.), (x: -180., y: 90.)]
+                .into();
+        assert_eq!(tile_cover(&world, 2).len(), 16);
+        assert_eq!(tile_cover(&world, 0), vec![Tile { x: 0, y: 0, z: 0 }]);
+
+        let null_island: Geometry<f64> = point!(x: 0., y: 0.).into();
+        assert_eq!(tile_cover(&null_island, 1).len(), 4);
+        let zurich: Geometry<f64> = point!(x: 8.54, y: 47.37).into();
+        assert_eq!(
+            tile_cover(&zurich, 10),
+            vec![Tile {
+                x: 536,
+                y: 358,
+                z: 10
+            }]
+        );
+
+        let empty: Geometry<f64> = LineString(vec![]).into();
+        assert!(tile_cover(&empty, 3).is_empty());
+    }
+}
@@ -0,0 +1,386 @@
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::coverage::validate_coverage;
+use crate::algorithm::erosion::faces_where;
+use crate::algorithm::line_intersection::LineIntersection;
+use crate::algorithm::relate::Relate;
+use crate::algorithm::spatial_join::{spatial_join, JoinPredicate};
+use crate::algorithm::sweepline::SweepLine;
+use crate::{
+    Coordinate, GeoFloat, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use rstar::RTreeNum;
+use std::iter;
+
+/// A rule which the geometries of a layer must obey, checked by [`check_topology`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TopologyRule<'a, T: GeoFloat> {
+    /// The areas of the layer's polygons must not overlap one another
+    MustNotOverlap,
+    /// There must be no gaps narrower than the given width between the layer's polygons, as
+    /// found by [`validate_coverage`]
+    MustNotHaveGaps(T),
+    /// Each geometry of the layer must be covered by the geometries of the other layer, taken
+    /// together
+    MustBeCoveredBy(&'a [Geometry<T>]),
+    /// The layer's lines must not cross, touch or overlap themselves
+    MustNotSelfIntersect,
+}
+
+/// Where a [`TopologyRule`] is broken.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopologyViolation<T: GeoFloat> {
+    /// The index of the rule broken, among the rules checked
+    pub rule: usize,
+    /// The indices of the geometries of the layer breaking it, in ascending order
+    pub indices: Vec<usize>,
+    /// Where the rule is broken: the area of an overlap, a gap, or the part of a geometry left
+    /// uncovered, or a point or segment at which a line meets itself
+    pub location: Geometry<T>,
+}
+
+/// Check the geometries of a layer against topology rules, such as the polygons of a layer not
+/// overlapping, or being covered by those of another, returning every violation found.
+///
+/// Candidate pairs of geometries are found with [`spatial_join`], which indexes their bounding
+/// rectangles and relates them. The areal rules only apply to the `Polygon`s, `MultiPolygon`s,
+/// `Rect`s and `Triangle`s of the layer, and `MustNotSelfIntersect` only to its `LineString`s
+/// and `MultiLineString`s, whose parts must not meet each other either. The violations are
+/// ordered by rule, and then by the geometries breaking them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::topology_rules::{check_topology, TopologyRule};
+/// use geo::{line_string, polygon, Geometry, MultiLineString, Point};
+///
+/// let parcels: Vec<Geometry<f64>> = vec![
+///     polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)].into(),
+///     // overlapping the first parcel by 1
+///     polygon![(x: 9., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 9., y: 10.)].into(),
+/// ];
+/// let violations = check_topology(&parcels, &[TopologyRule::MustNotOverlap]);
+/// assert_eq!(violations.len(), 1);
+/// assert_eq!(violations[0].indices, vec![0, 1]);
+///
+/// // a line looping back over itself
+/// let roads: Vec<Geometry<f64>> =
+///     vec![line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: -2.)].into()];
+/// let violations = check_topology(&roads, &[TopologyRule::MustNotSelfIntersect]);
+/// assert_eq!(violations[0].location, Point::new(3., 0.).into());
+///
+/// // the parcels only cover the road as far as x = 20
+/// let roads: Vec<Geometry<f64>> = vec![line_string![(x: 5., y: 5.), (x: 25., y: 5.)].into()];
+/// let violations = check_topology(&roads, &[TopologyRule::MustBeCoveredBy(&parcels)]);
+/// assert_eq!(
+///     violations[0].location,
+///     MultiLineString(vec![line_string![(x: 20., y: 5.), (x: 25., y: 5.)]]).into()
+/// );
+/// ```
+pub fn check_topology<T>(
+    layer: &[Geometry<T>],
+    rules: &[TopologyRule<T>],
+) -> Vec<TopologyViolation<T>>
+where
+    T: GeoFloat + RTreeNum,
+{
+    let mut violations = vec![];
+    for (rule, kind) in rules.iter().enumerate() {
+        let mut violation = |indices: Vec<usize>, location: Geometry<T>| {
+            violations.push(TopologyViolation {
+                rule,
+                indices,
+                location,
+            })
+        };
+        match kind {
+            TopologyRule::MustNotOverlap => {
+                for (a, b) in spatial_join(layer, layer, JoinPredicate::Intersects) {
+                    if a >= b {
+                        continue;
+                    }
+                    let (first, second) = match (polygons(&layer[a]), polygons(&layer[b])) {
+                        (Some(first), Some(second)) => (first, second),
+                        _ => continue,
+                    };
+                    let segments = rings(&first).chain(rings(&second)).collect();
+                    let overlaps = faces_where(segments, |coord| {
+                        is_inside(&layer[a], coord) && is_inside(&layer[b], coord)
+                    });
+                    if !overlaps.is_empty() {
+                        violation(vec![a, b], MultiPolygon(overlaps).into());
+                    }
+                }
+            }
+            TopologyRule::MustNotHaveGaps(gap_width) => {
+                let parts: Vec<Polygon<T>> = layer.iter().filter_map(polygons).flatten().collect();
+                for gap in validate_coverage(&parts, *gap_width).gaps {
+                    let gap = Geometry::Polygon(gap);
+                    let indices = (0..layer.len())
+                        .filter(|index| layer[*index].relate(&gap).is_intersects())
+                        .collect();
+                    violation(indices, gap);
+                }
+            }
+            TopologyRule::MustBeCoveredBy(cover) => {
+                let mut candidates = vec![vec![]; layer.len()];
+                for (a, b) in spatial_join(layer, cover, JoinPredicate::Intersects) {
+                    candidates[a].push(&cover[b]);
+                }
+                for (index, geometry) in layer.iter().enumerate() {
+                    if let Some(uncovered) = uncovered(geometry, &candidates[index]) {
+                        violation(vec![index], uncovered);
+                    }
+                }
+            }
+            TopologyRule::MustNotSelfIntersect => {
+                for (index, geometry) in layer.iter().enumerate() {
+                    let parts = match geometry {
+                        Geometry::LineString(line_string) => vec![line_string.clone()],
+                        Geometry::MultiLineString(line_strings) => line_strings.0.clone(),
+                        _ => continue,
+                    };
+                    for location in self_intersections(&parts) {
+                        violation(vec![index], location);
+                    }
+                }
+            }
+        }
+    }
+    violations
+}
+
+// The polygons of an areal geometry.
+fn polygons<T: GeoFloat>(geometry: &Geometry<T>) -> Option<Vec<Polygon<T>>> {
+    match geometry {
+        Geometry::Polygon(polygon) => Some(vec![polygon.clone()]),
+        Geometry::MultiPolygon(polygons) => Some(polygons.0.clone()),
+        Geometry::Rect(rect) => Some(vec![rect.to_polygon()]),
+        Geometry::Triangle(triangle) => Some(vec![triangle.to_polygon()]),
+        _ => None,
+    }
+}
+
+fn rings<T: GeoFloat>(polygons: &[Polygon<T>]) -> impl Iterator<Item = Line<T>> + '_ {
+    polygons
+        .iter()
+        .flat_map(|polygon| iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .flat_map(|ring| ring.lines())
+}
+
+fn is_inside<T: GeoFloat>(geometry: &Geometry<T>, coord: Coordinate<T>) -> bool {
+    geometry.coordinate_position(&coord) == CoordPos::Inside
+}
+
+fn is_covered<T: GeoFloat>(cover: &[&Geometry<T>], coord: Coordinate<T>) -> bool {
+    cover
+        .iter()
+        .any(|geometry| geometry.coordinate_position(&coord) != CoordPos::Outside)
+}
+
+// The parts of `geometry` outside every geometry of `cover`, if any.
+fn uncovered<T: GeoFloat>(geometry: &Geometry<T>, cover: &[&Geometry<T>]) -> Option<Geometry<T>> {
+    let cover_rings: Vec<Line<T>> = cover
+        .iter()
+        .filter_map(|geometry| polygons(geometry))
+        .flat_map(|polygons| rings(&polygons).collect::<Vec<_>>())
+        .collect();
+
+    if let Some(polygons) = polygons(geometry) {
+        let segments = rings(&polygons).chain(cover_rings).collect();
+        let uncovered = faces_where(segments, |coord| {
+            is_inside(geometry, coord) && !is_covered(cover, coord)
+        });
+        if uncovered.is_empty() {
+            return None;
+        }
+        return Some(MultiPolygon(uncovered).into());
+    }
+
+    let lines: Vec<Line<T>> = match geometry {
+        Geometry::Line(line) => vec![*line],
+        Geometry::LineString(line_string) => line_string.lines().collect(),
+        Geometry::MultiLineString(line_strings) => line_strings
+            .iter()
+            .flat_map(|line_string| line_string.lines())
+            .collect(),
+        Geometry::Point(point) => {
+            if is_covered(cover, point.0) {
+                return None;
+            }
+            return Some((*point).into());
+        }
+        Geometry::MultiPoint(points) => {
+            let uncovered: Vec<Point<T>> = points
+                .iter()
+                .filter(|point| !is_covered(cover, point.0))
+                .copied()
+                .collect();
+            if uncovered.is_empty() {
+                return None;
+            }
+            return Some(MultiPoint(uncovered).into());
+        }
+        _ => return None,
+    };
+    // the pieces of the lines between the rings of the cover, each wholly inside or outside it
+    let count = lines.len();
+    let two = T::one() + T::one();
+    let mut uncovered: Vec<LineString<T>> = vec![];
+    for noded in SweepLine::new(lines.into_iter().chain(cover_rings)).noded_segments() {
+        let midpoint = (noded.line.start + noded.line.end) / two;
+        if noded.source >= count || is_covered(cover, midpoint) {
+            continue;
+        }
+        // joining the pieces which follow on from one another
+        match uncovered.last_mut() {
+            Some(last) if last.0.last() == Some(&noded.line.start) => last.0.push(noded.line.end),
+            _ => uncovered.push(noded.line.into()),
+        }
+    }
+    if uncovered.is_empty() {
+        return None;
+    }
+    Some(MultiLineString(uncovered).into())
+}
+
+// The points and segments at which lines meet themselves, or each other, other than where one
+// segment of a line follows on from another.
+fn self_intersections<T: GeoFloat>(parts: &[LineString<T>]) -> Vec<Geometry<T>> {
+    // each segment, with its part and position along it
+    let mut positions = vec![];
+    let mut segments = vec![];
+    for (part, line_string) in parts.iter().enumerate() {
+        let mut coords = line_string.0.clone();
+        coords.dedup();
+        let is_closed = coords.len() > 3 && coords.first() == coords.last();
+        let count = coords.len().saturating_sub(1);
+        for (position, pair) in coords.windows(2).enumerate() {
+            positions.push((part, position, count, is_closed));
+            segments.push(Line::new(pair[0], pair[1]));
+        }
+    }
+
+    let mut locations: Vec<Geometry<T>> = vec![];
+    for intersection in SweepLine::new(segments).intersections() {
+        let (part, a, count, is_closed) = positions[intersection.first];
+        let (other_part, b, _, _) = positions[intersection.second];
+        let location = match intersection.intersection {
+            LineIntersection::SinglePoint { intersection, .. } => {
+                let is_adjacent =
+                    part == other_part && (b == a + 1 || (is_closed && a == 0 && b == count - 1));
+                if is_adjacent {
+                    continue;
+                }
+                Point(intersection).into()
+            }
+            LineIntersection::Collinear { intersection } => intersection.into(),
+        };
+        if !locations.contains(&location) {
+            locations.push(location);
+        }
+    }
+    locations
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::{line_string, point, polygon, Rect};
+
+    #[test]
+    fn overlaps_and_gaps() {
+        let layer = vec![
+            Rect::new((0., 0.), (10., 10.)).into(),
+            Rect::new((10., 0.), (20., 10.)).into(),
+            // overlapping both, along their shared edge
+            Rect::new((9., 4.), (11., 6.)).into(),
+            // a sliver of 0.1 from the first square
+            polygon![(x: -5., y: 0.), (x: -0.1, y: 0.), (x: -0.1, y: 10.), (x: -5., y: 10.)].into(),
+            polygon![(x: -5., y: 10.), (x: 20., y: 10.), (x: 20., y: 15.), (x: -5., y: 15.)].into(),
+            polygon![(x: -5., y: -5.), (x: 20., y: -5.), (x: 20., y: 0.), (x: -5., y: 0.)].into(),
+            // points and lines are ignored
+            point!(x: 5., y: 5.).into(),
+        ];
+        let rules = [
+            TopologyRule::MustNotOverlap,
+            TopologyRule::MustNotHaveGaps(0.5),
+        ];
+        let violations = check_topology(&layer, &rules);
+        let overlaps: Vec<_> = violations
+            .iter()
+            .filter(|violation| violation.rule == 0)
+            .collect();
+        assert_eq!(overlaps.len(), 2);
+        assert_eq!(overlaps[0].indices, vec![0, 2]);
+        assert_eq!(overlaps[1].indices, vec![1, 2]);
+        assert_relative_eq!(overlaps[0].location.unsigned_area(), 2.);
+
+        let gaps: Vec<_> = violations
+            .iter()
+            .filter(|violation| violation.rule == 1)
+            .collect();
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].indices, vec![0, 3, 4, 5]);
+        assert_relative_eq!(gaps[0].location.unsigned_area(), 1., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn coverage_by_another_layer() {
+        let cover = vec![
+            Rect::new((0., 0.), (10., 10.)).into(),
+            Rect::new((10., 0.), (20., 10.)).into(),
+        ];
+        let layer = vec![
+            // covered by the two squares together
+            Rect::new((5., 2.), (15., 8.)).into(),
+            Rect::new((15., 2.), (25., 8.)).into(),
+            line_string![(x: 2., y: 5.), (x: 18., y: 5.)].into(),
+            line_string![(x: -2., y: 5.), (x: 22., y: 5.)].into(),
+            point!(x: 20., y: 5.).into(),
+            point!(x: 21., y: 5.).into(),
+        ];
+        let violations = check_topology(&layer, &[TopologyRule::MustBeCoveredBy(&cover)]);
+        let indices: Vec<_> = violations
+            .iter()
+            .map(|violation| violation.indices[0])
+            .collect();
+        assert_eq!(indices, vec![1, 3, 5]);
+        assert_relative_eq!(violations[0].location.unsigned_area(), 30.);
+        assert_eq!(
+            violations[1].location,
+            MultiLineString(vec![
+                line_string![(x: -2., y: 5.), (x: 0., y: 5.)],
+                line_string![(x: 20., y: 5.), (x: 22., y: 5.)],
+            ])
+            .into()
+        );
+    }
+
+    #[test]
+    fn self_intersecting_lines() {
+        let layer: Vec<Geometry<f64>> = vec![
+            // simple, and closed
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 1.)].into(),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 0.)].into(),
+            // doubling back along itself
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 1., y: 0.)].into(),
+            // two parts crossing
+            MultiLineString(vec![
+                line_string![(x: 0., y: 0.), (x: 2., y: 2.)],
+                line_string![(x: 0., y: 2.), (x: 2., y: 0.)],
+            ])
+            .into(),
+        ];
+        let violations = check_topology(&layer, &[TopologyRule::MustNotSelfIntersect]);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].indices, vec![2]);
+        assert_eq!(
+            violations[0].location,
+            Line::from([(2., 0.), (1., 0.)]).into()
+        );
+        assert_eq!(violations[1].indices, vec![3]);
+        assert_eq!(violations[1].location, point!(x: 1., y: 1.).into());
+    }
+}
@@ -0,0 +1,319 @@
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::{CoordFloat, Coordinate, LineString, MultiPolygon, Polygon, Rect};
+use std::collections::HashMap;
+
+/// Trace the regions of `true` cells in a boolean raster mask into polygons.
+///
+/// `mask` is indexed as `mask[row][column]`. Its cells are taken to tile `bounds` in a regular
+/// grid, with row `0` at `bounds.min().y` and column `0` at `bounds.min().x`, as in
+/// [`isobands`](crate::algorithm::contour::isobands). The grid is as wide as the first row:
+/// shorter rows are padded with `false`, and longer ones cut short.
+///
+/// The boundaries run exactly along the edges of the cells. Each region of cells connected
+/// through their sides becomes one polygon, with a hole for each region of `false` cells it
+/// encloses, and a further polygon for each island of `true` cells within a hole. Cells which
+/// only meet at a corner are not connected, so the polygons are valid: where two polygons, a
+/// polygon and its hole, or two holes, meet at a corner, they touch at that point alone.
+/// Exteriors are wound counter-clockwise and interiors clockwise, and vertices in the middle of
+/// straight runs of cell edges are left out.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::trace_mask::trace_mask;
+/// use geo::Rect;
+///
+/// // a ring of cells, and a single cell touching it only at a corner
+/// let mask = vec![
+///     vec![true, true, true, false],
+///     vec![true, false, true, false],
+///     vec![true, true, true, false],
+///     vec![false, false, false, true],
+/// ];
+/// let traced = trace_mask(&mask, Rect::new((0., 0.), (8., 8.)));
+///
+/// assert_eq!(traced.0.len(), 2);
+/// let ring = &traced.0[0];
+/// assert_eq!(ring.interiors().len(), 1);
+/// assert_eq!(ring.signed_area(), 32.);
+/// assert_eq!(ring.exterior().0.len(), 5);
+/// assert_eq!(traced.0[1].signed_area(), 4.);
+/// ```
+pub fn trace_mask<T: CoordFloat>(mask: &[Vec<bool>], bounds: Rect<T>) -> MultiPolygon<T> {
+    let rows = mask.len();
+    let columns = mask.first().map_or(0, |row| row.len());
+    let is_set = |row: isize, column: isize| {
+        row >= 0
+            && column >= 0
+            && (column as usize) < columns
+            && mask
+                .get(row as usize)
+                .and_then(|cells| cells.get(column as usize))
+                .copied()
+                .unwrap_or(false)
+    };
+
+    // The cell edges with a set cell on their left and an unset cell on their right, between
+    // grid nodes numbered by (column, row).
+    let mut outgoing: HashMap<Node, Vec<Node>> = HashMap::new();
+    let mut edge_count = 0;
+    for row in 0..rows as isize {
+        for column in 0..columns as isize {
+            if !is_set(row, column) {
+                continue;
+            }
+            let (x, y) = (column, row);
+            let sides = [
+                (is_set(row - 1, column), (x, y), (x + 1, y)),
+                (is_set(row, column + 1), (x + 1, y), (x + 1, y + 1)),
+                (is_set(row + 1, column), (x + 1, y + 1), (x, y + 1)),
+                (is_set(row, column - 1), (x, y + 1), (x, y)),
+            ];
+            for (neighbour_is_set, from, to) in &sides {
+                if !neighbour_is_set {
+                    outgoing.entry(*from).or_default().push(*to);
+                    edge_count += 1;
+                }
+            }
+        }
+    }
+
+    let mut rings = vec![];
+    let mut starts: Vec<Node> = outgoing.keys().copied().collect();
+    starts.sort_by_key(|(x, y)| (*y, *x));
+    for start in starts {
+        while let Some(first) = outgoing.get_mut(&start).and_then(|ends| ends.pop()) {
+            let mut nodes = vec![start];
+            let (mut from, mut to) = (start, first);
+            while to != start {
+                nodes.push(to);
+                let next = take_leftmost_turn(&mut outgoing, from, to);
+                from = to;
+                to = next;
+            }
+            edge_count -= nodes.len();
+            split_at_repeated_nodes(nodes, &mut rings);
+        }
+    }
+    debug_assert_eq!(edge_count, 0);
+
+    let (shells, holes): (Vec<Vec<Node>>, Vec<Vec<Node>>) = rings
+        .into_iter()
+        .map(|ring| remove_collinear_nodes(&ring))
+        .partition(|ring| twice_signed_area(ring) > 0);
+
+    let mut interiors: Vec<Vec<Vec<Node>>> = vec![vec![]; shells.len()];
+    for hole in holes {
+        // the centre of the unset cell to the right of the hole's first edge, doubled to keep to
+        // integers, lies strictly inside the hole, and so inside the shells enclosing it
+        let (from, to) = (hole[0], hole[1]);
+        let (dx, dy) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+        let probe = (2 * from.0 + dx + dy, 2 * from.1 + dy - dx);
+        let owner = (0..shells.len())
+            .filter(|index| encloses(&shells[*index], probe))
+            .min_by_key(|index| twice_signed_area(&shells[*index]));
+        if let Some(owner) = owner {
+            interiors[owner].push(hole);
+        }
+    }
+
+    let min = bounds.min();
+    let width = bounds.width();
+    let height = bounds.height();
+    let to_line_string = |ring: &[Node]| {
+        let mut coords: Vec<Coordinate<T>> = ring
+            .iter()
+            .map(|(x, y)| Coordinate {
+                x: min.x + width * T::from(*x).unwrap() / T::from(columns).unwrap(),
+                y: min.y + height * T::from(*y).unwrap() / T::from(rows).unwrap(),
+            })
+            .collect();
+        coords.push(coords[0]);
+        LineString(coords)
+    };
+    shells
+        .iter()
+        .zip(interiors)
+        .map(|(shell, holes)| {
+            Polygon::new(
+                to_line_string(shell),
+                holes.iter().map(|hole| to_line_string(hole)).collect(),
+            )
+        })
+        .collect()
+}
+
+type Node = (isize, isize);
+
+// Remove and return the edge leaving `to` which turns furthest left after arriving from `from`.
+// Where two set cells meet only at a corner, turning left keeps to the boundary of the cell the
+// edge arrived along, so the cells aren't joined through the corner.
+fn take_leftmost_turn(outgoing: &mut HashMap<Node, Vec<Node>>, from: Node, to: Node) -> Node {
+    let ends = outgoing
+        .get_mut(&to)
+        .expect("every boundary edge is followed by another");
+    let turn = |end: &Node| (to.0 - from.0) * (end.1 - to.1) - (to.1 - from.1) * (end.0 - to.0);
+    let (index, _) = ends
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, end)| turn(end))
+        .expect("every boundary edge is followed by another");
+    ends.swap_remove(index)
+}
+
+// Split a closed walk through the grid into rings which each pass through a node only once.
+// Rings of the same region which touch at a corner, like a shell and a hole touching it, are
+// traced as one walk through that corner twice.
+fn split_at_repeated_nodes(nodes: Vec<Node>, rings: &mut Vec<Vec<Node>>) {
+    let mut stack: Vec<Node> = vec![];
+    let mut positions: HashMap<Node, usize> = HashMap::new();
+    for node in nodes {
+        if let Some(position) = positions.get(&node).copied() {
+            let ring = stack.split_off(position);
+            for removed in &ring {
+                positions.remove(removed);
+            }
+            rings.push(ring);
+        }
+        positions.insert(node, stack.len());
+        stack.push(node);
+    }
+    rings.push(stack);
+}
+
+fn remove_collinear_nodes(ring: &[Node]) -> Vec<Node> {
+    let len = ring.len();
+    (0..len)
+        .filter(|index| {
+            let previous = ring[(index + len - 1) % len];
+            let node = ring[*index];
+            let next = ring[(index + 1) % len];
+            (node.0 - previous.0) * (next.1 - node.1) != (node.1 - previous.1) * (next.0 - node.0)
+        })
+        .map(|index| ring[index])
+        .collect()
+}
+
+fn twice_signed_area(ring: &[Node]) -> isize {
+    let mut coords: Vec<Coordinate<isize>> =
+        ring.iter().map(|&(x, y)| Coordinate { x, y }).collect();
+    coords.push(coords[0]);
+    twice_signed_ring_area(&LineString(coords))
+}
+
+// Whether a point, in doubled grid coordinates and never on a cell edge, is inside `ring`.
+fn encloses(ring: &[Node], point: Node) -> bool {
+    let len = ring.len();
+    let mut inside = false;
+    for index in 0..len {
+        let (a, b) = (ring[index], ring[(index + 1) % len]);
+        let (a, b) = ((a.0 * 2, a.1 * 2), (b.0 * 2, b.1 * 2));
+        // only vertical edges can cross a horizontal ray
+        if a.0 == b.0 && a.0 > point.0 && (a.1 > point.1) != (b.1 > point.1) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::coordinate_position::CoordPos;
+    use crate::algorithm::dimensions::Dimensions;
+    use crate::algorithm::relate::Relate;
+    use crate::{polygon, Geometry};
+
+    fn unit_bounds(mask: &[Vec<bool>]) -> Rect<f64> {
+        Rect::new((0., 0.), (mask[0].len() as f64, mask.len() as f64))
+    }
+
+    #[test]
+    fn block_with_a_hole() {
+        let mask = vec![
+            vec![false, false, false, false, false],
+            vec![false, true, true, true, false],
+            vec![false, true, false, true, false],
+            vec![false, true, true, true, false],
+        ];
+        let traced = trace_mask(&mask, unit_bounds(&mask));
+        let expected = polygon![
+            exterior: [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 1., y: 4.), (x: 1., y: 1.)],
+            interiors: [[(x: 2., y: 2.), (x: 2., y: 3.), (x: 3., y: 3.), (x: 3., y: 2.), (x: 2., y: 2.)]],
+        ];
+        assert_eq!(traced, MultiPolygon(vec![expected]));
+    }
+
+    #[test]
+    fn corners_touch_at_points() {
+        // a block with two holes touching each other at a corner, and a third enclosing an
+        // island which touches the block at a corner; then a C whose tips meet at a corner, so
+        // its hole touches its shell there, and a cell touching the C at a corner
+        let mask: Vec<Vec<bool>> = vec![
+            "#######", "#.#####", "##.####", "#######", "#...###", "#.#.###", "#..####", "#######",
+            ".......", "###....", "#.#....", "##.....", "..#....",
+        ]
+        .iter()
+        .map(|row| row.chars().map(|cell| cell == '#').collect())
+        .collect();
+        let traced = trace_mask(&mask, unit_bounds(&mask));
+        assert_eq!(traced.0.len(), 4);
+        let interiors: Vec<usize> = traced
+            .iter()
+            .map(|polygon| polygon.interiors().len())
+            .collect();
+        assert_eq!(interiors, vec![3, 0, 1, 0]);
+        let set_cells = mask.iter().flatten().filter(|cell| **cell).count();
+        assert_eq!(traced.signed_area(), set_cells as f64);
+
+        // every cell centre is inside the traced polygons exactly when it is set
+        let polygons = traced.0.clone();
+        let traced = Geometry::MultiPolygon(traced);
+        for (row, cells) in mask.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                let centre = Geometry::Point((column as f64 + 0.5, row as f64 + 0.5).into());
+                assert_eq!(traced.relate(&centre).is_contains(), *cell);
+            }
+        }
+
+        // and the polygons are valid: each ring passes through each of its vertices once, and
+        // the polygons' interiors don't meet
+        for polygon in &polygons {
+            for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                let mut vertices: Vec<(i64, i64)> = ring.0[1..]
+                    .iter()
+                    .map(|coord| (coord.x as i64, coord.y as i64))
+                    .collect();
+                let len = vertices.len();
+                vertices.sort_unstable();
+                vertices.dedup();
+                assert_eq!(vertices.len(), len);
+            }
+            for other in &polygons {
+                if other != polygon {
+                    let matrix = polygon.relate(other);
+                    assert_eq!(
+                        matrix.get(CoordPos::Inside, CoordPos::Inside),
+                        Dimensions::Empty
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_to_bounds() {
+        let traced = trace_mask(&[vec![true, true]], Rect::new((10., 20.), (14., 21.)));
+        assert_eq!(
+            traced,
+            MultiPolygon(vec![polygon![
+                (x: 10., y: 20.), (x: 14., y: 20.), (x: 14., y: 21.), (x: 10., y: 21.),
+            ]])
+        );
+        assert!(trace_mask::<f64>(&[], Rect::new((0., 0.), (1., 1.)))
+            .0
+            .is_empty());
+    }
+}
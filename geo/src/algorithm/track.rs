@@ -0,0 +1,233 @@
+//! Measure and divide tracks, such as those recorded by a GPS receiver and stored as GPX.
+//!
+//! A track is a slice of timestamped points, as [`Geofence`](crate::algorithm::geofence::Geofence)
+//! takes them: each point is a longitude and latitude, in degrees, and each time is in seconds,
+//! increasing along the track. Distances are measured with
+//! [`HaversineDistance`](crate::algorithm::haversine_distance::HaversineDistance), in meters.
+
+use crate::algorithm::haversine_distance::HaversineDistance;
+use crate::{GeoFloat, Point};
+use num_traits::FromPrimitive;
+use std::ops::Range;
+
+/// A place where a track stayed, as found by [`stops`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stop<T: GeoFloat> {
+    /// The mean of the longitudes and latitudes of the track's points during the stop
+    pub center: Point<T>,
+    /// The indices of the track's points during the stop
+    pub points: Range<usize>,
+    /// The time of the first point of the stop
+    pub arrival: T,
+    /// The time of the last point of the stop
+    pub departure: T,
+}
+
+/// The speed of the track along each of its segments, in meters per second: the haversine
+/// distance between consecutive points, over the time between them.
+///
+/// There is one speed fewer than there are points. A segment taking no time has no speed if it
+/// covers no distance, and an infinite speed otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::track::segment_speeds;
+/// use geo::point;
+///
+/// // a tenth of a degree of longitude along the equator, in five minutes, then a wait
+/// let track = vec![
+///     (point!(x: 0., y: 0.), 0.),
+///     (point!(x: 0.1, y: 0.), 300.),
+///     (point!(x: 0.1, y: 0.), 600.),
+/// ];
+/// let speeds: Vec<f64> = segment_speeds(&track);
+/// assert_eq!(speeds.len(), 2);
+/// assert_eq!(speeds[0].round(), 37.); // meters per second
+/// assert_eq!(speeds[1], 0.);
+/// ```
+pub fn segment_speeds<T>(track: &[(Point<T>, T)]) -> Vec<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    track
+        .windows(2)
+        .map(|pair| {
+            let (start, start_time) = pair[0];
+            let (end, end_time) = pair[1];
+            let distance = start.haversine_distance(&end);
+            let duration = end_time - start_time;
+            if duration == T::zero() {
+                if distance == T::zero() {
+                    T::zero()
+                } else {
+                    T::infinity()
+                }
+            } else {
+                distance / duration
+            }
+        })
+        .collect()
+}
+
+/// The places where the track stayed within `radius` meters for at least `min_duration` seconds,
+/// in order.
+///
+/// A stop is a cluster of consecutive points all within `radius` of its first point, whose first
+/// and last points are at least `min_duration` apart. Clusters are grown greedily from the start
+/// of the track, and don't overlap: the points after a stop are clustered afresh. Since the center
+/// is the mean of the points' coordinates, stops straddling the antimeridian aren't supported.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::track::stops;
+/// use geo::point;
+///
+/// // driving east, waiting ten minutes at a junction, then moving on
+/// let track = vec![
+///     (point!(x: 0., y: 0.), 0.),
+///     (point!(x: 0.01, y: 0.), 60.),
+///     (point!(x: 0.01001, y: 0.), 300.),
+///     (point!(x: 0.01, y: 0.00001), 660.),
+///     (point!(x: 0.02, y: 0.), 720.),
+/// ];
+/// let stops = stops(&track, 10., 300.);
+/// assert_eq!(stops.len(), 1);
+/// assert_eq!(stops[0].points, 1..4);
+/// assert_eq!((stops[0].arrival, stops[0].departure), (60., 660.));
+/// ```
+pub fn stops<T>(track: &[(Point<T>, T)], radius: T, min_duration: T) -> Vec<Stop<T>>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    let mut stops = vec![];
+    let mut start = 0;
+    while start < track.len() {
+        let (anchor, arrival) = track[start];
+        let end = start
+            + track[start..]
+                .iter()
+                .take_while(|(point, _)| anchor.haversine_distance(point) <= radius)
+                .count();
+        let departure = track[end - 1].1;
+        if departure - arrival < min_duration {
+            start += 1;
+            continue;
+        }
+
+        let count = T::from(end - start).unwrap();
+        let sum = track[start..end]
+            .iter()
+            .fold(Point::new(T::zero(), T::zero()), |sum, (point, _)| {
+                sum + *point
+            });
+        stops.push(Stop {
+            center: sum / count,
+            points: start..end,
+            arrival,
+            departure,
+        });
+        start = end;
+    }
+    stops
+}
+
+/// Split the track wherever consecutive points are more than `max_time_gap` seconds, or
+/// `max_distance_gap` meters, apart, such as where the receiver lost its fix or was switched off.
+///
+/// Pass an infinite threshold to split on the other alone. An empty track has no parts.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::track::split_at_gaps;
+/// use geo::point;
+///
+/// let track = vec![
+///     (point!(x: 0., y: 0.), 0.),
+///     (point!(x: 0.001, y: 0.), 10.),
+///     // an hour without a fix
+///     (point!(x: 0.002, y: 0.), 3610.),
+///     (point!(x: 0.003, y: 0.), 3620.),
+///     // a jump of over a kilometer
+///     (point!(x: 0.02, y: 0.), 3630.),
+/// ];
+/// let parts = split_at_gaps(&track, 600., 1000.);
+/// assert_eq!(parts, vec![&track[0..2], &track[2..4], &track[4..5]]);
+///
+/// let parts = split_at_gaps(&track, f64::INFINITY, 1000.);
+/// assert_eq!(parts, vec![&track[0..4], &track[4..5]]);
+/// ```
+pub fn split_at_gaps<T>(
+    track: &[(Point<T>, T)],
+    max_time_gap: T,
+    max_distance_gap: T,
+) -> Vec<&[(Point<T>, T)]>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    if track.is_empty() {
+        return vec![];
+    }
+    let mut parts = vec![];
+    let mut start = 0;
+    for (index, pair) in track.windows(2).enumerate() {
+        let (from, from_time) = pair[0];
+        let (to, to_time) = pair[1];
+        if to_time - from_time > max_time_gap || from.haversine_distance(&to) > max_distance_gap {
+            parts.push(&track[start..=index]);
+            start = index + 1;
+        }
+    }
+    parts.push(&track[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn stops_at_either_end() {
+        // parked for an hour, a short drive, then parked again until the track ends
+        let track = vec![
+            (point!(x: 10., y: 50.), 0.),
+            (point!(x: 10., y: 50.), 1800.),
+            (point!(x: 10., y: 50.), 3600.),
+            (point!(x: 10.01, y: 50.), 3660.),
+            (point!(x: 10.02, y: 50.), 3720.),
+            (point!(x: 10.02, y: 50.), 4020.),
+        ];
+        let found = stops(&track, 20., 600.);
+        assert_eq!(
+            found,
+            vec![Stop {
+                center: point!(x: 10., y: 50.),
+                points: 0..3,
+                arrival: 0.,
+                departure: 3600.,
+            }]
+        );
+
+        // the last stop is only long enough with a lower threshold
+        let found = stops(&track, 20., 300.);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].points, 4..6);
+
+        assert!(stops(&[], 20., 300.).is_empty());
+        assert!(split_at_gaps::<f64>(&[], 600., 1000.).is_empty());
+        assert!(segment_speeds::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn instantaneous_segments() {
+        let track = vec![
+            (point!(x: 0., y: 0.), 0.),
+            (point!(x: 0., y: 0.), 0.),
+            (point!(x: 1., y: 0.), 0.),
+        ];
+        assert_eq!(segment_speeds(&track), vec![0., f64::INFINITY]);
+    }
+}
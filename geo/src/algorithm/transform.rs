@@ -0,0 +1,117 @@
+use crate::algorithm::map_coords::TryMapCoords;
+use crate::CoordNum;
+use std::error::Error;
+
+/// A fallible transformation of coordinates between two coordinate reference systems, and back.
+///
+/// Implement this for a projection engine to reproject geometries with
+/// [`Transform::transform`], without this crate depending on it. The Web Mercator and UTM
+/// projections of [`mercator`](crate::algorithm::mercator) implement it, as, with the
+/// `use-proj` feature, does [`ProjTransform`](crate::algorithm::proj::ProjTransform), for
+/// [PROJ](https://proj.org).
+pub trait CoordTransform<T: CoordNum> {
+    /// Transform a coordinate from the source system to the target system.
+    fn forward(&self, coord: &(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>>;
+
+    /// Transform a coordinate from the target system back to the source system.
+    fn inverse(&self, coord: &(T, T)) -> Result<(T, T), Box<dyn Error + Send + Sync>>;
+}
+
+/// Reproject a geometry with a [`CoordTransform`].
+pub trait Transform<T: CoordNum> {
+    type Output;
+
+    /// Transform every coordinate of the geometry forward, failing with the first coordinate
+    /// which fails to transform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::mercator::UtmZone;
+    /// use geo::algorithm::transform::Transform;
+    /// use geo::line_string;
+    ///
+    /// let zone = UtmZone::new(31, true).unwrap();
+    /// let line = line_string![(x: 2.29, y: 48.85), (x: 2.35, y: 48.86)];
+    /// let projected = line.transform(&zone).unwrap();
+    /// assert!(projected.0.iter().all(|coord| coord.x > 400_000. && coord.y > 5_400_000.));
+    ///
+    /// let unprojected = projected.inverse_transform(&zone).unwrap();
+    /// assert!((unprojected.0[1].x - 2.35_f64).abs() < 1e-9);
+    /// ```
+    fn transform<C: CoordTransform<T>>(
+        &self,
+        transform: &C,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>>;
+
+    /// Transform every coordinate of the geometry back, failing with the first coordinate which
+    /// fails to transform.
+    fn inverse_transform<C: CoordTransform<T>>(
+        &self,
+        transform: &C,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>>;
+}
+
+impl<T, G> Transform<T> for G
+where
+    T: CoordNum,
+    G: TryMapCoords<T, T>,
+{
+    type Output = G::Output;
+
+    fn transform<C: CoordTransform<T>>(
+        &self,
+        transform: &C,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        self.try_map_coords(|coord| transform.forward(coord))
+    }
+
+    fn inverse_transform<C: CoordTransform<T>>(
+        &self,
+        transform: &C,
+    ) -> Result<Self::Output, Box<dyn Error + Send + Sync>> {
+        self.try_map_coords(|coord| transform.inverse(coord))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Geometry};
+
+    // a transformation which only works in the first quadrant
+    struct Swap;
+
+    impl CoordTransform<f64> for Swap {
+        fn forward(
+            &self,
+            &(x, y): &(f64, f64),
+        ) -> Result<(f64, f64), Box<dyn Error + Send + Sync>> {
+            if x < 0. || y < 0. {
+                return Err("outside the first quadrant".into());
+            }
+            Ok((y, x))
+        }
+
+        fn inverse(&self, coord: &(f64, f64)) -> Result<(f64, f64), Box<dyn Error + Send + Sync>> {
+            self.forward(coord)
+        }
+    }
+
+    #[test]
+    fn transforms_and_fails() {
+        let triangle: Geometry<f64> =
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 0., y: 1.)].into();
+        let swapped = triangle.transform(&Swap).unwrap();
+        assert_eq!(
+            swapped,
+            polygon![(x: 0., y: 0.), (x: 0., y: 2.), (x: 1., y: 0.)].into()
+        );
+        assert_eq!(swapped.inverse_transform(&Swap).unwrap(), triangle);
+
+        let outside: Geometry<f64> =
+            polygon![(x: 0., y: 0.), (x: -2., y: 0.), (x: 0., y: 1.)].into();
+        let error = outside.transform(&Swap).unwrap_err();
+        assert_eq!(error.to_string(), "outside the first quadrant");
+    }
+}
@@ -0,0 +1,340 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::cancel::{self, Cancel, NeverCancelled};
+use crate::algorithm::convex_hull::ConvexHull;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::erosion::faces_where;
+use crate::algorithm::intersects::Intersects;
+use crate::algorithm::orient::{Direction, Orient};
+use crate::algorithm::sweepline::SweepLine;
+use crate::utils::lex_cmp;
+use crate::{
+    Coordinate, GeoError, GeoFloat, Line, LineString, MultiPoint, MultiPolygon, Point, Polygon,
+    Rect,
+};
+use std::cmp::Ordering;
+
+// The number of segments approximating a quarter circle around each vertex
+const QUADRANT_SEGMENTS: usize = 8;
+
+/// Buffer a line by a distance which varies along it, giving a corridor which tapers, such as a
+/// river widening towards its mouth, where a buffer by a single distance can't.
+///
+/// Each vertex of the line is given its own distance, and the distance between two vertices is
+/// interpolated linearly along the segment joining them. So each segment is buffered by the
+/// convex hull of the circles around its ends, and the buffer is the union of those hulls, with
+/// the circles approximated by polygons. The hulls' edges are noded together, the pieces inside
+/// another hull, or between two, dropped, and the faces the rest bound inside the hulls kept.
+pub trait VariableBuffer<T: GeoFloat> {
+    /// The points within the interpolated distance of the line, where `distances` gives the
+    /// distance at each of its coordinates, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't a distance for each coordinate, or any distance is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+    /// use geo::algorithm::variable_buffer::VariableBuffer;
+    /// use geo::{line_string, Coordinate};
+    ///
+    /// let river = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 20., y: 5.)];
+    /// let corridor = river.variable_buffer(&[1., 2., 4.]);
+    /// assert_eq!(corridor.0.len(), 1);
+    ///
+    /// // halfway along the first segment, the corridor is 1.5 either side of the river
+    /// let inside = Coordinate { x: 5., y: 1.4 };
+    /// let outside = Coordinate { x: 5., y: 1.6 };
+    /// assert_eq!(corridor.coordinate_position(&inside), CoordPos::Inside);
+    /// assert_eq!(corridor.coordinate_position(&outside), CoordPos::Outside);
+    /// ```
+    fn variable_buffer(&self, distances: &[T]) -> MultiPolygon<T> {
+        self.variable_buffer_cancellable(distances, &NeverCancelled)
+            .expect("never cancelled")
+    }
+
+    /// The buffer, as given by [`variable_buffer`](Self::variable_buffer), checking `cancel` as
+    /// the hulls are noded and their pieces classified, and failing with
+    /// [`GeoError::Cancelled`] once it's cancelled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't a distance for each coordinate, or any distance is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::cancel::Deadline;
+    /// use geo::algorithm::variable_buffer::VariableBuffer;
+    /// use geo::{line_string, GeoError};
+    /// use std::time::Duration;
+    ///
+    /// let river = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 20., y: 5.)];
+    /// let deadline = Deadline::after(Duration::from_secs(10));
+    /// let corridor = river.variable_buffer_cancellable(&[1., 2., 4.], &deadline);
+    /// assert_eq!(corridor, Ok(river.variable_buffer(&[1., 2., 4.])));
+    ///
+    /// let cancelled = river.variable_buffer_cancellable(&[1., 2., 4.], &|| true);
+    /// assert_eq!(cancelled, Err(GeoError::Cancelled));
+    /// ```
+    fn variable_buffer_cancellable(
+        &self,
+        distances: &[T],
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<T>, GeoError>;
+
+    /// The points within a distance of the line which tapers linearly along its length, from
+    /// `start` at its first coordinate to `end` at its last.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` or `end` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::variable_buffer::VariableBuffer;
+    /// use geo::line_string;
+    ///
+    /// let line = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 10., y: 0.)];
+    /// // a trapezoid from 1 to 3 wide either side, capped by half circles
+    /// let corridor = line.tapered_buffer(1., 3.);
+    /// let area = 10. * (2. + 6.) / 2.;
+    /// assert!(corridor.unsigned_area() > area);
+    /// assert!(corridor.unsigned_area() < area + (1. + 9.) * std::f64::consts::PI / 2. * 1.1);
+    /// ```
+    fn tapered_buffer(&self, start: T, end: T) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> VariableBuffer<T> for LineString<T> {
+    fn variable_buffer_cancellable(
+        &self,
+        distances: &[T],
+        cancel: &dyn Cancel,
+    ) -> Result<MultiPolygon<T>, GeoError> {
+        assert_eq!(
+            distances.len(),
+            self.0.len(),
+            "there must be a distance for each coordinate"
+        );
+        assert!(
+            distances.iter().all(|distance| *distance >= T::zero()),
+            "distances must not be negative"
+        );
+
+        let circles: Vec<Vec<Coordinate<T>>> = self
+            .0
+            .iter()
+            .zip(distances)
+            .map(|(coord, distance)| circle(*coord, *distance))
+            .collect();
+        let hulls: Vec<Polygon<T>> = if circles.len() == 1 {
+            vec![hull(&circles[0], &[])]
+        } else {
+            circles
+                .windows(2)
+                .map(|pair| hull(&pair[0], &pair[1]))
+                .collect()
+        };
+        union(&hulls, cancel)
+    }
+
+    fn tapered_buffer(&self, start: T, end: T) -> MultiPolygon<T> {
+        assert!(
+            start >= T::zero() && end >= T::zero(),
+            "distances must not be negative"
+        );
+        // the distance along the line to each coordinate
+        let mut along = Vec::with_capacity(self.0.len());
+        let mut length = T::zero();
+        for (index, coord) in self.0.iter().enumerate() {
+            if index > 0 {
+                let delta = *coord - self.0[index - 1];
+                length = length + delta.x.hypot(delta.y);
+            }
+            along.push(length);
+        }
+        let distances: Vec<T> = along
+            .into_iter()
+            .map(|along| {
+                if length > T::zero() {
+                    start + (end - start) * along / length
+                } else {
+                    start
+                }
+            })
+            .collect();
+        self.variable_buffer(&distances)
+    }
+}
+
+// The union of polygons, ignoring any holes, as the faces bounded by the pieces of their
+// exteriors on its boundary. With the exteriors wound counter-clockwise, a piece is on the
+// boundary if it's inside none of the polygons it doesn't run along, and those it does run along
+// are all on the same side of it. Fails with `GeoError::Cancelled` once `cancel` is.
+pub(crate) fn union<T: GeoFloat>(
+    polygons: &[Polygon<T>],
+    cancel: &dyn Cancel,
+) -> Result<MultiPolygon<T>, GeoError> {
+    cancel::check(cancel)?;
+    let polygons: Vec<Polygon<T>> = polygons
+        .iter()
+        .map(|polygon| polygon.orient(Direction::Default))
+        .collect();
+    let rects: Vec<Option<Rect<T>>> = polygons
+        .iter()
+        .map(|polygon| polygon.bounding_rect())
+        .collect();
+    let is_inside = |polygon: usize, coord: Coordinate<T>| match rects[polygon] {
+        Some(rect) if rect.intersects(&coord) => {
+            polygons[polygon].coordinate_position(&coord) == CoordPos::Inside
+        }
+        _ => false,
+    };
+
+    // the pieces of the exteriors, each with its polygon, and whether it runs the same way
+    let mut polygon_of_edge = vec![];
+    for (index, polygon) in polygons.iter().enumerate() {
+        polygon_of_edge.extend(polygon.exterior().lines().map(|_| index));
+    }
+    let mut pieces: Vec<(Line<T>, usize, bool)> = SweepLine::new(
+        polygons
+            .iter()
+            .flat_map(|polygon| polygon.exterior().lines()),
+    )
+    .noded_segments_cancellable(cancel)?
+    .into_iter()
+    .map(|piece| {
+        let polygon = polygon_of_edge[piece.source];
+        match lex_cmp(&piece.line.start, &piece.line.end) {
+            Ordering::Greater => (Line::new(piece.line.end, piece.line.start), polygon, false),
+            _ => (piece.line, polygon, true),
+        }
+    })
+    .collect();
+    pieces.sort_by(|a, b| lex_cmp(&a.0.start, &b.0.start).then(lex_cmp(&a.0.end, &b.0.end)));
+
+    let two = T::one() + T::one();
+    let mut segments = vec![];
+    let mut first = 0;
+    while first < pieces.len() {
+        cancel::check(cancel)?;
+        let line = pieces[first].0;
+        let count = pieces[first..]
+            .iter()
+            .take_while(|(other, _, _)| *other == line)
+            .count();
+        let run = &pieces[first..first + count];
+        first += count;
+
+        let is_left = run.iter().any(|(_, _, same_way)| *same_way);
+        let is_right = run.iter().any(|(_, _, same_way)| !*same_way);
+        let midpoint = (line.start + line.end) / two;
+        let is_inside_other = (0..polygons.len()).any(|polygon| {
+            !run.iter().any(|(_, along, _)| *along == polygon) && is_inside(polygon, midpoint)
+        });
+        if is_left != is_right && !is_inside_other {
+            segments.push(line);
+        }
+    }
+    cancel::check(cancel)?;
+    Ok(MultiPolygon(faces_where(segments, |coord| {
+        (0..polygons.len()).any(|polygon| is_inside(polygon, coord))
+    })))
+}
+
+// The vertices of a polygon approximating the circle of `radius` around `center`.
+fn circle<T: GeoFloat>(center: Coordinate<T>, radius: T) -> Vec<Coordinate<T>> {
+    if radius == T::zero() {
+        return vec![center];
+    }
+    let count = 4 * QUADRANT_SEGMENTS;
+    (0..count)
+        .map(|step| {
+            let angle = T::from(2. * core::f64::consts::PI * step as f64 / count as f64).unwrap();
+            let (sin, cos) = angle.sin_cos();
+            center + Coordinate { x: cos, y: sin } * radius
+        })
+        .collect()
+}
+
+fn hull<T: GeoFloat>(a: &[Coordinate<T>], b: &[Coordinate<T>]) -> Polygon<T> {
+    MultiPoint(a.iter().chain(b).map(|coord| Point(*coord)).collect()).convex_hull()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::line_string;
+    use std::cell::Cell;
+
+    #[test]
+    fn constant_distances() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let buffer = line.variable_buffer(&[2., 2.]);
+        assert_eq!(buffer.0.len(), 1);
+        // a rectangle, and a circle approximated by a polygon of 32 sides
+        let circle = 32. / 2. * 4. * (2. * std::f64::consts::PI / 32.).sin();
+        assert_relative_eq!(buffer.unsigned_area(), 10. * 4. + circle, epsilon = 1e-9);
+
+        let point: LineString<f64> = line_string![(x: 0., y: 0.)];
+        assert_relative_eq!(
+            point.variable_buffer(&[2.]).unsigned_area(),
+            circle,
+            epsilon = 1e-9
+        );
+        assert!(line.variable_buffer(&[0., 0.]).0.is_empty());
+    }
+
+    #[test]
+    fn tapering_around_corners() {
+        // doubling back, so the corridor overlaps itself
+        let line: LineString<f64> =
+            line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.), (x: 0., y: 4.)];
+        let buffer = line.variable_buffer(&[0.5, 1., 1., 3.]);
+        assert_eq!(buffer.0.len(), 1);
+        assert!(buffer.0[0].interiors().is_empty());
+        let position = |x, y| buffer.coordinate_position(&Coordinate { x, y });
+        // narrow at the start, and wide at the end, where it covers the start
+        assert_eq!(position(5., 0.7), CoordPos::Inside);
+        assert_eq!(position(5., -0.8), CoordPos::Outside);
+        assert_eq!(position(0., -0.4), CoordPos::Inside);
+        assert_eq!(position(1., 6.5), CoordPos::Inside);
+
+        // a loop leaves a hole
+        let ring: LineString<f64> = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let buffer = ring.tapered_buffer(1., 2.);
+        assert_eq!(buffer.0.len(), 1);
+        assert_eq!(buffer.0[0].interiors().len(), 1);
+    }
+
+    #[test]
+    fn cancelled_part_way() {
+        let line: LineString<f64> = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.)];
+        let distances = [1., 1., 1.];
+        let checks = Cell::new(0);
+        let cancel = || {
+            checks.set(checks.get() + 1);
+            checks.get() > 10
+        };
+        assert_eq!(
+            line.variable_buffer_cancellable(&distances, &cancel),
+            Err(GeoError::Cancelled)
+        );
+        assert_eq!(checks.get(), 11);
+        assert_eq!(
+            line.variable_buffer_cancellable(&distances, &|| false),
+            Ok(line.variable_buffer(&distances))
+        );
+    }
+}
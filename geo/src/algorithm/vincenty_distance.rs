@@ -162,6 +162,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Eq, PartialEq, Debug)]
 pub struct FailedToConvergeError;
 
@@ -0,0 +1,207 @@
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::algorithm::kernels::{Kernel, Orientation};
+use crate::algorithm::segment::{cross, dot};
+use crate::algorithm::sort_around_point::compare_around_point;
+use crate::{Coordinate, GeoFloat, Line, LineString, Point, Polygon};
+use std::cmp::Ordering;
+
+/// The region of a polygon visible from a point inside it: its visibility polygon, or isovist.
+///
+/// A point of the polygon is visible from `viewpoint` if the segment between them doesn't cross
+/// the polygon's boundary, so holes, like walls or buildings, cast shadows. The result is the
+/// field of view of a security camera with a full circle of vision, say, or the area of a
+/// square visible from a spot in it.
+///
+/// The vertices of the polygon are swept counter-clockwise around `viewpoint`, in the exact
+/// order of [`compare_around_point`]. In the direction of each vertex, a ray is cast to the
+/// nearest edge just clockwise of the direction, and to the nearest edge just counter-clockwise
+/// of it, which are different edges where the ray grazes a corner. Which side of the ray each
+/// edge lies on is decided with robust orientation predicates, so only the points where the ray
+/// meets an edge, between its ends, are subject to rounding.
+///
+/// Returns `None` if `viewpoint` isn't in the interior of `polygon`.
+///
+/// PERF: each ray is tested against every edge, so this takes time quadratic in the number of
+/// vertices.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::area::Area;
+/// use geo::algorithm::visibility::visibility_polygon;
+/// use geo::{point, polygon};
+///
+/// // an L-shaped room
+/// let room = polygon![
+///     (x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.), (x: 2., y: 4.), (x: 0., y: 4.),
+/// ];
+///
+/// // from the corner of the L, the whole room is visible
+/// let visible = visibility_polygon(&room, point!(x: 1., y: 1.)).unwrap();
+/// assert_eq!(visible.unsigned_area(), 12.);
+///
+/// // but from the end of one arm, the inside corner hides part of the other
+/// let visible = visibility_polygon(&room, point!(x: 3., y: 1.)).unwrap();
+/// assert_eq!(visible.unsigned_area(), 10.);
+///
+/// assert!(visibility_polygon(&room, point!(x: 3., y: 3.)).is_none());
+/// ```
+pub fn visibility_polygon<T: GeoFloat>(
+    polygon: &Polygon<T>,
+    viewpoint: Point<T>,
+) -> Option<Polygon<T>> {
+    if polygon.coordinate_position(&viewpoint.0) != CoordPos::Inside {
+        return None;
+    }
+    let origin = viewpoint.0;
+    let rings = || std::iter::once(polygon.exterior()).chain(polygon.interiors());
+    let edges: Vec<Line<T>> = rings()
+        .flat_map(|ring| ring.lines())
+        .filter(|line| line.start != line.end)
+        .collect();
+
+    let mut directions: Vec<Coordinate<T>> =
+        rings().flat_map(|ring| ring.0.iter().cloned()).collect();
+    directions.sort_by(|a, b| compare_around_point(origin, *a, *b));
+    directions.dedup_by(|a, b| compare_around_point(origin, *a, *b) == Ordering::Equal);
+
+    let mut coords: Vec<Coordinate<T>> = vec![];
+    for direction in directions {
+        for side in &[Orientation::Clockwise, Orientation::CounterClockwise] {
+            let hit = nearest_hit(&edges, origin, direction, *side)
+                .expect("a ray from the interior of a polygon meets its exterior on either side");
+            if coords.last() != Some(&hit) {
+                coords.push(hit);
+            }
+        }
+    }
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    Some(Polygon::new(LineString(coords), vec![]))
+}
+
+// The nearest point where the ray from `origin` through `direction` meets an edge with some part
+// strictly on the given `side` of the ray: the point which rays just to that side of it
+// converge on.
+fn nearest_hit<T: GeoFloat>(
+    edges: &[Line<T>],
+    origin: Coordinate<T>,
+    direction: Coordinate<T>,
+    side: Orientation,
+) -> Option<Coordinate<T>> {
+    let ray = direction - origin;
+    let mut nearest: Option<(T, Coordinate<T>)> = None;
+    for edge in edges {
+        let start_side = T::Ker::orient2d(origin, direction, edge.start);
+        let end_side = T::Ker::orient2d(origin, direction, edge.end);
+        if start_side == end_side || (start_side != side && end_side != side) {
+            continue;
+        }
+        // where the edge meets the line through the ray, as a multiple of `ray`
+        let (distance, hit) = if start_side == Orientation::Collinear {
+            (dot(edge.start - origin, ray), edge.start)
+        } else if end_side == Orientation::Collinear {
+            (dot(edge.end - origin, ray), edge.end)
+        } else {
+            let delta = edge.delta();
+            let t = cross(edge.start - origin, delta) / cross(ray, delta);
+            (t * dot(ray, ray), origin + ray * t)
+        };
+        if distance <= T::zero() {
+            continue;
+        }
+        match nearest {
+            Some((nearest_distance, _)) if nearest_distance <= distance => {}
+            _ => nearest = Some((distance, hit)),
+        }
+    }
+    nearest.map(|(_, hit)| hit)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::intersects::Intersects;
+    use crate::{point, polygon};
+
+    #[test]
+    fn convex() {
+        let square = polygon![(x: -1., y: -1.), (x: 1., y: -1.), (x: 1., y: 1.), (x: -1., y: 1.)];
+        let visible = visibility_polygon(&square, point!(x: 0., y: 0.)).unwrap();
+        // the square itself, swept from the first corner counter-clockwise of the x axis
+        assert_eq!(
+            visible.exterior().0,
+            vec![
+                (1., 1.).into(),
+                (-1., 1.).into(),
+                (-1., -1.).into(),
+                (1., -1.).into(),
+                (1., 1.).into(),
+            ]
+        );
+        assert!(visibility_polygon(&square, point!(x: 1., y: 0.)).is_none());
+    }
+
+    #[test]
+    fn shadow_of_a_hole() {
+        let square = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        ];
+        let viewpoint = point!(x: 2., y: 5.);
+        let visible = visibility_polygon(&square, viewpoint).unwrap();
+        // the shadow is the trapezium between the rays through the hole's near corners, from
+        // x = 4, where it is 2 wide, to x = 10, where it is 8 wide, less the hole itself
+        let shadow = (2. + 8.) / 2. * 6. - 4.;
+        assert_relative_eq!(visible.unsigned_area(), 100. - 4. - shadow);
+
+        // and a point is visible exactly when the segment to it misses the boundary
+        let walls: Vec<Line<f64>> = std::iter::once(square.exterior())
+            .chain(square.interiors())
+            .flat_map(|ring| ring.lines())
+            .collect();
+        for i in 0..20 {
+            for j in 0..20 {
+                let target = point!(x: 0.37 + i as f64 / 2., y: 0.29 + j as f64 / 2.);
+                if square.coordinate_position(&target.0) != CoordPos::Inside {
+                    continue;
+                }
+                let sight = Line::new(viewpoint, target);
+                let is_visible = !walls.iter().any(|wall| sight.intersects(wall));
+                assert_eq!(
+                    visible.coordinate_position(&target.0) == CoordPos::Inside,
+                    is_visible,
+                    "{:?}",
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn grazing_corners() {
+        // a base with two teeth on top
+        let comb = polygon![
+            (x: 0., y: 0.), (x: 8., y: 0.), (x: 8., y: 4.), (x: 6., y: 4.), (x: 6., y: 2.),
+            (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.), (x: 2., y: 2.), (x: 0., y: 2.),
+        ];
+        // the ray through the inside corner at (2, 2) grazes it, and runs on to the far corner of
+        // the first tooth at (4, 4)
+        let visible = visibility_polygon(&comb, point!(x: 1., y: 1.)).unwrap();
+        // all of the base, the half of the first tooth below that ray, and a sliver of the
+        // second tooth, seen through the gap between the teeth
+        assert_relative_eq!(visible.unsigned_area(), 16. + 2. + 0.4);
+        for target in &[
+            point!(x: 3.5, y: 2.5),
+            point!(x: 3.9, y: 3.8),
+            point!(x: 7.9, y: 2.1),
+        ] {
+            assert_eq!(visible.coordinate_position(&target.0), CoordPos::Inside);
+        }
+        for target in &[point!(x: 2.5, y: 3.5), point!(x: 7.5, y: 3.5)] {
+            assert_eq!(visible.coordinate_position(&target.0), CoordPos::Outside);
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use crate::{
+    CoordNum, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Callbacks for each part of a geometry, as it is [walked](Walk::walk) from the outside in:
+/// collections, their members, the polygons of multi-polygons, the rings of polygons, and
+/// finally the coordinates.
+///
+/// Every method does nothing by default, so a visitor implements only those it needs. Each
+/// `enter_*` is called before the parts inside it are walked, and the matching `leave_*` after.
+///
+/// - `enter_geometry` is called for a [`Geometry`] being walked, and for each member of a
+///   [`GeometryCollection`], before the geometry it holds.
+/// - `enter_polygon` is called for each [`Polygon`], including the members of a
+///   [`MultiPolygon`], and `enter_ring` for each of its rings, numbered from zero for the
+///   exterior.
+/// - `enter_line_string` is called for each [`LineString`] which isn't a ring, including the
+///   members of a [`MultiLineString`].
+/// - `visit_coord` is called for every coordinate: those of points, lines, line strings,
+///   rings and triangles, and the minimum and maximum coordinates of a [`Rect`].
+pub trait Visitor<T: CoordNum> {
+    fn enter_geometry(&mut self, _geometry: &Geometry<T>) {}
+    fn leave_geometry(&mut self, _geometry: &Geometry<T>) {}
+    fn enter_collection(&mut self, _collection: &GeometryCollection<T>) {}
+    fn leave_collection(&mut self, _collection: &GeometryCollection<T>) {}
+    fn enter_polygon(&mut self, _polygon: &Polygon<T>) {}
+    fn leave_polygon(&mut self, _polygon: &Polygon<T>) {}
+    fn enter_ring(&mut self, _ring: &LineString<T>, _index: usize) {}
+    fn leave_ring(&mut self, _ring: &LineString<T>, _index: usize) {}
+    fn enter_line_string(&mut self, _line_string: &LineString<T>) {}
+    fn leave_line_string(&mut self, _line_string: &LineString<T>) {}
+    fn visit_coord(&mut self, _coord: &Coordinate<T>) {}
+}
+
+/// Callbacks for each part of a geometry, as with [`Visitor`], but able to change the parts as
+/// they're [walked](Walk::walk_mut).
+///
+/// Whatever an `enter_*` callback leaves in place is what's walked inside it, so a visitor may,
+/// for example, replace a geometry, or drop the holes of a polygon, before its coordinates are
+/// visited. Rings are closed again after they've been walked, as [`Polygon::exterior_mut`]
+/// closes them, and a `Rect` is rebuilt from its visited minimum and maximum coordinates, which
+/// are swapped as [`Rect::new`] swaps them if they're no longer in order.
+pub trait VisitorMut<T: CoordNum> {
+    fn enter_geometry(&mut self, _geometry: &mut Geometry<T>) {}
+    fn leave_geometry(&mut self, _geometry: &mut Geometry<T>) {}
+    fn enter_collection(&mut self, _collection: &mut GeometryCollection<T>) {}
+    fn leave_collection(&mut self, _collection: &mut GeometryCollection<T>) {}
+    fn enter_polygon(&mut self, _polygon: &mut Polygon<T>) {}
+    fn leave_polygon(&mut self, _polygon: &mut Polygon<T>) {}
+    fn enter_ring(&mut self, _ring: &mut LineString<T>, _index: usize) {}
+    fn leave_ring(&mut self, _ring: &mut LineString<T>, _index: usize) {}
+    fn enter_line_string(&mut self, _line_string: &mut LineString<T>) {}
+    fn leave_line_string(&mut self, _line_string: &mut LineString<T>) {}
+    fn visit_coord(&mut self, _coord: &mut Coordinate<T>) {}
+}
+
+/// Walk the structure of a geometry with a [`Visitor`], or change it with a [`VisitorMut`],
+/// so that tools working across every type of geometry, such as statistics, reprojection,
+/// validation or the assignment of IDs, needn't each match on them all recursively.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::visit::{Visitor, VisitorMut, Walk};
+/// use geo::{line_string, point, polygon, Coordinate, Geometry, GeometryCollection};
+///
+/// let collection = GeometryCollection(vec![
+///     Geometry::from(point!(x: 0., y: 0.)),
+///     Geometry::from(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]),
+///     Geometry::from(polygon![
+///         exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///         interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)]],
+///     ]),
+/// ]);
+///
+/// // statistics
+/// #[derive(Default)]
+/// struct Counts {
+///     geometries: usize,
+///     holes: usize,
+///     coords: usize,
+/// }
+///
+/// impl Visitor<f64> for Counts {
+///     fn enter_geometry(&mut self, _geometry: &Geometry<f64>) {
+///         self.geometries += 1;
+///     }
+///     fn enter_ring(&mut self, _ring: &geo::LineString<f64>, index: usize) {
+///         if index > 0 {
+///             self.holes += 1;
+///         }
+///     }
+///     fn visit_coord(&mut self, _coord: &Coordinate<f64>) {
+///         self.coords += 1;
+///     }
+/// }
+///
+/// let mut counts = Counts::default();
+/// collection.walk(&mut counts);
+/// assert_eq!((counts.geometries, counts.holes, counts.coords), (3, 1, 12));
+///
+/// // reprojection, scaling from kilometers to meters
+/// struct ToMeters;
+///
+/// impl VisitorMut<f64> for ToMeters {
+///     fn visit_coord(&mut self, coord: &mut Coordinate<f64>) {
+///         *coord = *coord * 1000.;
+///     }
+/// }
+///
+/// let mut collection = collection;
+/// collection.walk_mut(&mut ToMeters);
+/// assert_eq!(collection.0[0], Geometry::from(point!(x: 0., y: 0.)));
+/// assert_eq!(
+///     collection.0[1],
+///     Geometry::from(line_string![(x: 0., y: 0.), (x: 1000., y: 1000.)])
+/// );
+/// ```
+pub trait Walk<T: CoordNum> {
+    /// Call `visitor` for each part of the geometry, from the outside in.
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V);
+
+    /// Call `visitor` for each part of the geometry, from the outside in, letting it change them.
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V);
+}
+
+impl<T: CoordNum> Walk<T> for Coordinate<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.visit_coord(self);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.visit_coord(self);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for Point<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.visit_coord(&self.0);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.visit_coord(&mut self.0);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for Line<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.visit_coord(&self.start);
+        visitor.visit_coord(&self.end);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.visit_coord(&mut self.start);
+        visitor.visit_coord(&mut self.end);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for LineString<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.enter_line_string(self);
+        self.0.iter().for_each(|coord| visitor.visit_coord(coord));
+        visitor.leave_line_string(self);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.enter_line_string(self);
+        self.0
+            .iter_mut()
+            .for_each(|coord| visitor.visit_coord(coord));
+        visitor.leave_line_string(self);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for Polygon<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.enter_polygon(self);
+        let rings = core::iter::once(self.exterior()).chain(self.interiors());
+        for (index, ring) in rings.enumerate() {
+            visitor.enter_ring(ring, index);
+            ring.0.iter().for_each(|coord| visitor.visit_coord(coord));
+            visitor.leave_ring(ring, index);
+        }
+        visitor.leave_polygon(self);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.enter_polygon(self);
+        self.exterior_mut(|exterior| walk_ring_mut(exterior, 0, visitor));
+        self.interiors_mut(|interiors| {
+            for (index, interior) in interiors.iter_mut().enumerate() {
+                walk_ring_mut(interior, index + 1, visitor);
+            }
+        });
+        visitor.leave_polygon(self);
+    }
+}
+
+fn walk_ring_mut<T: CoordNum, V: VisitorMut<T>>(
+    ring: &mut LineString<T>,
+    index: usize,
+    visitor: &mut V,
+) {
+    visitor.enter_ring(ring, index);
+    ring.0
+        .iter_mut()
+        .for_each(|coord| visitor.visit_coord(coord));
+    visitor.leave_ring(ring, index);
+}
+
+impl<T: CoordNum> Walk<T> for MultiPoint<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        self.0.iter().for_each(|point| point.walk(visitor));
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        self.0.iter_mut().for_each(|point| point.walk_mut(visitor));
+    }
+}
+
+impl<T: CoordNum> Walk<T> for MultiLineString<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        self.0
+            .iter()
+            .for_each(|line_string| line_string.walk(visitor));
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        self.0
+            .iter_mut()
+            .for_each(|line_string| line_string.walk_mut(visitor));
+    }
+}
+
+impl<T: CoordNum> Walk<T> for MultiPolygon<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        self.0.iter().for_each(|polygon| polygon.walk(visitor));
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        self.0
+            .iter_mut()
+            .for_each(|polygon| polygon.walk_mut(visitor));
+    }
+}
+
+impl<T: CoordNum> Walk<T> for Rect<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.visit_coord(&self.min());
+        visitor.visit_coord(&self.max());
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        let (mut min, mut max) = (self.min(), self.max());
+        visitor.visit_coord(&mut min);
+        visitor.visit_coord(&mut max);
+        *self = Rect::new(min, max);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for Triangle<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.visit_coord(&self.0);
+        visitor.visit_coord(&self.1);
+        visitor.visit_coord(&self.2);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.visit_coord(&mut self.0);
+        visitor.visit_coord(&mut self.1);
+        visitor.visit_coord(&mut self.2);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for GeometryCollection<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.enter_collection(self);
+        self.0.iter().for_each(|geometry| geometry.walk(visitor));
+        visitor.leave_collection(self);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.enter_collection(self);
+        self.0
+            .iter_mut()
+            .for_each(|geometry| geometry.walk_mut(visitor));
+        visitor.leave_collection(self);
+    }
+}
+
+impl<T: CoordNum> Walk<T> for Geometry<T> {
+    fn walk<V: Visitor<T>>(&self, visitor: &mut V) {
+        visitor.enter_geometry(self);
+        match self {
+            Geometry::Point(g) => g.walk(visitor),
+            Geometry::Line(g) => g.walk(visitor),
+            Geometry::LineString(g) => g.walk(visitor),
+            Geometry::Polygon(g) => g.walk(visitor),
+            Geometry::MultiPoint(g) => g.walk(visitor),
+            Geometry::MultiLineString(g) => g.walk(visitor),
+            Geometry::MultiPolygon(g) => g.walk(visitor),
+            Geometry::GeometryCollection(g) => g.walk(visitor),
+            Geometry::Rect(g) => g.walk(visitor),
+            Geometry::Triangle(g) => g.walk(visitor),
+        }
+        visitor.leave_geometry(self);
+    }
+
+    fn walk_mut<V: VisitorMut<T>>(&mut self, visitor: &mut V) {
+        visitor.enter_geometry(self);
+        match self {
+            Geometry::Point(g) => g.walk_mut(visitor),
+            Geometry::Line(g) => g.walk_mut(visitor),
+            Geometry::LineString(g) => g.walk_mut(visitor),
+            Geometry::Polygon(g) => g.walk_mut(visitor),
+            Geometry::MultiPoint(g) => g.walk_mut(visitor),
+            Geometry::MultiLineString(g) => g.walk_mut(visitor),
+            Geometry::MultiPolygon(g) => g.walk_mut(visitor),
+            Geometry::GeometryCollection(g) => g.walk_mut(visitor),
+            Geometry::Rect(g) => g.walk_mut(visitor),
+            Geometry::Triangle(g) => g.walk_mut(visitor),
+        }
+        visitor.leave_geometry(self);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    // Records the order of the callbacks.
+    #[derive(Default)]
+    struct Trace(Vec<String>);
+
+    impl Visitor<i32> for Trace {
+        fn enter_geometry(&mut self, _geometry: &Geometry<i32>) {
+            self.0.push("(".to_string());
+        }
+        fn leave_geometry(&mut self, _geometry: &Geometry<i32>) {
+            self.0.push(")".to_string());
+        }
+        fn enter_collection(&mut self, _collection: &GeometryCollection<i32>) {
+            self.0.push("collection".to_string());
+        }
+        fn enter_polygon(&mut self, _polygon: &Polygon<i32>) {
+            self.0.push("polygon".to_string());
+        }
+        fn enter_ring(&mut self, _ring: &LineString<i32>, index: usize) {
+            self.0.push(format!("ring {}", index));
+        }
+        fn enter_line_string(&mut self, _line_string: &LineString<i32>) {
+            self.0.push("line string".to_string());
+        }
+        fn visit_coord(&mut self, coord: &Coordinate<i32>) {
+            self.0.push(format!("{} {}", coord.x, coord.y));
+        }
+    }
+
+    #[test]
+    fn walks_from_the_outside_in() {
+        let nested = Geometry::GeometryCollection(GeometryCollection(vec![
+            Geometry::from(point!(x: 1, y: 2)),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::from(
+                MultiLineString(vec![line_string![(x: 0, y: 0), (x: 3, y: 0)]]),
+            )])),
+            Geometry::from(polygon![
+                exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 0, y: 4)],
+                interiors: [[(x: 1, y: 1), (x: 1, y: 2), (x: 2, y: 1)]],
+            ]),
+        ]));
+        let mut trace = Trace::default();
+        nested.walk(&mut trace);
+        assert_eq!(
+            trace.0.join(", "),
+            "(, collection, \
+             (, 1 2, ), \
+             (, collection, (, line string, 0 0, 3 0, ), ), \
+             (, polygon, ring 0, 0 0, 4 0, 0 4, 0 0, ring 1, 1 1, 1 2, 2 1, 1 1, ), \
+             )"
+        );
+    }
+
+    // Mirrors geometries across the y axis, and drops the holes of polygons.
+    struct Mirror;
+
+    impl VisitorMut<i32> for Mirror {
+        fn enter_polygon(&mut self, polygon: &mut Polygon<i32>) {
+            *polygon = Polygon::new(polygon.exterior().clone(), vec![]);
+        }
+        fn visit_coord(&mut self, coord: &mut Coordinate<i32>) {
+            coord.x = -coord.x;
+        }
+    }
+
+    #[test]
+    fn walk_mut_changes_every_part() {
+        let mut polygon = polygon![
+            exterior: [(x: 0, y: 0), (x: 4, y: 0), (x: 0, y: 4)],
+            interiors: [[(x: 1, y: 1), (x: 1, y: 2), (x: 2, y: 1)]],
+        ];
+        polygon.walk_mut(&mut Mirror);
+        assert_eq!(polygon, polygon![(x: 0, y: 0), (x: -4, y: 0), (x: 0, y: 4)]);
+
+        // the mirrored corners are put back in order
+        let mut rect = Rect::new((1, 1), (2, 3));
+        rect.walk_mut(&mut Mirror);
+        assert_eq!(rect, Rect::new((-2, 1), (-1, 3)));
+    }
+}
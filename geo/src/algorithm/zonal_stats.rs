@@ -0,0 +1,184 @@
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::{GeoFloat, Point, Rect};
+use rstar::{RTree, RTreeNum, RTreeObject, AABB};
+
+/// Summary statistics of the values of the points within a zone, computed by [`zonal_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZonalStats<T: GeoFloat> {
+    /// The number of points within the zone
+    pub count: usize,
+    /// The sum of their values
+    pub sum: T,
+    /// The mean of their values
+    pub mean: T,
+    /// The least of their values
+    pub min: T,
+    /// The greatest of their values
+    pub max: T,
+}
+
+/// Aggregate the values of points by the polygons, or zones, they fall within.
+///
+/// Returns, for each zone, the count, sum, mean, minimum and maximum of the values of the
+/// points within it, or `None` if there are none. A point within several zones counts towards
+/// each of them, including a point on the boundary between two zones, since a zone's boundary
+/// is taken to be part of it.
+///
+/// The points are indexed in an R*-tree, and only the points within the bounding rectangle of
+/// each zone are tested for whether they are within it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::zonal_stats::zonal_stats;
+/// use geo::{point, polygon};
+///
+/// // rainfall measured at three gauges
+/// let gauges = vec![
+///     (point!(x: 1., y: 1.), 10.),
+///     (point!(x: 3., y: 1.), 20.),
+///     (point!(x: 6., y: 1.), 60.),
+/// ];
+/// let catchments = vec![
+///     polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)],
+///     polygon![(x: 0., y: 5.), (x: 4., y: 5.), (x: 4., y: 7.), (x: 0., y: 7.)],
+/// ];
+///
+/// let stats = zonal_stats(&gauges, &catchments);
+/// let first = stats[0].unwrap();
+/// assert_eq!(first.count, 2);
+/// assert_eq!(first.mean, 15.);
+/// assert_eq!((first.min, first.max), (10., 20.));
+/// assert_eq!(stats[1], None);
+/// ```
+pub fn zonal_stats<T, G>(points: &[(Point<T>, T)], zones: &[G]) -> Vec<Option<ZonalStats<T>>>
+where
+    T: GeoFloat + RTreeNum,
+    G: BoundingRect<T, Output = Option<Rect<T>>> + CoordinatePosition<Scalar = T>,
+{
+    let tree = RTree::bulk_load(
+        points
+            .iter()
+            .map(|(point, value)| IndexedPoint {
+                point: *point,
+                value: *value,
+            })
+            .collect(),
+    );
+
+    zones
+        .iter()
+        .map(|zone| {
+            let rect = zone.bounding_rect()?;
+            let envelope = AABB::from_corners(rect.min().into(), rect.max().into());
+            let mut stats: Option<ZonalStats<T>> = None;
+            for indexed in tree.locate_in_envelope_intersecting(&envelope) {
+                if zone.coordinate_position(&indexed.point.0) == CoordPos::Outside {
+                    continue;
+                }
+                let value = indexed.value;
+                stats = Some(match stats {
+                    None => ZonalStats {
+                        count: 1,
+                        sum: value,
+                        mean: value,
+                        min: value,
+                        max: value,
+                    },
+                    Some(stats) => ZonalStats {
+                        count: stats.count + 1,
+                        sum: stats.sum + value,
+                        mean: stats.mean,
+                        min: stats.min.min(value),
+                        max: stats.max.max(value),
+                    },
+                });
+            }
+            stats.map(|stats| ZonalStats {
+                mean: stats.sum / T::from(stats.count).unwrap(),
+                ..stats
+            })
+        })
+        .collect()
+}
+
+// A point and its value, as stored in the R*-tree.
+struct IndexedPoint<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    point: Point<T>,
+    value: T,
+}
+
+impl<T> RTreeObject for IndexedPoint<T>
+where
+    T: GeoFloat + RTreeNum,
+{
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        AABB::from_point(self.point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon, MultiPolygon};
+
+    #[test]
+    fn boundaries_and_holes() {
+        let zones = vec![
+            MultiPolygon(vec![polygon![
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                interiors: [[(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)]],
+            ]]),
+            MultiPolygon(vec![
+                polygon![(x: 4., y: 0.), (x: 8., y: 0.), (x: 8., y: 4.), (x: 4., y: 4.)],
+                polygon![(x: 2., y: 2.), (x: 2.5, y: 2.), (x: 2.5, y: 2.5), (x: 2., y: 2.5)],
+            ]),
+        ];
+        let points = vec![
+            // in the hole of the first zone, and in the island of the second
+            (point!(x: 2.2, y: 2.2), 1.),
+            // on the boundary between the zones
+            (point!(x: 4., y: 2.), -3.),
+            (point!(x: 0.5, y: 0.5), 8.),
+            (point!(x: 6., y: 3.), 4.),
+            (point!(x: 9., y: 3.), 100.),
+        ];
+        let stats = zonal_stats(&points, &zones);
+        assert_eq!(
+            stats,
+            vec![
+                Some(ZonalStats {
+                    count: 2,
+                    sum: 5.,
+                    mean: 2.5,
+                    min: -3.,
+                    max: 8.,
+                }),
+                Some(ZonalStats {
+                    count: 3,
+                    sum: 2.,
+                    mean: 2. / 3.,
+                    min: -3.,
+                    max: 4.,
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let zones = vec![polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]];
+        assert_eq!(zonal_stats::<f64, _>(&[], &zones), vec![None]);
+        let empty_zone = vec![MultiPolygon::<f64>(vec![])];
+        assert_eq!(
+            zonal_stats(&[(point!(x: 0., y: 0.), 1.)], &empty_zone),
+            vec![None]
+        );
+    }
+}
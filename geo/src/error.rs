@@ -0,0 +1,44 @@
+use std::{error, fmt};
+
+/// Why an algorithm failed on input it can't handle, returned by the fallible variants of
+/// algorithms which would otherwise panic, such as
+/// [`TryRelate::try_relate`](crate::algorithm::relate::TryRelate::try_relate) and
+/// [`SweepLine::try_new`](crate::algorithm::sweepline::SweepLine::try_new), or by the
+/// cancellable variants of long-running algorithms.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeoError {
+    /// A coordinate of an input geometry is `NaN` or infinite
+    NonFiniteCoordinate {
+        /// Which of the input geometries, counting from 0
+        geometry: usize,
+        /// The position of the coordinate among those of the geometry, in the order of
+        /// [`CoordsIter::coords_iter`](crate::algorithm::coords_iter::CoordsIter::coords_iter)
+        index: usize,
+    },
+    /// An end of an input segment is `NaN` or infinite
+    NonFiniteSegment {
+        /// The index of the segment among the input segments
+        segment: usize,
+    },
+    /// The algorithm was abandoned, on a request to [`Cancel`](crate::algorithm::cancel::Cancel)
+    Cancelled,
+}
+
+impl fmt::Display for GeoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoError::NonFiniteCoordinate { geometry, index } => write!(
+                f,
+                "coordinate {} of geometry {} isn't finite",
+                index, geometry
+            ),
+            GeoError::NonFiniteSegment { segment } => {
+                write!(f, "segment {} has an end which isn't finite", segment)
+            }
+            GeoError::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl error::Error for GeoError {}
@@ -2,7 +2,7 @@ use crate::{
     CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
     MultiPolygon, Point, Polygon, Rect, Triangle,
 };
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
 /// A `GeometryCow` is a "one of" enum, just like [`Geometry`], except it is possible for the inner
 /// type of a `GeometryCow` to be a reference rather than owned.
@@ -10,6 +10,7 @@ use std::borrow::Cow;
 /// This is a way to "upgrade" an inner type to something like a `Geometry` without `moving` it.
 ///
 /// As an example, see the [`Relate`] trait which uses `GeometryCow`.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
 #[derive(PartialEq, Debug, Hash)]
 pub(crate) enum GeometryCow<'a, T>
 where
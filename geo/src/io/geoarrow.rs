@@ -0,0 +1,1109 @@
+//! Convert geometries to and from the buffers of [GeoArrow]'s native encoding, and run bulk
+//! algorithms directly over them.
+//!
+//! GeoArrow stores an array of geometries of one type as a single buffer of coordinates, here in
+//! its separated layout of one buffer of `x` and another of `y`, and a buffer of `i32` offsets
+//! for each level of nesting: the [`PolygonArray`] of two triangles and a square has six `x`,
+//! two `geom_offsets` per polygon into its rings, and `ring_offsets` into the coordinates.
+//!
+//! The buffers here are exactly those of the Arrow arrays, so they can be moved into and out of
+//! Arrow's `ScalarBuffer`s and `OffsetBuffer`s without copying; this module doesn't depend on the
+//! `arrow` crate itself, which needs a newer compiler than this crate supports. Validity bitmaps
+//! are left to the caller: a null geometry is stored as an empty one.
+//!
+//! Each array's `unsigned_areas`, `euclidean_lengths`, `bounding_rects` and `centroids` work over
+//! the buffers with [`LineStringView`]s, without allocating a geometry for each feature.
+//!
+//! [GeoArrow]: https://geoarrow.org
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::io::geoarrow::PolygonArray;
+//! use geo::{point, polygon, Rect};
+//!
+//! let polygons = vec![
+//!     polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+//!     polygon![(x: 4., y: 0.), (x: 7., y: 0.), (x: 4., y: 3.)],
+//! ];
+//! let array: PolygonArray<f64> = polygons.iter().collect();
+//! assert_eq!(array.geom_offsets(), &[0, 1, 2]);
+//! assert_eq!(array.ring_offsets(), &[0, 5, 9]);
+//!
+//! assert_eq!(array.unsigned_areas(), vec![4., 4.5]);
+//! assert_eq!(array.centroids()[1], Some(point!(x: 5., y: 1.)));
+//! assert_eq!(array.bounding_rects()[0], Some(Rect::new((0., 0.), (2., 2.))));
+//! assert_eq!(array.get(1), Some(polygons[1].clone()));
+//! ```
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::Range;
+
+use crate::algorithm::coord_slices::LineStringView;
+use crate::{
+    Coordinate, GeoFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon, Rect,
+};
+
+/// An error in the buffers given to one of the arrays' `from_parts`.
+///
+/// With the `use-serde` feature this can be serialized, but not deserialized, as it borrows the
+/// names of the buffers for `'static`.
+#[cfg_attr(feature = "use-serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeoArrowError {
+    /// The `x` and `y` buffers had different lengths.
+    MismatchedCoordinates { x: usize, y: usize },
+    /// A buffer of offsets was empty, decreased, or ran past the end of the buffer it indexes.
+    InvalidOffsets {
+        /// The name of the buffer of offsets, such as `"ring_offsets"`.
+        name: &'static str,
+    },
+}
+
+impl std::error::Error for GeoArrowError {}
+
+impl fmt::Display for GeoArrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoArrowError::MismatchedCoordinates { x, y } => write!(
+                f,
+                "Expected as many y coordinates as x coordinates, but found {} and {}",
+                y, x
+            ),
+            GeoArrowError::InvalidOffsets { name } => write!(f, "Invalid {}", name),
+        }
+    }
+}
+
+/// A GeoArrow array of `Point`s: a buffer of `x`, and one of `y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointArray<T: GeoFloat> {
+    coords: Coords<T>,
+}
+
+/// A GeoArrow array of `LineString`s, each running between consecutive `geom_offsets` into the
+/// coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineStringArray<T: GeoFloat> {
+    coords: Coords<T>,
+    geom_offsets: Vec<i32>,
+}
+
+/// A GeoArrow array of `Polygon`s, each with the rings between consecutive `geom_offsets`
+/// into the `ring_offsets`, which index the coordinates. The first ring of each polygon is its
+/// exterior.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonArray<T: GeoFloat> {
+    coords: Coords<T>,
+    geom_offsets: Vec<i32>,
+    ring_offsets: Vec<i32>,
+}
+
+/// A GeoArrow array of `MultiPoint`s, each with the points between consecutive
+/// `geom_offsets`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPointArray<T: GeoFloat> {
+    coords: Coords<T>,
+    geom_offsets: Vec<i32>,
+}
+
+/// A GeoArrow array of `MultiLineString`s, each with the lines between consecutive
+/// `geom_offsets` into the `ring_offsets`, which index the coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiLineStringArray<T: GeoFloat> {
+    coords: Coords<T>,
+    geom_offsets: Vec<i32>,
+    ring_offsets: Vec<i32>,
+}
+
+/// A GeoArrow array of `MultiPolygon`s, each with the polygons between consecutive
+/// `geom_offsets` into the `polygon_offsets`, which index the `ring_offsets`, which index the
+/// coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolygonArray<T: GeoFloat> {
+    coords: Coords<T>,
+    geom_offsets: Vec<i32>,
+    polygon_offsets: Vec<i32>,
+    ring_offsets: Vec<i32>,
+}
+
+// The separated coordinate buffers shared by each type of array.
+#[derive(Debug, Clone, PartialEq)]
+struct Coords<T> {
+    x: Vec<T>,
+    y: Vec<T>,
+}
+
+impl<T: GeoFloat> Coords<T> {
+    fn empty() -> Self {
+        Coords {
+            x: vec![],
+            y: vec![],
+        }
+    }
+
+    fn new(x: Vec<T>, y: Vec<T>) -> Result<Self, GeoArrowError> {
+        if x.len() != y.len() {
+            return Err(GeoArrowError::MismatchedCoordinates {
+                x: x.len(),
+                y: y.len(),
+            });
+        }
+        Ok(Coords { x, y })
+    }
+
+    fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    fn push(&mut self, coord: Coordinate<T>) {
+        self.x.push(coord.x);
+        self.y.push(coord.y);
+    }
+
+    fn coord(&self, index: usize) -> Coordinate<T> {
+        Coordinate {
+            x: self.x[index],
+            y: self.y[index],
+        }
+    }
+
+    fn view(&self, range: Range<usize>) -> LineStringView<'_, T> {
+        LineStringView::new(&self.x[range.clone()], &self.y[range]).unwrap()
+    }
+
+    fn line_string(&self, range: Range<usize>) -> LineString<T> {
+        range.map(|index| self.coord(index)).collect()
+    }
+
+    fn bounding_rect(&self, range: Range<usize>) -> Option<Rect<T>> {
+        self.view(range).bounding_rect()
+    }
+}
+
+// The next offset, as an `i32`.
+//
+// # Panics
+//
+// If `len` doesn't fit in an `i32`, as GeoArrow's offsets must.
+fn offset(len: usize) -> i32 {
+    assert!(
+        len <= i32::MAX as usize,
+        "GeoArrow offsets must fit in an i32"
+    );
+    len as i32
+}
+
+// Check that `offsets` is a valid buffer of offsets into something of length `len`.
+fn check_offsets(offsets: &[i32], len: usize, name: &'static str) -> Result<(), GeoArrowError> {
+    let valid = !offsets.is_empty()
+        && offsets[0] >= 0
+        && offsets.windows(2).all(|pair| pair[0] <= pair[1])
+        && offsets[offsets.len() - 1] as usize <= len;
+    if valid {
+        Ok(())
+    } else {
+        Err(GeoArrowError::InvalidOffsets { name })
+    }
+}
+
+// The range from `offsets[range.start]` to `offsets[range.end]`.
+fn nested(offsets: &[i32], range: Range<usize>) -> Range<usize> {
+    offsets[range.start] as usize..offsets[range.end] as usize
+}
+
+// The centroid of polygons, from their rings with whether each is an exterior, falling
+// back to the centroid of the rings as lines, then of their coordinates, where there's no area.
+fn polygons_centroid<'a, T, I>(rings: I) -> Option<Point<T>>
+where
+    T: GeoFloat + 'a,
+    I: Iterator<Item = (bool, LineStringView<'a, T>)>,
+{
+    let (zero, two, three) = (
+        T::zero(),
+        T::one() + T::one(),
+        T::one() + T::one() + T::one(),
+    );
+    let (mut area, mut area_x, mut area_y) = (zero, zero, zero);
+    let (mut length, mut length_x, mut length_y) = (zero, zero, zero);
+    let mut first = None;
+    for (exterior, ring) in rings {
+        if ring.is_empty() {
+            continue;
+        }
+        first = first.or_else(|| Some(ring.coord(0)));
+        let len = ring.len();
+        let (mut twice, mut moment_x, mut moment_y) = (zero, zero, zero);
+        for line in ring
+            .lines()
+            .chain(Some(Line::new(ring.coord(len - 1), ring.coord(0))))
+        {
+            let cross = line.start.x * line.end.y - line.end.x * line.start.y;
+            twice = twice + cross;
+            moment_x = moment_x + (line.start.x + line.end.x) * cross;
+            moment_y = moment_y + (line.start.y + line.end.y) * cross;
+            let segment = line.dx().hypot(line.dy());
+            length = length + segment;
+            length_x = length_x + (line.start.x + line.end.x) / two * segment;
+            length_y = length_y + (line.start.y + line.end.y) / two * segment;
+        }
+        // the ring's area, and its moments, which are its area times its centroid
+        let sign = if exterior { T::one() } else { -T::one() };
+        let sign = if twice < zero { -sign } else { sign };
+        area = area + sign * twice / two;
+        area_x = area_x + sign * moment_x / (two * three);
+        area_y = area_y + sign * moment_y / (two * three);
+    }
+    if area != zero {
+        Some(Point::new(area_x / area, area_y / area))
+    } else if length != zero {
+        Some(Point::new(length_x / length, length_y / length))
+    } else {
+        first.map(Point)
+    }
+}
+
+// The centroid of lines, weighting each segment by its length, or of their coordinates, if they
+// have no length.
+fn lines_centroid<'a, T, I>(lines: I) -> Option<Point<T>>
+where
+    T: GeoFloat + 'a,
+    I: Iterator<Item = LineStringView<'a, T>>,
+{
+    let two = T::one() + T::one();
+    let (mut length, mut sum_x, mut sum_y) = (T::zero(), T::zero(), T::zero());
+    let mut first = None;
+    for line in lines {
+        if line.is_empty() {
+            continue;
+        }
+        first = first.or_else(|| Some(line.coord(0)));
+        for segment in line.lines() {
+            let segment_length = segment.dx().hypot(segment.dy());
+            length = length + segment_length;
+            sum_x = sum_x + (segment.start.x + segment.end.x) / two * segment_length;
+            sum_y = sum_y + (segment.start.y + segment.end.y) / two * segment_length;
+        }
+    }
+    if length > T::zero() {
+        Some(Point::new(sum_x / length, sum_y / length))
+    } else {
+        first.map(Point)
+    }
+}
+
+impl<T: GeoFloat> PointArray<T> {
+    /// An array from the buffers of a GeoArrow point array.
+    pub fn from_parts(x: Vec<T>, y: Vec<T>) -> Result<Self, GeoArrowError> {
+        Ok(PointArray {
+            coords: Coords::new(x, y)?,
+        })
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        (self.coords.x, self.coords.y)
+    }
+
+    pub fn x(&self) -> &[T] {
+        &self.coords.x
+    }
+
+    pub fn y(&self) -> &[T] {
+        &self.coords.y
+    }
+
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<Point<T>> {
+        if index < self.len() {
+            Some(Point(self.coords.coord(index)))
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Point<T>> + '_ {
+        (0..self.len()).map(move |index| Point(self.coords.coord(index)))
+    }
+
+    /// The smallest [`Rect`] containing all the points, or `None` if there are none.
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        self.coords.bounding_rect(0..self.len())
+    }
+}
+
+impl<'a, T: GeoFloat> FromIterator<&'a Point<T>> for PointArray<T> {
+    fn from_iter<I: IntoIterator<Item = &'a Point<T>>>(iter: I) -> Self {
+        let mut coords = Coords::empty();
+        for point in iter {
+            coords.push(point.0);
+        }
+        PointArray { coords }
+    }
+}
+
+impl<T: GeoFloat> LineStringArray<T> {
+    /// An array from the buffers of a GeoArrow line string array.
+    pub fn from_parts(x: Vec<T>, y: Vec<T>, geom_offsets: Vec<i32>) -> Result<Self, GeoArrowError> {
+        let coords = Coords::new(x, y)?;
+        check_offsets(&geom_offsets, coords.len(), "geom_offsets")?;
+        Ok(LineStringArray {
+            coords,
+            geom_offsets,
+        })
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Vec<i32>) {
+        (self.coords.x, self.coords.y, self.geom_offsets)
+    }
+
+    pub fn x(&self) -> &[T] {
+        &self.coords.x
+    }
+
+    pub fn y(&self) -> &[T] {
+        &self.coords.y
+    }
+
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A view of the line string at `index`, borrowing the coordinate buffers.
+    pub fn view(&self, index: usize) -> Option<LineStringView<'_, T>> {
+        if index < self.len() {
+            Some(
+                self.coords
+                    .view(nested(&self.geom_offsets, index..index + 1)),
+            )
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, index: usize) -> Option<LineString<T>> {
+        self.view(index)
+            .map(|view| LineString(view.coords().collect()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = LineString<T>> + '_ {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    pub fn euclidean_lengths(&self) -> Vec<T> {
+        self.views().map(|view| view.euclidean_length()).collect()
+    }
+
+    pub fn bounding_rects(&self) -> Vec<Option<Rect<T>>> {
+        self.views().map(|view| view.bounding_rect()).collect()
+    }
+
+    /// The centroid of each line string, weighting its segments by their lengths.
+    pub fn centroids(&self) -> Vec<Option<Point<T>>> {
+        self.views()
+            .map(|view| lines_centroid(std::iter::once(view)))
+            .collect()
+    }
+
+    fn views(&self) -> impl Iterator<Item = LineStringView<'_, T>> {
+        (0..self.len()).map(move |index| self.view(index).unwrap())
+    }
+}
+
+impl<'a, T: GeoFloat> FromIterator<&'a LineString<T>> for LineStringArray<T> {
+    /// # Panics
+    ///
+    /// If there are more coordinates than fit in an `i32`.
+    fn from_iter<I: IntoIterator<Item = &'a LineString<T>>>(iter: I) -> Self {
+        let mut coords = Coords::empty();
+        let mut geom_offsets = vec![0];
+        for line_string in iter {
+            line_string.0.iter().for_each(|coord| coords.push(*coord));
+            geom_offsets.push(offset(coords.len()));
+        }
+        LineStringArray {
+            coords,
+            geom_offsets,
+        }
+    }
+}
+
+impl<T: GeoFloat> PolygonArray<T> {
+    /// An array from the buffers of a GeoArrow polygon array.
+    pub fn from_parts(
+        x: Vec<T>,
+        y: Vec<T>,
+        geom_offsets: Vec<i32>,
+        ring_offsets: Vec<i32>,
+    ) -> Result<Self, GeoArrowError> {
+        let coords = Coords::new(x, y)?;
+        check_offsets(&ring_offsets, coords.len(), "ring_offsets")?;
+        check_offsets(&geom_offsets, ring_offsets.len() - 1, "geom_offsets")?;
+        Ok(PolygonArray {
+            coords,
+            geom_offsets,
+            ring_offsets,
+        })
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Vec<i32>, Vec<i32>) {
+        (
+            self.coords.x,
+            self.coords.y,
+            self.geom_offsets,
+            self.ring_offsets,
+        )
+    }
+
+    pub fn x(&self) -> &[T] {
+        &self.coords.x
+    }
+
+    pub fn y(&self) -> &[T] {
+        &self.coords.y
+    }
+
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    pub fn ring_offsets(&self) -> &[i32] {
+        &self.ring_offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Views of the rings of the polygon at `index`, starting with its exterior.
+    pub fn rings(&self, index: usize) -> impl Iterator<Item = LineStringView<'_, T>> {
+        let rings = if index < self.len() {
+            nested(&self.geom_offsets, index..index + 1)
+        } else {
+            0..0
+        };
+        rings.map(move |ring| self.coords.view(nested(&self.ring_offsets, ring..ring + 1)))
+    }
+
+    pub fn get(&self, index: usize) -> Option<Polygon<T>> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut rings = self
+            .rings(index)
+            .map(|ring| LineString(ring.coords().collect()));
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Some(Polygon::new(exterior, rings.collect()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Polygon<T>> + '_ {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    /// The area of each polygon: that of its exterior, less those of its interiors.
+    pub fn unsigned_areas(&self) -> Vec<T> {
+        (0..self.len())
+            .map(|index| {
+                let mut rings = self.rings(index);
+                let exterior = rings
+                    .next()
+                    .map_or(T::zero(), |ring| ring.signed_area().abs());
+                rings.fold(exterior, |area, ring| area - ring.signed_area().abs())
+            })
+            .collect()
+    }
+
+    /// The bounding rectangle of each polygon's exterior.
+    pub fn bounding_rects(&self) -> Vec<Option<Rect<T>>> {
+        (0..self.len())
+            .map(|index| {
+                self.rings(index)
+                    .next()
+                    .and_then(|ring| ring.bounding_rect())
+            })
+            .collect()
+    }
+
+    /// The centroid of each polygon, as [`Centroid`](crate::algorithm::centroid::Centroid)
+    /// finds: that of its area, or if it has none, that of its rings as lines.
+    pub fn centroids(&self) -> Vec<Option<Point<T>>> {
+        (0..self.len())
+            .map(|index| {
+                let rings = self.rings(index).enumerate();
+                polygons_centroid(rings.map(|(ring, view)| (ring == 0, view)))
+            })
+            .collect()
+    }
+}
+
+impl<'a, T: GeoFloat> FromIterator<&'a Polygon<T>> for PolygonArray<T> {
+    /// # Panics
+    ///
+    /// If there are more coordinates, or rings, than fit in an `i32`.
+    fn from_iter<I: IntoIterator<Item = &'a Polygon<T>>>(iter: I) -> Self {
+        let mut coords = Coords::empty();
+        let (mut geom_offsets, mut ring_offsets) = (vec![0], vec![0]);
+        for polygon in iter {
+            for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                ring.0.iter().for_each(|coord| coords.push(*coord));
+                ring_offsets.push(offset(coords.len()));
+            }
+            geom_offsets.push(offset(ring_offsets.len() - 1));
+        }
+        PolygonArray {
+            coords,
+            geom_offsets,
+            ring_offsets,
+        }
+    }
+}
+
+impl<T: GeoFloat> MultiPointArray<T> {
+    /// An array from the buffers of a GeoArrow multi-point array.
+    pub fn from_parts(x: Vec<T>, y: Vec<T>, geom_offsets: Vec<i32>) -> Result<Self, GeoArrowError> {
+        let coords = Coords::new(x, y)?;
+        check_offsets(&geom_offsets, coords.len(), "geom_offsets")?;
+        Ok(MultiPointArray {
+            coords,
+            geom_offsets,
+        })
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Vec<i32>) {
+        (self.coords.x, self.coords.y, self.geom_offsets)
+    }
+
+    pub fn x(&self) -> &[T] {
+        &self.coords.x
+    }
+
+    pub fn y(&self) -> &[T] {
+        &self.coords.y
+    }
+
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<MultiPoint<T>> {
+        if index >= self.len() {
+            return None;
+        }
+        let points = nested(&self.geom_offsets, index..index + 1);
+        Some(MultiPoint(
+            points
+                .map(|point| Point(self.coords.coord(point)))
+                .collect(),
+        ))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = MultiPoint<T>> + '_ {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    pub fn bounding_rects(&self) -> Vec<Option<Rect<T>>> {
+        (0..self.len())
+            .map(|index| {
+                self.coords
+                    .bounding_rect(nested(&self.geom_offsets, index..index + 1))
+            })
+            .collect()
+    }
+
+    /// The mean of the points of each multi-point.
+    pub fn centroids(&self) -> Vec<Option<Point<T>>> {
+        (0..self.len())
+            .map(|index| {
+                let points = self
+                    .coords
+                    .view(nested(&self.geom_offsets, index..index + 1));
+                let count = T::from(points.len())?;
+                let sum = points
+                    .coords()
+                    .fold(Coordinate::zero(), |sum, coord| sum + coord);
+                if points.is_empty() {
+                    None
+                } else {
+                    Some(Point(sum / count))
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a, T: GeoFloat> FromIterator<&'a MultiPoint<T>> for MultiPointArray<T> {
+    /// # Panics
+    ///
+    /// If there are more coordinates than fit in an `i32`.
+    fn from_iter<I: IntoIterator<Item = &'a MultiPoint<T>>>(iter: I) -> Self {
+        let mut coords = Coords::empty();
+        let mut geom_offsets = vec![0];
+        for multi_point in iter {
+            multi_point.0.iter().for_each(|point| coords.push(point.0));
+            geom_offsets.push(offset(coords.len()));
+        }
+        MultiPointArray {
+            coords,
+            geom_offsets,
+        }
+    }
+}
+
+impl<T: GeoFloat> MultiLineStringArray<T> {
+    /// An array from the buffers of a GeoArrow multi-line string array.
+    pub fn from_parts(
+        x: Vec<T>,
+        y: Vec<T>,
+        geom_offsets: Vec<i32>,
+        ring_offsets: Vec<i32>,
+    ) -> Result<Self, GeoArrowError> {
+        let coords = Coords::new(x, y)?;
+        check_offsets(&ring_offsets, coords.len(), "ring_offsets")?;
+        check_offsets(&geom_offsets, ring_offsets.len() - 1, "geom_offsets")?;
+        Ok(MultiLineStringArray {
+            coords,
+            geom_offsets,
+            ring_offsets,
+        })
+    }
+
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Vec<i32>, Vec<i32>) {
+        (
+            self.coords.x,
+            self.coords.y,
+            self.geom_offsets,
+            self.ring_offsets,
+        )
+    }
+
+    pub fn x(&self) -> &[T] {
+        &self.coords.x
+    }
+
+    pub fn y(&self) -> &[T] {
+        &self.coords.y
+    }
+
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    pub fn ring_offsets(&self) -> &[i32] {
+        &self.ring_offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Views of the lines of the multi-line string at `index`.
+    pub fn lines(&self, index: usize) -> impl Iterator<Item = LineStringView<'_, T>> {
+        let lines = if index < self.len() {
+            nested(&self.geom_offsets, index..index + 1)
+        } else {
+            0..0
+        };
+        lines.map(move |line| self.coords.view(nested(&self.ring_offsets, line..line + 1)))
+    }
+
+    pub fn get(&self, index: usize) -> Option<MultiLineString<T>> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(MultiLineString(
+            self.lines(index)
+                .map(|line| LineString(line.coords().collect()))
+                .collect(),
+        ))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = MultiLineString<T>> + '_ {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    pub fn euclidean_lengths(&self) -> Vec<T> {
+        (0..self.len())
+            .map(|index| {
+                self.lines(index)
+                    .fold(T::zero(), |length, line| length + line.euclidean_length())
+            })
+            .collect()
+    }
+
+    pub fn bounding_rects(&self) -> Vec<Option<Rect<T>>> {
+        (0..self.len())
+            .map(|index| {
+                let lines = nested(&self.geom_offsets, index..index + 1);
+                self.coords.bounding_rect(nested(&self.ring_offsets, lines))
+            })
+            .collect()
+    }
+
+    /// The centroid of each multi-line string, weighting its segments by their lengths.
+    pub fn centroids(&self) -> Vec<Option<Point<T>>> {
+        (0..self.len())
+            .map(|index| lines_centroid(self.lines(index)))
+            .collect()
+    }
+}
+
+impl<'a, T: GeoFloat> FromIterator<&'a MultiLineString<T>> for MultiLineStringArray<T> {
+    /// # Panics
+    ///
+    /// If there are more coordinates, or lines, than fit in an `i32`.
+    fn from_iter<I: IntoIterator<Item = &'a MultiLineString<T>>>(iter: I) -> Self {
+        let mut coords = Coords::empty();
+        let (mut geom_offsets, mut ring_offsets) = (vec![0], vec![0]);
+        for multi_line_string in iter {
+            for line_string in &multi_line_string.0 {
+                line_string.0.iter().for_each(|coord| coords.push(*coord));
+                ring_offsets.push(offset(coords.len()));
+            }
+            geom_offsets.push(offset(ring_offsets.len() - 1));
+        }
+        MultiLineStringArray {
+            coords,
+            geom_offsets,
+            ring_offsets,
+        }
+    }
+}
+
+impl<T: GeoFloat> MultiPolygonArray<T> {
+    /// An array from the buffers of a GeoArrow multi-polygon array.
+    pub fn from_parts(
+        x: Vec<T>,
+        y: Vec<T>,
+        geom_offsets: Vec<i32>,
+        polygon_offsets: Vec<i32>,
+        ring_offsets: Vec<i32>,
+    ) -> Result<Self, GeoArrowError> {
+        let coords = Coords::new(x, y)?;
+        check_offsets(&ring_offsets, coords.len(), "ring_offsets")?;
+        check_offsets(&polygon_offsets, ring_offsets.len() - 1, "polygon_offsets")?;
+        check_offsets(&geom_offsets, polygon_offsets.len() - 1, "geom_offsets")?;
+        Ok(MultiPolygonArray {
+            coords,
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+        })
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>, Vec<i32>, Vec<i32>, Vec<i32>) {
+        (
+            self.coords.x,
+            self.coords.y,
+            self.geom_offsets,
+            self.polygon_offsets,
+            self.ring_offsets,
+        )
+    }
+
+    pub fn x(&self) -> &[T] {
+        &self.coords.x
+    }
+
+    pub fn y(&self) -> &[T] {
+        &self.coords.y
+    }
+
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    pub fn polygon_offsets(&self) -> &[i32] {
+        &self.polygon_offsets
+    }
+
+    pub fn ring_offsets(&self) -> &[i32] {
+        &self.ring_offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Views of the rings of the multi-polygon at `index`, polygon by polygon, each with
+    /// whether it's the exterior of its polygon.
+    pub fn rings(&self, index: usize) -> impl Iterator<Item = (bool, LineStringView<'_, T>)> {
+        let polygons = if index < self.len() {
+            nested(&self.geom_offsets, index..index + 1)
+        } else {
+            0..0
+        };
+        polygons.flat_map(move |polygon| {
+            let rings = nested(&self.polygon_offsets, polygon..polygon + 1);
+            let exterior = rings.start;
+            rings.map(move |ring| {
+                let view = self.coords.view(nested(&self.ring_offsets, ring..ring + 1));
+                (ring == exterior, view)
+            })
+        })
+    }
+
+    pub fn get(&self, index: usize) -> Option<MultiPolygon<T>> {
+        if index >= self.len() {
+            return None;
+        }
+        let polygons = nested(&self.geom_offsets, index..index + 1).map(|polygon| {
+            let mut rings = nested(&self.polygon_offsets, polygon..polygon + 1).map(|ring| {
+                self.coords
+                    .line_string(nested(&self.ring_offsets, ring..ring + 1))
+            });
+            let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+            Polygon::new(exterior, rings.collect())
+        });
+        Some(MultiPolygon(polygons.collect()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = MultiPolygon<T>> + '_ {
+        (0..self.len()).map(move |index| self.get(index).unwrap())
+    }
+
+    /// The area of each multi-polygon: those of its polygons' exteriors, less those of their
+    /// interiors.
+    pub fn unsigned_areas(&self) -> Vec<T> {
+        (0..self.len())
+            .map(|index| {
+                self.rings(index).fold(T::zero(), |area, (exterior, ring)| {
+                    let ring_area = ring.signed_area().abs();
+                    if exterior {
+                        area + ring_area
+                    } else {
+                        area - ring_area
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// The bounding rectangle of each multi-polygon's exteriors.
+    pub fn bounding_rects(&self) -> Vec<Option<Rect<T>>> {
+        (0..self.len())
+            .map(|index| {
+                self.rings(index)
+                    .filter(|(exterior, _)| *exterior)
+                    .filter_map(|(_, ring)| ring.bounding_rect())
+                    .fold(None, |rect: Option<Rect<T>>, ring_rect| {
+                        Some(match rect {
+                            None => ring_rect,
+                            Some(rect) => Rect::new(
+                                Coordinate {
+                                    x: rect.min().x.min(ring_rect.min().x),
+                                    y: rect.min().y.min(ring_rect.min().y),
+                                },
+                                Coordinate {
+                                    x: rect.max().x.max(ring_rect.max().x),
+                                    y: rect.max().y.max(ring_rect.max().y),
+                                },
+                            ),
+                        })
+                    })
+            })
+            .collect()
+    }
+
+    /// The centroid of each multi-polygon, as [`Centroid`](crate::algorithm::centroid::Centroid)
+    /// finds: that of its area, or if it has none, that of its rings as lines.
+    pub fn centroids(&self) -> Vec<Option<Point<T>>> {
+        (0..self.len())
+            .map(|index| polygons_centroid(self.rings(index)))
+            .collect()
+    }
+}
+
+impl<'a, T: GeoFloat> FromIterator<&'a MultiPolygon<T>> for MultiPolygonArray<T> {
+    /// # Panics
+    ///
+    /// If there are more coordinates, rings, or polygons, than fit in an `i32`.
+    fn from_iter<I: IntoIterator<Item = &'a MultiPolygon<T>>>(iter: I) -> Self {
+        let mut coords = Coords::empty();
+        let (mut geom_offsets, mut polygon_offsets, mut ring_offsets) = (vec![0], vec![0], vec![0]);
+        for multi_polygon in iter {
+            for polygon in &multi_polygon.0 {
+                for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                    ring.0.iter().for_each(|coord| coords.push(*coord));
+                    ring_offsets.push(offset(coords.len()));
+                }
+                polygon_offsets.push(offset(ring_offsets.len() - 1));
+            }
+            geom_offsets.push(offset(polygon_offsets.len() - 1));
+        }
+        MultiPolygonArray {
+            coords,
+            geom_offsets,
+            polygon_offsets,
+            ring_offsets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::area::Area;
+    use crate::algorithm::bounding_rect::BoundingRect;
+    use crate::algorithm::centroid::Centroid;
+    use crate::algorithm::euclidean_length::EuclideanLength;
+    use crate::{line_string, point, polygon};
+
+    fn polygons() -> Vec<Polygon<f64>> {
+        vec![
+            polygon![
+                exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+                interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+            ],
+            polygon![(x: 10., y: 0.), (x: 13., y: 1.), (x: 11., y: 5.)],
+            Polygon::new(LineString(vec![]), vec![]),
+        ]
+    }
+
+    #[test]
+    fn round_trips() {
+        let points = vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)];
+        let array: PointArray<f64> = points.iter().collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), points);
+
+        let line_strings = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+            LineString(vec![]),
+            line_string![(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 5.)],
+        ];
+        let array: LineStringArray<f64> = line_strings.iter().collect();
+        assert_eq!(array.geom_offsets(), &[0, 2, 2, 5]);
+        assert_eq!(array.iter().collect::<Vec<_>>(), line_strings);
+
+        let polygons = polygons();
+        let array: PolygonArray<f64> = polygons.iter().collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), polygons);
+        let (x, y, geom_offsets, ring_offsets) = array.clone().into_parts();
+        assert_eq!(
+            PolygonArray::from_parts(x, y, geom_offsets, ring_offsets),
+            Ok(array)
+        );
+
+        let multi_points = vec![MultiPoint(points), MultiPoint(vec![])];
+        let array: MultiPointArray<f64> = multi_points.iter().collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), multi_points);
+
+        let multi_line_strings = vec![MultiLineString(line_strings), MultiLineString(vec![])];
+        let array: MultiLineStringArray<f64> = multi_line_strings.iter().collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), multi_line_strings);
+
+        let multi_polygons = vec![MultiPolygon(polygons), MultiPolygon(vec![])];
+        let array: MultiPolygonArray<f64> = multi_polygons.iter().collect();
+        assert_eq!(array.iter().collect::<Vec<_>>(), multi_polygons);
+        let (x, y, geom_offsets, polygon_offsets, ring_offsets) = array.clone().into_parts();
+        assert_eq!(
+            MultiPolygonArray::from_parts(x, y, geom_offsets, polygon_offsets, ring_offsets),
+            Ok(array)
+        );
+
+        assert_eq!(
+            LineStringArray::from_parts(vec![0.], vec![0., 1.], vec![0, 1]),
+            Err(GeoArrowError::MismatchedCoordinates { x: 1, y: 2 })
+        );
+        assert_eq!(
+            PolygonArray::from_parts(vec![0.; 4], vec![0.; 4], vec![0, 1], vec![0, 5]),
+            Err(GeoArrowError::InvalidOffsets {
+                name: "ring_offsets"
+            })
+        );
+        assert_eq!(
+            MultiPointArray::from_parts(vec![0.; 4], vec![0.; 4], vec![0, 3, 2]),
+            Err(GeoArrowError::InvalidOffsets {
+                name: "geom_offsets"
+            })
+        );
+    }
+
+    #[test]
+    fn bulk_algorithms_match_geometry_algorithms() {
+        let polygons = polygons();
+        let array: PolygonArray<f64> = polygons.iter().collect();
+        let areas = array.unsigned_areas();
+        let rects = array.bounding_rects();
+        let centroids = array.centroids();
+        for (i, polygon) in polygons.iter().enumerate() {
+            assert_relative_eq!(areas[i], polygon.unsigned_area());
+            assert_eq!(rects[i], polygon.bounding_rect());
+            match (centroids[i], polygon.centroid()) {
+                (Some(bulk), Some(centroid)) => assert_relative_eq!(bulk, centroid),
+                (bulk, centroid) => assert_eq!(bulk, centroid),
+            }
+        }
+
+        let multi_polygons = [
+            MultiPolygon(polygons.clone()),
+            MultiPolygon(polygons[..1].to_vec()),
+        ];
+        let array: MultiPolygonArray<f64> = multi_polygons.iter().collect();
+        let areas = array.unsigned_areas();
+        let rects = array.bounding_rects();
+        let centroids = array.centroids();
+        for (i, multi_polygon) in multi_polygons.iter().enumerate() {
+            assert_relative_eq!(areas[i], multi_polygon.unsigned_area());
+            assert_eq!(rects[i], multi_polygon.bounding_rect());
+            assert_relative_eq!(centroids[i].unwrap(), multi_polygon.centroid().unwrap());
+        }
+
+        let line_strings = [
+            line_string![(x: 0., y: 0.), (x: 3., y: 4.), (x: 3., y: 10.)],
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.)],
+        ];
+        let array: LineStringArray<f64> = line_strings.iter().collect();
+        let lengths = array.euclidean_lengths();
+        let centroids = array.centroids();
+        for (i, line_string) in line_strings.iter().enumerate() {
+            assert_relative_eq!(lengths[i], line_string.euclidean_length());
+            assert_eq!(array.bounding_rects()[i], line_string.bounding_rect());
+            assert_relative_eq!(centroids[i].unwrap(), line_string.centroid().unwrap());
+        }
+
+        let multi_points = [MultiPoint(vec![point!(x: 0., y: 0.), point!(x: 2., y: 6.)])];
+        let array: MultiPointArray<f64> = multi_points.iter().collect();
+        assert_eq!(array.centroids(), vec![multi_points[0].centroid()]);
+        assert_eq!(
+            array.bounding_rects(),
+            vec![multi_points[0].bounding_rect()]
+        );
+    }
+}
@@ -0,0 +1,449 @@
+//! Convert geometries to and from [GeoJSON] geometry objects of the [`geojson`] crate.
+//!
+//! `Line`s are converted to two-position `LineString`s, and `Rect`s and `Triangle`s to
+//! `Polygon`s, since GeoJSON has no equivalent types. Positions with more than two elements
+//! (i.e. with altitudes) are accepted when reading, but only their first two elements are kept.
+//!
+//! Unlike the `geo-types` conversions provided by the `geojson` crate, reading can check (or
+//! repair) polygon rings according to a [`RingPolicy`], so that imported polygons are
+//! immediately usable by algorithms which expect closed, consistently oriented rings.
+//!
+//! [GeoJSON]: https://tools.ietf.org/html/rfc7946
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::io::geojson::{read_geojson, RingPolicy, ToGeoJson};
+//! use geo::{polygon, Geometry};
+//!
+//! // an unclosed, clockwise ring
+//! let value = geojson::Value::Polygon(vec![vec![
+//!     vec![0.0, 0.0],
+//!     vec![0.0, 1.0],
+//!     vec![1.0, 1.0],
+//!     vec![1.0, 0.0],
+//! ]]);
+//!
+//! let polygon = read_geojson::<f64>(&geojson::Geometry::new(value.clone()), RingPolicy::Repair).unwrap();
+//! assert_eq!(
+//!     polygon,
+//!     Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)])
+//! );
+//! assert!(read_geojson::<f64>(&geojson::Geometry::new(value), RingPolicy::Validate).is_err());
+//!
+//! let geojson = polygon.to_geojson();
+//! assert_eq!(read_geojson(&geojson, RingPolicy::Validate), Ok(polygon));
+//! ```
+
+use crate::algorithm::winding_order::{Winding, WindingOrder};
+use crate::{
+    CoordFloat, Coordinate, GeoFloat, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use geojson::Value;
+use std::fmt;
+
+/// How polygon rings are checked when reading GeoJSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingPolicy {
+    /// Read rings as they are.
+    Keep,
+    /// Reject rings which aren't closed or have fewer than four positions, and rings which
+    /// aren't oriented as [RFC 7946](https://tools.ietf.org/html/rfc7946#section-3.1.6)
+    /// requires: exteriors counter-clockwise, and interiors clockwise.
+    Validate,
+    /// Close unclosed rings, and reorient rings as RFC 7946 requires. Rings with fewer than
+    /// three distinct positions can't be repaired, and are rejected.
+    Repair,
+}
+
+/// An error encountered while reading GeoJSON.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeoJsonError {
+    /// A position had fewer than two elements.
+    InvalidPosition {
+        /// The number of elements in the position.
+        len: usize,
+    },
+    /// A polygon ring's first and last positions differ.
+    UnclosedRing,
+    /// A polygon ring had too few positions.
+    TooFewRingPositions {
+        /// The number of positions in the ring.
+        len: usize,
+    },
+    /// A polygon ring was wound in the wrong direction.
+    WrongRingOrientation {
+        /// The orientation required of the ring.
+        expected: WindingOrder,
+    },
+}
+
+impl std::error::Error for GeoJsonError {}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeoJsonError::InvalidPosition { len } => write!(
+                f,
+                "Expected a position with at least two elements, but found {}",
+                len
+            ),
+            GeoJsonError::UnclosedRing => write!(f, "Polygon ring isn't closed"),
+            GeoJsonError::TooFewRingPositions { len } => write!(
+                f,
+                "Expected a polygon ring with at least four positions, but found {}",
+                len
+            ),
+            GeoJsonError::WrongRingOrientation { expected } => {
+                write!(f, "Expected a polygon ring wound {:?}", expected)
+            }
+        }
+    }
+}
+
+/// Convert a geometry to a GeoJSON geometry object.
+///
+/// Polygon rings are written in their existing orientation; use
+/// [`Orient`](crate::algorithm::orient::Orient) beforehand to produce the orientation required by
+/// RFC 7946.
+pub trait ToGeoJson<T: CoordFloat> {
+    /// Convert the geometry to a GeoJSON geometry object.
+    fn to_geojson(&self) -> geojson::Geometry {
+        geojson::Geometry::new(self.to_geojson_value())
+    }
+
+    /// Convert the geometry to the value of a GeoJSON geometry object.
+    fn to_geojson_value(&self) -> Value;
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for Point<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::Point(position(self.0))
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for Line<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::LineString(vec![position(self.start), position(self.end)])
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for LineString<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::LineString(positions(self))
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for Polygon<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::Polygon(polygon_positions(self))
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for MultiPoint<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::MultiPoint(self.iter().map(|point| position(point.0)).collect())
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for MultiLineString<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::MultiLineString(self.iter().map(positions).collect())
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for MultiPolygon<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::MultiPolygon(self.iter().map(polygon_positions).collect())
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for GeometryCollection<T> {
+    fn to_geojson_value(&self) -> Value {
+        Value::GeometryCollection(self.iter().map(|geometry| geometry.to_geojson()).collect())
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for Rect<T> {
+    fn to_geojson_value(&self) -> Value {
+        self.to_polygon().to_geojson_value()
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for Triangle<T> {
+    fn to_geojson_value(&self) -> Value {
+        self.to_polygon().to_geojson_value()
+    }
+}
+
+impl<T: CoordFloat> ToGeoJson<T> for Geometry<T> {
+    fn to_geojson_value(&self) -> Value {
+        match self {
+            Geometry::Point(g) => g.to_geojson_value(),
+            Geometry::Line(g) => g.to_geojson_value(),
+            Geometry::LineString(g) => g.to_geojson_value(),
+            Geometry::Polygon(g) => g.to_geojson_value(),
+            Geometry::MultiPoint(g) => g.to_geojson_value(),
+            Geometry::MultiLineString(g) => g.to_geojson_value(),
+            Geometry::MultiPolygon(g) => g.to_geojson_value(),
+            Geometry::GeometryCollection(g) => g.to_geojson_value(),
+            Geometry::Rect(g) => g.to_geojson_value(),
+            Geometry::Triangle(g) => g.to_geojson_value(),
+        }
+    }
+}
+
+/// Read a GeoJSON geometry object, checking its polygon rings according to `ring_policy`.
+pub fn read_geojson<T: GeoFloat>(
+    geometry: &geojson::Geometry,
+    ring_policy: RingPolicy,
+) -> Result<Geometry<T>, GeoJsonError> {
+    read_value(&geometry.value, ring_policy)
+}
+
+fn read_value<T: GeoFloat>(
+    value: &Value,
+    ring_policy: RingPolicy,
+) -> Result<Geometry<T>, GeoJsonError> {
+    Ok(match value {
+        Value::Point(position) => Geometry::Point(Point(read_position(position)?)),
+        Value::MultiPoint(positions) => Geometry::MultiPoint(MultiPoint(
+            positions
+                .iter()
+                .map(|position| read_position(position).map(Point))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::LineString(positions) => Geometry::LineString(read_line_string(positions)?),
+        Value::MultiLineString(line_strings) => Geometry::MultiLineString(MultiLineString(
+            line_strings
+                .iter()
+                .map(|positions| read_line_string(positions))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::Polygon(rings) => Geometry::Polygon(read_polygon(rings, ring_policy)?),
+        Value::MultiPolygon(polygons) => Geometry::MultiPolygon(MultiPolygon(
+            polygons
+                .iter()
+                .map(|rings| read_polygon(rings, ring_policy))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::GeometryCollection(geometries) => Geometry::GeometryCollection(GeometryCollection(
+            geometries
+                .iter()
+                .map(|geometry| read_geojson(geometry, ring_policy))
+                .collect::<Result<_, _>>()?,
+        )),
+    })
+}
+
+fn position<T: CoordFloat>(coord: Coordinate<T>) -> geojson::Position {
+    vec![
+        coord.x.to_f64().unwrap_or(f64::NAN),
+        coord.y.to_f64().unwrap_or(f64::NAN),
+    ]
+}
+
+fn positions<T: CoordFloat>(line_string: &LineString<T>) -> Vec<geojson::Position> {
+    line_string.0.iter().map(|coord| position(*coord)).collect()
+}
+
+fn polygon_positions<T: CoordFloat>(polygon: &Polygon<T>) -> Vec<Vec<geojson::Position>> {
+    if polygon.exterior().0.is_empty() {
+        return vec![];
+    }
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(positions)
+        .collect()
+}
+
+fn read_position<T: CoordFloat>(position: &[f64]) -> Result<Coordinate<T>, GeoJsonError> {
+    match position {
+        [x, y, ..] => Ok(Coordinate {
+            x: T::from(*x).unwrap_or_else(T::nan),
+            y: T::from(*y).unwrap_or_else(T::nan),
+        }),
+        _ => Err(GeoJsonError::InvalidPosition {
+            len: position.len(),
+        }),
+    }
+}
+
+fn read_line_string<T: CoordFloat>(
+    positions: &[geojson::Position],
+) -> Result<LineString<T>, GeoJsonError> {
+    positions
+        .iter()
+        .map(|position| read_position(position))
+        .collect::<Result<_, _>>()
+        .map(LineString)
+}
+
+fn read_polygon<T: GeoFloat>(
+    rings: &[Vec<geojson::Position>],
+    ring_policy: RingPolicy,
+) -> Result<Polygon<T>, GeoJsonError> {
+    let mut rings = rings.iter().enumerate().map(|(idx, positions)| {
+        let expected = if idx == 0 {
+            WindingOrder::CounterClockwise
+        } else {
+            WindingOrder::Clockwise
+        };
+        read_ring(positions, ring_policy, expected)
+    });
+    let exterior = match rings.next() {
+        Some(exterior) => exterior?,
+        None => LineString(vec![]),
+    };
+    Ok(Polygon::new(exterior, rings.collect::<Result<_, _>>()?))
+}
+
+fn read_ring<T: GeoFloat>(
+    positions: &[geojson::Position],
+    ring_policy: RingPolicy,
+    expected: WindingOrder,
+) -> Result<LineString<T>, GeoJsonError> {
+    let mut ring = read_line_string(positions)?;
+    match ring_policy {
+        RingPolicy::Keep => {}
+        RingPolicy::Validate => {
+            if !ring.is_closed() {
+                return Err(GeoJsonError::UnclosedRing);
+            }
+            if ring.0.len() < 4 {
+                return Err(GeoJsonError::TooFewRingPositions { len: ring.0.len() });
+            }
+            if let Some(winding_order) = ring.winding_order() {
+                if winding_order != expected {
+                    return Err(GeoJsonError::WrongRingOrientation { expected });
+                }
+            }
+        }
+        RingPolicy::Repair => {
+            // `Polygon::new` closes the ring, but the winding order needs a closed ring
+            ring.close();
+            if ring.0.len() < 4 {
+                return Err(GeoJsonError::TooFewRingPositions { len: ring.0.len() });
+            }
+            ring.make_winding_order(expected);
+        }
+    }
+    Ok(ring)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn round_trip(geometry: Geometry<f64>) {
+        let geojson = geometry.to_geojson();
+        assert_eq!(read_geojson(&geojson, RingPolicy::Validate), Ok(geometry));
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 1., y: 2.), (x: 2., y: 2.), (x: 2., y: 1.)]],
+        );
+        let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+        round_trip(point!(x: 1.5, y: -2.5).into());
+        round_trip(line_string.clone().into());
+        round_trip(polygon.clone().into());
+        round_trip(Polygon::new(LineString::<f64>(vec![]), vec![]).into());
+        round_trip(MultiPoint(vec![point!(x: 1., y: 2.)]).into());
+        round_trip(MultiLineString(vec![line_string.clone()]).into());
+        round_trip(MultiPolygon(vec![polygon]).into());
+        round_trip(Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: 1., y: 2.).into(),
+            line_string.into(),
+        ])));
+    }
+
+    #[test]
+    fn substituted_types_test() {
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(
+            read_geojson(&rect.to_geojson(), RingPolicy::Keep),
+            Ok(Geometry::Polygon(rect.to_polygon()))
+        );
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(
+            line.to_geojson_value(),
+            Value::LineString(vec![vec![0., 0.], vec![1., 1.]])
+        );
+    }
+
+    #[test]
+    fn position_test() {
+        let value = Value::Point(vec![1., 2., 3.]);
+        assert_eq!(
+            read_geojson(&geojson::Geometry::new(value), RingPolicy::Keep),
+            Ok(Geometry::Point(point!(x: 1., y: 2.)))
+        );
+        let value = Value::LineString(vec![vec![1., 2.], vec![1.]]);
+        assert_eq!(
+            read_geojson::<f64>(&geojson::Geometry::new(value), RingPolicy::Keep),
+            Err(GeoJsonError::InvalidPosition { len: 1 })
+        );
+    }
+
+    #[test]
+    fn ring_policy_test() {
+        let clockwise_exterior = || {
+            geojson::Geometry::new(Value::Polygon(vec![vec![
+                vec![0., 0.],
+                vec![0., 1.],
+                vec![1., 1.],
+                vec![0., 0.],
+            ]]))
+        };
+        assert_eq!(
+            read_geojson::<f64>(&clockwise_exterior(), RingPolicy::Validate),
+            Err(GeoJsonError::WrongRingOrientation {
+                expected: WindingOrder::CounterClockwise
+            })
+        );
+        let kept = read_geojson::<f64>(&clockwise_exterior(), RingPolicy::Keep).unwrap();
+        assert_eq!(
+            kept,
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 0., y: 1.), (x: 1., y: 1.)])
+        );
+        let repaired = read_geojson::<f64>(&clockwise_exterior(), RingPolicy::Repair).unwrap();
+        assert_eq!(
+            repaired,
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)])
+        );
+
+        let unclosed = geojson::Geometry::new(Value::Polygon(vec![vec![
+            vec![0., 0.],
+            vec![1., 0.],
+            vec![1., 1.],
+        ]]));
+        assert_eq!(
+            read_geojson::<f64>(&unclosed, RingPolicy::Validate),
+            Err(GeoJsonError::UnclosedRing)
+        );
+        assert_eq!(
+            read_geojson::<f64>(&unclosed, RingPolicy::Repair),
+            Ok(Geometry::Polygon(
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]
+            ))
+        );
+
+        let degenerate = geojson::Geometry::new(Value::Polygon(vec![vec![
+            vec![0., 0.],
+            vec![1., 0.],
+            vec![0., 0.],
+        ]]));
+        for ring_policy in &[RingPolicy::Validate, RingPolicy::Repair] {
+            assert_eq!(
+                read_geojson::<f64>(&degenerate, *ring_policy),
+                Err(GeoJsonError::TooFewRingPositions { len: 3 })
+            );
+        }
+    }
+}
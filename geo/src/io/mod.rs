@@ -0,0 +1,11 @@
+/// Convert geometries to and from the buffers of GeoArrow-encoded Arrow arrays, and compute measures
+/// over them in bulk.
+#[cfg(feature = "geoarrow")]
+pub mod geoarrow;
+/// Convert geometries to and from GeoJSON geometry objects, validating or repairing polygon rings.
+#[cfg(feature = "use-geojson")]
+pub mod geojson;
+/// Render geometries as SVG images for debugging.
+pub mod svg;
+/// Encode and decode geometries as Well-Known Binary (WKB) and PostGIS's Extended WKB (EWKB).
+pub mod wkb;
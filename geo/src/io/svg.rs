@@ -0,0 +1,470 @@
+//! Render geometries as [SVG] images, for debugging.
+//!
+//! Coordinates are scaled to fit the image, with the y axis pointing up, so that the image
+//! looks like a plot of the geometries. Sizes in an [`SvgStyle`] (stroke widths, point radii
+//! and font sizes) are in pixels of the output image, independent of the geometries' extent.
+//!
+//! [SVG]: https://www.w3.org/TR/SVG11/
+//!
+//! # Examples
+//!
+//! Render a single geometry:
+//!
+//! ```
+//! use geo::io::svg::ToSvg;
+//! use geo::polygon;
+//!
+//! let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+//! let svg = polygon.to_svg();
+//! assert!(svg.starts_with("<svg"));
+//! ```
+//!
+//! Layer several geometries and labels with their own styles:
+//!
+//! ```
+//! use geo::io::svg::{SvgDocument, SvgStyle};
+//! use geo::{line_string, point, Coordinate, Geometry};
+//!
+//! let highlight = SvgStyle {
+//!     stroke: "red".to_string(),
+//!     stroke_width: 2.0,
+//!     ..SvgStyle::default()
+//! };
+//! let svg = SvgDocument::new()
+//!     .add_geometry(&Geometry::LineString(line_string![(x: 0., y: 0.), (x: 10., y: 10.)]), &SvgStyle::default())
+//!     .add_geometry(&Geometry::Point(point!(x: 5., y: 5.)), &highlight)
+//!     .add_label(Coordinate { x: 5., y: 5. }, "crossing", &highlight)
+//!     .render();
+//! assert!(svg.contains("crossing"));
+//! ```
+
+use crate::algorithm::bounding_rect::BoundingRect;
+use crate::algorithm::map_coords::MapCoords;
+use crate::{
+    CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use std::fmt::Write;
+
+/// The presentation of geometries and labels in an SVG image.
+///
+/// Sizes are in pixels of the output image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgStyle {
+    /// The fill color of areas and points, as an SVG color (e.g. `"steelblue"` or `"#4682b4"`).
+    pub fill: String,
+    /// The opacity of fills, between `0` and `1`.
+    pub fill_opacity: f64,
+    /// The stroke color of lines and outlines, and the color of label text.
+    pub stroke: String,
+    /// The width of lines and outlines.
+    pub stroke_width: f64,
+    /// The radius of points.
+    pub point_radius: f64,
+    /// The font size of labels.
+    pub font_size: f64,
+    /// An optional `class` attribute for the rendered elements, for styling with CSS.
+    pub class: Option<String>,
+}
+
+impl Default for SvgStyle {
+    fn default() -> Self {
+        SvgStyle {
+            fill: "#9ecae1".to_string(),
+            fill_opacity: 0.5,
+            stroke: "#08519c".to_string(),
+            stroke_width: 1.0,
+            point_radius: 3.0,
+            font_size: 10.0,
+            class: None,
+        }
+    }
+}
+
+/// Render a geometry as a standalone SVG image.
+pub trait ToSvg<T: CoordFloat> {
+    /// Render the geometry with the default [`SvgStyle`].
+    fn to_svg(&self) -> String {
+        self.to_svg_with_style(&SvgStyle::default())
+    }
+
+    /// Render the geometry with the given style.
+    fn to_svg_with_style(&self, style: &SvgStyle) -> String;
+}
+
+macro_rules! impl_to_svg {
+    ($type:ident, $variant:ident) => {
+        impl<T: CoordFloat> ToSvg<T> for $type<T> {
+            fn to_svg_with_style(&self, style: &SvgStyle) -> String {
+                SvgDocument::new()
+                    .add_geometry(&Geometry::$variant(self.clone()), style)
+                    .render()
+            }
+        }
+    };
+}
+
+impl_to_svg!(Point, Point);
+impl_to_svg!(Line, Line);
+impl_to_svg!(LineString, LineString);
+impl_to_svg!(Polygon, Polygon);
+impl_to_svg!(MultiPoint, MultiPoint);
+impl_to_svg!(MultiLineString, MultiLineString);
+impl_to_svg!(MultiPolygon, MultiPolygon);
+impl_to_svg!(GeometryCollection, GeometryCollection);
+impl_to_svg!(Rect, Rect);
+impl_to_svg!(Triangle, Triangle);
+
+impl<T: CoordFloat> ToSvg<T> for Geometry<T> {
+    fn to_svg_with_style(&self, style: &SvgStyle) -> String {
+        SvgDocument::new().add_geometry(self, style).render()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Layer {
+    Geometry(Geometry<f64>, SvgStyle),
+    Label(Coordinate<f64>, String, SvgStyle),
+}
+
+/// An SVG image built up from layers of geometries and text labels, each with their own style.
+///
+/// Layers are drawn in the order they're added.
+#[derive(Debug, Clone)]
+pub struct SvgDocument {
+    width: f64,
+    margin: f64,
+    layers: Vec<Layer>,
+}
+
+impl Default for SvgDocument {
+    fn default() -> Self {
+        SvgDocument {
+            width: 800.0,
+            margin: 20.0,
+            layers: vec![],
+        }
+    }
+}
+
+impl SvgDocument {
+    /// Create an empty document, 800 pixels wide.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the width of the image in pixels. Its height follows from the aspect ratio of the
+    /// content.
+    pub fn width(&mut self, width: f64) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    /// Add a geometry layer.
+    pub fn add_geometry<T: CoordFloat>(
+        &mut self,
+        geometry: &Geometry<T>,
+        style: &SvgStyle,
+    ) -> &mut Self {
+        let geometry = geometry.map_coords(|&(x, y)| {
+            (
+                x.to_f64().unwrap_or(f64::NAN),
+                y.to_f64().unwrap_or(f64::NAN),
+            )
+        });
+        self.layers.push(Layer::Geometry(geometry, style.clone()));
+        self
+    }
+
+    /// Add a text label, centered on `coord`.
+    pub fn add_label<T: CoordFloat>(
+        &mut self,
+        coord: Coordinate<T>,
+        text: &str,
+        style: &SvgStyle,
+    ) -> &mut Self {
+        let coord = Coordinate {
+            x: coord.x.to_f64().unwrap_or(f64::NAN),
+            y: coord.y.to_f64().unwrap_or(f64::NAN),
+        };
+        self.layers
+            .push(Layer::Label(coord, text.to_string(), style.clone()));
+        self
+    }
+
+    /// Render the document as an SVG string.
+    pub fn render(&self) -> String {
+        let bounds = self.bounds();
+        let (min, extent) = match bounds {
+            Some(bounds) => (bounds.min(), bounds.width().max(bounds.height())),
+            None => (Coordinate { x: 0., y: 0. }, 0.),
+        };
+        let content_width = self.width - 2. * self.margin;
+        let scale = if extent > 0. {
+            content_width / extent
+        } else {
+            1.
+        };
+        let height = match bounds {
+            Some(bounds) => bounds.height() * scale + 2. * self.margin,
+            None => 2. * self.margin,
+        };
+        let top = min.y + (height - self.margin) / scale;
+        let canvas = Canvas {
+            min_x: min.x,
+            top,
+            scale,
+            margin: self.margin,
+        };
+
+        let mut svg = String::new();
+        write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = num(self.width),
+            h = num(height)
+        )
+        .unwrap();
+        for layer in &self.layers {
+            match layer {
+                Layer::Geometry(geometry, style) => canvas.geometry(&mut svg, geometry, style),
+                Layer::Label(coord, text, style) => canvas.label(&mut svg, *coord, text, style),
+            }
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+
+    fn bounds(&self) -> Option<Rect<f64>> {
+        self.layers
+            .iter()
+            .filter_map(|layer| match layer {
+                Layer::Geometry(geometry, _) => geometry.bounding_rect(),
+                Layer::Label(coord, _, _) => Some(Rect::new(*coord, *coord)),
+            })
+            .fold(None, |acc: Option<Rect<f64>>, rect| match acc {
+                None => Some(rect),
+                Some(acc) => Some(Rect::new(
+                    Coordinate {
+                        x: acc.min().x.min(rect.min().x),
+                        y: acc.min().y.min(rect.min().y),
+                    },
+                    Coordinate {
+                        x: acc.max().x.max(rect.max().x),
+                        y: acc.max().y.max(rect.max().y),
+                    },
+                )),
+            })
+    }
+}
+
+/// The mapping from geometry coordinates to image pixels.
+struct Canvas {
+    min_x: f64,
+    top: f64,
+    scale: f64,
+    margin: f64,
+}
+
+impl Canvas {
+    fn x(&self, coord: Coordinate<f64>) -> String {
+        num((coord.x - self.min_x) * self.scale + self.margin)
+    }
+
+    fn y(&self, coord: Coordinate<f64>) -> String {
+        num((self.top - coord.y) * self.scale)
+    }
+
+    fn points(&self, coords: &[Coordinate<f64>]) -> String {
+        coords
+            .iter()
+            .map(|coord| format!("{},{}", self.x(*coord), self.y(*coord)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn geometry(&self, svg: &mut String, geometry: &Geometry<f64>, style: &SvgStyle) {
+        match geometry {
+            Geometry::Point(point) => self.point(svg, point.0, style),
+            Geometry::Line(line) => self.line_string(svg, &[line.start, line.end], style),
+            Geometry::LineString(line_string) => self.line_string(svg, &line_string.0, style),
+            Geometry::Polygon(polygon) => self.polygon(svg, polygon, style),
+            Geometry::MultiPoint(multi_point) => {
+                for point in multi_point {
+                    self.point(svg, point.0, style);
+                }
+            }
+            Geometry::MultiLineString(multi_line_string) => {
+                for line_string in multi_line_string {
+                    self.line_string(svg, &line_string.0, style);
+                }
+            }
+            Geometry::MultiPolygon(multi_polygon) => {
+                for polygon in multi_polygon {
+                    self.polygon(svg, polygon, style);
+                }
+            }
+            Geometry::GeometryCollection(geometry_collection) => {
+                for geometry in geometry_collection {
+                    self.geometry(svg, geometry, style);
+                }
+            }
+            Geometry::Rect(rect) => self.polygon(svg, &rect.to_polygon(), style),
+            Geometry::Triangle(triangle) => self.polygon(svg, &triangle.to_polygon(), style),
+        }
+    }
+
+    fn point(&self, svg: &mut String, coord: Coordinate<f64>, style: &SvgStyle) {
+        write!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}" fill-opacity="{}" stroke="{}" stroke-width="{}"{}/>"#,
+            self.x(coord),
+            self.y(coord),
+            num(style.point_radius),
+            escape(&style.fill),
+            num(style.fill_opacity),
+            escape(&style.stroke),
+            num(style.stroke_width),
+            class(style),
+        )
+        .unwrap();
+    }
+
+    fn line_string(&self, svg: &mut String, coords: &[Coordinate<f64>], style: &SvgStyle) {
+        write!(
+            svg,
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}"{}/>"#,
+            self.points(coords),
+            escape(&style.stroke),
+            num(style.stroke_width),
+            class(style),
+        )
+        .unwrap();
+    }
+
+    fn polygon(&self, svg: &mut String, polygon: &Polygon<f64>, style: &SvgStyle) {
+        let path = std::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            .filter(|ring| !ring.0.is_empty())
+            .map(|ring| format!("M{}Z", self.points(&ring.0)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        write!(
+            svg,
+            r#"<path d="{}" fill="{}" fill-opacity="{}" fill-rule="evenodd" stroke="{}" stroke-width="{}"{}/>"#,
+            path,
+            escape(&style.fill),
+            num(style.fill_opacity),
+            escape(&style.stroke),
+            num(style.stroke_width),
+            class(style),
+        )
+        .unwrap();
+    }
+
+    fn label(&self, svg: &mut String, coord: Coordinate<f64>, text: &str, style: &SvgStyle) {
+        write!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}" fill="{}" text-anchor="middle"{}>{}</text>"#,
+            self.x(coord),
+            self.y(coord),
+            num(style.font_size),
+            escape(&style.stroke),
+            class(style),
+            escape(text),
+        )
+        .unwrap();
+    }
+}
+
+/// Format a number with at most two decimal places.
+fn num(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+fn class(style: &SvgStyle) -> String {
+    match &style.class {
+        Some(class) => format!(r#" class="{}""#, escape(class)),
+        None => String::new(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    #[test]
+    fn point_test() {
+        let style = SvgStyle {
+            class: Some("vertex".to_string()),
+            ..SvgStyle::default()
+        };
+        assert_eq!(
+            point!(x: 1., y: 2.).to_svg_with_style(&style),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="40" viewBox="0 0 800 40">"#,
+                r##"<circle cx="20" cy="20" r="3" fill="#9ecae1" fill-opacity="0.5" stroke="#08519c" stroke-width="1" class="vertex"/>"##,
+                "</svg>"
+            )
+        );
+    }
+
+    #[test]
+    fn y_axis_points_up_test() {
+        let mut document = SvgDocument::new();
+        document.width(140.);
+        let svg = document
+            .add_geometry(
+                &Geometry::LineString(line_string![(x: 0., y: 0.), (x: 10., y: 5.)]),
+                &SvgStyle::default(),
+            )
+            .render();
+        // 100 pixels of content for 10 units, plus margins
+        assert!(svg.contains(r#"width="140" height="90""#));
+        assert!(svg.contains(r#"points="20,70 120,20""#));
+    }
+
+    #[test]
+    fn polygon_with_hole_test() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.)]],
+        );
+        let svg = polygon.to_svg();
+        assert_eq!(svg.matches('M').count(), 2);
+        assert!(svg.contains(r#"fill-rule="evenodd""#));
+    }
+
+    #[test]
+    fn label_escaping_test() {
+        let svg = SvgDocument::new()
+            .add_label(
+                Coordinate { x: 0., y: 0. },
+                "a < b & \"c\"",
+                &SvgStyle::default(),
+            )
+            .render();
+        assert!(svg.contains("a &lt; b &amp; &quot;c&quot;</text>"));
+    }
+
+    #[test]
+    fn empty_document_test() {
+        assert_eq!(
+            SvgDocument::new().render(),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="40" viewBox="0 0 800 40"></svg>"#
+        );
+    }
+}
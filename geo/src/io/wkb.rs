@@ -0,0 +1,645 @@
+//! Encode and decode geometries as [Well-Known Binary] (WKB), including the [EWKB] extension
+//! used by PostGIS to embed a spatial reference identifier (SRID).
+//!
+//! `Line`s are encoded as two-point `LineString`s, and `Rect`s and `Triangle`s as `Polygon`s,
+//! since WKB has no equivalent types.
+//!
+//! Decoding accepts geometries with Z and/or M ordinates, in both the ISO WKB and EWKB
+//! encodings, but discards the extra ordinates.
+//!
+//! [Well-Known Binary]: https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary
+//! [EWKB]: https://postgis.net/docs/using_postgis_dbmanagement.html#EWKB_EWKT
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::io::wkb::{read_ewkb, read_wkb, ByteOrder, ToWkb};
+//! use geo::{point, Geometry};
+//!
+//! let point = point!(x: 1.0, y: 2.0);
+//!
+//! let wkb = point.to_wkb(ByteOrder::LittleEndian);
+//! assert_eq!(read_wkb(&wkb), Ok(Geometry::Point(point)));
+//!
+//! let ewkb = point.to_ewkb(ByteOrder::BigEndian, Some(4326));
+//! assert_eq!(read_ewkb(&ewkb), Ok((Geometry::Point(point), Some(4326))));
+//! ```
+
+use crate::{
+    CoordFloat, Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use std::fmt;
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// The maximum depth of nested geometry collections accepted when decoding.
+const MAX_DEPTH: usize = 64;
+
+/// The byte order of the numbers in a WKB encoded geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Most significant byte first, also known as XDR.
+    BigEndian,
+    /// Least significant byte first, also known as NDR.
+    LittleEndian,
+}
+
+/// An error encountered while decoding WKB.
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WkbError {
+    /// The input ended before the geometry was complete.
+    UnexpectedEnd,
+    /// The input continued after the end of the geometry, by the given number of bytes.
+    TrailingBytes(usize),
+    /// The byte order marker was neither `0` nor `1`.
+    InvalidByteOrder(u8),
+    /// The geometry type code isn't one of the seven basic WKB geometry types.
+    UnsupportedGeometryType(u32),
+    /// A member of a multi-geometry had the wrong type.
+    UnexpectedGeometryType {
+        /// The geometry type code of the members of the multi-geometry.
+        expected: u32,
+        /// The geometry type code found.
+        found: u32,
+    },
+    /// Geometry collections were nested too deeply.
+    TooDeeplyNested,
+}
+
+impl std::error::Error for WkbError {}
+
+impl fmt::Display for WkbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WkbError::UnexpectedEnd => write!(f, "Unexpected end of WKB input"),
+            WkbError::TrailingBytes(count) => {
+                write!(
+                    f,
+                    "Unexpected {} bytes after the end of the WKB geometry",
+                    count
+                )
+            }
+            WkbError::InvalidByteOrder(byte) => write!(f, "Invalid WKB byte order {}", byte),
+            WkbError::UnsupportedGeometryType(code) => {
+                write!(f, "Unsupported WKB geometry type {}", code)
+            }
+            WkbError::UnexpectedGeometryType { expected, found } => write!(
+                f,
+                "Expected a WKB geometry of type {}, but found type {}",
+                expected, found
+            ),
+            WkbError::TooDeeplyNested => write!(f, "WKB geometry collections nested too deeply"),
+        }
+    }
+}
+
+/// Encode a geometry as WKB or EWKB.
+///
+/// # Examples
+///
+/// ```
+/// use geo::io::wkb::{ByteOrder, ToWkb};
+/// use geo::point;
+///
+/// let wkb = point!(x: 1.0, y: 2.0).to_wkb(ByteOrder::BigEndian);
+/// assert_eq!(
+///     wkb,
+///     vec![
+///         0, 0, 0, 0, 1,
+///         0x3f, 0xf0, 0, 0, 0, 0, 0, 0,
+///         0x40, 0, 0, 0, 0, 0, 0, 0,
+///     ]
+/// );
+/// ```
+pub trait ToWkb<T: CoordFloat> {
+    /// Encode the geometry as EWKB, with the given SRID if any.
+    fn to_ewkb(&self, byte_order: ByteOrder, srid: Option<u32>) -> Vec<u8>;
+
+    /// Encode the geometry as WKB.
+    fn to_wkb(&self, byte_order: ByteOrder) -> Vec<u8> {
+        // EWKB without an SRID is plain two-dimensional WKB
+        self.to_ewkb(byte_order, None)
+    }
+}
+
+macro_rules! impl_to_wkb {
+    ($type:ident, $write:ident) => {
+        impl<T: CoordFloat> ToWkb<T> for $type<T> {
+            fn to_ewkb(&self, byte_order: ByteOrder, srid: Option<u32>) -> Vec<u8> {
+                let mut writer = Writer {
+                    bytes: vec![],
+                    byte_order,
+                };
+                writer.$write(self, srid);
+                writer.bytes
+            }
+        }
+    };
+}
+
+impl_to_wkb!(Point, point);
+impl_to_wkb!(Line, line);
+impl_to_wkb!(LineString, line_string);
+impl_to_wkb!(Polygon, polygon);
+impl_to_wkb!(MultiPoint, multi_point);
+impl_to_wkb!(MultiLineString, multi_line_string);
+impl_to_wkb!(MultiPolygon, multi_polygon);
+impl_to_wkb!(GeometryCollection, geometry_collection);
+impl_to_wkb!(Rect, rect);
+impl_to_wkb!(Triangle, triangle);
+impl_to_wkb!(Geometry, geometry);
+
+/// Decode a WKB geometry.
+///
+/// EWKB input is accepted too, but its SRID is discarded; use [`read_ewkb`] to keep it.
+pub fn read_wkb<T: CoordFloat>(bytes: &[u8]) -> Result<Geometry<T>, WkbError> {
+    read_ewkb(bytes).map(|(geometry, _srid)| geometry)
+}
+
+/// Decode an EWKB geometry, along with its SRID if it has one.
+///
+/// Plain WKB input is accepted too, and has no SRID.
+pub fn read_ewkb<T: CoordFloat>(bytes: &[u8]) -> Result<(Geometry<T>, Option<u32>), WkbError> {
+    let mut reader = Reader {
+        bytes,
+        byte_order: ByteOrder::LittleEndian,
+    };
+    let (geometry_type, srid) = reader.header()?;
+    let geometry = reader.geometry(geometry_type, 0)?;
+    if !reader.bytes.is_empty() {
+        return Err(WkbError::TrailingBytes(reader.bytes.len()));
+    }
+    Ok((geometry, srid))
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+    byte_order: ByteOrder,
+}
+
+impl Writer {
+    fn u32(&mut self, value: u32) {
+        match self.byte_order {
+            ByteOrder::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            ByteOrder::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+
+    fn f64(&mut self, value: f64) {
+        match self.byte_order {
+            ByteOrder::BigEndian => self.bytes.extend_from_slice(&value.to_be_bytes()),
+            ByteOrder::LittleEndian => self.bytes.extend_from_slice(&value.to_le_bytes()),
+        }
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u32(len as u32)
+    }
+
+    fn header(&mut self, geometry_type: u32, srid: Option<u32>) {
+        self.bytes.push(match self.byte_order {
+            ByteOrder::BigEndian => 0,
+            ByteOrder::LittleEndian => 1,
+        });
+        match srid {
+            Some(srid) => {
+                self.u32(geometry_type | EWKB_SRID_FLAG);
+                self.u32(srid);
+            }
+            None => self.u32(geometry_type),
+        }
+    }
+
+    fn coord<T: CoordFloat>(&mut self, coord: Coordinate<T>) {
+        self.f64(coord.x.to_f64().unwrap_or(f64::NAN));
+        self.f64(coord.y.to_f64().unwrap_or(f64::NAN));
+    }
+
+    fn coords<T: CoordFloat>(&mut self, coords: &[Coordinate<T>]) {
+        self.len(coords.len());
+        for coord in coords {
+            self.coord(*coord);
+        }
+    }
+
+    fn point<T: CoordFloat>(&mut self, point: &Point<T>, srid: Option<u32>) {
+        self.header(POINT, srid);
+        self.coord(point.0);
+    }
+
+    fn line<T: CoordFloat>(&mut self, line: &Line<T>, srid: Option<u32>) {
+        self.header(LINE_STRING, srid);
+        self.coords(&[line.start, line.end]);
+    }
+
+    fn line_string<T: CoordFloat>(&mut self, line_string: &LineString<T>, srid: Option<u32>) {
+        self.header(LINE_STRING, srid);
+        self.coords(&line_string.0);
+    }
+
+    fn polygon<T: CoordFloat>(&mut self, polygon: &Polygon<T>, srid: Option<u32>) {
+        self.header(POLYGON, srid);
+        if polygon.exterior().0.is_empty() {
+            // an empty polygon has no rings
+            self.len(0);
+            return;
+        }
+        self.len(1 + polygon.interiors().len());
+        self.coords(&polygon.exterior().0);
+        for interior in polygon.interiors() {
+            self.coords(&interior.0);
+        }
+    }
+
+    fn multi_point<T: CoordFloat>(&mut self, multi_point: &MultiPoint<T>, srid: Option<u32>) {
+        self.header(MULTI_POINT, srid);
+        self.len(multi_point.0.len());
+        for point in multi_point {
+            self.point(point, None);
+        }
+    }
+
+    fn multi_line_string<T: CoordFloat>(
+        &mut self,
+        multi_line_string: &MultiLineString<T>,
+        srid: Option<u32>,
+    ) {
+        self.header(MULTI_LINE_STRING, srid);
+        self.len(multi_line_string.0.len());
+        for line_string in multi_line_string {
+            self.line_string(line_string, None);
+        }
+    }
+
+    fn multi_polygon<T: CoordFloat>(&mut self, multi_polygon: &MultiPolygon<T>, srid: Option<u32>) {
+        self.header(MULTI_POLYGON, srid);
+        self.len(multi_polygon.0.len());
+        for polygon in multi_polygon {
+            self.polygon(polygon, None);
+        }
+    }
+
+    fn geometry_collection<T: CoordFloat>(
+        &mut self,
+        geometry_collection: &GeometryCollection<T>,
+        srid: Option<u32>,
+    ) {
+        self.header(GEOMETRY_COLLECTION, srid);
+        self.len(geometry_collection.0.len());
+        for geometry in geometry_collection {
+            self.geometry(geometry, None);
+        }
+    }
+
+    fn rect<T: CoordFloat>(&mut self, rect: &Rect<T>, srid: Option<u32>) {
+        self.polygon(&rect.to_polygon(), srid);
+    }
+
+    fn triangle<T: CoordFloat>(&mut self, triangle: &Triangle<T>, srid: Option<u32>) {
+        self.polygon(&triangle.to_polygon(), srid);
+    }
+
+    fn geometry<T: CoordFloat>(&mut self, geometry: &Geometry<T>, srid: Option<u32>) {
+        match geometry {
+            Geometry::Point(g) => self.point(g, srid),
+            Geometry::Line(g) => self.line(g, srid),
+            Geometry::LineString(g) => self.line_string(g, srid),
+            Geometry::Polygon(g) => self.polygon(g, srid),
+            Geometry::MultiPoint(g) => self.multi_point(g, srid),
+            Geometry::MultiLineString(g) => self.multi_line_string(g, srid),
+            Geometry::MultiPolygon(g) => self.multi_polygon(g, srid),
+            Geometry::GeometryCollection(g) => self.geometry_collection(g, srid),
+            Geometry::Rect(g) => self.rect(g, srid),
+            Geometry::Triangle(g) => self.triangle(g, srid),
+        }
+    }
+}
+
+/// The type of a geometry, and the number of ordinates in each of its coordinates.
+#[derive(Clone, Copy)]
+struct GeometryType {
+    code: u32,
+    dimensions: usize,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    byte_order: ByteOrder,
+}
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        if self.bytes.len() < N {
+            return Err(WkbError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(N);
+        self.bytes = tail;
+        let mut array = [0; N];
+        array.copy_from_slice(head);
+        Ok(array)
+    }
+
+    fn u32(&mut self) -> Result<u32, WkbError> {
+        let bytes = self.take()?;
+        Ok(match self.byte_order {
+            ByteOrder::BigEndian => u32::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, WkbError> {
+        let bytes = self.take()?;
+        Ok(match self.byte_order {
+            ByteOrder::BigEndian => f64::from_be_bytes(bytes),
+            ByteOrder::LittleEndian => f64::from_le_bytes(bytes),
+        })
+    }
+
+    /// Read a count of items, each of which takes at least `min_item_size` bytes.
+    fn len(&mut self, min_item_size: usize) -> Result<usize, WkbError> {
+        let len = self.u32()? as usize;
+        // reject impossible counts up front, rather than allocating for them
+        if len.saturating_mul(min_item_size) > self.bytes.len() {
+            return Err(WkbError::UnexpectedEnd);
+        }
+        Ok(len)
+    }
+
+    fn header(&mut self) -> Result<(GeometryType, Option<u32>), WkbError> {
+        self.byte_order = match self.take::<1>()?[0] {
+            0 => ByteOrder::BigEndian,
+            1 => ByteOrder::LittleEndian,
+            byte => return Err(WkbError::InvalidByteOrder(byte)),
+        };
+        let raw = self.u32()?;
+        let srid = if raw & EWKB_SRID_FLAG != 0 {
+            Some(self.u32()?)
+        } else {
+            None
+        };
+        let mut dimensions = 2;
+        if raw & EWKB_Z_FLAG != 0 {
+            dimensions += 1;
+        }
+        if raw & EWKB_M_FLAG != 0 {
+            dimensions += 1;
+        }
+        let iso_code = raw & !(EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG);
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for both
+        let code = iso_code % 1000;
+        dimensions += match iso_code / 1000 {
+            0 => 0,
+            1 | 2 => 1,
+            3 => 2,
+            _ => return Err(WkbError::UnsupportedGeometryType(raw)),
+        };
+        if !(POINT..=GEOMETRY_COLLECTION).contains(&code) || dimensions > 4 {
+            return Err(WkbError::UnsupportedGeometryType(raw));
+        }
+        Ok((GeometryType { code, dimensions }, srid))
+    }
+
+    /// Read the header of a member of a multi-geometry, which must have type `expected`.
+    fn member_header(&mut self, expected: u32) -> Result<GeometryType, WkbError> {
+        let (geometry_type, _srid) = self.header()?;
+        if geometry_type.code != expected {
+            return Err(WkbError::UnexpectedGeometryType {
+                expected,
+                found: geometry_type.code,
+            });
+        }
+        Ok(geometry_type)
+    }
+
+    fn coord<T: CoordFloat>(&mut self, dimensions: usize) -> Result<Coordinate<T>, WkbError> {
+        let x = self.f64()?;
+        let y = self.f64()?;
+        for _ in 2..dimensions {
+            self.f64()?;
+        }
+        Ok(Coordinate {
+            x: T::from(x).unwrap_or_else(T::nan),
+            y: T::from(y).unwrap_or_else(T::nan),
+        })
+    }
+
+    fn line_string<T: CoordFloat>(&mut self, dimensions: usize) -> Result<LineString<T>, WkbError> {
+        let len = self.len(dimensions * 8)?;
+        (0..len)
+            .map(|_| self.coord(dimensions))
+            .collect::<Result<_, _>>()
+            .map(LineString)
+    }
+
+    fn polygon<T: CoordFloat>(&mut self, dimensions: usize) -> Result<Polygon<T>, WkbError> {
+        let len = self.len(4)?;
+        let mut rings = (0..len)
+            .map(|_| self.line_string(dimensions))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    fn geometry<T: CoordFloat>(
+        &mut self,
+        geometry_type: GeometryType,
+        depth: usize,
+    ) -> Result<Geometry<T>, WkbError> {
+        // the smallest geometry is an empty one, with a byte order, type and count
+        const MIN_GEOMETRY_SIZE: usize = 9;
+
+        let dimensions = geometry_type.dimensions;
+        Ok(match geometry_type.code {
+            POINT => Geometry::Point(Point(self.coord(dimensions)?)),
+            LINE_STRING => Geometry::LineString(self.line_string(dimensions)?),
+            POLYGON => Geometry::Polygon(self.polygon(dimensions)?),
+            MULTI_POINT => {
+                let len = self.len(MIN_GEOMETRY_SIZE)?;
+                let points = (0..len)
+                    .map(|_| {
+                        let member = self.member_header(POINT)?;
+                        self.coord(member.dimensions).map(Point)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            MULTI_LINE_STRING => {
+                let len = self.len(MIN_GEOMETRY_SIZE)?;
+                let line_strings = (0..len)
+                    .map(|_| {
+                        let member = self.member_header(LINE_STRING)?;
+                        self.line_string(member.dimensions)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiLineString(MultiLineString(line_strings))
+            }
+            MULTI_POLYGON => {
+                let len = self.len(MIN_GEOMETRY_SIZE)?;
+                let polygons = (0..len)
+                    .map(|_| {
+                        let member = self.member_header(POLYGON)?;
+                        self.polygon(member.dimensions)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            GEOMETRY_COLLECTION => {
+                if depth >= MAX_DEPTH {
+                    return Err(WkbError::TooDeeplyNested);
+                }
+                let len = self.len(MIN_GEOMETRY_SIZE)?;
+                let geometries = (0..len)
+                    .map(|_| {
+                        let (member, _srid) = self.header()?;
+                        self.geometry(member, depth + 1)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            code => return Err(WkbError::UnsupportedGeometryType(code)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon};
+
+    fn round_trip(geometry: Geometry<f64>) {
+        for byte_order in &[ByteOrder::BigEndian, ByteOrder::LittleEndian] {
+            let wkb = geometry.to_wkb(*byte_order);
+            assert_eq!(read_wkb(&wkb), Ok(geometry.clone()));
+            let ewkb = geometry.to_ewkb(*byte_order, Some(3857));
+            assert_eq!(read_ewkb(&ewkb), Ok((geometry.clone(), Some(3857))));
+        }
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 0.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 1.)]],
+        );
+        let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.), (x: -5., y: 6.5)];
+        round_trip(point!(x: 1.5, y: -2.5).into());
+        round_trip(line_string.clone().into());
+        round_trip(LineString::<f64>(vec![]).into());
+        round_trip(polygon.clone().into());
+        round_trip(Polygon::new(LineString::<f64>(vec![]), vec![]).into());
+        round_trip(MultiPoint(vec![point!(x: 1., y: 2.), point!(x: 3., y: 4.)]).into());
+        round_trip(MultiLineString(vec![line_string.clone(), line_string.clone()]).into());
+        round_trip(MultiPolygon(vec![polygon.clone()]).into());
+        round_trip(Geometry::GeometryCollection(GeometryCollection(vec![
+            point!(x: 1., y: 2.).into(),
+            Geometry::GeometryCollection(GeometryCollection(vec![line_string.into()])),
+            MultiPolygon::<f64>(vec![]).into(),
+        ])));
+    }
+
+    #[test]
+    fn substituted_types_test() {
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(
+            read_wkb(&line.to_wkb(ByteOrder::LittleEndian)),
+            Ok(Geometry::LineString(line.into()))
+        );
+        let rect = Rect::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 1., y: 1. });
+        assert_eq!(
+            read_wkb(&rect.to_wkb(ByteOrder::LittleEndian)),
+            Ok(Geometry::Polygon(rect.to_polygon()))
+        );
+    }
+
+    #[test]
+    fn known_encoding_test() {
+        // SELECT ST_AsEWKB('SRID=4326;LINESTRING(1 2, 3 4)'::geometry, 'NDR')
+        let ewkb = vec![
+            0x01, 0x02, 0x00, 0x00, 0x20, 0xe6, 0x10, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x10, 0x40,
+        ];
+        let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+        assert_eq!(
+            line_string.to_ewkb(ByteOrder::LittleEndian, Some(4326)),
+            ewkb
+        );
+        assert_eq!(
+            read_ewkb(&ewkb),
+            Ok((Geometry::LineString(line_string), Some(4326)))
+        );
+    }
+
+    #[test]
+    fn extra_dimensions_test() {
+        let mut iso_z = vec![0x01, 0xe9, 0x03, 0x00, 0x00];
+        let mut ewkb_zm = vec![0x01, 0x01, 0x00, 0x00, 0xc0];
+        for ordinate in &[1.0f64, 2.0, 3.0] {
+            iso_z.extend_from_slice(&ordinate.to_le_bytes());
+            ewkb_zm.extend_from_slice(&ordinate.to_le_bytes());
+        }
+        ewkb_zm.extend_from_slice(&4.0f64.to_le_bytes());
+        let expected = Ok(Geometry::Point(point!(x: 1., y: 2.)));
+        assert_eq!(read_wkb(&iso_z), expected);
+        assert_eq!(read_wkb(&ewkb_zm), expected);
+    }
+
+    #[test]
+    fn invalid_input_test() {
+        let wkb = point!(x: 1.0, y: 2.0).to_wkb(ByteOrder::LittleEndian);
+        assert_eq!(
+            read_wkb::<f64>(&wkb[..wkb.len() - 1]),
+            Err(WkbError::UnexpectedEnd)
+        );
+        let mut trailing = wkb.clone();
+        trailing.push(0);
+        assert_eq!(read_wkb::<f64>(&trailing), Err(WkbError::TrailingBytes(1)));
+        assert_eq!(
+            read_wkb::<f64>(&[2, 1, 0, 0, 0]),
+            Err(WkbError::InvalidByteOrder(2))
+        );
+        assert_eq!(
+            read_wkb::<f64>(&[1, 8, 0, 0, 0]),
+            Err(WkbError::UnsupportedGeometryType(8))
+        );
+        // a multipoint containing a line string
+        let mut multi = vec![1, 4, 0, 0, 0, 1, 0, 0, 0];
+        multi.extend(LineString::<f64>(vec![]).to_wkb(ByteOrder::LittleEndian));
+        assert_eq!(
+            read_wkb::<f64>(&multi),
+            Err(WkbError::UnexpectedGeometryType {
+                expected: POINT,
+                found: LINE_STRING
+            })
+        );
+        // a huge count can't allocate
+        assert_eq!(
+            read_wkb::<f64>(&[1, 2, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]),
+            Err(WkbError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn nesting_limit_test() {
+        let mut wkb = vec![];
+        for _ in 0..=MAX_DEPTH {
+            wkb.extend_from_slice(&[1, 7, 0, 0, 0, 1, 0, 0, 0]);
+        }
+        wkb.extend(GeometryCollection::<f64>(vec![]).to_wkb(ByteOrder::LittleEndian));
+        assert_eq!(read_wkb::<f64>(&wkb), Err(WkbError::TooDeeplyNested));
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/georust/meta/master/logo/logo.png")]
 
 //! The `geo` crate provides geospatial primitive types and algorithms.
@@ -35,6 +36,9 @@
 //!
 //! - **[`Area`](algorithm::area::Area)**: Calculate the planar area of a geometry
 //! - **[`ChamberlainDuquetteArea`](algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea)**: Calculate the geodesic area of a geometry
+//! - **[`intersection_area`](algorithm::area_interpolation::intersection_area)**: Calculate the area of the intersection of two polygons
+//! - **[`area_interpolate`](algorithm::area_interpolation::area_interpolate)**: Redistribute values from one set of polygons onto another in proportion to the areas where they overlap
+//! - **[`zonal_stats`](algorithm::zonal_stats::zonal_stats)**: Aggregate the count, sum, mean, minimum and maximum of the values of points within each of a set of polygons
 //!
 //! ## Distance
 //!
@@ -49,6 +53,9 @@
 //! - **[`GeodesicLength`](algorithm::geodesic_length::GeodesicLength)**: Calculate the geodesic length of a geometry using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //! - **[`HaversineLength`](algorithm::haversine_length::HaversineLength)**: Calculate the geodesic length of a geometry using the haversine formula
 //! - **[`VincentyLength`](algorithm::vincenty_length::VincentyLength)**: Calculate the geodesic length of a geometry using Vincenty’s formula
+//! - **[`Sinuosity`](algorithm::sinuosity::Sinuosity)**: Calculate the ratio of the length of a line to the distance between its ends
+//! - **[`Curvature`](algorithm::sinuosity::Curvature)**: Calculate the curvature at each vertex of a line, or at a fixed spacing along it
+//! - **[`Resample`](algorithm::resample::Resample)**: Resample a line at a fixed spacing, or to a fixed number of points, along it
 //!
 //! ## Simplification
 //!
@@ -57,23 +64,50 @@
 //! - **[`SimplifyVW`](algorithm::simplifyvw::SimplifyVW)**: Simplify a geometry using the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVWPreserve`](algorithm::simplifyvw::SimplifyVWPreserve)**: Simplify a geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwIdx`](algorithm::simplifyvw::SimplifyVwIdx)**: Calculate a simplified geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm, returning coordinate indices
+//! - **[`generalize`](algorithm::generalize::generalize)**: Simplify the members of a collection together, keeping the boundaries they share coherent, repairing and orienting the output
+//! - **[`SimplificationQuality`](algorithm::simplification_quality::SimplificationQuality)**: Measure the deviation, area change and reduction in coordinates of a simplified geometry against its original, for choosing a tolerance
+//! - **[`SimplifyImportance`](algorithm::simplify::SimplifyImportance)** and **[`SimplifyVwImportance`](algorithm::simplifyvw::SimplifyVwImportance)**: Calculate the tolerance at which each coordinate would be removed by the Ramer–Douglas–Peucker or Visvalingam-Whyatt algorithm, to simplify once for every zoom level
+//! - **[`RemoveSmallHoles`](algorithm::ring_filters::RemoveSmallHoles)**: Drop the interior rings of a polygon enclosing less than a given area
+//! - **[`KeepLargestShells`](algorithm::ring_filters::KeepLargestShells)**: Keep the polygons of a `MultiPolygon` with the largest exterior rings
 //!
 //! ## Query
 //!
 //! - **[`Bearing`](algorithm::bearing::Bearing)**: Calculate the bearing between points
 //! - **[`ClosestPoint`](algorithm::closest_point::ClosestPoint)**: Find the point on a geometry
 //!   closest to a given point
+//! - **[`ClosestPointTo`](algorithm::closest_point::ClosestPointTo)**: Find the point on a
+//!   geometry closest to another geometry
+//! - **[`Crossings`](algorithm::crossing_direction::Crossings)**: Find where, and which way,
+//!   a line segment crosses a `LineString`
+//! - **[`HaversineClosestPoint`](algorithm::haversine_closest_point::HaversineClosestPoint)**:
+//!   Find the point on a geometry closest to a given point, on a sphere
+//! - **[`segment`](algorithm::segment)**: Project coordinates onto line segments, and measure
+//!   squared distances between segments and coordinates
 //! - **[`IsConvex`](algorithm::is_convex::IsConvex)**: Calculate the convexity of a
-//!   [`LineString`]
+//!   [`LineString`] or [`Polygon`]
+//! - **[`InteriorAngles`](algorithm::angles::InteriorAngles)**: Calculate the interior angle
+//!   at each vertex of a polygon
+//! - **[`TurnAngles`](algorithm::angles::TurnAngles)**: Calculate the signed angle turned
+//!   through at each vertex of a line
+//! - **[`ElevatedLineString`](algorithm::elevated_line_string::ElevatedLineString)**: Attach
+//!   Z values, such as elevations, to the vertices of a line, and keep them through transforms,
+//!   simplification and clipping, interpolating them along its segments
 //! - **[`LineInterpolatePoint`](algorithm::line_interpolate_point::LineInterpolatePoint)**:
 //!   Generates a point that lies a given fraction along the line
 //! - **[`LineLocatePoint`](algorithm::line_locate_point::LineLocatePoint)**: Calculate the
 //!   fraction of a line’s total length representing the location of the closest point on the
 //!   line to the given point
+//! - **[`MeasuredLineString`](algorithm::measured_line_string::MeasuredLineString)**: Attach
+//!   measures, such as timestamps, to the vertices of a line, locate the points and parts of it
+//!   at or between measures, and resample it at fixed intervals of the measure
+//! - **[`sort_around_point`](algorithm::sort_around_point::sort_around_point)**: Sort points by
+//!   their direction from an origin, robustly
 //!
 //! ## Similarity
 //!
 //! - **[`FrechetDistance`](algorithm::frechet_distance::FrechetDistance)**: Calculate the similarity between [`LineString`]s using the Fréchet distance
+//! - **[`SublineMatch`](algorithm::frechet_distance::SublineMatch)**: Find the part of a [`LineString`] most similar to a shorter one, by the Fréchet distance
+//! - **[`HausdorffDistance`](algorithm::hausdorff_distance::HausdorffDistance)**: Calculate the discrete Hausdorff distance between geometries, or the greatest distance from one to another
 //!
 //! ## Topology
 //!
@@ -82,12 +116,39 @@
 //! - **[`CoordinatePosition`](algorithm::coordinate_position::CoordinatePosition)**: Calculate
 //!   the position of a coordinate relative to a geometry
 //! - **[`HasDimensions`](algorithm::dimensions::HasDimensions)**: Determine the dimensions of a geometry
+//! - **[`AdjacencyGraph`](algorithm::adjacency::AdjacencyGraph)**: Find which of a set of
+//!   polygons share a boundary, and which only touch at a point
+//! - **[`spatial_join`](algorithm::spatial_join::spatial_join)**: Find the pairs of two sets of
+//!   geometries which intersect, contain one another, or are within a distance
+//! - **[`validate_coverage`](algorithm::coverage::validate_coverage)**: Find the overlaps, and
+//!   narrow gaps, between the polygons of a coverage
+//! - **[`check_topology`](algorithm::topology_rules::check_topology)**: Check a layer of
+//!   geometries against topology rules, such as its polygons not overlapping, or being covered
+//!   by another layer, and find where each rule is broken
+//! - **[`PlanarGraph`](algorithm::planar_graph::PlanarGraph)**: Node a set of geometries
+//!   together, and walk the graph of their shared edges
+//! - **[`planarize`](algorithm::planar_graph::planarize)**: Node a network of lines against
+//!   itself, merging coincident segments, and map each segment to the lines along it
 //! - **[`Intersects`](algorithm::intersects::Intersects)**: Calculate if a geometry intersects
 //!   another geometry
 //! - **[`line_intersection`](algorithm::line_intersection::line_intersection)**: Calculates the
 //!   intersection, if any, between two lines.
+//! - **[`haversine_line_intersection`](algorithm::haversine_line_intersection::haversine_line_intersection)**:
+//!   Calculates the intersection, if any, between two great-circle segments.
 //! - **[`Relate`](algorithm::relate::Relate)**: Topologically relate two geometries based on
 //!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+//! - **[`TryRelate`](algorithm::relate::TryRelate)**: Topologically relate two geometries,
+//!   failing rather than panicking on coordinates which aren't finite
+//! - **[`RelateWithStats`](algorithm::relate::RelateWithStats)**: Topologically relate two
+//!   geometries, counting the edges, nodes and segment intersection tests involved
+//! - **[`RelateCancellable`](algorithm::relate::RelateCancellable)**: Topologically relate two
+//!   geometries, abandoning the computation once it's cancelled
+//! - **[`RelateMany`](algorithm::relate::RelateMany)**: Topologically relate one geometry to
+//!   many others, building its topology graph only once
+//! - **[`EditableGeometry`](algorithm::relate::EditableGeometry)**: A geometry prepared for
+//!   relating, whose topology graph is updated only where it's edited
+//! - **[`SweepLine`](algorithm::sweepline::SweepLine)**: Find the intersections within a set of
+//!   line segments
 //!
 //! ## Winding
 //!
@@ -103,11 +164,21 @@
 //!   coordinates in a geometry in-place
 //! - **[`TryMapCoords`](algorithm::map_coords::TryMapCoords)**: Map a fallible function over all
 //!   the coordinates in a geometry, returning a new geometry wrapped in a `Result`
+//! - **[`Walk`](algorithm::visit::Walk)**: Walk the collections, members, rings and coordinates of
+//!   any geometry with a [`Visitor`](algorithm::visit::Visitor), or change them with a
+//!   [`VisitorMut`](algorithm::visit::VisitorMut)
 //!
 //! ## Boundary
 //!
+//! - **[`AlphaShape`](algorithm::alpha_shape::AlphaShape)**: Calculate the alpha shape of the
+//!   coordinates of a geometry, from its Delaunay triangulation, and the alphas at which it
+//!   changes
 //! - **[`BoundingRect`](algorithm::bounding_rect::BoundingRect)**: Calculate the axis-aligned
 //!   bounding rectangle of a geometry
+//! - **[`BoundingRectAccumulator`](algorithm::bounding_rect::BoundingRectAccumulator)**: Grow a
+//!   bounding rectangle one geometry or coordinate at a time, over a stream
+//! - **[`Calipers`](algorithm::calipers::Calipers)**: Calculate the diameter and width of a
+//!   geometry, with the pairs of points defining them
 //! - **[`ConcaveHull`](algorithm::concave_hull::ConcaveHull)**: Calculate the concave hull of a
 //!   geometry
 //! - **[`ConvexHull`](algorithm::convex_hull::ConvexHull)**: Calculate the convex hull of a
@@ -124,18 +195,88 @@
 //! ## Miscellaneous
 //!
 //! - **[`Centroid`](algorithm::centroid::Centroid)**: Calculate the centroid of a geometry
+//! - **[`Geofence`](algorithm::geofence::Geofence)**: Emit events as a track of timestamped points enters, leaves, or dwells in an area
+//! - **[`track`](algorithm::track)**: Compute the speeds of a timestamped track, detect where it stopped, and split it at gaps in time or distance
+//! - **[`GeometricMedian`](algorithm::geometric_median::GeometricMedian)**: Calculate the point minimising the total distance to a set of points
 //! - **[`HaversineDestination`](algorithm::haversine_destination::HaversineDestination)**:
 //! - **[`HaversineIntermediate`](algorithm::haversine_intermediate::HaversineIntermediate)**:
 //! - **`Proj`**: Project geometries with the `proj` crate
+//! - **[`Transform`](algorithm::transform::Transform)**: Reproject geometries with any fallible [`CoordTransform`](algorithm::transform::CoordTransform), such as the built-in Web Mercator and UTM projections, or PROJ with the `use-proj` feature
+//! - **[`to_web_mercator`](algorithm::mercator::to_web_mercator)**, **[`from_web_mercator`](algorithm::mercator::from_web_mercator)** and **[`UtmZone`](algorithm::mercator::UtmZone)**: Project between longitude and latitude and the Web Mercator and UTM planes, without the `proj` crate
+//! - **[`LonLat`](algorithm::lon_lat::LonLat)**, **[`wrap_longitude`](algorithm::lon_lat::wrap_longitude)** and **[`clamp_latitude`](algorithm::lon_lat::clamp_latitude)**: Report the coordinates of a geometry out of range as longitudes and latitudes, and wrap and clamp them into range, before spherical and geodesic algorithms
+//! - **[`Cancel`](algorithm::cancel::Cancel)**, **[`CancelToken`](algorithm::cancel::CancelToken)** and **[`Deadline`](algorithm::cancel::Deadline)**: Abandon a long-running relate or buffer on pathological input, from another thread or after a time budget, with its cancellable variant
+//! - **[`Progress`](algorithm::progress::Progress)**: Report how many items a batch operation, such as a spatial join or relating one geometry to many, has processed, for a progress bar
+//! - **[`HasFiniteCoordinates`](algorithm::finite::HasFiniteCoordinates)** and **[`ScrubNonFinite`](algorithm::finite::ScrubNonFinite)**: Find the `NaN` and infinite coordinates of a geometry, and remove them, with whatever they leave too small to stand
 //! - **[`ChaikinSmoothing`](algorithm::chaikin_smoothing::ChaikinSmoothing)**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
+//! - **[`Smooth`](algorithm::smooth::Smooth)**: Smooth lines and polygon rings by Chaikin corner cutting or a Catmull–Rom spline through their vertices, keeping rings closed
+//! - **[`ConvexDecomposition`](algorithm::convex_decomposition::ConvexDecomposition)**: Split a polygon with holes into convex polygons, by triangulating it and merging the triangles following Hertel and Mehlhorn
+//! - **[`Erosion`](algorithm::erosion::Erosion)**: Erode a polygon inwards by a distance, a negative buffer, or by a series of steps until it collapses, for setbacks and nested rings
+//! - **[`VariableBuffer`](algorithm::variable_buffer::VariableBuffer)**: Buffer a line by a distance given at each of its vertices, or tapering along it, for corridors of varying width
+//! - **[`SingleSidedBuffer`](algorithm::single_sided_buffer::SingleSidedBuffer)**: Buffer a line on its left or right side only, with flat ends, such as the land within a distance of a coastline
+//! - **[`GeodesicBuffer`](algorithm::geodesic_buffer::GeodesicBuffer)**: Buffer a point, line or polygon of longitudes and latitudes by a distance in metres, in an azimuthal equidistant projection centred on it
+//! - **[`LabelFit`](algorithm::label_fit::LabelFit)**: Find whether, and where, a rotated rectangle such as a label fits inside a polygon, by eroding the polygon by the rectangle
+//! - **[`EqualAreaPartition`](algorithm::equal_area_partition::EqualAreaPartition)**: Split a polygon into a number of parts of equal area by recursive bisection, for balanced territories and sampling frames
+//! - **[`LargestInscribedCircle`](algorithm::inscribed::LargestInscribedCircle)** and **[`LargestInscribedRect`](algorithm::inscribed::LargestInscribedRect)**: Find the largest circle inside a polygon, centred on its pole of inaccessibility, and approximate the largest axis-aligned or rotated rectangle inside it
+//! - **[`RectangleDecomposition`](algorithm::rectangle_decomposition::RectangleDecomposition)**: Decompose a rectilinear polygon, such as a floorplan, into a near-minimal set of non-overlapping `Rect`s
+//! - **[`split_polygon`](algorithm::split::split_polygon)**: Cut a polygon into pieces along a line crossing it, any number of times
+//! - **[`clip_lines`](algorithm::clip_lines::clip_lines)**: Split lines into their parts inside and outside a set of polygons, without a full overlay
+//! - **[`GeometryDeduper`](algorithm::dedup::GeometryDeduper)** and **[`equals_within`](algorithm::dedup::equals_within)**: Detect duplicate geometries up to a coordinate tolerance, regardless of where their rings start, which way they run, or the order of their members
+//! - **[`Normalize`](algorithm::normalize::Normalize)**: Put a geometry into a canonical form, with rings starting at their least vertex and wound consistently, and members sorted, so equal geometries compare, hash and diff alike
+//! - **`GeometryKey`**: Use geometries as the keys of hash maps and sets, by their normalized, optionally quantized, coordinates, with the `hash` feature
+//! - **[`streaming`](algorithm::streaming)**: Measure, densify, simplify and bound lines given as iterators of coordinates, in bounded memory, for lines too large to hold as a `LineString`
+//! - **[`LineStringView`](algorithm::coord_slices::LineStringView)** and **[`PolygonView`](algorithm::coord_slices::PolygonView)**: Measure, simplify and test points against lines and polygons whose coordinates are borrowed from separate `x` and `y` slices, as in GeoArrow, without copying them into `LineString`s
+//! - **`PackedLineString`** and **`PackedPolygon`**: Measure lines and rings, and test many points against a polygon, over coordinates packed into separate `x` and `y` arrays that the compiler can vectorize, with the `simd` feature
+//! - **[`square_grid`](algorithm::binning::square_grid)** and **[`hex_grid`](algorithm::binning::hex_grid)**: Generate grids of cells covering a `Rect`, optionally counting the points in each cell
+//! - **[`isolines`](algorithm::contour::isolines)** and **[`isobands`](algorithm::contour::isobands)**: Build contour lines and filled contour bands from a grid of values
+//! - **[`from_shapefile_rings`](algorithm::shapefile_rings::from_shapefile_rings)** and **[`to_shapefile_rings`](algorithm::shapefile_rings::to_shapefile_rings)**: Convert between the flat list of clockwise outer rings and counter-clockwise holes of a shapefile polygon and a `MultiPolygon`, assigning each hole to the outer ring enclosing it
+//! - **[`trace_mask`](algorithm::trace_mask::trace_mask)**: Trace the regions of set cells in a boolean raster mask into valid `Polygon`s with holes
+//! - **[`rasterize`](algorithm::rasterize::rasterize)** and **[`rasterize_counts`](algorithm::rasterize::rasterize_counts)**: Burn geometries into a boolean or count grid of cells covering a `Rect`, by cell centres or by all cells touched
+//! - **[`tile_cover`](algorithm::tile_cover::tile_cover)**: Find the tiles of a slippy map, at a zoom level, which a geometry in longitude and latitude intersects
+//! - **[`encode_geohash`](algorithm::geohash::encode_geohash)**, **[`decode_geohash`](algorithm::geohash::decode_geohash)** and **[`geohash_cover`](algorithm::geohash::geohash_cover)**: Convert between points and geohashes, and find the geohash cells which a geometry intersects
+//! - **`Sample`**: Draw random points uniformly from geometries, or a Poisson-disk sample of a `Rect`, with the `rand` crate
+//! - **[`ShapeDescriptors`](algorithm::shape_descriptors::ShapeDescriptors)**: Measure the compactness, elongation and convexity of `Polygon`s
+//! - **[`line_measures`](algorithm::line_measures)**: Measure distances, lengths and areas, and densify and interpolate lines, in a choice of metric spaces: `Euclidean`, `Haversine`, `Geodesic` or `Rhumb`
+//! - **[`IdwInterpolator`](algorithm::idw_interpolation::IdwInterpolator)**: Interpolate values at coordinates or over a grid from scattered samples using Inverse Distance Weighting
+//! - **[`MapMatcher`](algorithm::map_match::MapMatcher)**: Snap the points of a trace, such as a GPS track, to a network of `LineString`s, following connected edges
+//! - **[`LineNetwork`](algorithm::line_network::LineNetwork)**: Node a network of `LineString`s into a graph, snap points onto it, and find the shortest path and its length between them
+//! - **[`Linearize`](algorithm::linearize::Linearize)**: Approximate the arcs of a [`CircularString`](algorithm::linearize::CircularString), compound [`Curve`](algorithm::linearize::Curve) or [`CurvePolygon`](algorithm::linearize::CurvePolygon) by straight segments within a tolerance
+//! - **[`visibility_polygon`](algorithm::visibility::visibility_polygon)**: Find the region of a `Polygon`, with holes, visible from a point inside it
+//!
+//! ## Input and output
+//!
+//! - **`PolygonArray`** and the other GeoArrow arrays: Convert geometries to and from GeoArrow's coordinate and offset buffers, and compute areas, lengths, bounds and centroids over them in bulk
+//! - **`ToGeoJson`** and **`read_geojson`**: Convert geometries to and from GeoJSON geometry objects, optionally validating or repairing polygon rings
+//! - **[`ToSvg`](io::svg::ToSvg)** and **[`SvgDocument`](io::svg::SvgDocument)**: Render geometries, and labels, as SVG images for debugging
+//! - **[`ToWkb`](io::wkb::ToWkb)**, **[`read_wkb`](io::wkb::read_wkb)** and **[`read_ewkb`](io::wkb::read_ewkb)**: Encode and decode geometries as Well-Known Binary, including PostGIS's EWKB with an SRID
 //!
 //! # Features
 //!
 //! The following optional [Cargo features] are available:
 //!
+//! - `debug`: Exposes read-only views of the topology graphs computed by [`Relate`](algorithm::relate::Relate), for diagnosing unexpected results
+//! - `geoarrow`: Enables the `io::geoarrow` module, which converts geometries to and from the buffers of GeoArrow-encoded Arrow arrays
+//! - `hash`: Enables `GeometryKey`, which makes geometries usable as the keys of hash maps and sets by their canonical form
 //! - `proj-network`: Enables [network grid] support for the [`proj` crate]. After enabling this feature, [further configuration][proj crate file download] is required to use the network grid
+//! - `simd`: Enables the `packed` module, whose bulk measures and point-in-polygon tests run over coordinates laid out for the compiler to vectorize
+//! - `std`: Enabled by default. See [`no_std`](#no_std) below
+//! - `use-geojson`: Enables conversion of geometries to and from the [`geojson` crate]'s GeoJSON geometry objects
 //! - `use-proj`: Enables coordinate conversion and transformation of `Point` geometries using the [`proj` crate]
-//! - `use-serde`: Allows geometry types to be serialized and deserialized with [Serde]
+//! - `use-rand`: Enables drawing random points from geometries with the [`rand` crate]
+//! - `use-serde`: Allows geometry types, and the results and errors of algorithms such as [`IntersectionMatrix`](algorithm::relate::IntersectionMatrix) and [`LineIntersection`](algorithm::line_intersection::LineIntersection), to be serialized and deserialized with [Serde]
+//!
+//! ## `no_std`
+//!
+//! With default features disabled, `geo` is `no_std` and needs only `alloc`, so it can be used
+//! on embedded targets and for deterministic WebAssembly builds. Floating point functions then
+//! come from [libm]. The pure computations remain available: [`Area`](algorithm::area::Area),
+//! [`EuclideanLength`](algorithm::euclidean_length::EuclideanLength),
+//! [`Centroid`](algorithm::centroid::Centroid), [`ConvexHull`](algorithm::convex_hull::ConvexHull),
+//! [`BoundingRect`](algorithm::bounding_rect::BoundingRect),
+//! [`CoordinatePosition`](algorithm::coordinate_position::CoordinatePosition) and
+//! [`Intersects`](algorithm::intersects::Intersects) for point-in-polygon tests, and
+//! [`Simplify`](algorithm::simplify::Simplify), along with the traits they build on. Everything
+//! else, including `Relate`, `Contains` and the R*-tree backed algorithms, needs the `std`
+//! feature, as do all the other optional features.
 //!
 //! # Ecosystem
 //!
@@ -151,7 +292,9 @@
 //!
 //! [`geo-types`]: https://crates.io/crates/geo-types
 //! [`proj` crate]: https://github.com/georust/proj
+//! [`rand` crate]: https://crates.io/crates/rand
 //! [geojson crate]: https://crates.io/crates/geojson
+//! [`geojson` crate]: https://crates.io/crates/geojson
 //! [wkt crate]: https://crates.io/crates/wkt
 //! [shapefile crate]: https://crates.io/crates/shapefile
 //! [latlng crate]: https://crates.io/crates/latlon
@@ -161,11 +304,13 @@
 //! [Cargo features]: https://doc.rust-lang.org/cargo/reference/features.html
 //! [GEOS]: https://trac.osgeo.org/geos
 //! [JTS]: https://github.com/locationtech/jts
+//! [libm]: https://github.com/rust-lang/libm
 //! [network grid]: https://proj.org/usage/network.html
 //! [OGC-SFA]: https://www.ogc.org/standards/sfa
 //! [proj crate file download]: https://docs.rs/proj/*/proj/#grid-file-download
 //! [Serde]: https://serde.rs/
 
+extern crate alloc;
 extern crate geo_types;
 extern crate num_traits;
 #[cfg(feature = "use-serde")]
@@ -173,11 +318,15 @@ extern crate num_traits;
 extern crate serde;
 #[cfg(feature = "use-proj")]
 extern crate proj;
+#[cfg(feature = "std")]
 extern crate rstar;
 
 pub use crate::algorithm::*;
 #[allow(deprecated)]
 pub use crate::traits::ToGeo;
+#[cfg(feature = "std")]
+pub use crate::error::GeoError;
+#[cfg(feature = "std")]
 pub use crate::types::Closest;
 
 pub use geo_types::{
@@ -187,7 +336,12 @@ pub use geo_types::{
 
 /// This module includes all the functions of geometric calculations
 pub mod algorithm;
+#[cfg(feature = "std")]
+mod error;
 mod geometry_cow;
+/// This module includes readers and writers for geometry interchange formats
+#[cfg(feature = "std")]
+pub mod io;
 mod traits;
 mod types;
 mod utils;
@@ -197,7 +351,7 @@ pub(crate) use geometry_cow::GeometryCow;
 #[macro_use]
 extern crate approx;
 
-#[macro_use]
+#[cfg_attr(feature = "std", macro_use)]
 extern crate log;
 
 /// Mean radius of Earth in meters
@@ -207,54 +361,137 @@ extern crate log;
 /// https://link.springer.com/article/10.1007%2Fs001900050278
 /// https://sci-hub.se/https://doi.org/10.1007/s001900050278
 /// https://en.wikipedia.org/wiki/Earth_radius#Mean_radius
+#[cfg(feature = "std")]
 const MEAN_EARTH_RADIUS: f64 = 6371008.8;
 
 // Radius of Earth at the equator in meters (derived from the WGS-84 ellipsoid)
+#[cfg(feature = "std")]
 const EQUATORIAL_EARTH_RADIUS: f64 = 6_378_137.0;
 
 // Radius of Earth at the poles in meters (derived from the WGS-84 ellipsoid)
+#[cfg(feature = "std")]
 const POLAR_EARTH_RADIUS: f64 = 6_356_752.314_245;
 
 // Flattening of the WGS-84 ellipsoid - https://en.wikipedia.org/wiki/Flattening
+#[cfg(feature = "std")]
 const EARTH_FLATTENING: f64 =
     (EQUATORIAL_EARTH_RADIUS - POLAR_EARTH_RADIUS) / EQUATORIAL_EARTH_RADIUS;
 
 /// A prelude which re-exports the traits for manipulating objects in this
 /// crate. Typically imported with `use geo::prelude::*`.
 pub mod prelude {
+    pub use crate::algorithm::angles::{InteriorAngles, TurnAngles};
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::alpha_shape::AlphaShape;
     pub use crate::algorithm::area::Area;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::bearing::Bearing;
     pub use crate::algorithm::bounding_rect::BoundingRect;
+    pub use crate::algorithm::calipers::Calipers;
     pub use crate::algorithm::centroid::Centroid;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::chaikin_smoothing::ChaikinSmoothing;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::closest_point::ClosestPoint;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::closest_point::ClosestPointTo;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::contains::Contains;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::convex_decomposition::ConvexDecomposition;
     pub use crate::algorithm::convex_hull::ConvexHull;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::crossing_direction::Crossings;
     pub use crate::algorithm::dimensions::HasDimensions;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::equal_area_partition::EqualAreaPartition;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::erosion::Erosion;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::euclidean_distance::EuclideanDistance;
     pub use crate::algorithm::euclidean_length::EuclideanLength;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::extremes::Extremes;
-    pub use crate::algorithm::frechet_distance::FrechetDistance;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::finite::{HasFiniteCoordinates, ScrubNonFinite};
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::frechet_distance::{FrechetDistance, SublineMatch};
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::geodesic_buffer::GeodesicBuffer;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::geodesic_distance::GeodesicDistance;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::geodesic_intermediate::GeodesicIntermediate;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::geodesic_length::GeodesicLength;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::geometric_median::GeometricMedian;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::hausdorff_distance::HausdorffDistance;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::haversine_closest_point::HaversineClosestPoint;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::haversine_destination::HaversineDestination;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::haversine_distance::HaversineDistance;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::haversine_intermediate::HaversineIntermediate;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::haversine_length::HaversineLength;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::inscribed::{LargestInscribedCircle, LargestInscribedRect};
     pub use crate::algorithm::intersects::Intersects;
     pub use crate::algorithm::is_convex::IsConvex;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::label_fit::LabelFit;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::line_measures::{
+        Densify, Distance, InterpolatePoint, Length, MeasureArea,
+    };
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::linearize::Linearize;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::lon_lat::LonLat;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::map_coords::MapCoords;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::normalize::Normalize;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::orient::Orient;
     #[cfg(feature = "use-proj")]
     pub use crate::algorithm::proj::Proj;
+    #[cfg(feature = "use-rand")]
+    pub use crate::algorithm::sample::Sample;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::rectangle_decomposition::RectangleDecomposition;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::resample::Resample;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::ring_filters::{KeepLargestShells, RemoveSmallHoles};
+    #[cfg(feature = "std")]
     pub use crate::algorithm::rotate::{Rotate, RotatePoint};
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::simplification_quality::SimplificationQuality;
     pub use crate::algorithm::simplify::Simplify;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::simplifyvw::SimplifyVW;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::sinuosity::{Curvature, Sinuosity};
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::smooth::Smooth;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::transform::Transform;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::translate::Translate;
+    #[cfg(feature = "std")]
+    pub use crate::algorithm::variable_buffer::VariableBuffer;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::vincenty_distance::VincentyDistance;
+    #[cfg(feature = "std")]
     pub use crate::algorithm::vincenty_length::VincentyLength;
+    pub use crate::algorithm::visit::Walk;
 }
 
 /// A common numeric trait used for geo algorithms.
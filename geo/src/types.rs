@@ -1,6 +1,8 @@
+#[cfg(feature = "std")]
 use crate::{GeoFloat, Point};
 
 /// The result of trying to find the closest spot on an object to a point.
+#[cfg(feature = "std")]
 #[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Closest<F: GeoFloat> {
@@ -12,6 +14,7 @@ pub enum Closest<F: GeoFloat> {
     Indeterminate,
 }
 
+#[cfg(feature = "std")]
 impl<F: GeoFloat> Closest<F> {
     /// Compare two `Closest`s relative to `p` and return a copy of the best
     /// one.
@@ -1,5 +1,6 @@
 //! Internal utility functions, types, and data structures.
 
+use core::ops::{Add, Mul, Sub};
 use geo_types::{CoordNum, Coordinate};
 
 /// Partition a mutable slice in-place so that it contains all elements for
@@ -74,10 +75,28 @@ pub fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
     }
 }
 
+/// Interpolate linearly from `start` to `end`, such as between the coordinates or
+/// measures at the ends of a segment. The ends themselves come back exactly at a
+/// `fraction` of zero or one.
+#[cfg(feature = "std")]
+pub fn lerp<V, T>(start: V, end: V, fraction: T) -> V
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<T, Output = V>,
+    T: CoordNum,
+{
+    if fraction == T::zero() {
+        start
+    } else if fraction == T::one() {
+        end
+    } else {
+        start + (end - start) * fraction
+    }
+}
+
 // Moved to their own module, but we re-export to avoid breaking the API.
 pub use crate::algorithm::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
 
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 /// Compare two coordinates lexicographically: first by the
 /// x coordinate, and break ties with the y coordinate.